@@ -37,30 +37,7 @@ use super::traits::HasExamples;
 impl<'f> HasExamples<'f, 72> for ExecutionAnalyze {
     fn example_inputs() -> [Self::Input; 72] {
         let op1 = {
-            let mut test_runner = TestRunnerBuilder::new().without_trace().build();
-            let (public_key1, _, account1) = test_runner.new_account(true);
-            let (public_key2, _, account2) = test_runner.new_account(true);
-
-            let manifest = ManifestBuilder::new()
-                .lock_fee(account1, "10")
-                .withdraw_from_account(account1, XRD, "10")
-                .take_from_worktop(XRD, "10", "bucket")
-                .with_bucket("bucket", |builder, bucket| {
-                    builder.call_method(
-                        account2,
-                        ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT,
-                        manifest_args!(bucket, Option::<ResourceOrNonFungible>::None),
-                    )
-                })
-                .build();
-            let receipt = test_runner.preview_manifest(
-                manifest.clone(),
-                vec![public_key1.into(), public_key2.into()],
-                0,
-                PreviewFlags::default(),
-            );
-            receipt.expect_commit_success();
-
+            let (manifest, receipt, _) = account_transfer_preview();
             (manifest, receipt)
         };
 
@@ -144,26 +121,7 @@ impl<'f> HasExamples<'f, 72> for ExecutionAnalyze {
             .chain(execute_scenarios(
                 &mut TestRunnerBuilder::new().without_trace().build(),
             ))
-            .map(|(manifest, receipt)| {
-                let instructions =
-                    to_serializable_instructions(&manifest.instructions, 0xf2).unwrap();
-                let instructions = SerializableInstructions::Parsed(instructions);
-                let instructions = InstructionsConvert::handle(InstructionsConvertInput {
-                    instructions,
-                    network_id: 0xf2.into(),
-                    instructions_kind: SerializableInstructionsKind::String,
-                })
-                .unwrap();
-
-                let preview_receipt =
-                    scrypto_encode(&VersionedTransactionReceipt::V1(receipt)).unwrap();
-
-                Self::Input {
-                    instructions,
-                    network_id: 0xf2.into(),
-                    preview_receipt: preview_receipt.into(),
-                }
-            })
+            .map(|(manifest, receipt)| analyze_input(&manifest, receipt))
             .collect::<Vec<_>>()
             .try_into()
             .unwrap_or_else(|v: Vec<Self::Input>| {
@@ -172,6 +130,95 @@ impl<'f> HasExamples<'f, 72> for ExecutionAnalyze {
     }
 }
 
+impl<'f> HasExamples<'f, 1> for ExecutionLint {
+    fn example_inputs() -> [Self::Input; 1] {
+        let (_, receipt, account) = account_transfer_preview();
+        let preview_receipt = scrypto_encode(&VersionedTransactionReceipt::V1(receipt)).unwrap();
+
+        [Self::Input {
+            network_id: 0xf2.into(),
+            preview_receipt: preview_receipt.into(),
+            owned_accounts: vec![SerializableNodeId::from_global_address(account, 0xf2)],
+            known_counterparties: vec![],
+            dust_threshold: dec!("1").into(),
+            look_alike_leading_characters: 4.into(),
+            look_alike_trailing_characters: 6.into(),
+        }]
+    }
+}
+
+impl<'f> HasExamples<'f, 1> for ExecutionVerifyAttestation {
+    fn example_inputs() -> [Self::Input; 1] {
+        let private_key = Ed25519PrivateKey::from_u64(1).unwrap();
+
+        let (manifest, receipt, _) = account_transfer_preview();
+        let output = ExecutionAnalyze::handle(ExecutionAnalyzeInput {
+            attestation_private_key: Some(private_key.to_bytes().into()),
+            ..analyze_input(&manifest, receipt)
+        })
+        .unwrap();
+
+        [Self::Input {
+            attestation: output.attestation.unwrap(),
+            trusted_public_key: private_key.public_key().into(),
+        }]
+    }
+}
+
+/// Previews a transfer of XRD between two accounts, returning the manifest, the receipt and the
+/// account that the XRD is withdrawn from.
+fn account_transfer_preview() -> (TransactionManifestV1, TransactionReceipt, ComponentAddress) {
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+    let (public_key1, _, account1) = test_runner.new_account(true);
+    let (public_key2, _, account2) = test_runner.new_account(true);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account1, "10")
+        .withdraw_from_account(account1, XRD, "10")
+        .take_from_worktop(XRD, "10", "bucket")
+        .with_bucket("bucket", |builder, bucket| {
+            builder.call_method(
+                account2,
+                ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT,
+                manifest_args!(bucket, Option::<ResourceOrNonFungible>::None),
+            )
+        })
+        .build();
+    let receipt = test_runner.preview_manifest(
+        manifest.clone(),
+        vec![public_key1.into(), public_key2.into()],
+        0,
+        PreviewFlags::default(),
+    );
+    receipt.expect_commit_success();
+
+    (manifest, receipt, account1)
+}
+
+fn analyze_input(
+    manifest: &TransactionManifestV1,
+    receipt: TransactionReceipt,
+) -> ExecutionAnalyzeInput {
+    let instructions = to_serializable_instructions(&manifest.instructions, 0xf2).unwrap();
+    let instructions = SerializableInstructions::Parsed(instructions);
+    let instructions = InstructionsConvert::handle(InstructionsConvertInput {
+        instructions,
+        network_id: 0xf2.into(),
+        instructions_kind: SerializableInstructionsKind::String,
+    })
+    .unwrap();
+
+    let preview_receipt = scrypto_encode(&VersionedTransactionReceipt::V1(receipt)).unwrap();
+
+    ExecutionAnalyzeInput {
+        instructions,
+        network_id: 0xf2.into(),
+        preview_receipt: preview_receipt.into(),
+        dex_registry: vec![],
+        attestation_private_key: None,
+    }
+}
+
 pub fn execute_scenarios(
     test_runner: &mut TestRunner<NoExtension, InMemorySubstateDatabase>,
 ) -> Vec<(TransactionManifestV1, TransactionReceipt)> {
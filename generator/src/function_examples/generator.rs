@@ -20,15 +20,14 @@ use indexmap::{indexmap, IndexMap};
 use radix_engine_toolkit::prelude::*;
 
 #[allow(clippy::type_complexity)]
-pub fn generate_function_examples() -> IndexMap<
-    &'static str,
-    IndexMap<String, Vec<FunctionExample<serde_json::Value, serde_json::Value>>>,
-> {
-    indexmap!(
-        "information" => function_examples![
+pub fn generate_function_examples(
+) -> IndexMap<String, IndexMap<String, Vec<FunctionExample<serde_json::Value, serde_json::Value>>>>
+{
+    let mut function_examples = indexmap!(
+        "information".to_owned() => function_examples![
             BuildInformation
         ],
-        "derive" => function_examples![
+        "derive".to_owned() => function_examples![
             DeriveVirtualAccountAddressFromPublicKey,
             DeriveVirtualIdentityAddressFromPublicKey,
             DeriveVirtualSignatureNonFungibleGlobalIdFromPublicKey,
@@ -38,7 +37,7 @@ pub fn generate_function_examples() -> IndexMap<
             DeriveOlympiaAccountAddressFromPublicKey,
             DeriveNodeAddressFromPublicKey,
         ],
-        "instructions" => function_examples![
+        "instructions".to_owned() => function_examples![
             InstructionsHash,
             InstructionsConvert,
             InstructionsCompile,
@@ -46,41 +45,87 @@ pub fn generate_function_examples() -> IndexMap<
             InstructionsStaticallyValidate,
             InstructionsExtractAddresses,
         ],
-        "execution" => function_examples![
-            ExecutionAnalyze
+        "execution".to_owned() => function_examples![
+            ExecutionAnalyze,
+            ExecutionLint,
+            ExecutionVerifyAttestation,
         ],
-        "manifest" => function_examples![
+        "manifest".to_owned() => function_examples![
             ManifestHash,
             ManifestCompile,
             ManifestDecompile,
             ManifestStaticallyValidate,
+            ManifestEncodeCallArguments,
         ],
-        "intent" => function_examples![
+        "intent".to_owned() => function_examples![
             IntentHash,
             IntentCompile,
             IntentDecompile,
             IntentStaticallyValidate,
+            IntentCompare,
+            IntentDisplayRecords,
+            IntentSummaryHash,
         ],
-        "signed_intent" => function_examples![
+        "signed_intent".to_owned() => function_examples![
             SignedIntentHash,
             SignedIntentCompile,
             SignedIntentDecompile,
             SignedIntentStaticallyValidate,
         ],
-        "notarized_transaction" => function_examples![
+        "notarized_transaction".to_owned() => function_examples![
             NotarizedTransactionHash,
             NotarizedTransactionCompile,
             NotarizedTransactionDecompile,
             NotarizedTransactionStaticallyValidate,
+            NotarizedTransactionVerifyNotary,
+            TransactionPreflightCheck,
         ],
-        "utils" => function_examples![
-            UtilsKnownAddress
+        "validation".to_owned() => function_examples![
+            ValidationNotarizedPayloadSize,
+            ValidationNotarizedTransaction,
         ],
-        "address" => function_examples![
+        "personal_message".to_owned() => function_examples![
+            PersonalMessageVerify
+        ],
+        "schema".to_owned() => function_examples![
+            SchemaDecode,
+            SchemaFunctionSignature,
+        ],
+        "utils".to_owned() => function_examples![
+            UtilsKnownAddress,
+            PayloadRoundTripVerify,
+        ],
+        "address".to_owned() => function_examples![
             AddressEntityType,
             AddressDecode,
+        ],
+        "value".to_owned() => function_examples![
+            ValueAlias,
+            ValueDealias,
+            ValueValidateAgainstSchema,
         ]
-    )
+    );
+
+    // The examples whose inputs are committed to the toolkit as a source asset, with the outputs
+    // obtained by invoking the functions.
+    for (module, functions) in function_example_inputs().unwrap() {
+        let module_examples = function_examples.entry(module).or_default();
+        for function_name in functions.into_keys() {
+            let examples = FunctionExamples::handle(FunctionExamplesInput {
+                function_name: Some(function_name.clone()),
+            })
+            .unwrap()
+            .into_iter()
+            .flat_map(|entry| entry.examples)
+            .map(Into::into);
+            module_examples
+                .entry(function_name)
+                .or_default()
+                .extend(examples);
+        }
+    }
+
+    function_examples
 }
 
 macro_rules! function_examples {
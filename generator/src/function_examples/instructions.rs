@@ -63,6 +63,7 @@ impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for InstructionsCompile {
              }| Self::Input {
                 instructions,
                 network_id,
+                bytes_encoding: SerializableBytesEncoding::Hex,
             },
         )
     }
@@ -72,6 +73,7 @@ impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for InstructionsDecompile {
     fn example_inputs() -> [Self::Input; NUMBER_OF_MANIFESTS_DOUBLE] {
         InstructionsCompile::example_outputs().map(|output| InstructionsDecompileInput {
             compiled: output,
+            bytes_encoding: SerializableBytesEncoding::Hex,
             instructions_kind: SerializableInstructionsKind::String,
             network_id: 0xf2.into(),
         })
@@ -29,7 +29,10 @@ impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for IntentHash {
 
 impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for IntentCompile {
     fn example_inputs() -> [Self::Input; NUMBER_OF_MANIFESTS_DOUBLE] {
-        NotarizedTransactionHash::example_inputs().map(|tx| tx.signed_intent.intent)
+        NotarizedTransactionHash::example_inputs().map(|tx| IntentCompileInput {
+            intent: tx.signed_intent.intent,
+            bytes_encoding: SerializableBytesEncoding::Hex,
+        })
     }
 }
 
@@ -37,6 +40,7 @@ impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for IntentDecompile {
     fn example_inputs() -> [Self::Input; NUMBER_OF_MANIFESTS_DOUBLE] {
         IntentCompile::example_outputs().map(|output| IntentDecompileInput {
             compiled: output,
+            bytes_encoding: SerializableBytesEncoding::Hex,
             instructions_kind: SerializableInstructionsKind::String,
         })
     }
@@ -52,3 +56,42 @@ impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for IntentStaticallyValidat
             })
     }
 }
+
+impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for IntentCompare {
+    fn example_inputs() -> [Self::Input; NUMBER_OF_MANIFESTS_DOUBLE] {
+        let compiled = compiled_intents();
+        std::array::from_fn(|index| IntentCompareInput {
+            compiled_a: compiled[index].clone(),
+            compiled_b: compiled[(index + 1) % NUMBER_OF_MANIFESTS_DOUBLE].clone(),
+            instructions_kind: SerializableInstructionsKind::String,
+        })
+    }
+}
+
+impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for IntentDisplayRecords {
+    fn example_inputs() -> [Self::Input; NUMBER_OF_MANIFESTS_DOUBLE] {
+        compiled_intents().map(|compiled| IntentDisplayRecordsInput {
+            compiled,
+            network_id: 0xf2.into(),
+            max_field_length: 64.into(),
+        })
+    }
+}
+
+impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for IntentSummaryHash {
+    fn example_inputs() -> [Self::Input; NUMBER_OF_MANIFESTS_DOUBLE] {
+        compiled_intents().map(|compiled| IntentSummaryHashInput {
+            compiled,
+            network_id: 0xf2.into(),
+        })
+    }
+}
+
+fn compiled_intents() -> [SerializableBytes; NUMBER_OF_MANIFESTS_DOUBLE] {
+    IntentCompile::example_outputs().map(|output| {
+        SerializableBytesEncoding::Hex
+            .decode(&output)
+            .unwrap()
+            .into()
+    })
+}
@@ -15,7 +15,10 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use radix_engine::blueprints::native_schema::*;
+use radix_engine_common::prelude::*;
 use radix_engine_toolkit::prelude::*;
+use scrypto::blueprints::account::*;
 
 use super::manifest_provider::*;
 use super::traits::HasExamples;
@@ -38,6 +41,7 @@ impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for ManifestCompile {
             .map(|transaction| Self::Input {
                 manifest: transaction,
                 network_id: 0xf2.into(),
+                bytes_encoding: SerializableBytesEncoding::Hex,
             })
     }
 }
@@ -46,6 +50,7 @@ impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for ManifestDecompile {
     fn example_inputs() -> [Self::Input; NUMBER_OF_MANIFESTS_DOUBLE] {
         ManifestCompile::example_outputs().map(|output| ManifestDecompileInput {
             compiled: output,
+            bytes_encoding: SerializableBytesEncoding::Hex,
             instructions_kind: SerializableInstructionsKind::String,
             network_id: 0xf2.into(),
         })
@@ -62,3 +67,26 @@ impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for ManifestStaticallyValid
             })
     }
 }
+
+impl<'f> HasExamples<'f, 1> for ManifestEncodeCallArguments {
+    fn example_inputs() -> [Self::Input; 1] {
+        [Self::Input {
+            package_definition: manifest_encode(&*ACCOUNT_PACKAGE_DEFINITION)
+                .unwrap()
+                .into(),
+            blueprint_name: ACCOUNT_BLUEPRINT.to_owned(),
+            function_name: ACCOUNT_WITHDRAW_IDENT.to_owned(),
+            arguments: vec![
+                SerializableManifestValue::Address {
+                    value: SerializableManifestAddress::Static(SerializableNodeId::new(
+                        XRD.into_node_id(),
+                        0xf2,
+                    )),
+                },
+                SerializableManifestValue::Decimal {
+                    value: dec!("10").into(),
+                },
+            ],
+        }]
+    }
+}
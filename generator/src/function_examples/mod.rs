@@ -25,6 +25,10 @@ pub mod intent;
 pub mod manifest;
 pub mod manifest_provider;
 pub mod notarized_transaction;
+pub mod personal_message;
+pub mod schema;
 pub mod signed_intent;
 pub mod traits;
 pub mod utils;
+pub mod validation;
+pub mod value;
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use radix_engine_common::prelude::PublicKey;
 use radix_engine_common::types::*;
 use radix_engine_toolkit::prelude::*;
 use transaction::prelude::{
@@ -34,7 +35,10 @@ impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for NotarizedTransactionHas
 
 impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for NotarizedTransactionCompile {
     fn example_inputs() -> [Self::Input; NUMBER_OF_MANIFESTS_DOUBLE] {
-        notarized_transactions()
+        notarized_transactions().map(|transaction| NotarizedTransactionCompileInput {
+            notarized_transaction: transaction,
+            bytes_encoding: SerializableBytesEncoding::Hex,
+        })
     }
 }
 
@@ -43,6 +47,7 @@ impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for NotarizedTransactionDec
         NotarizedTransactionCompile::example_outputs().map(|output| {
             NotarizedTransactionDecompileInput {
                 compiled: output,
+                bytes_encoding: SerializableBytesEncoding::Hex,
                 instructions_kind: SerializableInstructionsKind::String,
             }
         })
@@ -58,6 +63,31 @@ impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for NotarizedTransactionSta
     }
 }
 
+impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for NotarizedTransactionVerifyNotary {
+    fn example_inputs() -> [Self::Input; NUMBER_OF_MANIFESTS_DOUBLE] {
+        let notary_public_key = PublicKey::from(notary_private_key().public_key());
+        compiled_notarized_transactions().map(|compiled| Self::Input {
+            compiled,
+            expected_notary_public_key: notary_public_key.into(),
+        })
+    }
+}
+
+impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for TransactionPreflightCheck {
+    fn example_inputs() -> [Self::Input; NUMBER_OF_MANIFESTS_DOUBLE] {
+        notarized_transactions().map(|transaction| Self::Input {
+            notarized_transaction: transaction,
+            validation_config: ValidationConfig::default(0xf2).into(),
+            current_epoch: Some(95.into()),
+            recently_used_nonces: vec![],
+        })
+    }
+}
+
+fn notary_private_key() -> Secp256k1PrivateKey {
+    Secp256k1PrivateKey::from_u64(1).unwrap()
+}
+
 fn build_transaction(instructions: SerializableInstructions) -> SerializableNotarizedTransaction {
     let instructions_kind = match instructions {
         SerializableInstructions::String(..) => SerializableInstructionsKind::String,
@@ -69,7 +99,7 @@ fn build_transaction(instructions: SerializableInstructions) -> SerializableNota
         blobs: Default::default(),
     };
 
-    let notary_private_key = Secp256k1PrivateKey::from_u64(1).unwrap();
+    let notary_private_key = notary_private_key();
     let signer1_private_key = Secp256k1PrivateKey::from_u64(2).unwrap();
     let signer2_private_key = Ed25519PrivateKey::from_u64(2).unwrap();
 
@@ -97,3 +127,12 @@ fn build_transaction(instructions: SerializableInstructions) -> SerializableNota
 pub fn notarized_transactions() -> [SerializableNotarizedTransaction; NUMBER_OF_MANIFESTS_DOUBLE] {
     get_serializable_instructions().map(build_transaction)
 }
+
+pub fn compiled_notarized_transactions() -> [SerializableBytes; NUMBER_OF_MANIFESTS_DOUBLE] {
+    NotarizedTransactionCompile::example_outputs().map(|output| {
+        SerializableBytesEncoding::Hex
+            .decode(&output)
+            .unwrap()
+            .into()
+    })
+}
@@ -0,0 +1,41 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::prelude::*;
+use transaction::prelude::*;
+
+use super::traits::HasExamples;
+
+impl<'f> HasExamples<'f, 2> for PersonalMessageVerify {
+    fn example_inputs() -> [Self::Input; 2] {
+        let message = b"Sign in to the Radix dashboard".to_vec();
+
+        [
+            PrivateKey::Ed25519(Ed25519PrivateKey::from_u64(1).unwrap()),
+            PrivateKey::Secp256k1(Secp256k1PrivateKey::from_u64(1).unwrap()),
+        ]
+        .map(|private_key| Self::Input {
+            message: message.clone().into(),
+            signature: radix_engine_toolkit_core::functions::personal_message::sign(
+                &private_key,
+                &message,
+            )
+            .into(),
+            network_id: 0xf2.into(),
+        })
+    }
+}
@@ -0,0 +1,45 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine::blueprints::native_schema::*;
+use radix_engine_toolkit::prelude::*;
+use scrypto::blueprints::account::*;
+use scrypto::prelude::*;
+
+use super::traits::HasExamples;
+
+impl<'f> HasExamples<'f, 1> for SchemaDecode {
+    fn example_inputs() -> [Self::Input; 1] {
+        [account_package_definition()]
+    }
+}
+
+impl<'f> HasExamples<'f, 2> for SchemaFunctionSignature {
+    fn example_inputs() -> [Self::Input; 2] {
+        [ACCOUNT_CREATE_IDENT, ACCOUNT_WITHDRAW_IDENT].map(|function_name| Self::Input {
+            package_definition: account_package_definition(),
+            blueprint_name: ACCOUNT_BLUEPRINT.to_owned(),
+            function_name: function_name.to_owned(),
+        })
+    }
+}
+
+fn account_package_definition() -> SerializableBytes {
+    manifest_encode(&*ACCOUNT_PACKAGE_DEFINITION)
+        .unwrap()
+        .into()
+}
@@ -29,7 +29,10 @@ impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for SignedIntentHash {
 
 impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for SignedIntentCompile {
     fn example_inputs() -> [Self::Input; NUMBER_OF_MANIFESTS_DOUBLE] {
-        NotarizedTransactionHash::example_inputs().map(|tx| tx.signed_intent)
+        NotarizedTransactionHash::example_inputs().map(|tx| SignedIntentCompileInput {
+            signed_intent: tx.signed_intent,
+            bytes_encoding: SerializableBytesEncoding::Hex,
+        })
     }
 }
 
@@ -37,6 +40,7 @@ impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for SignedIntentDecompile {
     fn example_inputs() -> [Self::Input; NUMBER_OF_MANIFESTS_DOUBLE] {
         SignedIntentCompile::example_outputs().map(|output| SignedIntentDecompileInput {
             compiled: output,
+            bytes_encoding: SerializableBytesEncoding::Hex,
             instructions_kind: SerializableInstructionsKind::String,
         })
     }
@@ -59,6 +59,17 @@ pub struct FunctionExample<I, O> {
     output: O,
 }
 
+impl From<radix_engine_toolkit::functions::information::FunctionExample>
+    for FunctionExample<serde_json::Value, serde_json::Value>
+{
+    fn from(value: radix_engine_toolkit::functions::information::FunctionExample) -> Self {
+        Self {
+            input: serde_json::from_str(&value.input).unwrap(),
+            output: serde_json::from_str(&value.output).unwrap(),
+        }
+    }
+}
+
 fn unwrap_or_panic<O, E>(result: Result<O, E>) -> O {
     match result {
         Ok(value) => value,
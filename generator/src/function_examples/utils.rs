@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use super::manifest_provider::*;
+use super::notarized_transaction::compiled_notarized_transactions;
 use super::traits::HasExamples;
 use radix_engine_toolkit::prelude::*;
 
@@ -27,3 +29,13 @@ impl<'f> HasExamples<'f, 0xFF> for UtilsKnownAddress {
             .unwrap()
     }
 }
+
+impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for PayloadRoundTripVerify {
+    fn example_inputs() -> [Self::Input; NUMBER_OF_MANIFESTS_DOUBLE] {
+        compiled_notarized_transactions().map(|compiled| Self::Input {
+            compiled,
+            payload_kind: SerializablePayloadKind::NotarizedTransaction,
+            instructions_kind: SerializableInstructionsKind::String,
+        })
+    }
+}
@@ -0,0 +1,90 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_common::prelude::*;
+use radix_engine_toolkit::prelude::*;
+use sbor::generate_full_schema_from_single_type;
+use scrypto::blueprints::account::*;
+use transaction::prelude::*;
+
+use super::traits::HasExamples;
+
+impl<'f> HasExamples<'f, 2> for ValueAlias {
+    fn example_inputs() -> [Self::Input; 2] {
+        [
+            SerializableManifestValue::Array {
+                element_value_kind: SerializableManifestValueKind::U8,
+                elements: vec![
+                    SerializableManifestValue::U8 { value: 0x01.into() },
+                    SerializableManifestValue::U8 { value: 0x02.into() },
+                    SerializableManifestValue::U8 { value: 0x03.into() },
+                ],
+            },
+            SerializableManifestValue::Tuple {
+                fields: vec![
+                    SerializableManifestValue::Address {
+                        value: SerializableManifestAddress::Static(SerializableNodeId::new(
+                            VALIDATOR_OWNER_BADGE.into_node_id(),
+                            0xf2,
+                        )),
+                    },
+                    SerializableManifestValue::NonFungibleLocalId {
+                        value: NonFungibleLocalId::integer(1).into(),
+                    },
+                ],
+            },
+        ]
+    }
+}
+
+impl<'f> HasExamples<'f, 2> for ValueDealias {
+    fn example_inputs() -> [Self::Input; 2] {
+        ValueAlias::example_outputs()
+    }
+}
+
+impl<'f> HasExamples<'f, 2> for ValueValidateAgainstSchema {
+    fn example_inputs() -> [Self::Input; 2] {
+        let (local_type_id, VersionedSchema::V1(schema)) =
+            generate_full_schema_from_single_type::<AccountWithdrawInput, ScryptoCustomSchema>();
+        let schema = PayloadSchema {
+            local_type_id: local_type_id.into(),
+            schema: scrypto_encode(&schema).unwrap().into(),
+        };
+
+        let resource_address = SerializableManifestValue::Address {
+            value: SerializableManifestAddress::Static(SerializableNodeId::new(
+                XRD.into_node_id(),
+                0xf2,
+            )),
+        };
+        [
+            SerializableManifestValue::Decimal {
+                value: dec!("10").into(),
+            },
+            SerializableManifestValue::String {
+                value: "10".to_owned(),
+            },
+        ]
+        .map(|amount| Self::Input {
+            value: SerializableManifestValue::Tuple {
+                fields: vec![resource_address.clone(), amount],
+            },
+            schema: schema.clone(),
+        })
+    }
+}
@@ -24,16 +24,31 @@ pub fn generate_function_schema(
     indexmap!(
         "information" => function_schema![
             BuildInformation,
+            MethodsThatRequireAuth,
+            FunctionCatalog,
+            FunctionExamples,
+            MemoryStats,
+            FreeCache,
+            AddressCacheConfigure,
+        ],
+        "context" => function_schema![
+            ContextCreate,
+            ContextDestroy,
         ],
         "derive" => function_schema![
             DeriveVirtualAccountAddressFromPublicKey,
             DeriveVirtualIdentityAddressFromPublicKey,
             DeriveVirtualSignatureNonFungibleGlobalIdFromPublicKey,
+            DeriveVirtualSignatureNonFungibleGlobalIdFromPublicKeyHash,
+            DeriveVirtualGlobalAddressFromPublicKeyHash,
+            DerivePublicKeyHashFromVirtualSignatureNonFungibleGlobalId,
             DeriveVirtualAccountAddressFromOlympiaAccountAddress,
             DeriveResourceAddressFromOlympiaResourceAddress,
             DerivePublicKeyFromOlympiaAccountAddress,
             DeriveOlympiaAccountAddressFromPublicKey,
             DeriveNodeAddressFromPublicKey,
+            DeriveNonce,
+            DeriveTransactionTrackerKey,
         ],
         "instructions" => function_schema![
             InstructionsHash,
@@ -42,46 +57,164 @@ pub fn generate_function_schema(
             InstructionsDecompile,
             InstructionsStaticallyValidate,
             InstructionsExtractAddresses,
+            InstructionsExtractAddressesByRole,
+            InstructionsExtractRequiredSigners,
+            InstructionsExtractMaximumOutflows,
+            InstructionsSimulateDeposits,
+            InstructionsAuthorizedDepositorBadges,
+            InstructionsNonAccountTransfers,
+            InstructionsExtractComments,
+            InstructionsAttachComments,
+            InstructionsLineRanges,
+            InstructionsCompileDiagnostics,
         ],
         "execution" => function_schema![
-            ExecutionAnalyze
+            ExecutionAnalyze,
+            ExecutionVerifyAttestation,
+            ExecutionExtractAccountResourceMovements,
+            ExecutionMinimumXrdRequiredPerAccount,
+            ExecutionLint
+        ],
+        "events" => function_schema![
+            EventsCompileFilter
+        ],
+        "gateway" => function_schema![
+            GatewaySummarizeCommittedTransaction
         ],
         "manifest" => function_schema![
             ManifestHash,
+            ManifestDeduplicationKey,
             ManifestCompile,
             ManifestDecompile,
+            ManifestConvert,
             ManifestStaticallyValidate,
+            ManifestEncodeCallArguments,
+            ManifestEncodeMetadataInit,
+            ManifestFaucetFreeXrd,
+            ManifestModifyAddGuarantees,
+            ManifestModifySetLockFee,
+            ManifestModifySponsorFee,
+            ManifestExtractFeeSponsorship,
+            ManifestModifyInstructions,
+            ManifestEnforcePolicy,
+            ManifestValidateFeeLocks,
+            ManifestValidateIdAllocation,
+            ManifestResolveIncludes,
+        ],
+        "legacy" => function_schema![
+            LegacyValueUpgrade,
+            LegacyValueDowngrade,
+            LegacyInstructionsUpgrade,
+            LegacyInstructionsDowngrade,
         ],
         "intent" => function_schema![
             IntentHash,
             IntentCompile,
             IntentDecompile,
             IntentStaticallyValidate,
+            IntentRequiredSigningKeys,
+            IntentDisplayRecords,
+            IntentSummaryHash,
+            IntentCompare,
         ],
         "signed_intent" => function_schema![
             SignedIntentHash,
             SignedIntentCompile,
             SignedIntentDecompile,
             SignedIntentStaticallyValidate,
+            SignedIntentNormalizeSignatures,
+            SignedIntentNotarySignatoryIssues,
         ],
         "notarized_transaction" => function_schema![
             NotarizedTransactionHash,
             NotarizedTransactionCompile,
             NotarizedTransactionDecompile,
             NotarizedTransactionStaticallyValidate,
+            NotarizedTransactionSubmissionEnvelope,
+            TransactionPreflightCheck,
+            NotarizedTransactionVerifyNotary,
+        ],
+        "validation" => function_schema![
+            ValidationHeader,
+            ValidationIntentSignatureCount,
+            ValidationMessage,
+            ValidationManifestDepth,
+            ValidationIdAllocation,
+            ValidationNotarizedPayloadSize,
+            ValidationIntent,
+            ValidationSignedIntent,
+            ValidationNotarizedTransaction,
+        ],
+        "transaction_request" => function_schema![
+            TransactionRequestNormalize,
+            TransactionRequestValidate,
+            TransactionRequestToManifest,
+            TransactionRequestFromManifest,
+        ],
+        "dapp_definition" => function_schema![
+            DappDefinitionVerifyLink,
+            DappDefinitionReferencedDappDefinitions,
+        ],
+        "olympia" => function_schema![
+            OlympiaTransactionDecode,
         ],
         "utils" => function_schema![
-            UtilsKnownAddress
+            UtilsKnownAddress,
+            UtilsKnownAddressesBatch,
+            UtilsRegisterNetwork,
+            UtilsDeregisterNetwork,
+            UtilsNetworks,
+            PayloadRoundTripVerify,
+        ],
+        "schema" => function_schema![
+            SchemaDecode,
+            SchemaFunctionSignature,
         ],
         "scrypto_sbor" => function_schema![
             ScryptoSborDecodeToString
         ],
         "manifest_sbor" => function_schema![
-            ManifestSborDecodeToString
+            ManifestSborDecodeToString,
+            ManifestSborEncodeStringRepresentation
+        ],
+        "sbor" => function_schema![
+            SborDecodeUnknown
+        ],
+        "native_state" => function_schema![
+            NativeStateDecode
+        ],
+        "hashing" => function_schema![
+            HashingHash
+        ],
+        "header" => function_schema![
+            HeaderEpochRangeFromValidityDuration,
+            HeaderValidityDurationFromEpochRange
+        ],
+        "personal_message" => function_schema![
+            PersonalMessageHash,
+            PersonalMessageVerify
+        ],
+        "public_key" => function_schema![
+            PublicKeyValidate,
+            PublicKeySecp256k1Compress,
+            PublicKeySecp256k1Decompress,
         ],
         "address" => function_schema![
             AddressEntityType,
             AddressDecode,
+            AddressValidate,
+            AddressAbbreviate,
+            AddressIsLookAlike,
+        ],
+        "amount" => function_schema![
+            AmountFormat,
+            AmountXrdFormatOptions,
+            AmountParse,
+        ],
+        "value" => function_schema![
+            ValueAlias,
+            ValueDealias,
+            ValueValidateAgainstSchema,
         ]
     )
 }
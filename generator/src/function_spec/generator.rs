@@ -21,41 +21,137 @@ use rocket_okapi::okapi::openapi3::OpenApi;
 pub fn generate_function_spec() -> OpenApi {
     open_api_spec![
         BuildInformation,
+        MethodsThatRequireAuth,
+        FunctionCatalog,
+        FunctionExamples,
+        MemoryStats,
+        FreeCache,
+        AddressCacheConfigure,
+        ContextCreate,
+        ContextDestroy,
         DeriveVirtualAccountAddressFromPublicKey,
         DeriveVirtualIdentityAddressFromPublicKey,
         DeriveVirtualSignatureNonFungibleGlobalIdFromPublicKey,
+        DeriveVirtualSignatureNonFungibleGlobalIdFromPublicKeyHash,
+        DeriveVirtualGlobalAddressFromPublicKeyHash,
+        DerivePublicKeyHashFromVirtualSignatureNonFungibleGlobalId,
         DeriveVirtualAccountAddressFromOlympiaAccountAddress,
         DeriveResourceAddressFromOlympiaResourceAddress,
         DerivePublicKeyFromOlympiaAccountAddress,
         DeriveOlympiaAccountAddressFromPublicKey,
         DeriveNodeAddressFromPublicKey,
+        DeriveNonce,
+        DeriveTransactionTrackerKey,
         InstructionsHash,
         InstructionsConvert,
         InstructionsCompile,
         InstructionsDecompile,
         InstructionsStaticallyValidate,
         InstructionsExtractAddresses,
+        InstructionsExtractAddressesByRole,
+        InstructionsExtractRequiredSigners,
+        InstructionsExtractMaximumOutflows,
+        InstructionsSimulateDeposits,
+        InstructionsAuthorizedDepositorBadges,
+        InstructionsNonAccountTransfers,
+        InstructionsExtractComments,
+        InstructionsAttachComments,
+        InstructionsLineRanges,
+        InstructionsCompileDiagnostics,
         ExecutionAnalyze,
+        ExecutionVerifyAttestation,
+        ExecutionExtractAccountResourceMovements,
+        ExecutionMinimumXrdRequiredPerAccount,
+        ExecutionLint,
+        EventsCompileFilter,
+        GatewaySummarizeCommittedTransaction,
         ManifestHash,
+        ManifestDeduplicationKey,
         ManifestCompile,
         ManifestDecompile,
+        ManifestConvert,
         ManifestStaticallyValidate,
+        ManifestEncodeCallArguments,
+        ManifestEncodeMetadataInit,
+        ManifestFaucetFreeXrd,
+        ManifestModifyAddGuarantees,
+        ManifestModifySetLockFee,
+        ManifestModifySponsorFee,
+        ManifestExtractFeeSponsorship,
+        ManifestModifyInstructions,
+        ManifestEnforcePolicy,
+        ManifestValidateFeeLocks,
+        ManifestValidateIdAllocation,
+        ManifestResolveIncludes,
+        LegacyValueUpgrade,
+        LegacyValueDowngrade,
+        LegacyInstructionsUpgrade,
+        LegacyInstructionsDowngrade,
         IntentHash,
         IntentCompile,
         IntentDecompile,
         IntentStaticallyValidate,
+        IntentRequiredSigningKeys,
+        IntentDisplayRecords,
+        IntentSummaryHash,
+        IntentCompare,
         SignedIntentHash,
         SignedIntentCompile,
         SignedIntentDecompile,
         SignedIntentStaticallyValidate,
+        SignedIntentNormalizeSignatures,
+        SignedIntentNotarySignatoryIssues,
         NotarizedTransactionHash,
         NotarizedTransactionCompile,
         NotarizedTransactionDecompile,
         NotarizedTransactionStaticallyValidate,
+        NotarizedTransactionSubmissionEnvelope,
+        TransactionPreflightCheck,
+        NotarizedTransactionVerifyNotary,
+        ValidationHeader,
+        ValidationIntentSignatureCount,
+        ValidationMessage,
+        ValidationManifestDepth,
+        ValidationIdAllocation,
+        ValidationNotarizedPayloadSize,
+        ValidationIntent,
+        ValidationSignedIntent,
+        ValidationNotarizedTransaction,
+        TransactionRequestNormalize,
+        TransactionRequestValidate,
+        TransactionRequestToManifest,
+        TransactionRequestFromManifest,
+        DappDefinitionVerifyLink,
+        DappDefinitionReferencedDappDefinitions,
+        OlympiaTransactionDecode,
         UtilsKnownAddress,
+        UtilsKnownAddressesBatch,
+        UtilsRegisterNetwork,
+        UtilsDeregisterNetwork,
+        UtilsNetworks,
+        PayloadRoundTripVerify,
+        SchemaDecode,
+        SchemaFunctionSignature,
         ScryptoSborDecodeToString,
         ScryptoSborEncodeStringRepresentation,
         ManifestSborDecodeToString,
+        ManifestSborEncodeStringRepresentation,
+        SborDecodeUnknown,
+        NativeStateDecode,
+        HashingHash,
+        HeaderEpochRangeFromValidityDuration,
+        HeaderValidityDurationFromEpochRange,
+        PersonalMessageHash,
+        PersonalMessageVerify,
+        PublicKeyValidate,
+        PublicKeySecp256k1Compress,
+        PublicKeySecp256k1Decompress,
+        AmountFormat,
+        AmountXrdFormatOptions,
+        AmountParse,
+        ValueAlias,
+        ValueDealias,
+        ValueValidateAgainstSchema,
     ]
 }
 
@@ -18,18 +18,78 @@
 mod function_examples;
 mod function_schema;
 mod function_spec;
+mod models;
 mod serializable_models;
+mod test_vectors;
 mod utils;
 
 use crate::function_examples::generator::generate_function_examples;
 use function_schema::generator::generate_function_schema;
 use function_spec::generator::generate_function_spec;
+use models::generator::{generate_models, ModelLanguage};
 use serializable_models::generator::generate_serializable_model_examples;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use test_vectors::generator::{generate_test_vectors, verify_test_vectors};
 use utils::convert_open_api_adts_to_discriminated_unions;
 
 fn main() {
+    // Generating or verifying the test vectors of a directory of manifests:
+    // `generator test-vectors <manifests directory> <output directory> [--verify]`
+    let arguments = std::env::args().skip(1).collect::<Vec<_>>();
+    if let Some("test-vectors") = arguments.first().map(String::as_str) {
+        let [_, manifests_directory, output_directory, rest @ ..] = arguments.as_slice() else {
+            panic!(
+                "Usage: generator test-vectors <manifests directory> <output directory> [--verify]"
+            )
+        };
+        let manifests_directory = Path::new(manifests_directory).canonicalize().unwrap();
+        let output_directory = PathBuf::from(output_directory);
+
+        if rest.iter().any(|argument| argument == "--verify") {
+            let mismatches = verify_test_vectors(&manifests_directory, &output_directory);
+            for mismatch in mismatches.iter() {
+                eprintln!("{}: {}", mismatch.fixture_path.display(), mismatch.reason);
+            }
+            if !mismatches.is_empty() {
+                std::process::exit(1);
+            }
+        } else {
+            generate_test_vectors(&manifests_directory, &output_directory);
+        }
+        return;
+    }
+
+    // Generating the models of a host SDK language:
+    // `generator generate-models --language <ts|kotlin|swift|jsonschema> [output directory]`
+    if let Some("generate-models") = arguments.first().map(String::as_str) {
+        let usage = "Usage: generator generate-models --language <ts|kotlin|swift|jsonschema> [output directory]";
+        let [_, flag, language, rest @ ..] = arguments.as_slice() else {
+            panic!("{usage}")
+        };
+        if flag != "--language" || rest.len() > 1 {
+            panic!("{usage}")
+        }
+        let language = ModelLanguage::from_str(language).unwrap_or_else(|error| panic!("{error}"));
+
+        let workspace_directory = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .to_path_buf();
+        let output_directory = rest.first().map(PathBuf::from).unwrap_or_else(|| {
+            PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .join("output")
+                .join("models")
+                .join(language.directory_name())
+        });
+
+        if let Err(error) = generate_models(language, &workspace_directory, &output_directory) {
+            eprintln!("{error}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
     let output_directory = {
         let manifest_directory = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
         manifest_directory.join("output")
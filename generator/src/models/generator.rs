@@ -0,0 +1,152 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Generates the models of the toolkit for the host SDKs. The JSON Schema models are generated
+//! from the schemars annotations while the models of the other languages are generated by running
+//! the typeshare CLI over the workspace. In all cases the function catalog and the version of the
+//! toolkit are written next to the models so that SDKs can pin to a toolkit version.
+
+use std::path::Path;
+use std::process::Command;
+use std::str::FromStr;
+
+use radix_engine_toolkit::prelude::*;
+
+use crate::function_schema::generator::generate_function_schema;
+
+/// The package that the generated Kotlin models are placed in.
+const KOTLIN_PACKAGE: &str = "com.radixdlt.ret.models";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModelLanguage {
+    TypeScript,
+    Kotlin,
+    Swift,
+    JsonSchema,
+}
+
+impl FromStr for ModelLanguage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ts" | "typescript" => Ok(Self::TypeScript),
+            "kotlin" => Ok(Self::Kotlin),
+            "swift" => Ok(Self::Swift),
+            "jsonschema" => Ok(Self::JsonSchema),
+            _ => Err(format!(
+                "Unsupported language \"{s}\", expected one of: ts, kotlin, swift, jsonschema"
+            )),
+        }
+    }
+}
+
+impl ModelLanguage {
+    pub fn directory_name(&self) -> &'static str {
+        match self {
+            Self::TypeScript => "typescript",
+            Self::Kotlin => "kotlin",
+            Self::Swift => "swift",
+            Self::JsonSchema => "jsonschema",
+        }
+    }
+}
+
+/// Generates the models of the given language into the output directory. The output only depends
+/// on the source of the workspace so running it twice on the same version yields the same files.
+pub fn generate_models(
+    language: ModelLanguage,
+    workspace_directory: &Path,
+    output_directory: &Path,
+) -> Result<(), String> {
+    std::fs::create_dir_all(output_directory).map_err(|error| error.to_string())?;
+
+    match language {
+        ModelLanguage::JsonSchema => generate_json_schema_models(output_directory)?,
+        ModelLanguage::TypeScript => run_typeshare(
+            workspace_directory,
+            "typescript",
+            &output_directory.join("models.ts"),
+            &[],
+        )?,
+        ModelLanguage::Kotlin => run_typeshare(
+            workspace_directory,
+            "kotlin",
+            &output_directory.join("Models.kt"),
+            &[format!("--java-package={KOTLIN_PACKAGE}")],
+        )?,
+        ModelLanguage::Swift => run_typeshare(
+            workspace_directory,
+            "swift",
+            &output_directory.join("Models.swift"),
+            &[],
+        )?,
+    }
+
+    let function_catalog = FunctionCatalog::handle(FunctionCatalogInput {})
+        .map_err(|error| format!("Failed to generate the function catalog: {error:?}"))?;
+    write_json(
+        &output_directory.join("function_catalog.json"),
+        &function_catalog,
+    )?;
+
+    let build_information = BuildInformation::handle(BuildInformationInput {})
+        .map_err(|error| format!("Failed to get the build information: {error:?}"))?;
+    write_json(
+        &output_directory.join("build_information.json"),
+        &build_information,
+    )
+}
+
+/// Writes the JSON Schema of the input and output of every function, grouped by module.
+fn generate_json_schema_models(output_directory: &Path) -> Result<(), String> {
+    for (module, functions) in generate_function_schema() {
+        for (function_name, (input_schema, output_schema)) in functions {
+            let output_directory = output_directory.join(module).join(function_name);
+            std::fs::create_dir_all(&output_directory).map_err(|error| error.to_string())?;
+
+            write_json(&output_directory.join("Input.json"), &input_schema)?;
+            write_json(&output_directory.join("Output.json"), &output_schema)?;
+        }
+    }
+    Ok(())
+}
+
+fn run_typeshare(
+    workspace_directory: &Path,
+    language: &str,
+    output_file: &Path,
+    extra_arguments: &[String],
+) -> Result<(), String> {
+    let status = Command::new("typeshare")
+        .arg(workspace_directory)
+        .arg(format!("--lang={language}"))
+        .arg(format!("--output-file={}", output_file.display()))
+        .args(extra_arguments)
+        .status()
+        .map_err(|error| format!("Failed to run typeshare, is it installed? {error}"))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("typeshare exited with {status}"))
+    }
+}
+
+fn write_json<T: serde::Serialize>(path: &Path, value: &T) -> Result<(), String> {
+    let serialized = serde_json::to_string_pretty(value).map_err(|error| error.to_string())?;
+    std::fs::write(path, serialized + "\n").map_err(|error| error.to_string())
+}
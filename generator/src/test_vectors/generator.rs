@@ -0,0 +1,230 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Generation and verification of conformance test vectors. Each `.rtm` manifest in the input
+//! directory is turned into a JSON fixture holding the header and manifest that were used along
+//! with the compiled intent, the intent hash, and the static analysis of the instructions. SDKs in
+//! other languages can then check that they produce the same outputs as the toolkit does.
+//!
+//! The header of a manifest is read from a `<name>.header.json` file next to it if one exists and
+//! defaults to [`default_header`] otherwise. Blobs are not supported: manifests referencing blobs
+//! are compiled with the blob references but without the blobs themselves.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use radix_engine_toolkit::prelude::*;
+use radix_engine_toolkit_core::utils::network_definition_from_network_id;
+use serde::{Deserialize, Serialize};
+use transaction::manifest::*;
+use transaction::prelude::{Epoch, Secp256k1PrivateKey, TransactionHeaderV1};
+use walkdir::WalkDir;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TestVector {
+    pub header: SerializableTransactionHeader,
+    pub manifest: String,
+    pub compiled_intent: String,
+    pub intent_hash: SerializableTransactionHash,
+    pub addresses: HashMap<SerializableEntityType, Vec<SerializableNodeId>>,
+    pub named_addresses: Vec<SerializableU32>,
+    pub required_signers: InstructionsExtractRequiredSignersOutput,
+}
+
+/// A test vector whose fixture on disk does not match the one produced by the toolkit.
+#[derive(Clone, Debug)]
+pub struct TestVectorMismatch {
+    pub fixture_path: PathBuf,
+    pub reason: String,
+}
+
+/// The header used for manifests that do not come with a header file of their own.
+pub fn default_header() -> SerializableTransactionHeader {
+    TransactionHeaderV1 {
+        network_id: 0xf2,
+        start_epoch_inclusive: Epoch::of(90),
+        end_epoch_exclusive: Epoch::of(100),
+        nonce: 100,
+        notary_public_key: Secp256k1PrivateKey::from_u64(1)
+            .unwrap()
+            .public_key()
+            .into(),
+        notary_is_signatory: true,
+        tip_percentage: 0,
+    }
+    .into()
+}
+
+/// Produces the test vector of the manifest at the given path.
+pub fn generate_test_vector(manifest_path: &Path) -> TestVector {
+    let manifest = std::fs::read_to_string(manifest_path).unwrap();
+    let header = {
+        let header_path = manifest_path.with_extension("header.json");
+        if header_path.exists() {
+            serde_json::from_str(&std::fs::read_to_string(header_path).unwrap()).unwrap()
+        } else {
+            default_header()
+        }
+    };
+    let network_id = *header.network_id;
+
+    let compiled_manifest = compile(
+        &manifest,
+        &network_definition_from_network_id(network_id),
+        MockBlobProvider::new(),
+    )
+    .unwrap();
+    let instructions = SerializableInstructions::Parsed(
+        to_serializable_instructions(&compiled_manifest.instructions, network_id).unwrap(),
+    );
+
+    let intent = SerializableIntent {
+        header: header.clone(),
+        manifest: SerializableTransactionManifest {
+            instructions: instructions.clone(),
+            blobs: vec![],
+        },
+        message: SerializableMessage::None,
+    };
+    let compiled_intent = IntentCompile::handle(IntentCompileInput {
+        intent: intent.clone(),
+        bytes_encoding: SerializableBytesEncoding::Hex,
+    })
+    .unwrap();
+    let intent_hash = IntentHash::handle(intent).unwrap();
+
+    let InstructionsExtractAddressesOutput {
+        mut addresses,
+        named_addresses,
+    } = InstructionsExtractAddresses::handle(InstructionsExtractAddressesInput {
+        instructions: instructions.clone(),
+        network_id: network_id.into(),
+    })
+    .unwrap();
+    for node_ids in addresses.values_mut() {
+        node_ids.sort_by_key(|node_id| node_id.0.to_string());
+    }
+    let mut named_addresses = named_addresses
+        .into_iter()
+        .map(|named_address| *named_address)
+        .collect::<Vec<_>>();
+    named_addresses.sort();
+    let required_signers =
+        InstructionsExtractRequiredSigners::handle(InstructionsExtractRequiredSignersInput {
+            instructions,
+            network_id: network_id.into(),
+        })
+        .unwrap();
+
+    TestVector {
+        header,
+        manifest,
+        compiled_intent,
+        intent_hash,
+        addresses,
+        named_addresses: named_addresses.into_iter().map(Into::into).collect(),
+        required_signers,
+    }
+}
+
+/// Generates the test vectors of all of the manifests in the manifests directory and writes them
+/// to the output directory, mirroring the directory structure of the manifests directory.
+pub fn generate_test_vectors(manifests_directory: &Path, output_directory: &Path) {
+    for (manifest_path, fixture_path) in fixture_paths(manifests_directory, output_directory) {
+        let test_vector = to_canonical_json(&generate_test_vector(&manifest_path));
+
+        std::fs::create_dir_all(fixture_path.parent().unwrap()).unwrap();
+        std::fs::write(
+            fixture_path,
+            serde_json::to_string_pretty(&test_vector).unwrap(),
+        )
+        .unwrap();
+    }
+}
+
+/// Regenerates the test vectors of all of the manifests in the manifests directory and compares
+/// them against the fixtures in the output directory, returning the fixtures which are missing or
+/// do not match.
+pub fn verify_test_vectors(
+    manifests_directory: &Path,
+    output_directory: &Path,
+) -> Vec<TestVectorMismatch> {
+    let mut mismatches = vec![];
+    for (manifest_path, fixture_path) in fixture_paths(manifests_directory, output_directory) {
+        let expected = to_canonical_json(&generate_test_vector(&manifest_path));
+        let reason = match std::fs::read_to_string(&fixture_path)
+            .map_err(|error| format!("Failed to read the fixture: {error}"))
+            .and_then(|fixture| {
+                serde_json::from_str::<serde_json::Value>(&fixture)
+                    .map_err(|error| format!("Failed to parse the fixture: {error}"))
+            }) {
+            Ok(actual) if to_canonical_json(&actual) == expected => continue,
+            Ok(..) => "The fixture does not match the generated test vector".to_owned(),
+            Err(reason) => reason,
+        };
+        mismatches.push(TestVectorMismatch {
+            fixture_path,
+            reason,
+        })
+    }
+    mismatches
+}
+
+/// Maps each of the manifests in the manifests directory to the path of its fixture in the output
+/// directory. The manifests are sorted by their path so that generation is deterministic.
+fn fixture_paths(manifests_directory: &Path, output_directory: &Path) -> Vec<(PathBuf, PathBuf)> {
+    let mut manifest_paths = WalkDir::new(manifests_directory)
+        .into_iter()
+        .map(|entry| entry.unwrap().into_path())
+        .filter(|path| path.extension().and_then(|str| str.to_str()) == Some("rtm"))
+        .collect::<Vec<_>>();
+    manifest_paths.sort();
+
+    manifest_paths
+        .into_iter()
+        .map(|manifest_path| {
+            let fixture_path = output_directory
+                .join(manifest_path.strip_prefix(manifests_directory).unwrap())
+                .with_extension("json");
+            (manifest_path, fixture_path)
+        })
+        .collect()
+}
+
+/// Converts the value to JSON with the keys of all objects sorted so that values holding hash maps
+/// always serialize to the same string.
+fn to_canonical_json<T: Serialize>(value: &T) -> serde_json::Value {
+    fn canonicalize(value: serde_json::Value) -> serde_json::Value {
+        match value {
+            serde_json::Value::Object(map) => {
+                let mut entries = map.into_iter().collect::<Vec<_>>();
+                entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+                serde_json::Value::Object(
+                    entries
+                        .into_iter()
+                        .map(|(key, value)| (key, canonicalize(value)))
+                        .collect(),
+                )
+            }
+            serde_json::Value::Array(values) => {
+                serde_json::Value::Array(values.into_iter().map(canonicalize).collect())
+            }
+            value => value,
+        }
+    }
+    canonicalize(serde_json::to_value(value).unwrap())
+}
@@ -0,0 +1,105 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use criterion::*;
+use radix_engine::transaction::*;
+use radix_engine_toolkit_core::functions::execution::{self, *};
+use radix_engine_toolkit_core::functions::manifest;
+use scrypto::prelude::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+const PAYLOAD_SIZES: [usize; 3] = [1, 10, 100];
+
+fn manifest_benchmarks(c: &mut Criterion) {
+    let public_key = Secp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let account = ComponentAddress::virtual_account_from_public_key(&public_key);
+
+    let mut group = c.benchmark_group("manifest");
+    for payload_size in PAYLOAD_SIZES {
+        let manifest = transfer_manifest(account, account, payload_size);
+        let compiled = manifest::compile(&manifest).unwrap();
+
+        group.bench_with_input(
+            BenchmarkId::new("compile", payload_size),
+            &manifest,
+            |b, manifest| b.iter(|| manifest::compile(black_box(manifest))),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("decompile", payload_size),
+            &compiled,
+            |b, compiled| b.iter(|| manifest::decompile(black_box(compiled))),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("statically_validate", payload_size),
+            &manifest,
+            |b, manifest| b.iter(|| manifest::statically_validate(black_box(manifest))),
+        );
+    }
+    group.finish();
+}
+
+fn execution_benchmarks(c: &mut Criterion) {
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key1, _, account1) = test_runner.new_account(true);
+    let (public_key2, _, account2) = test_runner.new_account(true);
+
+    let mut group = c.benchmark_group("execution");
+    for payload_size in PAYLOAD_SIZES {
+        let manifest = transfer_manifest(account1, account2, payload_size);
+        let receipt = test_runner.preview_manifest(
+            manifest.clone(),
+            vec![public_key1.into(), public_key2.into()],
+            0,
+            PreviewFlags {
+                use_free_credit: true,
+                assume_all_signature_proofs: true,
+                skip_epoch_check: true,
+            },
+        );
+        receipt.expect_commit_success();
+
+        let receipt = VersionedTransactionReceipt::V1(receipt);
+        let receipt = ExecutionAnalysisTransactionReceipt::new(&receipt).unwrap();
+
+        group.bench_with_input(
+            BenchmarkId::new("analyze", payload_size),
+            &manifest,
+            |b, manifest| {
+                b.iter(|| execution::analyze(black_box(&manifest.instructions), &receipt))
+            },
+        );
+    }
+    group.finish();
+}
+
+fn transfer_manifest(
+    from: ComponentAddress,
+    to: ComponentAddress,
+    payload_size: usize,
+) -> TransactionManifestV1 {
+    let mut builder = ManifestBuilder::new();
+    for _ in 0..payload_size {
+        builder = builder.withdraw_from_account(from, XRD, dec!("1"));
+    }
+    builder
+        .try_deposit_entire_worktop_or_abort(to, None)
+        .build()
+}
+
+criterion_group!(benches, manifest_benchmarks, execution_benchmarks);
+criterion_main!(benches);
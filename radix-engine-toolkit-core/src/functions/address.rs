@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use bech32::FromBase32;
 use radix_engine_common::prelude::*;
 
 use crate::models::node_id::*;
@@ -34,3 +35,193 @@ pub fn decode(node_id: &str) -> Option<(u8, EntityType, String, [u8; 30])> {
         .map(|data| (network_id, entity_type, hrp, data))
         .ok()
 }
+
+/// Validates an address against the expected network and entity types returning all of the issues
+/// found with it. An empty list of issues means that the address is valid. When no expected
+/// network or entity types are given then they're not checked.
+pub fn validate(
+    address: &str,
+    expected_network_id: Option<u8>,
+    expected_entity_types: &[EntityType],
+) -> Vec<AddressValidationIssue> {
+    let (hrp, data, variant) = match bech32::decode(address) {
+        Ok(decoded) => decoded,
+        Err(bech32::Error::InvalidChecksum) => {
+            return vec![AddressValidationIssue::InvalidChecksum]
+        }
+        Err(error) => return vec![AddressValidationIssue::InvalidBech32(format!("{error:?}"))],
+    };
+    if variant != bech32::Variant::Bech32m {
+        return vec![AddressValidationIssue::InvalidVariant];
+    }
+    let Ok(data) = Vec::<u8>::from_base32(&data) else {
+        return vec![AddressValidationIssue::InvalidBech32(
+            "Invalid padding of the data".to_owned(),
+        )];
+    };
+    let node_id = match <[u8; NodeId::LENGTH]>::try_from(data) {
+        Ok(data) => NodeId(data),
+        Err(data) => {
+            return vec![AddressValidationIssue::InvalidLength {
+                expected: NodeId::LENGTH,
+                actual: data.len(),
+            }]
+        }
+    };
+    let Some(entity_type) = node_id.entity_type() else {
+        return vec![AddressValidationIssue::InvalidEntityType(node_id.0[0])];
+    };
+
+    let mut issues = vec![];
+
+    match network_id_from_hrp(&hrp) {
+        Some(network_id) => {
+            if let Some(expected) = expected_network_id.filter(|expected| *expected != network_id) {
+                issues.push(AddressValidationIssue::WrongNetwork {
+                    expected,
+                    actual: network_id,
+                });
+            }
+
+            let expected_hrp = HrpSet::from(&network_definition_from_network_id(network_id))
+                .get_entity_hrp(&entity_type)
+                .to_owned();
+            if expected_hrp != hrp {
+                issues.push(AddressValidationIssue::HrpMismatch {
+                    expected: expected_hrp,
+                    actual: hrp,
+                });
+            }
+        }
+        None => issues.push(AddressValidationIssue::UnknownNetwork(hrp)),
+    }
+
+    if !expected_entity_types.is_empty() && !expected_entity_types.contains(&entity_type) {
+        issues.push(AddressValidationIssue::WrongEntityType {
+            expected: expected_entity_types.to_vec(),
+            actual: entity_type,
+        });
+    }
+
+    issues
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AddressValidationIssue {
+    /// The address is not a valid Bech32 string.
+    InvalidBech32(String),
+    /// The checksum of the address does not match its content, typically the result of a typo.
+    InvalidChecksum,
+    /// The address is encoded as Bech32 rather than Bech32m.
+    InvalidVariant,
+    /// The address does not encode a node id of the expected length.
+    InvalidLength { expected: usize, actual: usize },
+    /// The first byte of the address is not a known entity type.
+    InvalidEntityType(u8),
+    /// The HRP of the address does not belong to any known network.
+    UnknownNetwork(String),
+    /// The address belongs to a network other than the expected one.
+    WrongNetwork { expected: u8, actual: u8 },
+    /// The HRP of the address does not match the HRP of its entity type on its network.
+    HrpMismatch { expected: String, actual: String },
+    /// The address is of an entity type other than the expected ones.
+    WrongEntityType {
+        expected: Vec<EntityType>,
+        actual: EntityType,
+    },
+}
+
+/// Abbreviates the address for display by keeping its HRP, the first `leading` and the last
+/// `trailing` characters of its data part. Since different addresses can share an abbreviation,
+/// a short checksum marker derived from the hash of the full address is returned alongside it so
+/// that look-alike addresses can still be told apart at a glance.
+pub fn abbreviate(address: &str, leading: usize, trailing: usize) -> AbbreviatedAddress {
+    let (hrp, data) = split_address(address);
+    let characters = data.chars().collect::<Vec<_>>();
+
+    let abbreviated = if leading + trailing >= characters.len() {
+        address.to_owned()
+    } else {
+        let first = characters[..leading].iter().collect::<String>();
+        let last = characters[characters.len() - trailing..]
+            .iter()
+            .collect::<String>();
+        match hrp {
+            Some(hrp) => format!("{hrp}1{first}...{last}"),
+            None => format!("{first}...{last}"),
+        }
+    };
+
+    AbbreviatedAddress {
+        abbreviated,
+        checksum_marker: checksum_marker(address),
+    }
+}
+
+/// Determines whether two distinct addresses look alike: they share an HRP and at least their
+/// first `leading` and last `trailing` data characters, and would therefore be displayed the same
+/// when abbreviated. Such pairs are what address-poisoning attacks rely on.
+pub fn is_look_alike(address1: &str, address2: &str, leading: usize, trailing: usize) -> bool {
+    if address1 == address2 {
+        return false;
+    }
+
+    let (hrp1, _) = split_address(address1);
+    let (hrp2, _) = split_address(address2);
+    let similarity = similarity(address1, address2);
+    hrp1 == hrp2
+        && similarity.matching_leading_characters >= leading
+        && similarity.matching_trailing_characters >= trailing
+}
+
+/// Counts the characters that the data parts of the two addresses have in common at their start
+/// and at their end.
+pub fn similarity(address1: &str, address2: &str) -> AddressSimilarity {
+    let (_, data1) = split_address(address1);
+    let (_, data2) = split_address(address2);
+
+    let matching_leading_characters = data1
+        .chars()
+        .zip(data2.chars())
+        .take_while(|(character1, character2)| character1 == character2)
+        .count();
+    let matching_trailing_characters = data1
+        .chars()
+        .rev()
+        .zip(data2.chars().rev())
+        .take_while(|(character1, character2)| character1 == character2)
+        .count();
+
+    AddressSimilarity {
+        matching_leading_characters,
+        matching_trailing_characters,
+    }
+}
+
+/// Splits a Bech32 address into its HRP and its data part at the last separator.
+fn split_address(address: &str) -> (Option<&str>, &str) {
+    match address.rfind('1') {
+        Some(index) => (Some(&address[..index]), &address[index + 1..]),
+        None => (None, address),
+    }
+}
+
+fn checksum_marker(address: &str) -> String {
+    hash(address.as_bytes()).0[..2]
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AbbreviatedAddress {
+    pub abbreviated: String,
+    /// The first two bytes of the hash of the full address as hex.
+    pub checksum_marker: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AddressSimilarity {
+    pub matching_leading_characters: usize,
+    pub matching_trailing_characters: usize,
+}
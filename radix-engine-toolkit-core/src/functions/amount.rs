@@ -0,0 +1,227 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Formatting of resource amounts for display, so that every host application renders balances
+//! the same way, and parsing of amounts typed in by users. Amounts are always formatted and parsed
+//! with a `.` decimal separator regardless of locale.
+
+use scrypto::prelude::*;
+
+/// The symbol of XRD, the same on every network.
+pub const XRD_SYMBOL: &str = "XRD";
+
+/// The divisibility of XRD, the same on every network.
+pub const XRD_DIVISIBILITY: u8 = Decimal::SCALE as u8;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AmountFormatOptions {
+    /// The divisibility of the resource, amounts never have more decimal places than this.
+    pub divisibility: u8,
+    /// The symbol of the resource which is appended to the amount after a space.
+    pub symbol: Option<String>,
+    /// The maximum number of decimal places to show. Amounts with more decimal places than this
+    /// and the divisibility are rounded with the rounding mode.
+    pub maximum_decimal_places: Option<u8>,
+    pub rounding_mode: RoundingMode,
+    /// The minimum number of decimal places to show, the fractional part is padded with zeros up
+    /// to this number of places. Trailing zeros beyond it are removed.
+    pub minimum_decimal_places: u8,
+    /// The separator put between groups of three digits of the integral part, if any.
+    pub group_separator: Option<char>,
+}
+
+impl AmountFormatOptions {
+    pub fn new(divisibility: u8, symbol: Option<String>) -> Self {
+        Self {
+            divisibility,
+            symbol,
+            maximum_decimal_places: None,
+            rounding_mode: RoundingMode::ToZero,
+            minimum_decimal_places: 0,
+            group_separator: None,
+        }
+    }
+
+    pub fn xrd() -> Self {
+        Self::new(XRD_DIVISIBILITY, Some(XRD_SYMBOL.to_owned()))
+    }
+}
+
+pub fn format(
+    amount: &Decimal,
+    options: &AmountFormatOptions,
+) -> Result<String, AmountFormatError> {
+    if options.divisibility > Decimal::SCALE as u8 {
+        return Err(AmountFormatError::InvalidDivisibility(options.divisibility));
+    }
+    let decimal_places = options
+        .maximum_decimal_places
+        .map_or(options.divisibility, |maximum| {
+            maximum.min(options.divisibility)
+        });
+    let minimum_decimal_places = options.minimum_decimal_places.min(decimal_places);
+
+    let rounded = amount
+        .checked_round(decimal_places as i32, options.rounding_mode)
+        .ok_or(AmountFormatError::Overflow)?;
+
+    // The string representation of decimals has no trailing zeros in the fractional part.
+    let string = rounded.to_string();
+    let (sign, unsigned) = match string.strip_prefix('-') {
+        Some(unsigned) => ("-", unsigned),
+        None => ("", string.as_str()),
+    };
+    let (integral, fractional) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let fractional = format!(
+        "{fractional:0<width$}",
+        width = minimum_decimal_places as usize
+    );
+
+    let mut formatted = sign.to_owned();
+    match options.group_separator {
+        Some(separator) => {
+            for (index, digit) in integral.chars().enumerate() {
+                if index != 0 && (integral.len() - index) % 3 == 0 {
+                    formatted.push(separator)
+                }
+                formatted.push(digit)
+            }
+        }
+        None => formatted.push_str(integral),
+    }
+    if !fractional.is_empty() {
+        formatted.push('.');
+        formatted.push_str(&fractional);
+    }
+    if let Some(ref symbol) = options.symbol {
+        formatted.push(' ');
+        formatted.push_str(symbol);
+    }
+
+    Ok(formatted)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AmountFormatError {
+    InvalidDivisibility(u8),
+    Overflow,
+}
+
+/// The characters accepted as group separators in the integral part of amounts typed in by users.
+pub const GROUP_SEPARATORS: [char; 5] = [',', '_', '\'', ' ', '\u{a0}'];
+
+/// Parses an amount typed in by a user into a [`Decimal`]. On top of the canonical representation
+/// this accepts surrounding whitespace, a leading `+`, separators between groups of three digits
+/// of the integral part (one of [`GROUP_SEPARATORS`], used consistently), and an exponent such as
+/// `1.5e3`. The decimal separator is always `.`, so inputs like `1,5` or `1.000,5` which are only
+/// valid in other locales are rejected rather than guessed at. Amounts with more decimal places
+/// than a [`Decimal`] can hold are rejected instead of being rounded.
+pub fn parse(input: &str) -> Result<Decimal, AmountParseError> {
+    let input = input.trim();
+    let (negative, unsigned) = match input.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, input.strip_prefix('+').unwrap_or(input)),
+    };
+    let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (
+            mantissa,
+            exponent
+                .parse::<i32>()
+                .map_err(|_| AmountParseError::InvalidExponent(exponent.to_owned()))?,
+        ),
+        None => (unsigned, 0),
+    };
+    let (integral, fractional) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if integral.is_empty() && fractional.is_empty() {
+        return Err(AmountParseError::Empty);
+    }
+    if let Some(character) = fractional.chars().find(|c| !c.is_ascii_digit()) {
+        return Err(AmountParseError::InvalidCharacter(character));
+    }
+    let integral = ungroup(integral)?;
+
+    // The significant digits and the position of the decimal point within them.
+    let mut digits = format!("{integral}{fractional}");
+    let mut point = integral.len() as i64 + exponent as i64;
+    let leading_zeros = digits.len() - digits.trim_start_matches('0').len();
+    digits.drain(..leading_zeros);
+    point -= leading_zeros as i64;
+    digits.truncate(digits.trim_end_matches('0').len());
+    if digits.is_empty() {
+        return Ok(Decimal::ZERO);
+    }
+
+    if digits.len() as i64 - point > Decimal::SCALE as i64 {
+        return Err(AmountParseError::TooManyDecimalPlaces);
+    }
+    // The largest decimal has 40 integral digits, anything longer can't be represented.
+    if point > 40 {
+        return Err(AmountParseError::Overflow);
+    }
+    let canonical = if point <= 0 {
+        format!("0.{}{digits}", "0".repeat(-point as usize))
+    } else if point as usize >= digits.len() {
+        format!("{digits}{}", "0".repeat(point as usize - digits.len()))
+    } else {
+        let (integral, fractional) = digits.split_at(point as usize);
+        format!("{integral}.{fractional}")
+    };
+    let sign = if negative { "-" } else { "" };
+
+    format!("{sign}{canonical}")
+        .parse::<Decimal>()
+        .map_err(|_| AmountParseError::Overflow)
+}
+
+/// Removes the group separators from the integral part of an amount, checking that all groups but
+/// the first have exactly three digits.
+fn ungroup(integral: &str) -> Result<String, AmountParseError> {
+    let mut separators = integral.chars().filter(|c| !c.is_ascii_digit());
+    let Some(separator) = separators.next() else {
+        return Ok(integral.to_owned());
+    };
+    if let Some(character) = std::iter::once(separator)
+        .chain(separators)
+        .find(|c| *c != separator || !GROUP_SEPARATORS.contains(c))
+    {
+        return Err(if GROUP_SEPARATORS.contains(&character) {
+            AmountParseError::InvalidDigitGrouping
+        } else {
+            AmountParseError::InvalidCharacter(character)
+        });
+    }
+
+    let mut groups = integral.split(separator);
+    let first_group_is_valid = groups
+        .next()
+        .map_or(false, |group| (1..=3).contains(&group.len()));
+    if !first_group_is_valid || groups.any(|group| group.len() != 3) {
+        return Err(AmountParseError::InvalidDigitGrouping);
+    }
+
+    Ok(integral.replace(separator, ""))
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AmountParseError {
+    Empty,
+    InvalidCharacter(char),
+    InvalidDigitGrouping,
+    InvalidExponent(String),
+    TooManyDecimalPlaces,
+    Overflow,
+}
@@ -0,0 +1,73 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Signed attestations over payloads that the toolkit produces, such as the canonical encoding of
+//! the output of the execution analysis, so that the verdict of the analyzer is tamper-evident when
+//! passed between services. Attestations are always signed with Ed25519 keys and the payload is
+//! hashed with a domain separation prefix so that an attestation can never be a valid signature
+//! over a transaction intent or a personal message.
+//!
+//! The hash signed is: `blake2b_256(PREFIX || payload)`.
+
+use scrypto::prelude::*;
+use transaction::prelude::*;
+
+pub const ATTESTATION_PREFIX: &[u8] = b"\x19Radix Toolkit Attestation:\n";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attestation {
+    pub payload: Vec<u8>,
+    pub public_key: Ed25519PublicKey,
+    pub signature: Ed25519Signature,
+}
+
+pub fn hash(payload: &[u8]) -> Hash {
+    scrypto::prelude::hash([ATTESTATION_PREFIX, payload].concat())
+}
+
+pub fn sign(payload: Vec<u8>, private_key: &Ed25519PrivateKey) -> Attestation {
+    sign_with(payload, |hash| {
+        (private_key.public_key(), private_key.sign(hash))
+    })
+}
+
+/// Signs the payload through the signer, which is given the hash to sign and returns the public
+/// key that it signed with and the signature. This allows for keys which are held outside of the
+/// toolkit, such as in HSMs.
+pub fn sign_with<S>(payload: Vec<u8>, signer: S) -> Attestation
+where
+    S: FnOnce(&Hash) -> (Ed25519PublicKey, Ed25519Signature),
+{
+    let (public_key, signature) = signer(&hash(&payload));
+    Attestation {
+        payload,
+        public_key,
+        signature,
+    }
+}
+
+/// Verifies that the attestation was signed by the trusted public key. The public key of the
+/// attestation is not trusted by itself as anybody can produce a valid attestation with a key of
+/// their own.
+pub fn verify(attestation: &Attestation, trusted_public_key: &Ed25519PublicKey) -> bool {
+    attestation.public_key == *trusted_public_key
+        && verify_ed25519(
+            &hash(&attestation.payload),
+            trusted_public_key,
+            &attestation.signature,
+        )
+}
@@ -0,0 +1,161 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Verification of the two-way link between an entity and the dApp definition that it claims to be
+//! a part of. An entity is a verified part of a dApp only if it references the dApp definition in
+//! its metadata and the dApp definition claims the entity back in its own metadata, which prevents
+//! entities from impersonating dApps that they have no relation to.
+
+use scrypto::api::node_modules::metadata::MetadataValue;
+use scrypto::prelude::*;
+
+use crate::utils::is_account;
+
+/// The metadata key that components use to reference their dApp definition.
+pub const DAPP_DEFINITION_KEY: &str = "dapp_definition";
+/// The metadata key that resources and packages use to reference their dApp definitions.
+pub const DAPP_DEFINITIONS_KEY: &str = "dapp_definitions";
+/// The metadata key that dApp definitions use to claim the entities that are a part of the dApp.
+pub const CLAIMED_ENTITIES_KEY: &str = "claimed_entities";
+pub const ACCOUNT_TYPE_KEY: &str = "account_type";
+/// The `account_type` that marks an account as a dApp definition.
+pub const DAPP_DEFINITION_ACCOUNT_TYPE: &str = "dapp definition";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DappDefinitionLinkVerification {
+    pub entity_address: GlobalAddress,
+    pub dapp_definition_address: GlobalAddress,
+    pub entity_references_dapp_definition: bool,
+    pub dapp_definition_claims_entity: bool,
+    /// All of the violations encountered. The link is verified only if there are none.
+    pub violations: Vec<DappDefinitionLinkViolation>,
+}
+
+impl DappDefinitionLinkVerification {
+    pub fn is_verified(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DappDefinitionLinkViolation {
+    /// The metadata value of the key is not of the type that the metadata standard requires.
+    InvalidMetadataValueType {
+        address: GlobalAddress,
+        key: String,
+    },
+    EntityDoesNotReferenceDappDefinition,
+    DappDefinitionIsNotAnAccount,
+    AccountTypeIsNotDappDefinition {
+        account_type: Option<String>,
+    },
+    DappDefinitionDoesNotClaimEntity,
+}
+
+/// The dApp definitions that an entity references in its metadata, from the `dapp_definition` and
+/// `dapp_definitions` keys. Values of the wrong type are ignored.
+pub fn referenced_dapp_definitions(
+    metadata: &HashMap<String, MetadataValue>,
+) -> Vec<GlobalAddress> {
+    let mut dapp_definitions = vec![];
+    if let Some(MetadataValue::GlobalAddress(address)) = metadata.get(DAPP_DEFINITION_KEY) {
+        dapp_definitions.push(*address)
+    }
+    if let Some(MetadataValue::GlobalAddressArray(addresses)) = metadata.get(DAPP_DEFINITIONS_KEY) {
+        dapp_definitions.extend(addresses.iter().copied())
+    }
+    dapp_definitions
+        .into_iter()
+        .collect::<IndexSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Verifies the link between the entity and the dApp definition given the current metadata of both.
+pub fn verify_link(
+    entity_address: GlobalAddress,
+    entity_metadata: &HashMap<String, MetadataValue>,
+    dapp_definition_address: GlobalAddress,
+    dapp_definition_metadata: &HashMap<String, MetadataValue>,
+) -> DappDefinitionLinkVerification {
+    let mut violations = vec![];
+
+    for key in [DAPP_DEFINITION_KEY, DAPP_DEFINITIONS_KEY] {
+        let is_valid_type = match (key, entity_metadata.get(key)) {
+            (_, None) => true,
+            (DAPP_DEFINITION_KEY, Some(MetadataValue::GlobalAddress(..))) => true,
+            (DAPP_DEFINITIONS_KEY, Some(MetadataValue::GlobalAddressArray(..))) => true,
+            _ => false,
+        };
+        if !is_valid_type {
+            violations.push(DappDefinitionLinkViolation::InvalidMetadataValueType {
+                address: entity_address,
+                key: key.to_owned(),
+            })
+        }
+    }
+    let entity_references_dapp_definition =
+        referenced_dapp_definitions(entity_metadata).contains(&dapp_definition_address);
+    if !entity_references_dapp_definition {
+        violations.push(DappDefinitionLinkViolation::EntityDoesNotReferenceDappDefinition)
+    }
+
+    if !is_account(&dapp_definition_address) {
+        violations.push(DappDefinitionLinkViolation::DappDefinitionIsNotAnAccount)
+    }
+    match dapp_definition_metadata.get(ACCOUNT_TYPE_KEY) {
+        Some(MetadataValue::String(account_type))
+            if account_type == DAPP_DEFINITION_ACCOUNT_TYPE => {}
+        Some(MetadataValue::String(account_type)) => violations.push(
+            DappDefinitionLinkViolation::AccountTypeIsNotDappDefinition {
+                account_type: Some(account_type.clone()),
+            },
+        ),
+        Some(..) => violations.push(DappDefinitionLinkViolation::InvalidMetadataValueType {
+            address: dapp_definition_address,
+            key: ACCOUNT_TYPE_KEY.to_owned(),
+        }),
+        None => violations.push(
+            DappDefinitionLinkViolation::AccountTypeIsNotDappDefinition { account_type: None },
+        ),
+    }
+
+    let dapp_definition_claims_entity = match dapp_definition_metadata.get(CLAIMED_ENTITIES_KEY) {
+        Some(MetadataValue::GlobalAddressArray(claimed_entities)) => {
+            claimed_entities.contains(&entity_address)
+        }
+        Some(..) => {
+            violations.push(DappDefinitionLinkViolation::InvalidMetadataValueType {
+                address: dapp_definition_address,
+                key: CLAIMED_ENTITIES_KEY.to_owned(),
+            });
+            false
+        }
+        None => false,
+    };
+    if !dapp_definition_claims_entity {
+        violations.push(DappDefinitionLinkViolation::DappDefinitionDoesNotClaimEntity)
+    }
+
+    DappDefinitionLinkVerification {
+        entity_address,
+        dapp_definition_address,
+        entity_references_dapp_definition,
+        dapp_definition_claims_entity,
+        violations,
+    }
+}
@@ -43,6 +43,83 @@ where
     NonFungibleGlobalId::from_public_key(public_key)
 }
 
+pub fn virtual_signature_non_fungible_global_id_from_public_key_hash(
+    public_key_hash: &PublicKeyHash,
+) -> NonFungibleGlobalId {
+    let (resource_address, hash_bytes) = match public_key_hash {
+        PublicKeyHash::Secp256k1(Secp256k1PublicKeyHash(hash_bytes)) => {
+            (SECP256K1_SIGNATURE_VIRTUAL_BADGE, hash_bytes)
+        }
+        PublicKeyHash::Ed25519(Ed25519PublicKeyHash(hash_bytes)) => {
+            (ED25519_SIGNATURE_VIRTUAL_BADGE, hash_bytes)
+        }
+    };
+    NonFungibleGlobalId::new(
+        resource_address,
+        NonFungibleLocalId::bytes(hash_bytes.to_vec())
+            .expect("Public key hashes are valid bytes local ids"),
+    )
+}
+
+/// The reverse of [`virtual_signature_non_fungible_global_id_from_public_key_hash`]: extracts the
+/// hash of the public key that a virtual signature badge non-fungible global id stands for.
+pub fn public_key_hash_from_virtual_signature_non_fungible_global_id(
+    non_fungible_global_id: &NonFungibleGlobalId,
+) -> Result<PublicKeyHash, DerivationError> {
+    let resource_address = non_fungible_global_id.resource_address();
+    let NonFungibleLocalId::Bytes(bytes) = non_fungible_global_id.local_id() else {
+        return Err(DerivationError::InvalidVirtualSignatureBadgeLocalId(
+            non_fungible_global_id.local_id().clone(),
+        ));
+    };
+    let hash_bytes = <[u8; NodeId::RID_LENGTH]>::try_from(bytes.value()).map_err(|_| {
+        DerivationError::InvalidVirtualSignatureBadgeLocalId(
+            non_fungible_global_id.local_id().clone(),
+        )
+    })?;
+
+    if resource_address == SECP256K1_SIGNATURE_VIRTUAL_BADGE {
+        Ok(PublicKeyHash::Secp256k1(Secp256k1PublicKeyHash(hash_bytes)))
+    } else if resource_address == ED25519_SIGNATURE_VIRTUAL_BADGE {
+        Ok(PublicKeyHash::Ed25519(Ed25519PublicKeyHash(hash_bytes)))
+    } else {
+        Err(DerivationError::NotAVirtualSignatureBadge(resource_address))
+    }
+}
+
+/// Derives the address of the virtual account or identity of the given entity type controlled by
+/// the key with the given hash. This is the address that the engine allocates for the key, which
+/// allows addresses to be derived when only the hash of the key is known (e.g. from the
+/// `owner_keys` metadata of an entity). The entity type must be one of the virtual account or
+/// identity types of the same curve as the hash.
+pub fn virtual_global_address_from_public_key_hash(
+    public_key_hash: &PublicKeyHash,
+    entity_type: EntityType,
+) -> Result<GlobalAddress, DerivationError> {
+    let hash_bytes = match (entity_type, public_key_hash) {
+        (
+            EntityType::GlobalVirtualSecp256k1Account | EntityType::GlobalVirtualSecp256k1Identity,
+            PublicKeyHash::Secp256k1(Secp256k1PublicKeyHash(hash_bytes)),
+        )
+        | (
+            EntityType::GlobalVirtualEd25519Account | EntityType::GlobalVirtualEd25519Identity,
+            PublicKeyHash::Ed25519(Ed25519PublicKeyHash(hash_bytes)),
+        ) => hash_bytes,
+        (
+            EntityType::GlobalVirtualSecp256k1Account
+            | EntityType::GlobalVirtualSecp256k1Identity
+            | EntityType::GlobalVirtualEd25519Account
+            | EntityType::GlobalVirtualEd25519Identity,
+            _,
+        ) => return Err(DerivationError::PublicKeyHashCurveMismatch(entity_type)),
+        _ => return Err(DerivationError::UnsupportedVirtualEntityType(entity_type)),
+    };
+
+    Ok(GlobalAddress::new_or_panic(
+        NodeId::new(entity_type as u8, hash_bytes).0,
+    ))
+}
+
 pub fn virtual_account_address_from_olympia_account_address<S>(
     olympia_account_address: S,
 ) -> Result<ComponentAddress, DerivationError>
@@ -74,6 +151,14 @@ where
     // Convert from 5 bits to 8 bits.
     let data = Vec::<u8>::from_base32(&data).map_err(DerivationError::Bech32BaseConversionError)?;
 
+    resource_address_from_olympia_resource_address_bytes(&data)
+}
+
+/// Derives the Babylon resource address of an Olympia resource from the raw bytes of its Olympia
+/// address (i.e., the data encoded in the Bech32 Olympia resource address).
+pub fn resource_address_from_olympia_resource_address_bytes(
+    data: &[u8],
+) -> Result<ResourceAddress, DerivationError> {
     // Check the length of the data to ensure that it's valid.
     let prefix = data.first();
     let length = data.len();
@@ -81,7 +166,7 @@ where
     match (prefix, length) {
         (Some(0x01), 1) => Ok(scrypto::prelude::XRD),
         (Some(0x03), 27) => {
-            let hash = scrypto::prelude::hash(&data);
+            let hash = scrypto::prelude::hash(data);
 
             let mut bytes = [0u8; 30];
             bytes[0] = EntityType::GlobalFungibleResourceManager as u8;
@@ -234,4 +319,10 @@ pub enum DerivationError {
     },
     Bech32DecodeError(bech32::Error),
     Bech32BaseConversionError(bech32::Error),
+    NotAVirtualSignatureBadge(ResourceAddress),
+    InvalidVirtualSignatureBadgeLocalId(NonFungibleLocalId),
+    /// The entity type is not one of the virtual account or identity types.
+    UnsupportedVirtualEntityType(EntityType),
+    /// The virtual entity type is of a different curve than the public key hash.
+    PublicKeyHashCurveMismatch(EntityType),
 }
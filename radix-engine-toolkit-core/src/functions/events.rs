@@ -19,6 +19,8 @@ use radix_engine_common::prelude::*;
 use radix_engine_interface::prelude::*;
 use radix_engine_queries::typed_native_events::*;
 
+use crate::utils::*;
+
 /// Attempts to decode the passed in event into a structured [`TypedNativeEvent`] if the event is
 /// emitted from a native blueprint of a well-defined schema.
 pub fn sbor_decode_to_native_event(
@@ -27,3 +29,158 @@ pub fn sbor_decode_to_native_event(
 ) -> Result<TypedNativeEvent, TypedNativeEventError> {
     to_typed_native_event(event_type_identifier, event_data)
 }
+
+/// The names of the events emitted by accounts when resources move in or out of them.
+const ACCOUNT_RESOURCE_MOVEMENT_EVENT_NAMES: [&str; 3] =
+    ["WithdrawEvent", "DepositEvent", "RejectedDepositEvent"];
+
+/// The maximum number of clauses that a filter may compile into. Conjunctions of disjunctions grow
+/// multiplicatively when they're brought into disjunctive normal form, so filters that would exceed
+/// this are rejected instead of being expanded.
+pub const MAXIMUM_EVENT_FILTER_CLAUSES: usize = 64;
+
+/// A high-level description of the events that a consumer of the transaction stream is interested
+/// in. This is compiled by [`compile_event_filter`] into a [`CompiledEventFilter`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EventFilter {
+    /// Matches events emitted by the global entity with the given address.
+    Emitter(GlobalAddress),
+    /// Matches events with the given name regardless of the emitter.
+    EventName(String),
+    /// Matches the withdraw and deposit events of the given account. When a resource address is
+    /// specified then only the movements of this resource are matched.
+    AccountResourceMovement {
+        account: ComponentAddress,
+        resource_address: Option<ResourceAddress>,
+    },
+    /// Matches events that match all of the filters.
+    All(Vec<EventFilter>),
+    /// Matches events that match any of the filters.
+    Any(Vec<EventFilter>),
+}
+
+/// The compiled form of an [`EventFilter`] in disjunctive normal form: an event is matched if it
+/// matches any of the clauses.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompiledEventFilter {
+    pub clauses: Vec<EventFilterClause>,
+}
+
+/// A conjunction of predicates on an event. Predicates that are [`None`] match all events.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EventFilterClause {
+    pub emitter: Option<GlobalAddress>,
+    pub event_name: Option<String>,
+    pub resource_address: Option<ResourceAddress>,
+}
+
+impl EventFilterClause {
+    fn merge(&self, other: &Self) -> Option<Self> {
+        Some(Self {
+            emitter: merge_predicate(&self.emitter, &other.emitter)?,
+            event_name: merge_predicate(&self.event_name, &other.event_name)?,
+            resource_address: merge_predicate(&self.resource_address, &other.resource_address)?,
+        })
+    }
+}
+
+fn merge_predicate<T: Clone + PartialEq>(a: &Option<T>, b: &Option<T>) -> Option<Option<T>> {
+    match (a, b) {
+        (Some(a), Some(b)) if a != b => None,
+        (Some(value), _) | (_, Some(value)) => Some(Some(value.clone())),
+        (None, None) => Some(None),
+    }
+}
+
+pub fn compile_event_filter(filter: &EventFilter) -> Result<CompiledEventFilter, EventFilterError> {
+    let mut clauses = Vec::<EventFilterClause>::new();
+    for clause in compile_clauses(filter)? {
+        if !clauses.contains(&clause) {
+            clauses.push(clause)
+        }
+    }
+
+    if clauses.is_empty() {
+        Err(EventFilterError::FilterMatchesNoEvents)
+    } else {
+        Ok(CompiledEventFilter { clauses })
+    }
+}
+
+fn compile_clauses(filter: &EventFilter) -> Result<Vec<EventFilterClause>, EventFilterError> {
+    match filter {
+        EventFilter::Emitter(address) => Ok(vec![EventFilterClause {
+            emitter: Some(*address),
+            ..Default::default()
+        }]),
+        EventFilter::EventName(name) => {
+            if name.is_empty() {
+                Err(EventFilterError::EmptyEventName)
+            } else {
+                Ok(vec![EventFilterClause {
+                    event_name: Some(name.clone()),
+                    ..Default::default()
+                }])
+            }
+        }
+        EventFilter::AccountResourceMovement {
+            account,
+            resource_address,
+        } => {
+            if !is_account(&GlobalAddress::from(*account)) {
+                return Err(EventFilterError::NotAnAccount(*account));
+            }
+            Ok(ACCOUNT_RESOURCE_MOVEMENT_EVENT_NAMES
+                .iter()
+                .map(|name| EventFilterClause {
+                    emitter: Some((*account).into()),
+                    event_name: Some(name.to_string()),
+                    resource_address: *resource_address,
+                })
+                .collect())
+        }
+        EventFilter::Any(filters) => filters.iter().try_fold(vec![], |mut clauses, filter| {
+            clauses.extend(compile_clauses(filter)?);
+            check_clause_count(clauses)
+        }),
+        EventFilter::All(filters) => {
+            filters
+                .iter()
+                .try_fold(vec![EventFilterClause::default()], |clauses, filter| {
+                    let other_clauses = compile_clauses(filter)?;
+                    // Both sides are bounded so the product that is checked is bounded as well.
+                    check_clause_count(
+                        clauses
+                            .iter()
+                            .flat_map(|clause| {
+                                other_clauses
+                                    .iter()
+                                    .filter_map(move |other| clause.merge(other))
+                            })
+                            .collect(),
+                    )
+                })
+        }
+    }
+}
+
+fn check_clause_count(
+    clauses: Vec<EventFilterClause>,
+) -> Result<Vec<EventFilterClause>, EventFilterError> {
+    if clauses.len() > MAXIMUM_EVENT_FILTER_CLAUSES {
+        Err(EventFilterError::TooManyClauses {
+            maximum: MAXIMUM_EVENT_FILTER_CLAUSES,
+            actual: clauses.len(),
+        })
+    } else {
+        Ok(clauses)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum EventFilterError {
+    EmptyEventName,
+    NotAnAccount(ComponentAddress),
+    FilterMatchesNoEvents,
+    TooManyClauses { maximum: usize, actual: usize },
+}
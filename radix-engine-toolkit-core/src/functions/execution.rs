@@ -15,30 +15,46 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use radix_engine::blueprints::account::{
+    DepositEvent as AccountDepositEvent, WithdrawEvent as AccountWithdrawEvent,
+};
 use radix_engine::system::system_modules::execution_trace::*;
 use radix_engine::transaction::*;
 use radix_engine_interface::blueprints::account::DefaultDepositRule;
 use radix_engine_queries::typed_substate_layout::UnstakeData;
 use scrypto::api::node_modules::metadata::*;
 use scrypto::prelude::*;
+use std::any::{type_name, Any, TypeId};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
 use transaction::prelude::*;
 
 use crate::instruction_visitor::core::error::*;
+use crate::instruction_visitor::core::traits::*;
 use crate::instruction_visitor::core::traverser::*;
 use crate::instruction_visitor::visitors::account_proofs_visitor::*;
+use crate::instruction_visitor::visitors::authorized_depositor_badges_visitor::*;
+use crate::instruction_visitor::visitors::fee_locks_visitor::*;
+use crate::instruction_visitor::visitors::required_badges_visitor::*;
 use crate::instruction_visitor::visitors::transaction_type::account_deposit_settings_visitor::*;
 use crate::instruction_visitor::visitors::transaction_type::claim_stake_visitor::ClaimStakeInformation;
 use crate::instruction_visitor::visitors::transaction_type::claim_stake_visitor::ClaimStakeVisitor;
+use crate::instruction_visitor::visitors::transaction_type::entity_control_changes_visitor::*;
 use crate::instruction_visitor::visitors::transaction_type::general_transaction_visitor::*;
+use crate::instruction_visitor::visitors::transaction_type::owner_keys_visitor::*;
 use crate::instruction_visitor::visitors::transaction_type::reserved_instructions::ReservedInstruction;
+use crate::instruction_visitor::visitors::transaction_type::reserved_instructions::ReservedInstructionUsage;
 use crate::instruction_visitor::visitors::transaction_type::reserved_instructions::ReservedInstructionsVisitor;
+use crate::instruction_visitor::visitors::transaction_type::royalty_claims_visitor::*;
 use crate::instruction_visitor::visitors::transaction_type::simple_transfer_visitor::*;
 use crate::instruction_visitor::visitors::transaction_type::stake_visitor::{
     StakeInformation, StakeVisitor,
 };
+use crate::instruction_visitor::visitors::transaction_type::swap_visitor::*;
 use crate::instruction_visitor::visitors::transaction_type::transfer_visitor::*;
 use crate::instruction_visitor::visitors::transaction_type::unstake_visitor::UnstakeInformation;
 use crate::instruction_visitor::visitors::transaction_type::unstake_visitor::UnstakeVisitor;
+use crate::instruction_visitor::visitors::worktop_guarantees_visitor::*;
 use crate::models::node_id::InvalidEntityTypeIdError;
 use crate::models::node_id::TypedNodeId;
 use crate::utils;
@@ -46,6 +62,34 @@ use crate::utils;
 pub fn analyze(
     instructions: &[InstructionV1],
     preview_receipt: &ExecutionAnalysisTransactionReceipt,
+) -> Result<ExecutionAnalysis, ExecutionModuleError> {
+    analyze_with_plugins(instructions, preview_receipt, &mut [])
+}
+
+/// Performs the execution analysis of the transaction while also running the given plugins over
+/// the instructions. The plugins are traversed alongside the toolkit's own visitors and are then
+/// asked to contribute their outputs which are made available through
+/// [`ExecutionAnalysis::plugin_outputs`].
+pub fn analyze_with_plugins(
+    instructions: &[InstructionV1],
+    preview_receipt: &ExecutionAnalysisTransactionReceipt,
+    plugins: &mut [&mut dyn ExecutionAnalysisPlugin],
+) -> Result<ExecutionAnalysis, ExecutionModuleError> {
+    analyze_with_dex_registry(
+        instructions,
+        preview_receipt,
+        &DexRegistry::default(),
+        plugins,
+    )
+}
+
+/// Performs the execution analysis of the transaction with the given plugins while classifying the
+/// calls made to the swap methods of the exchanges in the registry as [`TransactionType::Swap`].
+pub fn analyze_with_dex_registry(
+    instructions: &[InstructionV1],
+    preview_receipt: &ExecutionAnalysisTransactionReceipt,
+    dex_registry: &DexRegistry,
+    plugins: &mut [&mut dyn ExecutionAnalysisPlugin],
 ) -> Result<ExecutionAnalysis, ExecutionModuleError> {
     let execution_trace = preview_receipt.execution_trace();
 
@@ -58,10 +102,16 @@ pub fn analyze(
     let mut stake_visitor = StakeVisitor::new(execution_trace);
     let mut unstake_visitor = UnstakeVisitor::new(preview_receipt);
     let mut claim_stake_visitor = ClaimStakeVisitor::new(execution_trace);
+    let mut owner_keys_visitor = OwnerKeysVisitor::default();
+    let mut entity_control_changes_visitor = EntityControlChangesVisitor::default();
+    let mut royalty_claims_visitor = RoyaltyClaimsVisitor::new(execution_trace);
+    let mut swap_visitor = SwapVisitor::new(execution_trace, dex_registry);
+    let mut required_badges_visitor = RequiredBadgesVisitor::default();
+    let mut worktop_guarantees_visitor = WorktopGuaranteesVisitor::default();
+    let mut authorized_depositor_badges_visitor = AuthorizedDepositorBadgesVisitor::default();
 
-    traverse(
-        instructions,
-        &mut [
+    {
+        let mut visitors: Vec<&mut dyn InstructionVisitor> = vec![
             &mut simple_transfer_visitor,
             &mut transfer_visitor,
             &mut account_proofs_visitor,
@@ -71,8 +121,21 @@ pub fn analyze(
             &mut stake_visitor,
             &mut unstake_visitor,
             &mut claim_stake_visitor,
-        ],
-    )?;
+            &mut owner_keys_visitor,
+            &mut entity_control_changes_visitor,
+            &mut royalty_claims_visitor,
+            &mut swap_visitor,
+            &mut required_badges_visitor,
+            &mut worktop_guarantees_visitor,
+            &mut authorized_depositor_badges_visitor,
+        ];
+        visitors.extend(
+            plugins
+                .iter_mut()
+                .map(|plugin| plugin.as_instruction_visitor()),
+        );
+        traverse(instructions, &mut visitors)?;
+    }
 
     let mut transaction_types = vec![];
     if let Some((from_account_address, to_account_address, transfer)) =
@@ -123,12 +186,54 @@ pub fn analyze(
             ClaimStakeTransactionType(claim_stake),
         )))
     }
-    if let Some((account_withdraws, account_deposits)) = general_transaction_visitor.output() {
+    if let Some(owner_keys_changes) = owner_keys_visitor.output() {
+        transaction_types.push(TransactionType::OwnerKeysUpdate(Box::new(
+            OwnerKeysUpdateTransactionType { owner_keys_changes },
+        )))
+    }
+    if let Some(entity_control_changes) = entity_control_changes_visitor.output() {
+        transaction_types.push(TransactionType::EntityControlChanges(Box::new(
+            EntityControlChangesTransactionType {
+                entity_control_changes,
+            },
+        )))
+    }
+    if let Some(claims) = royalty_claims_visitor.output() {
+        let mut destinations = account_resource_movements_from_events(preview_receipt)
+            .deposits
+            .into_iter()
+            .filter(|(_, amounts)| amounts.contains_key(&XRD))
+            .map(|(account, _)| account)
+            .collect::<Vec<_>>();
+        destinations.sort();
+        transaction_types.push(TransactionType::RoyaltyClaim(Box::new(
+            RoyaltyClaimTransactionType {
+                claims,
+                destinations,
+            },
+        )))
+    }
+    if let Some(swaps) = swap_visitor.output() {
+        transaction_types.push(TransactionType::Swap(Box::new(SwapTransactionType {
+            swaps,
+        })))
+    }
+    let mut resource_movement_discrepancies = vec![];
+    if let Some((account_withdraws, account_deposits, account_created_proofs, presented_proofs)) =
+        general_transaction_visitor.output()
+    {
+        resource_movement_discrepancies = compare_resource_movements(
+            &account_withdraws,
+            &account_deposits,
+            &account_resource_movements_from_events(preview_receipt),
+        );
         transaction_types.push(TransactionType::GeneralTransaction(Box::new(
             GeneralTransactionType {
                 account_proofs: account_proofs_visitor.output(),
                 account_withdraws,
                 account_deposits,
+                account_created_proofs,
+                presented_proofs,
                 addresses_in_manifest: crate::functions::instructions::extract_addresses(
                     instructions,
                 ),
@@ -154,18 +259,324 @@ pub fn analyze(
         finalization_cost: preview_receipt.fee_summary.total_finalization_cost_in_xrd,
         storage_expansion_cost: preview_receipt.fee_summary.total_storage_cost_in_xrd,
         royalty_cost: preview_receipt.fee_summary.total_royalty_cost_in_xrd,
+        fee_payers: FeePayers::from_fee_locks(&crate::functions::manifest::fee_locks(instructions)),
     };
 
-    let reserved_instructions = reserved_instructions_visitor.output();
+    let (reserved_instructions, reserved_instruction_usages) =
+        reserved_instructions_visitor.output_with_arguments();
+    let required_badges = required_badges_visitor.output();
+    let guarantees = worktop_guarantees_visitor.output();
+    let authorized_depositor_badges = authorized_depositor_badges_visitor.output();
+    let resource_movement_graph = resource_movement_graph(preview_receipt, &guarantees);
+
+    let mut plugin_outputs = ExecutionAnalysisPluginOutputs::default();
+    for plugin in plugins.iter_mut() {
+        plugin.contribute(preview_receipt, &mut plugin_outputs);
+    }
 
     Ok(ExecutionAnalysis {
         fee_locks,
         fee_summary,
         transaction_types,
         reserved_instructions,
+        reserved_instruction_usages,
+        required_badges,
+        guarantees,
+        authorized_depositor_badges,
+        resource_movement_discrepancies,
+        resource_movement_graph,
+        plugin_outputs,
     })
 }
 
+/// Derives the directed graph of the resources moved by the transaction from the vault changes in
+/// the execution trace. Resources withdrawn from the vaults of an entity flow into the worktop and
+/// resources deposited into them flow out of it, so the worktop is the first node of every graph
+/// and every edge has it on one of its ends.
+///
+/// A deposit is [`ResourceMovementGuarantee::Guaranteed`] if the manifest asserted that the worktop
+/// contains the resource at an instruction prior to the deposit. A withdraw is guaranteed if it's
+/// from an account since the manifest gives the exact amount to withdraw from accounts.
+pub fn resource_movement_graph(
+    preview_receipt: &ExecutionAnalysisTransactionReceipt,
+    guarantees: &[WorktopGuarantee],
+) -> ResourceMovementGraph {
+    let mut graph = ResourceMovementGraph {
+        nodes: vec![ResourceMovementGraphNode::Worktop],
+        edges: vec![],
+    };
+
+    for (instruction_index, resource_changes) in
+        preview_receipt.execution_trace().resource_changes.iter()
+    {
+        for ResourceChange {
+            node_id,
+            resource_address,
+            amount,
+            ..
+        } in resource_changes
+        {
+            let Ok(entity) = TypedNodeId::new(*node_id) else {
+                continue;
+            };
+            if amount.is_zero() {
+                continue;
+            }
+
+            let entity_node = ResourceMovementGraphNode::Entity(entity);
+            let entity_index = match graph.nodes.iter().position(|node| *node == entity_node) {
+                Some(index) => index,
+                None => {
+                    graph.nodes.push(entity_node);
+                    graph.nodes.len() - 1
+                }
+            };
+
+            let edge = if amount.is_negative() {
+                let is_account = GlobalAddress::try_from(node_id.as_bytes())
+                    .map_or(false, |address| utils::is_account(&address));
+                ResourceMovementGraphEdge {
+                    instruction_index: *instruction_index,
+                    from: entity_index,
+                    to: ResourceMovementGraph::WORKTOP_NODE_INDEX,
+                    resource_address: *resource_address,
+                    amount: -*amount,
+                    guarantee: if is_account {
+                        ResourceMovementGuarantee::Guaranteed
+                    } else {
+                        ResourceMovementGuarantee::Predicted
+                    },
+                }
+            } else {
+                let is_guaranteed = guarantees.iter().any(|guarantee| {
+                    guarantee.resource_address == *resource_address
+                        && guarantee.instruction_index < *instruction_index
+                });
+                ResourceMovementGraphEdge {
+                    instruction_index: *instruction_index,
+                    from: ResourceMovementGraph::WORKTOP_NODE_INDEX,
+                    to: entity_index,
+                    resource_address: *resource_address,
+                    amount: *amount,
+                    guarantee: if is_guaranteed {
+                        ResourceMovementGuarantee::Guaranteed
+                    } else {
+                        ResourceMovementGuarantee::Predicted
+                    },
+                }
+            };
+            graph.edges.push(edge);
+        }
+    }
+
+    graph
+}
+
+/// Derives the resources withdrawn from and deposited into accounts from the account events emitted
+/// during the execution of the transaction. Unlike the manifest analysis, this does not rely on any
+/// heuristics over the instructions and thus also accounts for movements that happen in exotic
+/// manifests (e.g. through components depositing into accounts directly). Non-fungible movements
+/// are given as the number of non-fungibles.
+pub fn account_resource_movements_from_events(
+    receipt: &ExecutionAnalysisTransactionReceipt,
+) -> AccountResourceMovements {
+    let mut movements = AccountResourceMovements::default();
+
+    for (EventTypeIdentifier(emitter, event_name), event_data) in
+        receipt.commit_result().application_events.iter()
+    {
+        let Emitter::Method(node_id, ObjectModuleId::Main) = emitter else {
+            continue;
+        };
+        let Ok(account) = ComponentAddress::try_from(node_id.as_bytes()) else {
+            continue;
+        };
+        if !utils::is_account(&account) {
+            continue;
+        }
+
+        let (movement, resource_address, amount) = match event_name.as_str() {
+            "WithdrawEvent" => match scrypto_decode::<AccountWithdrawEvent>(event_data) {
+                Ok(AccountWithdrawEvent::Fungible(resource_address, amount)) => {
+                    (&mut movements.withdraws, resource_address, amount)
+                }
+                Ok(AccountWithdrawEvent::NonFungible(resource_address, ids)) => (
+                    &mut movements.withdraws,
+                    resource_address,
+                    Decimal::from(ids.len() as u64),
+                ),
+                Err(..) => continue,
+            },
+            "DepositEvent" => match scrypto_decode::<AccountDepositEvent>(event_data) {
+                Ok(AccountDepositEvent::Fungible(resource_address, amount)) => {
+                    (&mut movements.deposits, resource_address, amount)
+                }
+                Ok(AccountDepositEvent::NonFungible(resource_address, ids)) => (
+                    &mut movements.deposits,
+                    resource_address,
+                    Decimal::from(ids.len() as u64),
+                ),
+                Err(..) => continue,
+            },
+            _ => continue,
+        };
+
+        let entry = movement
+            .entry(account)
+            .or_default()
+            .entry(resource_address)
+            .or_default();
+        *entry = entry.checked_add(amount).unwrap_or(Decimal::MAX);
+    }
+
+    movements
+}
+
+/// Computes the minimum XRD balance that each account must hold for the transaction to go through,
+/// so that wallets can check balances before submission. The fees locked against an account are
+/// counted first, after which its XRD withdrawals and deposits are replayed in the order of the
+/// events of the receipt: a deposit only offsets the withdrawals that come after it. Accounts that
+/// do not need any XRD are omitted.
+pub fn minimum_xrd_required_per_account(
+    instructions: &[InstructionV1],
+    receipt: &ExecutionAnalysisTransactionReceipt,
+) -> IndexMap<ComponentAddress, Decimal> {
+    // The running XRD outflow of each account and the highest that it gets.
+    let mut outflows = IndexMap::<ComponentAddress, (Decimal, Decimal)>::new();
+    let mut add_outflow = |account: ComponentAddress, amount: Decimal| {
+        let (outflow, maximum_outflow) = outflows.entry(account).or_default();
+        *outflow = outflow.checked_add(amount).unwrap_or(Decimal::MAX);
+        *maximum_outflow = (*maximum_outflow).max(*outflow);
+    };
+
+    for fee_lock in crate::functions::manifest::fee_locks(instructions) {
+        add_outflow(fee_lock.account, fee_lock.amount)
+    }
+
+    for (EventTypeIdentifier(emitter, event_name), event_data) in
+        receipt.commit_result().application_events.iter()
+    {
+        let Emitter::Method(node_id, ObjectModuleId::Main) = emitter else {
+            continue;
+        };
+        let Ok(account) = ComponentAddress::try_from(node_id.as_bytes()) else {
+            continue;
+        };
+        if !utils::is_account(&account) {
+            continue;
+        }
+
+        let xrd_outflow = match event_name.as_str() {
+            "WithdrawEvent" => match scrypto_decode::<AccountWithdrawEvent>(event_data) {
+                Ok(AccountWithdrawEvent::Fungible(resource_address, amount))
+                    if resource_address == XRD =>
+                {
+                    amount
+                }
+                _ => continue,
+            },
+            "DepositEvent" => match scrypto_decode::<AccountDepositEvent>(event_data) {
+                Ok(AccountDepositEvent::Fungible(resource_address, amount))
+                    if resource_address == XRD =>
+                {
+                    -amount
+                }
+                _ => continue,
+            },
+            _ => continue,
+        };
+        add_outflow(account, xrd_outflow)
+    }
+
+    outflows
+        .into_iter()
+        .filter_map(|(account, (_, maximum_outflow))| {
+            maximum_outflow
+                .is_positive()
+                .then_some((account, maximum_outflow))
+        })
+        .collect()
+}
+
+/// Compares the account withdraws and deposits found through the manifest analysis against those
+/// derived from the events of the receipt and reports all of the account and resource pairs where
+/// the amounts do not agree. The discrepancies of each direction are ordered by account and then by
+/// resource address so that the result does not depend on the iteration order of the maps.
+fn compare_resource_movements(
+    account_withdraws: &HashMap<ComponentAddress, Vec<ResourceTracker>>,
+    account_deposits: &HashMap<ComponentAddress, Vec<ResourceTracker>>,
+    event_movements: &AccountResourceMovements,
+) -> Vec<ResourceMovementDiscrepancy> {
+    let mut discrepancies = vec![];
+    for (direction, manifest_movements, event_movements) in [
+        (
+            ResourceMovementDirection::Withdraw,
+            account_withdraws,
+            &event_movements.withdraws,
+        ),
+        (
+            ResourceMovementDirection::Deposit,
+            account_deposits,
+            &event_movements.deposits,
+        ),
+    ] {
+        let manifest_movements = manifest_movements
+            .iter()
+            .map(|(account, trackers)| {
+                let mut amounts = HashMap::<ResourceAddress, Decimal>::new();
+                for tracker in trackers {
+                    let (resource_address, amount) = match tracker {
+                        ResourceTracker::Fungible {
+                            resource_address,
+                            amount,
+                        }
+                        | ResourceTracker::NonFungible {
+                            resource_address,
+                            amount,
+                            ..
+                        } => (*resource_address, **amount),
+                    };
+                    let entry = amounts.entry(resource_address).or_default();
+                    *entry = entry.checked_add(amount).unwrap_or(Decimal::MAX);
+                }
+                (*account, amounts)
+            })
+            .collect::<HashMap<_, _>>();
+
+        let pairs = manifest_movements
+            .iter()
+            .chain(event_movements.iter())
+            .flat_map(|(account, amounts)| {
+                amounts
+                    .keys()
+                    .map(|resource_address| (*account, *resource_address))
+            })
+            .collect::<BTreeSet<_>>();
+        for (account, resource_address) in pairs {
+            let amount_of =
+                |movements: &HashMap<ComponentAddress, HashMap<ResourceAddress, Decimal>>| {
+                    movements
+                        .get(&account)
+                        .and_then(|amounts| amounts.get(&resource_address))
+                        .copied()
+                        .unwrap_or_default()
+                };
+            let manifest_amount = amount_of(&manifest_movements);
+            let receipt_amount = amount_of(event_movements);
+            if manifest_amount != receipt_amount {
+                discrepancies.push(ResourceMovementDiscrepancy {
+                    account,
+                    resource_address,
+                    direction,
+                    manifest_amount,
+                    receipt_amount,
+                })
+            }
+        }
+    }
+    discrepancies
+}
+
 /// A transaction receipt used for execution analysis. This struct maintains the invariant that the
 /// execution of the transaction succeeded and was committed to ledger state and that there is an
 /// execution trace output.
@@ -236,6 +647,176 @@ pub struct ExecutionAnalysis {
     pub fee_summary: FeeSummary,
     pub transaction_types: Vec<TransactionType>,
     pub reserved_instructions: HashSet<ReservedInstruction>,
+    pub reserved_instruction_usages: Vec<ReservedInstructionUsage>,
+    pub required_badges: Vec<InstructionBadgeRequirements>,
+    pub guarantees: Vec<WorktopGuarantee>,
+    /// The deposits into accounts which presented an authorized depositor badge. As the analysis is
+    /// of a successful preview, these are the badges which satisfied the deposit rules of the
+    /// accounts whenever the rules would have otherwise rejected the deposited resources.
+    pub authorized_depositor_badges: Vec<AuthorizedDepositorBadgeUsage>,
+    /// The account withdraws and deposits where the manifest analysis and the events of the receipt
+    /// disagree. This is only populated for general transactions.
+    pub resource_movement_discrepancies: Vec<ResourceMovementDiscrepancy>,
+    /// The resources moved by the transaction as a directed graph, see [`resource_movement_graph`].
+    pub resource_movement_graph: ResourceMovementGraph,
+    /// The outputs contributed by the plugins passed to [`analyze_with_plugins`]. This is always
+    /// empty when the analysis is performed through [`analyze`].
+    pub plugin_outputs: ExecutionAnalysisPluginOutputs,
+}
+
+/// A user-defined visitor that runs inside of the execution analysis. This allows users of the
+/// toolkit to classify their own transaction types without having to fork the toolkit: plugins are
+/// traversed alongside the toolkit's visitors and then contribute typed outputs to the analysis.
+pub trait ExecutionAnalysisPlugin: InstructionVisitor + AsInstructionVisitor {
+    /// Called once all of the instructions have been visited. The plugin may insert any number of
+    /// outputs into the passed map, or none if it did not recognize anything in the transaction.
+    fn contribute(
+        &mut self,
+        preview_receipt: &ExecutionAnalysisTransactionReceipt,
+        outputs: &mut ExecutionAnalysisPluginOutputs,
+    );
+}
+
+/// Upcasts a plugin into an [`InstructionVisitor`] so that it can be traversed. This is implemented
+/// for all visitors and does not need to be implemented manually.
+pub trait AsInstructionVisitor {
+    fn as_instruction_visitor(&mut self) -> &mut dyn InstructionVisitor;
+}
+
+impl<T> AsInstructionVisitor for T
+where
+    T: InstructionVisitor,
+{
+    fn as_instruction_visitor(&mut self) -> &mut dyn InstructionVisitor {
+        self
+    }
+}
+
+/// A side-channel map of the outputs contributed by the [`ExecutionAnalysisPlugin`]s, keyed by the
+/// type of the output. Outputs are shared when the analysis is cloned and two maps are considered
+/// equal if they hold the same output instances.
+#[derive(Clone, Default)]
+pub struct ExecutionAnalysisPluginOutputs(
+    BTreeMap<TypeId, (&'static str, Arc<dyn Any + Send + Sync>)>,
+);
+
+impl ExecutionAnalysisPluginOutputs {
+    /// Inserts the output into the map, replacing any previous output of the same type.
+    pub fn insert<T>(&mut self, output: T)
+    where
+        T: Any + Send + Sync,
+    {
+        self.0
+            .insert(TypeId::of::<T>(), (type_name::<T>(), Arc::new(output)));
+    }
+
+    pub fn get<T>(&self) -> Option<&T>
+    where
+        T: Any + Send + Sync,
+    {
+        self.0
+            .get(&TypeId::of::<T>())
+            .and_then(|(_, output)| output.downcast_ref::<T>())
+    }
+
+    pub fn contains<T>(&self) -> bool
+    where
+        T: Any + Send + Sync,
+    {
+        self.0.contains_key(&TypeId::of::<T>())
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl std::fmt::Debug for ExecutionAnalysisPluginOutputs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set()
+            .entries(self.0.values().map(|(type_name, _)| type_name))
+            .finish()
+    }
+}
+
+impl PartialEq for ExecutionAnalysisPluginOutputs {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len()
+            && self.0.iter().zip(other.0.iter()).all(
+                |((type_a, (_, output_a)), (type_b, (_, output_b)))| {
+                    type_a == type_b
+                        && Arc::as_ptr(output_a) as *const () == Arc::as_ptr(output_b) as *const ()
+                },
+            )
+    }
+}
+
+impl Eq for ExecutionAnalysisPluginOutputs {}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AccountResourceMovements {
+    pub withdraws: HashMap<ComponentAddress, HashMap<ResourceAddress, Decimal>>,
+    pub deposits: HashMap<ComponentAddress, HashMap<ResourceAddress, Decimal>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResourceMovementDiscrepancy {
+    pub account: ComponentAddress,
+    pub resource_address: ResourceAddress,
+    pub direction: ResourceMovementDirection,
+    pub manifest_amount: Decimal,
+    pub receipt_amount: Decimal,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResourceMovementDirection {
+    Withdraw,
+    Deposit,
+}
+
+/// The resources moved by a transaction as a directed graph meant for visualization. The edges
+/// refer to the nodes by their index in [`ResourceMovementGraph::nodes`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResourceMovementGraph {
+    pub nodes: Vec<ResourceMovementGraphNode>,
+    pub edges: Vec<ResourceMovementGraphEdge>,
+}
+
+impl ResourceMovementGraph {
+    pub const WORKTOP_NODE_INDEX: usize = 0;
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ResourceMovementGraphNode {
+    Worktop,
+    /// An account or component whose vaults the resources were withdrawn from or deposited into.
+    /// The entity type is available through [`TypedNodeId::entity_type`].
+    Entity(TypedNodeId),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResourceMovementGraphEdge {
+    pub instruction_index: usize,
+    pub from: usize,
+    pub to: usize,
+    pub resource_address: ResourceAddress,
+    /// The amount of the resource moved. This is the number of non-fungibles for non-fungible
+    /// resources.
+    pub amount: Decimal,
+    pub guarantee: ResourceMovementGuarantee,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResourceMovementGuarantee {
+    /// The amount moved is guaranteed by the manifest.
+    Guaranteed,
+    /// The amount moved is what was seen in the preview and may differ when the transaction is
+    /// committed.
+    Predicted,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Default)]
@@ -244,6 +825,8 @@ pub struct FeeSummary {
     pub finalization_cost: Decimal,
     pub storage_expansion_cost: Decimal,
     pub royalty_cost: Decimal,
+    /// The accounts that the fees were locked against by the manifest.
+    pub fee_payers: FeePayers,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Default)]
@@ -260,9 +843,44 @@ pub enum TransactionType {
     StakeTransaction(Box<StakeTransactionType>),
     UnstakeTransaction(Box<UnstakeTransactionType>),
     ClaimStakeTransaction(Box<ClaimStakeTransactionType>),
+    OwnerKeysUpdate(Box<OwnerKeysUpdateTransactionType>),
+    EntityControlChanges(Box<EntityControlChangesTransactionType>),
+    RoyaltyClaim(Box<RoyaltyClaimTransactionType>),
+    Swap(Box<SwapTransactionType>),
     GeneralTransaction(Box<GeneralTransactionType>),
 }
 
+/// A transaction that changes the `owner_keys` metadata of entities. This is reported alongside the
+/// other transaction types of the manifest since owner keys are used in ROLA.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OwnerKeysUpdateTransactionType {
+    pub owner_keys_changes: IndexMap<GlobalAddress, Vec<OwnerKeysAction>>,
+}
+
+/// A transaction that locks metadata, assigns roles, or changes the owner of entities. This is
+/// reported alongside the other transaction types of the manifest since these changes are high-risk
+/// and must be clearly flagged.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EntityControlChangesTransactionType {
+    pub entity_control_changes: IndexMap<GlobalAddress, Vec<EntityControlChange>>,
+}
+
+/// A transaction that claims the royalties accumulated by components or packages.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoyaltyClaimTransactionType {
+    pub claims: Vec<RoyaltyClaim>,
+    /// The accounts that XRD was deposited into, as reported by the account events. These are
+    /// where the claimed royalties ended up unless they were sent somewhere other than an account.
+    pub destinations: Vec<ComponentAddress>,
+}
+
+/// A transaction that swaps resources on the exchanges registered in the [`DexRegistry`] passed to
+/// [`analyze_with_dex_registry`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SwapTransactionType {
+    pub swaps: Vec<Swap>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct StakeTransactionType(pub Vec<StakeInformation>);
 
@@ -298,6 +916,8 @@ pub struct GeneralTransactionType {
     pub account_proofs: HashSet<ResourceAddress>,
     pub account_withdraws: HashMap<ComponentAddress, Vec<ResourceTracker>>,
     pub account_deposits: HashMap<ComponentAddress, Vec<ResourceTracker>>,
+    pub account_created_proofs: HashMap<ComponentAddress, Vec<ResourceSpecifier>>,
+    pub presented_proofs: Vec<PresentedProof>,
     pub addresses_in_manifest: (HashSet<TypedNodeId>, HashSet<u32>),
     pub addresses_of_newly_created_entities: HashSet<TypedNodeId>,
     pub metadata_of_newly_created_entities:
@@ -0,0 +1,59 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use scrypto::prelude::*;
+
+/// Computes the epoch range of a transaction header that keeps the transaction valid for at least
+/// the given duration. Since the current epoch may be about to end, the end epoch has one more
+/// epoch added to it on top of the epochs needed to cover the duration.
+///
+/// [`None`] is returned if the epoch duration is zero or the end epoch overflows.
+pub fn epoch_range_from_validity_duration(
+    current_epoch: Epoch,
+    epoch_duration_in_seconds: u64,
+    validity_duration_in_seconds: u64,
+) -> Option<(Epoch, Epoch)> {
+    if epoch_duration_in_seconds == 0 {
+        return None;
+    }
+
+    let epochs = validity_duration_in_seconds.checked_add(epoch_duration_in_seconds - 1)?
+        / epoch_duration_in_seconds;
+    let end_epoch_exclusive = current_epoch.number().checked_add(epochs)?.checked_add(1)?;
+
+    Some((current_epoch, Epoch::of(end_epoch_exclusive)))
+}
+
+/// Computes the range of durations (in seconds) that a transaction with the given end epoch would
+/// remain valid for, as the minimum and maximum depending on how far into the current epoch we are.
+///
+/// [`None`] is returned if the end epoch is not after the current epoch or if the durations
+/// overflow.
+pub fn validity_duration_from_epoch_range(
+    current_epoch: Epoch,
+    end_epoch_exclusive: Epoch,
+    epoch_duration_in_seconds: u64,
+) -> Option<(u64, u64)> {
+    let epochs = end_epoch_exclusive
+        .number()
+        .checked_sub(current_epoch.number())
+        .filter(|epochs| *epochs > 0)?;
+
+    let minimum = (epochs - 1).checked_mul(epoch_duration_in_seconds)?;
+    let maximum = epochs.checked_mul(epoch_duration_in_seconds)?;
+    Some((minimum, maximum))
+}
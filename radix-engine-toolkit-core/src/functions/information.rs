@@ -15,6 +15,10 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use radix_engine::types::MethodKey;
+
+use crate::statics::*;
+
 pub fn information() -> BuildInformation {
     let version = env!("CARGO_PKG_VERSION").into();
     let scrypto_dependency = DependencyInformation::from_environment_variable();
@@ -25,12 +29,45 @@ pub fn information() -> BuildInformation {
     }
 }
 
+/// The methods which the static analyzers treat as requiring auth, that is, the methods which are
+/// not public in the role definitions of the native blueprints and modules. The method names are
+/// sorted.
+pub fn methods_that_require_auth() -> MethodsThatRequireAuth {
+    MethodsThatRequireAuth {
+        account: method_names(&ACCOUNT_METHODS_THAT_REQUIRE_AUTH),
+        identity: method_names(&IDENTITY_METHODS_THAT_REQUIRE_AUTH),
+        validator: method_names(&VALIDATOR_METHODS_THAT_REQUIRE_AUTH),
+        role_assignment: method_names(&ROLE_ASSIGNMENT_METHODS_THAT_REQUIRE_AUTH),
+        metadata: method_names(&METADATA_METHODS_THAT_REQUIRE_AUTH),
+        royalty: method_names(&ROYALTY_METHODS_THAT_REQUIRE_AUTH),
+    }
+}
+
+fn method_names(method_keys: &[MethodKey]) -> Vec<String> {
+    let mut method_names = method_keys
+        .iter()
+        .map(|method_key| method_key.ident.clone())
+        .collect::<Vec<_>>();
+    method_names.sort();
+    method_names
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct BuildInformation {
     pub version: String,
     pub scrypto_dependency: DependencyInformation,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MethodsThatRequireAuth {
+    pub account: Vec<String>,
+    pub identity: Vec<String>,
+    pub validator: Vec<String>,
+    pub role_assignment: Vec<String>,
+    pub metadata: Vec<String>,
+    pub royalty: Vec<String>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum DependencyInformation {
     // Crates.io
@@ -59,3 +96,212 @@ impl DependencyInformation {
         }
     }
 }
+
+#[cfg(feature = "bench")]
+pub use benchmark::*;
+
+#[cfg(feature = "bench")]
+mod benchmark {
+    use std::time::Duration;
+    #[cfg(not(target_arch = "wasm32"))]
+    use std::{hint::black_box, time::Instant};
+
+    #[cfg(not(target_arch = "wasm32"))]
+    use radix_engine::transaction::VersionedTransactionReceipt;
+    #[cfg(not(target_arch = "wasm32"))]
+    use scrypto::prelude::*;
+    #[cfg(not(target_arch = "wasm32"))]
+    use transaction::prelude::*;
+
+    #[cfg(not(target_arch = "wasm32"))]
+    use crate::functions::{execution, manifest};
+
+    /// Times the key functions of the toolkit on synthetic manifests of the given payload sizes.
+    /// Execution analysis can not be benchmarked without a preview receipt, so it is only timed
+    /// for the manifest and receipt pairs passed in `execution_analysis_inputs`. Not available on
+    /// `wasm32` where [`Instant::now`] panics for the lack of a clock.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn benchmark(
+        configuration: &BenchmarkConfiguration,
+        execution_analysis_inputs: &[(&TransactionManifestV1, &VersionedTransactionReceipt)],
+    ) -> BenchmarkReport {
+        let iterations = configuration.iterations.max(1);
+        let mut results = vec![];
+
+        for payload_size in configuration.payload_sizes.iter().copied() {
+            let manifest = synthetic_manifest(payload_size);
+            let instruction_count = manifest.instructions.len();
+            let compiled = manifest::compile(&manifest).expect("Synthetic manifest must compile");
+
+            results.push(measure(
+                BenchmarkedFunction::Compile,
+                instruction_count,
+                iterations,
+                || {
+                    black_box(manifest::compile(&manifest).ok());
+                },
+            ));
+            results.push(measure(
+                BenchmarkedFunction::Decompile,
+                instruction_count,
+                iterations,
+                || {
+                    black_box(manifest::decompile(&compiled).ok());
+                },
+            ));
+            results.push(measure(
+                BenchmarkedFunction::StaticValidation,
+                instruction_count,
+                iterations,
+                || {
+                    black_box(manifest::statically_validate(&manifest).ok());
+                },
+            ));
+        }
+
+        for (manifest, receipt) in execution_analysis_inputs {
+            let Ok(receipt) = execution::ExecutionAnalysisTransactionReceipt::new(receipt) else {
+                continue;
+            };
+            results.push(measure(
+                BenchmarkedFunction::ExecutionAnalysis,
+                manifest.instructions.len(),
+                iterations,
+                || {
+                    black_box(execution::analyze(&manifest.instructions, &receipt).ok());
+                },
+            ));
+        }
+
+        BenchmarkReport {
+            toolkit_version: env!("CARGO_PKG_VERSION").into(),
+            results,
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct BenchmarkConfiguration {
+        /// The number of withdraw instructions in each of the synthetic manifests.
+        pub payload_sizes: Vec<usize>,
+        pub iterations: u32,
+    }
+
+    impl Default for BenchmarkConfiguration {
+        fn default() -> Self {
+            Self {
+                payload_sizes: vec![1, 10, 100, 1000],
+                iterations: 100,
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub enum BenchmarkedFunction {
+        Compile,
+        Decompile,
+        StaticValidation,
+        ExecutionAnalysis,
+    }
+
+    impl BenchmarkedFunction {
+        pub fn name(&self) -> &'static str {
+            match self {
+                Self::Compile => "compile",
+                Self::Decompile => "decompile",
+                Self::StaticValidation => "static_validation",
+                Self::ExecutionAnalysis => "execution_analysis",
+            }
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct BenchmarkResult {
+        pub function: BenchmarkedFunction,
+        pub instruction_count: usize,
+        pub iterations: u32,
+        pub mean: Duration,
+        pub min: Duration,
+        pub max: Duration,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct BenchmarkReport {
+        pub toolkit_version: String,
+        pub results: Vec<BenchmarkResult>,
+    }
+
+    impl BenchmarkReport {
+        /// Serializes the report into JSON with all timings in nanoseconds so that reports from
+        /// different toolkit releases can be compared by tooling.
+        pub fn to_json(&self) -> String {
+            let results = self
+                .results
+                .iter()
+                .map(|result| {
+                    serde_json::json!({
+                        "function": result.function.name(),
+                        "instruction_count": result.instruction_count,
+                        "iterations": result.iterations,
+                        "mean_nanoseconds": result.mean.as_nanos() as u64,
+                        "min_nanoseconds": result.min.as_nanos() as u64,
+                        "max_nanoseconds": result.max.as_nanos() as u64,
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            serde_json::json!({
+                "toolkit_version": self.toolkit_version,
+                "results": results,
+            })
+            .to_string()
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn measure<F>(
+        function: BenchmarkedFunction,
+        instruction_count: usize,
+        iterations: u32,
+        mut callback: F,
+    ) -> BenchmarkResult
+    where
+        F: FnMut(),
+    {
+        let mut total = Duration::ZERO;
+        let mut min = Duration::MAX;
+        let mut max = Duration::ZERO;
+
+        for _ in 0..iterations {
+            let start = Instant::now();
+            callback();
+            let elapsed = start.elapsed();
+
+            total += elapsed;
+            min = min.min(elapsed);
+            max = max.max(elapsed);
+        }
+
+        BenchmarkResult {
+            function,
+            instruction_count,
+            iterations,
+            mean: total / iterations,
+            min,
+            max,
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn synthetic_manifest(payload_size: usize) -> TransactionManifestV1 {
+        let public_key = Secp256k1PrivateKey::from_u64(1).unwrap().public_key();
+        let account = ComponentAddress::virtual_account_from_public_key(&public_key);
+
+        let mut builder = ManifestBuilder::new().lock_fee(account, dec!("10"));
+        for _ in 0..payload_size {
+            builder = builder.withdraw_from_account(account, XRD, dec!("1"));
+        }
+        builder
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build()
+    }
+}
@@ -19,12 +19,23 @@ use radix_engine::utils::*;
 use sbor::*;
 use scrypto::prelude::*;
 use transaction::errors::*;
+use transaction::manifest::CompileError;
 use transaction::prelude::*;
 use transaction::validation::*;
 
 use crate::instruction_visitor::core::traverser::traverse;
+use crate::instruction_visitor::visitors::access_controller_visitor::*;
 use crate::instruction_visitor::visitors::account_interactions_visitor::*;
+use crate::instruction_visitor::visitors::addresses_by_role_visitor::*;
+use crate::instruction_visitor::visitors::authorized_depositor_badges_visitor::*;
 use crate::instruction_visitor::visitors::identity_interactions_visitor::IdentityInteractionsVisitor;
+use crate::instruction_visitor::visitors::maximum_outflows_visitor::*;
+use crate::instruction_visitor::visitors::non_account_transfers_visitor::*;
+use crate::instruction_visitor::visitors::required_badges_visitor::*;
+use crate::instruction_visitor::visitors::required_signers_visitor::*;
+use crate::instruction_visitor::visitors::transaction_type::reserved_instructions::*;
+use crate::instruction_visitor::visitors::worktop_guarantees_visitor::*;
+use crate::instruction_visitor::visitors::worktop_simulator_visitor::*;
 use crate::models::node_id::TypedNodeId;
 use crate::sbor::indexed_manifest_value::*;
 
@@ -68,6 +79,35 @@ pub fn extract_addresses(instructions: &[InstructionV1]) -> (HashSet<TypedNodeId
     (static_addresses, named_addresses)
 }
 
+/// Extracts the addresses in the instructions grouped by the role they play in a single pass over
+/// the instructions.
+pub fn extract_addresses_by_role(instructions: &[InstructionV1]) -> AddressesByRole {
+    let mut account_interactions_visitor = AccountInteractionsVisitor::default();
+    let mut addresses_by_role_visitor = AddressesByRoleVisitor::default();
+    traverse(
+        instructions,
+        &mut [
+            &mut account_interactions_visitor,
+            &mut addresses_by_role_visitor,
+        ],
+    )
+    .expect("These visitors can't fail");
+
+    let (_, accounts_withdrawn_from, accounts_deposited_into) =
+        account_interactions_visitor.output();
+    let (called_components, called_packages, resources_moved, badges_presented) =
+        addresses_by_role_visitor.output();
+
+    AddressesByRole {
+        called_components,
+        called_packages,
+        accounts_withdrawn_from,
+        accounts_deposited_into,
+        resources_moved,
+        badges_presented,
+    }
+}
+
 pub fn identities_requiring_auth(instructions: &[InstructionV1]) -> HashSet<ComponentAddress> {
     let mut visitor = IdentityInteractionsVisitor::default();
     traverse(instructions, &mut [&mut visitor]).expect("This visitor can't fail");
@@ -95,8 +135,673 @@ pub fn accounts_deposited_into(instructions: &[InstructionV1]) -> HashSet<Compon
     accounts_deposited_into
 }
 
+/// Classifies the calls made to access controllers by the instructions into the steps of the access
+/// controller lifecycle (e.g. initiating, confirming or cancelling a recovery).
+pub fn access_controller_interactions(
+    instructions: &[InstructionV1],
+) -> Vec<AccessControllerInteraction> {
+    let mut visitor = AccessControllerVisitor::default();
+    traverse(instructions, &mut [&mut visitor]).expect("This visitor can't fail");
+    visitor.output()
+}
+
+/// Lists, per instruction, the proofs created and the badges that must be present in the auth zone
+/// for the instruction to succeed.
+pub fn required_badges(instructions: &[InstructionV1]) -> Vec<InstructionBadgeRequirements> {
+    let mut visitor = RequiredBadgesVisitor::default();
+    traverse(instructions, &mut [&mut visitor]).expect("This visitor can't fail");
+    visitor.output()
+}
+
+/// Lists the accounts, identities and access controllers whose auth is required by the
+/// instructions along with the methods that triggered the requirement.
+pub fn required_signers(instructions: &[InstructionV1]) -> Vec<RequiredSigner> {
+    let mut visitor = RequiredSignersVisitor::default();
+    traverse(instructions, &mut [&mut visitor]).expect("This visitor can't fail");
+    visitor.output()
+}
+
+/// Lists the instructions that call reserved functionality (e.g. locking fees or updating account
+/// settings) along with their index and the decoded arguments of the call.
+pub fn reserved_instructions(instructions: &[InstructionV1]) -> Vec<ReservedInstructionUsage> {
+    let mut visitor = ReservedInstructionsVisitor::default();
+    traverse(instructions, &mut [&mut visitor]).expect("This visitor can't fail");
+    let (_, usages) = visitor.output_with_arguments();
+    usages
+}
+
+/// Lists the guarantees made on the worktop contents through `ASSERT_WORKTOP_CONTAINS*`
+/// instructions along with their index.
+pub fn worktop_guarantees(instructions: &[InstructionV1]) -> Vec<WorktopGuarantee> {
+    let mut visitor = WorktopGuaranteesVisitor::default();
+    traverse(instructions, &mut [&mut visitor]).expect("This visitor can't fail");
+    visitor.output()
+}
+
+/// Lists the deposits into accounts which present an authorized depositor badge along with their
+/// index, see [`AuthorizedDepositorBadgesVisitor`].
+pub fn authorized_depositor_badges(
+    instructions: &[InstructionV1],
+) -> Vec<AuthorizedDepositorBadgeUsage> {
+    let mut visitor = AuthorizedDepositorBadgesVisitor::default();
+    traverse(instructions, &mut [&mut visitor]).expect("This visitor can't fail");
+    visitor.output()
+}
+
+/// Computes the maximum amount of each resource that could flow out of each of the accounts as a
+/// result of the instructions, see [`MaximumOutflowsVisitor`]. Non-fungible outflows are given as
+/// the number of non-fungibles. Returns [`None`] if the outflows are unbounded.
+pub fn maximum_outflows(
+    instructions: &[InstructionV1],
+    accounts: HashSet<ComponentAddress>,
+) -> Option<HashMap<ComponentAddress, HashMap<ResourceAddress, Decimal>>> {
+    let mut visitor = MaximumOutflowsVisitor::new(accounts);
+    traverse(instructions, &mut [&mut visitor]).expect("This visitor can't fail");
+    visitor.output()
+}
+
+/// Predicts the deposits made into each of the accounts by simulating the worktop through the
+/// instructions, see [`WorktopSimulatorVisitor`]. Used when no preview receipt is available.
+pub fn simulated_deposits(
+    instructions: &[InstructionV1],
+) -> IndexMap<ComponentAddress, Vec<SimulatedDeposit>> {
+    let mut visitor = WorktopSimulatorVisitor::new();
+    traverse(instructions, &mut [&mut visitor]).expect("This visitor can't fail");
+    visitor.output()
+}
+
+/// Finds the instructions passing the entire worktop or buckets to components that are not
+/// accounts, see [`NonAccountTransfersVisitor`].
+pub fn non_account_transfers(instructions: &[InstructionV1]) -> Vec<NonAccountTransfer> {
+    let mut visitor = NonAccountTransfersVisitor::default();
+    traverse(instructions, &mut [&mut visitor]).expect("This visitor can't fail");
+    visitor.output()
+}
+
+/// Collects the comments of a manifest string along with the index of the instruction that they're
+/// attached to so that they can be re-attached after the manifest goes through a conversion that
+/// drops them (e.g. being compiled and later decompiled), see [`attach_comments`].
+///
+/// A comment is attached to the instruction that follows it. Comments on the same line as the end
+/// of an instruction are attached to that instruction as inline comments. Comments that appear in
+/// the middle of an instruction are attached before that instruction.
+pub fn extract_comments(manifest: &str) -> Vec<ManifestComment> {
+    let mut comments = vec![];
+    let mut instruction_index = 0;
+    let mut is_on_instruction_end_line = false;
+
+    let mut characters = manifest.chars().peekable();
+    while let Some(character) = characters.next() {
+        match character {
+            '"' => consume_string_literal(&mut characters, |_| {}),
+            ';' => {
+                instruction_index += 1;
+                is_on_instruction_end_line = true;
+            }
+            '\n' => is_on_instruction_end_line = false,
+            '#' => {
+                let mut text = String::new();
+                while let Some(character) = characters.next_if(|character| *character != '\n') {
+                    text.push(character)
+                }
+                if text.ends_with('\r') {
+                    text.pop();
+                }
+
+                if is_on_instruction_end_line {
+                    comments.push(ManifestComment {
+                        instruction_index: instruction_index - 1,
+                        text,
+                        position: ManifestCommentPosition::Inline,
+                    })
+                } else {
+                    comments.push(ManifestComment {
+                        instruction_index,
+                        text,
+                        position: ManifestCommentPosition::Before,
+                    })
+                }
+            }
+            _ => {}
+        }
+    }
+
+    comments
+}
+
+/// Re-attaches the comments collected by [`extract_comments`] to a manifest string, typically one
+/// obtained by decompiling the instructions of the manifest that the comments were collected from.
+/// Comments attached to instructions past the end of the manifest are appended at its end.
+pub fn attach_comments(manifest: &str, comments: &[ManifestComment]) -> String {
+    let push_comments =
+        |output: &mut String, instruction_index: usize, position: ManifestCommentPosition| {
+            for comment in comments.iter().filter(|comment| {
+                comment.instruction_index == instruction_index && comment.position == position
+            }) {
+                match position {
+                    ManifestCommentPosition::Before => {
+                        output.push('#');
+                        output.push_str(&comment.text);
+                        output.push('\n');
+                    }
+                    ManifestCommentPosition::Inline => {
+                        output.push_str(" #");
+                        output.push_str(&comment.text);
+                    }
+                }
+            }
+        };
+
+    let mut output = String::with_capacity(manifest.len());
+    let mut instruction_index = 0;
+    push_comments(
+        &mut output,
+        instruction_index,
+        ManifestCommentPosition::Before,
+    );
+
+    let mut characters = manifest.chars().peekable();
+    while let Some(character) = characters.next() {
+        output.push(character);
+        match character {
+            '"' => consume_string_literal(&mut characters, |character| output.push(character)),
+            ';' => {
+                push_comments(
+                    &mut output,
+                    instruction_index,
+                    ManifestCommentPosition::Inline,
+                );
+                if let Some(character) = characters.next_if_eq(&'\n') {
+                    output.push(character)
+                } else {
+                    output.push('\n')
+                }
+                instruction_index += 1;
+                push_comments(
+                    &mut output,
+                    instruction_index,
+                    ManifestCommentPosition::Before,
+                );
+            }
+            _ => {}
+        }
+    }
+
+    for comment in comments.iter().filter(|comment| {
+        comment.instruction_index > instruction_index
+            || (comment.instruction_index == instruction_index
+                && comment.position == ManifestCommentPosition::Inline)
+    }) {
+        output.push('#');
+        output.push_str(&comment.text);
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Maps each of the instructions of a manifest string, typically one obtained by decompiling the
+/// instructions, to the range of lines that it spans so that findings which reference instruction
+/// indices can be highlighted in the displayed manifest. Comments and blank lines between the
+/// instructions are not a part of any range.
+pub fn instruction_line_ranges(manifest: &str) -> Vec<InstructionLineRange> {
+    let mut line_ranges = vec![];
+    let mut line = 0;
+    let mut first_line = None;
+
+    let mut characters = manifest.chars().peekable();
+    while let Some(character) = characters.next() {
+        match character {
+            '\n' => line += 1,
+            '#' => while characters.next_if(|character| *character != '\n').is_some() {},
+            character if character.is_whitespace() => {}
+            character => {
+                let instruction_first_line = *first_line.get_or_insert(line);
+                match character {
+                    '"' => consume_string_literal(&mut characters, |character| {
+                        if character == '\n' {
+                            line += 1
+                        }
+                    }),
+                    ';' => {
+                        line_ranges.push(InstructionLineRange {
+                            instruction_index: line_ranges.len(),
+                            first_line: instruction_first_line,
+                            last_line: line,
+                        });
+                        first_line = None;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    line_ranges
+}
+
+/// Converts the error encountered when compiling a manifest string into a diagnostic that can be
+/// surfaced by editors, locating the offending token in the manifest and, where feasible, suggesting
+/// what it was likely meant to be.
+///
+/// The location, token, expected token classes, and suggestion are determined on a best-effort
+/// basis by scanning the manifest string for misspelled instruction names, misspelled value kinds,
+/// missing semicolons, and unterminated string literals. When none of them are found, only the kind
+/// and message of the error are reported.
+pub fn diagnose_compile_error(manifest: &str, error: &CompileError) -> ManifestCompileDiagnostic {
+    let kind = match error {
+        CompileError::LexerError(..) => ManifestCompileErrorKind::LexerError,
+        CompileError::ParserError(..) => ManifestCompileErrorKind::ParserError,
+        CompileError::GeneratorError(..) => ManifestCompileErrorKind::GeneratorError,
+    };
+    let message = format!("{error:?}");
+
+    match find_manifest_issue(manifest) {
+        Some(ManifestIssue {
+            span,
+            token,
+            expected,
+            suggestion,
+        }) => ManifestCompileDiagnostic {
+            kind,
+            message,
+            span: Some(span),
+            token,
+            expected,
+            suggestion,
+        },
+        None => ManifestCompileDiagnostic {
+            kind,
+            message,
+            span: None,
+            token: None,
+            expected: vec![],
+            suggestion: None,
+        },
+    }
+}
+
+/// Scans a manifest string for the first issue that can be located without relying on the internals
+/// of the manifest compiler.
+fn find_manifest_issue(manifest: &str) -> Option<ManifestIssue> {
+    let characters = manifest.chars().collect::<Vec<_>>();
+
+    let mut index = 0;
+    let mut position = ManifestPosition { line: 0, column: 0 };
+    let mut last_token_end = position;
+    let mut is_expecting_instruction = true;
+    let mut depth = 0usize;
+    let mut type_arguments_depth = 0usize;
+
+    let advance = |index: &mut usize, position: &mut ManifestPosition| {
+        if characters[*index] == '\n' {
+            position.line += 1;
+            position.column = 0;
+        } else {
+            position.column += 1;
+        }
+        *index += 1;
+    };
+
+    while index < characters.len() {
+        let start = position;
+        match characters[index] {
+            '#' => {
+                while index < characters.len() && characters[index] != '\n' {
+                    advance(&mut index, &mut position)
+                }
+                continue;
+            }
+            character if character.is_whitespace() => {
+                advance(&mut index, &mut position);
+                continue;
+            }
+            '"' => {
+                advance(&mut index, &mut position);
+                let mut is_terminated = false;
+                while index < characters.len() {
+                    let character = characters[index];
+                    advance(&mut index, &mut position);
+                    match character {
+                        '\\' if index < characters.len() => advance(&mut index, &mut position),
+                        '"' => {
+                            is_terminated = true;
+                            break;
+                        }
+                        _ => {}
+                    }
+                }
+                if !is_terminated {
+                    return Some(ManifestIssue {
+                        span: ManifestSpan {
+                            start,
+                            end: position,
+                        },
+                        token: None,
+                        expected: vec![ManifestTokenClass::ClosingQuote],
+                        suggestion: Some("\"".to_owned()),
+                    });
+                }
+            }
+            character if character.is_ascii_alphabetic() || character == '_' => {
+                let mut identifier = String::new();
+                while index < characters.len()
+                    && (characters[index].is_ascii_alphanumeric() || characters[index] == '_')
+                {
+                    identifier.push(characters[index]);
+                    advance(&mut index, &mut position);
+                }
+                let span = ManifestSpan {
+                    start,
+                    end: position,
+                };
+
+                if type_arguments_depth > 0 {
+                    // Type arguments hold value kinds and enum discriminators, both of which are
+                    // left for the compiler to validate.
+                } else if is_expecting_instruction {
+                    is_expecting_instruction = false;
+                    if !MANIFEST_INSTRUCTION_NAMES.contains(&identifier.as_str()) {
+                        return Some(ManifestIssue {
+                            span,
+                            suggestion: closest_match(&identifier, MANIFEST_INSTRUCTION_NAMES),
+                            token: Some(identifier),
+                            expected: vec![ManifestTokenClass::InstructionName],
+                        });
+                    }
+                } else if depth == 0 && MANIFEST_INSTRUCTION_NAMES.contains(&identifier.as_str()) {
+                    return Some(ManifestIssue {
+                        span: ManifestSpan {
+                            start: last_token_end,
+                            end: last_token_end,
+                        },
+                        token: Some(identifier),
+                        expected: vec![ManifestTokenClass::Semicolon],
+                        suggestion: Some(";".to_owned()),
+                    });
+                } else if !MANIFEST_VALUE_KIND_NAMES.contains(&identifier.as_str()) {
+                    return Some(ManifestIssue {
+                        span,
+                        suggestion: closest_match(&identifier, MANIFEST_VALUE_KIND_NAMES),
+                        token: Some(identifier),
+                        expected: vec![ManifestTokenClass::ValueKind],
+                    });
+                }
+            }
+            character if character.is_ascii_digit() => {
+                while index < characters.len()
+                    && (characters[index].is_ascii_alphanumeric() || characters[index] == '.')
+                {
+                    advance(&mut index, &mut position)
+                }
+            }
+            character => {
+                advance(&mut index, &mut position);
+                match character {
+                    '(' | '[' => depth += 1,
+                    ')' | ']' => depth = depth.saturating_sub(1),
+                    '<' => type_arguments_depth += 1,
+                    '>' => type_arguments_depth = type_arguments_depth.saturating_sub(1),
+                    // The arrow separating the keys and values of map entries.
+                    '=' if characters.get(index) == Some(&'>') => {
+                        advance(&mut index, &mut position)
+                    }
+                    ';' if depth == 0 && type_arguments_depth == 0 => {
+                        is_expecting_instruction = true
+                    }
+                    _ => {}
+                }
+            }
+        }
+        last_token_end = position;
+    }
+
+    if is_expecting_instruction {
+        None
+    } else {
+        Some(ManifestIssue {
+            span: ManifestSpan {
+                start: last_token_end,
+                end: last_token_end,
+            },
+            token: None,
+            expected: vec![ManifestTokenClass::Semicolon],
+            suggestion: Some(";".to_owned()),
+        })
+    }
+}
+
+/// Finds the candidate closest to the given token by case-insensitive edit distance, provided that
+/// it's close enough for the token to plausibly be a misspelling of it.
+fn closest_match(token: &str, candidates: &[&str]) -> Option<String> {
+    let token = token.to_ascii_lowercase().chars().collect::<Vec<_>>();
+    candidates
+        .iter()
+        .map(|candidate| {
+            let candidate_characters = candidate.to_ascii_lowercase().chars().collect::<Vec<_>>();
+            (edit_distance(&token, &candidate_characters), *candidate)
+        })
+        .filter(|(distance, candidate)| *distance <= usize::max(1, candidate.len() / 3))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate.to_owned())
+}
+
+/// The Levenshtein distance between two sequences of characters.
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut previous_row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a_character) in a.iter().enumerate() {
+        let mut current_row = vec![i + 1; b.len() + 1];
+        for (j, b_character) in b.iter().enumerate() {
+            let substitution_cost = usize::from(a_character != b_character);
+            current_row[j + 1] = (previous_row[j] + substitution_cost)
+                .min(previous_row[j + 1] + 1)
+                .min(current_row[j] + 1);
+        }
+        previous_row = current_row;
+    }
+    previous_row[b.len()]
+}
+
+/// Consumes the characters of a string literal whose opening quote has already been consumed up to
+/// and including the closing quote, taking escaped quotes into account. Each of the consumed
+/// characters is passed to the callback.
+fn consume_string_literal(
+    characters: &mut std::iter::Peekable<std::str::Chars>,
+    mut callback: impl FnMut(char),
+) {
+    while let Some(character) = characters.next() {
+        callback(character);
+        match character {
+            '\\' => {
+                if let Some(character) = characters.next() {
+                    callback(character)
+                }
+            }
+            '"' => break,
+            _ => {}
+        }
+    }
+}
+
+/// A comment of a manifest string and the instruction that it's attached to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ManifestComment {
+    pub instruction_index: usize,
+    /// The text of the comment without the leading `#`.
+    pub text: String,
+    pub position: ManifestCommentPosition,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ManifestCommentPosition {
+    /// The comment is on its own line before the instruction.
+    Before,
+    /// The comment is on the same line as the end of the instruction.
+    Inline,
+}
+
+/// The lines of a manifest string that an instruction spans. The lines are zero-based and the range
+/// is inclusive of both ends.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InstructionLineRange {
+    pub instruction_index: usize,
+    pub first_line: usize,
+    pub last_line: usize,
+}
+
 #[derive(Clone, Debug)]
 pub enum InstructionValidationError {
     TransactionValidationError(TransactionValidationError),
     LocatedInstructionSchemaValidationError(LocatedInstructionSchemaValidationError),
 }
+
+/// A compilation error of a manifest string along with the information needed to point out and fix
+/// the offending part of the manifest, see [`diagnose_compile_error`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ManifestCompileDiagnostic {
+    pub kind: ManifestCompileErrorKind,
+    /// The debug representation of the underlying compile error.
+    pub message: String,
+    /// The location of the offending token. A span whose start and end are equal is the location at
+    /// which a token is missing.
+    pub span: Option<ManifestSpan>,
+    pub token: Option<String>,
+    pub expected: Vec<ManifestTokenClass>,
+    /// The text that the span is likely meant to be replaced with.
+    pub suggestion: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ManifestCompileErrorKind {
+    LexerError,
+    ParserError,
+    GeneratorError,
+}
+
+/// A range of a manifest string that's inclusive of its start and exclusive of its end.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ManifestSpan {
+    pub start: ManifestPosition,
+    pub end: ManifestPosition,
+}
+
+/// A zero-based line and column, in characters, of a manifest string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ManifestPosition {
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ManifestTokenClass {
+    InstructionName,
+    ValueKind,
+    Semicolon,
+    ClosingQuote,
+}
+
+struct ManifestIssue {
+    span: ManifestSpan,
+    token: Option<String>,
+    expected: Vec<ManifestTokenClass>,
+    suggestion: Option<String>,
+}
+
+const MANIFEST_INSTRUCTION_NAMES: &[&str] = &[
+    "TAKE_FROM_WORKTOP",
+    "TAKE_NON_FUNGIBLES_FROM_WORKTOP",
+    "TAKE_ALL_FROM_WORKTOP",
+    "RETURN_TO_WORKTOP",
+    "ASSERT_WORKTOP_CONTAINS",
+    "ASSERT_WORKTOP_CONTAINS_ANY",
+    "ASSERT_WORKTOP_CONTAINS_NON_FUNGIBLES",
+    "POP_FROM_AUTH_ZONE",
+    "PUSH_TO_AUTH_ZONE",
+    "CREATE_PROOF_FROM_AUTH_ZONE_OF_AMOUNT",
+    "CREATE_PROOF_FROM_AUTH_ZONE_OF_NON_FUNGIBLES",
+    "CREATE_PROOF_FROM_AUTH_ZONE_OF_ALL",
+    "DROP_AUTH_ZONE_PROOFS",
+    "DROP_AUTH_ZONE_REGULAR_PROOFS",
+    "DROP_AUTH_ZONE_SIGNATURE_PROOFS",
+    "CREATE_PROOF_FROM_BUCKET_OF_AMOUNT",
+    "CREATE_PROOF_FROM_BUCKET_OF_NON_FUNGIBLES",
+    "CREATE_PROOF_FROM_BUCKET_OF_ALL",
+    "BURN_RESOURCE",
+    "CLONE_PROOF",
+    "DROP_PROOF",
+    "DROP_NAMED_PROOFS",
+    "DROP_ALL_PROOFS",
+    "CALL_FUNCTION",
+    "CALL_METHOD",
+    "CALL_ROYALTY_METHOD",
+    "CALL_METADATA_METHOD",
+    "CALL_ROLE_ASSIGNMENT_METHOD",
+    "CALL_DIRECT_VAULT_METHOD",
+    "ALLOCATE_GLOBAL_ADDRESS",
+    "PUBLISH_PACKAGE",
+    "PUBLISH_PACKAGE_ADVANCED",
+    "CREATE_FUNGIBLE_RESOURCE",
+    "CREATE_FUNGIBLE_RESOURCE_WITH_INITIAL_SUPPLY",
+    "CREATE_NON_FUNGIBLE_RESOURCE",
+    "CREATE_NON_FUNGIBLE_RESOURCE_WITH_INITIAL_SUPPLY",
+    "CREATE_ACCESS_CONTROLLER",
+    "CREATE_IDENTITY",
+    "CREATE_IDENTITY_ADVANCED",
+    "CREATE_ACCOUNT",
+    "CREATE_ACCOUNT_ADVANCED",
+    "CREATE_VALIDATOR",
+    "SET_METADATA",
+    "REMOVE_METADATA",
+    "LOCK_METADATA",
+    "SET_COMPONENT_ROYALTY",
+    "LOCK_COMPONENT_ROYALTY",
+    "CLAIM_COMPONENT_ROYALTIES",
+    "CLAIM_PACKAGE_ROYALTIES",
+    "SET_OWNER_ROLE",
+    "LOCK_OWNER_ROLE",
+    "SET_ROLE",
+    "MINT_FUNGIBLE",
+    "MINT_NON_FUNGIBLE",
+    "MINT_RUID_NON_FUNGIBLE",
+    "RECALL_FROM_VAULT",
+    "RECALL_NON_FUNGIBLES_FROM_VAULT",
+    "FREEZE_VAULT",
+    "UNFREEZE_VAULT",
+];
+
+const MANIFEST_VALUE_KIND_NAMES: &[&str] = &[
+    "true",
+    "false",
+    "Bool",
+    "I8",
+    "I16",
+    "I32",
+    "I64",
+    "I128",
+    "U8",
+    "U16",
+    "U32",
+    "U64",
+    "U128",
+    "String",
+    "Enum",
+    "Array",
+    "Tuple",
+    "Map",
+    "Address",
+    "NamedAddress",
+    "AddressReservation",
+    "Bucket",
+    "Proof",
+    "Expression",
+    "Blob",
+    "Decimal",
+    "PreciseDecimal",
+    "NonFungibleLocalId",
+    "NonFungibleGlobalId",
+    "Bytes",
+    "Some",
+    "None",
+    "Ok",
+    "Err",
+];
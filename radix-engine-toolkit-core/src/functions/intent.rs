@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use radix_engine_store_interface::db_key_mapper::*;
+use radix_engine_store_interface::interface::*;
 use sbor::*;
 use scrypto::prelude::*;
 use transaction::errors::*;
@@ -51,3 +53,294 @@ pub fn statically_validate(
         .map_err(TransactionValidationError::PrepareError)
         .and_then(|prepared| validator.validate_intent_v1(&prepared))
 }
+
+/// Determines which of the candidate public keys must produce intent signatures for the manifest
+/// of the intent to be authorized: the keys whose virtual account or virtual identity is a required
+/// signer of the manifest. When the header marks the notary as a signatory the notary public key is
+/// never included since the notary signature already acts as an intent signature.
+pub fn required_signing_keys(
+    intent: &IntentV1,
+    candidate_public_keys: &[PublicKey],
+) -> Vec<PublicKey> {
+    let required_signers = crate::functions::instructions::required_signers(&intent.instructions.0)
+        .into_iter()
+        .map(|required_signer| required_signer.entity_address)
+        .collect::<IndexSet<_>>();
+
+    candidate_public_keys
+        .iter()
+        .filter(|public_key| {
+            !(intent.header.notary_is_signatory && **public_key == intent.header.notary_public_key)
+        })
+        .filter(|public_key| {
+            let virtual_account = ComponentAddress::virtual_account_from_public_key(*public_key);
+            let virtual_identity = ComponentAddress::virtual_identity_from_public_key(*public_key);
+            required_signers.contains(&GlobalAddress::from(virtual_account))
+                || required_signers.contains(&GlobalAddress::from(virtual_identity))
+        })
+        .copied()
+        .collect::<IndexSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// The domain separator used in the derivation of nonces to avoid the derived nonces colliding with
+/// other values derived from the same inputs.
+const NONCE_DERIVATION_DOMAIN: &[u8] = b"radix-engine-toolkit:nonce";
+
+/// Derives a nonce from the given entropy. The nonce is the first four bytes, interpreted as a
+/// little-endian [`u32`], of the Blake2b-256 hash of the domain separator followed by the entropy.
+pub fn nonce_from_entropy(entropy: &[u8]) -> u32 {
+    derive_nonce(&[NONCE_DERIVATION_DOMAIN, entropy])
+}
+
+/// Derives a nonce deterministically from the content of the intent and a key known only to the
+/// caller (e.g. a wallet-specific secret).
+///
+/// The nonce is the first four bytes, interpreted as a little-endian [`u32`], of the Blake2b-256
+/// hash of the domain separator, the compiled intent with its nonce set to zero, the key, and the
+/// attempt number as four little-endian bytes. Since the nonce of the passed intent is ignored the
+/// same content always produces the same nonce for the same attempt, while incrementing the
+/// attempt number on retries produces distinct nonces and thus distinct intent hashes.
+pub fn nonce_from_intent(intent: &IntentV1, key: &[u8], attempt: u32) -> Result<u32, EncodeError> {
+    let mut intent = intent.clone();
+    intent.header.nonce = 0;
+    let compiled_intent = compile(&intent)?;
+
+    Ok(derive_nonce(&[
+        NONCE_DERIVATION_DOMAIN,
+        &compiled_intent,
+        key,
+        &attempt.to_le_bytes(),
+    ]))
+}
+
+fn derive_nonce(parts: &[&[u8]]) -> u32 {
+    let hash = scrypto::prelude::hash(parts.concat());
+    u32::from_le_bytes([hash.0[0], hash.0[1], hash.0[2], hash.0[3]])
+}
+
+/// The parameters of the partitioning scheme used by the transaction tracker to store the status of
+/// intent hashes. Intent hashes are stored in the partition of their expiry epoch, with each
+/// partition covering [`epochs_per_partition`] epochs and the partitions being reused in a circular
+/// manner starting from [`start_partition`] at [`start_epoch`]. These values can be read from the
+/// transaction tracker substate.
+///
+/// [`epochs_per_partition`]: TransactionTrackerPartitioning::epochs_per_partition
+/// [`start_partition`]: TransactionTrackerPartitioning::start_partition
+/// [`start_epoch`]: TransactionTrackerPartitioning::start_epoch
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionTrackerPartitioning {
+    pub start_epoch: Epoch,
+    pub start_partition: u8,
+    pub partition_range_start_inclusive: u8,
+    pub partition_range_end_inclusive: u8,
+    pub epochs_per_partition: u64,
+}
+
+/// The location of the status of an intent hash in the transaction tracker.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionTrackerKey {
+    pub partition_number: PartitionNumber,
+    pub db_partition_key: DbPartitionKey,
+    pub db_sort_key: DbSortKey,
+}
+
+/// Computes the partition and database keys under which the transaction tracker stores the status
+/// of the intent with the given hash and end epoch. [`None`] is returned if the end epoch is outside
+/// of the range of epochs currently covered by the partitions of the tracker.
+pub fn transaction_tracker_key(
+    intent_hash: &Hash,
+    end_epoch_exclusive: Epoch,
+    partitioning: &TransactionTrackerPartitioning,
+) -> Option<TransactionTrackerKey> {
+    let partition_number = transaction_tracker_partition(end_epoch_exclusive, partitioning)?;
+    let partition_number = PartitionNumber(partition_number);
+    let substate_key = SubstateKey::Map(scrypto_encode(intent_hash).ok()?);
+
+    Some(TransactionTrackerKey {
+        partition_number,
+        db_partition_key: SpreadPrefixKeyMapper::to_db_partition_key(
+            TRANSACTION_TRACKER.as_node_id(),
+            partition_number,
+        ),
+        db_sort_key: SpreadPrefixKeyMapper::to_db_sort_key(&substate_key),
+    })
+}
+
+fn transaction_tracker_partition(
+    expiry_epoch: Epoch,
+    TransactionTrackerPartitioning {
+        start_epoch,
+        start_partition,
+        partition_range_start_inclusive,
+        partition_range_end_inclusive,
+        epochs_per_partition,
+    }: &TransactionTrackerPartitioning,
+) -> Option<u8> {
+    let number_of_partitions = (*partition_range_end_inclusive as u64)
+        .checked_sub(*partition_range_start_inclusive as u64)?
+        + 1;
+    let epoch = expiry_epoch.number();
+    let start_epoch = start_epoch.number();
+    let end_epoch_exclusive =
+        start_epoch.checked_add(number_of_partitions.checked_mul(*epochs_per_partition)?)?;
+    if epoch < start_epoch || epoch >= end_epoch_exclusive || *epochs_per_partition == 0 {
+        return None;
+    }
+
+    let partition_number = *start_partition as u64 + (epoch - start_epoch) / epochs_per_partition;
+    let partition_number = if partition_number > *partition_range_end_inclusive as u64 {
+        partition_number - number_of_partitions
+    } else {
+        partition_number
+    };
+    u8::try_from(partition_number).ok()
+}
+
+/// The fields of the transaction header, used to report which of them differ between two intents.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeaderField {
+    NetworkId,
+    StartEpochInclusive,
+    EndEpochExclusive,
+    Nonce,
+    NotaryPublicKey,
+    NotaryIsSignatory,
+    TipPercentage,
+}
+
+/// An entry of the diff between the instructions of two intents. Indices refer to the position of
+/// the instruction in the instructions of the first intent (`index_a`) or the second (`index_b`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InstructionDifference {
+    Unchanged { index_a: usize, index_b: usize },
+    Removed { index_a: usize },
+    Added { index_b: usize },
+}
+
+/// The differences between two intents, e.g. between a transaction request and an updated request
+/// for the same operation sent by a dApp.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IntentComparison {
+    pub changed_header_fields: Vec<HeaderField>,
+    pub message_changed: bool,
+    /// The instructions of both intents in order, aligned on their longest common subsequence.
+    pub instruction_differences: Vec<InstructionDifference>,
+    /// The hashes of the blobs only present in the second intent.
+    pub added_blobs: Vec<Hash>,
+    /// The hashes of the blobs only present in the first intent.
+    pub removed_blobs: Vec<Hash>,
+}
+
+impl IntentComparison {
+    pub fn is_identical(&self) -> bool {
+        self.changed_header_fields.is_empty()
+            && !self.message_changed
+            && self.added_blobs.is_empty()
+            && self.removed_blobs.is_empty()
+            && self
+                .instruction_differences
+                .iter()
+                .all(|difference| matches!(difference, InstructionDifference::Unchanged { .. }))
+    }
+}
+
+/// Compares two intents field by field. Instructions are compared in their manifest form, so an
+/// instruction only counts as unchanged if it also refers to the same buckets, proofs and address
+/// reservations in both intents.
+pub fn compare(intent_a: &IntentV1, intent_b: &IntentV1) -> IntentComparison {
+    let header_a = &intent_a.header;
+    let header_b = &intent_b.header;
+    let changed_header_fields = [
+        (
+            HeaderField::NetworkId,
+            header_a.network_id != header_b.network_id,
+        ),
+        (
+            HeaderField::StartEpochInclusive,
+            header_a.start_epoch_inclusive != header_b.start_epoch_inclusive,
+        ),
+        (
+            HeaderField::EndEpochExclusive,
+            header_a.end_epoch_exclusive != header_b.end_epoch_exclusive,
+        ),
+        (HeaderField::Nonce, header_a.nonce != header_b.nonce),
+        (
+            HeaderField::NotaryPublicKey,
+            header_a.notary_public_key != header_b.notary_public_key,
+        ),
+        (
+            HeaderField::NotaryIsSignatory,
+            header_a.notary_is_signatory != header_b.notary_is_signatory,
+        ),
+        (
+            HeaderField::TipPercentage,
+            header_a.tip_percentage != header_b.tip_percentage,
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(field, changed)| changed.then_some(field))
+    .collect();
+
+    let blob_hashes = |intent: &IntentV1| {
+        intent
+            .blobs
+            .blobs
+            .iter()
+            .map(|blob| scrypto::prelude::hash(&blob.0))
+            .collect::<IndexSet<_>>()
+    };
+    let blobs_a = blob_hashes(intent_a);
+    let blobs_b = blob_hashes(intent_b);
+
+    IntentComparison {
+        changed_header_fields,
+        message_changed: intent_a.message != intent_b.message,
+        instruction_differences: instruction_differences(
+            &intent_a.instructions.0,
+            &intent_b.instructions.0,
+        ),
+        added_blobs: blobs_b.difference(&blobs_a).copied().collect(),
+        removed_blobs: blobs_a.difference(&blobs_b).copied().collect(),
+    }
+}
+
+fn instruction_differences(
+    instructions_a: &[InstructionV1],
+    instructions_b: &[InstructionV1],
+) -> Vec<InstructionDifference> {
+    // The lengths of the longest common subsequences of all of the suffixes of both instruction
+    // lists, which allows the diff to be walked from the start of both lists.
+    let (length_a, length_b) = (instructions_a.len(), instructions_b.len());
+    let mut lengths = vec![vec![0usize; length_b + 1]; length_a + 1];
+    for index_a in (0..length_a).rev() {
+        for index_b in (0..length_b).rev() {
+            lengths[index_a][index_b] = if instructions_a[index_a] == instructions_b[index_b] {
+                lengths[index_a + 1][index_b + 1] + 1
+            } else {
+                lengths[index_a + 1][index_b].max(lengths[index_a][index_b + 1])
+            };
+        }
+    }
+
+    let mut differences = vec![];
+    let (mut index_a, mut index_b) = (0, 0);
+    while index_a < length_a && index_b < length_b {
+        if instructions_a[index_a] == instructions_b[index_b] {
+            differences.push(InstructionDifference::Unchanged { index_a, index_b });
+            index_a += 1;
+            index_b += 1;
+        } else if lengths[index_a + 1][index_b] >= lengths[index_a][index_b + 1] {
+            differences.push(InstructionDifference::Removed { index_a });
+            index_a += 1;
+        } else {
+            differences.push(InstructionDifference::Added { index_b });
+            index_b += 1;
+        }
+    }
+    differences
+        .extend((index_a..length_a).map(|index_a| InstructionDifference::Removed { index_a }));
+    differences.extend((index_b..length_b).map(|index_b| InstructionDifference::Added { index_b }));
+    differences
+}
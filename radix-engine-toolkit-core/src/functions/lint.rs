@@ -0,0 +1,137 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Lints over the effects of executed transactions. Each rule reports a finding of its own type so
+//! that wallets can filter their transaction history by the kind of finding.
+
+use scrypto::prelude::*;
+
+use crate::functions::address;
+use crate::functions::execution::*;
+use crate::utils::network_definition_from_network_id;
+
+pub fn lint(
+    receipt: &ExecutionAnalysisTransactionReceipt,
+    configuration: &LintConfiguration,
+) -> Vec<LintFinding> {
+    lint_resource_movements(
+        &account_resource_movements_from_events(receipt),
+        configuration,
+    )
+}
+
+pub fn lint_resource_movements(
+    movements: &AccountResourceMovements,
+    configuration: &LintConfiguration,
+) -> Vec<LintFinding> {
+    address_poisoning(movements, configuration)
+        .into_iter()
+        .map(LintFinding::AddressPoisoning)
+        .collect()
+}
+
+/// Flags transactions whose only effect is a dust deposit into the accounts of the user from
+/// unknown accounts whose addresses look alike one of the known counterparties of the user. Such
+/// transactions plant a look-alike address in the history of the user in the hope that it is
+/// later copied from there as the recipient of a transfer.
+fn address_poisoning(
+    movements: &AccountResourceMovements,
+    configuration: &LintConfiguration,
+) -> Vec<AddressPoisoningFinding> {
+    let only_dust_deposits_to_owned_accounts = !movements.deposits.is_empty()
+        && movements.deposits.iter().all(|(account, amounts)| {
+            configuration.owned_accounts.contains(account)
+                && amounts
+                    .values()
+                    .all(|amount| *amount <= configuration.dust_threshold)
+        });
+    let only_unknown_senders = !movements.withdraws.is_empty()
+        && movements.withdraws.keys().all(|account| {
+            !configuration.owned_accounts.contains(account)
+                && !configuration.known_counterparties.contains(account)
+        });
+    if !only_dust_deposits_to_owned_accounts || !only_unknown_senders {
+        return vec![];
+    }
+
+    let encoder = AddressBech32Encoder::new(&network_definition_from_network_id(
+        configuration.network_id,
+    ));
+    let encode = |address: &ComponentAddress| encoder.encode(address.as_node_id().as_bytes()).ok();
+
+    let mut recipients = movements.deposits.keys().copied().collect::<Vec<_>>();
+    recipients.sort();
+    let mut senders = movements.withdraws.keys().copied().collect::<Vec<_>>();
+    senders.sort();
+
+    senders
+        .into_iter()
+        .filter_map(|sender| {
+            let encoded_sender = encode(&sender)?;
+            let resembled_counterparty =
+                configuration
+                    .known_counterparties
+                    .iter()
+                    .copied()
+                    .find(|counterparty| {
+                        encode(counterparty).map_or(false, |encoded_counterparty| {
+                            address::is_look_alike(
+                                &encoded_sender,
+                                &encoded_counterparty,
+                                configuration.look_alike_leading_characters,
+                                configuration.look_alike_trailing_characters,
+                            )
+                        })
+                    })?;
+            Some(AddressPoisoningFinding {
+                sender,
+                resembled_counterparty,
+                recipients: recipients.clone(),
+            })
+        })
+        .collect()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintConfiguration {
+    pub network_id: u8,
+    /// The accounts of the user whose transactions are linted.
+    pub owned_accounts: Vec<ComponentAddress>,
+    /// The accounts that the user has previously transacted with.
+    pub known_counterparties: Vec<ComponentAddress>,
+    /// The largest amount of a resource that is considered dust.
+    pub dust_threshold: Decimal,
+    /// The number of leading and trailing characters of the data part of two addresses that must
+    /// match for them to be considered look-alikes.
+    pub look_alike_leading_characters: usize,
+    pub look_alike_trailing_characters: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LintFinding {
+    AddressPoisoning(AddressPoisoningFinding),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AddressPoisoningFinding {
+    /// The unknown account that the dust was withdrawn from.
+    pub sender: ComponentAddress,
+    /// The known counterparty whose address the sender's address resembles.
+    pub resembled_counterparty: ComponentAddress,
+    /// The accounts of the user that received the dust.
+    pub recipients: Vec<ComponentAddress>,
+}
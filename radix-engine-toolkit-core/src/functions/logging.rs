@@ -0,0 +1,316 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Redaction-safe formatting of the transaction models and analysis outputs for logging. Host
+//! applications may log the toolkit's objects through [`ToLogString`] without leaking user data:
+//! signatures and public keys are truncated, messages are redacted down to their size, addresses
+//! are shortened, and instructions are only counted.
+
+use std::fmt::{self, Display, Formatter};
+
+use scrypto::address::AddressBech32Encoder;
+use scrypto::prelude::*;
+use transaction::prelude::*;
+
+use crate::functions::execution::*;
+use crate::instruction_visitor::visitors::fee_locks_visitor::FeePayers;
+use crate::utils::network_definition_from_network_id;
+
+/// The number of leading bytes kept when truncating signatures and public keys.
+pub const TRUNCATED_BYTES_LENGTH: usize = 4;
+/// The number of characters of the data part of an address kept at its start when shortening it.
+pub const SHORTENED_ADDRESS_LEADING_CHARACTERS: usize = 4;
+/// The number of characters of the data part of an address kept at its end when shortening it.
+pub const SHORTENED_ADDRESS_TRAILING_CHARACTERS: usize = 6;
+
+pub trait ToLogString {
+    /// Writes the redacted representation of the object. Addresses are encoded for the network
+    /// with the given id before they're shortened.
+    fn fmt_log(&self, f: &mut Formatter<'_>, network_id: u8) -> fmt::Result;
+
+    /// A [`Display`]able wrapper of the object that writes its redacted representation.
+    fn log_display(&self, network_id: u8) -> LogDisplay<'_, Self> {
+        LogDisplay {
+            value: self,
+            network_id,
+        }
+    }
+
+    fn to_log_string(&self, network_id: u8) -> String {
+        self.log_display(network_id).to_string()
+    }
+}
+
+pub struct LogDisplay<'a, T: ToLogString + ?Sized> {
+    value: &'a T,
+    network_id: u8,
+}
+
+impl<'a, T: ToLogString + ?Sized> Display for LogDisplay<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.value.fmt_log(f, self.network_id)
+    }
+}
+
+impl ToLogString for IntentV1 {
+    fn fmt_log(&self, f: &mut Formatter<'_>, network_id: u8) -> fmt::Result {
+        let TransactionHeaderV1 {
+            network_id: header_network_id,
+            start_epoch_inclusive,
+            end_epoch_exclusive,
+            nonce,
+            notary_public_key,
+            notary_is_signatory,
+            tip_percentage,
+        } = &self.header;
+        write!(
+            f,
+            "IntentV1 {{ network_id: {}, epochs: {}..{}, nonce: {}, notary_public_key: {}, \
+             notary_is_signatory: {}, tip_percentage: {}, instructions: {}, blobs: {}, message: ",
+            header_network_id,
+            start_epoch_inclusive.number(),
+            end_epoch_exclusive.number(),
+            nonce,
+            notary_public_key.log_display(network_id),
+            notary_is_signatory,
+            tip_percentage,
+            self.instructions.0.len(),
+            self.blobs.blobs.len(),
+        )?;
+        self.message.fmt_log(f, network_id)?;
+        write!(f, " }}")
+    }
+}
+
+impl ToLogString for SignedIntentV1 {
+    fn fmt_log(&self, f: &mut Formatter<'_>, network_id: u8) -> fmt::Result {
+        write!(f, "SignedIntentV1 {{ intent: ")?;
+        self.intent.fmt_log(f, network_id)?;
+        write!(f, ", intent_signatures: [")?;
+        for (index, signature) in self.intent_signatures.signatures.iter().enumerate() {
+            if index != 0 {
+                write!(f, ", ")?;
+            }
+            signature.0.fmt_log(f, network_id)?;
+        }
+        write!(f, "] }}")
+    }
+}
+
+impl ToLogString for NotarizedTransactionV1 {
+    fn fmt_log(&self, f: &mut Formatter<'_>, network_id: u8) -> fmt::Result {
+        write!(f, "NotarizedTransactionV1 {{ signed_intent: ")?;
+        self.signed_intent.fmt_log(f, network_id)?;
+        write!(f, ", notary_signature: ")?;
+        self.notary_signature.0.fmt_log(f, network_id)?;
+        write!(f, " }}")
+    }
+}
+
+impl ToLogString for MessageV1 {
+    fn fmt_log(&self, f: &mut Formatter<'_>, _: u8) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "None"),
+            Self::Plaintext(PlaintextMessageV1 { message, .. }) => {
+                let length = match message {
+                    MessageContentsV1::String(string) => string.len(),
+                    MessageContentsV1::Bytes(bytes) => bytes.len(),
+                };
+                write!(f, "Plaintext(<redacted {length} bytes>)")
+            }
+            Self::Encrypted(EncryptedMessageV1 { encrypted, .. }) => {
+                write!(f, "Encrypted(<redacted {} bytes>)", encrypted.0.len())
+            }
+        }
+    }
+}
+
+impl ToLogString for PublicKey {
+    fn fmt_log(&self, f: &mut Formatter<'_>, _: u8) -> fmt::Result {
+        match self {
+            Self::Secp256k1(public_key) => {
+                write!(f, "Secp256k1({})", TruncatedBytes(&public_key.0))
+            }
+            Self::Ed25519(public_key) => write!(f, "Ed25519({})", TruncatedBytes(&public_key.0)),
+        }
+    }
+}
+
+impl ToLogString for SignatureV1 {
+    fn fmt_log(&self, f: &mut Formatter<'_>, _: u8) -> fmt::Result {
+        match self {
+            Self::Secp256k1(signature) => write!(f, "Secp256k1({})", TruncatedBytes(&signature.0)),
+            Self::Ed25519(signature) => write!(f, "Ed25519({})", TruncatedBytes(&signature.0)),
+        }
+    }
+}
+
+impl ToLogString for SignatureWithPublicKeyV1 {
+    fn fmt_log(&self, f: &mut Formatter<'_>, network_id: u8) -> fmt::Result {
+        match self {
+            Self::Secp256k1 { signature } => {
+                write!(f, "Secp256k1({})", TruncatedBytes(&signature.0))
+            }
+            Self::Ed25519 {
+                public_key,
+                signature,
+            } => write!(
+                f,
+                "Ed25519({}, public_key: {})",
+                TruncatedBytes(&signature.0),
+                PublicKey::Ed25519(*public_key).log_display(network_id)
+            ),
+        }
+    }
+}
+
+impl ToLogString for ExecutionAnalysis {
+    fn fmt_log(&self, f: &mut Formatter<'_>, network_id: u8) -> fmt::Result {
+        write!(f, "ExecutionAnalysis {{ transaction_types: [")?;
+        for (index, transaction_type) in self.transaction_types.iter().enumerate() {
+            if index != 0 {
+                write!(f, ", ")?;
+            }
+            transaction_type.fmt_log(f, network_id)?;
+        }
+        write!(
+            f,
+            "], fee_locks: {{ lock: {}, contingent_lock: {} }}, fee_summary: {{ execution_cost: {}, \
+             finalization_cost: {}, storage_expansion_cost: {}, royalty_cost: {}, fee_payers: ",
+            self.fee_locks.lock,
+            self.fee_locks.contingent_lock,
+            self.fee_summary.execution_cost,
+            self.fee_summary.finalization_cost,
+            self.fee_summary.storage_expansion_cost,
+            self.fee_summary.royalty_cost,
+        )?;
+        match &self.fee_summary.fee_payers {
+            FeePayers::None => write!(f, "[]")?,
+            FeePayers::Single(account) => write!(
+                f,
+                "[{}]",
+                ShortenedAddress(account.as_node_id(), network_id)
+            )?,
+            FeePayers::Multiple(accounts) => {
+                write!(f, "[")?;
+                for (index, account) in accounts.iter().enumerate() {
+                    if index != 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", ShortenedAddress(account.as_node_id(), network_id))?;
+                }
+                write!(f, "]")?
+            }
+        }
+        write!(
+            f,
+            " }}, reserved_instructions: {}, required_badges: {}, guarantees: {}, \
+             resource_movement_discrepancies: {}, resource_movement_graph: {{ nodes: {}, edges: {} }} }}",
+            self.reserved_instructions.len(),
+            self.required_badges.len(),
+            self.guarantees.len(),
+            self.resource_movement_discrepancies.len(),
+            self.resource_movement_graph.nodes.len(),
+            self.resource_movement_graph.edges.len(),
+        )
+    }
+}
+
+impl ToLogString for TransactionType {
+    fn fmt_log(&self, f: &mut Formatter<'_>, network_id: u8) -> fmt::Result {
+        match self {
+            Self::SimpleTransfer(transfer) => write!(
+                f,
+                "SimpleTransfer {{ from: {}, to: {} }}",
+                ShortenedAddress(transfer.from.as_node_id(), network_id),
+                ShortenedAddress(transfer.to.as_node_id(), network_id),
+            ),
+            Self::Transfer(transfer) => write!(
+                f,
+                "Transfer {{ from: {}, recipients: {} }}",
+                ShortenedAddress(transfer.from.as_node_id(), network_id),
+                transfer.transfers.len(),
+            ),
+            Self::AccountDepositSettings(..) => write!(f, "AccountDepositSettings"),
+            Self::StakeTransaction(stakes) => write!(f, "Stake {{ stakes: {} }}", stakes.0.len()),
+            Self::UnstakeTransaction(unstakes) => {
+                write!(f, "Unstake {{ unstakes: {} }}", unstakes.0.len())
+            }
+            Self::ClaimStakeTransaction(claims) => {
+                write!(f, "ClaimStake {{ claims: {} }}", claims.0.len())
+            }
+            Self::OwnerKeysUpdate(..) => write!(f, "OwnerKeysUpdate"),
+            Self::EntityControlChanges(..) => write!(f, "EntityControlChanges"),
+            Self::RoyaltyClaim(royalty_claim) => {
+                write!(
+                    f,
+                    "RoyaltyClaim {{ claims: {} }}",
+                    royalty_claim.claims.len()
+                )
+            }
+            Self::Swap(swap) => write!(f, "Swap {{ swaps: {} }}", swap.swaps.len()),
+            Self::GeneralTransaction(..) => write!(f, "GeneralTransaction"),
+        }
+    }
+}
+
+/// Shortens a Bech32m address by keeping its human readable part and the ends of its data part,
+/// e.g. `account_rdx12y7m...q8hzu9`. Strings which are not long enough to be shortened are returned
+/// as they are.
+pub fn shorten_address(address: &str) -> String {
+    let Some((hrp, data)) = address.rsplit_once('1') else {
+        return address.to_owned();
+    };
+    if !data.is_ascii()
+        || data.len()
+            <= SHORTENED_ADDRESS_LEADING_CHARACTERS + SHORTENED_ADDRESS_TRAILING_CHARACTERS
+    {
+        return address.to_owned();
+    }
+    format!(
+        "{}1{}...{}",
+        hrp,
+        &data[..SHORTENED_ADDRESS_LEADING_CHARACTERS],
+        &data[data.len() - SHORTENED_ADDRESS_TRAILING_CHARACTERS..]
+    )
+}
+
+struct ShortenedAddress<'a>(&'a NodeId, u8);
+
+impl<'a> Display for ShortenedAddress<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let encoder = AddressBech32Encoder::new(&network_definition_from_network_id(self.1));
+        match encoder.encode(self.0.as_bytes()) {
+            Ok(address) => write!(f, "{}", shorten_address(&address)),
+            Err(..) => write!(f, "<invalid address>"),
+        }
+    }
+}
+
+struct TruncatedBytes<'a>(&'a [u8]);
+
+impl<'a> Display for TruncatedBytes<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for byte in self.0.iter().take(TRUNCATED_BYTES_LENGTH) {
+            write!(f, "{byte:02x}")?;
+        }
+        if self.0.len() > TRUNCATED_BYTES_LENGTH {
+            write!(f, "...")?;
+        }
+        Ok(())
+    }
+}
@@ -17,15 +17,22 @@
 
 use radix_engine_interface::blueprints::access_controller::*;
 use radix_engine_interface::blueprints::account::*;
+use radix_engine_interface::blueprints::package::*;
 use sbor::*;
 use scrypto::prelude::*;
 use transaction::errors::*;
 use transaction::prelude::*;
 use transaction::validation::*;
 
+use crate::functions::schema::*;
 use crate::instruction_visitor::core::error::InstructionVisitorError;
 use crate::instruction_visitor::core::traverser::traverse;
+use crate::instruction_visitor::visitors::fee_locks_visitor::*;
+use crate::instruction_visitor::visitors::id_allocation_visitor::*;
+use crate::instruction_visitor::visitors::required_signers_visitor::RequiredSigner;
 use crate::instruction_visitor::visitors::transaction_type::transfer_visitor::*;
+use crate::sbor::indexed_manifest_value::IndexedManifestValue;
+use crate::utils::{is_account, to_manifest_type};
 
 pub fn hash(manifest: &TransactionManifestV1) -> Result<Hash, EncodeError> {
     compile(manifest).map(scrypto::prelude::hash)
@@ -197,6 +204,604 @@ pub fn modify(
     })
 }
 
+/// Sets the fee locked by the manifest to the given amount locked against the given account.
+///
+/// * A leading `lock_fee` call to an account is replaced with one to the given account.
+/// * A leading `lock_fee_and_withdraw` (or its non-fungible variant) on the given account has its
+///   amount to lock updated. On any other account it's converted back to a plain withdraw.
+/// * Otherwise, the lock fee is added in the same way as in [`modify`].
+pub fn set_lock_fee(
+    manifest: &TransactionManifestV1,
+    account: ComponentAddress,
+    amount: Decimal,
+) -> Result<TransactionManifestV1, ManifestModificationError> {
+    let mut manifest = manifest.clone();
+
+    if let Some(InstructionV1::CallMethod {
+        address: DynamicGlobalAddress::Static(address),
+        method_name,
+        args,
+    }) = manifest.instructions.first_mut()
+    {
+        if is_account(&*address) {
+            let is_fee_payer = *address == GlobalAddress::from(account);
+            match method_name.as_str() {
+                ACCOUNT_LOCK_FEE_IDENT => {
+                    *address = account.into();
+                    *args = to_manifest_value_and_unwrap!(&AccountLockFeeInput { amount });
+                    return Ok(manifest);
+                }
+                ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => {
+                    let AccountLockFeeAndWithdrawInput {
+                        resource_address,
+                        amount: withdraw_amount,
+                        ..
+                    } = decode_arguments(method_name, args)?;
+
+                    if is_fee_payer {
+                        *args = to_manifest_value_and_unwrap!(&AccountLockFeeAndWithdrawInput {
+                            amount_to_lock: amount,
+                            resource_address,
+                            amount: withdraw_amount
+                        });
+                        return Ok(manifest);
+                    }
+
+                    *method_name = ACCOUNT_WITHDRAW_IDENT.to_owned();
+                    *args = to_manifest_value_and_unwrap!(&AccountWithdrawInput {
+                        resource_address,
+                        amount: withdraw_amount
+                    });
+                }
+                ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                    let AccountLockFeeAndWithdrawNonFungiblesInput {
+                        resource_address,
+                        ids,
+                        ..
+                    } = decode_arguments(method_name, args)?;
+
+                    if is_fee_payer {
+                        *args = to_manifest_value_and_unwrap!(
+                            &AccountLockFeeAndWithdrawNonFungiblesInput {
+                                amount_to_lock: amount,
+                                resource_address,
+                                ids
+                            }
+                        );
+                        return Ok(manifest);
+                    }
+
+                    *method_name = ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT.to_owned();
+                    *args = to_manifest_value_and_unwrap!(&AccountWithdrawNonFungiblesInput {
+                        resource_address,
+                        ids
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+
+    modify(
+        &manifest,
+        TransactionManifestModifications {
+            add_access_controller_proofs: vec![],
+            add_lock_fee: Some((account, amount)),
+            add_assertions: vec![],
+        },
+    )
+}
+
+fn decode_arguments<T: ManifestDecode>(
+    method_name: &str,
+    args: &ManifestValue,
+) -> Result<T, ManifestModificationError> {
+    to_manifest_type(args).ok_or_else(|| ManifestModificationError::InvalidArguments {
+        method_name: method_name.to_owned(),
+        arguments: args.clone(),
+    })
+}
+
+/// Adds the guarantees to the manifest. Each guarantee is inserted as an `ASSERT_WORKTOP_CONTAINS`
+/// instruction at its instruction index. If the instruction currently at that index already
+/// asserts on the same resource then its amount is updated instead, which allows for guarantees
+/// previously added to the manifest to be edited.
+pub fn add_guarantees(
+    manifest: &TransactionManifestV1,
+    mut guarantees: Vec<Guarantee>,
+) -> Result<TransactionManifestV1, ManifestModificationError> {
+    let mut instructions = manifest.instructions.clone();
+
+    // Same as with the assertions in `modify`, the guarantees are applied in descending order of
+    // their instruction index so that the insertions don't shift the indices of the guarantees
+    // that are yet to be applied.
+    guarantees.sort_by(|b, a| a.instruction_index.cmp(&b.instruction_index));
+
+    if let Some(guarantee) = guarantees.first() {
+        if guarantee.instruction_index > instructions.len() {
+            return Err(ManifestModificationError::AssertionIndexOutOfBounds {
+                assertion_index: guarantee.instruction_index,
+                instructions_length: instructions.len(),
+            });
+        }
+    }
+
+    for Guarantee {
+        instruction_index,
+        resource_address,
+        amount,
+    } in guarantees
+    {
+        match instructions.get_mut(instruction_index) {
+            Some(InstructionV1::AssertWorktopContains {
+                resource_address: asserted_resource_address,
+                amount: asserted_amount,
+            }) if *asserted_resource_address == resource_address => *asserted_amount = amount,
+            _ => instructions.insert(
+                instruction_index,
+                InstructionV1::AssertWorktopContains {
+                    resource_address,
+                    amount,
+                },
+            ),
+        }
+    }
+
+    Ok(TransactionManifestV1 {
+        instructions,
+        blobs: manifest.blobs.clone(),
+    })
+}
+
+/// Applies the instruction modifications to the manifest in the order they're given in, with the
+/// index of each modification referring to the instructions as they are after applying all of the
+/// modifications that came before it.
+///
+/// Bucket and proof ids used by the instructions that follow a modification are renumbered to
+/// account for the buckets and proofs created or no longer created by the modified instructions.
+/// An error is returned when this can't be done safely: when a removed bucket or proof is used by
+/// a later instruction or when the modification involves address reservations. The instructions
+/// are validated once all of the modifications have been applied.
+pub fn modify_instructions(
+    manifest: &TransactionManifestV1,
+    modifications: Vec<InstructionModification>,
+) -> Result<TransactionManifestV1, ManifestModificationError> {
+    let mut instructions = manifest.instructions.clone();
+
+    for modification in modifications {
+        match modification {
+            InstructionModification::Insert { index, instruction } => {
+                insert_instruction(&mut instructions, index, instruction)?
+            }
+            InstructionModification::Remove { index } => {
+                remove_instruction(&mut instructions, index)?;
+            }
+            InstructionModification::Replace { index, instruction } => {
+                let existing_instruction = instructions.get(index).ok_or(
+                    ManifestModificationError::InstructionIndexOutOfBounds {
+                        instruction_index: index,
+                        instructions_length: instructions.len(),
+                    },
+                )?;
+
+                // When both instructions create the same kind of id (or none at all) then the ids
+                // of the instructions that follow remain the same and nothing is renumbered.
+                if created_id_kind(existing_instruction) == created_id_kind(&instruction)
+                    && created_id_kind(&instruction) != Some(CreatedIdKind::AddressReservation)
+                {
+                    instructions[index] = instruction;
+                } else {
+                    remove_instruction(&mut instructions, index)?;
+                    insert_instruction(&mut instructions, index, instruction)?;
+                }
+            }
+        }
+    }
+
+    NotarizedTransactionValidator::validate_instructions_v1(&instructions)
+        .map_err(ManifestModificationError::ValidationError)?;
+
+    Ok(TransactionManifestV1 {
+        instructions,
+        blobs: manifest.blobs.clone(),
+    })
+}
+
+fn insert_instruction(
+    instructions: &mut Vec<InstructionV1>,
+    index: usize,
+    instruction: InstructionV1,
+) -> Result<(), ManifestModificationError> {
+    if index > instructions.len() {
+        return Err(ManifestModificationError::InstructionIndexOutOfBounds {
+            instruction_index: index,
+            instructions_length: instructions.len(),
+        });
+    }
+
+    match created_id_kind(&instruction) {
+        Some(CreatedIdKind::AddressReservation) => {
+            return Err(
+                ManifestModificationError::AddressReservationModificationNotSupported {
+                    instruction_index: index,
+                },
+            )
+        }
+        Some(kind) => {
+            // The inserted instruction takes the id of the first instruction after it that creates
+            // an id of the same kind, which along with all of the ids after it is shifted by one.
+            let created_id = count_created_ids(&instructions[..index], kind);
+            for instruction in instructions[index..].iter_mut() {
+                visit_ids(instruction, &mut |id| match id {
+                    ManifestIdMut::Bucket(bucket) if kind == CreatedIdKind::Bucket => {
+                        if bucket.0 >= created_id {
+                            bucket.0 += 1
+                        }
+                    }
+                    ManifestIdMut::Proof(proof) if kind == CreatedIdKind::Proof => {
+                        if proof.0 >= created_id {
+                            proof.0 += 1
+                        }
+                    }
+                    _ => {}
+                })
+            }
+        }
+        None => {}
+    }
+
+    instructions.insert(index, instruction);
+    Ok(())
+}
+
+fn remove_instruction(
+    instructions: &mut Vec<InstructionV1>,
+    index: usize,
+) -> Result<InstructionV1, ManifestModificationError> {
+    let instruction =
+        instructions
+            .get(index)
+            .ok_or(ManifestModificationError::InstructionIndexOutOfBounds {
+                instruction_index: index,
+                instructions_length: instructions.len(),
+            })?;
+
+    match created_id_kind(instruction) {
+        Some(CreatedIdKind::AddressReservation) => {
+            return Err(
+                ManifestModificationError::AddressReservationModificationNotSupported {
+                    instruction_index: index,
+                },
+            )
+        }
+        Some(kind) => {
+            let removed_id = count_created_ids(&instructions[..index], kind);
+
+            let mut is_removed_id_used = false;
+            for instruction in instructions[index + 1..].iter_mut() {
+                visit_ids(instruction, &mut |id| match id {
+                    ManifestIdMut::Bucket(bucket) if kind == CreatedIdKind::Bucket => {
+                        is_removed_id_used |= bucket.0 == removed_id
+                    }
+                    ManifestIdMut::Proof(proof) if kind == CreatedIdKind::Proof => {
+                        is_removed_id_used |= proof.0 == removed_id
+                    }
+                    _ => {}
+                })
+            }
+            if is_removed_id_used {
+                return Err(ManifestModificationError::RemovedIdInUse {
+                    instruction_index: index,
+                });
+            }
+
+            for instruction in instructions[index + 1..].iter_mut() {
+                visit_ids(instruction, &mut |id| match id {
+                    ManifestIdMut::Bucket(bucket) if kind == CreatedIdKind::Bucket => {
+                        if bucket.0 > removed_id {
+                            bucket.0 -= 1
+                        }
+                    }
+                    ManifestIdMut::Proof(proof) if kind == CreatedIdKind::Proof => {
+                        if proof.0 > removed_id {
+                            proof.0 -= 1
+                        }
+                    }
+                    _ => {}
+                })
+            }
+        }
+        None => {}
+    }
+
+    Ok(instructions.remove(index))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CreatedIdKind {
+    Bucket,
+    Proof,
+    AddressReservation,
+}
+
+enum ManifestIdMut<'a> {
+    Bucket(&'a mut ManifestBucket),
+    Proof(&'a mut ManifestProof),
+}
+
+fn created_id_kind(instruction: &InstructionV1) -> Option<CreatedIdKind> {
+    match instruction {
+        InstructionV1::TakeAllFromWorktop { .. }
+        | InstructionV1::TakeFromWorktop { .. }
+        | InstructionV1::TakeNonFungiblesFromWorktop { .. } => Some(CreatedIdKind::Bucket),
+        InstructionV1::PopFromAuthZone { .. }
+        | InstructionV1::CreateProofFromAuthZoneOfAmount { .. }
+        | InstructionV1::CreateProofFromAuthZoneOfNonFungibles { .. }
+        | InstructionV1::CreateProofFromAuthZoneOfAll { .. }
+        | InstructionV1::CreateProofFromBucketOfAmount { .. }
+        | InstructionV1::CreateProofFromBucketOfNonFungibles { .. }
+        | InstructionV1::CreateProofFromBucketOfAll { .. }
+        | InstructionV1::CloneProof { .. } => Some(CreatedIdKind::Proof),
+        InstructionV1::AllocateGlobalAddress { .. } => Some(CreatedIdKind::AddressReservation),
+        _ => None,
+    }
+}
+
+fn count_created_ids(instructions: &[InstructionV1], kind: CreatedIdKind) -> u32 {
+    instructions
+        .iter()
+        .filter(|instruction| created_id_kind(instruction) == Some(kind))
+        .count() as u32
+}
+
+fn visit_ids(instruction: &mut InstructionV1, callback: &mut dyn FnMut(ManifestIdMut)) {
+    match instruction {
+        InstructionV1::ReturnToWorktop { bucket_id }
+        | InstructionV1::CreateProofFromBucketOfAmount { bucket_id, .. }
+        | InstructionV1::CreateProofFromBucketOfNonFungibles { bucket_id, .. }
+        | InstructionV1::CreateProofFromBucketOfAll { bucket_id }
+        | InstructionV1::BurnResource { bucket_id } => callback(ManifestIdMut::Bucket(bucket_id)),
+        InstructionV1::PushToAuthZone { proof_id }
+        | InstructionV1::CloneProof { proof_id }
+        | InstructionV1::DropProof { proof_id } => callback(ManifestIdMut::Proof(proof_id)),
+        InstructionV1::CallFunction { args, .. }
+        | InstructionV1::CallMethod { args, .. }
+        | InstructionV1::CallRoyaltyMethod { args, .. }
+        | InstructionV1::CallMetadataMethod { args, .. }
+        | InstructionV1::CallRoleAssignmentMethod { args, .. }
+        | InstructionV1::CallDirectVaultMethod { args, .. } => visit_value_ids(args, callback),
+        _ => {}
+    }
+}
+
+fn visit_value_ids(value: &mut ManifestValue, callback: &mut dyn FnMut(ManifestIdMut)) {
+    match value {
+        ManifestValue::Custom {
+            value: ManifestCustomValue::Bucket(bucket),
+        } => callback(ManifestIdMut::Bucket(bucket)),
+        ManifestValue::Custom {
+            value: ManifestCustomValue::Proof(proof),
+        } => callback(ManifestIdMut::Proof(proof)),
+        ManifestValue::Enum { fields, .. } | ManifestValue::Tuple { fields } => fields
+            .iter_mut()
+            .for_each(|field| visit_value_ids(field, callback)),
+        ManifestValue::Array { elements, .. } => elements
+            .iter_mut()
+            .for_each(|element| visit_value_ids(element, callback)),
+        ManifestValue::Map { entries, .. } => entries.iter_mut().for_each(|(key, value)| {
+            visit_value_ids(key, callback);
+            visit_value_ids(value, callback);
+        }),
+        _ => {}
+    }
+}
+
+/// Checks the manifest against the policy and returns all of the violations found. The manifest
+/// passes the policy if no violations are returned.
+///
+/// Calls made to named addresses are reported as violations whenever the policy restricts the
+/// packages or components that may be called since there is no way to whitelist entities created
+/// in the same transaction. For the same reason, method calls on resource managers (e.g., mints
+/// and burns) are violations whenever the policy restricts the packages or components, and direct
+/// vault method calls (e.g., recalls and freezes) are violations whenever the policy restricts the
+/// packages, components, or resources.
+pub fn enforce_policy(
+    manifest: &TransactionManifestV1,
+    policy: &ManifestPolicy,
+) -> Vec<PolicyViolation> {
+    let mut violations = vec![];
+    let mut xrd_spend = Decimal::ZERO;
+    let restricts_invocations =
+        policy.allowed_components.is_some() || policy.allowed_packages.is_some();
+
+    for (instruction_index, instruction) in manifest.instructions.iter().enumerate() {
+        // Resources may be moved by both instructions and call arguments, so all of the static
+        // resource addresses in the instruction are checked.
+        if let Some(allowed_resources) = &policy.allowed_resources {
+            let static_addresses = IndexedManifestValue::from_typed(instruction)
+                .static_addresses()
+                .into_iter()
+                .collect::<IndexSet<_>>();
+            for node_id in static_addresses {
+                if let Ok(resource_address) = ResourceAddress::try_from(node_id) {
+                    if !allowed_resources.contains(&resource_address) {
+                        violations.push(PolicyViolation::ResourceNotAllowed {
+                            instruction_index,
+                            resource_address,
+                        })
+                    }
+                }
+            }
+        }
+
+        match instruction {
+            InstructionV1::CallFunction {
+                package_address, ..
+            } => match (package_address, &policy.allowed_packages) {
+                (DynamicPackageAddress::Static(package_address), Some(allowed_packages))
+                    if !allowed_packages.contains(package_address) =>
+                {
+                    violations.push(PolicyViolation::PackageNotAllowed {
+                        instruction_index,
+                        package_address: *package_address,
+                    })
+                }
+                (DynamicPackageAddress::Named(..), Some(..)) => {
+                    violations.push(PolicyViolation::NamedAddressNotAllowed { instruction_index })
+                }
+                _ => {}
+            },
+            InstructionV1::CallMethod {
+                address,
+                method_name,
+                ..
+            }
+            | InstructionV1::CallRoyaltyMethod {
+                address,
+                method_name,
+                ..
+            }
+            | InstructionV1::CallMetadataMethod {
+                address,
+                method_name,
+                ..
+            }
+            | InstructionV1::CallRoleAssignmentMethod {
+                address,
+                method_name,
+                ..
+            } => match address {
+                DynamicGlobalAddress::Static(address) => {
+                    if let Ok(component_address) =
+                        ComponentAddress::try_from(address.as_node_id().as_bytes())
+                    {
+                        if policy
+                            .allowed_components
+                            .as_ref()
+                            .map_or(false, |allowed| !allowed.contains(&component_address))
+                        {
+                            violations.push(PolicyViolation::ComponentNotAllowed {
+                                instruction_index,
+                                component_address,
+                            })
+                        }
+                    } else if let Ok(package_address) =
+                        PackageAddress::try_from(address.as_node_id().as_bytes())
+                    {
+                        if policy
+                            .allowed_packages
+                            .as_ref()
+                            .map_or(false, |allowed| !allowed.contains(&package_address))
+                        {
+                            violations.push(PolicyViolation::PackageNotAllowed {
+                                instruction_index,
+                                package_address,
+                            })
+                        }
+                    } else if let Ok(resource_address) =
+                        ResourceAddress::try_from(address.as_node_id().as_bytes())
+                    {
+                        if restricts_invocations {
+                            violations.push(PolicyViolation::ResourceMethodNotAllowed {
+                                instruction_index,
+                                resource_address,
+                                method_name: method_name.to_owned(),
+                            })
+                        }
+                    }
+                }
+                DynamicGlobalAddress::Named(..) => {
+                    if restricts_invocations {
+                        violations
+                            .push(PolicyViolation::NamedAddressNotAllowed { instruction_index })
+                    }
+                }
+            },
+            InstructionV1::CallDirectVaultMethod {
+                address,
+                method_name,
+                ..
+            } => {
+                if restricts_invocations || policy.allowed_resources.is_some() {
+                    violations.push(PolicyViolation::DirectVaultMethodNotAllowed {
+                        instruction_index,
+                        vault_address: *address,
+                        method_name: method_name.to_owned(),
+                    })
+                }
+            }
+            _ => {}
+        }
+
+        if let InstructionV1::CallMethod {
+            address: DynamicGlobalAddress::Static(address),
+            method_name,
+            args,
+        } = instruction
+        {
+            if is_account(address) {
+                match account_xrd_spend(method_name, args) {
+                    Some(spend) => xrd_spend = xrd_spend.checked_add(spend).unwrap_or(Decimal::MAX),
+                    None => violations.push(PolicyViolation::InvalidArguments {
+                        instruction_index,
+                        method_name: method_name.to_owned(),
+                    }),
+                }
+            }
+        }
+    }
+
+    if let Some(max_xrd_spend) = policy.max_xrd_spend {
+        if xrd_spend > max_xrd_spend {
+            violations.push(PolicyViolation::MaxXrdSpendExceeded {
+                max_xrd_spend,
+                xrd_spend,
+            })
+        }
+    }
+
+    violations
+}
+
+/// The amount of XRD spent by a call to an account method, either by being withdrawn or locked for
+/// fees. Returns [`None`] if the arguments of the call could not be decoded.
+fn account_xrd_spend(method_name: &str, args: &ManifestValue) -> Option<Decimal> {
+    let xrd_amount = |resource_address: ResourceAddress, amount: Decimal| {
+        if resource_address == XRD {
+            amount
+        } else {
+            Decimal::ZERO
+        }
+    };
+
+    let spend = match method_name {
+        ACCOUNT_WITHDRAW_IDENT => {
+            let AccountWithdrawInput {
+                resource_address,
+                amount,
+            } = to_manifest_type(args)?;
+            xrd_amount(resource_address, amount)
+        }
+        ACCOUNT_LOCK_FEE_IDENT => to_manifest_type::<AccountLockFeeInput>(args)?.amount,
+        ACCOUNT_LOCK_CONTINGENT_FEE_IDENT => {
+            to_manifest_type::<AccountLockContingentFeeInput>(args)?.amount
+        }
+        ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => {
+            let AccountLockFeeAndWithdrawInput {
+                amount_to_lock,
+                resource_address,
+                amount,
+            } = to_manifest_type(args)?;
+            amount_to_lock.checked_add(xrd_amount(resource_address, amount))?
+        }
+        ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => {
+            to_manifest_type::<AccountLockFeeAndWithdrawNonFungiblesInput>(args)?.amount_to_lock
+        }
+        _ => Decimal::ZERO,
+    };
+    Some(spend)
+}
+
 #[allow(clippy::type_complexity)]
 pub fn parse_transfer_information(
     manifest: &TransactionManifestV1,
@@ -213,6 +818,325 @@ pub fn parse_transfer_information(
     Ok(transfer_visitor.output())
 }
 
+/// Creates the instructions that lock the given fees against the accounts, allowing for the fee
+/// locking to be split across multiple accounts. Contingent fee locks are created through the
+/// `lock_contingent_fee` method.
+pub fn lock_fee_instructions(fee_locks: &[AccountFeeLock]) -> Vec<InstructionV1> {
+    fee_locks
+        .iter()
+        .map(|fee_lock| InstructionV1::CallMethod {
+            address: DynamicGlobalAddress::Static(fee_lock.account.into()),
+            method_name: if fee_lock.contingent {
+                ACCOUNT_LOCK_CONTINGENT_FEE_IDENT
+            } else {
+                ACCOUNT_LOCK_FEE_IDENT
+            }
+            .to_owned(),
+            args: manifest_args!(fee_lock.amount).into(),
+        })
+        .collect()
+}
+
+pub fn fee_locks(instructions: &[InstructionV1]) -> Vec<AccountFeeLock> {
+    let mut fee_locks_visitor = FeeLocksVisitor::default();
+    traverse(instructions, &mut [&mut fee_locks_visitor]).expect("This visitor can't fail");
+    fee_locks_visitor.output()
+}
+
+/// Checks that every bucket is consumed exactly once, every proof is dropped, and that none of them
+/// are used before they're created or after they're consumed, reporting the instruction at which
+/// each issue is found. All issues are reported rather than only the first.
+pub fn validate_id_allocation(instructions: &[InstructionV1]) -> Vec<IdAllocationDiagnostic> {
+    let mut id_allocation_visitor = IdAllocationVisitor::default();
+    traverse(instructions, &mut [&mut id_allocation_visitor]).expect("This visitor can't fail");
+    id_allocation_visitor.output()
+}
+
+/// Checks whether the fees locked against accounts by the instructions add up to the target fee.
+/// Contingent locks don't count towards the target since they can't pay for a failing transaction,
+/// they're reported separately.
+pub fn validate_fee_locks(
+    instructions: &[InstructionV1],
+    target_fee: Decimal,
+) -> FeeLockValidation {
+    let fee_locks = fee_locks(instructions);
+
+    let sum = |contingent: bool| {
+        fee_locks
+            .iter()
+            .filter(|fee_lock| fee_lock.contingent == contingent)
+            .fold(Decimal::ZERO, |total, fee_lock| {
+                total.checked_add(fee_lock.amount).unwrap_or(Decimal::MAX)
+            })
+    };
+    let total_lock = sum(false);
+    let total_contingent_lock = sum(true);
+    let shortfall = if total_lock >= target_fee {
+        Decimal::ZERO
+    } else {
+        target_fee - total_lock
+    };
+
+    FeeLockValidation {
+        fee_payers: FeePayers::from_fee_locks(&fee_locks),
+        total_lock,
+        total_contingent_lock,
+        shortfall,
+    }
+}
+
+/// Distinguishes the accounts paying the fees of the instructions from the accounts whose assets
+/// move, for transactions where a sponsor pays the fees for a user.
+pub fn fee_sponsorship(instructions: &[InstructionV1]) -> FeeSponsorship {
+    let fee_payers = fee_locks(instructions)
+        .into_iter()
+        .map(|fee_lock| fee_lock.account)
+        .collect::<IndexSet<_>>();
+
+    let mut asset_movers = crate::functions::instructions::accounts_withdrawn_from(instructions)
+        .into_iter()
+        .chain(crate::functions::instructions::accounts_deposited_into(
+            instructions,
+        ))
+        .collect::<IndexSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+    asset_movers.sort();
+
+    let sponsors = fee_payers
+        .iter()
+        .filter(|fee_payer| !asset_movers.contains(fee_payer))
+        .copied()
+        .collect();
+
+    FeeSponsorship {
+        fee_payers: fee_payers.into_iter().collect(),
+        asset_movers,
+        sponsors,
+    }
+}
+
+/// Has the sponsor account lock the fee of the user's manifest, see [`set_lock_fee`] for how any
+/// existing leading fee lock is replaced. The required signers of the sponsored manifest are split
+/// into the sponsor's and the user's so that each party can be asked for its own signatures.
+pub fn sponsor_fee(
+    manifest: &TransactionManifestV1,
+    sponsor: ComponentAddress,
+    amount: Decimal,
+) -> Result<SponsoredManifest, ManifestModificationError> {
+    let manifest = set_lock_fee(manifest, sponsor, amount)?;
+    let (sponsor_signers, user_signers) =
+        crate::functions::instructions::required_signers(&manifest.instructions)
+            .into_iter()
+            .partition(|signer| signer.entity_address == GlobalAddress::from(sponsor));
+
+    Ok(SponsoredManifest {
+        manifest,
+        sponsor_signers,
+        user_signers,
+    })
+}
+
+/// Computes a key which stays the same for transactions that are economically identical: the same
+/// manifest signed by the same set of signers on the same network. The nonce, epochs, and tip of the
+/// intent are not inputs of the key so a wallet can use it to detect when a dApp re-requests a
+/// transaction that is already pending. The order of the signers and any repeated signers do not
+/// affect the key.
+pub fn deduplication_key(
+    manifest: &TransactionManifestV1,
+    signers: &[PublicKey],
+    network_id: u8,
+) -> Result<Hash, EncodeError> {
+    let blob_hashes = manifest.blobs.keys().copied().collect::<Vec<_>>();
+    let mut signers = signers
+        .iter()
+        .map(|public_key| match public_key {
+            PublicKey::Secp256k1(public_key) => (0u8, public_key.0.to_vec()),
+            PublicKey::Ed25519(public_key) => (1u8, public_key.0.to_vec()),
+        })
+        .collect::<Vec<_>>();
+    signers.sort();
+    signers.dedup();
+
+    manifest_encode(&(
+        network_id,
+        manifest.instructions.clone(),
+        blob_hashes,
+        signers,
+    ))
+    .map(scrypto::prelude::hash)
+}
+
+/// The directive used in manifest files to include the contents of another manifest file, written
+/// on its own line as `INCLUDE "path/to/fragment.rtm";`.
+pub const MANIFEST_INCLUDE_DIRECTIVE: &str = "INCLUDE";
+
+/// The maximum depth of nested includes, guarding against runaway recursion from loaders that
+/// produce new paths on every call.
+pub const MAXIMUM_MANIFEST_INCLUDE_DEPTH: usize = 16;
+
+/// Resolves the `INCLUDE` directives of a manifest string by replacing each of them with the
+/// contents returned by the loader for the included path, recursively. Paths are passed to the
+/// loader as written in the directive, so it's up to the loader to resolve relative paths. The
+/// directive isn't valid manifest syntax, so unresolved includes fail to compile rather than being
+/// silently ignored.
+pub fn resolve_includes<F, E>(
+    manifest: &str,
+    mut loader: F,
+) -> Result<String, ManifestIncludeError<E>>
+where
+    F: FnMut(&str) -> Result<String, E>,
+{
+    let mut include_stack = vec![];
+    resolve_includes_internal(manifest, &mut loader, &mut include_stack)
+}
+
+fn resolve_includes_internal<F, E>(
+    manifest: &str,
+    loader: &mut F,
+    include_stack: &mut Vec<String>,
+) -> Result<String, ManifestIncludeError<E>>
+where
+    F: FnMut(&str) -> Result<String, E>,
+{
+    let mut output = String::with_capacity(manifest.len());
+    let mut is_in_string_literal = false;
+
+    for (line_index, line) in manifest.split_inclusive('\n').enumerate() {
+        let directive = line
+            .trim()
+            .strip_prefix(MANIFEST_INCLUDE_DIRECTIVE)
+            .filter(|rest| rest.starts_with(char::is_whitespace));
+
+        match directive {
+            Some(arguments) if !is_in_string_literal => {
+                let path = arguments
+                    .trim()
+                    .strip_suffix(';')
+                    .map(str::trim_end)
+                    .and_then(|path| path.strip_prefix('"'))
+                    .and_then(|path| path.strip_suffix('"'))
+                    .filter(|path| !path.is_empty() && !path.contains('"'))
+                    .ok_or_else(|| ManifestIncludeError::InvalidDirective {
+                        line_number: line_index + 1,
+                        directive: line.trim().to_owned(),
+                    })?;
+
+                if include_stack.iter().any(|included| included == path) {
+                    return Err(ManifestIncludeError::IncludeCycle {
+                        path: path.to_owned(),
+                    });
+                }
+                if include_stack.len() >= MAXIMUM_MANIFEST_INCLUDE_DEPTH {
+                    return Err(ManifestIncludeError::MaximumDepthExceeded {
+                        path: path.to_owned(),
+                    });
+                }
+
+                let fragment = loader(path).map_err(|error| ManifestIncludeError::LoaderError {
+                    path: path.to_owned(),
+                    error,
+                })?;
+
+                include_stack.push(path.to_owned());
+                let resolved = resolve_includes_internal(&fragment, loader, include_stack)?;
+                include_stack.pop();
+
+                output.push_str(&resolved);
+                if !resolved.is_empty() && !resolved.ends_with('\n') {
+                    output.push('\n')
+                }
+            }
+            _ => {
+                is_in_string_literal = ends_in_string_literal(line, is_in_string_literal);
+                output.push_str(line);
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+/// Checks if a string literal is left open at the end of a line given whether the line starts inside
+/// of one, taking escaped quotes and comments into account.
+fn ends_in_string_literal(line: &str, starts_in_string_literal: bool) -> bool {
+    let mut is_in_string_literal = starts_in_string_literal;
+    let mut characters = line.chars();
+    while let Some(character) = characters.next() {
+        match (character, is_in_string_literal) {
+            ('"', _) => is_in_string_literal = !is_in_string_literal,
+            ('\\', true) => {
+                characters.next();
+            }
+            ('#', false) => break,
+            _ => {}
+        }
+    }
+    is_in_string_literal
+}
+
+/// Validates the arguments of a call to a function or method against the blueprint schema found in
+/// the package definition and encodes them as the manifest SBOR tuple used as the call's `args`.
+/// All of the arguments are validated and the errors of all of the invalid arguments are returned.
+pub fn encode_call_arguments(
+    package_definition: &PackageDefinition,
+    blueprint_name: &str,
+    function_name: &str,
+    arguments: Vec<ManifestValue>,
+) -> Result<Vec<u8>, CallArgumentsEncodeError> {
+    let blueprint_definition = package_definition
+        .blueprints
+        .get(blueprint_name)
+        .ok_or_else(|| CallArgumentsEncodeError::BlueprintNotFound(blueprint_name.to_owned()))?;
+    let VersionedScryptoSchema::V1(schema) = &blueprint_definition.schema.schema;
+
+    let signature = function_signature(package_definition, blueprint_name, function_name)
+        .map_err(CallArgumentsEncodeError::SchemaDecodeError)?
+        .ok_or_else(|| CallArgumentsEncodeError::FunctionNotFound(function_name.to_owned()))?;
+    if let TypeRef::Generic(..) = signature.input {
+        return Err(CallArgumentsEncodeError::GenericInputNotSupported);
+    }
+
+    if signature.arguments.len() != arguments.len() {
+        return Err(CallArgumentsEncodeError::InvalidNumberOfArguments {
+            expected: signature.arguments.len(),
+            actual: arguments.len(),
+        });
+    }
+
+    let argument_errors = signature
+        .arguments
+        .iter()
+        .zip(arguments.iter())
+        .enumerate()
+        .filter_map(|(index, (argument, value))| {
+            let error = match manifest_encode(value) {
+                Ok(encoded) => validate_payload_against_schema::<ManifestCustomExtension, _>(
+                    &encoded,
+                    schema,
+                    argument.local_type_id,
+                    &(),
+                    MANIFEST_SBOR_V1_MAX_DEPTH,
+                )
+                .err()
+                .map(|error| format!("{:?}", error)),
+                Err(error) => Some(format!("{:?}", error)),
+            }?;
+            Some(ArgumentValidationError {
+                index,
+                name: argument.name.clone(),
+                error,
+            })
+        })
+        .collect::<Vec<_>>();
+    if !argument_errors.is_empty() {
+        return Err(CallArgumentsEncodeError::InvalidArguments(argument_errors));
+    }
+
+    manifest_encode(&ManifestValue::Tuple { fields: arguments })
+        .map_err(CallArgumentsEncodeError::EncodeError)
+}
+
 #[derive(Clone, Debug)]
 pub struct TransactionManifestModifications {
     /// The [`ComponentAddress`]es of the access controllers to add create proof instructions in
@@ -226,6 +1150,125 @@ pub struct TransactionManifestModifications {
     pub add_assertions: Vec<(usize, Assertion)>,
 }
 
+/// A guarantee that the worktop contains at least the given amount of the resource at the given
+/// instruction index.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Guarantee {
+    pub instruction_index: usize,
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+}
+
+/// A modification of a single instruction in a manifest, see [`modify_instructions`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InstructionModification {
+    Insert {
+        index: usize,
+        instruction: InstructionV1,
+    },
+    Remove {
+        index: usize,
+    },
+    Replace {
+        index: usize,
+        instruction: InstructionV1,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeeLockValidation {
+    pub fee_payers: FeePayers,
+    /// The total of the non-contingent fee locks.
+    pub total_lock: Decimal,
+    /// The total of the contingent fee locks, which isn't counted towards the target fee.
+    pub total_contingent_lock: Decimal,
+    /// The amount by which the non-contingent locked fees fall short of the target fee, zero when
+    /// the target is met.
+    pub shortfall: Decimal,
+}
+
+impl FeeLockValidation {
+    pub fn meets_target(&self) -> bool {
+        self.shortfall.is_zero()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeeSponsorship {
+    /// The accounts that fees are locked against, in the order of their first lock.
+    pub fee_payers: Vec<ComponentAddress>,
+    /// The accounts that resources are withdrawn from or deposited into.
+    pub asset_movers: Vec<ComponentAddress>,
+    /// The fee payers whose assets don't move, i.e. that only pay the fees for others.
+    pub sponsors: Vec<ComponentAddress>,
+}
+
+impl FeeSponsorship {
+    pub fn is_sponsored(&self) -> bool {
+        !self.sponsors.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SponsoredManifest {
+    pub manifest: TransactionManifestV1,
+    /// The signers required from the sponsor, at least for locking the fee.
+    pub sponsor_signers: Vec<RequiredSigner>,
+    /// The signers required from the user.
+    pub user_signers: Vec<RequiredSigner>,
+}
+
+/// A policy that manifests are checked against in [`enforce_policy`]. A [`None`] in any of the
+/// fields means that no restriction is imposed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ManifestPolicy {
+    /// The packages whose functions and methods may be called.
+    pub allowed_packages: Option<HashSet<PackageAddress>>,
+    /// The components whose methods may be called.
+    pub allowed_components: Option<HashSet<ComponentAddress>>,
+    /// The resources that may be moved or referenced by the manifest.
+    pub allowed_resources: Option<HashSet<ResourceAddress>>,
+    /// The maximum amount of XRD that may be withdrawn from accounts or locked for fees.
+    pub max_xrd_spend: Option<Decimal>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PolicyViolation {
+    PackageNotAllowed {
+        instruction_index: usize,
+        package_address: PackageAddress,
+    },
+    ComponentNotAllowed {
+        instruction_index: usize,
+        component_address: ComponentAddress,
+    },
+    ResourceNotAllowed {
+        instruction_index: usize,
+        resource_address: ResourceAddress,
+    },
+    NamedAddressNotAllowed {
+        instruction_index: usize,
+    },
+    ResourceMethodNotAllowed {
+        instruction_index: usize,
+        resource_address: ResourceAddress,
+        method_name: String,
+    },
+    DirectVaultMethodNotAllowed {
+        instruction_index: usize,
+        vault_address: InternalAddress,
+        method_name: String,
+    },
+    InvalidArguments {
+        instruction_index: usize,
+        method_name: String,
+    },
+    MaxXrdSpendExceeded {
+        max_xrd_spend: Decimal,
+        xrd_spend: Decimal,
+    },
+}
+
 #[derive(Clone, Debug)]
 pub enum Assertion {
     Amount {
@@ -273,4 +1316,87 @@ pub enum ManifestModificationError {
         value: ManifestValue,
         error: EncodeError,
     },
+    InstructionIndexOutOfBounds {
+        instruction_index: usize,
+        instructions_length: usize,
+    },
+    RemovedIdInUse {
+        instruction_index: usize,
+    },
+    AddressReservationModificationNotSupported {
+        instruction_index: usize,
+    },
+    ValidationError(TransactionValidationError),
+}
+
+#[derive(Clone, Debug)]
+pub enum CallArgumentsEncodeError {
+    BlueprintNotFound(String),
+    FunctionNotFound(String),
+    GenericInputNotSupported,
+    SchemaDecodeError(SchemaDecodeError),
+    InvalidNumberOfArguments { expected: usize, actual: usize },
+    InvalidArguments(Vec<ArgumentValidationError>),
+    EncodeError(EncodeError),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArgumentValidationError {
+    /// The index of the argument in the function's input tuple.
+    pub index: usize,
+    /// The name of the argument if the schema defines one.
+    pub name: Option<String>,
+    pub error: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ManifestIncludeError<E> {
+    LoaderError {
+        path: String,
+        error: E,
+    },
+    /// An `INCLUDE` directive that isn't of the form `INCLUDE "path";`. The line number is that of
+    /// the directive in the manifest or fragment that contains it.
+    InvalidDirective {
+        line_number: usize,
+        directive: String,
+    },
+    IncludeCycle {
+        path: String,
+    },
+    MaximumDepthExceeded {
+        path: String,
+    },
+}
+
+/// Constructs a manifest that locks a fee against the faucet, takes free XRD from the faucet, and
+/// deposits it into the given account. The faucet address is the same on all networks where the
+/// faucet exists, so the network only comes into play when the manifest is converted to a string.
+pub fn faucet_free_xrd(to_account: ComponentAddress) -> TransactionManifestV1 {
+    let instructions = vec![
+        InstructionV1::CallMethod {
+            address: DynamicGlobalAddress::Static(FAUCET.into()),
+            method_name: "lock_fee".to_owned(),
+            args: manifest_args!(dec!("100")).into(),
+        },
+        InstructionV1::CallMethod {
+            address: DynamicGlobalAddress::Static(FAUCET.into()),
+            method_name: "free".to_owned(),
+            args: manifest_args!().into(),
+        },
+        InstructionV1::CallMethod {
+            address: DynamicGlobalAddress::Static(to_account.into()),
+            method_name: ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT.to_owned(),
+            args: manifest_args!(
+                ManifestExpression::EntireWorktop,
+                Option::<ResourceOrNonFungible>::None
+            )
+            .into(),
+        },
+    ];
+
+    TransactionManifestV1 {
+        instructions,
+        blobs: Default::default(),
+    }
 }
@@ -15,6 +15,9 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use radix_engine_common::data::manifest::converter::{
+    from_decimal, from_non_fungible_local_id, from_precise_decimal,
+};
 use sbor::prelude::ContextualSerialize;
 use sbor::representations::{SerializationMode, SerializationParameters};
 use sbor::*;
@@ -22,6 +25,8 @@ use scrypto::address::*;
 use scrypto::prelude::*;
 use transaction::data::{format_manifest_value, ManifestDecompilationDisplayContext};
 
+use crate::functions::scrypto_sbor;
+
 pub fn encode(value: &ManifestValue) -> Result<Vec<u8>, EncodeError> {
     manifest_encode(value)
 }
@@ -101,11 +106,140 @@ where
     Ok(string)
 }
 
+/// Encodes a Scrypto value given in one of its string representations as a manifest value, e.g. to
+/// pass a value copied from an API response as an argument in a manifest. References become static
+/// addresses. Owned nodes can't be passed in manifests and are rejected.
+pub fn encode_string_representation(
+    representation: scrypto_sbor::StringRepresentation,
+) -> Result<Vec<u8>, ManifestSborError> {
+    let value = scrypto_sbor::value_from_string_representation(representation)
+        .map_err(|error| ManifestSborError::InvalidStringRepresentation(format!("{error:?}")))?;
+    let value = manifest_value_from_scrypto_value(value)?;
+    manifest_encode(&value).map_err(ManifestSborError::EncodeError)
+}
+
+fn manifest_value_from_scrypto_value(
+    value: ScryptoValue,
+) -> Result<ManifestValue, ManifestSborError> {
+    let values = |values: Vec<ScryptoValue>| {
+        values
+            .into_iter()
+            .map(manifest_value_from_scrypto_value)
+            .collect::<Result<Vec<_>, _>>()
+    };
+
+    Ok(match value {
+        Value::Bool { value } => Value::Bool { value },
+        Value::I8 { value } => Value::I8 { value },
+        Value::I16 { value } => Value::I16 { value },
+        Value::I32 { value } => Value::I32 { value },
+        Value::I64 { value } => Value::I64 { value },
+        Value::I128 { value } => Value::I128 { value },
+        Value::U8 { value } => Value::U8 { value },
+        Value::U16 { value } => Value::U16 { value },
+        Value::U32 { value } => Value::U32 { value },
+        Value::U64 { value } => Value::U64 { value },
+        Value::U128 { value } => Value::U128 { value },
+        Value::String { value } => Value::String { value },
+        Value::Enum {
+            discriminator,
+            fields,
+        } => Value::Enum {
+            discriminator,
+            fields: values(fields)?,
+        },
+        Value::Array {
+            element_value_kind,
+            elements,
+        } => Value::Array {
+            element_value_kind: manifest_value_kind_from_scrypto_value_kind(element_value_kind)?,
+            elements: values(elements)?,
+        },
+        Value::Tuple { fields } => Value::Tuple {
+            fields: values(fields)?,
+        },
+        Value::Map {
+            key_value_kind,
+            value_value_kind,
+            entries,
+        } => Value::Map {
+            key_value_kind: manifest_value_kind_from_scrypto_value_kind(key_value_kind)?,
+            value_value_kind: manifest_value_kind_from_scrypto_value_kind(value_value_kind)?,
+            entries: entries
+                .into_iter()
+                .map(|(key, value)| {
+                    Ok((
+                        manifest_value_from_scrypto_value(key)?,
+                        manifest_value_from_scrypto_value(value)?,
+                    ))
+                })
+                .collect::<Result<Vec<_>, ManifestSborError>>()?,
+        },
+        Value::Custom { value } => Value::Custom {
+            value: match value {
+                ScryptoCustomValue::Reference(Reference(node_id)) => {
+                    ManifestCustomValue::Address(ManifestAddress::Static(node_id))
+                }
+                ScryptoCustomValue::Own(..) => {
+                    return Err(ManifestSborError::OwnNotRepresentableInManifest)
+                }
+                ScryptoCustomValue::Decimal(value) => {
+                    ManifestCustomValue::Decimal(from_decimal(value))
+                }
+                ScryptoCustomValue::PreciseDecimal(value) => {
+                    ManifestCustomValue::PreciseDecimal(from_precise_decimal(value))
+                }
+                ScryptoCustomValue::NonFungibleLocalId(value) => {
+                    ManifestCustomValue::NonFungibleLocalId(from_non_fungible_local_id(value))
+                }
+            },
+        },
+    })
+}
+
+fn manifest_value_kind_from_scrypto_value_kind(
+    value_kind: ScryptoValueKind,
+) -> Result<ManifestValueKind, ManifestSborError> {
+    Ok(match value_kind {
+        ValueKind::Bool => ValueKind::Bool,
+        ValueKind::I8 => ValueKind::I8,
+        ValueKind::I16 => ValueKind::I16,
+        ValueKind::I32 => ValueKind::I32,
+        ValueKind::I64 => ValueKind::I64,
+        ValueKind::I128 => ValueKind::I128,
+        ValueKind::U8 => ValueKind::U8,
+        ValueKind::U16 => ValueKind::U16,
+        ValueKind::U32 => ValueKind::U32,
+        ValueKind::U64 => ValueKind::U64,
+        ValueKind::U128 => ValueKind::U128,
+        ValueKind::String => ValueKind::String,
+        ValueKind::Enum => ValueKind::Enum,
+        ValueKind::Array => ValueKind::Array,
+        ValueKind::Tuple => ValueKind::Tuple,
+        ValueKind::Map => ValueKind::Map,
+        ValueKind::Custom(custom_value_kind) => ValueKind::Custom(match custom_value_kind {
+            ScryptoCustomValueKind::Reference => ManifestCustomValueKind::Address,
+            ScryptoCustomValueKind::Own => {
+                return Err(ManifestSborError::OwnNotRepresentableInManifest)
+            }
+            ScryptoCustomValueKind::Decimal => ManifestCustomValueKind::Decimal,
+            ScryptoCustomValueKind::PreciseDecimal => ManifestCustomValueKind::PreciseDecimal,
+            ScryptoCustomValueKind::NonFungibleLocalId => {
+                ManifestCustomValueKind::NonFungibleLocalId
+            }
+        }),
+    })
+}
+
 #[derive(Debug, Clone)]
 pub enum ManifestSborError {
     SchemaValidationError,
     DecodeError(DecodeError),
+    EncodeError(EncodeError),
     FmtError(std::fmt::Error),
+    InvalidStringRepresentation(String),
+    /// Owned nodes can't be passed to manifests, only references to global entities.
+    OwnNotRepresentableInManifest,
 }
 
 #[derive(Clone, Copy)]
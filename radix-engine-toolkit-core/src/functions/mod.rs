@@ -17,18 +17,34 @@
 
 pub mod information;
 
+pub mod attestation;
+pub mod dapp_definition;
 pub mod derive;
 
 pub mod execution;
 pub mod instructions;
 pub mod intent;
+pub mod lint;
+pub mod logging;
 pub mod manifest;
 pub mod notarized_transaction;
 pub mod signed_intent;
+pub mod signing_state;
+pub mod transaction_request;
+pub mod validation;
 
 pub mod manifest_sbor;
+pub mod native_state;
+pub mod sbor;
+pub mod schema;
 pub mod scrypto_sbor;
 
 pub mod address;
+pub mod amount;
 pub mod events;
+pub mod hashing;
+pub mod header;
+pub mod olympia;
+pub mod personal_message;
+pub mod public_key;
 pub mod utils;
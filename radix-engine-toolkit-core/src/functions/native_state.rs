@@ -0,0 +1,294 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine::blueprints::native_schema::*;
+use radix_engine::blueprints::package::*;
+use radix_engine::types::*;
+use radix_engine_common::ScryptoSbor;
+use radix_engine_interface::blueprints::account::DefaultDepositRule;
+use sbor::representations::SerializationMode;
+use scrypto::address::AddressBech32Encoder;
+
+use crate::functions::scrypto_sbor::*;
+
+/// The native blueprints whose state can be decoded through their blueprint schemas.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NativeBlueprint {
+    Account,
+    Validator,
+    AccessController,
+    OneResourcePool,
+    TwoResourcePool,
+    MultiResourcePool,
+    FungibleResourceManager,
+    NonFungibleResourceManager,
+    FungibleVault,
+    NonFungibleVault,
+}
+
+impl NativeBlueprint {
+    pub fn blueprint_name(&self) -> &'static str {
+        match self {
+            Self::Account => "Account",
+            Self::Validator => "Validator",
+            Self::AccessController => "AccessController",
+            Self::OneResourcePool => "OneResourcePool",
+            Self::TwoResourcePool => "TwoResourcePool",
+            Self::MultiResourcePool => "MultiResourcePool",
+            Self::FungibleResourceManager => "FungibleResourceManager",
+            Self::NonFungibleResourceManager => "NonFungibleResourceManager",
+            Self::FungibleVault => "FungibleVault",
+            Self::NonFungibleVault => "NonFungibleVault",
+        }
+    }
+
+    fn package_definition(&self) -> &'static PackageDefinition {
+        match self {
+            Self::Account => &ACCOUNT_PACKAGE_DEFINITION,
+            Self::Validator => &CONSENSUS_MANAGER_PACKAGE_DEFINITION,
+            Self::AccessController => &ACCESS_CONTROLLER_PACKAGE_DEFINITION,
+            Self::OneResourcePool | Self::TwoResourcePool | Self::MultiResourcePool => {
+                &POOL_PACKAGE_DEFINITION
+            }
+            Self::FungibleResourceManager
+            | Self::NonFungibleResourceManager
+            | Self::FungibleVault
+            | Self::NonFungibleVault => &RESOURCE_PACKAGE_DEFINITION,
+        }
+    }
+
+    fn blueprint_definition(
+        &self,
+    ) -> Result<&'static BlueprintDefinitionInit, NativeStateDecodeError> {
+        self.package_definition()
+            .blueprints
+            .get(self.blueprint_name())
+            .ok_or(NativeStateDecodeError::BlueprintNotFound(*self))
+    }
+}
+
+/// Decodes the payload of a field of a native blueprint into its string representation. The
+/// payload is validated against and annotated with the type of the field as defined in the schema
+/// of the blueprint, so the layout of the state follows the version of the engine the toolkit is
+/// built against rather than being hardcoded.
+pub fn decode_field<T>(
+    blueprint: NativeBlueprint,
+    field_index: u8,
+    payload: T,
+    representation: SerializationMode,
+    bech32_encoder: &AddressBech32Encoder,
+) -> Result<String, NativeStateDecodeError>
+where
+    T: AsRef<[u8]>,
+{
+    let blueprint_definition = blueprint.blueprint_definition()?;
+    let VersionedScryptoSchema::V1(schema) = &blueprint_definition.schema.schema;
+
+    let field = blueprint_definition
+        .schema
+        .state
+        .fields
+        .get(field_index as usize)
+        .ok_or(NativeStateDecodeError::FieldNotFound {
+            blueprint,
+            field_index,
+        })?;
+    let local_type_id = match field.field {
+        TypeRef::Static(local_type_id) => local_type_id,
+        TypeRef::Generic(_) => {
+            return Err(NativeStateDecodeError::GenericFieldType {
+                blueprint,
+                field_index,
+            })
+        }
+    };
+
+    decode_to_string_representation(
+        payload,
+        representation,
+        bech32_encoder,
+        Some((local_type_id, schema.clone())),
+    )
+    .map_err(NativeStateDecodeError::ScryptoSborError)
+}
+
+/// Decodes the payload of a field of a native blueprint into its typed model. [`None`] is returned
+/// for the fields which the toolkit has no typed model for, their payloads can still be decoded
+/// into their string representation through [`decode_field`].
+pub fn decode_typed_field<T>(
+    blueprint: NativeBlueprint,
+    field_index: u8,
+    payload: T,
+) -> Result<Option<NativeFieldState>, NativeStateDecodeError>
+where
+    T: AsRef<[u8]>,
+{
+    let payload = payload.as_ref();
+    let decode_error = NativeStateDecodeError::DecodeError;
+    let state = match (blueprint, field_index) {
+        (NativeBlueprint::FungibleVault, 0) => {
+            let LiquidResourcePayload { amount } = scrypto_decode(payload).map_err(decode_error)?;
+            NativeFieldState::FungibleVaultBalance { amount }
+        }
+        (NativeBlueprint::NonFungibleVault, 0) => {
+            let LiquidResourcePayload { amount } = scrypto_decode(payload).map_err(decode_error)?;
+            NativeFieldState::NonFungibleVaultBalance { amount }
+        }
+        (NativeBlueprint::FungibleResourceManager, 0) => {
+            NativeFieldState::FungibleResourceManagerDivisibility {
+                divisibility: scrypto_decode(payload).map_err(decode_error)?,
+            }
+        }
+        (NativeBlueprint::FungibleResourceManager, 1) => {
+            NativeFieldState::FungibleResourceManagerTotalSupply {
+                total_supply: scrypto_decode(payload).map_err(decode_error)?,
+            }
+        }
+        (NativeBlueprint::Account, 0) => {
+            let AccountPayload {
+                default_deposit_rule,
+            } = scrypto_decode(payload).map_err(decode_error)?;
+            NativeFieldState::Account {
+                default_deposit_rule,
+            }
+        }
+        (NativeBlueprint::OneResourcePool, 0) => {
+            let OneResourcePoolPayload {
+                vault: (vault,),
+                pool_unit_resource_manager: (pool_unit_resource_address,),
+            } = scrypto_decode(payload).map_err(decode_error)?;
+            NativeFieldState::OneResourcePool {
+                vault: vault.0,
+                pool_unit_resource_address,
+            }
+        }
+        (NativeBlueprint::TwoResourcePool, 0) => {
+            let TwoResourcePoolPayload {
+                vaults,
+                pool_unit_resource_manager: (pool_unit_resource_address,),
+            } = scrypto_decode(payload).map_err(decode_error)?;
+            NativeFieldState::TwoResourcePool {
+                vaults: vaults
+                    .into_iter()
+                    .map(|(resource_address, (vault,))| (resource_address, vault.0))
+                    .collect(),
+                pool_unit_resource_address,
+            }
+        }
+        (NativeBlueprint::MultiResourcePool, 0) => {
+            let MultiResourcePoolPayload {
+                vaults,
+                pool_unit_resource_manager: (pool_unit_resource_address,),
+            } = scrypto_decode(payload).map_err(decode_error)?;
+            NativeFieldState::MultiResourcePool {
+                vaults: vaults
+                    .into_iter()
+                    .map(|(resource_address, (vault,))| (resource_address, vault.0))
+                    .collect(),
+                pool_unit_resource_address,
+            }
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(state))
+}
+
+/// The typed state of the fields of native blueprints that the toolkit has models for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NativeFieldState {
+    FungibleVaultBalance {
+        amount: Decimal,
+    },
+    /// The number of non-fungibles in the vault, the ids are stored in a collection and not in the
+    /// field.
+    NonFungibleVaultBalance {
+        amount: Decimal,
+    },
+    FungibleResourceManagerDivisibility {
+        divisibility: u8,
+    },
+    FungibleResourceManagerTotalSupply {
+        total_supply: Decimal,
+    },
+    Account {
+        default_deposit_rule: DefaultDepositRule,
+    },
+    OneResourcePool {
+        vault: NodeId,
+        pool_unit_resource_address: ResourceAddress,
+    },
+    TwoResourcePool {
+        vaults: Vec<(ResourceAddress, NodeId)>,
+        pool_unit_resource_address: ResourceAddress,
+    },
+    MultiResourcePool {
+        vaults: Vec<(ResourceAddress, NodeId)>,
+        pool_unit_resource_address: ResourceAddress,
+    },
+}
+
+/// The SBOR layouts of the field payloads that [`decode_typed_field`] decodes. Vaults and resource
+/// managers are single field tuples of their [`Own`] and [`ResourceAddress`] respectively.
+#[derive(ScryptoSbor)]
+struct LiquidResourcePayload {
+    amount: Decimal,
+}
+
+#[derive(ScryptoSbor)]
+struct AccountPayload {
+    default_deposit_rule: DefaultDepositRule,
+}
+
+#[derive(ScryptoSbor)]
+struct OneResourcePoolPayload {
+    vault: (Own,),
+    pool_unit_resource_manager: (ResourceAddress,),
+}
+
+#[derive(ScryptoSbor)]
+struct TwoResourcePoolPayload {
+    vaults: [(ResourceAddress, (Own,)); 2],
+    pool_unit_resource_manager: (ResourceAddress,),
+}
+
+#[derive(ScryptoSbor)]
+struct MultiResourcePoolPayload {
+    vaults: IndexMap<ResourceAddress, (Own,)>,
+    pool_unit_resource_manager: (ResourceAddress,),
+}
+
+/// The number of fields in the state of the native blueprint.
+pub fn field_count(blueprint: NativeBlueprint) -> Result<usize, NativeStateDecodeError> {
+    blueprint
+        .blueprint_definition()
+        .map(|blueprint_definition| blueprint_definition.schema.state.fields.len())
+}
+
+#[derive(Debug)]
+pub enum NativeStateDecodeError {
+    BlueprintNotFound(NativeBlueprint),
+    FieldNotFound {
+        blueprint: NativeBlueprint,
+        field_index: u8,
+    },
+    GenericFieldType {
+        blueprint: NativeBlueprint,
+        field_index: u8,
+    },
+    ScryptoSborError(ScryptoSborError),
+    DecodeError(DecodeError),
+}
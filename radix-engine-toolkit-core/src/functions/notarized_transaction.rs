@@ -21,6 +21,7 @@ use transaction::errors::*;
 use transaction::model::*;
 use transaction::validation::*;
 
+use crate::functions::signed_intent::NotarySignatoryIssue;
 use crate::models::transaction_hash::TransactionHash;
 
 pub fn hash(
@@ -59,3 +60,201 @@ pub fn statically_validate(
         .and_then(|prepared| validator.validate(prepared))
         .map(|_| ())
 }
+
+/// Produces everything that is needed to submit a notarized transaction to the network and to
+/// then track it: the compiled payload, the notarized transaction hash (the payload id) and the
+/// intent hash which the status of the transaction is tracked by.
+pub fn submission_envelope(
+    notarized_transaction: &NotarizedTransactionV1,
+) -> Result<SubmissionEnvelope, PrepareError> {
+    let network_id = notarized_transaction.signed_intent.intent.header.network_id;
+
+    let payload = compile(notarized_transaction).map_err(PrepareError::EncodeError)?;
+    let prepared = notarized_transaction.prepare()?;
+
+    Ok(SubmissionEnvelope {
+        payload,
+        payload_id: TransactionHash::new(prepared.notarized_transaction_hash(), network_id),
+        intent_hash: TransactionHash::new(prepared.intent_hash(), network_id),
+    })
+}
+
+/// The names of the methods that lock fees on accounts and the faucet.
+const FEE_LOCKING_METHODS: [&str; 4] = [
+    "lock_fee",
+    "lock_contingent_fee",
+    "lock_fee_and_withdraw",
+    "lock_fee_and_withdraw_non_fungibles",
+];
+
+/// Performs a number of checks on a notarized transaction to determine the reasons for which the
+/// network would likely reject it. An empty vector is returned if no issues are found. This is a
+/// best-effort check and a transaction passing it could still be rejected.
+///
+/// The current epoch is used to check the validity window of the transaction and the recently used
+/// nonces are used to hint at transactions that could be duplicates of previously submitted ones.
+pub fn preflight_check(
+    notarized_transaction: &NotarizedTransactionV1,
+    validation_config: ValidationConfig,
+    current_epoch: Option<Epoch>,
+    recently_used_nonces: &[u32],
+) -> Vec<PreflightIssue> {
+    let mut issues = vec![];
+    let header = &notarized_transaction.signed_intent.intent.header;
+
+    match compile(notarized_transaction) {
+        Ok(payload) if payload.len() > validation_config.max_notarized_payload_size => {
+            issues.push(PreflightIssue::PayloadTooLarge {
+                payload_size: payload.len(),
+                max_payload_size: validation_config.max_notarized_payload_size,
+            })
+        }
+        Ok(..) => {}
+        Err(error) => issues.push(PreflightIssue::EncodeError(error)),
+    }
+
+    if let Err(error) = statically_validate(notarized_transaction, validation_config) {
+        issues.push(PreflightIssue::StaticValidationFailed(error))
+    }
+
+    if let Some(current_epoch) = current_epoch {
+        if current_epoch < header.start_epoch_inclusive {
+            issues.push(PreflightIssue::EpochWindowNotStarted {
+                current_epoch,
+                start_epoch_inclusive: header.start_epoch_inclusive,
+            })
+        } else if current_epoch >= header.end_epoch_exclusive {
+            issues.push(PreflightIssue::EpochWindowExpired {
+                current_epoch,
+                end_epoch_exclusive: header.end_epoch_exclusive,
+            })
+        }
+    }
+
+    let locks_fees = notarized_transaction
+        .signed_intent
+        .intent
+        .instructions
+        .0
+        .iter()
+        .any(|instruction| {
+            matches!(
+                instruction,
+                InstructionV1::CallMethod { method_name, .. }
+                    if FEE_LOCKING_METHODS.contains(&method_name.as_str())
+            )
+        });
+    if !locks_fees {
+        issues.push(PreflightIssue::NoFeeLocked)
+    }
+
+    if recently_used_nonces.contains(&header.nonce) {
+        issues.push(PreflightIssue::NonceRecentlyUsed(header.nonce))
+    }
+
+    if let Ok(notary_signatory_issues) = crate::functions::signed_intent::notary_signatory_issues(
+        &notarized_transaction.signed_intent,
+    ) {
+        issues.extend(
+            notary_signatory_issues
+                .into_iter()
+                .map(PreflightIssue::NotarySignatoryMisconfigured),
+        )
+    }
+
+    issues
+}
+
+/// Checks that the notarized transaction was notarized by the expected public key: that the notary
+/// public key in the header is the expected one and that the notary signature is a valid signature
+/// by that key over the signed intent hash. An empty vector is returned if both checks pass.
+pub fn verify_notary(
+    notarized_transaction: &NotarizedTransactionV1,
+    expected_notary_public_key: &PublicKey,
+) -> Result<Vec<NotaryVerificationFailure>, PrepareError> {
+    let notary_public_key = notarized_transaction
+        .signed_intent
+        .intent
+        .header
+        .notary_public_key;
+    let signed_intent_hash = notarized_transaction
+        .signed_intent
+        .prepare()?
+        .signed_intent_hash();
+
+    let mut failures = vec![];
+    if notary_public_key != *expected_notary_public_key {
+        failures.push(NotaryVerificationFailure::NotaryPublicKeyMismatch {
+            actual: notary_public_key,
+        })
+    }
+
+    let signature = match (notary_public_key, notarized_transaction.notary_signature.0) {
+        (PublicKey::Secp256k1(..), SignatureV1::Secp256k1(signature)) => {
+            Some(SignatureWithPublicKeyV1::Secp256k1 { signature })
+        }
+        (PublicKey::Ed25519(public_key), SignatureV1::Ed25519(signature)) => {
+            Some(SignatureWithPublicKeyV1::Ed25519 {
+                public_key,
+                signature,
+            })
+        }
+        _ => None,
+    };
+    match signature {
+        Some(signature) => {
+            let recovered_public_key = verify_and_recover(signed_intent_hash.as_hash(), &signature);
+            if recovered_public_key != Some(notary_public_key) {
+                failures.push(NotaryVerificationFailure::InvalidNotarySignature)
+            }
+        }
+        None => failures.push(NotaryVerificationFailure::NotarySignatureCurveMismatch),
+    }
+
+    Ok(failures)
+}
+
+#[derive(Debug, Clone)]
+pub enum PreflightIssue {
+    EncodeError(EncodeError),
+    PayloadTooLarge {
+        payload_size: usize,
+        max_payload_size: usize,
+    },
+    StaticValidationFailed(TransactionValidationError),
+    EpochWindowNotStarted {
+        current_epoch: Epoch,
+        start_epoch_inclusive: Epoch,
+    },
+    EpochWindowExpired {
+        current_epoch: Epoch,
+        end_epoch_exclusive: Epoch,
+    },
+    NoFeeLocked,
+    /// The nonce of the transaction was recently used. This is not a reason for rejection by itself
+    /// but, if the rest of the intent is the same, then the transaction is a duplicate of one that
+    /// has already been submitted and will be rejected if that one was committed.
+    NonceRecentlyUsed(u32),
+    NotarySignatoryMisconfigured(NotarySignatoryIssue),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NotaryVerificationFailure {
+    /// The notary public key in the header is not the expected one.
+    NotaryPublicKeyMismatch { actual: PublicKey },
+    /// The notary signature is on a different curve than the notary public key in the header.
+    NotarySignatureCurveMismatch,
+    /// The notary signature is not a valid signature over the signed intent hash by the notary
+    /// public key in the header.
+    InvalidNotarySignature,
+}
+
+pub struct SubmissionEnvelope {
+    /// The compiled notarized transaction. This is the payload which is submitted to the network
+    /// once hex encoded.
+    pub payload: Vec<u8>,
+    /// The notarized transaction hash which identifies this exact payload.
+    pub payload_id: TransactionHash,
+    /// The intent hash which identifies the transaction regardless of its signatures.
+    pub intent_hash: TransactionHash,
+}
@@ -0,0 +1,339 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Decoding of Olympia transaction blobs for historical purposes, e.g., for explorers that wish to
+//! present the Olympia and Babylon history of an account together.
+//!
+//! An Olympia transaction is a sequence of instructions each starting with a single byte opcode.
+//! Variable-length data (substates, messages, and syscalls) is prefixed by its length as a big
+//! endian `u16`. Only the substates needed to understand transfers and stakes are decoded, other
+//! substates are kept in their raw form.
+
+use scrypto::prelude::*;
+
+use crate::functions::derive::{
+    resource_address_from_olympia_resource_address_bytes, virtual_account_address_from_public_key,
+    DerivationError,
+};
+
+/// The length of an Olympia substate id: the 32 byte transaction id and the 4 byte index.
+const SUBSTATE_ID_LENGTH: usize = 36;
+/// The length of an Olympia (recoverable) ECDSA signature.
+const SIGNATURE_LENGTH: usize = 65;
+/// The length of an Olympia `UInt256` amount.
+const AMOUNT_LENGTH: usize = 32;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OlympiaTransaction {
+    pub instructions: Vec<OlympiaInstruction>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OlympiaInstruction {
+    End,
+    Up(OlympiaSubstate),
+    VirtualDown(Vec<u8>),
+    VirtualDownWithArgument(Vec<u8>),
+    Down(OlympiaSubstateId),
+    LocalDown(u16),
+    Message(Vec<u8>),
+    Signature([u8; SIGNATURE_LENGTH]),
+    Syscall(Vec<u8>),
+    Header { version: u8, flags: u8 },
+    Read(OlympiaSubstateId),
+    LocalRead(u16),
+    VirtualRead(Vec<u8>),
+    LocalVirtualRead(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OlympiaSubstateId {
+    pub transaction_id: Hash,
+    pub index: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OlympiaSubstate {
+    Tokens {
+        resource: Vec<u8>,
+        owner: Secp256k1PublicKey,
+        amount: Decimal,
+    },
+    PreparedStake {
+        validator: Secp256k1PublicKey,
+        owner: Secp256k1PublicKey,
+        amount: Decimal,
+    },
+    StakeOwnership {
+        validator: Secp256k1PublicKey,
+        owner: Secp256k1PublicKey,
+        amount: Decimal,
+    },
+    PreparedUnstake {
+        validator: Secp256k1PublicKey,
+        owner: Secp256k1PublicKey,
+        amount: Decimal,
+    },
+    Other {
+        type_id: u8,
+        bytes: Vec<u8>,
+    },
+}
+
+/// An action of an Olympia transaction with the involved addresses mapped to their Babylon
+/// equivalents.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OlympiaAction {
+    /// Tokens were deposited into an account. Olympia transfers consume all of the tokens of the
+    /// sender and output the change back to the sender, so the sender of a transfer also appears
+    /// as the receiver of its change.
+    TokensDeposited {
+        account: ComponentAddress,
+        resource_address: ResourceAddress,
+        amount: Decimal,
+    },
+    StakePrepared {
+        account: ComponentAddress,
+        validator: Secp256k1PublicKey,
+        amount: Decimal,
+    },
+    UnstakePrepared {
+        account: ComponentAddress,
+        validator: Secp256k1PublicKey,
+        amount: Decimal,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OlympiaTransactionDecodeError {
+    UnexpectedEndOfPayload,
+    UnknownOpcode(u8),
+    InvalidSubstate { type_id: u8 },
+    InvalidAccountAddress(u8),
+    AmountOutOfRange,
+}
+
+pub fn decode_transaction(
+    payload: &[u8],
+) -> Result<OlympiaTransaction, OlympiaTransactionDecodeError> {
+    let mut reader = Reader(payload);
+    let mut instructions = vec![];
+
+    while !reader.0.is_empty() {
+        let instruction = match reader.read_u8()? {
+            0x00 => OlympiaInstruction::End,
+            0x01 => OlympiaInstruction::Up(decode_substate(reader.read_length_prefixed()?)?),
+            0x02 => OlympiaInstruction::VirtualDown(reader.read_length_prefixed()?.to_vec()),
+            0x03 => {
+                OlympiaInstruction::VirtualDownWithArgument(reader.read_length_prefixed()?.to_vec())
+            }
+            0x04 => OlympiaInstruction::Down(reader.read_substate_id()?),
+            0x05 => OlympiaInstruction::LocalDown(reader.read_u16()?),
+            0x06 => OlympiaInstruction::Message(reader.read_length_prefixed()?.to_vec()),
+            0x07 => OlympiaInstruction::Signature(reader.read_array()?),
+            0x08 => OlympiaInstruction::Syscall(reader.read_length_prefixed()?.to_vec()),
+            0x09 => {
+                let [version, flags] = reader.read_array()?;
+                OlympiaInstruction::Header { version, flags }
+            }
+            0x0A => OlympiaInstruction::Read(reader.read_substate_id()?),
+            0x0B => OlympiaInstruction::LocalRead(reader.read_u16()?),
+            0x0C => OlympiaInstruction::VirtualRead(reader.read_length_prefixed()?.to_vec()),
+            0x0D => OlympiaInstruction::LocalVirtualRead(reader.read_length_prefixed()?.to_vec()),
+            opcode => return Err(OlympiaTransactionDecodeError::UnknownOpcode(opcode)),
+        };
+        instructions.push(instruction);
+    }
+
+    Ok(OlympiaTransaction { instructions })
+}
+
+/// Maps the outputs of an Olympia transaction to actions on the Babylon equivalents of the
+/// involved accounts and resources.
+pub fn actions(transaction: &OlympiaTransaction) -> Result<Vec<OlympiaAction>, DerivationError> {
+    let mut actions = vec![];
+    for instruction in transaction.instructions.iter() {
+        let OlympiaInstruction::Up(substate) = instruction else {
+            continue;
+        };
+        let action = match substate {
+            OlympiaSubstate::Tokens {
+                resource,
+                owner,
+                amount,
+            } => OlympiaAction::TokensDeposited {
+                account: virtual_account_address_from_public_key(owner),
+                resource_address: resource_address_from_olympia_resource_address_bytes(resource)?,
+                amount: *amount,
+            },
+            OlympiaSubstate::PreparedStake {
+                validator,
+                owner,
+                amount,
+            } => OlympiaAction::StakePrepared {
+                account: virtual_account_address_from_public_key(owner),
+                validator: *validator,
+                amount: *amount,
+            },
+            OlympiaSubstate::PreparedUnstake {
+                validator,
+                owner,
+                amount,
+            } => OlympiaAction::UnstakePrepared {
+                account: virtual_account_address_from_public_key(owner),
+                validator: *validator,
+                amount: *amount,
+            },
+            OlympiaSubstate::StakeOwnership { .. } | OlympiaSubstate::Other { .. } => continue,
+        };
+        actions.push(action);
+    }
+    Ok(actions)
+}
+
+fn decode_substate(bytes: &[u8]) -> Result<OlympiaSubstate, OlympiaTransactionDecodeError> {
+    let mut reader = Reader(bytes);
+    let type_id = reader.read_u8()?;
+    let invalid_substate = |_| OlympiaTransactionDecodeError::InvalidSubstate { type_id };
+
+    let substate = match type_id {
+        0x06 => {
+            // Reserved byte.
+            reader.read_u8().map_err(invalid_substate)?;
+            let resource = reader.read_resource_address().map_err(invalid_substate)?;
+            let owner = reader.read_account_address().map_err(invalid_substate)?;
+            let amount = reader.read_amount().map_err(invalid_substate)?;
+            OlympiaSubstate::Tokens {
+                resource,
+                owner,
+                amount,
+            }
+        }
+        0x07 | 0x08 | 0x09 => {
+            // Reserved byte.
+            reader.read_u8().map_err(invalid_substate)?;
+            let validator = Secp256k1PublicKey(reader.read_array().map_err(invalid_substate)?);
+            let owner = reader.read_account_address().map_err(invalid_substate)?;
+            let amount = reader.read_amount().map_err(invalid_substate)?;
+            match type_id {
+                0x07 => OlympiaSubstate::PreparedStake {
+                    validator,
+                    owner,
+                    amount,
+                },
+                0x08 => OlympiaSubstate::StakeOwnership {
+                    validator,
+                    owner,
+                    amount,
+                },
+                _ => OlympiaSubstate::PreparedUnstake {
+                    validator,
+                    owner,
+                    amount,
+                },
+            }
+        }
+        type_id => {
+            return Ok(OlympiaSubstate::Other {
+                type_id,
+                bytes: bytes.to_vec(),
+            })
+        }
+    };
+
+    if reader.0.is_empty() {
+        Ok(substate)
+    } else {
+        Err(OlympiaTransactionDecodeError::InvalidSubstate { type_id })
+    }
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn read_bytes(&mut self, length: usize) -> Result<&'a [u8], OlympiaTransactionDecodeError> {
+        if self.0.len() < length {
+            return Err(OlympiaTransactionDecodeError::UnexpectedEndOfPayload);
+        }
+        let (bytes, remaining) = self.0.split_at(length);
+        self.0 = remaining;
+        Ok(bytes)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], OlympiaTransactionDecodeError> {
+        self.read_bytes(N)
+            .map(|bytes| bytes.try_into().expect("Length checked above"))
+    }
+
+    fn read_u8(&mut self) -> Result<u8, OlympiaTransactionDecodeError> {
+        self.read_array::<1>().map(|[byte]| byte)
+    }
+
+    fn read_u16(&mut self) -> Result<u16, OlympiaTransactionDecodeError> {
+        self.read_array().map(u16::from_be_bytes)
+    }
+
+    fn read_length_prefixed(&mut self) -> Result<&'a [u8], OlympiaTransactionDecodeError> {
+        let length = self.read_u16()?;
+        self.read_bytes(length as usize)
+    }
+
+    fn read_substate_id(&mut self) -> Result<OlympiaSubstateId, OlympiaTransactionDecodeError> {
+        let bytes = self.read_array::<SUBSTATE_ID_LENGTH>()?;
+        let mut transaction_id = [0u8; 32];
+        transaction_id.copy_from_slice(&bytes[..32]);
+        let mut index = [0u8; 4];
+        index.copy_from_slice(&bytes[32..]);
+        Ok(OlympiaSubstateId {
+            transaction_id: Hash(transaction_id),
+            index: u32::from_be_bytes(index),
+        })
+    }
+
+    /// Reads an Olympia resource address which is either the native token (`0x01`) or a resource
+    /// identified by the hash of its creator's key and its symbol (`0x03` followed by 26 bytes).
+    fn read_resource_address(&mut self) -> Result<Vec<u8>, OlympiaTransactionDecodeError> {
+        let prefix = self.read_u8()?;
+        let mut resource = vec![prefix];
+        if prefix == 0x03 {
+            resource.extend(self.read_bytes(26)?);
+        }
+        Ok(resource)
+    }
+
+    /// Reads an Olympia account address which is the `0x04` prefix followed by the compressed
+    /// public key of the account.
+    fn read_account_address(
+        &mut self,
+    ) -> Result<Secp256k1PublicKey, OlympiaTransactionDecodeError> {
+        match self.read_u8()? {
+            0x04 => self.read_array().map(Secp256k1PublicKey),
+            prefix => Err(OlympiaTransactionDecodeError::InvalidAccountAddress(prefix)),
+        }
+    }
+
+    /// Reads an Olympia `UInt256` amount which uses the same 18 decimal places as [`Decimal`], so
+    /// the integer is the number of attos of the [`Decimal`].
+    fn read_amount(&mut self) -> Result<Decimal, OlympiaTransactionDecodeError> {
+        let bytes = self.read_array::<AMOUNT_LENGTH>()?;
+        if bytes[..16].iter().any(|byte| *byte != 0) {
+            return Err(OlympiaTransactionDecodeError::AmountOutOfRange);
+        }
+        let attos = u128::from_be_bytes(bytes[16..].try_into().expect("Length is 16"));
+        Ok(Decimal(attos.into()))
+    }
+}
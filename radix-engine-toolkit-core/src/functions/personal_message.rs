@@ -0,0 +1,47 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Signing and verification of arbitrary (non-transaction) messages. The message is hashed with a
+//! domain separation prefix so that a signature over a personal message can never be a valid
+//! signature over a transaction intent.
+//!
+//! The hash signed is: `blake2b_256(PREFIX || len(message) as decimal ASCII || message)`.
+
+use scrypto::prelude::*;
+use transaction::prelude::*;
+
+pub const PERSONAL_MESSAGE_PREFIX: &[u8] = b"\x19Radix Signed Message:\n";
+
+pub fn hash(message: &[u8]) -> Hash {
+    let length = message.len().to_string();
+    let mut data = Vec::with_capacity(PERSONAL_MESSAGE_PREFIX.len() + length.len() + message.len());
+    data.extend_from_slice(PERSONAL_MESSAGE_PREFIX);
+    data.extend_from_slice(length.as_bytes());
+    data.extend_from_slice(message);
+    scrypto::prelude::hash(data)
+}
+
+pub fn sign(private_key: &PrivateKey, message: &[u8]) -> SignatureWithPublicKeyV1 {
+    private_key.sign_with_public_key(&hash(message))
+}
+
+/// Verifies the signature over the message and returns the address of the virtual account of the
+/// signer. [`None`] is returned if the signature is not valid.
+pub fn verify(message: &[u8], signature: &SignatureWithPublicKeyV1) -> Option<ComponentAddress> {
+    verify_and_recover(&hash(message), signature)
+        .map(|public_key| ComponentAddress::virtual_account_from_public_key(&public_key))
+}
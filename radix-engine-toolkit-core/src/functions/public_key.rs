@@ -0,0 +1,89 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Validation of public key encodings and conversion of Secp256k1 public keys between their
+//! compressed and uncompressed forms. Scrypto's public key types are plain byte arrays which are
+//! never checked to be points on their curves, so invalid keys are otherwise only caught once a
+//! signature fails to verify.
+
+use scrypto::prelude::*;
+
+/// The length of an uncompressed Secp256k1 public key: a `0x04` prefix followed by the 32-byte x
+/// and y coordinates of the point.
+pub const SECP256K1_UNCOMPRESSED_PUBLIC_KEY_LENGTH: usize = 65;
+
+/// Checks that the public key is a valid point of its curve.
+pub fn validate(public_key: &PublicKey) -> Result<(), PublicKeyError> {
+    match public_key {
+        PublicKey::Secp256k1(public_key) => secp256k1_validate(&public_key.0).map(|_| ()),
+        PublicKey::Ed25519(public_key) => ed25519_validate(&public_key.0).map(|_| ()),
+    }
+}
+
+/// Validates a compressed Secp256k1 public key: its length, its prefix and that the x coordinate
+/// is that of a point on the curve.
+pub fn secp256k1_validate(bytes: &[u8]) -> Result<Secp256k1PublicKey, PublicKeyError> {
+    check_length(bytes, Secp256k1PublicKey::LENGTH)?;
+    secp256k1::PublicKey::from_slice(bytes).map_err(|_| PublicKeyError::InvalidCurvePoint)?;
+    Secp256k1PublicKey::try_from(bytes).map_err(|_| PublicKeyError::InvalidCurvePoint)
+}
+
+/// Validates an Ed25519 public key: its length and that it decompresses to a point on the curve.
+pub fn ed25519_validate(bytes: &[u8]) -> Result<Ed25519PublicKey, PublicKeyError> {
+    check_length(bytes, Ed25519PublicKey::LENGTH)?;
+    ed25519_dalek::PublicKey::from_bytes(bytes).map_err(|_| PublicKeyError::InvalidCurvePoint)?;
+    Ed25519PublicKey::try_from(bytes).map_err(|_| PublicKeyError::InvalidCurvePoint)
+}
+
+/// Compresses an uncompressed Secp256k1 public key into the form used by Scrypto.
+pub fn secp256k1_compress(uncompressed: &[u8]) -> Result<Secp256k1PublicKey, PublicKeyError> {
+    check_length(uncompressed, SECP256K1_UNCOMPRESSED_PUBLIC_KEY_LENGTH)?;
+    let public_key = secp256k1::PublicKey::from_slice(uncompressed)
+        .map_err(|_| PublicKeyError::InvalidCurvePoint)?;
+    Ok(Secp256k1PublicKey(public_key.serialize()))
+}
+
+/// Decompresses a Secp256k1 public key into its uncompressed form.
+pub fn secp256k1_decompress(
+    public_key: &Secp256k1PublicKey,
+) -> Result<[u8; SECP256K1_UNCOMPRESSED_PUBLIC_KEY_LENGTH], PublicKeyError> {
+    let public_key = secp256k1::PublicKey::from_slice(&public_key.0)
+        .map_err(|_| PublicKeyError::InvalidCurvePoint)?;
+    Ok(public_key.serialize_uncompressed())
+}
+
+fn check_length(bytes: &[u8], expected: usize) -> Result<(), PublicKeyError> {
+    if bytes.len() == expected {
+        Ok(())
+    } else {
+        Err(PublicKeyError::InvalidLength {
+            expected,
+            actual: bytes.len(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PublicKeyError {
+    InvalidLength {
+        expected: usize,
+        actual: usize,
+    },
+    /// The bytes are not the encoding of a point on the curve of the key, or have an invalid
+    /// prefix in the case of Secp256k1 keys.
+    InvalidCurvePoint,
+}
@@ -0,0 +1,166 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use sbor::prelude::ContextualSerialize;
+use sbor::representations::{SerializationMode, SerializationParameters};
+use sbor::*;
+use scrypto::address::*;
+use scrypto::prelude::*;
+
+/// The maximum length of a payload that [`decode_unknown`] decodes when no limit is specified.
+pub const DEFAULT_MAXIMUM_SBOR_PAYLOAD_LENGTH: usize = 1024 * 1024;
+
+/// Limits on the payloads that [`decode_unknown`] accepts. Payloads that exceed them are rejected
+/// before they're decoded or while being decoded, which keeps pathological payloads from using an
+/// unbounded amount of memory or stack, which is especially important in WASM builds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SborDecodeLimits {
+    /// The maximum depth of the value, capped at the maximum depth of the SBOR flavor of the
+    /// payload.
+    pub maximum_depth: usize,
+    /// The maximum length of the payload in bytes.
+    pub maximum_length: usize,
+}
+
+impl Default for SborDecodeLimits {
+    fn default() -> Self {
+        Self {
+            maximum_depth: SCRYPTO_SBOR_V1_MAX_DEPTH,
+            maximum_length: DEFAULT_MAXIMUM_SBOR_PAYLOAD_LENGTH,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SborPayloadKind {
+    Scrypto,
+    Manifest,
+}
+
+impl SborPayloadKind {
+    /// Determines the SBOR flavor of a payload from its prefix byte.
+    pub fn of_payload(payload: &[u8]) -> Option<Self> {
+        match payload.first().copied() {
+            Some(SCRYPTO_SBOR_V1_PAYLOAD_PREFIX) => Some(Self::Scrypto),
+            Some(MANIFEST_SBOR_V1_PAYLOAD_PREFIX) => Some(Self::Manifest),
+            _ => None,
+        }
+    }
+
+    pub fn maximum_depth(self) -> usize {
+        match self {
+            Self::Scrypto => SCRYPTO_SBOR_V1_MAX_DEPTH,
+            Self::Manifest => MANIFEST_SBOR_V1_MAX_DEPTH,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownSborPayload {
+    pub kind: SborPayloadKind,
+    pub programmatic_json: String,
+    pub natural_json: String,
+}
+
+/// Decodes a payload that may be either Scrypto or Manifest SBOR, picking the flavor based on the
+/// payload prefix, and returns its programmatic and natural JSON representations.
+pub fn decode_unknown<T>(
+    payload: T,
+    limits: SborDecodeLimits,
+    bech32_encoder: &AddressBech32Encoder,
+) -> Result<UnknownSborPayload, SborDecodeUnknownError>
+where
+    T: AsRef<[u8]>,
+{
+    let payload = payload.as_ref();
+    if payload.len() > limits.maximum_length {
+        return Err(SborDecodeUnknownError::PayloadTooLong {
+            length: payload.len(),
+            maximum_length: limits.maximum_length,
+        });
+    }
+    let kind = match SborPayloadKind::of_payload(payload) {
+        Some(kind) => kind,
+        None => {
+            return Err(SborDecodeUnknownError::UnknownPayloadPrefix(
+                payload.first().copied(),
+            ))
+        }
+    };
+    let depth_limit = limits.maximum_depth.min(kind.maximum_depth());
+
+    let (programmatic_json, natural_json) = match kind {
+        SborPayloadKind::Scrypto => {
+            ScryptoDecoder::new(payload, depth_limit)
+                .decode_payload::<ScryptoValue>(SCRYPTO_SBOR_V1_PAYLOAD_PREFIX)
+                .map_err(SborDecodeUnknownError::DecodeError)?;
+
+            let payload = ScryptoRawPayload::new_from_valid_slice(payload);
+            let to_json = |mode| {
+                let serializable = payload.serializable(SerializationParameters::Schemaless {
+                    mode,
+                    custom_context: ScryptoValueDisplayContext::with_optional_bech32(Some(
+                        bech32_encoder,
+                    )),
+                    depth_limit,
+                });
+                serde_json::to_string(&serializable).expect("Impossible Case!")
+            };
+            (
+                to_json(SerializationMode::Programmatic),
+                to_json(SerializationMode::Natural),
+            )
+        }
+        SborPayloadKind::Manifest => {
+            ManifestDecoder::new(payload, depth_limit)
+                .decode_payload::<ManifestValue>(MANIFEST_SBOR_V1_PAYLOAD_PREFIX)
+                .map_err(SborDecodeUnknownError::DecodeError)?;
+
+            let payload = ManifestRawPayload::new_from_valid_slice(payload);
+            let to_json = |mode| {
+                let serializable = payload.serializable(SerializationParameters::Schemaless {
+                    mode,
+                    custom_context: ManifestValueDisplayContext::with_optional_bech32(Some(
+                        bech32_encoder,
+                    )),
+                    depth_limit,
+                });
+                serde_json::to_string(&serializable).expect("Impossible Case!")
+            };
+            (
+                to_json(SerializationMode::Programmatic),
+                to_json(SerializationMode::Natural),
+            )
+        }
+    };
+
+    Ok(UnknownSborPayload {
+        kind,
+        programmatic_json,
+        natural_json,
+    })
+}
+
+#[derive(Debug, Clone)]
+pub enum SborDecodeUnknownError {
+    UnknownPayloadPrefix(Option<u8>),
+    PayloadTooLong {
+        length: usize,
+        maximum_length: usize,
+    },
+    DecodeError(DecodeError),
+}
@@ -0,0 +1,347 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet, VecDeque};
+
+use radix_engine::blueprints::package::*;
+use radix_engine::types::*;
+use radix_engine_common::prelude::ScryptoCustomTypeKind;
+use sbor::{ChildNames, LocalTypeId, Schema, SchemaTypeKind};
+
+/// Decodes a [`PackageDefinition`] from its SBOR encoded form. Package definitions are typically
+/// manifest SBOR encoded (e.g. in `.rpd` files) but the Scrypto SBOR encoding is also accepted.
+pub fn decode_package_definition(payload: &[u8]) -> Result<PackageDefinition, SchemaDecodeError> {
+    manifest_decode::<PackageDefinition>(payload)
+        .or_else(|_| scrypto_decode::<PackageDefinition>(payload))
+        .map_err(SchemaDecodeError::DecodeError)
+}
+
+pub fn package_information(
+    package_definition: &PackageDefinition,
+) -> Result<Vec<BlueprintInformation>, SchemaDecodeError> {
+    package_definition
+        .blueprints
+        .iter()
+        .map(|(blueprint_name, blueprint_definition)| {
+            blueprint_information(blueprint_name, blueprint_definition)
+        })
+        .collect()
+}
+
+pub fn blueprint_information(
+    blueprint_name: &str,
+    blueprint_definition: &BlueprintDefinitionInit,
+) -> Result<BlueprintInformation, SchemaDecodeError> {
+    let VersionedScryptoSchema::V1(schema) = &blueprint_definition.schema.schema;
+
+    let functions = blueprint_definition
+        .schema
+        .functions
+        .functions
+        .iter()
+        .map(|(function_name, function_schema)| {
+            let arguments = match &function_schema.input {
+                TypeRef::Static(local_type_id) => function_arguments(schema, *local_type_id)?,
+                TypeRef::Generic(_) => vec![],
+            };
+            Ok(FunctionInformation {
+                name: function_name.clone(),
+                export_name: function_schema.export.clone(),
+                receiver: function_schema.receiver.clone(),
+                input: function_schema.input.clone(),
+                output: function_schema.output.clone(),
+                arguments,
+            })
+        })
+        .collect::<Result<Vec<_>, SchemaDecodeError>>()?;
+
+    let events = blueprint_definition
+        .schema
+        .events
+        .event_schema
+        .iter()
+        .map(|(event_name, type_ref)| EventInformation {
+            name: event_name.clone(),
+            event_type: type_ref.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    let roots = functions
+        .iter()
+        .flat_map(|function| [&function.input, &function.output])
+        .chain(events.iter().map(|event| &event.event_type))
+        .filter_map(|type_ref| match type_ref {
+            TypeRef::Static(local_type_id) => Some(*local_type_id),
+            TypeRef::Generic(_) => None,
+        });
+    let types = describe_reachable_types(schema, roots)?;
+
+    Ok(BlueprintInformation {
+        name: blueprint_name.to_owned(),
+        is_transient: blueprint_definition.is_transient,
+        feature_set: blueprint_definition.feature_set.clone(),
+        functions,
+        events,
+        types,
+    })
+}
+
+/// Looks up the signature of a function or method in the package definition. [`None`] is
+/// returned if either the blueprint or the function do not exist.
+pub fn function_signature(
+    package_definition: &PackageDefinition,
+    blueprint_name: &str,
+    function_name: &str,
+) -> Result<Option<FunctionInformation>, SchemaDecodeError> {
+    let Some(blueprint_definition) = package_definition.blueprints.get(blueprint_name) else {
+        return Ok(None);
+    };
+    Ok(blueprint_information(blueprint_name, blueprint_definition)?
+        .functions
+        .into_iter()
+        .find(|function| function.name == function_name))
+}
+
+fn function_arguments(
+    schema: &Schema<ScryptoCustomSchema>,
+    local_type_id: LocalTypeId,
+) -> Result<Vec<ArgumentInformation>, SchemaDecodeError> {
+    let Some(SchemaTypeKind::<ScryptoCustomSchema>::Tuple { field_types }) =
+        schema.resolve_type_kind(local_type_id)
+    else {
+        return Err(SchemaDecodeError::FunctionInputIsNotATuple(local_type_id));
+    };
+    let field_names = match schema
+        .resolve_type_metadata(local_type_id)
+        .and_then(|metadata| metadata.child_names.as_ref())
+    {
+        Some(ChildNames::NamedFields(names)) => names.iter().map(|name| name.to_string()).collect(),
+        _ => vec![],
+    };
+
+    Ok(field_types
+        .iter()
+        .enumerate()
+        .map(|(index, local_type_id)| ArgumentInformation {
+            name: field_names.get(index).cloned(),
+            local_type_id: *local_type_id,
+        })
+        .collect())
+}
+
+fn describe_reachable_types(
+    schema: &Schema<ScryptoCustomSchema>,
+    roots: impl Iterator<Item = LocalTypeId>,
+) -> Result<Vec<TypeDescription>, SchemaDecodeError> {
+    let mut visited = HashSet::<LocalTypeId>::new();
+    let mut queue = roots.collect::<VecDeque<_>>();
+    let mut descriptions = vec![];
+
+    while let Some(local_type_id) = queue.pop_front() {
+        if !visited.insert(local_type_id) {
+            continue;
+        }
+
+        let type_kind = schema
+            .resolve_type_kind(local_type_id)
+            .ok_or(SchemaDecodeError::InvalidLocalTypeId(local_type_id))?;
+        let type_metadata = schema.resolve_type_metadata(local_type_id);
+        let type_name = type_metadata
+            .and_then(|metadata| metadata.type_name.as_ref())
+            .map(|name| name.to_string());
+
+        let kind = match type_kind {
+            SchemaTypeKind::<ScryptoCustomSchema>::Array { element_type } => {
+                queue.push_back(*element_type);
+                TypeKindDescription::Array {
+                    element_type: *element_type,
+                }
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::Tuple { field_types } => {
+                queue.extend(field_types.iter().copied());
+                let field_names = match type_metadata.and_then(|m| m.child_names.as_ref()) {
+                    Some(ChildNames::NamedFields(names)) => {
+                        names.iter().map(|name| Some(name.to_string())).collect()
+                    }
+                    _ => vec![None; field_types.len()],
+                };
+                TypeKindDescription::Tuple {
+                    fields: field_names.into_iter().zip(field_types.clone()).collect(),
+                }
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::Enum { variants } => {
+                let variant_names = match type_metadata.and_then(|m| m.child_names.as_ref()) {
+                    Some(ChildNames::EnumVariants(variants)) => variants
+                        .iter()
+                        .filter_map(|(discriminator, metadata)| {
+                            metadata
+                                .type_name
+                                .as_ref()
+                                .map(|name| (*discriminator, name.to_string()))
+                        })
+                        .collect(),
+                    _ => BTreeMap::new(),
+                };
+                TypeKindDescription::Enum {
+                    variants: variants
+                        .iter()
+                        .map(|(discriminator, field_types)| {
+                            queue.extend(field_types.iter().copied());
+                            VariantDescription {
+                                discriminator: *discriminator,
+                                name: variant_names.get(discriminator).cloned(),
+                                field_types: field_types.clone(),
+                            }
+                        })
+                        .collect(),
+                }
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::Map {
+                key_type,
+                value_type,
+            } => {
+                queue.push_back(*key_type);
+                queue.push_back(*value_type);
+                TypeKindDescription::Map {
+                    key_type: *key_type,
+                    value_type: *value_type,
+                }
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::Any => TypeKindDescription::Any,
+            SchemaTypeKind::<ScryptoCustomSchema>::Bool => TypeKindDescription::Bool,
+            SchemaTypeKind::<ScryptoCustomSchema>::I8 => TypeKindDescription::I8,
+            SchemaTypeKind::<ScryptoCustomSchema>::I16 => TypeKindDescription::I16,
+            SchemaTypeKind::<ScryptoCustomSchema>::I32 => TypeKindDescription::I32,
+            SchemaTypeKind::<ScryptoCustomSchema>::I64 => TypeKindDescription::I64,
+            SchemaTypeKind::<ScryptoCustomSchema>::I128 => TypeKindDescription::I128,
+            SchemaTypeKind::<ScryptoCustomSchema>::U8 => TypeKindDescription::U8,
+            SchemaTypeKind::<ScryptoCustomSchema>::U16 => TypeKindDescription::U16,
+            SchemaTypeKind::<ScryptoCustomSchema>::U32 => TypeKindDescription::U32,
+            SchemaTypeKind::<ScryptoCustomSchema>::U64 => TypeKindDescription::U64,
+            SchemaTypeKind::<ScryptoCustomSchema>::U128 => TypeKindDescription::U128,
+            SchemaTypeKind::<ScryptoCustomSchema>::String => TypeKindDescription::String,
+            SchemaTypeKind::<ScryptoCustomSchema>::Custom(custom) => match custom {
+                ScryptoCustomTypeKind::Reference => TypeKindDescription::Reference,
+                ScryptoCustomTypeKind::Own => TypeKindDescription::Own,
+                ScryptoCustomTypeKind::Decimal => TypeKindDescription::Decimal,
+                ScryptoCustomTypeKind::PreciseDecimal => TypeKindDescription::PreciseDecimal,
+                ScryptoCustomTypeKind::NonFungibleLocalId => {
+                    TypeKindDescription::NonFungibleLocalId
+                }
+            },
+        };
+
+        descriptions.push(TypeDescription {
+            local_type_id,
+            type_name,
+            kind,
+        })
+    }
+
+    Ok(descriptions)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlueprintInformation {
+    pub name: String,
+    pub is_transient: bool,
+    pub feature_set: BTreeSet<String>,
+    pub functions: Vec<FunctionInformation>,
+    pub events: Vec<EventInformation>,
+    /// The descriptions of all of the types reachable from the functions and events of the
+    /// blueprint.
+    pub types: Vec<TypeDescription>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionInformation {
+    pub name: String,
+    pub export_name: String,
+    /// The receiver of the function, [`None`] for functions and [`Some`] for methods.
+    pub receiver: Option<ReceiverInfo>,
+    pub input: TypeRef<LocalTypeId>,
+    pub output: TypeRef<LocalTypeId>,
+    pub arguments: Vec<ArgumentInformation>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArgumentInformation {
+    pub name: Option<String>,
+    pub local_type_id: LocalTypeId,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventInformation {
+    pub name: String,
+    pub event_type: TypeRef<LocalTypeId>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypeDescription {
+    pub local_type_id: LocalTypeId,
+    pub type_name: Option<String>,
+    pub kind: TypeKindDescription,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TypeKindDescription {
+    Any,
+    Bool,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    String,
+    Array {
+        element_type: LocalTypeId,
+    },
+    Tuple {
+        fields: Vec<(Option<String>, LocalTypeId)>,
+    },
+    Enum {
+        variants: Vec<VariantDescription>,
+    },
+    Map {
+        key_type: LocalTypeId,
+        value_type: LocalTypeId,
+    },
+    Reference,
+    Own,
+    Decimal,
+    PreciseDecimal,
+    NonFungibleLocalId,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VariantDescription {
+    pub discriminator: u8,
+    pub name: Option<String>,
+    pub field_types: Vec<LocalTypeId>,
+}
+
+#[derive(Debug, Clone)]
+pub enum SchemaDecodeError {
+    DecodeError(DecodeError),
+    InvalidLocalTypeId(LocalTypeId),
+    FunctionInputIsNotATuple(LocalTypeId),
+}
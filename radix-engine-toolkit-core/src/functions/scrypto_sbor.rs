@@ -87,16 +87,78 @@ where
 pub fn encode_string_representation(
     representation: StringRepresentation,
 ) -> Result<Vec<u8>, ScryptoSborError> {
-    match representation {
+    let value = value_from_string_representation(representation)?;
+    scrypto_encode(&value).map_err(ScryptoSborError::EncodeError)
+}
+
+pub fn value_from_string_representation(
+    representation: StringRepresentation,
+) -> Result<ScryptoValue, ScryptoSborError> {
+    let value = match representation {
         StringRepresentation::ProgrammaticJson(value) => {
-            let value = serde_json::from_str::<ProgrammaticScryptoValue>(&value)
+            serde_json::from_str::<ProgrammaticScryptoValue>(&value)
+                .map_err(ScryptoSborError::SerdeDeserializationFailed)?
+        }
+        StringRepresentation::CoreApiProgrammaticJson(value) => {
+            let mut value = serde_json::from_str::<serde_json::Value>(&value)
                 .map_err(ScryptoSborError::SerdeDeserializationFailed)?;
-            if value_contains_network_mismatch(&value) {
-                return Err(ScryptoSborError::ValueContainsNetworkMismatch);
-            }
+            normalize_core_api_programmatic_json(&mut value);
+            serde_json::from_value::<ProgrammaticScryptoValue>(value)
+                .map_err(ScryptoSborError::SerdeDeserializationFailed)?
+        }
+    };
+    if value_contains_network_mismatch(&value) {
+        return Err(ScryptoSborError::ValueContainsNetworkMismatch);
+    }
+
+    Ok(value.to_scrypto_value())
+}
 
-            let value = value.to_scrypto_value();
-            scrypto_encode(&value).map_err(ScryptoSborError::EncodeError)
+/// Rewrites a value in the programmatic JSON format emitted by the Core and Gateway APIs into the
+/// programmatic JSON format of the toolkit. The two formats differ in that the APIs:
+///
+/// * Annotate values with their `type_name`, `field_name` and `variant_name` which the toolkit
+///   doesn't need to encode the value.
+/// * Represent byte arrays as values of the `Array` kind with a `hex` field while the toolkit has a
+///   dedicated `Bytes` kind for them.
+/// * Represent some integers and enum variant ids as JSON numbers while the toolkit always uses
+///   strings for them.
+fn normalize_core_api_programmatic_json(value: &mut serde_json::Value) {
+    let serde_json::Value::Object(object) = value else {
+        return;
+    };
+
+    for annotation in ["type_name", "field_name", "variant_name"] {
+        object.remove(annotation);
+    }
+    if object.get("kind").and_then(serde_json::Value::as_str) == Some("Array")
+        && object.contains_key("hex")
+    {
+        object.insert("kind".to_owned(), "Bytes".into());
+    }
+    for key in ["value", "variant_id"] {
+        if let Some(serde_json::Value::Number(number)) = object.get(key) {
+            let number = number.to_string();
+            object.insert(key.to_owned(), number.into());
+        }
+    }
+
+    for key in ["fields", "elements"] {
+        if let Some(serde_json::Value::Array(values)) = object.get_mut(key) {
+            values
+                .iter_mut()
+                .for_each(normalize_core_api_programmatic_json);
+        }
+    }
+    if let Some(serde_json::Value::Array(entries)) = object.get_mut("entries") {
+        for entry in entries.iter_mut() {
+            if let serde_json::Value::Object(entry) = entry {
+                for key in ["key", "value"] {
+                    if let Some(value) = entry.get_mut(key) {
+                        normalize_core_api_programmatic_json(value);
+                    }
+                }
+            }
         }
     }
 }
@@ -104,6 +166,9 @@ pub fn encode_string_representation(
 #[derive(Debug, Clone)]
 pub enum StringRepresentation {
     ProgrammaticJson(String),
+    /// The programmatic JSON format emitted by the Core and Gateway APIs, which differs slightly
+    /// from the programmatic JSON of the toolkit.
+    CoreApiProgrammaticJson(String),
 }
 
 #[derive(Debug)]
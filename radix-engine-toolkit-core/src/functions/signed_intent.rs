@@ -19,6 +19,7 @@ use sbor::*;
 use scrypto::prelude::*;
 use transaction::errors::*;
 use transaction::model::*;
+use transaction::prelude::*;
 use transaction::validation::*;
 
 use crate::models::transaction_hash::TransactionHash;
@@ -51,3 +52,110 @@ pub fn statically_validate(
         .map_err(TransactionValidationError::PrepareError)
         .and_then(|prepared| validator.validate_intent_v1(&prepared.intent))
 }
+
+/// Normalizes the signatures of the signed intent: every signature is verified against the intent
+/// hash, signatures from a public key that already signed are dropped, and the remaining signatures
+/// are sorted by their public key. Signed intents with the same intent and the same set of signers
+/// thus normalize to the same payload regardless of the order in which the signatures were
+/// collected.
+pub fn normalize_signatures(
+    signed_intent: &SignedIntentV1,
+) -> Result<SignedIntentV1, SignatureNormalizationError> {
+    let intent_hash = signed_intent
+        .intent
+        .prepare()
+        .map_err(SignatureNormalizationError::PrepareError)?
+        .intent_hash();
+
+    let mut signatures = BTreeMap::<Vec<u8>, IntentSignatureV1>::new();
+    for (index, signature) in signed_intent
+        .intent_signatures
+        .signatures
+        .iter()
+        .enumerate()
+    {
+        let public_key = verify_and_recover(intent_hash.as_hash(), &signature.0)
+            .ok_or(SignatureNormalizationError::InvalidSignature { index })?;
+        let sort_key =
+            scrypto_encode(&public_key).map_err(SignatureNormalizationError::EncodeError)?;
+        signatures
+            .entry(sort_key)
+            .or_insert_with(|| signature.clone());
+    }
+
+    Ok(SignedIntentV1 {
+        intent: signed_intent.intent.clone(),
+        intent_signatures: IntentSignaturesV1 {
+            signatures: signatures.into_values().collect(),
+        },
+    })
+}
+
+/// Checks the intent signatures of the signed intent against the `notary_is_signatory` flag of its
+/// header. Signatures that do not verify against the intent hash are ignored here as they are
+/// reported by the static validation of the signed intent.
+pub fn notary_signatory_issues(
+    signed_intent: &SignedIntentV1,
+) -> Result<Vec<NotarySignatoryIssue>, PrepareError> {
+    let header = &signed_intent.intent.header;
+    let intent_hash = signed_intent.intent.prepare()?.intent_hash();
+
+    let mut issues = vec![];
+    let notary_signature_indices = signed_intent
+        .intent_signatures
+        .signatures
+        .iter()
+        .enumerate()
+        .filter_map(|(index, signature)| {
+            verify_and_recover(intent_hash.as_hash(), &signature.0)
+                .filter(|public_key| *public_key == header.notary_public_key)
+                .map(|_| index)
+        })
+        .collect::<Vec<_>>();
+
+    if header.notary_is_signatory {
+        issues.extend(
+            notary_signature_indices
+                .into_iter()
+                .map(|index| NotarySignatoryIssue::RedundantNotarySignature { index }),
+        );
+
+        let notary_is_required_signer = crate::functions::intent::required_signing_keys(
+            &IntentV1 {
+                header: TransactionHeaderV1 {
+                    notary_is_signatory: false,
+                    ..header.clone()
+                },
+                ..signed_intent.intent.clone()
+            },
+            &[header.notary_public_key],
+        )
+        .contains(&header.notary_public_key);
+        if !notary_is_required_signer {
+            issues.push(NotarySignatoryIssue::NotaryNotRequiredSigner)
+        }
+    }
+
+    Ok(issues)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NotarySignatoryIssue {
+    /// The notary is a signatory yet the intent signature at the given index is also by the notary.
+    /// The signature is redundant and the transaction is rejected for having duplicate signers.
+    RedundantNotarySignature { index: usize },
+    /// The notary is a signatory but neither its virtual account nor its virtual identity is a
+    /// required signer of the manifest. The flag is likely set by mistake, unless the notary
+    /// signature badge is needed by some other access rule.
+    NotaryNotRequiredSigner,
+}
+
+#[derive(Clone, Debug)]
+pub enum SignatureNormalizationError {
+    PrepareError(PrepareError),
+    EncodeError(EncodeError),
+    /// The signature at the given index does not verify against the intent hash.
+    InvalidSignature {
+        index: usize,
+    },
+}
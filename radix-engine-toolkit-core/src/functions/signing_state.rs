@@ -0,0 +1,248 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Incremental tracking of the signatures collected for an intent that requires several signers,
+//! e.g. when the signatures of a multisig transaction are gathered from different devices.
+
+use sbor::*;
+use scrypto::prelude::*;
+use transaction::model::*;
+use transaction::prelude::*;
+use transaction::validation::*;
+
+use crate::instruction_visitor::visitors::required_signers_visitor::*;
+
+/// The signing state of an intent. It knows the signers that the intent requires, which of them
+/// have provided enough valid signatures, and whether the intent is ready to be notarized.
+///
+/// Signers that are virtual accounts or identities are satisfied by a signature of the key that
+/// they were derived from. The keys of any other signer are not known to the toolkit and must be
+/// provided through [`SigningState::set_signer_keys`], until then the signer is never satisfied.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SigningState {
+    signed_intent: SignedIntentV1,
+    signers: Vec<SignerRequirement>,
+}
+
+/// The keys that can sign for a required signer and the number of distinct signatures of them that
+/// are needed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignerRequirement {
+    pub entity_address: GlobalAddress,
+    pub keys: Vec<PublicKey>,
+    pub threshold: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignerStatus {
+    pub entity_address: GlobalAddress,
+    pub threshold: u8,
+    /// The keys that have signed for the signer, including the notary key if it's a signatory.
+    pub signed_by: Vec<PublicKey>,
+    pub is_satisfied: bool,
+}
+
+impl SigningState {
+    pub fn new(intent: IntentV1) -> Self {
+        let signers = crate::functions::instructions::required_signers(&intent.instructions.0)
+            .into_iter()
+            .map(|RequiredSigner { entity_address, .. }| SignerRequirement {
+                entity_address,
+                keys: vec![],
+                threshold: 1,
+            })
+            .collect();
+        Self {
+            signed_intent: SignedIntentV1 {
+                intent,
+                intent_signatures: IntentSignaturesV1 { signatures: vec![] },
+            },
+            signers,
+        }
+    }
+
+    pub fn intent(&self) -> &IntentV1 {
+        &self.signed_intent.intent
+    }
+
+    pub fn signers(&self) -> &[SignerRequirement] {
+        &self.signers
+    }
+
+    /// Sets the keys and threshold of a required signer, replacing any that were set before. Used
+    /// for signers whose keys can't be derived from their address, such as allocated accounts
+    /// secured by a multi-key access rule.
+    pub fn set_signer_keys(
+        &mut self,
+        entity_address: GlobalAddress,
+        keys: Vec<PublicKey>,
+        threshold: u8,
+    ) -> Result<(), SigningStateError> {
+        let signer = self
+            .signers
+            .iter_mut()
+            .find(|signer| signer.entity_address == entity_address)
+            .ok_or(SigningStateError::NotARequiredSigner(entity_address))?;
+        signer.keys = keys;
+        signer.threshold = threshold;
+        Ok(())
+    }
+
+    /// Adds a signature to the state after verifying it against the intent hash. Returns the
+    /// signers that the signature counts towards.
+    pub fn add_signature(
+        &mut self,
+        signature: SignatureWithPublicKeyV1,
+    ) -> Result<Vec<GlobalAddress>, SigningStateError> {
+        let intent_hash = self
+            .signed_intent
+            .intent
+            .prepare()
+            .map_err(SigningStateError::PrepareError)?
+            .intent_hash();
+        let public_key = verify_and_recover(intent_hash.as_hash(), &signature)
+            .ok_or(SigningStateError::InvalidSignature)?;
+        if self.signature_keys().contains(&public_key) {
+            return Err(SigningStateError::DuplicateSignature(public_key));
+        }
+
+        let signers = self
+            .signers
+            .iter()
+            .filter(|signer| signer.accepts(&public_key))
+            .map(|signer| signer.entity_address)
+            .collect::<Vec<_>>();
+        if signers.is_empty() {
+            return Err(SigningStateError::SignatureNotRequired(public_key));
+        }
+
+        self.signed_intent
+            .intent_signatures
+            .signatures
+            .push(IntentSignatureV1(signature));
+        Ok(signers)
+    }
+
+    pub fn signer_statuses(&self) -> Vec<SignerStatus> {
+        let mut keys = self.signature_keys();
+        let header = &self.signed_intent.intent.header;
+        if header.notary_is_signatory {
+            keys.push(header.notary_public_key)
+        }
+
+        self.signers
+            .iter()
+            .map(|signer| {
+                let signed_by = keys
+                    .iter()
+                    .filter(|key| signer.accepts(key))
+                    .copied()
+                    .collect::<Vec<_>>();
+                SignerStatus {
+                    entity_address: signer.entity_address,
+                    threshold: signer.threshold,
+                    is_satisfied: signed_by.len() >= signer.threshold as usize,
+                    signed_by,
+                }
+            })
+            .collect()
+    }
+
+    /// The required signers that do not yet have enough signatures.
+    pub fn remaining_signers(&self) -> Vec<GlobalAddress> {
+        self.signer_statuses()
+            .into_iter()
+            .filter(|status| !status.is_satisfied)
+            .map(|status| status.entity_address)
+            .collect()
+    }
+
+    /// Checks if all of the required signers are satisfied, in which case the signed intent can be
+    /// handed to the notary.
+    pub fn can_notarize(&self) -> bool {
+        self.remaining_signers().is_empty()
+    }
+
+    pub fn signed_intent(&self) -> &SignedIntentV1 {
+        &self.signed_intent
+    }
+
+    /// Encodes the state so that signing can be resumed in a different session through
+    /// [`SigningState::from_bytes`].
+    pub fn to_bytes(&self) -> Result<Vec<u8>, EncodeError> {
+        let signers = self
+            .signers
+            .iter()
+            .map(|signer| (signer.entity_address, signer.keys.clone(), signer.threshold))
+            .collect::<Vec<_>>();
+        manifest_encode(&(self.signed_intent.clone(), signers))
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let (signed_intent, signers) =
+            manifest_decode::<(SignedIntentV1, Vec<(GlobalAddress, Vec<PublicKey>, u8)>)>(bytes)?;
+        Ok(Self {
+            signed_intent,
+            signers: signers
+                .into_iter()
+                .map(|(entity_address, keys, threshold)| SignerRequirement {
+                    entity_address,
+                    keys,
+                    threshold,
+                })
+                .collect(),
+        })
+    }
+
+    /// The keys of the collected signatures. Signatures are verified when they're added, those
+    /// that don't verify (e.g. in a tampered encoded state) are not counted.
+    fn signature_keys(&self) -> Vec<PublicKey> {
+        let Ok(prepared_intent) = self.signed_intent.intent.prepare() else {
+            return vec![];
+        };
+        let intent_hash = prepared_intent.intent_hash();
+        self.signed_intent
+            .intent_signatures
+            .signatures
+            .iter()
+            .filter_map(|signature| verify_and_recover(intent_hash.as_hash(), &signature.0))
+            .collect()
+    }
+}
+
+impl SignerRequirement {
+    fn accepts(&self, public_key: &PublicKey) -> bool {
+        self.keys.contains(public_key)
+            || self.entity_address
+                == GlobalAddress::from(ComponentAddress::virtual_account_from_public_key(
+                    public_key,
+                ))
+            || self.entity_address
+                == GlobalAddress::from(ComponentAddress::virtual_identity_from_public_key(
+                    public_key,
+                ))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum SigningStateError {
+    PrepareError(PrepareError),
+    InvalidSignature,
+    DuplicateSignature(PublicKey),
+    SignatureNotRequired(PublicKey),
+    NotARequiredSigner(GlobalAddress),
+}
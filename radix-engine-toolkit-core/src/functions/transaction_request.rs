@@ -0,0 +1,214 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The transaction request that dApps send to wallets, through the Connector extension, for the
+//! wallet to review, sign, and submit. This module holds the shared implementation of its
+//! normalization and limits so that the extension and the wallets agree on which requests are
+//! valid. The wire encoding of the request is handled by the toolkit's JSON interface.
+
+use scrypto::prelude::*;
+use transaction::manifest::*;
+use transaction::prelude::*;
+
+use crate::functions::instructions::{diagnose_compile_error, ManifestCompileDiagnostic};
+use crate::utils::{is_account, network_definition_from_network_id};
+
+/// The version of the transaction request that this module implements.
+pub const TRANSACTION_REQUEST_VERSION: u64 = 1;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionRequest {
+    pub version: u64,
+    /// The manifest of the transaction in its string representation.
+    pub transaction_manifest: String,
+    pub blobs: Vec<Vec<u8>>,
+    /// A plaintext message to attach to the transaction.
+    pub message: Option<String>,
+    /// The fee that the dApp suggests to lock for the transaction. The wallet remains free to lock
+    /// any fee it sees fit.
+    pub lock_fee_hint: Option<LockFeeHint>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LockFeeHint {
+    pub amount: Decimal,
+    /// The account that the dApp suggests to lock the fee against, if any.
+    pub account: Option<ComponentAddress>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionRequestLimits {
+    /// The maximum length of the manifest string in bytes.
+    pub max_manifest_length: usize,
+    /// The maximum size of all of the blobs combined in bytes.
+    pub max_blobs_size: usize,
+    /// The maximum length of the message in bytes.
+    pub max_message_length: usize,
+}
+
+impl Default for TransactionRequestLimits {
+    /// The limits of the transaction validator as the wallet would not be able to submit a request
+    /// exceeding them.
+    fn default() -> Self {
+        let validation_config = ValidationConfig::default(NetworkDefinition::mainnet().id);
+        Self {
+            max_manifest_length: validation_config.max_notarized_payload_size,
+            max_blobs_size: validation_config.max_notarized_payload_size,
+            max_message_length: validation_config
+                .message_validation
+                .max_plaintext_message_length,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransactionRequestViolation {
+    UnsupportedVersion { version: u64, supported: u64 },
+    ManifestTooLong { length: usize, max: usize },
+    BlobsTooLarge { size: usize, max: usize },
+    MessageTooLong { length: usize, max: usize },
+    LockFeeHintNotPositive { amount: Decimal },
+    LockFeeHintAccountNotAnAccount { address: ComponentAddress },
+    InvalidManifest(ManifestCompileDiagnostic),
+}
+
+/// Constructs the transaction request of a manifest.
+pub fn from_manifest(
+    manifest: &TransactionManifestV1,
+    network_id: u8,
+    message: Option<String>,
+    lock_fee_hint: Option<LockFeeHint>,
+) -> Result<TransactionRequest, DecompileError> {
+    let network_definition = network_definition_from_network_id(network_id);
+    let transaction_manifest = decompile(&manifest.instructions, &network_definition)?;
+
+    Ok(normalize(TransactionRequest {
+        version: TRANSACTION_REQUEST_VERSION,
+        transaction_manifest,
+        blobs: manifest.blobs.values().cloned().collect(),
+        message,
+        lock_fee_hint,
+    }))
+}
+
+/// Normalizes the request so that equivalent requests are equal: line endings of the manifest are
+/// normalized to `\n` and it's trimmed, the message is trimmed and dropped when empty, and repeated
+/// blobs are removed.
+pub fn normalize(request: TransactionRequest) -> TransactionRequest {
+    let TransactionRequest {
+        version,
+        transaction_manifest,
+        blobs,
+        message,
+        lock_fee_hint,
+    } = request;
+
+    let transaction_manifest = transaction_manifest.replace("\r\n", "\n").trim().to_owned();
+    let message = message
+        .map(|message| message.trim().to_owned())
+        .filter(|message| !message.is_empty());
+    let blobs = blobs
+        .into_iter()
+        .collect::<IndexSet<_>>()
+        .into_iter()
+        .collect();
+
+    TransactionRequest {
+        version,
+        transaction_manifest,
+        blobs,
+        message,
+        lock_fee_hint,
+    }
+}
+
+/// Checks the request against the limits and compiles its manifest for the network. All of the
+/// violations are reported rather than only the first.
+pub fn validate(
+    request: &TransactionRequest,
+    network_id: u8,
+    limits: &TransactionRequestLimits,
+) -> Vec<TransactionRequestViolation> {
+    let mut violations = vec![];
+
+    if request.version != TRANSACTION_REQUEST_VERSION {
+        violations.push(TransactionRequestViolation::UnsupportedVersion {
+            version: request.version,
+            supported: TRANSACTION_REQUEST_VERSION,
+        })
+    }
+
+    let manifest_length = request.transaction_manifest.len();
+    if manifest_length > limits.max_manifest_length {
+        violations.push(TransactionRequestViolation::ManifestTooLong {
+            length: manifest_length,
+            max: limits.max_manifest_length,
+        })
+    }
+
+    let blobs_size = request.blobs.iter().map(Vec::len).sum::<usize>();
+    if blobs_size > limits.max_blobs_size {
+        violations.push(TransactionRequestViolation::BlobsTooLarge {
+            size: blobs_size,
+            max: limits.max_blobs_size,
+        })
+    }
+
+    if let Some(message) = &request.message {
+        if message.len() > limits.max_message_length {
+            violations.push(TransactionRequestViolation::MessageTooLong {
+                length: message.len(),
+                max: limits.max_message_length,
+            })
+        }
+    }
+
+    if let Some(LockFeeHint { amount, account }) = &request.lock_fee_hint {
+        if !amount.is_positive() {
+            violations.push(TransactionRequestViolation::LockFeeHintNotPositive { amount: *amount })
+        }
+        if let Some(account) = account {
+            if !is_account(account) {
+                violations.push(
+                    TransactionRequestViolation::LockFeeHintAccountNotAnAccount {
+                        address: *account,
+                    },
+                )
+            }
+        }
+    }
+
+    if let Err(diagnostic) = to_manifest(request, network_id) {
+        violations.push(TransactionRequestViolation::InvalidManifest(diagnostic))
+    }
+
+    violations
+}
+
+/// Compiles the manifest of the request along with its blobs.
+pub fn to_manifest(
+    request: &TransactionRequest,
+    network_id: u8,
+) -> Result<TransactionManifestV1, ManifestCompileDiagnostic> {
+    let network_definition = network_definition_from_network_id(network_id);
+    compile(
+        &request.transaction_manifest,
+        &network_definition,
+        BlobProvider::new_with_blobs(request.blobs.clone()),
+    )
+    .map_err(|error| diagnose_compile_error(&request.transaction_manifest, &error))
+}
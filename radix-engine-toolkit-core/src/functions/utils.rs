@@ -63,3 +63,20 @@ pub fn decode_transaction_id(
     // Validation complete, return data bytes
     Ok(hash)
 }
+
+/// Returns the offset of the first byte at which the two payloads differ, or [`None`] if they are
+/// identical. If one payload is a prefix of the other then the offset is the length of the shorter
+/// one.
+pub fn first_divergence(original: &[u8], other: &[u8]) -> Option<usize> {
+    original
+        .iter()
+        .zip(other.iter())
+        .position(|(a, b)| a != b)
+        .or_else(|| {
+            if original.len() == other.len() {
+                None
+            } else {
+                Some(original.len().min(other.len()))
+            }
+        })
+}
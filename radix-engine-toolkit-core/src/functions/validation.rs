@@ -0,0 +1,293 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The static validation rules of transactions as individual checks. Unlike the
+//! `statically_validate` functions, which stop at the first error they encounter, every check here
+//! reports all of the violations it finds and the aggregate checks run all of the rules that apply
+//! to the given model so that the full list of problems can be shown at once.
+
+use sbor::*;
+use scrypto::prelude::*;
+use transaction::errors::*;
+use transaction::model::*;
+use transaction::validation::*;
+
+/// The maximum number of intent signatures that the transaction validator accepts.
+pub const MAX_NUMBER_OF_INTENT_SIGNATURES: usize = 16;
+
+#[derive(Clone, Debug)]
+pub enum StaticValidationViolation {
+    NetworkIdMismatch {
+        expected: u8,
+        actual: u8,
+    },
+    EmptyEpochRange {
+        start_epoch_inclusive: Epoch,
+        end_epoch_exclusive: Epoch,
+    },
+    EpochRangeTooLarge {
+        range: u64,
+        max: u64,
+    },
+    TipPercentageOutOfRange {
+        tip_percentage: u16,
+        min: u16,
+        max: u16,
+    },
+    TooManyIntentSignatures {
+        count: usize,
+        max: usize,
+    },
+    MimeTypeTooLong {
+        length: usize,
+        max: usize,
+    },
+    PlaintextMessageTooLong {
+        length: usize,
+        max: usize,
+    },
+    EncryptedMessageTooLong {
+        length: usize,
+        max: usize,
+    },
+    NoDecryptors,
+    NoDecryptorsForCurve(CurveType),
+    DecryptorsCurveMismatch {
+        expected: CurveType,
+        actual: CurveType,
+    },
+    TooManyDecryptors {
+        count: usize,
+        max: usize,
+    },
+    ManifestTooDeep {
+        max_depth: usize,
+    },
+    InvalidIdAllocation(TransactionValidationError),
+    NotarizedPayloadTooLarge {
+        size: usize,
+        max: usize,
+    },
+}
+
+/// Checks that the header is for the network of the validation config and that its epoch range
+/// and tip percentage are within the allowed bounds.
+pub fn validate_header(
+    header: &TransactionHeaderV1,
+    validation_config: &ValidationConfig,
+) -> Vec<StaticValidationViolation> {
+    let mut violations = vec![];
+
+    if header.network_id != validation_config.network_id {
+        violations.push(StaticValidationViolation::NetworkIdMismatch {
+            expected: validation_config.network_id,
+            actual: header.network_id,
+        })
+    }
+
+    if header.end_epoch_exclusive <= header.start_epoch_inclusive {
+        violations.push(StaticValidationViolation::EmptyEpochRange {
+            start_epoch_inclusive: header.start_epoch_inclusive,
+            end_epoch_exclusive: header.end_epoch_exclusive,
+        })
+    } else {
+        let range = header.end_epoch_exclusive.number() - header.start_epoch_inclusive.number();
+        if range > validation_config.max_epoch_range {
+            violations.push(StaticValidationViolation::EpochRangeTooLarge {
+                range,
+                max: validation_config.max_epoch_range,
+            })
+        }
+    }
+
+    if header.tip_percentage < validation_config.min_tip_percentage
+        || header.tip_percentage > validation_config.max_tip_percentage
+    {
+        violations.push(StaticValidationViolation::TipPercentageOutOfRange {
+            tip_percentage: header.tip_percentage,
+            min: validation_config.min_tip_percentage,
+            max: validation_config.max_tip_percentage,
+        })
+    }
+
+    violations
+}
+
+pub fn validate_intent_signature_count(
+    signed_intent: &SignedIntentV1,
+) -> Vec<StaticValidationViolation> {
+    let count = signed_intent.intent_signatures.signatures.len();
+    if count > MAX_NUMBER_OF_INTENT_SIGNATURES {
+        vec![StaticValidationViolation::TooManyIntentSignatures {
+            count,
+            max: MAX_NUMBER_OF_INTENT_SIGNATURES,
+        }]
+    } else {
+        vec![]
+    }
+}
+
+/// Checks the sizes of the message and, for encrypted messages, the decryptors of each curve.
+pub fn validate_message(
+    message: &MessageV1,
+    message_validation_config: &MessageValidationConfig,
+) -> Vec<StaticValidationViolation> {
+    let mut violations = vec![];
+
+    match message {
+        MessageV1::None => {}
+        MessageV1::Plaintext(PlaintextMessageV1 { mime_type, message }) => {
+            if mime_type.len() > message_validation_config.max_mime_type_length {
+                violations.push(StaticValidationViolation::MimeTypeTooLong {
+                    length: mime_type.len(),
+                    max: message_validation_config.max_mime_type_length,
+                })
+            }
+            let length = match message {
+                MessageContentsV1::String(string) => string.len(),
+                MessageContentsV1::Bytes(bytes) => bytes.len(),
+            };
+            if length > message_validation_config.max_plaintext_message_length {
+                violations.push(StaticValidationViolation::PlaintextMessageTooLong {
+                    length,
+                    max: message_validation_config.max_plaintext_message_length,
+                })
+            }
+        }
+        MessageV1::Encrypted(EncryptedMessageV1 {
+            encrypted,
+            decryptors_by_curve,
+        }) => {
+            if encrypted.0.len() > message_validation_config.max_encrypted_message_length {
+                violations.push(StaticValidationViolation::EncryptedMessageTooLong {
+                    length: encrypted.0.len(),
+                    max: message_validation_config.max_encrypted_message_length,
+                })
+            }
+            if decryptors_by_curve.is_empty() {
+                violations.push(StaticValidationViolation::NoDecryptors)
+            }
+
+            let mut count = 0;
+            for (curve_type, decryptors) in decryptors_by_curve.iter() {
+                let (actual, decryptors) = match decryptors {
+                    DecryptorsByCurve::Ed25519 { decryptors, .. } => {
+                        (CurveType::Ed25519, decryptors)
+                    }
+                    DecryptorsByCurve::Secp256k1 { decryptors, .. } => {
+                        (CurveType::Secp256k1, decryptors)
+                    }
+                };
+                if actual != *curve_type {
+                    violations.push(StaticValidationViolation::DecryptorsCurveMismatch {
+                        expected: *curve_type,
+                        actual,
+                    })
+                }
+                if decryptors.is_empty() {
+                    violations.push(StaticValidationViolation::NoDecryptorsForCurve(*curve_type))
+                }
+                count += decryptors.len();
+            }
+            if count > message_validation_config.max_decryptors {
+                violations.push(StaticValidationViolation::TooManyDecryptors {
+                    count,
+                    max: message_validation_config.max_decryptors,
+                })
+            }
+        }
+    }
+
+    violations
+}
+
+/// Checks that the intent can be encoded without exceeding the maximum depth of the manifest SBOR
+/// payload, which deeply nested instruction arguments do.
+pub fn validate_manifest_depth(intent: &IntentV1) -> Vec<StaticValidationViolation> {
+    match crate::functions::intent::compile(intent) {
+        Err(EncodeError::MaxDepthExceeded(max_depth)) => {
+            vec![StaticValidationViolation::ManifestTooDeep { max_depth }]
+        }
+        _ => vec![],
+    }
+}
+
+/// Checks that the buckets, proofs, address reservations, and named addresses are allocated and
+/// used correctly by the instructions. Only the first violation is reported as the allocations
+/// that follow it can't be meaningfully checked.
+pub fn validate_id_allocation(instructions: &[InstructionV1]) -> Vec<StaticValidationViolation> {
+    match NotarizedTransactionValidator::validate_instructions_v1(instructions) {
+        Ok(()) => vec![],
+        Err(error) => vec![StaticValidationViolation::InvalidIdAllocation(error)],
+    }
+}
+
+/// Checks the size of the compiled notarized transaction. Transactions which can't be compiled are
+/// reported by [`validate_manifest_depth`] instead.
+pub fn validate_notarized_payload_size(
+    notarized_transaction: &NotarizedTransactionV1,
+    validation_config: &ValidationConfig,
+) -> Vec<StaticValidationViolation> {
+    match crate::functions::notarized_transaction::compile(notarized_transaction) {
+        Ok(payload) if payload.len() > validation_config.max_notarized_payload_size => {
+            vec![StaticValidationViolation::NotarizedPayloadTooLarge {
+                size: payload.len(),
+                max: validation_config.max_notarized_payload_size,
+            }]
+        }
+        _ => vec![],
+    }
+}
+
+/// Runs all of the rules that apply to an intent.
+pub fn validate_intent(
+    intent: &IntentV1,
+    validation_config: &ValidationConfig,
+) -> Vec<StaticValidationViolation> {
+    let mut violations = validate_header(&intent.header, validation_config);
+    violations.extend(validate_message(
+        &intent.message,
+        &validation_config.message_validation,
+    ));
+    violations.extend(validate_manifest_depth(intent));
+    violations.extend(validate_id_allocation(&intent.instructions.0));
+    violations
+}
+
+/// Runs all of the rules that apply to a signed intent.
+pub fn validate_signed_intent(
+    signed_intent: &SignedIntentV1,
+    validation_config: &ValidationConfig,
+) -> Vec<StaticValidationViolation> {
+    let mut violations = validate_intent(&signed_intent.intent, validation_config);
+    violations.extend(validate_intent_signature_count(signed_intent));
+    violations
+}
+
+/// Runs all of the rules that apply to a notarized transaction.
+pub fn validate_notarized_transaction(
+    notarized_transaction: &NotarizedTransactionV1,
+    validation_config: &ValidationConfig,
+) -> Vec<StaticValidationViolation> {
+    let mut violations =
+        validate_signed_intent(&notarized_transaction.signed_intent, validation_config);
+    violations.extend(validate_notarized_payload_size(
+        notarized_transaction,
+        validation_config,
+    ));
+    violations
+}
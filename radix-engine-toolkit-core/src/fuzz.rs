@@ -0,0 +1,298 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Structure-aware generators of manifest values, instructions, and intents for fuzzing. The
+//! generators are driven by [`Unstructured`] data and are thus deterministic: the same input bytes
+//! always produce the same output, which allows integrators to plug them into `cargo fuzz` targets
+//! through the [`Arbitrary`] wrappers, or to reproduce a case from a seed through the `*_from_seed`
+//! functions.
+//!
+//! The generated values are well-formed enough to survive an SBOR encode/decode round-trip but are
+//! not guaranteed to pass static validation (e.g. buckets may be referenced before being created).
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+use radix_engine_common::data::manifest::converter::{from_decimal, from_non_fungible_local_id};
+use scrypto::prelude::*;
+use transaction::prelude::*;
+
+/// The maximum depth of the generated manifest values.
+pub const MAXIMUM_VALUE_DEPTH: usize = 6;
+
+/// The maximum number of elements, fields, or entries in a generated collection.
+pub const MAXIMUM_COLLECTION_LENGTH: usize = 8;
+
+/// The number of bytes of [`Unstructured`] data derived from a seed.
+const SEED_DATA_LENGTH: usize = 4096;
+
+const NETWORK_IDS: [u8; 3] = [0x01, 0x02, 0xF2];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArbitraryManifestValue(pub ManifestValue);
+
+impl<'a> Arbitrary<'a> for ArbitraryManifestValue {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        manifest_value(u, 0).map(Self)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArbitraryInstructions(pub Vec<InstructionV1>);
+
+impl<'a> Arbitrary<'a> for ArbitraryInstructions {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        instructions(u).map(Self)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArbitraryIntent(pub IntentV1);
+
+impl<'a> Arbitrary<'a> for ArbitraryIntent {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        intent(u).map(Self)
+    }
+}
+
+pub fn manifest_value_from_seed(seed: u64) -> Result<ManifestValue> {
+    manifest_value(&mut Unstructured::new(&seed_data(seed)), 0)
+}
+
+pub fn instructions_from_seed(seed: u64) -> Result<Vec<InstructionV1>> {
+    instructions(&mut Unstructured::new(&seed_data(seed)))
+}
+
+pub fn intent_from_seed(seed: u64) -> Result<IntentV1> {
+    intent(&mut Unstructured::new(&seed_data(seed)))
+}
+
+/// Generates a manifest value, only producing leaf values once [`MAXIMUM_VALUE_DEPTH`] is reached.
+pub fn manifest_value(u: &mut Unstructured, depth: usize) -> Result<ManifestValue> {
+    let maximum_kind = if depth >= MAXIMUM_VALUE_DEPTH { 9 } else { 13 };
+    let value = match u.int_in_range(0..=maximum_kind)? {
+        0 => ManifestValue::Bool {
+            value: u.arbitrary()?,
+        },
+        1 => ManifestValue::U8 {
+            value: u.arbitrary()?,
+        },
+        2 => ManifestValue::U32 {
+            value: u.arbitrary()?,
+        },
+        3 => ManifestValue::U64 {
+            value: u.arbitrary()?,
+        },
+        4 => ManifestValue::I64 {
+            value: u.arbitrary()?,
+        },
+        5 => ManifestValue::U128 {
+            value: u.arbitrary()?,
+        },
+        6 => ManifestValue::String {
+            value: u.arbitrary()?,
+        },
+        7 => ManifestValue::Custom {
+            value: ManifestCustomValue::Decimal(from_decimal(&decimal(u)?)),
+        },
+        8 => ManifestValue::Custom {
+            value: ManifestCustomValue::Address(ManifestAddress::Static(
+                *global_address(u)?.as_node_id(),
+            )),
+        },
+        9 => ManifestValue::Custom {
+            value: ManifestCustomValue::NonFungibleLocalId(from_non_fungible_local_id(
+                non_fungible_local_id(u)?,
+            )),
+        },
+        10 => ManifestValue::Tuple {
+            fields: collection(u, |u| manifest_value(u, depth + 1))?,
+        },
+        11 => ManifestValue::Enum {
+            discriminator: u.arbitrary()?,
+            fields: collection(u, |u| manifest_value(u, depth + 1))?,
+        },
+        12 => ManifestValue::Array {
+            element_value_kind: ManifestValueKind::String,
+            elements: collection(u, |u| {
+                Ok(ManifestValue::String {
+                    value: u.arbitrary()?,
+                })
+            })?,
+        },
+        _ => ManifestValue::Map {
+            key_value_kind: ManifestValueKind::U32,
+            value_value_kind: ManifestValueKind::Bool,
+            entries: collection(u, |u| {
+                Ok((
+                    ManifestValue::U32 {
+                        value: u.arbitrary()?,
+                    },
+                    ManifestValue::Bool {
+                        value: u.arbitrary()?,
+                    },
+                ))
+            })?,
+        },
+    };
+    Ok(value)
+}
+
+pub fn instructions(u: &mut Unstructured) -> Result<Vec<InstructionV1>> {
+    collection(u, instruction)
+}
+
+pub fn instruction(u: &mut Unstructured) -> Result<InstructionV1> {
+    let instruction = match u.int_in_range(0..=9)? {
+        0 => InstructionV1::TakeAllFromWorktop {
+            resource_address: resource_address(u)?,
+        },
+        1 => InstructionV1::TakeFromWorktop {
+            resource_address: resource_address(u)?,
+            amount: decimal(u)?,
+        },
+        2 => InstructionV1::ReturnToWorktop {
+            bucket_id: ManifestBucket(u.int_in_range(0..=7)?),
+        },
+        3 => InstructionV1::AssertWorktopContains {
+            resource_address: resource_address(u)?,
+            amount: decimal(u)?,
+        },
+        4 => InstructionV1::AssertWorktopContainsAny {
+            resource_address: resource_address(u)?,
+        },
+        5 => InstructionV1::PopFromAuthZone,
+        6 => InstructionV1::DropAuthZoneProofs,
+        7 => InstructionV1::DropAllProofs,
+        8 => InstructionV1::CallFunction {
+            package_address: DynamicPackageAddress::Static(package_address(u)?),
+            blueprint_name: identifier(u)?,
+            function_name: identifier(u)?,
+            args: arguments(u)?,
+        },
+        _ => InstructionV1::CallMethod {
+            address: DynamicGlobalAddress::Static(global_address(u)?),
+            method_name: identifier(u)?,
+            args: arguments(u)?,
+        },
+    };
+    Ok(instruction)
+}
+
+pub fn intent(u: &mut Unstructured) -> Result<IntentV1> {
+    let start_epoch_inclusive = u.int_in_range(0..=u32::MAX as u64)?;
+    let header = TransactionHeaderV1 {
+        network_id: *u.choose(&NETWORK_IDS)?,
+        start_epoch_inclusive: Epoch::of(start_epoch_inclusive),
+        end_epoch_exclusive: Epoch::of(start_epoch_inclusive + u.int_in_range(1..=100)?),
+        nonce: u.arbitrary()?,
+        notary_public_key: if u.arbitrary()? {
+            PublicKey::Secp256k1(Secp256k1PublicKey(u.arbitrary()?))
+        } else {
+            PublicKey::Ed25519(Ed25519PublicKey(u.arbitrary()?))
+        },
+        notary_is_signatory: u.arbitrary()?,
+        tip_percentage: u.arbitrary()?,
+    };
+    let blobs = collection(u, |u| Ok(BlobV1(u.arbitrary()?)))?;
+
+    Ok(IntentV1 {
+        header,
+        instructions: InstructionsV1(instructions(u)?),
+        blobs: BlobsV1 { blobs },
+        message: MessageV1::None,
+    })
+}
+
+/// Call arguments are always a tuple in manifests.
+fn arguments(u: &mut Unstructured) -> Result<ManifestValue> {
+    Ok(ManifestValue::Tuple {
+        fields: collection(u, |u| manifest_value(u, 1))?,
+    })
+}
+
+fn collection<T>(
+    u: &mut Unstructured,
+    mut generator: impl FnMut(&mut Unstructured) -> Result<T>,
+) -> Result<Vec<T>> {
+    let length = u.int_in_range(0..=MAXIMUM_COLLECTION_LENGTH)?;
+    (0..length).map(|_| generator(u)).collect()
+}
+
+fn identifier(u: &mut Unstructured) -> Result<String> {
+    const CHARACTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyz_";
+    let length = u.int_in_range(1..=32)?;
+    (0..length)
+        .map(|_| u.choose(CHARACTERS).map(|character| *character as char))
+        .collect()
+}
+
+fn decimal(u: &mut Unstructured) -> Result<Decimal> {
+    let whole = Decimal::from(u.arbitrary::<i64>()?);
+    let fraction = Decimal::from(u.int_in_range(0..=999_999u32)?) / Decimal::from(1_000_000);
+    Ok(whole + fraction)
+}
+
+fn non_fungible_local_id(u: &mut Unstructured) -> Result<NonFungibleLocalId> {
+    let id = if u.arbitrary()? {
+        NonFungibleLocalId::integer(u.arbitrary()?)
+    } else {
+        NonFungibleLocalId::string(identifier(u)?)
+            .expect("Identifiers are always valid string local ids")
+    };
+    Ok(id)
+}
+
+fn resource_address(u: &mut Unstructured) -> Result<ResourceAddress> {
+    Ok(*u.choose(&[
+        XRD,
+        SECP256K1_SIGNATURE_VIRTUAL_BADGE,
+        ED25519_SIGNATURE_VIRTUAL_BADGE,
+        ACCOUNT_OWNER_BADGE,
+    ])?)
+}
+
+fn package_address(u: &mut Unstructured) -> Result<PackageAddress> {
+    Ok(*u.choose(&[
+        ACCOUNT_PACKAGE,
+        IDENTITY_PACKAGE,
+        FAUCET_PACKAGE,
+        POOL_PACKAGE,
+    ])?)
+}
+
+fn global_address(u: &mut Unstructured) -> Result<GlobalAddress> {
+    let address = match u.int_in_range(0..=3)? {
+        0 => FAUCET.into(),
+        1 => CONSENSUS_MANAGER.into(),
+        2 => resource_address(u)?.into(),
+        _ => ComponentAddress::virtual_account_from_public_key(&Ed25519PublicKey(u.arbitrary()?))
+            .into(),
+    };
+    Ok(address)
+}
+
+/// Expands a seed into a deterministic stream of bytes by hashing the seed and a counter.
+fn seed_data(seed: u64) -> Vec<u8> {
+    let mut data = Vec::with_capacity(SEED_DATA_LENGTH);
+    let mut counter = 0u64;
+    while data.len() < SEED_DATA_LENGTH {
+        let mut input = seed.to_le_bytes().to_vec();
+        input.extend(counter.to_le_bytes());
+        data.extend(hash(input).0);
+        counter += 1;
+    }
+    data
+}
@@ -0,0 +1,238 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_interface::blueprints::access_controller::*;
+use scrypto::prelude::*;
+use transaction::prelude::DynamicGlobalAddress;
+
+use crate::instruction_visitor::core::error::InstructionVisitorError;
+use crate::instruction_visitor::core::traits::InstructionVisitor;
+use crate::utils::{is_access_controller, to_manifest_type};
+
+/// A call made to an access controller along with the index of the instruction making it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccessControllerInteraction {
+    pub instruction_index: usize,
+    pub access_controller: ComponentAddress,
+    pub operation: AccessControllerOperation,
+}
+
+/// The step of the access controller lifecycle that a method call performs. The proposer of the
+/// quick-confirm and cancel operations is the role that initiated the recovery or badge withdraw
+/// attempt being confirmed or cancelled.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccessControllerOperation {
+    CreateProof,
+    InitiateRecovery {
+        proposer: Proposer,
+        proposal: RecoveryProposal,
+    },
+    QuickConfirmRecovery {
+        proposer: Proposer,
+        proposal: RecoveryProposal,
+    },
+    TimedConfirmRecovery {
+        proposal: RecoveryProposal,
+    },
+    CancelRecovery {
+        proposer: Proposer,
+    },
+    StopTimedRecovery {
+        proposal: RecoveryProposal,
+    },
+    InitiateBadgeWithdrawAttempt {
+        proposer: Proposer,
+    },
+    QuickConfirmBadgeWithdrawAttempt {
+        proposer: Proposer,
+    },
+    CancelBadgeWithdrawAttempt {
+        proposer: Proposer,
+    },
+    LockPrimaryRole,
+    UnlockPrimaryRole,
+    MintRecoveryBadges {
+        non_fungible_local_ids: IndexSet<NonFungibleLocalId>,
+    },
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct AccessControllerVisitor {
+    interactions: Vec<AccessControllerInteraction>,
+    instruction_index: usize,
+}
+
+impl AccessControllerVisitor {
+    pub fn output(self) -> Vec<AccessControllerInteraction> {
+        self.interactions
+    }
+}
+
+impl InstructionVisitor for AccessControllerVisitor {
+    fn visit_call_method(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        args: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        let access_controller = match address {
+            DynamicGlobalAddress::Static(address) if is_access_controller(address) => {
+                // This never panics. We have already checked that this is an access controller
+                // when we called `is_access_controller`.
+                ComponentAddress::new_or_panic(address.as_node_id().0)
+            }
+            _ => return Ok(()),
+        };
+
+        if let Some(operation) = operation(method_name, args) {
+            self.interactions.push(AccessControllerInteraction {
+                instruction_index: self.instruction_index,
+                access_controller,
+                operation,
+            })
+        }
+        Ok(())
+    }
+
+    fn post_visit(&mut self) -> Result<(), InstructionVisitorError> {
+        self.instruction_index += 1;
+        Ok(())
+    }
+}
+
+/// Classifies a call to an access controller method, [`None`] is returned for unknown methods and
+/// for arguments that don't match those expected by the method.
+fn operation(method_name: &str, args: &ManifestValue) -> Option<AccessControllerOperation> {
+    let proposal = |rule_set, timed_recovery_delay_in_minutes| RecoveryProposal {
+        rule_set,
+        timed_recovery_delay_in_minutes,
+    };
+
+    let operation = match method_name {
+        ACCESS_CONTROLLER_CREATE_PROOF_IDENT => AccessControllerOperation::CreateProof,
+        ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_PRIMARY_IDENT => {
+            let AccessControllerInitiateRecoveryAsPrimaryInput {
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            } = to_manifest_type(args)?;
+            AccessControllerOperation::InitiateRecovery {
+                proposer: Proposer::Primary,
+                proposal: proposal(rule_set, timed_recovery_delay_in_minutes),
+            }
+        }
+        ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_RECOVERY_IDENT => {
+            let AccessControllerInitiateRecoveryAsRecoveryInput {
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            } = to_manifest_type(args)?;
+            AccessControllerOperation::InitiateRecovery {
+                proposer: Proposer::Recovery,
+                proposal: proposal(rule_set, timed_recovery_delay_in_minutes),
+            }
+        }
+        ACCESS_CONTROLLER_QUICK_CONFIRM_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT => {
+            let AccessControllerQuickConfirmPrimaryRoleRecoveryProposalInput {
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            } = to_manifest_type(args)?;
+            AccessControllerOperation::QuickConfirmRecovery {
+                proposer: Proposer::Primary,
+                proposal: proposal(rule_set, timed_recovery_delay_in_minutes),
+            }
+        }
+        ACCESS_CONTROLLER_QUICK_CONFIRM_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT => {
+            let AccessControllerQuickConfirmRecoveryRoleRecoveryProposalInput {
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            } = to_manifest_type(args)?;
+            AccessControllerOperation::QuickConfirmRecovery {
+                proposer: Proposer::Recovery,
+                proposal: proposal(rule_set, timed_recovery_delay_in_minutes),
+            }
+        }
+        ACCESS_CONTROLLER_TIMED_CONFIRM_RECOVERY_IDENT => {
+            let AccessControllerTimedConfirmRecoveryInput {
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            } = to_manifest_type(args)?;
+            AccessControllerOperation::TimedConfirmRecovery {
+                proposal: proposal(rule_set, timed_recovery_delay_in_minutes),
+            }
+        }
+        ACCESS_CONTROLLER_CANCEL_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT => {
+            AccessControllerOperation::CancelRecovery {
+                proposer: Proposer::Primary,
+            }
+        }
+        ACCESS_CONTROLLER_CANCEL_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT => {
+            AccessControllerOperation::CancelRecovery {
+                proposer: Proposer::Recovery,
+            }
+        }
+        ACCESS_CONTROLLER_STOP_TIMED_RECOVERY_IDENT => {
+            let AccessControllerStopTimedRecoveryInput {
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            } = to_manifest_type(args)?;
+            AccessControllerOperation::StopTimedRecovery {
+                proposal: proposal(rule_set, timed_recovery_delay_in_minutes),
+            }
+        }
+        ACCESS_CONTROLLER_INITIATE_BADGE_WITHDRAW_ATTEMPT_AS_PRIMARY_IDENT => {
+            AccessControllerOperation::InitiateBadgeWithdrawAttempt {
+                proposer: Proposer::Primary,
+            }
+        }
+        ACCESS_CONTROLLER_INITIATE_BADGE_WITHDRAW_ATTEMPT_AS_RECOVERY_IDENT => {
+            AccessControllerOperation::InitiateBadgeWithdrawAttempt {
+                proposer: Proposer::Recovery,
+            }
+        }
+        ACCESS_CONTROLLER_QUICK_CONFIRM_PRIMARY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT => {
+            AccessControllerOperation::QuickConfirmBadgeWithdrawAttempt {
+                proposer: Proposer::Primary,
+            }
+        }
+        ACCESS_CONTROLLER_QUICK_CONFIRM_RECOVERY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT => {
+            AccessControllerOperation::QuickConfirmBadgeWithdrawAttempt {
+                proposer: Proposer::Recovery,
+            }
+        }
+        ACCESS_CONTROLLER_CANCEL_PRIMARY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT => {
+            AccessControllerOperation::CancelBadgeWithdrawAttempt {
+                proposer: Proposer::Primary,
+            }
+        }
+        ACCESS_CONTROLLER_CANCEL_RECOVERY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT => {
+            AccessControllerOperation::CancelBadgeWithdrawAttempt {
+                proposer: Proposer::Recovery,
+            }
+        }
+        ACCESS_CONTROLLER_LOCK_PRIMARY_ROLE_IDENT => AccessControllerOperation::LockPrimaryRole,
+        ACCESS_CONTROLLER_UNLOCK_PRIMARY_ROLE_IDENT => AccessControllerOperation::UnlockPrimaryRole,
+        ACCESS_CONTROLLER_MINT_RECOVERY_BADGES_IDENT => {
+            let AccessControllerMintRecoveryBadgesInput {
+                non_fungible_local_ids,
+            } = to_manifest_type(args)?;
+            AccessControllerOperation::MintRecoveryBadges {
+                non_fungible_local_ids,
+            }
+        }
+        _ => return None,
+    };
+    Some(operation)
+}
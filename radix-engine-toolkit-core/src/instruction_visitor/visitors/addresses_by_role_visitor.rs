@@ -0,0 +1,238 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_interface::blueprints::account::*;
+use scrypto::prelude::*;
+use transaction::prelude::{DynamicGlobalAddress, DynamicPackageAddress};
+
+use crate::instruction_visitor::core::error::InstructionVisitorError;
+use crate::instruction_visitor::core::traits::InstructionVisitor;
+use crate::utils::{is_account, to_manifest_type};
+
+/// The addresses seen in a manifest grouped by the role that they play in it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AddressesByRole {
+    pub called_components: HashSet<ComponentAddress>,
+    pub called_packages: HashSet<PackageAddress>,
+    pub accounts_withdrawn_from: HashSet<ComponentAddress>,
+    pub accounts_deposited_into: HashSet<ComponentAddress>,
+    pub resources_moved: HashSet<ResourceAddress>,
+    pub badges_presented: HashSet<ResourceAddress>,
+}
+
+/// Collects the called components and packages, the resources moved and the badges presented in
+/// a manifest. The account withdraws and deposits are collected by the account interactions
+/// visitor and are not duplicated here.
+#[derive(Clone, Debug, Default)]
+pub struct AddressesByRoleVisitor {
+    called_components: HashSet<ComponentAddress>,
+    called_packages: HashSet<PackageAddress>,
+    resources_moved: HashSet<ResourceAddress>,
+    badges_presented: HashSet<ResourceAddress>,
+}
+
+impl AddressesByRoleVisitor {
+    pub fn output(
+        self,
+    ) -> (
+        HashSet<ComponentAddress>,
+        HashSet<PackageAddress>,
+        HashSet<ResourceAddress>,
+        HashSet<ResourceAddress>,
+    ) {
+        (
+            self.called_components,
+            self.called_packages,
+            self.resources_moved,
+            self.badges_presented,
+        )
+    }
+
+    fn record_called_component(&mut self, address: &DynamicGlobalAddress) {
+        if let DynamicGlobalAddress::Static(address) = address {
+            if address.as_node_id().is_global_component() {
+                // This never panics. We have already checked that this is a global component.
+                self.called_components
+                    .insert(ComponentAddress::new_or_panic(address.as_node_id().0));
+            }
+        }
+    }
+}
+
+impl InstructionVisitor for AddressesByRoleVisitor {
+    fn visit_take_all_from_worktop(
+        &mut self,
+        resource_address: &ResourceAddress,
+    ) -> Result<(), InstructionVisitorError> {
+        self.resources_moved.insert(*resource_address);
+        Ok(())
+    }
+
+    fn visit_take_from_worktop(
+        &mut self,
+        resource_address: &ResourceAddress,
+        _: &Decimal,
+    ) -> Result<(), InstructionVisitorError> {
+        self.resources_moved.insert(*resource_address);
+        Ok(())
+    }
+
+    fn visit_take_non_fungibles_from_worktop(
+        &mut self,
+        resource_address: &ResourceAddress,
+        _: &[NonFungibleLocalId],
+    ) -> Result<(), InstructionVisitorError> {
+        self.resources_moved.insert(*resource_address);
+        Ok(())
+    }
+
+    fn visit_create_proof_from_auth_zone_of_amount(
+        &mut self,
+        resource_address: &ResourceAddress,
+        _: &Decimal,
+    ) -> Result<(), InstructionVisitorError> {
+        self.badges_presented.insert(*resource_address);
+        Ok(())
+    }
+
+    fn visit_create_proof_from_auth_zone_of_non_fungibles(
+        &mut self,
+        resource_address: &ResourceAddress,
+        _: &[NonFungibleLocalId],
+    ) -> Result<(), InstructionVisitorError> {
+        self.badges_presented.insert(*resource_address);
+        Ok(())
+    }
+
+    fn visit_create_proof_from_auth_zone_of_all(
+        &mut self,
+        resource_address: &ResourceAddress,
+    ) -> Result<(), InstructionVisitorError> {
+        self.badges_presented.insert(*resource_address);
+        Ok(())
+    }
+
+    fn visit_call_function(
+        &mut self,
+        package_address: &DynamicPackageAddress,
+        _: &str,
+        _: &str,
+        _: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        if let DynamicPackageAddress::Static(package_address) = package_address {
+            self.called_packages.insert(*package_address);
+        }
+        Ok(())
+    }
+
+    fn visit_call_method(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        args: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        self.record_called_component(address);
+
+        if !is_account(address) {
+            return Ok(());
+        }
+
+        match method_name {
+            ACCOUNT_WITHDRAW_IDENT => {
+                if let Some(AccountWithdrawInput {
+                    resource_address, ..
+                }) = to_manifest_type(args)
+                {
+                    self.resources_moved.insert(resource_address);
+                }
+            }
+            ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                if let Some(AccountWithdrawNonFungiblesInput {
+                    resource_address, ..
+                }) = to_manifest_type(args)
+                {
+                    self.resources_moved.insert(resource_address);
+                }
+            }
+            ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => {
+                if let Some(AccountLockFeeAndWithdrawInput {
+                    resource_address, ..
+                }) = to_manifest_type(args)
+                {
+                    self.resources_moved.insert(resource_address);
+                }
+            }
+            ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                if let Some(AccountLockFeeAndWithdrawNonFungiblesInput {
+                    resource_address, ..
+                }) = to_manifest_type(args)
+                {
+                    self.resources_moved.insert(resource_address);
+                }
+            }
+            ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT => {
+                if let Some(AccountCreateProofOfAmountInput {
+                    resource_address, ..
+                }) = to_manifest_type(args)
+                {
+                    self.badges_presented.insert(resource_address);
+                }
+            }
+            ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT => {
+                if let Some(AccountCreateProofOfNonFungiblesInput {
+                    resource_address, ..
+                }) = to_manifest_type(args)
+                {
+                    self.badges_presented.insert(resource_address);
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn visit_call_royalty_method(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        _: &str,
+        _: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        self.record_called_component(address);
+        Ok(())
+    }
+
+    fn visit_call_metadata_method(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        _: &str,
+        _: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        self.record_called_component(address);
+        Ok(())
+    }
+
+    fn visit_call_role_assignment_method(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        _: &str,
+        _: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        self.record_called_component(address);
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use scrypto::blueprints::account::*;
+use scrypto::prelude::*;
+use transaction::prelude::*;
+
+use crate::instruction_visitor::core::error::InstructionVisitorError;
+use crate::instruction_visitor::core::traits::InstructionVisitor;
+use crate::utils::{is_account, to_manifest_type};
+
+/// A deposit into an account which presents an authorized depositor badge. When the deposit rules
+/// of the account would otherwise reject the resources, the deposit goes through only if the badge
+/// is an authorized depositor of the account and a proof of it is in the auth zone. Thus, when the
+/// transaction succeeds, the badge is the one that satisfied the deposit rules.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuthorizedDepositorBadgeUsage {
+    pub instruction_index: usize,
+    pub account: DynamicGlobalAddress,
+    pub method_name: String,
+    pub badge: ResourceOrNonFungible,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct AuthorizedDepositorBadgesVisitor {
+    usages: Vec<AuthorizedDepositorBadgeUsage>,
+    instruction_index: usize,
+}
+
+impl AuthorizedDepositorBadgesVisitor {
+    pub fn output(self) -> Vec<AuthorizedDepositorBadgeUsage> {
+        self.usages
+    }
+}
+
+impl InstructionVisitor for AuthorizedDepositorBadgesVisitor {
+    fn post_visit(&mut self) -> Result<(), InstructionVisitorError> {
+        self.instruction_index += 1;
+        Ok(())
+    }
+
+    fn visit_call_method(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        args: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        if !is_account(address)
+            || ![
+                ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT,
+                ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT,
+                ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT,
+                ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT,
+            ]
+            .contains(&method_name)
+        {
+            return Ok(());
+        }
+
+        // All of the try deposit methods take the bucket or buckets followed by the badge.
+        if let Some((_, Some(badge))) =
+            to_manifest_type::<(ManifestValue, Option<ResourceOrNonFungible>)>(args)
+        {
+            self.usages.push(AuthorizedDepositorBadgeUsage {
+                instruction_index: self.instruction_index,
+                account: address.clone(),
+                method_name: method_name.to_owned(),
+                badge,
+            })
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_interface::blueprints::account::*;
+use scrypto::prelude::*;
+use transaction::prelude::DynamicGlobalAddress;
+
+use crate::instruction_visitor::core::error::InstructionVisitorError;
+use crate::instruction_visitor::core::traits::InstructionVisitor;
+use crate::utils::{is_account, to_manifest_type};
+
+/// A visitor that collects the fees locked against accounts in the order in which they're locked.
+#[derive(Default, Clone)]
+pub struct FeeLocksVisitor(Vec<AccountFeeLock>);
+
+impl FeeLocksVisitor {
+    pub fn output(self) -> Vec<AccountFeeLock> {
+        self.0
+    }
+}
+
+impl InstructionVisitor for FeeLocksVisitor {
+    fn visit_call_method(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        args: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        let DynamicGlobalAddress::Static(address) = address else {
+            return Ok(());
+        };
+        if !is_account(address) {
+            return Ok(());
+        }
+        let account = ComponentAddress::new_or_panic(address.as_node_id().0);
+
+        let fee_lock = match method_name {
+            ACCOUNT_LOCK_FEE_IDENT => to_manifest_type::<AccountLockFeeInput>(args)
+                .map(|AccountLockFeeInput { amount }| (amount, false)),
+            ACCOUNT_LOCK_CONTINGENT_FEE_IDENT => {
+                to_manifest_type::<AccountLockContingentFeeInput>(args)
+                    .map(|AccountLockContingentFeeInput { amount }| (amount, true))
+            }
+            ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => {
+                to_manifest_type::<AccountLockFeeAndWithdrawInput>(args)
+                    .map(|input| (input.amount_to_lock, false))
+            }
+            ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                to_manifest_type::<AccountLockFeeAndWithdrawNonFungiblesInput>(args)
+                    .map(|input| (input.amount_to_lock, false))
+            }
+            _ => None,
+        };
+        if let Some((amount, contingent)) = fee_lock {
+            self.0.push(AccountFeeLock {
+                account,
+                amount,
+                contingent,
+            })
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountFeeLock {
+    pub account: ComponentAddress,
+    pub amount: Decimal,
+    /// Contingent fees are only paid if the transaction succeeds and thus can not pay for the
+    /// execution of a failing transaction.
+    pub contingent: bool,
+}
+
+/// The accounts that pay the fees of a transaction.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub enum FeePayers {
+    /// No fees are locked against accounts, e.g. when they're locked against the faucet.
+    #[default]
+    None,
+    Single(ComponentAddress),
+    /// The fee locking is split across several accounts given in the order of their first lock.
+    Multiple(Vec<ComponentAddress>),
+}
+
+impl FeePayers {
+    pub fn from_fee_locks(fee_locks: &[AccountFeeLock]) -> Self {
+        let accounts = fee_locks
+            .iter()
+            .map(|fee_lock| fee_lock.account)
+            .collect::<IndexSet<_>>();
+        match accounts.len() {
+            0 => Self::None,
+            1 => Self::Single(accounts[0]),
+            _ => Self::Multiple(accounts.into_iter().collect()),
+        }
+    }
+}
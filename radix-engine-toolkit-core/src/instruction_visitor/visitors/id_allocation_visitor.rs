@@ -0,0 +1,255 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use scrypto::prelude::*;
+use transaction::prelude::*;
+use transaction::validation::ManifestIdAllocator;
+
+use crate::instruction_visitor::core::error::InstructionVisitorError;
+use crate::instruction_visitor::core::traits::InstructionVisitor;
+use crate::sbor::indexed_manifest_value::IndexedManifestValue;
+
+/// A visitor that follows the lifetimes of the buckets and proofs of a manifest and reports every
+/// instruction that breaks them. Unlike the id validation of the transaction validator, it doesn't
+/// stop at the first issue and it reports the instruction that each issue was found at.
+#[derive(Default, Clone)]
+pub struct IdAllocationVisitor {
+    instruction_index: usize,
+    id_allocator: ManifestIdAllocator,
+    /// The instruction that each bucket was created at and, if consumed, was consumed at.
+    buckets: IndexMap<ManifestBucket, (usize, Option<usize>)>,
+    /// The instruction that each proof was created at, the bucket that the proof was created from,
+    /// and, if dropped, the instruction that it was dropped at.
+    proofs: IndexMap<ManifestProof, (usize, Option<ManifestBucket>, Option<usize>)>,
+    diagnostics: Vec<IdAllocationDiagnostic>,
+}
+
+impl IdAllocationVisitor {
+    pub fn output(mut self) -> Vec<IdAllocationDiagnostic> {
+        for (bucket, (created_at, consumed_at)) in self.buckets.iter() {
+            if consumed_at.is_none() {
+                self.diagnostics.push(IdAllocationDiagnostic {
+                    instruction_index: *created_at,
+                    kind: IdAllocationDiagnosticKind::BucketNeverConsumed(*bucket),
+                })
+            }
+        }
+        for (proof, (created_at, _, dropped_at)) in self.proofs.iter() {
+            if dropped_at.is_none() {
+                self.diagnostics.push(IdAllocationDiagnostic {
+                    instruction_index: *created_at,
+                    kind: IdAllocationDiagnosticKind::ProofNeverDropped(*proof),
+                })
+            }
+        }
+        self.diagnostics
+    }
+
+    fn report(&mut self, kind: IdAllocationDiagnosticKind) {
+        self.diagnostics.push(IdAllocationDiagnostic {
+            instruction_index: self.instruction_index,
+            kind,
+        })
+    }
+
+    fn new_bucket(&mut self) {
+        let bucket = self.id_allocator.new_bucket_id();
+        self.buckets.insert(bucket, (self.instruction_index, None));
+    }
+
+    fn new_proof(&mut self, bucket: Option<ManifestBucket>) {
+        let proof = self.id_allocator.new_proof_id();
+        self.proofs
+            .insert(proof, (self.instruction_index, bucket, None));
+    }
+
+    /// Checks that the bucket is alive, returning whether it is.
+    fn use_bucket(&mut self, bucket: &ManifestBucket) -> bool {
+        match self.buckets.get(bucket) {
+            None => {
+                self.report(IdAllocationDiagnosticKind::BucketNotYetCreated(*bucket));
+                false
+            }
+            Some((_, Some(consumed_at))) => {
+                let consumed_at = *consumed_at;
+                self.report(IdAllocationDiagnosticKind::BucketAlreadyConsumed {
+                    bucket: *bucket,
+                    consumed_at,
+                });
+                false
+            }
+            Some((_, None)) => true,
+        }
+    }
+
+    fn consume_bucket(&mut self, bucket: &ManifestBucket) {
+        if !self.use_bucket(bucket) {
+            return;
+        }
+
+        let locking_proofs = self
+            .proofs
+            .iter()
+            .filter(|(_, (_, source, dropped_at))| *source == Some(*bucket) && dropped_at.is_none())
+            .map(|(proof, _)| *proof)
+            .collect::<Vec<_>>();
+        if !locking_proofs.is_empty() {
+            self.report(IdAllocationDiagnosticKind::BucketLockedByProofs {
+                bucket: *bucket,
+                proofs: locking_proofs,
+            })
+        }
+
+        let instruction_index = self.instruction_index;
+        if let Some((_, consumed_at)) = self.buckets.get_mut(bucket) {
+            *consumed_at = Some(instruction_index)
+        }
+    }
+
+    /// Checks that the proof is alive, returning the bucket it was created from if it is.
+    fn use_proof(&mut self, proof: &ManifestProof) -> Option<Option<ManifestBucket>> {
+        match self.proofs.get(proof) {
+            None => {
+                self.report(IdAllocationDiagnosticKind::ProofNotYetCreated(*proof));
+                None
+            }
+            Some((_, _, Some(dropped_at))) => {
+                let dropped_at = *dropped_at;
+                self.report(IdAllocationDiagnosticKind::ProofAlreadyDropped {
+                    proof: *proof,
+                    dropped_at,
+                });
+                None
+            }
+            Some((_, bucket, None)) => Some(*bucket),
+        }
+    }
+
+    fn drop_proof(&mut self, proof: &ManifestProof) {
+        if self.use_proof(proof).is_none() {
+            return;
+        }
+
+        let instruction_index = self.instruction_index;
+        if let Some((_, _, dropped_at)) = self.proofs.get_mut(proof) {
+            *dropped_at = Some(instruction_index)
+        }
+    }
+
+    fn drop_all_proofs(&mut self) {
+        let instruction_index = self.instruction_index;
+        for (_, _, dropped_at) in self.proofs.values_mut() {
+            dropped_at.get_or_insert(instruction_index);
+        }
+    }
+
+    fn consume_args(&mut self, args: &ManifestValue) {
+        let args = IndexedManifestValue::from_manifest_value(args);
+        for bucket in args.buckets() {
+            self.consume_bucket(bucket)
+        }
+        for proof in args.proofs() {
+            self.drop_proof(proof)
+        }
+    }
+}
+
+impl InstructionVisitor for IdAllocationVisitor {
+    fn visit_instruction(
+        &mut self,
+        instruction: &InstructionV1,
+    ) -> Result<(), InstructionVisitorError> {
+        match instruction {
+            InstructionV1::TakeAllFromWorktop { .. }
+            | InstructionV1::TakeFromWorktop { .. }
+            | InstructionV1::TakeNonFungiblesFromWorktop { .. } => self.new_bucket(),
+            InstructionV1::ReturnToWorktop { bucket_id }
+            | InstructionV1::BurnResource { bucket_id } => self.consume_bucket(bucket_id),
+            InstructionV1::PopFromAuthZone
+            | InstructionV1::CreateProofFromAuthZoneOfAmount { .. }
+            | InstructionV1::CreateProofFromAuthZoneOfNonFungibles { .. }
+            | InstructionV1::CreateProofFromAuthZoneOfAll { .. } => self.new_proof(None),
+            InstructionV1::CreateProofFromBucketOfAmount { bucket_id, .. }
+            | InstructionV1::CreateProofFromBucketOfNonFungibles { bucket_id, .. }
+            | InstructionV1::CreateProofFromBucketOfAll { bucket_id } => {
+                // The proof is created regardless so that the ids of the following proofs match
+                // the ids allocated by the transaction processor.
+                self.use_bucket(bucket_id);
+                self.new_proof(Some(*bucket_id))
+            }
+            InstructionV1::CloneProof { proof_id } => {
+                let bucket = self.use_proof(proof_id).flatten();
+                self.new_proof(bucket)
+            }
+            InstructionV1::PushToAuthZone { proof_id } | InstructionV1::DropProof { proof_id } => {
+                self.drop_proof(proof_id)
+            }
+            InstructionV1::DropNamedProofs | InstructionV1::DropAllProofs => self.drop_all_proofs(),
+            InstructionV1::CallFunction { args, .. }
+            | InstructionV1::CallMethod { args, .. }
+            | InstructionV1::CallRoyaltyMethod { args, .. }
+            | InstructionV1::CallMetadataMethod { args, .. }
+            | InstructionV1::CallRoleAssignmentMethod { args, .. }
+            | InstructionV1::CallDirectVaultMethod { args, .. } => self.consume_args(args),
+            InstructionV1::AssertWorktopContains { .. }
+            | InstructionV1::AssertWorktopContainsAny { .. }
+            | InstructionV1::AssertWorktopContainsNonFungibles { .. }
+            | InstructionV1::DropAuthZoneProofs
+            | InstructionV1::DropAuthZoneRegularProofs
+            | InstructionV1::DropAuthZoneSignatureProofs
+            | InstructionV1::AllocateGlobalAddress { .. } => {}
+        }
+        Ok(())
+    }
+
+    fn post_visit(&mut self) -> Result<(), InstructionVisitorError> {
+        self.instruction_index += 1;
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdAllocationDiagnostic {
+    /// The instruction that the issue was found at. Buckets that are never consumed and proofs that
+    /// are never dropped are reported at the instruction that created them.
+    pub instruction_index: usize,
+    pub kind: IdAllocationDiagnosticKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IdAllocationDiagnosticKind {
+    BucketNotYetCreated(ManifestBucket),
+    BucketAlreadyConsumed {
+        bucket: ManifestBucket,
+        consumed_at: usize,
+    },
+    /// The bucket is consumed while proofs created from it are still alive.
+    BucketLockedByProofs {
+        bucket: ManifestBucket,
+        proofs: Vec<ManifestProof>,
+    },
+    /// The bucket is never consumed, which fails the transaction at its end.
+    BucketNeverConsumed(ManifestBucket),
+    ProofNotYetCreated(ManifestProof),
+    ProofAlreadyDropped {
+        proof: ManifestProof,
+        dropped_at: usize,
+    },
+    /// The proof is never dropped. Such proofs are dropped by the transaction processor at the end
+    /// of the transaction, so this doesn't fail the transaction but keeps the proof's bucket locked.
+    ProofNeverDropped(ManifestProof),
+}
@@ -0,0 +1,358 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_interface::blueprints::account::*;
+use scrypto::prelude::*;
+use transaction::prelude::*;
+use transaction::validation::ManifestIdAllocator;
+
+use crate::instruction_visitor::core::error::InstructionVisitorError;
+use crate::instruction_visitor::core::traits::InstructionVisitor;
+use crate::sbor::indexed_manifest_value::IndexedManifestValue;
+use crate::utils::to_manifest_type;
+
+/// A visitor that statically determines the maximum amount of each resource that could flow out of
+/// a set of accounts as a result of the manifest.
+///
+/// Resources can only leave the worktop through buckets or expressions passed to calls and through
+/// burns. Thus, the outflow of a resource from an account is the smaller of the amount withdrawn
+/// from the account and the amount sent to entities other than the accounts in the set. Sending
+/// a bucket of an unknown amount or an `ENTIRE_WORKTOP` expression to a third party means that all
+/// of the withdrawn amount could flow out. The fees locked and the amounts burned directly from the
+/// accounts are always counted as outflows.
+///
+/// Direct vault method calls, such as recalls, can take resources out of the vaults of any of the
+/// accounts without going through their methods, so the outflows of manifests that contain them
+/// are unbounded.
+pub struct MaximumOutflowsVisitor {
+    /// The accounts that the outflows are computed for.
+    accounts: HashSet<ComponentAddress>,
+    /// The amounts withdrawn from each of the accounts.
+    withdrawn: IndexMap<ComponentAddress, IndexMap<ResourceAddress, Decimal>>,
+    /// The fees locked against each of the accounts.
+    fees_locked: IndexMap<ComponentAddress, Decimal>,
+    /// The amounts burned directly from each of the accounts.
+    burned: IndexMap<ComponentAddress, IndexMap<ResourceAddress, Decimal>>,
+    /// Whether a direct vault method was called, making the outflows unbounded.
+    is_vault_method_called: bool,
+    /// The amounts of each resource sent to third parties where [`None`] is an unknown amount.
+    sent: HashMap<ResourceAddress, Option<Decimal>>,
+    /// Whether the entire worktop was sent to a third party.
+    is_entire_worktop_sent: bool,
+    /// The resource and amount of each of the buckets where [`None`] is an unknown amount.
+    bucket_tracker: HashMap<ManifestBucket, (ResourceAddress, Option<Decimal>)>,
+    id_allocator: ManifestIdAllocator,
+}
+
+impl MaximumOutflowsVisitor {
+    pub fn new(accounts: HashSet<ComponentAddress>) -> Self {
+        Self {
+            accounts,
+            withdrawn: Default::default(),
+            fees_locked: Default::default(),
+            burned: Default::default(),
+            is_vault_method_called: Default::default(),
+            sent: Default::default(),
+            is_entire_worktop_sent: Default::default(),
+            bucket_tracker: Default::default(),
+            id_allocator: Default::default(),
+        }
+    }
+
+    /// Returns the maximum outflows, or [`None`] if they are unbounded.
+    pub fn output(self) -> Option<HashMap<ComponentAddress, HashMap<ResourceAddress, Decimal>>> {
+        if self.is_vault_method_called {
+            return None;
+        }
+
+        let mut outflows = HashMap::<ComponentAddress, HashMap<ResourceAddress, Decimal>>::new();
+
+        for (account, withdrawn) in self.withdrawn {
+            for (resource_address, withdrawn_amount) in withdrawn {
+                let amount = match self.sent.get(&resource_address) {
+                    _ if self.is_entire_worktop_sent => withdrawn_amount,
+                    Some(Some(sent_amount)) => withdrawn_amount.min(*sent_amount),
+                    Some(None) => withdrawn_amount,
+                    None => Decimal::ZERO,
+                };
+                if amount.is_positive() {
+                    outflows
+                        .entry(account)
+                        .or_default()
+                        .insert(resource_address, amount);
+                }
+            }
+        }
+
+        for (account, fee) in self.fees_locked {
+            if fee.is_positive() {
+                let amount = outflows.entry(account).or_default().entry(XRD).or_default();
+                *amount = amount.checked_add(fee).unwrap_or(Decimal::MAX);
+            }
+        }
+
+        for (account, burned) in self.burned {
+            for (resource_address, burned_amount) in burned {
+                if burned_amount.is_positive() {
+                    let amount = outflows
+                        .entry(account)
+                        .or_default()
+                        .entry(resource_address)
+                        .or_default();
+                    *amount = amount.checked_add(burned_amount).unwrap_or(Decimal::MAX);
+                }
+            }
+        }
+
+        Some(outflows)
+    }
+
+    fn add_bucket(&mut self, resource_address: &ResourceAddress, amount: Option<Decimal>) {
+        let bucket = self.id_allocator.new_bucket_id();
+        self.bucket_tracker
+            .insert(bucket, (*resource_address, amount));
+    }
+
+    fn add_withdraw(
+        &mut self,
+        account: ComponentAddress,
+        resource_address: ResourceAddress,
+        amount: Decimal,
+    ) {
+        let withdrawn = self
+            .withdrawn
+            .entry(account)
+            .or_default()
+            .entry(resource_address)
+            .or_default();
+        *withdrawn = withdrawn.checked_add(amount).unwrap_or(Decimal::MAX);
+    }
+
+    fn add_burn(
+        &mut self,
+        account: ComponentAddress,
+        resource_address: ResourceAddress,
+        amount: Decimal,
+    ) {
+        let burned = self
+            .burned
+            .entry(account)
+            .or_default()
+            .entry(resource_address)
+            .or_default();
+        *burned = burned.checked_add(amount).unwrap_or(Decimal::MAX);
+    }
+
+    fn add_fee(&mut self, account: ComponentAddress, amount: Decimal) {
+        let fee = self.fees_locked.entry(account).or_default();
+        *fee = fee.checked_add(amount).unwrap_or(Decimal::MAX);
+    }
+
+    fn send_bucket(&mut self, bucket: &ManifestBucket) {
+        if let Some((resource_address, amount)) = self.bucket_tracker.remove(bucket) {
+            let sent = self
+                .sent
+                .entry(resource_address)
+                .or_insert(Some(Decimal::ZERO));
+            *sent = match (*sent, amount) {
+                (Some(sent), Some(amount)) => sent.checked_add(amount),
+                _ => None,
+            };
+        }
+    }
+
+    fn handle_call(&mut self, args: &ManifestValue, is_third_party: bool) {
+        let indexed_manifest_value = IndexedManifestValue::from_manifest_value(args);
+        for bucket in indexed_manifest_value.buckets() {
+            if is_third_party {
+                self.send_bucket(bucket)
+            } else {
+                self.bucket_tracker.remove(bucket);
+            }
+        }
+        if is_third_party && !indexed_manifest_value.expressions().is_empty() {
+            self.is_entire_worktop_sent = true;
+        }
+    }
+
+    fn handle_account_method(
+        &mut self,
+        account: ComponentAddress,
+        method_name: &str,
+        args: &ManifestValue,
+    ) {
+        let ids_amount = |ids: &IndexSet<NonFungibleLocalId>| Decimal::from(ids.len() as u64);
+
+        match method_name {
+            ACCOUNT_WITHDRAW_IDENT => {
+                if let Some(AccountWithdrawInput {
+                    resource_address,
+                    amount,
+                }) = to_manifest_type(args)
+                {
+                    self.add_withdraw(account, resource_address, amount)
+                }
+            }
+            ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                if let Some(AccountWithdrawNonFungiblesInput {
+                    resource_address,
+                    ids,
+                }) = to_manifest_type(args)
+                {
+                    self.add_withdraw(account, resource_address, ids_amount(&ids))
+                }
+            }
+            ACCOUNT_LOCK_FEE_IDENT => {
+                if let Some(AccountLockFeeInput { amount }) = to_manifest_type(args) {
+                    self.add_fee(account, amount)
+                }
+            }
+            ACCOUNT_LOCK_CONTINGENT_FEE_IDENT => {
+                if let Some(AccountLockContingentFeeInput { amount }) = to_manifest_type(args) {
+                    self.add_fee(account, amount)
+                }
+            }
+            ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => {
+                if let Some(AccountLockFeeAndWithdrawInput {
+                    amount_to_lock,
+                    resource_address,
+                    amount,
+                }) = to_manifest_type(args)
+                {
+                    self.add_fee(account, amount_to_lock);
+                    self.add_withdraw(account, resource_address, amount)
+                }
+            }
+            ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                if let Some(AccountLockFeeAndWithdrawNonFungiblesInput {
+                    amount_to_lock,
+                    resource_address,
+                    ids,
+                }) = to_manifest_type(args)
+                {
+                    self.add_fee(account, amount_to_lock);
+                    self.add_withdraw(account, resource_address, ids_amount(&ids))
+                }
+            }
+            ACCOUNT_BURN_IDENT => {
+                if let Some(AccountBurnInput {
+                    resource_address,
+                    amount,
+                }) = to_manifest_type(args)
+                {
+                    self.add_burn(account, resource_address, amount)
+                }
+            }
+            ACCOUNT_BURN_NON_FUNGIBLES_IDENT => {
+                if let Some(AccountBurnNonFungiblesInput {
+                    resource_address,
+                    ids,
+                }) = to_manifest_type(args)
+                {
+                    self.add_burn(account, resource_address, ids_amount(&ids))
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl InstructionVisitor for MaximumOutflowsVisitor {
+    fn visit_take_all_from_worktop(
+        &mut self,
+        resource_address: &ResourceAddress,
+    ) -> Result<(), InstructionVisitorError> {
+        self.add_bucket(resource_address, None);
+        Ok(())
+    }
+
+    fn visit_take_from_worktop(
+        &mut self,
+        resource_address: &ResourceAddress,
+        amount: &Decimal,
+    ) -> Result<(), InstructionVisitorError> {
+        self.add_bucket(resource_address, Some(*amount));
+        Ok(())
+    }
+
+    fn visit_take_non_fungibles_from_worktop(
+        &mut self,
+        resource_address: &ResourceAddress,
+        ids: &[NonFungibleLocalId],
+    ) -> Result<(), InstructionVisitorError> {
+        self.add_bucket(resource_address, Some(Decimal::from(ids.len() as u64)));
+        Ok(())
+    }
+
+    fn visit_return_to_worktop(
+        &mut self,
+        bucket_id: &ManifestBucket,
+    ) -> Result<(), InstructionVisitorError> {
+        self.bucket_tracker.remove(bucket_id);
+        Ok(())
+    }
+
+    fn visit_burn_resource(
+        &mut self,
+        bucket_id: &ManifestBucket,
+    ) -> Result<(), InstructionVisitorError> {
+        self.send_bucket(bucket_id);
+        Ok(())
+    }
+
+    fn visit_call_function(
+        &mut self,
+        _: &DynamicPackageAddress,
+        _: &str,
+        _: &str,
+        args: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        self.handle_call(args, true);
+        Ok(())
+    }
+
+    fn visit_call_method(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        args: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        let account = match address {
+            DynamicGlobalAddress::Static(address) => {
+                ComponentAddress::try_from(address.as_node_id().as_bytes())
+                    .ok()
+                    .filter(|address| self.accounts.contains(address))
+            }
+            DynamicGlobalAddress::Named(..) => None,
+        };
+
+        if let Some(account) = account {
+            self.handle_account_method(account, method_name, args);
+        }
+        self.handle_call(args, account.is_none());
+        Ok(())
+    }
+    fn visit_call_direct_vault_method(
+        &mut self,
+        _: &InternalAddress,
+        _: &str,
+        _: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        self.is_vault_method_called = true;
+        Ok(())
+    }
+}
@@ -15,7 +15,18 @@
 // specific language governing permissions and limitations
 // under the License.
 
+pub mod access_controller_visitor;
 pub mod account_interactions_visitor;
 pub mod account_proofs_visitor;
+pub mod addresses_by_role_visitor;
+pub mod authorized_depositor_badges_visitor;
+pub mod fee_locks_visitor;
+pub mod id_allocation_visitor;
 pub mod identity_interactions_visitor;
+pub mod maximum_outflows_visitor;
+pub mod non_account_transfers_visitor;
+pub mod required_badges_visitor;
+pub mod required_signers_visitor;
 pub mod transaction_type;
+pub mod worktop_guarantees_visitor;
+pub mod worktop_simulator_visitor;
@@ -0,0 +1,86 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use scrypto::prelude::*;
+use transaction::prelude::*;
+
+use crate::instruction_visitor::core::error::InstructionVisitorError;
+use crate::instruction_visitor::core::traits::InstructionVisitor;
+use crate::sbor::indexed_manifest_value::IndexedManifestValue;
+use crate::utils::is_account;
+
+/// A visitor that finds the method calls passing the entire worktop or buckets to components that
+/// are not accounts. Resources handed to such components are no longer protected by the account
+/// deposit rules of the user which makes these calls the primary vector of drain attacks, so
+/// wallets warn on them.
+#[derive(Default, Clone)]
+pub struct NonAccountTransfersVisitor {
+    transfers: Vec<NonAccountTransfer>,
+    instruction_index: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NonAccountTransfer {
+    pub instruction_index: usize,
+    /// The component that the resources are passed to, which is a named address when the component
+    /// is allocated within the manifest.
+    pub component_address: DynamicGlobalAddress,
+    pub method_name: String,
+    /// Whether the `ENTIRE_WORKTOP` expression is passed to the component.
+    pub entire_worktop: bool,
+    pub buckets: Vec<ManifestBucket>,
+}
+
+impl NonAccountTransfersVisitor {
+    pub fn output(self) -> Vec<NonAccountTransfer> {
+        self.transfers
+    }
+}
+
+impl InstructionVisitor for NonAccountTransfersVisitor {
+    fn post_visit(&mut self) -> Result<(), InstructionVisitorError> {
+        self.instruction_index += 1;
+        Ok(())
+    }
+
+    fn visit_call_method(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        args: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        if is_account(address) {
+            return Ok(());
+        }
+
+        let args = IndexedManifestValue::from_manifest_value(args);
+        let entire_worktop = args
+            .expressions()
+            .contains(&ManifestExpression::EntireWorktop);
+        let buckets = args.buckets().clone();
+        if entire_worktop || !buckets.is_empty() {
+            self.transfers.push(NonAccountTransfer {
+                instruction_index: self.instruction_index,
+                component_address: address.clone(),
+                method_name: method_name.to_owned(),
+                entire_worktop,
+                buckets,
+            })
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,287 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_interface::blueprints::account::*;
+use scrypto::prelude::*;
+use transaction::prelude::DynamicGlobalAddress;
+
+use crate::instruction_visitor::core::error::InstructionVisitorError;
+use crate::instruction_visitor::core::traits::InstructionVisitor;
+use crate::statics::*;
+use crate::utils::to_manifest_type;
+
+/// The proofs created and the badges required by a single instruction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstructionBadgeRequirements {
+    pub instruction_index: usize,
+    pub proofs_created: Vec<CreatedProof>,
+    pub required_badges: Vec<RequiredBadge>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CreatedProof {
+    Amount {
+        resource_address: ResourceAddress,
+        amount: Decimal,
+    },
+    NonFungibles {
+        resource_address: ResourceAddress,
+        ids: Vec<NonFungibleLocalId>,
+    },
+    All {
+        resource_address: ResourceAddress,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RequiredBadge {
+    pub entity_address: GlobalAddress,
+    pub method_name: String,
+    pub requirement: BadgeRequirement,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BadgeRequirement {
+    /// A proof of the non-fungible must be present in the auth zone. This is the default owner
+    /// badge of the entity which could have been changed after the entity was created.
+    NonFungible(NonFungibleGlobalId),
+    /// A proof satisfying any of the roles must be present in the auth zone. Used for entities
+    /// whose role assignments are not known statically.
+    AnyOfRoles(Vec<String>),
+}
+
+/// A visitor that finds the proofs created in a manifest and the badges that must be present in
+/// the auth zone for the method calls to the access-controlled entities that the toolkit knows
+/// about: accounts, identities, validators, and access controllers.
+#[derive(Clone, Debug, Default)]
+pub struct RequiredBadgesVisitor {
+    instruction_index: usize,
+    proofs_created: Vec<CreatedProof>,
+    required_badges: Vec<RequiredBadge>,
+    output: Vec<InstructionBadgeRequirements>,
+}
+
+impl RequiredBadgesVisitor {
+    pub fn output(self) -> Vec<InstructionBadgeRequirements> {
+        self.output
+    }
+
+    fn require_owner_badge(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        methods_that_require_auth: &[MethodKey],
+    ) {
+        let DynamicGlobalAddress::Static(address) = address else {
+            return;
+        };
+        if !methods_that_require_auth
+            .iter()
+            .any(|MethodKey { ident }| ident.as_str() == method_name)
+        {
+            return;
+        }
+        if let Some(badge) = default_owner_badge(address) {
+            self.required_badges.push(RequiredBadge {
+                entity_address: *address,
+                method_name: method_name.to_owned(),
+                requirement: BadgeRequirement::NonFungible(badge),
+            })
+        }
+    }
+}
+
+impl InstructionVisitor for RequiredBadgesVisitor {
+    fn visit_create_proof_from_auth_zone_of_amount(
+        &mut self,
+        resource_address: &ResourceAddress,
+        amount: &Decimal,
+    ) -> Result<(), InstructionVisitorError> {
+        self.proofs_created.push(CreatedProof::Amount {
+            resource_address: *resource_address,
+            amount: *amount,
+        });
+        Ok(())
+    }
+
+    fn visit_create_proof_from_auth_zone_of_non_fungibles(
+        &mut self,
+        resource_address: &ResourceAddress,
+        ids: &[NonFungibleLocalId],
+    ) -> Result<(), InstructionVisitorError> {
+        self.proofs_created.push(CreatedProof::NonFungibles {
+            resource_address: *resource_address,
+            ids: ids.to_vec(),
+        });
+        Ok(())
+    }
+
+    fn visit_create_proof_from_auth_zone_of_all(
+        &mut self,
+        resource_address: &ResourceAddress,
+    ) -> Result<(), InstructionVisitorError> {
+        self.proofs_created.push(CreatedProof::All {
+            resource_address: *resource_address,
+        });
+        Ok(())
+    }
+
+    fn visit_call_method(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        args: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        match entity_type(address) {
+            Some(
+                EntityType::GlobalAccount
+                | EntityType::GlobalVirtualSecp256k1Account
+                | EntityType::GlobalVirtualEd25519Account,
+            ) => {
+                self.require_owner_badge(address, method_name, &ACCOUNT_METHODS_THAT_REQUIRE_AUTH);
+                match method_name {
+                    ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT => {
+                        if let Some(AccountCreateProofOfAmountInput {
+                            resource_address,
+                            amount,
+                        }) = to_manifest_type(args)
+                        {
+                            self.proofs_created.push(CreatedProof::Amount {
+                                resource_address,
+                                amount,
+                            })
+                        }
+                    }
+                    ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT => {
+                        if let Some(AccountCreateProofOfNonFungiblesInput {
+                            resource_address,
+                            ids,
+                        }) = to_manifest_type(args)
+                        {
+                            self.proofs_created.push(CreatedProof::NonFungibles {
+                                resource_address,
+                                ids: ids.into_iter().collect(),
+                            })
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Some(
+                EntityType::GlobalIdentity
+                | EntityType::GlobalVirtualSecp256k1Identity
+                | EntityType::GlobalVirtualEd25519Identity,
+            ) => {
+                self.require_owner_badge(address, method_name, &IDENTITY_METHODS_THAT_REQUIRE_AUTH)
+            }
+            Some(EntityType::GlobalValidator) => {
+                self.require_owner_badge(address, method_name, &VALIDATOR_METHODS_THAT_REQUIRE_AUTH)
+            }
+            Some(EntityType::GlobalAccessController) => {
+                if let (DynamicGlobalAddress::Static(entity_address), Some((_, roles))) = (
+                    address,
+                    ACCESS_CONTROLLER_METHOD_ROLES
+                        .iter()
+                        .find(|(MethodKey { ident }, _)| ident.as_str() == method_name),
+                ) {
+                    self.required_badges.push(RequiredBadge {
+                        entity_address: *entity_address,
+                        method_name: method_name.to_owned(),
+                        requirement: BadgeRequirement::AnyOfRoles(roles.clone()),
+                    })
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn visit_call_metadata_method(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        _: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        self.require_owner_badge(address, method_name, &METADATA_METHODS_THAT_REQUIRE_AUTH);
+        Ok(())
+    }
+
+    fn visit_call_royalty_method(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        _: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        self.require_owner_badge(address, method_name, &ROYALTY_METHODS_THAT_REQUIRE_AUTH);
+        Ok(())
+    }
+
+    fn visit_call_role_assignment_method(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        _: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        self.require_owner_badge(
+            address,
+            method_name,
+            &ROLE_ASSIGNMENT_METHODS_THAT_REQUIRE_AUTH,
+        );
+        Ok(())
+    }
+
+    fn post_visit(&mut self) -> Result<(), InstructionVisitorError> {
+        if !self.proofs_created.is_empty() || !self.required_badges.is_empty() {
+            self.output.push(InstructionBadgeRequirements {
+                instruction_index: self.instruction_index,
+                proofs_created: std::mem::take(&mut self.proofs_created),
+                required_badges: std::mem::take(&mut self.required_badges),
+            })
+        }
+        self.instruction_index += 1;
+        Ok(())
+    }
+}
+
+fn entity_type(address: &DynamicGlobalAddress) -> Option<EntityType> {
+    match address {
+        DynamicGlobalAddress::Static(address) => address.as_node_id().entity_type(),
+        DynamicGlobalAddress::Named(_) => None,
+    }
+}
+
+/// The badge that an entity is owned by when it's created. Virtual accounts and identities are
+/// owned by the signature badge of the key they were derived from while allocated accounts,
+/// identities, and validators are owned by the owner badge minted for them.
+fn default_owner_badge(address: &GlobalAddress) -> Option<NonFungibleGlobalId> {
+    let node_id = address.as_node_id();
+    let (resource_address, local_id) = match node_id.entity_type()? {
+        EntityType::GlobalVirtualSecp256k1Account | EntityType::GlobalVirtualSecp256k1Identity => {
+            (SECP256K1_SIGNATURE_VIRTUAL_BADGE, node_id.0[1..].to_vec())
+        }
+        EntityType::GlobalVirtualEd25519Account | EntityType::GlobalVirtualEd25519Identity => {
+            (ED25519_SIGNATURE_VIRTUAL_BADGE, node_id.0[1..].to_vec())
+        }
+        EntityType::GlobalAccount => (ACCOUNT_OWNER_BADGE, node_id.0.to_vec()),
+        EntityType::GlobalIdentity => (IDENTITY_OWNER_BADGE, node_id.0.to_vec()),
+        EntityType::GlobalValidator => (VALIDATOR_OWNER_BADGE, node_id.0.to_vec()),
+        _ => return None,
+    };
+    NonFungibleLocalId::bytes(local_id)
+        .ok()
+        .map(|local_id| NonFungibleGlobalId::new(resource_address, local_id))
+}
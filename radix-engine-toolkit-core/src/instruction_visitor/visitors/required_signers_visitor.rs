@@ -0,0 +1,175 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use scrypto::prelude::*;
+use transaction::prelude::DynamicGlobalAddress;
+
+use crate::instruction_visitor::core::error::InstructionVisitorError;
+use crate::instruction_visitor::core::traits::InstructionVisitor;
+use crate::statics::*;
+use crate::utils::{is_access_controller, is_account, is_identity};
+
+/// An entity whose auth is required by the manifest along with the methods which triggered the
+/// requirement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RequiredSigner {
+    pub entity_address: GlobalAddress,
+    pub entity_kind: SignerEntityKind,
+    pub methods: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SignerEntityKind {
+    Account,
+    Identity,
+    AccessController,
+}
+
+/// A visitor which combines the account, identity and access controller auth detection into a
+/// single pass. Signers are reported in the order in which they're first encountered.
+#[derive(Clone, Debug, Default)]
+pub struct RequiredSignersVisitor(IndexMap<GlobalAddress, (SignerEntityKind, IndexSet<String>)>);
+
+impl RequiredSignersVisitor {
+    pub fn output(self) -> Vec<RequiredSigner> {
+        self.0
+            .into_iter()
+            .map(|(entity_address, (entity_kind, methods))| RequiredSigner {
+                entity_address,
+                entity_kind,
+                methods: methods.into_iter().collect(),
+            })
+            .collect()
+    }
+
+    fn record(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        main_module_methods: Option<&[MethodKey]>,
+    ) {
+        let DynamicGlobalAddress::Static(entity_address) = address else {
+            return;
+        };
+
+        let (entity_kind, requires_auth) = if is_account(address) {
+            (
+                SignerEntityKind::Account,
+                Self::requires_auth(
+                    main_module_methods,
+                    &ACCOUNT_METHODS_THAT_REQUIRE_AUTH,
+                    method_name,
+                ),
+            )
+        } else if is_identity(address) {
+            (
+                SignerEntityKind::Identity,
+                Self::requires_auth(
+                    main_module_methods,
+                    &IDENTITY_METHODS_THAT_REQUIRE_AUTH,
+                    method_name,
+                ),
+            )
+        } else if is_access_controller(address) {
+            let access_controller_methods = ACCESS_CONTROLLER_METHOD_ROLES
+                .iter()
+                .map(|(method_key, _)| method_key.clone())
+                .collect::<Vec<_>>();
+            (
+                SignerEntityKind::AccessController,
+                Self::requires_auth(main_module_methods, &access_controller_methods, method_name),
+            )
+        } else {
+            return;
+        };
+
+        if requires_auth {
+            self.0
+                .entry(*entity_address)
+                .or_insert_with(|| (entity_kind, IndexSet::new()))
+                .1
+                .insert(method_name.to_owned());
+        }
+    }
+
+    /// Checks if the method requires auth. Module methods are checked against the methods of the
+    /// module while main module methods are checked against the methods of the entity's blueprint.
+    fn requires_auth(
+        module_methods: Option<&[MethodKey]>,
+        blueprint_methods: &[MethodKey],
+        method_name: &str,
+    ) -> bool {
+        module_methods
+            .unwrap_or(blueprint_methods)
+            .iter()
+            .any(|MethodKey { ident }| ident.as_str() == method_name)
+    }
+}
+
+impl InstructionVisitor for RequiredSignersVisitor {
+    fn visit_call_method(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        _: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        self.record(address, method_name, None);
+        Ok(())
+    }
+
+    fn visit_call_royalty_method(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        _: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        self.record(
+            address,
+            method_name,
+            Some(ROYALTY_METHODS_THAT_REQUIRE_AUTH.as_slice()),
+        );
+        Ok(())
+    }
+
+    fn visit_call_metadata_method(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        _: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        self.record(
+            address,
+            method_name,
+            Some(METADATA_METHODS_THAT_REQUIRE_AUTH.as_slice()),
+        );
+        Ok(())
+    }
+
+    fn visit_call_role_assignment_method(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        _: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        self.record(
+            address,
+            method_name,
+            Some(ROLE_ASSIGNMENT_METHODS_THAT_REQUIRE_AUTH.as_slice()),
+        );
+        Ok(())
+    }
+}
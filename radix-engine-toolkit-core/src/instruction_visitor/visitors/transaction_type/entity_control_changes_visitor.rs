@@ -0,0 +1,125 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use scrypto::api::node_modules::auth::*;
+use scrypto::api::node_modules::metadata::*;
+use scrypto::prelude::*;
+use transaction::prelude::DynamicGlobalAddress;
+
+use crate::instruction_visitor::core::error::InstructionVisitorError;
+use crate::instruction_visitor::core::traits::InstructionVisitor;
+use crate::utils::to_manifest_type;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EntityControlChange {
+    /// A metadata key was locked, permanently fixing its value.
+    MetadataLocked { key: String },
+    RoleSet {
+        module: ObjectModuleId,
+        role_key: RoleKey,
+        rule: AccessRule,
+    },
+    /// The owner role was changed which transfers the ownership of the entity.
+    OwnerRoleSet { rule: AccessRule },
+    /// The owner role was locked, permanently fixing the owner of the entity.
+    OwnerRoleLocked,
+}
+
+/// A visitor that collects the changes made to who controls entities: locked metadata, role
+/// assignments, and changes to the owner role. These are high-risk operations that wallets must
+/// flag clearly, so, like the owner keys visitor, other instructions in the manifest don't
+/// disqualify it.
+#[derive(Clone, Debug, Default)]
+pub struct EntityControlChangesVisitor {
+    /// Maps the address of the entity to the changes made to its control in the order they appear
+    /// in the manifest.
+    entity_control_changes: IndexMap<GlobalAddress, Vec<EntityControlChange>>,
+}
+
+impl EntityControlChangesVisitor {
+    pub fn output(self) -> Option<IndexMap<GlobalAddress, Vec<EntityControlChange>>> {
+        if self.entity_control_changes.is_empty() {
+            None
+        } else {
+            Some(self.entity_control_changes)
+        }
+    }
+
+    fn add_change(&mut self, address: GlobalAddress, change: EntityControlChange) {
+        self.entity_control_changes
+            .entry(address)
+            .or_default()
+            .push(change);
+    }
+}
+
+impl InstructionVisitor for EntityControlChangesVisitor {
+    fn visit_call_metadata_method(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        args: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        let DynamicGlobalAddress::Static(address) = address else {
+            return Ok(());
+        };
+
+        if method_name == METADATA_LOCK_IDENT {
+            if let Some(MetadataLockInput { key }) = to_manifest_type(args) {
+                self.add_change(*address, EntityControlChange::MetadataLocked { key })
+            }
+        }
+        Ok(())
+    }
+
+    fn visit_call_role_assignment_method(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        args: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        let DynamicGlobalAddress::Static(address) = address else {
+            return Ok(());
+        };
+
+        let change = match method_name {
+            ROLE_ASSIGNMENT_SET_IDENT => match to_manifest_type(args) {
+                Some(RoleAssignmentSetInput {
+                    module,
+                    role_key,
+                    rule,
+                }) => EntityControlChange::RoleSet {
+                    module,
+                    role_key,
+                    rule,
+                },
+                None => return Ok(()),
+            },
+            ROLE_ASSIGNMENT_SET_OWNER_IDENT => match to_manifest_type(args) {
+                Some(RoleAssignmentSetOwnerInput { rule }) => {
+                    EntityControlChange::OwnerRoleSet { rule }
+                }
+                None => return Ok(()),
+            },
+            ROLE_ASSIGNMENT_LOCK_OWNER_IDENT => EntityControlChange::OwnerRoleLocked,
+            _ => return Ok(()),
+        };
+
+        self.add_change(*address, change);
+        Ok(())
+    }
+}
@@ -61,6 +61,19 @@ pub struct GeneralTransactionTypeVisitor<'r> {
     /// Tracks the buckets and their contents
     bucket_tracker: HashMap<ManifestBucket, ResourceTracker>,
 
+    /// The proofs created from the accounts
+    account_created_proofs: HashMap<ComponentAddress, Vec<ResourceSpecifier>>,
+
+    /// The proofs created from the accounts that were presented to components
+    presented_proofs: Vec<PresentedProof>,
+
+    /// Tracks the proofs in the auth zone. Proofs created from accounts are tracked while all other
+    /// proofs are `None`.
+    auth_zone_proofs: Vec<Option<AccountProof>>,
+
+    /// Tracks the named proofs that were created from account proofs
+    proof_tracker: HashMap<ManifestProof, AccountProof>,
+
     /// The index of the current instruction
     instruction_index: usize,
 }
@@ -146,6 +159,55 @@ impl<'r> InstructionVisitor for GeneralTransactionTypeVisitor<'r> {
                     instruction_index: self.instruction_index,
                 })?,
 
+            /* Proofs */
+            InstructionV1::PopFromAuthZone => {
+                let proof = self.id_allocator.new_proof_id();
+                if let Some(Some(account_proof)) = self.auth_zone_proofs.pop() {
+                    self.proof_tracker.insert(proof, account_proof);
+                }
+            }
+            InstructionV1::PushToAuthZone { proof_id } => {
+                let account_proof = self.proof_tracker.remove(proof_id);
+                self.auth_zone_proofs.push(account_proof);
+            }
+            InstructionV1::CreateProofFromAuthZoneOfAmount {
+                resource_address,
+                amount,
+            } => self.handle_create_proof_from_auth_zone(resource_address, |_| {
+                ResourceSpecifier::Amount(*resource_address, *amount)
+            }),
+            InstructionV1::CreateProofFromAuthZoneOfNonFungibles {
+                resource_address,
+                ids,
+            } => self.handle_create_proof_from_auth_zone(resource_address, |_| {
+                ResourceSpecifier::Ids(*resource_address, ids.iter().cloned().collect())
+            }),
+            InstructionV1::CreateProofFromAuthZoneOfAll { resource_address } => {
+                self.handle_create_proof_from_auth_zone(resource_address, |proof| proof.clone())
+            }
+            InstructionV1::CreateProofFromBucketOfAmount { .. }
+            | InstructionV1::CreateProofFromBucketOfNonFungibles { .. }
+            | InstructionV1::CreateProofFromBucketOfAll { .. } => {
+                self.id_allocator.new_proof_id();
+            }
+            InstructionV1::CloneProof { proof_id } => {
+                let proof = self.id_allocator.new_proof_id();
+                if let Some(account_proof) = self.proof_tracker.get(proof_id).cloned() {
+                    self.proof_tracker.insert(proof, account_proof);
+                }
+            }
+            InstructionV1::DropProof { proof_id } => {
+                self.proof_tracker.remove(proof_id);
+            }
+            InstructionV1::DropNamedProofs => self.proof_tracker.clear(),
+            InstructionV1::DropAuthZoneProofs | InstructionV1::DropAuthZoneRegularProofs => {
+                self.auth_zone_proofs.clear()
+            }
+            InstructionV1::DropAllProofs => {
+                self.proof_tracker.clear();
+                self.auth_zone_proofs.clear();
+            }
+
             /* Non-main module method put the visitor in illegal state */
             InstructionV1::CallRoyaltyMethod { .. }
             | InstructionV1::CallMetadataMethod { .. }
@@ -162,21 +224,7 @@ impl<'r> InstructionVisitor for GeneralTransactionTypeVisitor<'r> {
             InstructionV1::AssertWorktopContains { .. }
             | InstructionV1::AssertWorktopContainsAny { .. }
             | InstructionV1::AssertWorktopContainsNonFungibles { .. }
-            | InstructionV1::PopFromAuthZone
-            | InstructionV1::PushToAuthZone { .. }
-            | InstructionV1::DropNamedProofs
-            | InstructionV1::DropAuthZoneProofs
-            | InstructionV1::DropAuthZoneRegularProofs
             | InstructionV1::DropAuthZoneSignatureProofs
-            | InstructionV1::CreateProofFromAuthZoneOfAmount { .. }
-            | InstructionV1::CreateProofFromAuthZoneOfNonFungibles { .. }
-            | InstructionV1::CreateProofFromAuthZoneOfAll { .. }
-            | InstructionV1::CreateProofFromBucketOfAmount { .. }
-            | InstructionV1::CreateProofFromBucketOfNonFungibles { .. }
-            | InstructionV1::CreateProofFromBucketOfAll { .. }
-            | InstructionV1::CloneProof { .. }
-            | InstructionV1::DropProof { .. }
-            | InstructionV1::DropAllProofs
             | InstructionV1::AllocateGlobalAddress { .. } => {}
         }
         Ok(())
@@ -193,6 +241,10 @@ impl<'r> GeneralTransactionTypeVisitor<'r> {
             instruction_index: Default::default(),
             id_allocator: Default::default(),
             bucket_tracker: Default::default(),
+            account_created_proofs: Default::default(),
+            presented_proofs: Default::default(),
+            auth_zone_proofs: Default::default(),
+            proof_tracker: Default::default(),
         }
     }
 
@@ -202,11 +254,18 @@ impl<'r> GeneralTransactionTypeVisitor<'r> {
     ) -> Option<(
         HashMap<ComponentAddress, Vec<ResourceTracker>>,
         HashMap<ComponentAddress, Vec<ResourceTracker>>,
+        HashMap<ComponentAddress, Vec<ResourceSpecifier>>,
+        Vec<PresentedProof>,
     )> {
         if self.is_illegal_state {
             None
         } else {
-            Some((self.account_withdraws, self.account_deposits))
+            Some((
+                self.account_withdraws,
+                self.account_deposits,
+                self.account_created_proofs,
+                self.presented_proofs,
+            ))
         }
     }
 
@@ -227,6 +286,11 @@ impl<'r> GeneralTransactionTypeVisitor<'r> {
                 .ok_or(GeneralTransactionTypeError::UnknownBucket(*bucket))?;
         }
 
+        // Handle passed proofs
+        for proof in indexed_manifest_value.proofs() {
+            self.proof_tracker.remove(proof);
+        }
+
         Ok(())
     }
 
@@ -425,6 +489,60 @@ impl<'r> GeneralTransactionTypeVisitor<'r> {
                         .push(bucket_amount)
                 }
             }
+        } else if [
+            ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT,
+            ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT,
+        ]
+        .contains(&method_name)
+            && is_account(global_address)
+        {
+            // This never panics. We have already checked that this is an account when we called
+            // `is_account`.
+            let component_address = ComponentAddress::new_or_panic(global_address.as_node_id().0);
+
+            let encoded_args = manifest_encode(args).map_err(|error| {
+                GeneralTransactionTypeError::SborEncodeError {
+                    value: args.clone(),
+                    error,
+                }
+            })?;
+            let proof = if method_name == ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT {
+                manifest_decode::<AccountCreateProofOfAmountInput>(&encoded_args)
+                    .ok()
+                    .map(
+                        |AccountCreateProofOfAmountInput {
+                             resource_address,
+                             amount,
+                         }| {
+                            ResourceSpecifier::Amount(resource_address, amount)
+                        },
+                    )
+            } else {
+                manifest_decode::<AccountCreateProofOfNonFungiblesInput>(&encoded_args)
+                    .ok()
+                    .map(
+                        |AccountCreateProofOfNonFungiblesInput {
+                             resource_address,
+                             ids,
+                         }| {
+                            ResourceSpecifier::Ids(resource_address, ids.into_iter().collect())
+                        },
+                    )
+            };
+            let Some(proof) = proof else {
+                self.is_illegal_state = true;
+                return Ok(());
+            };
+
+            // The proofs created from accounts are put in the auth zone
+            self.account_created_proofs
+                .entry(component_address)
+                .or_default()
+                .push(proof.clone());
+            self.auth_zone_proofs.push(Some(AccountProof {
+                account: component_address,
+                proof,
+            }));
         } else {
             let indexed_manifest_value = IndexedManifestValue::from_manifest_value(args);
             for bucket in indexed_manifest_value.buckets() {
@@ -432,11 +550,69 @@ impl<'r> GeneralTransactionTypeVisitor<'r> {
                     .remove(bucket)
                     .ok_or(GeneralTransactionTypeError::UnknownBucket(*bucket))?;
             }
+
+            // The proofs in the auth zone and the named proofs passed in the arguments are
+            // presented to the component being called.
+            let passed_proofs = indexed_manifest_value
+                .proofs()
+                .iter()
+                .filter_map(|proof| self.proof_tracker.remove(proof))
+                .collect::<Vec<_>>();
+            if !is_account(global_address) {
+                let presented_proofs = self
+                    .auth_zone_proofs
+                    .iter()
+                    .flatten()
+                    .cloned()
+                    .chain(passed_proofs)
+                    .map(|AccountProof { account, proof }| PresentedProof {
+                        instruction_index: self.instruction_index,
+                        component_address: *global_address,
+                        method_name: method_name.to_owned(),
+                        account,
+                        proof,
+                    })
+                    .collect::<Vec<_>>();
+                self.presented_proofs.extend(presented_proofs);
+            }
         }
 
         Ok(())
     }
 
+    pub fn handle_create_proof_from_auth_zone<F>(
+        &mut self,
+        resource_address: &ResourceAddress,
+        proof_specifier: F,
+    ) where
+        F: FnOnce(&ResourceSpecifier) -> ResourceSpecifier,
+    {
+        // The proof created from the auth zone is only tracked if it's of a resource that was
+        // proved from an account, in which case the new proof is of the same account.
+        let proof = self.id_allocator.new_proof_id();
+        if let Some(AccountProof {
+            account,
+            proof: account_proof,
+        }) = self
+            .auth_zone_proofs
+            .iter()
+            .flatten()
+            .rev()
+            .find(|account_proof| match &account_proof.proof {
+                ResourceSpecifier::Amount(proof_resource_address, _)
+                | ResourceSpecifier::Ids(proof_resource_address, _) => {
+                    proof_resource_address == resource_address
+                }
+            })
+        {
+            let account_proof = AccountProof {
+                account: *account,
+                proof: proof_specifier(account_proof),
+            };
+            self.proof_tracker.insert(proof, account_proof);
+        }
+    }
+
     pub fn handle_take_from_worktop(
         &mut self,
         resource_address: &ResourceAddress,
@@ -658,6 +834,24 @@ pub enum GeneralTransactionTypeError {
     },
 }
 
+/// A proof created from an account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountProof {
+    pub account: ComponentAddress,
+    pub proof: ResourceSpecifier,
+}
+
+/// A proof created from an account and presented to a component either through the auth zone or
+/// by being passed as an argument to the method call.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PresentedProof {
+    pub instruction_index: usize,
+    pub component_address: GlobalAddress,
+    pub method_name: String,
+    pub account: ComponentAddress,
+    pub proof: ResourceSpecifier,
+}
+
 #[derive(Debug, Clone)]
 pub struct LocatedGeneralTransactionTypeError {
     pub instruction_index: usize,
@@ -17,9 +17,13 @@
 
 pub mod account_deposit_settings_visitor;
 pub mod claim_stake_visitor;
+pub mod entity_control_changes_visitor;
 pub mod general_transaction_visitor;
+pub mod owner_keys_visitor;
 pub mod reserved_instructions;
+pub mod royalty_claims_visitor;
 pub mod simple_transfer_visitor;
 pub mod stake_visitor;
+pub mod swap_visitor;
 pub mod transfer_visitor;
 pub mod unstake_visitor;
@@ -0,0 +1,97 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use scrypto::api::node_modules::metadata::*;
+use scrypto::prelude::*;
+use transaction::prelude::DynamicGlobalAddress;
+
+use crate::instruction_visitor::core::error::InstructionVisitorError;
+use crate::instruction_visitor::core::traits::InstructionVisitor;
+use crate::utils::to_manifest_type;
+
+/// The metadata key that holds the hashes of the public keys controlling an entity, used by ROLA
+/// to verify that a signer owns an account or identity.
+pub const OWNER_KEYS_METADATA_KEY: &str = "owner_keys";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OwnerKeysAction {
+    Set(Vec<PublicKeyHash>),
+    Remove,
+    Lock,
+}
+
+/// A visitor that collects the changes made to the `owner_keys` metadata of entities. Unlike the
+/// other transaction type visitors, other instructions in the manifest don't disqualify it since
+/// changes to the owner keys need to be surfaced regardless of what else the transaction does.
+#[derive(Clone, Debug, Default)]
+pub struct OwnerKeysVisitor {
+    /// Maps the address of the entity to the actions performed on its owner keys in the order they
+    /// appear in the manifest.
+    owner_keys_changes: IndexMap<GlobalAddress, Vec<OwnerKeysAction>>,
+}
+
+impl OwnerKeysVisitor {
+    pub fn output(self) -> Option<IndexMap<GlobalAddress, Vec<OwnerKeysAction>>> {
+        if self.owner_keys_changes.is_empty() {
+            None
+        } else {
+            Some(self.owner_keys_changes)
+        }
+    }
+}
+
+impl InstructionVisitor for OwnerKeysVisitor {
+    fn visit_call_metadata_method(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        args: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        let DynamicGlobalAddress::Static(address) = address else {
+            return Ok(());
+        };
+
+        let action = match method_name {
+            METADATA_SET_IDENT => match to_manifest_type(args) {
+                Some(MetadataSetInput {
+                    key,
+                    value: MetadataValue::PublicKeyHashArray(public_key_hashes),
+                }) if key == OWNER_KEYS_METADATA_KEY => OwnerKeysAction::Set(public_key_hashes),
+                _ => return Ok(()),
+            },
+            METADATA_REMOVE_IDENT => match to_manifest_type(args) {
+                Some(MetadataRemoveInput { key }) if key == OWNER_KEYS_METADATA_KEY => {
+                    OwnerKeysAction::Remove
+                }
+                _ => return Ok(()),
+            },
+            METADATA_LOCK_IDENT => match to_manifest_type(args) {
+                Some(MetadataLockInput { key }) if key == OWNER_KEYS_METADATA_KEY => {
+                    OwnerKeysAction::Lock
+                }
+                _ => return Ok(()),
+            },
+            _ => return Ok(()),
+        };
+
+        self.owner_keys_changes
+            .entry(*address)
+            .or_default()
+            .push(action);
+        Ok(())
+    }
+}
@@ -18,10 +18,11 @@
 use radix_engine_interface::blueprints::account::*;
 use radix_engine_interface::blueprints::identity::*;
 use scrypto::prelude::*;
+use transaction::prelude::DynamicGlobalAddress;
 
 use crate::{
     instruction_visitor::core::traits::InstructionVisitor,
-    utils::{is_access_controller, is_account},
+    utils::{is_access_controller, is_account, is_identity, to_manifest_type},
 };
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq, Copy)]
@@ -33,22 +34,103 @@ pub enum ReservedInstruction {
     AccessController,
 }
 
+/// An instruction of the manifest that calls reserved functionality along with the arguments of the
+/// call.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReservedInstructionUsage {
+    pub instruction_index: usize,
+    pub address: DynamicGlobalAddress,
+    pub reserved_instruction: ReservedInstruction,
+    pub arguments: ReservedInstructionArguments,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReservedInstructionArguments {
+    AccountLockFee {
+        amount: Decimal,
+        contingent: bool,
+    },
+    AccountLockFeeAndWithdraw {
+        amount_to_lock: Decimal,
+        resource_address: ResourceAddress,
+        amount: Decimal,
+    },
+    AccountLockFeeAndWithdrawNonFungibles {
+        amount_to_lock: Decimal,
+        resource_address: ResourceAddress,
+        ids: Vec<NonFungibleLocalId>,
+    },
+    Securify,
+    AccountAddAuthorizedDepositor {
+        badge: ResourceOrNonFungible,
+    },
+    AccountRemoveAuthorizedDepositor {
+        badge: ResourceOrNonFungible,
+    },
+    AccountSetDefaultDepositRule {
+        default_deposit_rule: DefaultDepositRule,
+    },
+    AccountSetResourcePreference {
+        resource_address: ResourceAddress,
+        resource_preference: ResourcePreference,
+    },
+    AccountRemoveResourcePreference {
+        resource_address: ResourceAddress,
+    },
+    AccessControllerMethod {
+        method_name: String,
+    },
+    /// The arguments of the call could not be decoded into the expected input type of the method.
+    Undecodable {
+        method_name: String,
+    },
+}
+
 #[derive(Clone, Debug, Default)]
-pub struct ReservedInstructionsVisitor(HashSet<ReservedInstruction>);
+pub struct ReservedInstructionsVisitor {
+    reserved_instructions: HashSet<ReservedInstruction>,
+    usages: Vec<ReservedInstructionUsage>,
+    instruction_index: usize,
+}
 
 impl ReservedInstructionsVisitor {
     pub fn output(self) -> HashSet<ReservedInstruction> {
-        self.0
+        self.reserved_instructions
+    }
+
+    pub fn output_with_arguments(
+        self,
+    ) -> (HashSet<ReservedInstruction>, Vec<ReservedInstructionUsage>) {
+        (self.reserved_instructions, self.usages)
+    }
+
+    fn add(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        reserved_instruction: ReservedInstruction,
+        arguments: ReservedInstructionArguments,
+    ) {
+        self.reserved_instructions.insert(reserved_instruction);
+        self.usages.push(ReservedInstructionUsage {
+            instruction_index: self.instruction_index,
+            address: address.clone(),
+            reserved_instruction,
+            arguments,
+        });
     }
 }
 
 impl InstructionVisitor for ReservedInstructionsVisitor {
     fn visit_call_method(
         &mut self,
-        address: &transaction::prelude::DynamicGlobalAddress,
+        address: &DynamicGlobalAddress,
         method_name: &str,
-        _: &radix_engine_common::prelude::ManifestValue,
+        args: &radix_engine_common::prelude::ManifestValue,
     ) -> Result<(), crate::instruction_visitor::core::error::InstructionVisitorError> {
+        let undecodable = || ReservedInstructionArguments::Undecodable {
+            method_name: method_name.to_owned(),
+        };
+
         // Case: Account lock fee calls
         if is_account(address)
             && (method_name == ACCOUNT_LOCK_FEE_IDENT
@@ -56,15 +138,71 @@ impl InstructionVisitor for ReservedInstructionsVisitor {
                 || method_name == ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT
                 || method_name == ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT)
         {
-            self.0.insert(ReservedInstruction::AccountLockFee);
+            let arguments = match method_name {
+                ACCOUNT_LOCK_FEE_IDENT => {
+                    to_manifest_type(args).map(|AccountLockFeeInput { amount }| {
+                        ReservedInstructionArguments::AccountLockFee {
+                            amount,
+                            contingent: false,
+                        }
+                    })
+                }
+                ACCOUNT_LOCK_CONTINGENT_FEE_IDENT => {
+                    to_manifest_type(args).map(|AccountLockContingentFeeInput { amount }| {
+                        ReservedInstructionArguments::AccountLockFee {
+                            amount,
+                            contingent: true,
+                        }
+                    })
+                }
+                ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => to_manifest_type(args).map(
+                    |AccountLockFeeAndWithdrawInput {
+                         amount_to_lock,
+                         resource_address,
+                         amount,
+                     }| {
+                        ReservedInstructionArguments::AccountLockFeeAndWithdraw {
+                            amount_to_lock,
+                            resource_address,
+                            amount,
+                        }
+                    },
+                ),
+                _ => to_manifest_type(args).map(
+                    |AccountLockFeeAndWithdrawNonFungiblesInput {
+                         amount_to_lock,
+                         resource_address,
+                         ids,
+                     }| {
+                        ReservedInstructionArguments::AccountLockFeeAndWithdrawNonFungibles {
+                            amount_to_lock,
+                            resource_address,
+                            ids: ids.into_iter().collect(),
+                        }
+                    },
+                ),
+            };
+            self.add(
+                address,
+                ReservedInstruction::AccountLockFee,
+                arguments.unwrap_or_else(undecodable),
+            );
         }
         // Case: Account securify
         else if is_account(address) && method_name == ACCOUNT_SECURIFY_IDENT {
-            self.0.insert(ReservedInstruction::AccountSecurify);
+            self.add(
+                address,
+                ReservedInstruction::AccountSecurify,
+                ReservedInstructionArguments::Securify,
+            );
         }
         // Case: Identity securify
-        else if is_account(address) && method_name == IDENTITY_SECURIFY_IDENT {
-            self.0.insert(ReservedInstruction::IdentitySecurify);
+        else if is_identity(address) && method_name == IDENTITY_SECURIFY_IDENT {
+            self.add(
+                address,
+                ReservedInstruction::IdentitySecurify,
+                ReservedInstructionArguments::Securify,
+            );
         }
         // Case: Account Update Settings
         else if is_account(address)
@@ -74,12 +212,66 @@ impl InstructionVisitor for ReservedInstructionsVisitor {
                 || method_name == ACCOUNT_SET_RESOURCE_PREFERENCE_IDENT
                 || method_name == ACCOUNT_REMOVE_RESOURCE_PREFERENCE_IDENT)
         {
-            self.0.insert(ReservedInstruction::AccountUpdateSettings);
+            let arguments = match method_name {
+                ACCOUNT_ADD_AUTHORIZED_DEPOSITOR => {
+                    to_manifest_type(args).map(|AccountAddAuthorizedDepositorInput { badge }| {
+                        ReservedInstructionArguments::AccountAddAuthorizedDepositor { badge }
+                    })
+                }
+                ACCOUNT_REMOVE_AUTHORIZED_DEPOSITOR => {
+                    to_manifest_type(args).map(|AccountRemoveAuthorizedDepositorInput { badge }| {
+                        ReservedInstructionArguments::AccountRemoveAuthorizedDepositor { badge }
+                    })
+                }
+                ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT => {
+                    to_manifest_type(args).map(|AccountSetDefaultDepositRuleInput { default }| {
+                        ReservedInstructionArguments::AccountSetDefaultDepositRule {
+                            default_deposit_rule: default,
+                        }
+                    })
+                }
+                ACCOUNT_SET_RESOURCE_PREFERENCE_IDENT => to_manifest_type(args).map(
+                    |AccountSetResourcePreferenceInput {
+                         resource_address,
+                         resource_preference,
+                     }| {
+                        ReservedInstructionArguments::AccountSetResourcePreference {
+                            resource_address,
+                            resource_preference,
+                        }
+                    },
+                ),
+                _ => to_manifest_type(args).map(
+                    |AccountRemoveResourcePreferenceInput { resource_address }| {
+                        ReservedInstructionArguments::AccountRemoveResourcePreference {
+                            resource_address,
+                        }
+                    },
+                ),
+            };
+            self.add(
+                address,
+                ReservedInstruction::AccountUpdateSettings,
+                arguments.unwrap_or_else(undecodable),
+            );
         }
         // Case: Access Controller methods.
         else if is_access_controller(address) {
-            self.0.insert(ReservedInstruction::AccessController);
+            self.add(
+                address,
+                ReservedInstruction::AccessController,
+                ReservedInstructionArguments::AccessControllerMethod {
+                    method_name: method_name.to_owned(),
+                },
+            );
         };
         Ok(())
     }
+
+    fn post_visit(
+        &mut self,
+    ) -> Result<(), crate::instruction_visitor::core::error::InstructionVisitorError> {
+        self.instruction_index += 1;
+        Ok(())
+    }
 }
@@ -0,0 +1,126 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine::system::system_modules::execution_trace::*;
+use radix_engine_interface::blueprints::package::PACKAGE_CLAIM_ROYALTIES_IDENT;
+use scrypto::api::node_modules::royalty::COMPONENT_ROYALTY_CLAIM_ROYALTIES_IDENT;
+use scrypto::prelude::*;
+use transaction::prelude::DynamicGlobalAddress;
+
+use crate::instruction_visitor::core::error::InstructionVisitorError;
+use crate::instruction_visitor::core::traits::InstructionVisitor;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoyaltyClaim {
+    /// The index of the instruction that claimed the royalties.
+    pub instruction_index: usize,
+    /// The component or package that the royalties were claimed from.
+    pub claimed_from: GlobalAddress,
+    /// The amount of XRD returned to the worktop by the claim.
+    pub claimed_xrd: Decimal,
+}
+
+/// A visitor that collects the royalties claimed from components and packages along with the amount
+/// of XRD claimed, which is read from the worktop changes in the execution trace. Like the owner
+/// keys visitor, other instructions in the manifest don't disqualify it.
+pub struct RoyaltyClaimsVisitor<'r> {
+    /// The execution trace of the transaction
+    execution_trace: &'r TransactionExecutionTrace,
+
+    /// The index of the current instruction
+    instruction_index: usize,
+
+    /// The royalty claims made in the transaction in the order they appear in the manifest.
+    claims: Vec<RoyaltyClaim>,
+}
+
+impl<'r> RoyaltyClaimsVisitor<'r> {
+    pub fn new(execution_trace: &'r TransactionExecutionTrace) -> Self {
+        Self {
+            execution_trace,
+            instruction_index: Default::default(),
+            claims: Default::default(),
+        }
+    }
+
+    pub fn output(self) -> Option<Vec<RoyaltyClaim>> {
+        if self.claims.is_empty() {
+            None
+        } else {
+            Some(self.claims)
+        }
+    }
+
+    fn add_claim(&mut self, claimed_from: GlobalAddress) {
+        let claimed_xrd = self
+            .execution_trace
+            .worktop_changes()
+            .get(&self.instruction_index)
+            .into_iter()
+            .flatten()
+            .filter_map(|worktop_change| match worktop_change {
+                WorktopChange::Put(ResourceSpecifier::Amount(XRD, amount)) => Some(*amount),
+                _ => None,
+            })
+            .fold(Decimal::ZERO, |total, amount| {
+                total.checked_add(amount).unwrap_or(Decimal::MAX)
+            });
+
+        self.claims.push(RoyaltyClaim {
+            instruction_index: self.instruction_index,
+            claimed_from,
+            claimed_xrd,
+        })
+    }
+}
+
+impl<'r> InstructionVisitor for RoyaltyClaimsVisitor<'r> {
+    fn visit_call_method(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        _: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        if let DynamicGlobalAddress::Static(address) = address {
+            if address.as_node_id().is_global_package()
+                && method_name == PACKAGE_CLAIM_ROYALTIES_IDENT
+            {
+                self.add_claim(*address)
+            }
+        }
+        Ok(())
+    }
+
+    fn visit_call_royalty_method(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        _: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        if let DynamicGlobalAddress::Static(address) = address {
+            if method_name == COMPONENT_ROYALTY_CLAIM_ROYALTIES_IDENT {
+                self.add_claim(*address)
+            }
+        }
+        Ok(())
+    }
+
+    fn post_visit(&mut self) -> Result<(), InstructionVisitorError> {
+        self.instruction_index += 1;
+        Ok(())
+    }
+}
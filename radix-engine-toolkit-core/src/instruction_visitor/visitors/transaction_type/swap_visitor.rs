@@ -0,0 +1,261 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use radix_engine::system::system_modules::execution_trace::*;
+use scrypto::prelude::*;
+use transaction::prelude::*;
+
+use crate::instruction_visitor::core::error::InstructionVisitorError;
+use crate::instruction_visitor::core::traits::InstructionVisitor;
+use crate::sbor::indexed_manifest_value::IndexedManifestValue;
+
+/// The decentralized exchanges whose swaps the [`SwapVisitor`] classifies. The toolkit doesn't know
+/// of any exchanges by itself, they're registered by the callers of the execution analysis.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DexRegistry {
+    venues: Vec<DexVenue>,
+}
+
+impl DexRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(mut self, venue: DexVenue) -> Self {
+        self.venues.push(venue);
+        self
+    }
+
+    pub fn venues(&self) -> &[DexVenue] {
+        &self.venues
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.venues.is_empty()
+    }
+
+    /// The venue that the method of the component belongs to if it's a registered swap method.
+    pub fn venue(&self, address: &GlobalAddress, method_name: &str) -> Option<&DexVenue> {
+        self.venues.iter().find(|venue| {
+            venue
+                .swap_methods
+                .iter()
+                .any(|method| method == method_name)
+                && venue
+                    .component_addresses
+                    .iter()
+                    .any(|component_address| component_address.as_node_id() == address.as_node_id())
+        })
+    }
+}
+
+/// A decentralized exchange: the components it's made up of (e.g. its pools or its router) and the
+/// methods of these components that perform swaps.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DexVenue {
+    pub name: String,
+    pub component_addresses: Vec<ComponentAddress>,
+    pub swap_methods: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Swap {
+    /// The index of the instruction that called the swap method.
+    pub instruction_index: usize,
+    /// The name of the venue that the swap was made on.
+    pub venue: String,
+    pub component_address: ComponentAddress,
+    pub method_name: String,
+    pub input_resource: ResourceAddress,
+    pub input_amount: Decimal,
+    pub output_resource: ResourceAddress,
+    /// The amount of the output resource returned by the swap in the preview.
+    pub output_amount: Decimal,
+    /// The minimum amount of the output resource guaranteed by the first worktop assertion on it
+    /// following the swap, if any.
+    pub minimum_output_amount: Option<Decimal>,
+}
+
+/// A visitor that classifies the calls made to the swap methods of the registered exchanges. A call
+/// is classified as a swap when it's passed a single bucket of a fungible resource and returns a
+/// single fungible resource other than it to the worktop, both of which are read from the worktop
+/// changes in the execution trace. Like the royalty claims visitor, other instructions in the
+/// manifest don't disqualify it.
+pub struct SwapVisitor<'r> {
+    /// The execution trace of the transaction
+    execution_trace: &'r TransactionExecutionTrace,
+
+    /// The exchanges whose swap methods are classified.
+    dex_registry: &'r DexRegistry,
+
+    /// The index of the current instruction
+    instruction_index: usize,
+
+    /// The fungible resources and amounts taken from the worktop into each of the buckets. Buckets
+    /// of non-fungibles are not tracked as they're not swap inputs.
+    buckets: IndexMap<ManifestBucket, (ResourceAddress, Decimal)>,
+
+    /// The id of the next bucket to be created by the manifest.
+    next_bucket_id: u32,
+
+    /// The swaps made in the transaction in the order they appear in the manifest.
+    swaps: Vec<Swap>,
+}
+
+impl<'r> SwapVisitor<'r> {
+    pub fn new(
+        execution_trace: &'r TransactionExecutionTrace,
+        dex_registry: &'r DexRegistry,
+    ) -> Self {
+        Self {
+            execution_trace,
+            dex_registry,
+            instruction_index: Default::default(),
+            buckets: Default::default(),
+            next_bucket_id: Default::default(),
+            swaps: Default::default(),
+        }
+    }
+
+    pub fn output(self) -> Option<Vec<Swap>> {
+        if self.swaps.is_empty() {
+            None
+        } else {
+            Some(self.swaps)
+        }
+    }
+
+    fn worktop_changes(&self) -> impl Iterator<Item = &WorktopChange> {
+        self.execution_trace
+            .worktop_changes()
+            .get(&self.instruction_index)
+            .into_iter()
+            .flatten()
+    }
+
+    fn add_bucket(&mut self) {
+        let bucket = ManifestBucket(self.next_bucket_id);
+        self.next_bucket_id += 1;
+
+        let taken = self
+            .worktop_changes()
+            .find_map(|worktop_change| match worktop_change {
+                WorktopChange::Take(ResourceSpecifier::Amount(resource_address, amount)) => {
+                    Some((*resource_address, *amount))
+                }
+                _ => None,
+            });
+        if let Some(taken) = taken {
+            self.buckets.insert(bucket, taken);
+        }
+    }
+}
+
+impl<'r> InstructionVisitor for SwapVisitor<'r> {
+    fn visit_take_all_from_worktop(
+        &mut self,
+        _: &ResourceAddress,
+    ) -> Result<(), InstructionVisitorError> {
+        self.add_bucket();
+        Ok(())
+    }
+
+    fn visit_take_from_worktop(
+        &mut self,
+        _: &ResourceAddress,
+        _: &Decimal,
+    ) -> Result<(), InstructionVisitorError> {
+        self.add_bucket();
+        Ok(())
+    }
+
+    fn visit_take_non_fungibles_from_worktop(
+        &mut self,
+        _: &ResourceAddress,
+        _: &[NonFungibleLocalId],
+    ) -> Result<(), InstructionVisitorError> {
+        self.add_bucket();
+        Ok(())
+    }
+
+    fn visit_assert_worktop_contains(
+        &mut self,
+        resource_address: &ResourceAddress,
+        amount: &Decimal,
+    ) -> Result<(), InstructionVisitorError> {
+        if let Some(swap) = self.swaps.iter_mut().rev().find(|swap| {
+            swap.output_resource == *resource_address && swap.minimum_output_amount.is_none()
+        }) {
+            swap.minimum_output_amount = Some(*amount)
+        }
+        Ok(())
+    }
+
+    fn visit_call_method(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        args: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        let DynamicGlobalAddress::Static(address) = address else {
+            return Ok(());
+        };
+        let Some(venue) = self.dex_registry.venue(address, method_name) else {
+            return Ok(());
+        };
+
+        let args = IndexedManifestValue::from_manifest_value(args);
+        let [bucket] = args.buckets().as_slice() else {
+            return Ok(());
+        };
+        let Some((input_resource, input_amount)) = self.buckets.get(bucket).copied() else {
+            return Ok(());
+        };
+
+        let outputs = self
+            .worktop_changes()
+            .filter_map(|worktop_change| match worktop_change {
+                WorktopChange::Put(ResourceSpecifier::Amount(resource_address, amount))
+                    if *resource_address != input_resource =>
+                {
+                    Some((*resource_address, *amount))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let [(output_resource, output_amount)] = outputs.as_slice() else {
+            return Ok(());
+        };
+
+        self.swaps.push(Swap {
+            instruction_index: self.instruction_index,
+            venue: venue.name.clone(),
+            component_address: ComponentAddress::new_or_panic(address.as_node_id().0),
+            method_name: method_name.to_owned(),
+            input_resource,
+            input_amount,
+            output_resource: *output_resource,
+            output_amount: *output_amount,
+            minimum_output_amount: None,
+        });
+        Ok(())
+    }
+
+    fn post_visit(&mut self) -> Result<(), InstructionVisitorError> {
+        self.instruction_index += 1;
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use scrypto::prelude::*;
+
+use crate::instruction_visitor::core::error::InstructionVisitorError;
+use crate::instruction_visitor::core::traits::InstructionVisitor;
+
+/// A guarantee on the contents of the worktop made through one of the `ASSERT_WORKTOP_CONTAINS*`
+/// instructions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WorktopGuarantee {
+    pub instruction_index: usize,
+    pub resource_address: ResourceAddress,
+    pub guarantee: GuaranteeKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GuaranteeKind {
+    /// The worktop contains at least this amount of the resource.
+    Amount(Decimal),
+    /// The worktop contains a non-zero amount of the resource.
+    Any,
+    /// The worktop contains all of the non-fungibles.
+    NonFungibles(Vec<NonFungibleLocalId>),
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct WorktopGuaranteesVisitor {
+    guarantees: Vec<WorktopGuarantee>,
+    instruction_index: usize,
+}
+
+impl WorktopGuaranteesVisitor {
+    pub fn output(self) -> Vec<WorktopGuarantee> {
+        self.guarantees
+    }
+
+    fn add_guarantee(&mut self, resource_address: &ResourceAddress, guarantee: GuaranteeKind) {
+        self.guarantees.push(WorktopGuarantee {
+            instruction_index: self.instruction_index,
+            resource_address: *resource_address,
+            guarantee,
+        })
+    }
+}
+
+impl InstructionVisitor for WorktopGuaranteesVisitor {
+    fn visit_assert_worktop_contains(
+        &mut self,
+        resource_address: &ResourceAddress,
+        amount: &Decimal,
+    ) -> Result<(), InstructionVisitorError> {
+        self.add_guarantee(resource_address, GuaranteeKind::Amount(*amount));
+        Ok(())
+    }
+
+    fn visit_assert_worktop_contains_any(
+        &mut self,
+        resource_address: &ResourceAddress,
+    ) -> Result<(), InstructionVisitorError> {
+        self.add_guarantee(resource_address, GuaranteeKind::Any);
+        Ok(())
+    }
+
+    fn visit_assert_worktop_contains_non_fungibles(
+        &mut self,
+        resource_address: &ResourceAddress,
+        ids: &[NonFungibleLocalId],
+    ) -> Result<(), InstructionVisitorError> {
+        self.add_guarantee(resource_address, GuaranteeKind::NonFungibles(ids.to_vec()));
+        Ok(())
+    }
+
+    fn post_visit(&mut self) -> Result<(), InstructionVisitorError> {
+        self.instruction_index += 1;
+        Ok(())
+    }
+}
@@ -0,0 +1,412 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_interface::blueprints::account::*;
+use scrypto::prelude::*;
+use transaction::prelude::*;
+use transaction::validation::ManifestIdAllocator;
+
+use crate::instruction_visitor::core::error::InstructionVisitorError;
+use crate::instruction_visitor::core::traits::InstructionVisitor;
+use crate::sbor::indexed_manifest_value::IndexedManifestValue;
+use crate::utils::{is_account, to_manifest_type};
+
+/// A visitor that simulates the contents of the worktop and of the buckets through the manifest to
+/// predict the deposits made into accounts without a preview receipt.
+///
+/// Resources withdrawn from accounts and taken from the worktop have amounts that are statically
+/// known. Once a call is made to a component other than an account, anything could have been put
+/// on the worktop and the amounts of all resources on the worktop become unknown, until the entire
+/// worktop is deposited. Each deposit is thus classified as either exact or unknown.
+pub struct WorktopSimulatorVisitor {
+    /// The amount of each of the resources on the worktop.
+    worktop: IndexMap<ResourceAddress, SimulatedAmount>,
+    /// Whether resources of unknown amounts could have been put on the worktop, e.g. returned by a
+    /// call to a component. Resources that are not in the worktop map have unknown amounts when
+    /// this is set.
+    has_unknown_inflows: bool,
+    /// The resource and amount of each of the buckets.
+    bucket_tracker: HashMap<ManifestBucket, (ResourceAddress, SimulatedAmount)>,
+    deposits: IndexMap<ComponentAddress, Vec<SimulatedDeposit>>,
+    id_allocator: ManifestIdAllocator,
+    instruction_index: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SimulatedAmount {
+    /// The amount is statically derivable from the manifest. Non-fungible amounts are the number of
+    /// non-fungibles.
+    Exact(Decimal),
+    Unknown,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimulatedDeposit {
+    pub instruction_index: usize,
+    /// The resource deposited, [`None`] for the resources of unknown addresses and amounts which
+    /// could have been on the worktop when it was deposited in its entirety.
+    pub resource_address: Option<ResourceAddress>,
+    pub amount: SimulatedAmount,
+}
+
+impl SimulatedAmount {
+    fn checked_add(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Exact(a), Self::Exact(b)) => a.checked_add(b).map_or(Self::Unknown, Self::Exact),
+            _ => Self::Unknown,
+        }
+    }
+
+    fn saturating_sub(self, amount: Decimal) -> Self {
+        match self {
+            Self::Exact(a) => Self::Exact(
+                a.checked_sub(amount)
+                    .unwrap_or(Decimal::ZERO)
+                    .max(Decimal::ZERO),
+            ),
+            Self::Unknown => Self::Unknown,
+        }
+    }
+}
+
+impl Default for WorktopSimulatorVisitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WorktopSimulatorVisitor {
+    pub fn new() -> Self {
+        Self {
+            worktop: Default::default(),
+            has_unknown_inflows: Default::default(),
+            bucket_tracker: Default::default(),
+            deposits: Default::default(),
+            id_allocator: Default::default(),
+            instruction_index: Default::default(),
+        }
+    }
+
+    pub fn output(self) -> IndexMap<ComponentAddress, Vec<SimulatedDeposit>> {
+        self.deposits
+    }
+
+    fn unaccounted_amount(&self) -> SimulatedAmount {
+        if self.has_unknown_inflows {
+            SimulatedAmount::Unknown
+        } else {
+            SimulatedAmount::Exact(Decimal::ZERO)
+        }
+    }
+
+    fn put_on_worktop(&mut self, resource_address: ResourceAddress, amount: SimulatedAmount) {
+        let unaccounted_amount = self.unaccounted_amount();
+        let worktop_amount = self
+            .worktop
+            .entry(resource_address)
+            .or_insert(unaccounted_amount);
+        *worktop_amount = worktop_amount.checked_add(amount);
+    }
+
+    /// Called when anything could have been put on the worktop.
+    fn add_unknown_inflows(&mut self) {
+        self.has_unknown_inflows = true;
+        self.worktop
+            .values_mut()
+            .for_each(|amount| *amount = SimulatedAmount::Unknown);
+    }
+
+    fn add_bucket(&mut self, resource_address: ResourceAddress, amount: SimulatedAmount) {
+        let bucket = self.id_allocator.new_bucket_id();
+        self.bucket_tracker
+            .insert(bucket, (resource_address, amount));
+    }
+
+    fn add_deposit(
+        &mut self,
+        account: ComponentAddress,
+        resource_address: Option<ResourceAddress>,
+        amount: SimulatedAmount,
+    ) {
+        self.deposits
+            .entry(account)
+            .or_default()
+            .push(SimulatedDeposit {
+                instruction_index: self.instruction_index,
+                resource_address,
+                amount,
+            })
+    }
+
+    fn deposit_buckets(
+        &mut self,
+        account: ComponentAddress,
+        indexed_manifest_value: &IndexedManifestValue,
+        is_refundable: bool,
+    ) {
+        for bucket in indexed_manifest_value.buckets() {
+            if let Some((resource_address, amount)) = self.bucket_tracker.remove(bucket) {
+                if is_refundable {
+                    // The deposit is either made in full or returned to the worktop.
+                    self.add_deposit(account, Some(resource_address), SimulatedAmount::Unknown);
+                    self.worktop
+                        .insert(resource_address, SimulatedAmount::Unknown);
+                } else {
+                    self.add_deposit(account, Some(resource_address), amount);
+                }
+            }
+        }
+    }
+
+    fn deposit_entire_worktop(&mut self, account: ComponentAddress, is_refundable: bool) {
+        for (resource_address, amount) in std::mem::take(&mut self.worktop) {
+            if amount == SimulatedAmount::Exact(Decimal::ZERO) {
+                continue;
+            }
+            let amount = if is_refundable {
+                SimulatedAmount::Unknown
+            } else {
+                amount
+            };
+            self.add_deposit(account, Some(resource_address), amount);
+        }
+        if self.has_unknown_inflows {
+            self.add_deposit(account, None, SimulatedAmount::Unknown);
+        }
+
+        self.has_unknown_inflows = false;
+        if is_refundable {
+            self.add_unknown_inflows();
+        }
+    }
+
+    /// Handles a call to anything other than an account. The buckets passed leave the manifest and
+    /// anything could be returned to the worktop.
+    fn handle_third_party_call(&mut self, args: &ManifestValue) {
+        let indexed_manifest_value = IndexedManifestValue::from_manifest_value(args);
+        for bucket in indexed_manifest_value.buckets() {
+            self.bucket_tracker.remove(bucket);
+        }
+        if indexed_manifest_value
+            .expressions()
+            .contains(&ManifestExpression::EntireWorktop)
+        {
+            self.worktop.clear();
+            self.has_unknown_inflows = false;
+        }
+        self.add_unknown_inflows();
+    }
+
+    fn handle_account_method(
+        &mut self,
+        account: ComponentAddress,
+        method_name: &str,
+        args: &ManifestValue,
+    ) {
+        let ids_amount = |ids: &IndexSet<NonFungibleLocalId>| {
+            SimulatedAmount::Exact(Decimal::from(ids.len() as u64))
+        };
+        let indexed_manifest_value = IndexedManifestValue::from_manifest_value(args);
+        let is_entire_worktop = indexed_manifest_value
+            .expressions()
+            .contains(&ManifestExpression::EntireWorktop);
+
+        match method_name {
+            ACCOUNT_WITHDRAW_IDENT => {
+                if let Some(AccountWithdrawInput {
+                    resource_address,
+                    amount,
+                }) = to_manifest_type(args)
+                {
+                    self.put_on_worktop(resource_address, SimulatedAmount::Exact(amount))
+                }
+            }
+            ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                if let Some(AccountWithdrawNonFungiblesInput {
+                    resource_address,
+                    ids,
+                }) = to_manifest_type(args)
+                {
+                    self.put_on_worktop(resource_address, ids_amount(&ids))
+                }
+            }
+            ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => {
+                if let Some(AccountLockFeeAndWithdrawInput {
+                    resource_address,
+                    amount,
+                    ..
+                }) = to_manifest_type(args)
+                {
+                    self.put_on_worktop(resource_address, SimulatedAmount::Exact(amount))
+                }
+            }
+            ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                if let Some(AccountLockFeeAndWithdrawNonFungiblesInput {
+                    resource_address,
+                    ids,
+                    ..
+                }) = to_manifest_type(args)
+                {
+                    self.put_on_worktop(resource_address, ids_amount(&ids))
+                }
+            }
+            ACCOUNT_DEPOSIT_IDENT | ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT => {
+                self.deposit_buckets(account, &indexed_manifest_value, false)
+            }
+            ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT => {
+                self.deposit_buckets(account, &indexed_manifest_value, true)
+            }
+            ACCOUNT_DEPOSIT_BATCH_IDENT | ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT => {
+                self.deposit_buckets(account, &indexed_manifest_value, false);
+                if is_entire_worktop {
+                    self.deposit_entire_worktop(account, false)
+                }
+            }
+            ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT => {
+                self.deposit_buckets(account, &indexed_manifest_value, true);
+                if is_entire_worktop {
+                    self.deposit_entire_worktop(account, true)
+                }
+            }
+            // Methods that don't return buckets.
+            ACCOUNT_LOCK_FEE_IDENT
+            | ACCOUNT_LOCK_CONTINGENT_FEE_IDENT
+            | ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT
+            | ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT
+            | ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT
+            | ACCOUNT_SET_RESOURCE_PREFERENCE_IDENT
+            | ACCOUNT_REMOVE_RESOURCE_PREFERENCE_IDENT
+            | ACCOUNT_BURN_IDENT
+            | ACCOUNT_BURN_NON_FUNGIBLES_IDENT => {}
+            _ => self.handle_third_party_call(args),
+        }
+    }
+}
+
+impl InstructionVisitor for WorktopSimulatorVisitor {
+    fn post_visit(&mut self) -> Result<(), InstructionVisitorError> {
+        self.instruction_index += 1;
+        Ok(())
+    }
+
+    fn visit_take_all_from_worktop(
+        &mut self,
+        resource_address: &ResourceAddress,
+    ) -> Result<(), InstructionVisitorError> {
+        let amount = self
+            .worktop
+            .remove(resource_address)
+            .unwrap_or(self.unaccounted_amount());
+        if self.has_unknown_inflows {
+            // The resource is no longer on the worktop, so its amount is known to be zero.
+            self.worktop
+                .insert(*resource_address, SimulatedAmount::Exact(Decimal::ZERO));
+        }
+        self.add_bucket(*resource_address, amount);
+        Ok(())
+    }
+
+    fn visit_take_from_worktop(
+        &mut self,
+        resource_address: &ResourceAddress,
+        amount: &Decimal,
+    ) -> Result<(), InstructionVisitorError> {
+        let unaccounted_amount = self.unaccounted_amount();
+        let worktop_amount = self
+            .worktop
+            .entry(*resource_address)
+            .or_insert(unaccounted_amount);
+        *worktop_amount = worktop_amount.saturating_sub(*amount);
+        self.add_bucket(*resource_address, SimulatedAmount::Exact(*amount));
+        Ok(())
+    }
+
+    fn visit_take_non_fungibles_from_worktop(
+        &mut self,
+        resource_address: &ResourceAddress,
+        ids: &[NonFungibleLocalId],
+    ) -> Result<(), InstructionVisitorError> {
+        self.visit_take_from_worktop(resource_address, &Decimal::from(ids.len() as u64))
+    }
+
+    fn visit_return_to_worktop(
+        &mut self,
+        bucket_id: &ManifestBucket,
+    ) -> Result<(), InstructionVisitorError> {
+        if let Some((resource_address, amount)) = self.bucket_tracker.remove(bucket_id) {
+            self.put_on_worktop(resource_address, amount)
+        }
+        Ok(())
+    }
+
+    fn visit_burn_resource(
+        &mut self,
+        bucket_id: &ManifestBucket,
+    ) -> Result<(), InstructionVisitorError> {
+        self.bucket_tracker.remove(bucket_id);
+        Ok(())
+    }
+
+    fn visit_call_function(
+        &mut self,
+        _: &DynamicPackageAddress,
+        _: &str,
+        _: &str,
+        args: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        self.handle_third_party_call(args);
+        Ok(())
+    }
+
+    fn visit_call_method(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        args: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        match address {
+            DynamicGlobalAddress::Static(static_address) if is_account(address) => {
+                // This never panics. We have already checked that this is an account when we
+                // called `is_account`.
+                let account = ComponentAddress::new_or_panic(static_address.as_node_id().0);
+                self.handle_account_method(account, method_name, args)
+            }
+            _ => self.handle_third_party_call(args),
+        }
+        Ok(())
+    }
+
+    fn visit_call_royalty_method(
+        &mut self,
+        _: &DynamicGlobalAddress,
+        _: &str,
+        args: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        self.handle_third_party_call(args);
+        Ok(())
+    }
+
+    fn visit_call_direct_vault_method(
+        &mut self,
+        _: &InternalAddress,
+        _: &str,
+        args: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        self.handle_third_party_call(args);
+        Ok(())
+    }
+}
@@ -15,15 +15,34 @@
 // specific language governing permissions and limitations
 // under the License.
 
-#[cfg(not(any(feature = "moka", feature = "lru")))]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "no_std")))]
+compile_error!("Either feature `std` or `no_std` must be enabled for this crate.");
+#[cfg(all(feature = "std", not(any(feature = "moka", feature = "lru"))))]
 compile_error!("Either feature `moka` or `lru` must be enabled for this crate.");
 #[cfg(all(feature = "moka", feature = "lru"))]
 compile_error!("Feature `moka` and `lru` can't be enabled at the same time.");
 
+extern crate alloc;
+
+#[cfg(feature = "std")]
 pub mod functions;
+#[cfg(feature = "fuzz")]
+pub mod fuzz;
+#[cfg(feature = "std")]
 pub mod instruction_visitor;
+#[cfg(feature = "std")]
 pub mod models;
+#[cfg(feature = "std")]
 pub mod sbor;
+#[cfg(feature = "std")]
 pub mod schema_visitor;
+#[cfg(feature = "std")]
 pub mod statics;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(feature = "std")]
 pub mod utils;
+
+pub mod signer;
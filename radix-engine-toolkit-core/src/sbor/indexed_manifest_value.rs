@@ -22,7 +22,7 @@ use sbor::rust::cell::Ref;
 use sbor::rust::prelude::*;
 use sbor::traversal::*;
 use sbor::*;
-use transaction::prelude::{ManifestAddress, ManifestBucket, ManifestExpression};
+use transaction::prelude::{ManifestAddress, ManifestBucket, ManifestExpression, ManifestProof};
 
 use crate::models::node_id::TypedNodeId;
 
@@ -34,6 +34,7 @@ pub struct IndexedManifestValue {
     static_addresses: Vec<NodeId>,
     named_addresses: Vec<u32>,
     buckets: Vec<ManifestBucket>,
+    proofs: Vec<ManifestProof>,
     expressions: Vec<ManifestExpression>,
 }
 
@@ -48,6 +49,7 @@ impl IndexedManifestValue {
         let mut static_addresses = Vec::new();
         let mut named_addresses = Vec::new();
         let mut buckets = Vec::new();
+        let mut proofs = Vec::new();
         let mut expressions = Vec::new();
         loop {
             let event = traverser.next_event();
@@ -65,8 +67,8 @@ impl IndexedManifestValue {
                             ManifestCustomValue::Expression(expression) => {
                                 expressions.push(expression)
                             }
-                            ManifestCustomValue::Proof(_)
-                            | ManifestCustomValue::Blob(_)
+                            ManifestCustomValue::Proof(proof) => proofs.push(proof),
+                            ManifestCustomValue::Blob(_)
                             | ManifestCustomValue::Decimal(_)
                             | ManifestCustomValue::PreciseDecimal(_)
                             | ManifestCustomValue::NonFungibleLocalId(_)
@@ -89,6 +91,7 @@ impl IndexedManifestValue {
             static_addresses,
             named_addresses,
             buckets,
+            proofs,
             expressions,
             manifest_value: RefCell::new(None),
         })
@@ -163,4 +166,8 @@ impl IndexedManifestValue {
     pub fn buckets(&self) -> &Vec<ManifestBucket> {
         &self.buckets
     }
+
+    pub fn proofs(&self) -> &Vec<ManifestProof> {
+        &self.proofs
+    }
 }
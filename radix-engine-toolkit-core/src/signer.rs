@@ -0,0 +1,563 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The subset of the toolkit that is available in `no_std + alloc` environments such as the
+//! firmware of hardware wallets: hashing of intents, decoding of compiled intents into summaries
+//! of their instructions that can be displayed to the signer, and the assembly of signed intents
+//! and notarized transactions from signatures produced by the signer.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use radix_engine_common::data::manifest::converter::{
+    to_decimal, to_non_fungible_local_id, to_precise_decimal,
+};
+use radix_engine_common::prelude::*;
+use sbor::*;
+use transaction::errors::*;
+use transaction::model::*;
+
+pub fn intent_hash(compiled_intent: &[u8]) -> Result<IntentHash, SignerError> {
+    let intent = IntentV1::from_payload_bytes(compiled_intent)?;
+    Ok(intent.prepare()?.intent_hash())
+}
+
+pub fn signed_intent_hash(signed_intent: &SignedIntentV1) -> Result<SignedIntentHash, SignerError> {
+    Ok(signed_intent.prepare()?.signed_intent_hash())
+}
+
+/// Decodes the compiled intent and summarizes each of its instructions in a form that can be
+/// displayed to the signer. Addresses are encoded for the given network.
+pub fn instruction_summaries(
+    compiled_intent: &[u8],
+    network_definition: &NetworkDefinition,
+) -> Result<Vec<InstructionSummary>, SignerError> {
+    let intent = IntentV1::from_payload_bytes(compiled_intent)?;
+    let encoder = AddressBech32Encoder::new(network_definition);
+    Ok(intent
+        .instructions
+        .0
+        .iter()
+        .map(|instruction| InstructionSummary::new(instruction, &encoder))
+        .collect())
+}
+
+/// Decodes the compiled intent into the ordered display records that a hardware wallet shows for
+/// its instructions, one record per instruction. The fields of an instruction are followed by the
+/// arguments of invocations as fields named `argument 1`, `argument 2`, and so on. Field values
+/// longer than `max_field_length` characters, such as addresses, are split into chunks of at most
+/// that length so that each chunk fits on a single line of the screen.
+pub fn display_records(
+    compiled_intent: &[u8],
+    network_definition: &NetworkDefinition,
+    max_field_length: usize,
+) -> Result<Vec<DisplayRecord>, SignerError> {
+    let summaries = instruction_summaries(compiled_intent, network_definition)?;
+    let instruction_count = summaries.len();
+    Ok(summaries
+        .into_iter()
+        .enumerate()
+        .map(|(index, summary)| DisplayRecord {
+            title: format!("{}/{} {}", index + 1, instruction_count, summary.title),
+            fields: summary
+                .fields
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .chain(
+                    summary
+                        .arguments
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, argument)| (format!("argument {}", index + 1), argument)),
+                )
+                .map(|(name, value)| DisplayField {
+                    name,
+                    chunks: chunk(&value, max_field_length),
+                })
+                .collect(),
+        })
+        .collect())
+}
+
+/// Computes the summary hash of the compiled intent: a digest of what the signer is shown rather
+/// than of the raw payload, so that companion software can display the same digest as a hardware
+/// wallet which can't render the whole transaction, for the user to compare manually.
+///
+/// The hash is the Blake2b-256 hash of the following normalized encoding, where all integers are
+/// little-endian and every string is its UTF-8 bytes prefixed by their length as a `u32`:
+///
+/// * The string `"RET summary hash v2"` as a domain separator.
+/// * The network id as a `u8`, since the summaries contain network specific addresses.
+/// * The number of instructions as a `u32`, followed by the summary of every instruction in order:
+///   its title, the number of fields as a `u32`, the name and value of every field, the number of
+///   arguments as a `u32`, and every argument.
+///
+/// The arguments of invocations are part of the hash, so intents that call the same methods with
+/// different amounts, non-fungible ids, or addresses have different summary hashes.
+///
+/// Field values are hashed in full before any chunking for display, so the summary hash does not
+/// depend on the screen size of the wallet.
+pub fn summary_hash(
+    compiled_intent: &[u8],
+    network_definition: &NetworkDefinition,
+) -> Result<Hash, SignerError> {
+    let summaries = instruction_summaries(compiled_intent, network_definition)?;
+
+    fn push_str(bytes: &mut Vec<u8>, string: &str) {
+        bytes.extend((string.len() as u32).to_le_bytes());
+        bytes.extend(string.as_bytes());
+    }
+
+    let mut bytes = Vec::new();
+    push_str(&mut bytes, SUMMARY_HASH_DOMAIN_SEPARATOR);
+    bytes.push(network_definition.id);
+    bytes.extend((summaries.len() as u32).to_le_bytes());
+    for summary in summaries.iter() {
+        push_str(&mut bytes, summary.title);
+        bytes.extend((summary.fields.len() as u32).to_le_bytes());
+        for (name, value) in summary.fields.iter() {
+            push_str(&mut bytes, name);
+            push_str(&mut bytes, value);
+        }
+        bytes.extend((summary.arguments.len() as u32).to_le_bytes());
+        for argument in summary.arguments.iter() {
+            push_str(&mut bytes, argument);
+        }
+    }
+
+    Ok(hash(bytes))
+}
+
+const SUMMARY_HASH_DOMAIN_SEPARATOR: &str = "RET summary hash v2";
+
+/// Assembles the signed intent from the intent and the signatures of the signers over its hash
+/// and compiles it.
+pub fn assemble_signed_intent(
+    intent: IntentV1,
+    intent_signatures: Vec<SignatureWithPublicKeyV1>,
+) -> Result<Vec<u8>, SignerError> {
+    let signed_intent = SignedIntentV1 {
+        intent,
+        intent_signatures: IntentSignaturesV1 {
+            signatures: intent_signatures
+                .into_iter()
+                .map(IntentSignatureV1)
+                .collect(),
+        },
+    };
+    Ok(signed_intent.to_payload_bytes()?)
+}
+
+/// Assembles the notarized transaction from the signed intent and the signature of the notary over
+/// its hash and compiles it.
+pub fn assemble_notarized_transaction(
+    signed_intent: SignedIntentV1,
+    notary_signature: SignatureV1,
+) -> Result<Vec<u8>, SignerError> {
+    let notarized_transaction = NotarizedTransactionV1 {
+        signed_intent,
+        notary_signature: NotarySignatureV1(notary_signature),
+    };
+    Ok(notarized_transaction.to_payload_bytes()?)
+}
+
+/// A short title of an instruction, its displayable fields, and the arguments of invocations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstructionSummary {
+    pub title: &'static str,
+    pub fields: Vec<(&'static str, String)>,
+    /// The arguments of the invocation in their normalized form, see [`format_argument`]. Empty
+    /// for all instructions other than invocations.
+    pub arguments: Vec<String>,
+}
+
+impl InstructionSummary {
+    pub fn new(instruction: &InstructionV1, encoder: &AddressBech32Encoder) -> Self {
+        let address = |node_id: &NodeId| {
+            encoder
+                .encode(node_id.as_bytes())
+                .unwrap_or_else(|_| format!("{:?}", node_id))
+        };
+        let dynamic_global_address = |address_: &DynamicGlobalAddress| match address_ {
+            DynamicGlobalAddress::Static(global_address) => address(global_address.as_node_id()),
+            DynamicGlobalAddress::Named(named) => format!("named address {}", named),
+        };
+        let dynamic_package_address = |address_: &DynamicPackageAddress| match address_ {
+            DynamicPackageAddress::Static(package_address) => address(package_address.as_node_id()),
+            DynamicPackageAddress::Named(named) => format!("named address {}", named),
+        };
+        let ids = |ids: &[NonFungibleLocalId]| {
+            ids.iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        let (title, fields) = match instruction {
+            InstructionV1::TakeAllFromWorktop { resource_address } => (
+                "Take All From Worktop",
+                vec![("resource", address(resource_address.as_node_id()))],
+            ),
+            InstructionV1::TakeFromWorktop {
+                resource_address,
+                amount,
+            } => (
+                "Take From Worktop",
+                vec![
+                    ("resource", address(resource_address.as_node_id())),
+                    ("amount", amount.to_string()),
+                ],
+            ),
+            InstructionV1::TakeNonFungiblesFromWorktop {
+                resource_address,
+                ids: local_ids,
+            } => (
+                "Take Non Fungibles From Worktop",
+                vec![
+                    ("resource", address(resource_address.as_node_id())),
+                    ("ids", ids(local_ids)),
+                ],
+            ),
+            InstructionV1::ReturnToWorktop { bucket_id } => (
+                "Return To Worktop",
+                vec![("bucket", bucket_id.0.to_string())],
+            ),
+            InstructionV1::AssertWorktopContainsAny { resource_address } => (
+                "Assert Worktop Contains Any",
+                vec![("resource", address(resource_address.as_node_id()))],
+            ),
+            InstructionV1::AssertWorktopContains {
+                resource_address,
+                amount,
+            } => (
+                "Assert Worktop Contains",
+                vec![
+                    ("resource", address(resource_address.as_node_id())),
+                    ("amount", amount.to_string()),
+                ],
+            ),
+            InstructionV1::AssertWorktopContainsNonFungibles {
+                resource_address,
+                ids: local_ids,
+            } => (
+                "Assert Worktop Contains Non Fungibles",
+                vec![
+                    ("resource", address(resource_address.as_node_id())),
+                    ("ids", ids(local_ids)),
+                ],
+            ),
+            InstructionV1::PopFromAuthZone {} => ("Pop From Auth Zone", vec![]),
+            InstructionV1::PushToAuthZone { proof_id } => {
+                ("Push To Auth Zone", vec![("proof", proof_id.0.to_string())])
+            }
+            InstructionV1::DropNamedProofs => ("Drop Named Proofs", vec![]),
+            InstructionV1::DropAuthZoneProofs => ("Drop Auth Zone Proofs", vec![]),
+            InstructionV1::DropAuthZoneSignatureProofs {} => {
+                ("Drop Auth Zone Signature Proofs", vec![])
+            }
+            InstructionV1::DropAuthZoneRegularProofs {} => {
+                ("Drop Auth Zone Regular Proofs", vec![])
+            }
+            InstructionV1::CreateProofFromAuthZoneOfAmount {
+                resource_address,
+                amount,
+            } => (
+                "Create Proof From Auth Zone",
+                vec![
+                    ("resource", address(resource_address.as_node_id())),
+                    ("amount", amount.to_string()),
+                ],
+            ),
+            InstructionV1::CreateProofFromAuthZoneOfNonFungibles {
+                resource_address,
+                ids: local_ids,
+            } => (
+                "Create Proof From Auth Zone",
+                vec![
+                    ("resource", address(resource_address.as_node_id())),
+                    ("ids", ids(local_ids)),
+                ],
+            ),
+            InstructionV1::CreateProofFromAuthZoneOfAll { resource_address } => (
+                "Create Proof From Auth Zone",
+                vec![("resource", address(resource_address.as_node_id()))],
+            ),
+            InstructionV1::CreateProofFromBucketOfAmount { bucket_id, amount } => (
+                "Create Proof From Bucket",
+                vec![
+                    ("bucket", bucket_id.0.to_string()),
+                    ("amount", amount.to_string()),
+                ],
+            ),
+            InstructionV1::CreateProofFromBucketOfNonFungibles {
+                bucket_id,
+                ids: local_ids,
+            } => (
+                "Create Proof From Bucket",
+                vec![("bucket", bucket_id.0.to_string()), ("ids", ids(local_ids))],
+            ),
+            InstructionV1::CreateProofFromBucketOfAll { bucket_id } => (
+                "Create Proof From Bucket",
+                vec![("bucket", bucket_id.0.to_string())],
+            ),
+            InstructionV1::BurnResource { bucket_id } => {
+                ("Burn Resource", vec![("bucket", bucket_id.0.to_string())])
+            }
+            InstructionV1::CloneProof { proof_id } => {
+                ("Clone Proof", vec![("proof", proof_id.0.to_string())])
+            }
+            InstructionV1::DropProof { proof_id } => {
+                ("Drop Proof", vec![("proof", proof_id.0.to_string())])
+            }
+            InstructionV1::CallFunction {
+                package_address,
+                blueprint_name,
+                function_name,
+                ..
+            } => (
+                "Call Function",
+                vec![
+                    ("package", dynamic_package_address(package_address)),
+                    ("blueprint", blueprint_name.clone()),
+                    ("function", function_name.clone()),
+                ],
+            ),
+            InstructionV1::CallMethod {
+                address: method_address,
+                method_name,
+                ..
+            }
+            | InstructionV1::CallRoyaltyMethod {
+                address: method_address,
+                method_name,
+                ..
+            }
+            | InstructionV1::CallMetadataMethod {
+                address: method_address,
+                method_name,
+                ..
+            }
+            | InstructionV1::CallRoleAssignmentMethod {
+                address: method_address,
+                method_name,
+                ..
+            } => (
+                "Call Method",
+                vec![
+                    ("address", dynamic_global_address(method_address)),
+                    ("method", method_name.clone()),
+                ],
+            ),
+            InstructionV1::CallDirectVaultMethod {
+                address: vault_address,
+                method_name,
+                ..
+            } => (
+                "Call Direct Vault Method",
+                vec![
+                    ("vault", address(vault_address.as_node_id())),
+                    ("method", method_name.clone()),
+                ],
+            ),
+            InstructionV1::DropAllProofs => ("Drop All Proofs", vec![]),
+            InstructionV1::AllocateGlobalAddress {
+                package_address,
+                blueprint_name,
+            } => (
+                "Allocate Global Address",
+                vec![
+                    ("package", address(package_address.as_node_id())),
+                    ("blueprint", blueprint_name.clone()),
+                ],
+            ),
+        };
+
+        let arguments = match instruction {
+            InstructionV1::CallFunction { args, .. }
+            | InstructionV1::CallMethod { args, .. }
+            | InstructionV1::CallRoyaltyMethod { args, .. }
+            | InstructionV1::CallMetadataMethod { args, .. }
+            | InstructionV1::CallRoleAssignmentMethod { args, .. }
+            | InstructionV1::CallDirectVaultMethod { args, .. } => match args {
+                ManifestValue::Tuple { fields } => fields
+                    .iter()
+                    .map(|argument| format_argument(argument, encoder))
+                    .collect(),
+                args => vec![format_argument(args, encoder)],
+            },
+            _ => vec![],
+        };
+
+        Self {
+            title,
+            fields,
+            arguments,
+        }
+    }
+}
+
+/// Formats the argument of an invocation into a normalized form that is independent of how the
+/// manifest was written: addresses are encoded for the network of the encoder, and decimals and
+/// non-fungible local ids are formatted in their canonical string representations. The syntax
+/// follows the one of manifest values, e.g., `Tuple(Address("resource_rdx1..."), Decimal("10"))`.
+pub fn format_argument(value: &ManifestValue, encoder: &AddressBech32Encoder) -> String {
+    let format_all = |values: &[ManifestValue]| {
+        values
+            .iter()
+            .map(|value| format_argument(value, encoder))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    match value {
+        ManifestValue::Bool { value } => value.to_string(),
+        ManifestValue::I8 { value } => format!("{}i8", value),
+        ManifestValue::I16 { value } => format!("{}i16", value),
+        ManifestValue::I32 { value } => format!("{}i32", value),
+        ManifestValue::I64 { value } => format!("{}i64", value),
+        ManifestValue::I128 { value } => format!("{}i128", value),
+        ManifestValue::U8 { value } => format!("{}u8", value),
+        ManifestValue::U16 { value } => format!("{}u16", value),
+        ManifestValue::U32 { value } => format!("{}u32", value),
+        ManifestValue::U64 { value } => format!("{}u64", value),
+        ManifestValue::U128 { value } => format!("{}u128", value),
+        ManifestValue::String { value } => format!("\"{}\"", value.escape_default()),
+        ManifestValue::Enum {
+            discriminator,
+            fields,
+        } => format!("Enum<{}u8>({})", discriminator, format_all(fields)),
+        ManifestValue::Array { elements, .. } => format!("Array({})", format_all(elements)),
+        ManifestValue::Tuple { fields } => format!("Tuple({})", format_all(fields)),
+        ManifestValue::Map { entries, .. } => format!(
+            "Map({})",
+            entries
+                .iter()
+                .map(|(key, value)| format!(
+                    "{} => {}",
+                    format_argument(key, encoder),
+                    format_argument(value, encoder)
+                ))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        ManifestValue::Custom { value } => match value {
+            ManifestCustomValue::Address(ManifestAddress::Static(node_id)) => format!(
+                "Address(\"{}\")",
+                encoder
+                    .encode(node_id.as_bytes())
+                    .unwrap_or_else(|_| hex_string(node_id.as_bytes()))
+            ),
+            ManifestCustomValue::Address(ManifestAddress::Named(named)) => {
+                format!("NamedAddress({}u32)", named)
+            }
+            ManifestCustomValue::Bucket(bucket) => format!("Bucket({}u32)", bucket.0),
+            ManifestCustomValue::Proof(proof) => format!("Proof({}u32)", proof.0),
+            ManifestCustomValue::AddressReservation(reservation) => {
+                format!("AddressReservation({}u32)", reservation.0)
+            }
+            ManifestCustomValue::Expression(ManifestExpression::EntireWorktop) => {
+                "Expression(\"ENTIRE_WORKTOP\")".to_string()
+            }
+            ManifestCustomValue::Expression(ManifestExpression::EntireAuthZone) => {
+                "Expression(\"ENTIRE_AUTH_ZONE\")".to_string()
+            }
+            ManifestCustomValue::Blob(blob) => format!("Blob(\"{}\")", hex_string(&blob.0)),
+            ManifestCustomValue::Decimal(value) => {
+                format!("Decimal(\"{}\")", to_decimal(value.clone()))
+            }
+            ManifestCustomValue::PreciseDecimal(value) => {
+                format!("PreciseDecimal(\"{}\")", to_precise_decimal(value.clone()))
+            }
+            ManifestCustomValue::NonFungibleLocalId(value) => format!(
+                "NonFungibleLocalId(\"{}\")",
+                to_non_fungible_local_id(value.clone())
+            ),
+        },
+    }
+}
+
+fn hex_string(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// A screen of a hardware wallet describing a single instruction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DisplayRecord {
+    pub title: String,
+    pub fields: Vec<DisplayField>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DisplayField {
+    pub name: String,
+    pub chunks: Vec<String>,
+}
+
+fn chunk(value: &str, max_length: usize) -> Vec<String> {
+    let max_length = max_length.max(1);
+    let characters = value.chars().collect::<Vec<_>>();
+    if characters.is_empty() {
+        return vec![String::new()];
+    }
+    characters
+        .chunks(max_length)
+        .map(|chunk| chunk.iter().collect())
+        .collect()
+}
+
+impl fmt::Display for InstructionSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.title)?;
+        for (index, (name, value)) in self.fields.iter().enumerate() {
+            let separator = if index == 0 { ": " } else { ", " };
+            write!(f, "{}{} {}", separator, name, value)?;
+        }
+        if !self.arguments.is_empty() {
+            let separator = if self.fields.is_empty() { ": " } else { ", " };
+            write!(f, "{}arguments ({})", separator, self.arguments.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignerError {
+    DecodeError(DecodeError),
+    EncodeError(EncodeError),
+    PrepareError(PrepareError),
+}
+
+impl From<DecodeError> for SignerError {
+    fn from(value: DecodeError) -> Self {
+        Self::DecodeError(value)
+    }
+}
+
+impl From<EncodeError> for SignerError {
+    fn from(value: EncodeError) -> Self {
+        Self::EncodeError(value)
+    }
+}
+
+impl From<PrepareError> for SignerError {
+    fn from(value: PrepareError) -> Self {
+        Self::PrepareError(value)
+    }
+}
@@ -21,7 +21,9 @@ use radix_engine::blueprints::package::*;
 use radix_engine::types::*;
 use scrypto::api::node_modules::auth::*;
 use scrypto::api::node_modules::royalty::*;
+use scrypto::blueprints::access_controller::*;
 use scrypto::blueprints::account::*;
+use scrypto::blueprints::consensus_manager::*;
 use scrypto::blueprints::identity::*;
 
 use crate::schema_visitor::core::traverser::traverse;
@@ -45,6 +47,16 @@ lazy_static! {
 
     pub static ref IDENTITY_METHODS_THAT_REQUIRE_AUTH: Vec<MethodKey> = identity_methods_that_require_auth();
 
+    // Consensus Manager Package
+    pub static ref VALIDATOR_BLUEPRINT_SCHEMA: BlueprintDefinitionInit = validator_blueprint_schema();
+
+    pub static ref VALIDATOR_METHODS_THAT_REQUIRE_AUTH: Vec<MethodKey> = validator_methods_that_require_auth();
+
+    // Access Controller Package
+    pub static ref ACCESS_CONTROLLER_BLUEPRINT_SCHEMA: BlueprintDefinitionInit = access_controller_blueprint_schema();
+
+    pub static ref ACCESS_CONTROLLER_METHOD_ROLES: Vec<(MethodKey, Vec<String>)> = access_controller_method_roles();
+
     // Modules Package
     pub static ref ROLE_ASSIGNMENT_BLUEPRINT_SCHEMA: BlueprintDefinitionInit = role_assignment_blueprint_schema();
     pub static ref METADATA_BLUEPRINT_SCHEMA: BlueprintDefinitionInit = metadata_blueprint_schema();
@@ -169,6 +181,52 @@ fn identity_methods_that_require_auth() -> Vec<MethodKey> {
     methods_that_require_auth(&IDENTITY_BLUEPRINT_SCHEMA)
 }
 
+fn validator_blueprint_schema() -> BlueprintDefinitionInit {
+    CONSENSUS_MANAGER_PACKAGE_DEFINITION
+        .blueprints
+        .get(VALIDATOR_BLUEPRINT)
+        .expect("Consensus manager package has no schema for the validator blueprint?")
+        .clone()
+}
+
+fn validator_methods_that_require_auth() -> Vec<MethodKey> {
+    methods_that_require_auth(&VALIDATOR_BLUEPRINT_SCHEMA)
+}
+
+fn access_controller_blueprint_schema() -> BlueprintDefinitionInit {
+    ACCESS_CONTROLLER_PACKAGE_DEFINITION
+        .blueprints
+        .get(ACCESS_CONTROLLER_BLUEPRINT)
+        .expect("Access controller package has no schema for the access controller blueprint?")
+        .clone()
+}
+
+fn access_controller_method_roles() -> Vec<(MethodKey, Vec<String>)> {
+    if let MethodAuthTemplate::StaticRoleDefinition(StaticRoleDefinition { ref methods, .. }) =
+        ACCESS_CONTROLLER_BLUEPRINT_SCHEMA.auth_config.method_auth
+    {
+        methods
+            .iter()
+            .filter_map(|(key, value)| {
+                if let MethodAccessibility::RoleProtected(role_list) = value {
+                    Some((
+                        key.clone(),
+                        role_list
+                            .list
+                            .iter()
+                            .map(|role_key| role_key.key.clone())
+                            .collect(),
+                    ))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    } else {
+        vec![]
+    }
+}
+
 fn role_assignment_blueprint_schema() -> BlueprintDefinitionInit {
     ROLE_ASSIGNMENT_PACKAGE_DEFINITION
         .blueprints
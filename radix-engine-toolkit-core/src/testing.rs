@@ -0,0 +1,104 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Utilities for the test suites of downstream SDKs: syntactically valid random addresses and
+//! non-fungible ids which can be used instead of hard-coded mainnet addresses. The generated
+//! addresses are valid encodings for their entity type and network but, being random, don't refer
+//! to entities which exist on any ledger.
+
+use crate::utils::network_definition_from_network_id;
+use scrypto::prelude::*;
+
+/// The length of the random string non-fungible local ids.
+const STRING_ID_LENGTH: usize = 16;
+
+/// The length of the random bytes non-fungible local ids.
+const BYTES_ID_LENGTH: usize = 16;
+
+const STRING_ID_CHARACTERS: &[u8] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789_";
+
+pub fn random_node_id(entity_type: EntityType) -> NodeId {
+    let mut bytes = random_bytes::<{ NodeId::LENGTH }>();
+    bytes[0] = entity_type as u8;
+    NodeId(bytes)
+}
+
+/// A random Bech32m encoded address of the given entity type on the given network.
+pub fn random_address(entity_type: EntityType, network_id: u8) -> String {
+    let network_definition = network_definition_from_network_id(network_id);
+    AddressBech32Encoder::new(&network_definition)
+        .encode(random_node_id(entity_type).as_bytes())
+        .expect("Addresses of all entity types can be encoded")
+}
+
+pub fn random_component_address() -> ComponentAddress {
+    ComponentAddress::new_or_panic(random_node_id(EntityType::GlobalGenericComponent).0)
+}
+
+/// A random address of a virtual Secp256k1 account, the kind of accounts created by wallets.
+pub fn random_account_address() -> ComponentAddress {
+    ComponentAddress::new_or_panic(random_node_id(EntityType::GlobalVirtualSecp256k1Account).0)
+}
+
+pub fn random_fungible_resource_address() -> ResourceAddress {
+    ResourceAddress::new_or_panic(random_node_id(EntityType::GlobalFungibleResourceManager).0)
+}
+
+pub fn random_non_fungible_resource_address() -> ResourceAddress {
+    ResourceAddress::new_or_panic(random_node_id(EntityType::GlobalNonFungibleResourceManager).0)
+}
+
+pub fn random_package_address() -> PackageAddress {
+    PackageAddress::new_or_panic(random_node_id(EntityType::GlobalPackage).0)
+}
+
+pub fn random_non_fungible_local_id(id_type: NonFungibleIdType) -> NonFungibleLocalId {
+    match id_type {
+        NonFungibleIdType::Integer => {
+            NonFungibleLocalId::integer(u64::from_le_bytes(random_bytes()))
+        }
+        NonFungibleIdType::String => {
+            let string = random_bytes::<STRING_ID_LENGTH>()
+                .iter()
+                .map(|byte| {
+                    STRING_ID_CHARACTERS[*byte as usize % STRING_ID_CHARACTERS.len()] as char
+                })
+                .collect::<String>();
+            NonFungibleLocalId::string(string).expect("The string id is valid")
+        }
+        NonFungibleIdType::Bytes => {
+            NonFungibleLocalId::bytes(random_bytes::<BYTES_ID_LENGTH>().to_vec())
+                .expect("The bytes id is valid")
+        }
+        NonFungibleIdType::RUID => NonFungibleLocalId::ruid(random_bytes()),
+    }
+}
+
+/// A random non-fungible of a random non-fungible resource.
+pub fn random_non_fungible_global_id(id_type: NonFungibleIdType) -> NonFungibleGlobalId {
+    NonFungibleGlobalId::new(
+        random_non_fungible_resource_address(),
+        random_non_fungible_local_id(id_type),
+    )
+}
+
+fn random_bytes<const N: usize>() -> [u8; N] {
+    let mut bytes = [0u8; N];
+    getrandom::getrandom(&mut bytes).expect("The system's source of randomness is available");
+    bytes
+}
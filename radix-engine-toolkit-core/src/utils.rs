@@ -17,7 +17,7 @@
 
 use radix_engine::system::system_substates::{KeyValueEntrySubstate, KeyValueEntrySubstateV1};
 use radix_engine::track::{BatchPartitionStateUpdate, NodeStateUpdates, PartitionStateUpdates};
-use radix_engine_common::prelude::NetworkDefinition;
+use radix_engine_common::prelude::{HrpSet, NetworkDefinition};
 use radix_engine_queries::typed_substate_layout::{
     to_typed_substate_key, to_typed_substate_value, NonFungibleResourceManagerDataEntryPayload,
     NonFungibleResourceManagerDataEntrySubstate, NonFungibleResourceManagerDataKeyPayload,
@@ -27,8 +27,13 @@ use radix_engine_queries::typed_substate_layout::{
 };
 use radix_engine_store_interface::interface::DatabaseUpdate;
 use regex::Regex;
-use sbor::{generate_full_schema_from_single_type, validate_payload_against_schema};
+use sbor::{
+    generate_full_schema_from_single_type, validate_payload_against_schema, LocalTypeId, Schema,
+    TypeKind,
+};
 use scrypto::{api::node_modules::metadata::MetadataValue, prelude::*};
+use std::collections::BTreeMap;
+use std::sync::RwLock;
 use transaction::model::IntentV1;
 use transaction::prelude::{DynamicGlobalAddress, TransactionManifestV1};
 
@@ -51,8 +56,169 @@ pub fn manifest_from_intent(intent: &IntentV1) -> TransactionManifestV1 {
     }
 }
 
+/// The network definitions registered at runtime through [`register_network_definition`].
+static REGISTERED_NETWORK_DEFINITIONS: RwLock<BTreeMap<u8, NetworkDefinition>> =
+    RwLock::new(BTreeMap::new());
+
+/// The ids of the networks known to the toolkit without any registration.
+pub const BUILT_IN_NETWORK_IDS: [u8; 15] = [
+    0x01, 0x02, 0x0A, 0x0B, 0x0C, 0x0D, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0xF0, 0xF1, 0xF2,
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NetworkRegistrationError {
+    /// The network id belongs to one of the built-in networks which can't be redefined.
+    NetworkIdIsBuiltIn(u8),
+    /// The HRP suffix is empty or contains characters other than lowercase ASCII letters, digits,
+    /// and underscores.
+    InvalidHrpSuffix(String),
+    /// The HRP suffix is already used by another network or overlaps with its HRP suffix such that
+    /// the addresses of the two networks can't be told apart.
+    HrpSuffixInUse { hrp_suffix: String, network_id: u8 },
+}
+
+/// Registers a custom network definition making it available to all of the address encoding and
+/// decoding paths of the toolkit. Registering a network id that has been registered before
+/// replaces its definition, the previous definition is returned.
+pub fn register_network_definition(
+    network_definition: NetworkDefinition,
+) -> Result<Option<NetworkDefinition>, NetworkRegistrationError> {
+    if BUILT_IN_NETWORK_IDS.contains(&network_definition.id) {
+        return Err(NetworkRegistrationError::NetworkIdIsBuiltIn(
+            network_definition.id,
+        ));
+    }
+
+    let hrp_suffix = &network_definition.hrp_suffix;
+    if hrp_suffix.is_empty()
+        || !hrp_suffix
+            .chars()
+            .all(|char| char.is_ascii_lowercase() || char.is_ascii_digit() || char == '_')
+    {
+        return Err(NetworkRegistrationError::InvalidHrpSuffix(
+            hrp_suffix.clone(),
+        ));
+    }
+
+    if let Some(network_id) =
+        network_id_with_overlapping_hrp_suffix(hrp_suffix, network_definition.id)
+    {
+        return Err(NetworkRegistrationError::HrpSuffixInUse {
+            hrp_suffix: hrp_suffix.clone(),
+            network_id,
+        });
+    }
+
+    Ok(REGISTERED_NETWORK_DEFINITIONS
+        .write()
+        .expect("Lock poisoned")
+        .insert(network_definition.id, network_definition))
+}
+
+/// Removes a network definition registered through [`register_network_definition`] returning it
+/// if it was registered.
+pub fn deregister_network_definition(network_id: u8) -> Option<NetworkDefinition> {
+    REGISTERED_NETWORK_DEFINITIONS
+        .write()
+        .expect("Lock poisoned")
+        .remove(&network_id)
+}
+
+/// Returns the definitions of all of the built-in and registered networks ordered by their id.
+pub fn network_definitions() -> Vec<NetworkDefinition> {
+    let registered = REGISTERED_NETWORK_DEFINITIONS
+        .read()
+        .expect("Lock poisoned")
+        .clone();
+
+    let mut network_definitions = BUILT_IN_NETWORK_IDS
+        .iter()
+        .filter_map(|network_id| built_in_network_definition(*network_id))
+        .chain(registered.into_values())
+        .collect::<Vec<_>>();
+    network_definitions.sort_by_key(|network_definition| network_definition.id);
+    network_definitions
+}
+
+/// Returns the HRP used for each of the entity types on the given network.
+pub fn hrp_table(network_definition: &NetworkDefinition) -> Vec<(EntityType, String)> {
+    let hrp_set = HrpSet::from(network_definition);
+    [
+        EntityType::GlobalPackage,
+        EntityType::GlobalConsensusManager,
+        EntityType::GlobalValidator,
+        EntityType::GlobalTransactionTracker,
+        EntityType::GlobalGenericComponent,
+        EntityType::GlobalAccount,
+        EntityType::GlobalIdentity,
+        EntityType::GlobalAccessController,
+        EntityType::GlobalOneResourcePool,
+        EntityType::GlobalTwoResourcePool,
+        EntityType::GlobalMultiResourcePool,
+        EntityType::GlobalVirtualSecp256k1Account,
+        EntityType::GlobalVirtualSecp256k1Identity,
+        EntityType::GlobalVirtualEd25519Account,
+        EntityType::GlobalVirtualEd25519Identity,
+        EntityType::GlobalFungibleResourceManager,
+        EntityType::InternalFungibleVault,
+        EntityType::GlobalNonFungibleResourceManager,
+        EntityType::InternalNonFungibleVault,
+        EntityType::InternalGenericComponent,
+        EntityType::InternalKeyValueStore,
+    ]
+    .into_iter()
+    .map(|entity_type| {
+        let hrp = hrp_set.get_entity_hrp(&entity_type).to_string();
+        (entity_type, hrp)
+    })
+    .collect()
+}
+
+/// Returns the id of a network other than the given one whose HRP suffix overlaps with the given
+/// HRP suffix. This considers the built-in and registered networks as well as the implicit
+/// `tdx_{id:x}_` suffixes that [`network_id_from_hrp`] decodes the ids of all other networks from.
+fn network_id_with_overlapping_hrp_suffix(hrp_suffix: &str, network_id: u8) -> Option<u8> {
+    let implicit_hrp_suffixes = (0..=u8::MAX).flat_map(|network_id| {
+        [
+            (network_id, format!("tdx_{:x}_", network_id)),
+            (network_id, format!("tdx_{:02x}_", network_id)),
+        ]
+    });
+
+    network_definitions()
+        .into_iter()
+        .map(|network_definition| (network_definition.id, network_definition.hrp_suffix))
+        .chain(implicit_hrp_suffixes)
+        .filter(|(other_network_id, _)| *other_network_id != network_id)
+        .find(|(_, other_hrp_suffix)| hrp_suffixes_overlap(hrp_suffix, other_hrp_suffix))
+        .map(|(other_network_id, _)| other_network_id)
+}
+
+/// HRPs are decoded by matching their ending against the HRP suffixes following an underscore, so
+/// two suffixes overlap when they are equal or when one of them ends with the other one preceded by
+/// an underscore.
+fn hrp_suffixes_overlap(a: &str, b: &str) -> bool {
+    a == b || a.ends_with(&format!("_{b}")) || b.ends_with(&format!("_{a}"))
+}
+
 pub fn network_definition_from_network_id(network_id: u8) -> NetworkDefinition {
-    match network_id {
+    if let Some(network_definition) = REGISTERED_NETWORK_DEFINITIONS
+        .read()
+        .expect("Lock poisoned")
+        .get(&network_id)
+    {
+        return network_definition.clone();
+    }
+
+    built_in_network_definition(network_id).unwrap_or_else(|| NetworkDefinition {
+        id: 0x25,
+        logical_name: "unnamed".to_string(),
+        hrp_suffix: format!("tdx_{:x}_", network_id),
+    })
+}
+
+fn built_in_network_definition(network_id: u8) -> Option<NetworkDefinition> {
+    let network_definition = match network_id {
         // Public facing networks
         0x01 => NetworkDefinition::mainnet(),
         0x02 => NetworkDefinition {
@@ -120,16 +286,26 @@ pub fn network_definition_from_network_id(network_id: u8) -> NetworkDefinition {
         },
         0xF2 => NetworkDefinition::simulator(),
 
-        // Unnamed
-        network_id => NetworkDefinition {
-            id: 0x25,
-            logical_name: "unnamed".to_string(),
-            hrp_suffix: format!("tdx_{:x}_", network_id),
-        },
-    }
+        _ => return None,
+    };
+    Some(network_definition)
 }
 
 pub fn network_id_from_hrp<S: AsRef<str>>(hrp: S) -> Option<u8> {
+    if let Some(network_id) = REGISTERED_NETWORK_DEFINITIONS
+        .read()
+        .expect("Lock poisoned")
+        .values()
+        .find(|network_definition| {
+            hrp.as_ref()
+                .strip_suffix(network_definition.hrp_suffix.as_str())
+                .map_or(false, |prefix| prefix.ends_with('_'))
+        })
+        .map(|network_definition| network_definition.id)
+    {
+        return Some(network_id);
+    }
+
     let network_specifier = {
         let re = Regex::new("_(sim|loc|rdx|test|tdx_[A-Fa-f0-9]{1,2}_)$")
             .expect("Failed to create Regex. Must panic");
@@ -172,22 +348,158 @@ pub fn to_manifest_type<D: ManifestDecode>(value: &ManifestValue) -> Option<D> {
         .and_then(|encoded| manifest_decode(&encoded).ok())
 }
 
-// TODO: This should return a `bool`.
-#[allow(clippy::result_unit_err)]
 pub fn validate_manifest_value_against_schema<S: ScryptoDescribe>(
     value: &ManifestValue,
-) -> Result<(), ()> {
+) -> Result<(), Vec<SchemaViolation>> {
     let (local_type_id, VersionedSchema::V1(schema)) =
         generate_full_schema_from_single_type::<S, ScryptoCustomSchema>();
-    let encoded_payload = manifest_encode(&value).map_err(|_| ())?;
-    validate_payload_against_schema::<ManifestCustomExtension, _>(
-        &encoded_payload,
-        &schema,
-        local_type_id,
-        &(),
-        SCRYPTO_SBOR_V1_MAX_DEPTH,
-    )
-    .map_err(|_| ())
+    let violations = manifest_value_schema_violations(value, &schema, local_type_id);
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(violations)
+    }
+}
+
+/// Validates a manifest value against a type in a schema and returns all of the places where it
+/// doesn't match. Violations are reported at the deepest value that fails validation, so a tuple
+/// with two invalid fields yields two violations instead of one for the tuple.
+pub fn manifest_value_schema_violations(
+    value: &ManifestValue,
+    schema: &Schema<ScryptoCustomSchema>,
+    local_type_id: LocalTypeId,
+) -> Vec<SchemaViolation> {
+    let mut violations = vec![];
+    collect_schema_violations(value, schema, local_type_id, "", &mut violations);
+    violations
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaViolation {
+    /// A JSON-pointer-like path to the value that violates the schema. Tuple fields, enum fields
+    /// and array elements are addressed by their index and map entries by their index followed by
+    /// `key` or `value`, e.g. `/1/0/key`. The root value has an empty path.
+    pub path: String,
+    /// The kind of the type that the schema expects at the path.
+    pub expected_type_kind: String,
+    /// The name of the type that the schema expects at the path, if it has one.
+    pub expected_type_name: Option<String>,
+    /// The kind of the value found at the path.
+    pub found_value_kind: ManifestValueKind,
+    pub error: String,
+}
+
+fn collect_schema_violations(
+    value: &ManifestValue,
+    schema: &Schema<ScryptoCustomSchema>,
+    local_type_id: LocalTypeId,
+    path: &str,
+    violations: &mut Vec<SchemaViolation>,
+) {
+    let error = match manifest_encode(value) {
+        Ok(encoded) => match validate_payload_against_schema::<ManifestCustomExtension, _>(
+            &encoded,
+            schema,
+            local_type_id,
+            &(),
+            MANIFEST_SBOR_V1_MAX_DEPTH,
+        ) {
+            Ok(()) => return,
+            Err(error) => format!("{:?}", error.error),
+        },
+        Err(error) => format!("{:?}", error),
+    };
+
+    let children = match (schema.resolve_type_kind(local_type_id), value) {
+        (Some(TypeKind::Tuple { field_types }), ManifestValue::Tuple { fields })
+            if field_types.len() == fields.len() =>
+        {
+            fields.iter().zip(field_types.iter()).collect::<Vec<_>>()
+        }
+        (
+            Some(TypeKind::Enum { variants }),
+            ManifestValue::Enum {
+                discriminator,
+                fields,
+            },
+        ) => match variants.get(discriminator) {
+            Some(field_types) if field_types.len() == fields.len() => {
+                fields.iter().zip(field_types.iter()).collect::<Vec<_>>()
+            }
+            _ => vec![],
+        },
+        (Some(TypeKind::Array { element_type }), ManifestValue::Array { elements, .. }) => elements
+            .iter()
+            .map(|element| (element, element_type))
+            .collect(),
+        _ => vec![],
+    };
+
+    let violations_count = violations.len();
+    for (index, (value, local_type_id)) in children.into_iter().enumerate() {
+        let path = format!("{}/{}", path, index);
+        collect_schema_violations(value, schema, *local_type_id, &path, violations);
+    }
+    if let (
+        Some(TypeKind::Map {
+            key_type,
+            value_type,
+        }),
+        ManifestValue::Map { entries, .. },
+    ) = (schema.resolve_type_kind(local_type_id), value)
+    {
+        for (index, (key, value)) in entries.iter().enumerate() {
+            let path = format!("{}/{}", path, index);
+            collect_schema_violations(key, schema, *key_type, &format!("{}/key", path), violations);
+            collect_schema_violations(
+                value,
+                schema,
+                *value_type,
+                &format!("{}/value", path),
+                violations,
+            );
+        }
+    }
+
+    // None of the children explain why the value is invalid so the value itself is reported.
+    if violations.len() == violations_count {
+        violations.push(SchemaViolation {
+            path: path.to_owned(),
+            expected_type_kind: schema
+                .resolve_type_kind(local_type_id)
+                .map(type_kind_name)
+                .unwrap_or_else(|| "Unknown".to_owned()),
+            expected_type_name: schema
+                .resolve_type_metadata(local_type_id)
+                .and_then(|metadata| metadata.get_name())
+                .map(ToOwned::to_owned),
+            found_value_kind: value.get_value_kind(),
+            error,
+        })
+    }
+}
+
+fn type_kind_name(type_kind: &TypeKind<ScryptoCustomTypeKind, LocalTypeId>) -> String {
+    match type_kind {
+        TypeKind::Any => "Any".to_owned(),
+        TypeKind::Bool => "Bool".to_owned(),
+        TypeKind::I8 => "I8".to_owned(),
+        TypeKind::I16 => "I16".to_owned(),
+        TypeKind::I32 => "I32".to_owned(),
+        TypeKind::I64 => "I64".to_owned(),
+        TypeKind::I128 => "I128".to_owned(),
+        TypeKind::U8 => "U8".to_owned(),
+        TypeKind::U16 => "U16".to_owned(),
+        TypeKind::U32 => "U32".to_owned(),
+        TypeKind::U64 => "U64".to_owned(),
+        TypeKind::U128 => "U128".to_owned(),
+        TypeKind::String => "String".to_owned(),
+        TypeKind::Array { .. } => "Array".to_owned(),
+        TypeKind::Tuple { .. } => "Tuple".to_owned(),
+        TypeKind::Enum { .. } => "Enum".to_owned(),
+        TypeKind::Map { .. } => "Map".to_owned(),
+        TypeKind::Custom(custom) => format!("{:?}", custom),
+    }
 }
 
 pub fn is_account<A: Into<DynamicGlobalAddress> + Clone>(node_id: &A) -> bool {
@@ -0,0 +1,116 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use radix_engine_interface::blueprints::access_controller::*;
+use radix_engine_toolkit_core::functions::instructions::access_controller_interactions;
+use radix_engine_toolkit_core::instruction_visitor::visitors::access_controller_visitor::*;
+use scrypto::prelude::*;
+use transaction::prelude::*;
+
+#[test]
+fn recovery_lifecycle_calls_are_classified() {
+    // Arrange
+    let access_controller = access_controller();
+    let rule_set = RuleSet {
+        primary_role: rule!(allow_all),
+        recovery_role: rule!(allow_all),
+        confirmation_role: rule!(deny_all),
+    };
+    let proposal = RecoveryProposal {
+        rule_set: rule_set.clone(),
+        timed_recovery_delay_in_minutes: Some(10),
+    };
+    let manifest = ManifestBuilder::new()
+        .call_method(
+            access_controller,
+            ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_RECOVERY_IDENT,
+            AccessControllerInitiateRecoveryAsRecoveryInput {
+                rule_set: rule_set.clone(),
+                timed_recovery_delay_in_minutes: Some(10),
+            },
+        )
+        .call_method(
+            access_controller,
+            ACCESS_CONTROLLER_TIMED_CONFIRM_RECOVERY_IDENT,
+            AccessControllerTimedConfirmRecoveryInput {
+                rule_set,
+                timed_recovery_delay_in_minutes: Some(10),
+            },
+        )
+        .call_method(
+            access_controller,
+            ACCESS_CONTROLLER_CANCEL_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT,
+            manifest_args!(),
+        )
+        .build();
+
+    // Act
+    let interactions = access_controller_interactions(&manifest.instructions);
+
+    // Assert
+    assert_eq!(
+        interactions,
+        vec![
+            AccessControllerInteraction {
+                instruction_index: 0,
+                access_controller,
+                operation: AccessControllerOperation::InitiateRecovery {
+                    proposer: Proposer::Recovery,
+                    proposal: proposal.clone()
+                }
+            },
+            AccessControllerInteraction {
+                instruction_index: 1,
+                access_controller,
+                operation: AccessControllerOperation::TimedConfirmRecovery { proposal }
+            },
+            AccessControllerInteraction {
+                instruction_index: 2,
+                access_controller,
+                operation: AccessControllerOperation::CancelRecovery {
+                    proposer: Proposer::Primary
+                }
+            },
+        ]
+    );
+}
+
+#[test]
+fn calls_with_unexpected_arguments_are_not_classified() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .call_method(
+            access_controller(),
+            ACCESS_CONTROLLER_TIMED_CONFIRM_RECOVERY_IDENT,
+            manifest_args!(),
+        )
+        .build();
+
+    // Act
+    let interactions = access_controller_interactions(&manifest.instructions);
+
+    // Assert
+    assert!(interactions.is_empty());
+}
+
+fn access_controller() -> ComponentAddress {
+    let public_key = Secp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let mut bytes = ComponentAddress::virtual_account_from_public_key(&public_key)
+        .as_node_id()
+        .0;
+    bytes[0] = EntityType::GlobalAccessController as u8;
+    ComponentAddress::new_or_panic(bytes)
+}
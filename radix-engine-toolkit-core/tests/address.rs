@@ -0,0 +1,145 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_core::functions::address::{self, AddressValidationIssue};
+use scrypto::prelude::*;
+
+#[test]
+fn valid_address_has_no_validation_issues() {
+    // Arrange
+    let address = xrd_address(0x01);
+
+    // Act
+    let issues = address::validate(
+        &address,
+        Some(0x01),
+        &[EntityType::GlobalFungibleResourceManager],
+    );
+
+    // Assert
+    assert!(issues.is_empty())
+}
+
+#[test]
+fn address_of_another_network_is_reported() {
+    // Arrange
+    let address = xrd_address(0x02);
+
+    // Act
+    let issues = address::validate(&address, Some(0x01), &[]);
+
+    // Assert
+    assert_eq!(
+        issues,
+        vec![AddressValidationIssue::WrongNetwork {
+            expected: 0x01,
+            actual: 0x02
+        }]
+    )
+}
+
+#[test]
+fn address_of_unexpected_entity_type_is_reported() {
+    // Arrange
+    let address = xrd_address(0x01);
+
+    // Act
+    let issues = address::validate(&address, None, &[EntityType::GlobalAccount]);
+
+    // Assert
+    assert_eq!(
+        issues,
+        vec![AddressValidationIssue::WrongEntityType {
+            expected: vec![EntityType::GlobalAccount],
+            actual: EntityType::GlobalFungibleResourceManager
+        }]
+    )
+}
+
+#[test]
+fn address_with_a_typo_fails_the_checksum() {
+    // Arrange
+    let mut address = xrd_address(0x01);
+    let last_character = address.pop().unwrap();
+    address.push(if last_character == 'q' { 'p' } else { 'q' });
+
+    // Act
+    let issues = address::validate(&address, None, &[]);
+
+    // Assert
+    assert_eq!(issues, vec![AddressValidationIssue::InvalidChecksum])
+}
+
+#[test]
+fn address_is_abbreviated_with_its_hrp() {
+    // Arrange
+    let address = xrd_address(0x01);
+
+    // Act
+    let abbreviated = address::abbreviate(&address, 4, 6);
+
+    // Assert
+    assert_eq!(
+        abbreviated.abbreviated,
+        format!(
+            "resource_rdx1{}...{}",
+            &address[13..17],
+            &address[address.len() - 6..]
+        )
+    );
+    assert_eq!(abbreviated.checksum_marker.len(), 4);
+}
+
+#[test]
+fn look_alike_addresses_share_an_abbreviation_but_not_a_checksum_marker() {
+    // Arrange
+    let address1 = "account_rdx1abcdqqqqqqqqqqqqqqqqqqqqwxyz";
+    let address2 = "account_rdx1abcdpppppppppppppppppppppwxyz";
+
+    // Act
+    let look_alike = address::is_look_alike(address1, address2, 4, 4);
+    let abbreviated1 = address::abbreviate(address1, 4, 4);
+    let abbreviated2 = address::abbreviate(address2, 4, 4);
+
+    // Assert
+    assert!(look_alike);
+    assert_eq!(abbreviated1.abbreviated, abbreviated2.abbreviated);
+    assert_ne!(abbreviated1.checksum_marker, abbreviated2.checksum_marker);
+}
+
+#[test]
+fn an_address_does_not_look_alike_itself_or_addresses_of_other_networks() {
+    // Arrange
+    let mainnet_address = xrd_address(0x01);
+    let stokenet_address = xrd_address(0x02);
+
+    // Act
+    let same = address::is_look_alike(&mainnet_address, &mainnet_address, 4, 4);
+    let other_network = address::is_look_alike(&mainnet_address, &stokenet_address, 0, 0);
+
+    // Assert
+    assert!(!same);
+    assert!(!other_network);
+}
+
+fn xrd_address(network_id: u8) -> String {
+    let network_definition =
+        radix_engine_toolkit_core::utils::network_definition_from_network_id(network_id);
+    AddressBech32Encoder::new(&network_definition)
+        .encode(XRD.as_node_id().as_bytes())
+        .unwrap()
+}
@@ -0,0 +1,57 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_core::functions::instructions::extract_addresses_by_role;
+use radix_engine_toolkit_core::instruction_visitor::visitors::addresses_by_role_visitor::*;
+use scrypto::prelude::*;
+use transaction::prelude::*;
+
+#[test]
+fn addresses_are_grouped_by_their_role_in_the_manifest() {
+    // Arrange
+    let account1 = ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+    );
+    let account2 = ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(2).unwrap().public_key(),
+    );
+    let manifest = ManifestBuilder::new()
+        .create_proof_from_account_of_amount(account1, ACCOUNT_OWNER_BADGE, dec!("1"))
+        .withdraw_from_account(account1, XRD, dec!("10"))
+        .call_method(FAUCET, "free", manifest_args!())
+        .call_function(FAUCET_PACKAGE, "Faucet", "new", manifest_args!())
+        .take_all_from_worktop(XRD, "bucket")
+        .return_to_worktop("bucket")
+        .deposit_batch(account2)
+        .build();
+
+    // Act
+    let addresses_by_role = extract_addresses_by_role(&manifest.instructions);
+
+    // Assert
+    assert_eq!(
+        addresses_by_role,
+        AddressesByRole {
+            called_components: [account1, account2, FAUCET].into_iter().collect(),
+            called_packages: [FAUCET_PACKAGE].into_iter().collect(),
+            accounts_withdrawn_from: [account1].into_iter().collect(),
+            accounts_deposited_into: [account2].into_iter().collect(),
+            resources_moved: [XRD].into_iter().collect(),
+            badges_presented: [ACCOUNT_OWNER_BADGE].into_iter().collect(),
+        }
+    );
+}
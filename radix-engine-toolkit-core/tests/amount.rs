@@ -0,0 +1,178 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_core::functions::amount::*;
+use scrypto::prelude::*;
+
+#[test]
+fn xrd_amounts_are_formatted_with_all_significant_decimal_places() {
+    // Arrange
+    let amount = dec!("1000.000000000000000001");
+
+    // Act
+    let formatted = format(&amount, &AmountFormatOptions::xrd());
+
+    // Assert
+    assert_eq!(formatted, Ok("1000.000000000000000001 XRD".to_owned()));
+}
+
+#[test]
+fn amounts_are_rounded_to_the_divisibility_of_the_resource() {
+    // Arrange
+    let amount = dec!("-12.345");
+    let options = AmountFormatOptions {
+        rounding_mode: RoundingMode::ToNearestMidpointAwayFromZero,
+        ..AmountFormatOptions::new(2, Some("TKN".to_owned()))
+    };
+
+    // Act
+    let formatted = format(&amount, &options);
+
+    // Assert
+    assert_eq!(formatted, Ok("-12.35 TKN".to_owned()));
+}
+
+#[test]
+fn amounts_are_truncated_to_the_maximum_decimal_places_by_default() {
+    // Arrange
+    let amount = dec!("0.999999");
+    let options = AmountFormatOptions {
+        maximum_decimal_places: Some(4),
+        ..AmountFormatOptions::xrd()
+    };
+
+    // Act
+    let formatted = format(&amount, &options);
+
+    // Assert
+    assert_eq!(formatted, Ok("0.9999 XRD".to_owned()));
+}
+
+#[test]
+fn amounts_are_padded_and_grouped() {
+    // Arrange
+    let amount = dec!("1234567");
+    let options = AmountFormatOptions {
+        minimum_decimal_places: 2,
+        group_separator: Some(','),
+        ..AmountFormatOptions::new(18, None)
+    };
+
+    // Act
+    let formatted = format(&amount, &options);
+
+    // Assert
+    assert_eq!(formatted, Ok("1,234,567.00".to_owned()));
+}
+
+#[test]
+fn the_largest_decimal_can_be_formatted() {
+    // Arrange
+    let amount = Decimal::MAX;
+    let options = AmountFormatOptions::new(0, None);
+
+    // Act
+    let formatted = format(&amount, &options);
+
+    // Assert
+    assert_eq!(
+        formatted,
+        Ok("3138550867693340381917894711603833208051".to_owned())
+    );
+}
+
+#[test]
+fn divisibility_larger_than_the_decimal_scale_is_rejected() {
+    // Arrange
+    let options = AmountFormatOptions::new(19, None);
+
+    // Act
+    let formatted = format(&Decimal::ONE, &options);
+
+    // Assert
+    assert_eq!(formatted, Err(AmountFormatError::InvalidDivisibility(19)));
+}
+
+#[test]
+fn amounts_with_group_separators_and_a_plus_sign_are_parsed() {
+    // Arrange
+    let inputs = [" +1,000,000.5 ", "1_000_000.5", "1'000'000.5", "1000000.50"];
+
+    // Act
+    let parsed = inputs.map(parse);
+
+    // Assert
+    assert!(parsed.iter().all(|amount| *amount == Ok(dec!("1000000.5"))));
+}
+
+#[test]
+fn amounts_in_scientific_notation_are_parsed() {
+    // Arrange
+    let inputs = ["1.5e3", "-25E-2", "1e-18", "0.001e+3"];
+
+    // Act
+    let parsed = inputs.map(parse);
+
+    // Assert
+    assert_eq!(
+        parsed,
+        [
+            Ok(dec!("1500")),
+            Ok(dec!("-0.25")),
+            Ok(dec!("0.000000000000000001")),
+            Ok(dec!("1")),
+        ]
+    );
+}
+
+#[test]
+fn amounts_with_a_comma_decimal_separator_are_rejected() {
+    // Arrange
+    let inputs = ["1,5", "1.000,5", "1,000_000"];
+
+    // Act
+    let parsed = inputs.map(parse);
+
+    // Assert
+    assert_eq!(
+        parsed,
+        [
+            Err(AmountParseError::InvalidDigitGrouping),
+            Err(AmountParseError::InvalidCharacter(',')),
+            Err(AmountParseError::InvalidDigitGrouping),
+        ]
+    );
+}
+
+#[test]
+fn amounts_that_a_decimal_cannot_hold_are_rejected() {
+    // Arrange
+    let inputs = ["1e-19", "1e40", ""];
+
+    // Act
+    let parsed = inputs.map(parse);
+
+    // Assert
+    assert_eq!(
+        parsed,
+        [
+            Err(AmountParseError::TooManyDecimalPlaces),
+            Err(AmountParseError::Overflow),
+            Err(AmountParseError::Empty),
+        ]
+    );
+}
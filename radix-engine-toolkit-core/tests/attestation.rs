@@ -0,0 +1,84 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_core::functions::attestation::*;
+use transaction::prelude::*;
+
+#[test]
+fn attestation_verifies_against_signing_key() {
+    // Arrange
+    let private_key = Ed25519PrivateKey::from_u64(1).unwrap();
+    let attestation = sign(b"{\"fee_locks\":{}}".to_vec(), &private_key);
+
+    // Act
+    let verified = verify(&attestation, &private_key.public_key());
+
+    // Assert
+    assert!(verified);
+}
+
+#[test]
+fn attestation_does_not_verify_against_another_key() {
+    // Arrange
+    let private_key = Ed25519PrivateKey::from_u64(1).unwrap();
+    let other_private_key = Ed25519PrivateKey::from_u64(2).unwrap();
+    let attestation = sign(b"{\"fee_locks\":{}}".to_vec(), &private_key);
+
+    // Act
+    let verified = verify(&attestation, &other_private_key.public_key());
+
+    // Assert
+    assert!(!verified);
+}
+
+#[test]
+fn attestation_does_not_verify_with_tampered_payload() {
+    // Arrange
+    let private_key = Ed25519PrivateKey::from_u64(1).unwrap();
+    let mut attestation = sign(b"{\"fee_locks\":{}}".to_vec(), &private_key);
+    attestation.payload = b"{\"fee_locks\":null}".to_vec();
+
+    // Act
+    let verified = verify(&attestation, &private_key.public_key());
+
+    // Assert
+    assert!(!verified);
+}
+
+#[test]
+fn attestation_signed_with_external_signer_is_identical() {
+    // Arrange
+    let private_key = Ed25519PrivateKey::from_u64(1).unwrap();
+    let payload = b"{\"fee_locks\":{}}".to_vec();
+
+    // Act
+    let attestation = sign_with(payload.clone(), |hash| {
+        (private_key.public_key(), private_key.sign(hash))
+    });
+
+    // Assert
+    assert_eq!(attestation, sign(payload, &private_key));
+}
+
+#[test]
+fn attestation_hash_is_domain_separated() {
+    // Act
+    let attestation_hash = hash(b"payload");
+
+    // Assert
+    assert_ne!(attestation_hash, scrypto::prelude::hash(b"payload"));
+}
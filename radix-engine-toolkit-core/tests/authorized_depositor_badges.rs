@@ -0,0 +1,82 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_interface::blueprints::account::*;
+use radix_engine_toolkit_core::functions::instructions::authorized_depositor_badges;
+use radix_engine_toolkit_core::instruction_visitor::visitors::authorized_depositor_badges_visitor::*;
+use scrypto::prelude::*;
+use transaction::prelude::*;
+
+mod test_data;
+
+#[test]
+fn deposits_presenting_an_authorized_depositor_badge_are_listed() {
+    // Arrange
+    let badge = ResourceOrNonFungible::NonFungible(NonFungibleGlobalId::new(
+        ACCOUNT_OWNER_BADGE,
+        NonFungibleLocalId::integer(1),
+    ));
+    let instructions = vec![
+        InstructionV1::TakeAllFromWorktop {
+            resource_address: XRD,
+        },
+        InstructionV1::CallMethod {
+            address: DynamicGlobalAddress::Static(test_data::account1().into()),
+            method_name: ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT.to_owned(),
+            args: manifest_args!(ManifestBucket(0), Option::<ResourceOrNonFungible>::None).into(),
+        },
+        InstructionV1::CallMethod {
+            address: DynamicGlobalAddress::Static(test_data::account1().into()),
+            method_name: ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT.to_owned(),
+            args: manifest_args!(ManifestExpression::EntireWorktop, Some(badge.clone())).into(),
+        },
+    ];
+
+    // Act
+    let usages = authorized_depositor_badges(&instructions);
+
+    // Assert
+    assert_eq!(
+        usages,
+        vec![AuthorizedDepositorBadgeUsage {
+            instruction_index: 2,
+            account: DynamicGlobalAddress::Static(test_data::account1().into()),
+            method_name: ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT.to_owned(),
+            badge,
+        }]
+    );
+}
+
+#[test]
+fn badges_passed_to_components_other_than_accounts_are_ignored() {
+    // Arrange
+    let instructions = vec![InstructionV1::CallMethod {
+        address: DynamicGlobalAddress::Static(FAUCET.into()),
+        method_name: ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT.to_owned(),
+        args: manifest_args!(
+            ManifestExpression::EntireWorktop,
+            Some(ResourceOrNonFungible::Resource(XRD))
+        )
+        .into(),
+    }];
+
+    // Act
+    let usages = authorized_depositor_badges(&instructions);
+
+    // Assert
+    assert!(usages.is_empty());
+}
@@ -0,0 +1,53 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+#![cfg(all(feature = "bench", not(target_arch = "wasm32")))]
+
+use radix_engine_toolkit_core::functions::information::*;
+
+#[test]
+fn benchmark_measures_each_function_for_each_payload_size() {
+    // Arrange
+    let configuration = BenchmarkConfiguration {
+        payload_sizes: vec![1, 2],
+        iterations: 2,
+    };
+
+    // Act
+    let report = benchmark(&configuration, &[]);
+
+    // Assert
+    assert_eq!(report.results.len(), 6);
+    for result in report.results.iter() {
+        assert_eq!(result.iterations, 2);
+        assert!(result.min <= result.mean && result.mean <= result.max);
+    }
+    assert_eq!(
+        report
+            .results
+            .iter()
+            .map(|result| (result.function, result.instruction_count))
+            .collect::<Vec<_>>(),
+        vec![
+            (BenchmarkedFunction::Compile, 3),
+            (BenchmarkedFunction::Decompile, 3),
+            (BenchmarkedFunction::StaticValidation, 3),
+            (BenchmarkedFunction::Compile, 4),
+            (BenchmarkedFunction::Decompile, 4),
+            (BenchmarkedFunction::StaticValidation, 4),
+        ]
+    );
+}
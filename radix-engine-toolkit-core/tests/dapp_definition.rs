@@ -0,0 +1,135 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_core::functions::dapp_definition::*;
+use scrypto::api::node_modules::metadata::MetadataValue;
+use scrypto::prelude::*;
+mod test_data;
+
+#[test]
+fn two_way_linked_entity_is_verified() {
+    // Arrange
+    let entity = GlobalAddress::from(FAUCET);
+    let dapp_definition = GlobalAddress::from(test_data::account1());
+    let entity_metadata = hashmap!(
+        DAPP_DEFINITION_KEY.to_owned() => MetadataValue::GlobalAddress(dapp_definition)
+    );
+    let dapp_definition_metadata = dapp_definition_metadata(vec![entity]);
+
+    // Act
+    let verification = verify_link(
+        entity,
+        &entity_metadata,
+        dapp_definition,
+        &dapp_definition_metadata,
+    );
+
+    // Assert
+    assert!(verification.is_verified(), "{verification:?}");
+}
+
+#[test]
+fn entity_not_claimed_by_dapp_definition_is_not_verified() {
+    // Arrange
+    let entity = GlobalAddress::from(XRD);
+    let dapp_definition = GlobalAddress::from(test_data::account1());
+    let entity_metadata = hashmap!(
+        DAPP_DEFINITIONS_KEY.to_owned() => MetadataValue::GlobalAddressArray(vec![dapp_definition])
+    );
+    let dapp_definition_metadata = dapp_definition_metadata(vec![FAUCET.into()]);
+
+    // Act
+    let verification = verify_link(
+        entity,
+        &entity_metadata,
+        dapp_definition,
+        &dapp_definition_metadata,
+    );
+
+    // Assert
+    assert!(verification.entity_references_dapp_definition);
+    assert!(!verification.dapp_definition_claims_entity);
+    assert_eq!(
+        verification.violations,
+        vec![DappDefinitionLinkViolation::DappDefinitionDoesNotClaimEntity]
+    );
+}
+
+#[test]
+fn all_link_violations_are_reported_at_once() {
+    // Arrange
+    let entity = GlobalAddress::from(XRD);
+    let dapp_definition = GlobalAddress::from(FAUCET);
+    let entity_metadata = hashmap!(
+        DAPP_DEFINITION_KEY.to_owned() => MetadataValue::String("dapp".to_owned())
+    );
+    let dapp_definition_metadata = hashmap!(
+        ACCOUNT_TYPE_KEY.to_owned() => MetadataValue::String("account".to_owned())
+    );
+
+    // Act
+    let verification = verify_link(
+        entity,
+        &entity_metadata,
+        dapp_definition,
+        &dapp_definition_metadata,
+    );
+
+    // Assert
+    assert_eq!(
+        verification.violations,
+        vec![
+            DappDefinitionLinkViolation::InvalidMetadataValueType {
+                address: entity,
+                key: DAPP_DEFINITION_KEY.to_owned()
+            },
+            DappDefinitionLinkViolation::EntityDoesNotReferenceDappDefinition,
+            DappDefinitionLinkViolation::DappDefinitionIsNotAnAccount,
+            DappDefinitionLinkViolation::AccountTypeIsNotDappDefinition {
+                account_type: Some("account".to_owned())
+            },
+            DappDefinitionLinkViolation::DappDefinitionDoesNotClaimEntity,
+        ]
+    );
+}
+
+#[test]
+fn referenced_dapp_definitions_are_read_from_both_keys() {
+    // Arrange
+    let dapp_definition1 = GlobalAddress::from(test_data::account1());
+    let dapp_definition2 = GlobalAddress::from(test_data::account2());
+    let metadata = hashmap!(
+        DAPP_DEFINITION_KEY.to_owned() => MetadataValue::GlobalAddress(dapp_definition1),
+        DAPP_DEFINITIONS_KEY.to_owned() =>
+            MetadataValue::GlobalAddressArray(vec![dapp_definition1, dapp_definition2])
+    );
+
+    // Act
+    let dapp_definitions = referenced_dapp_definitions(&metadata);
+
+    // Assert
+    assert_eq!(dapp_definitions, vec![dapp_definition1, dapp_definition2]);
+}
+
+fn dapp_definition_metadata(
+    claimed_entities: Vec<GlobalAddress>,
+) -> HashMap<String, MetadataValue> {
+    hashmap!(
+        ACCOUNT_TYPE_KEY.to_owned() => MetadataValue::String(DAPP_DEFINITION_ACCOUNT_TYPE.to_owned()),
+        CLAIMED_ENTITIES_KEY.to_owned() => MetadataValue::GlobalAddressArray(claimed_entities)
+    )
+}
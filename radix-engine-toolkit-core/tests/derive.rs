@@ -56,6 +56,131 @@ fn virtual_identity_address_can_be_derived_from_public_key() {
     )
 }
 
+#[test]
+fn virtual_signature_badge_from_public_key_hash_matches_the_one_from_the_public_key() {
+    // Arrange
+    let public_key = public_key();
+    let public_key_hash = PublicKeyHash::new_from_public_key(&public_key);
+
+    // Act
+    let non_fungible_global_id =
+        derive::virtual_signature_non_fungible_global_id_from_public_key_hash(&public_key_hash);
+
+    // Assert
+    assert_eq!(
+        non_fungible_global_id,
+        derive::virtual_signature_non_fungible_global_id_from_public_key(&public_key)
+    );
+}
+
+#[test]
+fn public_key_hash_can_be_derived_from_virtual_signature_badge() {
+    // Arrange
+    let public_key = public_key();
+    let non_fungible_global_id =
+        derive::virtual_signature_non_fungible_global_id_from_public_key(&public_key);
+
+    // Act
+    let public_key_hash = derive::public_key_hash_from_virtual_signature_non_fungible_global_id(
+        &non_fungible_global_id,
+    );
+
+    // Assert
+    assert_eq!(
+        public_key_hash.unwrap(),
+        PublicKeyHash::new_from_public_key(&public_key)
+    );
+}
+
+#[test]
+fn public_key_hash_can_not_be_derived_from_other_non_fungibles() {
+    // Arrange
+    let non_fungible_global_id = NonFungibleGlobalId::new(
+        PACKAGE_OWNER_BADGE,
+        NonFungibleLocalId::bytes(vec![0; 29]).unwrap(),
+    );
+
+    // Act
+    let public_key_hash = derive::public_key_hash_from_virtual_signature_non_fungible_global_id(
+        &non_fungible_global_id,
+    );
+
+    // Assert
+    assert!(matches!(
+        public_key_hash,
+        Err(derive::DerivationError::NotAVirtualSignatureBadge(..))
+    ));
+}
+
+#[test]
+fn virtual_account_and_identity_addresses_can_be_derived_from_public_key_hash() {
+    // Arrange
+    let public_key = public_key();
+    let public_key_hash = PublicKeyHash::new_from_public_key(&public_key);
+
+    // Act
+    let account_address = derive::virtual_global_address_from_public_key_hash(
+        &public_key_hash,
+        EntityType::GlobalVirtualSecp256k1Account,
+    );
+    let identity_address = derive::virtual_global_address_from_public_key_hash(
+        &public_key_hash,
+        EntityType::GlobalVirtualSecp256k1Identity,
+    );
+
+    // Assert
+    assert_eq!(
+        account_address.unwrap(),
+        GlobalAddress::from(derive::virtual_account_address_from_public_key(&public_key))
+    );
+    assert_eq!(
+        identity_address.unwrap(),
+        GlobalAddress::from(derive::virtual_identity_address_from_public_key(
+            &public_key
+        ))
+    );
+}
+
+#[test]
+fn virtual_address_can_not_be_derived_for_an_entity_type_of_another_curve() {
+    // Arrange
+    let public_key_hash = PublicKeyHash::new_from_public_key(&public_key());
+
+    // Act
+    let address = derive::virtual_global_address_from_public_key_hash(
+        &public_key_hash,
+        EntityType::GlobalVirtualEd25519Account,
+    );
+
+    // Assert
+    assert!(matches!(
+        address,
+        Err(derive::DerivationError::PublicKeyHashCurveMismatch(
+            EntityType::GlobalVirtualEd25519Account
+        ))
+    ));
+}
+
+#[test]
+fn virtual_address_can_not_be_derived_for_non_virtual_entity_types() {
+    // Arrange
+    let public_key_hash = PublicKeyHash::new_from_public_key(&public_key());
+
+    // Act
+    let address = derive::virtual_global_address_from_public_key_hash(
+        &public_key_hash,
+        EntityType::GlobalAccount,
+    );
+
+    // Assert
+    assert!(matches!(
+        address,
+        Err(derive::DerivationError::UnsupportedVirtualEntityType(
+            EntityType::GlobalAccount
+        ))
+    ));
+}
+
 #[test]
 fn virtual_account_address_can_be_derived_from_olympia_account_address() {
     // Arrange
@@ -0,0 +1,91 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_core::instruction_visitor::core::traverser::traverse;
+use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::entity_control_changes_visitor::*;
+use scrypto::api::node_modules::auth::*;
+use scrypto::api::node_modules::metadata::*;
+use scrypto::prelude::*;
+use transaction::prelude::*;
+
+#[test]
+fn entity_control_changes_are_collected_per_entity() {
+    // Arrange
+    let public_key = Secp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let account = ComponentAddress::virtual_account_from_public_key(&public_key);
+    let owner_rule = rule!(require(XRD));
+    let mut instructions = ManifestBuilder::new()
+        .lock_fee(account, dec!("10"))
+        .lock_metadata(account, "name")
+        .build()
+        .instructions;
+    instructions.extend([
+        InstructionV1::CallRoleAssignmentMethod {
+            address: DynamicGlobalAddress::Static(account.into()),
+            method_name: ROLE_ASSIGNMENT_SET_OWNER_IDENT.to_owned(),
+            args: to_manifest_value_and_unwrap!(&RoleAssignmentSetOwnerInput {
+                rule: owner_rule.clone()
+            }),
+        },
+        InstructionV1::CallRoleAssignmentMethod {
+            address: DynamicGlobalAddress::Static(account.into()),
+            method_name: ROLE_ASSIGNMENT_LOCK_OWNER_IDENT.to_owned(),
+            args: to_manifest_value_and_unwrap!(&RoleAssignmentLockOwnerInput {}),
+        },
+    ]);
+
+    // Act
+    let mut visitor = EntityControlChangesVisitor::default();
+    traverse(&instructions, &mut [&mut visitor]).unwrap();
+    let entity_control_changes = visitor.output();
+
+    // Assert
+    assert_eq!(
+        entity_control_changes,
+        Some(
+            [(
+                account.into(),
+                vec![
+                    EntityControlChange::MetadataLocked {
+                        key: "name".to_owned()
+                    },
+                    EntityControlChange::OwnerRoleSet { rule: owner_rule },
+                    EntityControlChange::OwnerRoleLocked,
+                ]
+            )]
+            .into_iter()
+            .collect()
+        )
+    );
+}
+
+#[test]
+fn manifests_not_changing_entity_control_have_no_entity_control_changes() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .call_method(FAUCET, "free", manifest_args!())
+        .set_metadata(FAUCET, "name", MetadataValue::String("Faucet".to_owned()))
+        .build();
+
+    // Act
+    let mut visitor = EntityControlChangesVisitor::default();
+    traverse(&manifest.instructions, &mut [&mut visitor]).unwrap();
+    let entity_control_changes = visitor.output();
+
+    // Assert
+    assert_eq!(entity_control_changes, None);
+}
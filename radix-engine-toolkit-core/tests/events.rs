@@ -0,0 +1,99 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+mod test_data;
+
+use radix_engine_toolkit_core::functions::events::*;
+use scrypto::prelude::*;
+
+#[test]
+fn account_resource_movement_filter_is_compiled_to_a_clause_per_event() {
+    // Arrange
+    let account = test_data::account1();
+    let filter = EventFilter::AccountResourceMovement {
+        account,
+        resource_address: Some(XRD),
+    };
+
+    // Act
+    let compiled = compile_event_filter(&filter).unwrap();
+
+    // Assert
+    assert_eq!(compiled.clauses.len(), 3);
+    for clause in compiled.clauses {
+        assert_eq!(clause.emitter, Some(GlobalAddress::from(account)));
+        assert_eq!(clause.resource_address, Some(XRD));
+    }
+}
+
+#[test]
+fn conflicting_conjunction_matches_no_events() {
+    // Arrange
+    let filter = EventFilter::All(vec![
+        EventFilter::EventName("DepositEvent".to_owned()),
+        EventFilter::EventName("WithdrawEvent".to_owned()),
+    ]);
+
+    // Act
+    let compiled = compile_event_filter(&filter);
+
+    // Assert
+    assert!(matches!(
+        compiled,
+        Err(EventFilterError::FilterMatchesNoEvents)
+    ));
+}
+
+#[test]
+fn account_resource_movement_filter_on_non_account_fails() {
+    // Arrange
+    let filter = EventFilter::AccountResourceMovement {
+        account: FAUCET,
+        resource_address: None,
+    };
+
+    // Act
+    let compiled = compile_event_filter(&filter);
+
+    // Assert
+    assert!(matches!(compiled, Err(EventFilterError::NotAnAccount(..))));
+}
+
+#[test]
+fn conjunction_expanding_beyond_the_maximum_number_of_clauses_fails() {
+    // Arrange
+    let filter = EventFilter::All(vec![
+        EventFilter::Any(vec![
+            EventFilter::Emitter(XRD.into()),
+            EventFilter::Emitter(FAUCET.into()),
+        ]),
+        EventFilter::Any(
+            (0..40)
+                .map(|index| EventFilter::EventName(format!("Event{index}")))
+                .collect(),
+        ),
+    ]);
+
+    // Act
+    let compiled = compile_event_filter(&filter);
+
+    // Assert
+    assert!(matches!(
+        compiled,
+        Err(EventFilterError::TooManyClauses { maximum: 64, .. })
+    ));
+}
@@ -20,6 +20,7 @@ use radix_engine::system::system_modules::execution_trace::ResourceSpecifier;
 use radix_engine::transaction::*;
 use radix_engine_interface::blueprints::account::*;
 use radix_engine_interface::blueprints::consensus_manager::*;
+use radix_engine_interface::blueprints::pool::*;
 use radix_engine_toolkit_core::functions::execution::{self, *};
 use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::claim_stake_visitor::*;
 use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::general_transaction_visitor::*;
@@ -27,8 +28,12 @@ use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::
 use scrypto::prelude::*;
 use scrypto_unit::*;
 use transaction::prelude::*;
+use radix_engine_toolkit_core::instruction_visitor::core::error::*;
+use radix_engine_toolkit_core::models::node_id::TypedNodeId;
+use radix_engine_toolkit_core::instruction_visitor::core::traits::*;
 use radix_engine_toolkit_core::instruction_visitor::core::traverser::*;
 use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::stake_visitor::*;
+use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::swap_visitor::*;
 use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::unstake_visitor::*;
 
 #[test]
@@ -1278,6 +1283,345 @@ fn stake(
         .expect_commit_success();
 }
 
+#[test]
+fn account_resource_movements_are_derived_from_receipt_events() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key1, _, account1) = test_runner.new_account(true);
+    let (public_key2, _, account2) = test_runner.new_account(true);
+
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account1, XRD, dec!("10"))
+        .take_from_worktop(XRD, dec!("10"), "bucket")
+        .try_deposit_or_abort(account2, None, "bucket")
+        .build();
+    let receipt = test_runner.preview_manifest(
+        manifest.clone(),
+        vec![public_key1.into(), public_key2.into()],
+        0,
+        PreviewFlags {
+            use_free_credit: true,
+            assume_all_signature_proofs: true,
+            skip_epoch_check: true,
+        },
+    );
+    receipt.expect_commit_success();
+    let receipt = VersionedTransactionReceipt::V1(receipt);
+    let receipt = ExecutionAnalysisTransactionReceipt::new(&receipt).unwrap();
+
+    // Act
+    let movements = execution::account_resource_movements_from_events(&receipt);
+    let analysis = execution::analyze(&manifest.instructions, &receipt).unwrap();
+
+    // Assert
+    assert_eq!(
+        movements
+            .withdraws
+            .get(&account1)
+            .and_then(|amounts| amounts.get(&XRD)),
+        Some(&dec!("10"))
+    );
+    assert_eq!(
+        movements
+            .deposits
+            .get(&account2)
+            .and_then(|amounts| amounts.get(&XRD)),
+        Some(&dec!("10"))
+    );
+    assert!(analysis.resource_movement_discrepancies.is_empty());
+}
+
+#[test]
+fn resource_movement_graph_connects_entities_through_the_worktop() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key1, _, account1) = test_runner.new_account(true);
+    let (public_key2, _, account2) = test_runner.new_account(true);
+    let (public_key3, _, account3) = test_runner.new_account(true);
+
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account1, XRD, dec!("20"))
+        .take_from_worktop(XRD, dec!("10"), "bucket")
+        .try_deposit_or_abort(account2, None, "bucket")
+        .assert_worktop_contains(XRD, dec!("10"))
+        .try_deposit_batch_or_abort(account3, None)
+        .build();
+    let receipt = test_runner.preview_manifest(
+        manifest.clone(),
+        vec![public_key1.into(), public_key2.into(), public_key3.into()],
+        0,
+        PreviewFlags {
+            use_free_credit: true,
+            assume_all_signature_proofs: true,
+            skip_epoch_check: true,
+        },
+    );
+    receipt.expect_commit_success();
+    let receipt = VersionedTransactionReceipt::V1(receipt);
+    let receipt = ExecutionAnalysisTransactionReceipt::new(&receipt).unwrap();
+
+    // Act
+    let analysis = execution::analyze(&manifest.instructions, &receipt).unwrap();
+
+    // Assert
+    let graph = analysis.resource_movement_graph;
+    let node_index = |account: ComponentAddress| {
+        graph
+            .nodes
+            .iter()
+            .position(|node| {
+                *node == ResourceMovementGraphNode::Entity(TypedNodeId::new(account).unwrap())
+            })
+            .expect("Account must be a node of the graph")
+    };
+    let edge = |from: usize, to: usize| {
+        graph
+            .edges
+            .iter()
+            .find(|edge| edge.from == from && edge.to == to && edge.resource_address == XRD)
+            .map(|edge| (edge.instruction_index, edge.amount, edge.guarantee))
+    };
+
+    assert_eq!(
+        graph.nodes[ResourceMovementGraph::WORKTOP_NODE_INDEX],
+        ResourceMovementGraphNode::Worktop
+    );
+    assert_eq!(
+        edge(
+            node_index(account1),
+            ResourceMovementGraph::WORKTOP_NODE_INDEX
+        ),
+        Some((0, dec!("20"), ResourceMovementGuarantee::Guaranteed))
+    );
+    assert_eq!(
+        edge(
+            ResourceMovementGraph::WORKTOP_NODE_INDEX,
+            node_index(account2)
+        ),
+        Some((2, dec!("10"), ResourceMovementGuarantee::Predicted))
+    );
+    assert_eq!(
+        edge(
+            ResourceMovementGraph::WORKTOP_NODE_INDEX,
+            node_index(account3)
+        ),
+        Some((4, dec!("10"), ResourceMovementGuarantee::Guaranteed))
+    );
+}
+
+#[test]
+fn minimum_xrd_required_accounts_for_fee_locks_and_prior_deposits() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key1, _, account1) = test_runner.new_account(true);
+    let (public_key2, _, account2) = test_runner.new_account(true);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account1, dec!("5"))
+        .withdraw_from_account(account1, XRD, dec!("10"))
+        .try_deposit_entire_worktop_or_abort(account2, None)
+        .withdraw_from_account(account2, XRD, dec!("3"))
+        .try_deposit_entire_worktop_or_abort(account1, None)
+        .build();
+    let receipt = test_runner.preview_manifest(
+        manifest.clone(),
+        vec![public_key1.into(), public_key2.into()],
+        0,
+        PreviewFlags {
+            use_free_credit: true,
+            assume_all_signature_proofs: true,
+            skip_epoch_check: true,
+        },
+    );
+    receipt.expect_commit_success();
+    let receipt = VersionedTransactionReceipt::V1(receipt);
+    let receipt = ExecutionAnalysisTransactionReceipt::new(&receipt).unwrap();
+
+    // Act
+    let minimum_xrd_required =
+        execution::minimum_xrd_required_per_account(&manifest.instructions, &receipt);
+
+    // Assert
+    assert_eq!(
+        minimum_xrd_required.into_iter().collect::<Vec<_>>(),
+        vec![(account1, dec!("15"))]
+    );
+}
+
+#[test]
+fn execution_analysis_plugins_contribute_their_outputs() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key1, _, account1) = test_runner.new_account(true);
+    let (public_key2, _, account2) = test_runner.new_account(true);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account1, XRD, dec!("10"))
+        .take_from_worktop(XRD, dec!("10"), "bucket")
+        .try_deposit_or_abort(account2, None, "bucket")
+        .build();
+    let receipt = test_runner.preview_manifest(
+        manifest.clone(),
+        vec![public_key1.into(), public_key2.into()],
+        0,
+        PreviewFlags {
+            use_free_credit: true,
+            assume_all_signature_proofs: true,
+            skip_epoch_check: true,
+        },
+    );
+    receipt.expect_commit_success();
+    let receipt = VersionedTransactionReceipt::V1(receipt);
+    let receipt = ExecutionAnalysisTransactionReceipt::new(&receipt).unwrap();
+
+    let mut plugin = CallMethodCounterPlugin::default();
+
+    // Act
+    let analysis =
+        execution::analyze_with_plugins(&manifest.instructions, &receipt, &mut [&mut plugin])
+            .unwrap();
+    let analysis_without_plugins = execution::analyze(&manifest.instructions, &receipt).unwrap();
+
+    // Assert
+    assert_eq!(analysis.plugin_outputs.len(), 1);
+    assert_eq!(
+        analysis.plugin_outputs.get::<CallMethodCount>(),
+        Some(&CallMethodCount(3))
+    );
+    assert!(analysis_without_plugins.plugin_outputs.is_empty());
+    assert_eq!(
+        analysis.transaction_types,
+        analysis_without_plugins.transaction_types
+    );
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct CallMethodCount(usize);
+
+#[derive(Default)]
+struct CallMethodCounterPlugin(usize);
+
+impl InstructionVisitor for CallMethodCounterPlugin {
+    fn visit_call_method(
+        &mut self,
+        _: &DynamicGlobalAddress,
+        _: &str,
+        _: &ManifestValue,
+    ) -> Result<(), InstructionVisitorError> {
+        self.0 += 1;
+        Ok(())
+    }
+}
+
+impl ExecutionAnalysisPlugin for CallMethodCounterPlugin {
+    fn contribute(
+        &mut self,
+        _: &ExecutionAnalysisTransactionReceipt,
+        outputs: &mut ExecutionAnalysisPluginOutputs,
+    ) {
+        outputs.insert(CallMethodCount(self.0))
+    }
+}
+
+#[test]
+fn calls_to_registered_dex_components_are_classified_as_swaps() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_account(true);
+
+    let (pool, pool_unit) = {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                POOL_PACKAGE,
+                ONE_RESOURCE_POOL_BLUEPRINT_IDENT,
+                ONE_RESOURCE_POOL_INSTANTIATE_IDENT,
+                OneResourcePoolInstantiateManifestInput {
+                    owner_role: OwnerRole::None,
+                    pool_manager_rule: rule!(allow_all),
+                    resource_address: XRD,
+                    address_reservation: None,
+                },
+            )
+            .build();
+        let receipt = test_runner.execute_manifest_ignoring_fee(manifest, vec![]);
+        let commit = receipt.expect_commit_success();
+        (
+            commit.new_component_addresses()[0],
+            commit.new_resource_addresses()[0],
+        )
+    };
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, dec!("10"))
+        .take_from_worktop(XRD, dec!("10"), "bucket")
+        .call_method_with_name_lookup(pool, ONE_RESOURCE_POOL_CONTRIBUTE_IDENT, |lookup| {
+            (lookup.bucket("bucket"),)
+        })
+        .assert_worktop_contains(pool_unit, dec!("9"))
+        .try_deposit_batch_or_abort(account, None)
+        .build();
+    let receipt = test_runner.preview_manifest(
+        manifest.clone(),
+        vec![public_key.into()],
+        0,
+        PreviewFlags {
+            use_free_credit: true,
+            assume_all_signature_proofs: true,
+            skip_epoch_check: true,
+        },
+    );
+    receipt.expect_commit_success();
+    let receipt = VersionedTransactionReceipt::V1(receipt);
+    let receipt = ExecutionAnalysisTransactionReceipt::new(&receipt).unwrap();
+
+    let dex_registry = DexRegistry::new().register(DexVenue {
+        name: "Pool".to_owned(),
+        component_addresses: vec![pool],
+        swap_methods: vec![ONE_RESOURCE_POOL_CONTRIBUTE_IDENT.to_owned()],
+    });
+
+    // Act
+    let analysis = execution::analyze_with_dex_registry(
+        &manifest.instructions,
+        &receipt,
+        &dex_registry,
+        &mut [],
+    )
+    .unwrap();
+    let analysis_without_registry = execution::analyze(&manifest.instructions, &receipt).unwrap();
+
+    // Assert
+    let swaps = analysis
+        .transaction_types
+        .iter()
+        .find_map(|transaction_type| match transaction_type {
+            TransactionType::Swap(swap_transaction) => Some(swap_transaction.swaps.clone()),
+            _ => None,
+        })
+        .expect("Must be classified as a swap");
+    assert_eq!(
+        swaps,
+        vec![Swap {
+            instruction_index: 3,
+            venue: "Pool".to_owned(),
+            component_address: pool,
+            method_name: ONE_RESOURCE_POOL_CONTRIBUTE_IDENT.to_owned(),
+            input_resource: XRD,
+            input_amount: dec!("10"),
+            output_resource: pool_unit,
+            output_amount: dec!("10"),
+            minimum_output_amount: Some(dec!("9")),
+        }]
+    );
+    assert!(!analysis_without_registry
+        .transaction_types
+        .iter()
+        .any(|transaction_type| matches!(transaction_type, TransactionType::Swap(..))));
+}
+
 fn stake_and_unstake(
     test_runner: &mut DefaultTestRunner,
     account: ComponentAddress,
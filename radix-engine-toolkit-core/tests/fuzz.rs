@@ -0,0 +1,83 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+#![cfg(feature = "fuzz")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use proptest::prelude::*;
+use radix_engine_toolkit_core::functions::*;
+use radix_engine_toolkit_core::fuzz::*;
+use scrypto::prelude::*;
+
+proptest! {
+    #[test]
+    fn generated_manifest_values_survive_an_encode_decode_round_trip(
+        data in prop::collection::vec(any::<u8>(), 0..2048)
+    ) {
+        // Arrange
+        let ArbitraryManifestValue(value) =
+            ArbitraryManifestValue::arbitrary(&mut Unstructured::new(&data)).unwrap();
+
+        // Act
+        let encoded = manifest_encode(&value).unwrap();
+        let decoded = manifest_decode::<ManifestValue>(&encoded).unwrap();
+
+        // Assert
+        prop_assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn generated_instructions_survive_a_compile_decompile_round_trip(
+        data in prop::collection::vec(any::<u8>(), 0..2048)
+    ) {
+        // Arrange
+        let ArbitraryInstructions(instructions) =
+            ArbitraryInstructions::arbitrary(&mut Unstructured::new(&data)).unwrap();
+
+        // Act
+        let compiled = instructions::compile(&instructions).unwrap();
+        let decompiled = instructions::decompile(compiled).unwrap();
+
+        // Assert
+        prop_assert_eq!(decompiled, instructions);
+    }
+
+    #[test]
+    fn generated_intents_survive_a_compile_decompile_round_trip(
+        data in prop::collection::vec(any::<u8>(), 0..4096)
+    ) {
+        // Arrange
+        let ArbitraryIntent(intent) =
+            ArbitraryIntent::arbitrary(&mut Unstructured::new(&data)).unwrap();
+
+        // Act
+        let compiled = intent::compile(&intent).unwrap();
+        let decompiled = intent::decompile(compiled).unwrap();
+
+        // Assert
+        prop_assert_eq!(decompiled, intent);
+    }
+}
+
+#[test]
+fn generation_from_a_seed_is_deterministic() {
+    // Act
+    let first = intent_from_seed(42).unwrap();
+    let second = intent_from_seed(42).unwrap();
+
+    // Assert
+    assert_eq!(first, second);
+}
@@ -15,12 +15,15 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use radix_engine::system::system_modules::execution_trace::ResourceSpecifier;
 use radix_engine_interface::blueprints::account::{
     AccountAddAuthorizedDepositorInput, ACCOUNT_ADD_AUTHORIZED_DEPOSITOR,
 };
 use radix_engine_toolkit_core::instruction_visitor::{
     core::traverser::traverse,
-    visitors::transaction_type::general_transaction_visitor::GeneralTransactionTypeVisitor,
+    visitors::transaction_type::general_transaction_visitor::{
+        GeneralTransactionTypeVisitor, PresentedProof,
+    },
 };
 use scrypto::prelude::*;
 use scrypto_unit::*;
@@ -60,6 +63,56 @@ fn account_create_proof_method_is_allowed_in_general_transaction() {
     assert!(visitor.output().is_some())
 }
 
+#[test]
+fn proofs_created_from_accounts_are_reported_as_presented_to_called_components() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+    let (pk, _, account) = test_runner.new_account(true);
+
+    let manifest = ManifestBuilder::new()
+        .create_proof_from_account_of_amount(account, XRD, 1)
+        .call_method(FAUCET, "free", manifest_args!())
+        .deposit_batch(account)
+        .build();
+    let receipt = test_runner.preview_manifest(
+        manifest.clone(),
+        vec![pk.into()],
+        Default::default(),
+        PreviewFlags {
+            use_free_credit: true,
+            assume_all_signature_proofs: true,
+            skip_epoch_check: true,
+        },
+    );
+
+    // Act
+    let mut visitor = GeneralTransactionTypeVisitor::new(
+        receipt
+            .expect_commit_success()
+            .execution_trace
+            .as_ref()
+            .unwrap(),
+    );
+    traverse(&manifest.instructions, &mut [&mut visitor]).unwrap();
+
+    // Assert
+    let (_, _, account_created_proofs, presented_proofs) = visitor.output().unwrap();
+    assert_eq!(
+        account_created_proofs.get(&account),
+        Some(&vec![ResourceSpecifier::Amount(XRD, dec!("1"))])
+    );
+    assert_eq!(
+        presented_proofs,
+        vec![PresentedProof {
+            instruction_index: 1,
+            component_address: FAUCET.into(),
+            method_name: "free".to_owned(),
+            account,
+            proof: ResourceSpecifier::Amount(XRD, dec!("1")),
+        }]
+    );
+}
+
 #[test]
 fn account_burn_resources_method_is_disallowed_in_general_transaction() {
     // Arrange
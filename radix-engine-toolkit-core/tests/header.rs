@@ -0,0 +1,46 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_core::functions::header::*;
+use scrypto::prelude::*;
+
+#[test]
+fn epoch_range_covers_the_validity_duration() {
+    // Act
+    let epoch_range = epoch_range_from_validity_duration(Epoch::of(100), 300, 30 * 60);
+
+    // Assert
+    assert_eq!(epoch_range, Some((Epoch::of(100), Epoch::of(107))));
+}
+
+#[test]
+fn validity_duration_can_be_obtained_from_epoch_range() {
+    // Act
+    let validity_duration = validity_duration_from_epoch_range(Epoch::of(100), Epoch::of(107), 300);
+
+    // Assert
+    assert_eq!(validity_duration, Some((30 * 60, 35 * 60)));
+}
+
+#[test]
+fn epoch_range_of_a_zero_epoch_duration_is_none() {
+    // Act
+    let epoch_range = epoch_range_from_validity_duration(Epoch::of(100), 0, 30 * 60);
+
+    // Assert
+    assert_eq!(epoch_range, None);
+}
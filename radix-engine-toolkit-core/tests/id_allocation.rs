@@ -0,0 +1,148 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_interface::blueprints::account::*;
+use radix_engine_toolkit_core::functions::manifest::*;
+use radix_engine_toolkit_core::instruction_visitor::visitors::id_allocation_visitor::*;
+use scrypto::prelude::*;
+use transaction::prelude::*;
+
+mod test_data;
+
+#[test]
+fn consumed_buckets_and_dropped_proofs_have_no_diagnostics() {
+    // Arrange
+    let instructions = vec![
+        InstructionV1::TakeAllFromWorktop {
+            resource_address: XRD,
+        },
+        InstructionV1::CreateProofFromBucketOfAll {
+            bucket_id: ManifestBucket(0),
+        },
+        InstructionV1::CloneProof {
+            proof_id: ManifestProof(0),
+        },
+        InstructionV1::DropProof {
+            proof_id: ManifestProof(0),
+        },
+        InstructionV1::PushToAuthZone {
+            proof_id: ManifestProof(1),
+        },
+        InstructionV1::DropAuthZoneProofs,
+        InstructionV1::DropAllProofs,
+        deposit(ManifestBucket(0)),
+    ];
+
+    // Act
+    let diagnostics = validate_id_allocation(&instructions);
+
+    // Assert
+    assert_eq!(diagnostics, vec![]);
+}
+
+#[test]
+fn ids_used_before_creation_or_after_consumption_are_reported_at_their_instruction() {
+    // Arrange
+    let instructions = vec![
+        deposit(ManifestBucket(0)),
+        InstructionV1::TakeAllFromWorktop {
+            resource_address: XRD,
+        },
+        deposit(ManifestBucket(0)),
+        InstructionV1::BurnResource {
+            bucket_id: ManifestBucket(0),
+        },
+        InstructionV1::DropProof {
+            proof_id: ManifestProof(0),
+        },
+    ];
+
+    // Act
+    let diagnostics = validate_id_allocation(&instructions);
+
+    // Assert
+    assert_eq!(
+        diagnostics,
+        vec![
+            IdAllocationDiagnostic {
+                instruction_index: 0,
+                kind: IdAllocationDiagnosticKind::BucketNotYetCreated(ManifestBucket(0))
+            },
+            IdAllocationDiagnostic {
+                instruction_index: 3,
+                kind: IdAllocationDiagnosticKind::BucketAlreadyConsumed {
+                    bucket: ManifestBucket(0),
+                    consumed_at: 2
+                }
+            },
+            IdAllocationDiagnostic {
+                instruction_index: 4,
+                kind: IdAllocationDiagnosticKind::ProofNotYetCreated(ManifestProof(0))
+            },
+        ]
+    );
+}
+
+#[test]
+fn buckets_locked_by_proofs_and_ids_left_alive_are_reported() {
+    // Arrange
+    let instructions = vec![
+        InstructionV1::TakeAllFromWorktop {
+            resource_address: XRD,
+        },
+        InstructionV1::TakeAllFromWorktop {
+            resource_address: XRD,
+        },
+        InstructionV1::CreateProofFromBucketOfAll {
+            bucket_id: ManifestBucket(0),
+        },
+        deposit(ManifestBucket(0)),
+    ];
+
+    // Act
+    let diagnostics = validate_id_allocation(&instructions);
+
+    // Assert
+    assert_eq!(
+        diagnostics,
+        vec![
+            IdAllocationDiagnostic {
+                instruction_index: 3,
+                kind: IdAllocationDiagnosticKind::BucketLockedByProofs {
+                    bucket: ManifestBucket(0),
+                    proofs: vec![ManifestProof(0)]
+                }
+            },
+            IdAllocationDiagnostic {
+                instruction_index: 1,
+                kind: IdAllocationDiagnosticKind::BucketNeverConsumed(ManifestBucket(1))
+            },
+            IdAllocationDiagnostic {
+                instruction_index: 2,
+                kind: IdAllocationDiagnosticKind::ProofNeverDropped(ManifestProof(0))
+            },
+        ]
+    );
+}
+
+fn deposit(bucket: ManifestBucket) -> InstructionV1 {
+    InstructionV1::CallMethod {
+        address: DynamicGlobalAddress::Static(test_data::account1().into()),
+        method_name: ACCOUNT_DEPOSIT_IDENT.to_owned(),
+        args: manifest_args!(bucket).into(),
+    }
+}
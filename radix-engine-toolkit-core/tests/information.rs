@@ -21,3 +21,15 @@ use radix_engine_toolkit_core::functions::information;
 fn information_can_be_obtained() {
     information::information();
 }
+
+#[test]
+fn methods_that_require_auth_include_withdrawals_but_not_deposits_into_accounts() {
+    // Act
+    let methods = information::methods_that_require_auth();
+
+    // Assert
+    assert!(methods.account.contains(&"withdraw".to_owned()));
+    assert!(!methods.account.contains(&"try_deposit_or_abort".to_owned()));
+    assert!(methods.metadata.contains(&"set".to_owned()));
+    assert!(methods.account.windows(2).all(|pair| pair[0] < pair[1]));
+}
@@ -0,0 +1,82 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_core::functions::instructions::*;
+use scrypto::prelude::*;
+use transaction::manifest::decompile;
+use transaction::prelude::*;
+
+#[test]
+fn instructions_are_mapped_to_the_lines_they_span() {
+    // Arrange
+    let manifest = r#"# Leading comment
+CALL_METHOD
+    Address("component_sim1cptxxxxxxxxxfaucetxxxxxxxxx000527798379xxxxxxxxxhkrefh")
+    "free"
+;
+
+DROP_ALL_PROOFS; # Inline
+CALL_METHOD Address("component_sim1cptxxxxxxxxxfaucetxxxxxxxxx000527798379xxxxxxxxxhkrefh") "not;
+the end";
+"#;
+
+    // Act
+    let line_ranges = instruction_line_ranges(manifest);
+
+    // Assert
+    assert_eq!(
+        line_ranges,
+        vec![
+            InstructionLineRange {
+                instruction_index: 0,
+                first_line: 1,
+                last_line: 4,
+            },
+            InstructionLineRange {
+                instruction_index: 1,
+                first_line: 6,
+                last_line: 6,
+            },
+            InstructionLineRange {
+                instruction_index: 2,
+                first_line: 7,
+                last_line: 8,
+            },
+        ]
+    );
+}
+
+#[test]
+fn every_decompiled_instruction_has_a_line_range() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET, 10)
+        .call_method(FAUCET, "free", manifest_args!())
+        .take_all_from_worktop(XRD, "bucket")
+        .return_to_worktop("bucket")
+        .build();
+    let string = decompile(&manifest.instructions, &NetworkDefinition::simulator()).unwrap();
+
+    // Act
+    let line_ranges = instruction_line_ranges(&string);
+
+    // Assert
+    assert_eq!(line_ranges.len(), manifest.instructions.len());
+    assert!(line_ranges
+        .windows(2)
+        .all(|pair| pair[0].last_line < pair[1].first_line));
+}
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use scrypto::prelude::*;
 use transaction::validation::ValidationConfig;
 mod test_data;
 
@@ -71,3 +72,200 @@ fn intent_can_be_statically_validated() {
     // Assert
     assert!(validation_result.is_ok())
 }
+
+#[test]
+fn nonce_derived_from_intent_does_not_depend_on_header_nonce() {
+    // Arrange
+    let mut intent = test_data::intent();
+    let key = [0x01, 0x02, 0x03];
+    let nonce1 =
+        radix_engine_toolkit_core::functions::intent::nonce_from_intent(&intent, &key, 0).unwrap();
+
+    // Act
+    intent.header.nonce = intent.header.nonce.wrapping_add(1);
+    let nonce2 =
+        radix_engine_toolkit_core::functions::intent::nonce_from_intent(&intent, &key, 0).unwrap();
+
+    // Assert
+    assert_eq!(nonce1, nonce2)
+}
+
+#[test]
+fn nonce_derived_from_intent_differs_between_attempts() {
+    // Arrange
+    let intent = test_data::intent();
+    let key = [0x01, 0x02, 0x03];
+
+    // Act
+    let nonce1 =
+        radix_engine_toolkit_core::functions::intent::nonce_from_intent(&intent, &key, 0).unwrap();
+    let nonce2 =
+        radix_engine_toolkit_core::functions::intent::nonce_from_intent(&intent, &key, 1).unwrap();
+
+    // Assert
+    assert_ne!(nonce1, nonce2)
+}
+
+#[test]
+fn transaction_tracker_partitions_wrap_around() {
+    // Arrange
+    let partitioning =
+        radix_engine_toolkit_core::functions::intent::TransactionTrackerPartitioning {
+            start_epoch: Epoch::of(1000),
+            start_partition: 250,
+            partition_range_start_inclusive: 65,
+            partition_range_end_inclusive: 255,
+            epochs_per_partition: 100,
+        };
+
+    // Act
+    let key = radix_engine_toolkit_core::functions::intent::transaction_tracker_key(
+        &Hash([0x01; 32]),
+        Epoch::of(1650),
+        &partitioning,
+    );
+
+    // Assert
+    assert_eq!(
+        key.map(|key| key.partition_number),
+        Some(PartitionNumber(65))
+    )
+}
+
+#[test]
+fn transaction_tracker_key_is_not_derived_for_untracked_epochs() {
+    // Arrange
+    let partitioning =
+        radix_engine_toolkit_core::functions::intent::TransactionTrackerPartitioning {
+            start_epoch: Epoch::of(1000),
+            start_partition: 65,
+            partition_range_start_inclusive: 65,
+            partition_range_end_inclusive: 255,
+            epochs_per_partition: 100,
+        };
+
+    // Act
+    let key = radix_engine_toolkit_core::functions::intent::transaction_tracker_key(
+        &Hash([0x01; 32]),
+        Epoch::of(999),
+        &partitioning,
+    );
+
+    // Assert
+    assert_eq!(key, None)
+}
+
+#[test]
+fn required_signing_keys_excludes_the_notary_when_it_is_a_signatory() {
+    // Arrange
+    let intent = test_data::intent();
+    let candidate_public_keys = [
+        test_data::private_key1().public_key().into(),
+        test_data::private_key2().public_key().into(),
+    ];
+
+    // Act
+    let required_signing_keys = radix_engine_toolkit_core::functions::intent::required_signing_keys(
+        &intent,
+        &candidate_public_keys,
+    );
+
+    // Assert
+    assert!(required_signing_keys.is_empty())
+}
+
+#[test]
+fn required_signing_keys_includes_the_notary_when_it_is_not_a_signatory() {
+    // Arrange
+    let mut intent = test_data::intent();
+    intent.header.notary_is_signatory = false;
+    let notary_public_key = PublicKey::from(test_data::private_key1().public_key());
+    let candidate_public_keys = [
+        notary_public_key,
+        test_data::private_key2().public_key().into(),
+    ];
+
+    // Act
+    let required_signing_keys = radix_engine_toolkit_core::functions::intent::required_signing_keys(
+        &intent,
+        &candidate_public_keys,
+    );
+
+    // Assert
+    assert_eq!(required_signing_keys, vec![notary_public_key])
+}
+
+#[test]
+fn comparing_an_intent_with_itself_finds_no_differences() {
+    // Arrange
+    let intent = test_data::intent();
+
+    // Act
+    let comparison = radix_engine_toolkit_core::functions::intent::compare(&intent, &intent);
+
+    // Assert
+    assert!(comparison.is_identical());
+}
+
+#[test]
+fn comparing_intents_reports_changed_header_fields_and_message() {
+    // Arrange
+    let intent_a = test_data::intent();
+    let mut intent_b = intent_a.clone();
+    intent_b.header.nonce = intent_a.header.nonce.wrapping_add(1);
+    intent_b.header.tip_percentage = intent_a.header.tip_percentage + 1;
+    intent_b.message = transaction::model::MessageV1::Plaintext(
+        transaction::model::PlaintextMessageV1::text("Updated"),
+    );
+
+    // Act
+    let comparison = radix_engine_toolkit_core::functions::intent::compare(&intent_a, &intent_b);
+
+    // Assert
+    assert_eq!(
+        comparison.changed_header_fields,
+        vec![
+            radix_engine_toolkit_core::functions::intent::HeaderField::Nonce,
+            radix_engine_toolkit_core::functions::intent::HeaderField::TipPercentage,
+        ]
+    );
+    assert!(comparison.message_changed);
+}
+
+#[test]
+fn comparing_intents_reports_added_and_removed_instructions() {
+    // Arrange
+    use radix_engine_toolkit_core::functions::intent::InstructionDifference;
+
+    let intent_a = test_data::intent();
+    let mut intent_b = intent_a.clone();
+    intent_b.instructions.0.remove(0);
+    intent_b
+        .instructions
+        .0
+        .push(transaction::model::InstructionV1::DropAllProofs);
+
+    // Act
+    let comparison = radix_engine_toolkit_core::functions::intent::compare(&intent_a, &intent_b);
+
+    // Assert
+    let length = intent_a.instructions.0.len();
+    assert_eq!(
+        comparison.instruction_differences.first(),
+        Some(&InstructionDifference::Removed { index_a: 0 })
+    );
+    assert_eq!(
+        comparison.instruction_differences.last(),
+        Some(&InstructionDifference::Added {
+            index_b: length - 1
+        })
+    );
+    assert_eq!(
+        comparison
+            .instruction_differences
+            .iter()
+            .filter(|difference| matches!(difference, InstructionDifference::Unchanged { .. }))
+            .count(),
+        length - 1
+    );
+}
@@ -0,0 +1,92 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_core::functions::execution::*;
+use radix_engine_toolkit_core::functions::lint::*;
+use scrypto::prelude::*;
+
+mod test_data;
+
+#[test]
+fn dust_deposit_from_look_alike_of_known_counterparty_is_flagged_as_address_poisoning() {
+    // Arrange
+    let movements = movements(test_data::account2(), test_data::account1(), dec!("0.001"));
+    let configuration = configuration();
+
+    // Act
+    let findings = lint_resource_movements(&movements, &configuration);
+
+    // Assert
+    assert_eq!(
+        findings,
+        vec![LintFinding::AddressPoisoning(AddressPoisoningFinding {
+            sender: test_data::account2(),
+            resembled_counterparty: test_data::account3(),
+            recipients: vec![test_data::account1()]
+        })]
+    )
+}
+
+#[test]
+fn deposits_above_the_dust_threshold_are_not_flagged() {
+    // Arrange
+    let movements = movements(test_data::account2(), test_data::account1(), dec!("10"));
+    let configuration = configuration();
+
+    // Act
+    let findings = lint_resource_movements(&movements, &configuration);
+
+    // Assert
+    assert!(findings.is_empty())
+}
+
+#[test]
+fn deposits_from_known_counterparties_are_not_flagged() {
+    // Arrange
+    let movements = movements(test_data::account3(), test_data::account1(), dec!("0.001"));
+    let configuration = configuration();
+
+    // Act
+    let findings = lint_resource_movements(&movements, &configuration);
+
+    // Assert
+    assert!(findings.is_empty())
+}
+
+fn movements(
+    sender: ComponentAddress,
+    recipient: ComponentAddress,
+    amount: Decimal,
+) -> AccountResourceMovements {
+    let amounts = || [(XRD, amount)].into_iter().collect::<HashMap<_, _>>();
+    AccountResourceMovements {
+        withdraws: [(sender, amounts())].into_iter().collect(),
+        deposits: [(recipient, amounts())].into_iter().collect(),
+    }
+}
+
+/// A configuration where any two accounts of the same network look alike.
+fn configuration() -> LintConfiguration {
+    LintConfiguration {
+        network_id: 0x01,
+        owned_accounts: vec![test_data::account1()],
+        known_counterparties: vec![test_data::account3()],
+        dust_threshold: dec!("0.01"),
+        look_alike_leading_characters: 0,
+        look_alike_trailing_characters: 0,
+    }
+}
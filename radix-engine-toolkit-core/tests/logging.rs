@@ -0,0 +1,105 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_core::functions::logging::*;
+use scrypto::address::AddressBech32Encoder;
+use scrypto::prelude::*;
+use transaction::model::*;
+mod test_data;
+
+#[test]
+fn notarized_transaction_log_string_truncates_signatures() {
+    // Arrange
+    let transaction = test_data::notarized_transaction();
+    let SignatureV1::Secp256k1(notary_signature) = transaction.notary_signature.0 else {
+        panic!("The notary of the test transaction has a Secp256k1 key")
+    };
+    let notary_signature = hex(&notary_signature.0);
+
+    // Act
+    let log_string = transaction.to_log_string(0x01);
+
+    // Assert
+    assert!(!log_string.contains(&notary_signature));
+    assert!(log_string.contains(&format!(
+        "notary_signature: Secp256k1({}...)",
+        &notary_signature[..TRUNCATED_BYTES_LENGTH * 2]
+    )));
+}
+
+#[test]
+fn intent_log_string_redacts_plaintext_messages() {
+    // Arrange
+    let mut intent = test_data::intent();
+    intent.message = MessageV1::Plaintext(PlaintextMessageV1 {
+        mime_type: "text/plain".to_owned(),
+        message: MessageContentsV1::String("Rent for March".to_owned()),
+    });
+
+    // Act
+    let log_string = intent.to_log_string(0x01);
+
+    // Assert
+    assert!(!log_string.contains("Rent for March"));
+    assert!(log_string.contains("message: Plaintext(<redacted 14 bytes>)"));
+}
+
+#[test]
+fn log_display_and_to_log_string_are_the_same() {
+    // Arrange
+    let signed_intent = test_data::signed_intent();
+
+    // Act
+    let displayed = format!("{}", signed_intent.log_display(0x01));
+    let log_string = signed_intent.to_log_string(0x01);
+
+    // Assert
+    assert_eq!(displayed, log_string);
+}
+
+#[test]
+fn addresses_are_shortened_to_their_ends() {
+    // Arrange
+    let address = AddressBech32Encoder::new(&NetworkDefinition::mainnet())
+        .encode(test_data::account1().as_node_id().as_bytes())
+        .unwrap();
+
+    // Act
+    let shortened = shorten_address(&address);
+
+    // Assert
+    assert!(shortened.starts_with(&address[..address.find('1').unwrap() + 5]));
+    assert!(shortened.ends_with(&address[address.len() - SHORTENED_ADDRESS_TRAILING_CHARACTERS..]));
+    assert!(shortened.contains("..."));
+    assert!(shortened.len() < address.len());
+}
+
+#[test]
+fn strings_too_short_to_be_shortened_are_kept_as_they_are() {
+    // Arrange
+    let address = "account_rdx1abcdef";
+
+    // Act
+    let shortened = shorten_address(address);
+
+    // Assert
+    assert_eq!(shortened, address);
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
@@ -15,10 +15,13 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use radix_engine::blueprints::native_schema::*;
 use radix_engine_interface::blueprints::access_controller::*;
 use radix_engine_interface::blueprints::account::*;
 use radix_engine_toolkit_core::functions::manifest::*;
+use radix_engine_toolkit_core::instruction_visitor::visitors::fee_locks_visitor::*;
 use scrypto::prelude::*;
+use scrypto_unit::*;
 use transaction::prelude::*;
 
 mod test_data;
@@ -1001,14 +1004,680 @@ fn assertions_are_added_at_expected_indices_even_with_other_things_need_to_be_ad
     assert_eq!(modified_manifest.instructions, expected_instructions);
 }
 
+#[test]
+fn call_arguments_can_be_encoded_when_valid() {
+    // Arrange
+    let arguments = vec![
+        to_manifest_value(&XRD).unwrap(),
+        to_manifest_value(&dec!("10")).unwrap(),
+    ];
+
+    // Act
+    let encoded = encode_call_arguments(
+        &ACCOUNT_PACKAGE_DEFINITION,
+        ACCOUNT_BLUEPRINT,
+        ACCOUNT_WITHDRAW_IDENT,
+        arguments,
+    );
+
+    // Assert
+    assert_eq!(
+        encoded.unwrap(),
+        manifest_encode(&AccountWithdrawInput {
+            resource_address: XRD,
+            amount: dec!("10"),
+        })
+        .unwrap()
+    );
+}
+
+#[test]
+fn call_arguments_encoding_reports_invalid_arguments() {
+    // Arrange
+    let arguments = vec![
+        to_manifest_value(&XRD).unwrap(),
+        to_manifest_value(&"10".to_owned()).unwrap(),
+    ];
+
+    // Act
+    let encoded = encode_call_arguments(
+        &ACCOUNT_PACKAGE_DEFINITION,
+        ACCOUNT_BLUEPRINT,
+        ACCOUNT_WITHDRAW_IDENT,
+        arguments,
+    );
+
+    // Assert
+    let Err(CallArgumentsEncodeError::InvalidArguments(errors)) = encoded else {
+        panic!("Expected invalid arguments error, got {:?}", encoded);
+    };
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].index, 1);
+    assert_eq!(errors[0].name, Some("amount".to_owned()));
+}
+
+#[test]
+fn call_arguments_encoding_fails_on_invalid_number_of_arguments() {
+    // Act
+    let encoded = encode_call_arguments(
+        &ACCOUNT_PACKAGE_DEFINITION,
+        ACCOUNT_BLUEPRINT,
+        ACCOUNT_WITHDRAW_IDENT,
+        vec![],
+    );
+
+    // Assert
+    assert!(matches!(
+        encoded,
+        Err(CallArgumentsEncodeError::InvalidNumberOfArguments {
+            expected: 2,
+            actual: 0
+        })
+    ));
+}
+
 fn account(id: u64) -> ComponentAddress {
     ComponentAddress::virtual_account_from_public_key(
         &Secp256k1PrivateKey::from_u64(id).unwrap().public_key(),
     )
 }
 
+fn vault(id: u64) -> InternalAddress {
+    let mut bytes = account(id).as_node_id().0;
+    bytes[0] = EntityType::InternalFungibleVault as u8;
+    InternalAddress::new_or_panic(bytes)
+}
+
 fn access_controller(id: u64) -> ComponentAddress {
     let mut bytes = account(id).as_node_id().0;
     bytes[0] = EntityType::GlobalAccessController as u8;
     ComponentAddress::new_or_panic(bytes)
 }
+
+#[test]
+fn faucet_free_xrd_manifest_can_be_executed() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_account(true);
+
+    // Act
+    let manifest = faucet_free_xrd(account);
+
+    // Assert
+    assert_eq!(manifest.instructions.len(), 3);
+    test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+}
+
+#[test]
+fn guarantees_are_inserted_at_their_instruction_index() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .call_method(FAUCET, "free", manifest_args!())
+        .call_method(FAUCET, "free", manifest_args!())
+        .build();
+
+    // Act
+    let manifest = add_guarantees(
+        &manifest,
+        vec![
+            Guarantee {
+                instruction_index: 1,
+                resource_address: XRD,
+                amount: dec!("10000"),
+            },
+            Guarantee {
+                instruction_index: 2,
+                resource_address: XRD,
+                amount: dec!("20000"),
+            },
+        ],
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(manifest.instructions.len(), 4);
+    assert_eq!(
+        manifest.instructions[1],
+        InstructionV1::AssertWorktopContains {
+            resource_address: XRD,
+            amount: dec!("10000")
+        }
+    );
+    assert_eq!(
+        manifest.instructions[3],
+        InstructionV1::AssertWorktopContains {
+            resource_address: XRD,
+            amount: dec!("20000")
+        }
+    );
+}
+
+#[test]
+fn existing_guarantees_are_updated_in_place() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .call_method(FAUCET, "free", manifest_args!())
+        .assert_worktop_contains(XRD, dec!("10000"))
+        .build();
+
+    // Act
+    let manifest = add_guarantees(
+        &manifest,
+        vec![Guarantee {
+            instruction_index: 1,
+            resource_address: XRD,
+            amount: dec!("9000"),
+        }],
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(manifest.instructions.len(), 2);
+    assert_eq!(
+        manifest.instructions[1],
+        InstructionV1::AssertWorktopContains {
+            resource_address: XRD,
+            amount: dec!("9000")
+        }
+    );
+}
+
+#[test]
+fn setting_lock_fee_replaces_an_existing_lock_fee() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account(1), dec!("10"))
+        .withdraw_from_account(account(1), XRD, dec!("100"))
+        .build();
+
+    // Act
+    let manifest = set_lock_fee(&manifest, account(2), dec!("20")).unwrap();
+
+    // Assert
+    assert_eq!(manifest.instructions.len(), 2);
+    assert_eq!(
+        manifest.instructions[0],
+        InstructionV1::CallMethod {
+            address: DynamicGlobalAddress::Static(account(2).into()),
+            method_name: ACCOUNT_LOCK_FEE_IDENT.to_owned(),
+            args: to_manifest_value_and_unwrap!(&AccountLockFeeInput { amount: dec!("20") })
+        }
+    );
+}
+
+#[test]
+fn setting_lock_fee_on_another_account_converts_lock_fee_and_withdraw_back_to_withdraw() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_fee_and_withdraw(account(1), dec!("10"), XRD, dec!("100"))
+        .build();
+
+    // Act
+    let manifest = set_lock_fee(&manifest, account(2), dec!("20")).unwrap();
+
+    // Assert
+    assert_eq!(manifest.instructions.len(), 2);
+    assert_eq!(
+        manifest.instructions[1],
+        InstructionV1::CallMethod {
+            address: DynamicGlobalAddress::Static(account(1).into()),
+            method_name: ACCOUNT_WITHDRAW_IDENT.to_owned(),
+            args: to_manifest_value_and_unwrap!(&AccountWithdrawInput {
+                resource_address: XRD,
+                amount: dec!("100")
+            })
+        }
+    );
+}
+
+#[test]
+fn removing_instructions_renumbers_the_buckets_of_later_instructions() {
+    // Arrange
+    let manifest = TransactionManifestV1 {
+        instructions: vec![
+            InstructionV1::TakeFromWorktop {
+                resource_address: XRD,
+                amount: dec!("10"),
+            },
+            InstructionV1::ReturnToWorktop {
+                bucket_id: ManifestBucket(0),
+            },
+            InstructionV1::TakeFromWorktop {
+                resource_address: XRD,
+                amount: dec!("20"),
+            },
+            InstructionV1::BurnResource {
+                bucket_id: ManifestBucket(1),
+            },
+        ],
+        blobs: Default::default(),
+    };
+
+    // Act
+    let manifest = modify_instructions(
+        &manifest,
+        vec![
+            InstructionModification::Remove { index: 1 },
+            InstructionModification::Remove { index: 0 },
+        ],
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(manifest.instructions.len(), 2);
+    assert_eq!(
+        manifest.instructions[1],
+        InstructionV1::BurnResource {
+            bucket_id: ManifestBucket(0),
+        }
+    );
+}
+
+#[test]
+fn inserting_instructions_renumbers_the_buckets_of_later_instructions() {
+    // Arrange
+    let manifest = TransactionManifestV1 {
+        instructions: vec![
+            InstructionV1::TakeFromWorktop {
+                resource_address: XRD,
+                amount: dec!("20"),
+            },
+            InstructionV1::BurnResource {
+                bucket_id: ManifestBucket(0),
+            },
+        ],
+        blobs: Default::default(),
+    };
+
+    // Act
+    let manifest = modify_instructions(
+        &manifest,
+        vec![
+            InstructionModification::Insert {
+                index: 0,
+                instruction: InstructionV1::TakeFromWorktop {
+                    resource_address: XRD,
+                    amount: dec!("10"),
+                },
+            },
+            InstructionModification::Insert {
+                index: 1,
+                instruction: InstructionV1::ReturnToWorktop {
+                    bucket_id: ManifestBucket(0),
+                },
+            },
+        ],
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(manifest.instructions.len(), 4);
+    assert_eq!(
+        manifest.instructions[3],
+        InstructionV1::BurnResource {
+            bucket_id: ManifestBucket(1),
+        }
+    );
+}
+
+#[test]
+fn removing_an_instruction_whose_bucket_is_used_later_fails() {
+    // Arrange
+    let manifest = TransactionManifestV1 {
+        instructions: vec![
+            InstructionV1::TakeFromWorktop {
+                resource_address: XRD,
+                amount: dec!("20"),
+            },
+            InstructionV1::BurnResource {
+                bucket_id: ManifestBucket(0),
+            },
+        ],
+        blobs: Default::default(),
+    };
+
+    // Act
+    let result = modify_instructions(
+        &manifest,
+        vec![InstructionModification::Remove { index: 0 }],
+    );
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(ManifestModificationError::RemovedIdInUse {
+            instruction_index: 0
+        })
+    );
+}
+
+#[test]
+fn manifest_within_policy_has_no_violations() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account(1), dec!("10"))
+        .withdraw_from_account(account(1), XRD, dec!("100"))
+        .deposit_batch(account(2))
+        .build();
+    let policy = ManifestPolicy {
+        allowed_packages: Some(Default::default()),
+        allowed_components: Some([account(1), account(2)].into_iter().collect()),
+        allowed_resources: Some([XRD].into_iter().collect()),
+        max_xrd_spend: Some(dec!("110")),
+    };
+
+    // Act
+    let violations = enforce_policy(&manifest, &policy);
+
+    // Assert
+    assert_eq!(violations, vec![]);
+}
+
+#[test]
+fn manifest_outside_of_policy_has_violations() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account(1), dec!("10"))
+        .withdraw_from_account(account(1), XRD, dec!("100"))
+        .deposit_batch(account(2))
+        .build();
+    let policy = ManifestPolicy {
+        allowed_packages: None,
+        allowed_components: Some([account(1)].into_iter().collect()),
+        allowed_resources: None,
+        max_xrd_spend: Some(dec!("50")),
+    };
+
+    // Act
+    let violations = enforce_policy(&manifest, &policy);
+
+    // Assert
+    assert_eq!(
+        violations,
+        vec![
+            PolicyViolation::ComponentNotAllowed {
+                instruction_index: 2,
+                component_address: account(2)
+            },
+            PolicyViolation::MaxXrdSpendExceeded {
+                max_xrd_spend: dec!("50"),
+                xrd_spend: dec!("110")
+            }
+        ]
+    );
+}
+
+#[test]
+fn resource_method_calls_violate_policies_restricting_invocations() {
+    // Arrange
+    let manifest = TransactionManifestV1 {
+        instructions: vec![InstructionV1::CallMethod {
+            address: DynamicGlobalAddress::Static(XRD.into()),
+            method_name: "mint".to_owned(),
+            args: manifest_args!(dec!("100")).into(),
+        }],
+        blobs: Default::default(),
+    };
+    let policy = ManifestPolicy {
+        allowed_packages: None,
+        allowed_components: Some([account(1)].into_iter().collect()),
+        allowed_resources: None,
+        max_xrd_spend: None,
+    };
+
+    // Act
+    let violations = enforce_policy(&manifest, &policy);
+
+    // Assert
+    assert_eq!(
+        violations,
+        vec![PolicyViolation::ResourceMethodNotAllowed {
+            instruction_index: 0,
+            resource_address: XRD,
+            method_name: "mint".to_owned(),
+        }]
+    );
+}
+
+#[test]
+fn direct_vault_method_calls_violate_policies_restricting_resources() {
+    // Arrange
+    let manifest = TransactionManifestV1 {
+        instructions: vec![InstructionV1::CallDirectVaultMethod {
+            address: vault(1),
+            method_name: "recall".to_owned(),
+            args: manifest_args!(dec!("100")).into(),
+        }],
+        blobs: Default::default(),
+    };
+    let policy = ManifestPolicy {
+        allowed_packages: None,
+        allowed_components: None,
+        allowed_resources: Some([XRD].into_iter().collect()),
+        max_xrd_spend: None,
+    };
+
+    // Act
+    let violations = enforce_policy(&manifest, &policy);
+
+    // Assert
+    assert_eq!(
+        violations,
+        vec![PolicyViolation::DirectVaultMethodNotAllowed {
+            instruction_index: 0,
+            vault_address: vault(1),
+            method_name: "recall".to_owned(),
+        }]
+    );
+}
+
+#[test]
+fn fee_locks_split_across_accounts_are_reported_as_multiple_fee_payers() {
+    // Arrange
+    let instructions = lock_fee_instructions(&[
+        AccountFeeLock {
+            account: account(1),
+            amount: dec!("5"),
+            contingent: false,
+        },
+        AccountFeeLock {
+            account: account(2),
+            amount: dec!("3"),
+            contingent: true,
+        },
+        AccountFeeLock {
+            account: account(1),
+            amount: dec!("2"),
+            contingent: false,
+        },
+    ]);
+
+    // Act
+    let validation = validate_fee_locks(&instructions, dec!("10"));
+
+    // Assert
+    assert_eq!(
+        validation,
+        FeeLockValidation {
+            fee_payers: FeePayers::Multiple(vec![account(1), account(2)]),
+            total_lock: dec!("7"),
+            total_contingent_lock: dec!("3"),
+            shortfall: dec!("3"),
+        }
+    );
+    assert!(!validation.meets_target());
+}
+
+#[test]
+fn contingent_fee_locks_do_not_count_towards_the_target_fee() {
+    // Arrange
+    let instructions = lock_fee_instructions(&[
+        AccountFeeLock {
+            account: account(1),
+            amount: dec!("2"),
+            contingent: false,
+        },
+        AccountFeeLock {
+            account: account(1),
+            amount: dec!("10"),
+            contingent: true,
+        },
+    ]);
+
+    // Act
+    let validation = validate_fee_locks(&instructions, dec!("10"));
+
+    // Assert
+    assert_eq!(validation.total_lock, dec!("2"));
+    assert_eq!(validation.total_contingent_lock, dec!("10"));
+    assert_eq!(validation.shortfall, dec!("8"));
+    assert!(!validation.meets_target());
+}
+
+#[test]
+fn fee_locks_below_the_target_report_a_shortfall() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account(1), dec!("4"))
+        .withdraw_from_account(account(1), XRD, dec!("100"))
+        .deposit_batch(account(2))
+        .build();
+
+    // Act
+    let validation = validate_fee_locks(&manifest.instructions, dec!("10"));
+
+    // Assert
+    assert_eq!(validation.fee_payers, FeePayers::Single(account(1)));
+    assert_eq!(validation.shortfall, dec!("6"));
+    assert!(!validation.meets_target());
+}
+
+#[test]
+fn fee_paid_by_an_account_that_moves_no_assets_is_a_sponsored_fee() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account(1), dec!("10"))
+        .withdraw_from_account(account(2), XRD, dec!("100"))
+        .try_deposit_batch_or_abort(account(3))
+        .build();
+
+    // Act
+    let sponsorship = fee_sponsorship(&manifest.instructions);
+
+    // Assert
+    assert_eq!(sponsorship.fee_payers, vec![account(1)]);
+    assert_eq!(sponsorship.sponsors, vec![account(1)]);
+    assert!(sponsorship.asset_movers.contains(&account(2)));
+    assert!(sponsorship.asset_movers.contains(&account(3)));
+    assert!(sponsorship.is_sponsored());
+}
+
+#[test]
+fn fee_paid_by_the_withdrawing_account_is_not_a_sponsored_fee() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_fee_and_withdraw(account(1), dec!("10"), XRD, dec!("100"))
+        .try_deposit_batch_or_abort(account(2))
+        .build();
+
+    // Act
+    let sponsorship = fee_sponsorship(&manifest.instructions);
+
+    // Assert
+    assert_eq!(sponsorship.fee_payers, vec![account(1)]);
+    assert!(sponsorship.sponsors.is_empty());
+    assert!(!sponsorship.is_sponsored());
+}
+
+#[test]
+fn sponsoring_a_fee_separates_the_sponsor_signer_from_the_user_signers() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_fee_and_withdraw(account(1), dec!("10"), XRD, dec!("100"))
+        .try_deposit_batch_or_abort(account(2))
+        .build();
+
+    // Act
+    let sponsored = sponsor_fee(&manifest, account(3), dec!("20")).unwrap();
+
+    // Assert
+    assert_eq!(
+        fee_sponsorship(&sponsored.manifest.instructions).sponsors,
+        vec![account(3)]
+    );
+    assert_eq!(
+        sponsored
+            .sponsor_signers
+            .iter()
+            .map(|signer| signer.entity_address)
+            .collect::<Vec<_>>(),
+        vec![GlobalAddress::from(account(3))]
+    );
+    assert_eq!(
+        sponsored
+            .user_signers
+            .iter()
+            .map(|signer| signer.entity_address)
+            .collect::<Vec<_>>(),
+        vec![GlobalAddress::from(account(1))]
+    );
+}
+
+#[test]
+fn deduplication_key_ignores_the_order_of_signers() {
+    // Arrange
+    let manifest = test_data::manifest();
+    let signer1 = PublicKey::from(test_data::private_key1().public_key());
+    let signer2 = PublicKey::from(test_data::private_key2().public_key());
+
+    // Act
+    let key1 = radix_engine_toolkit_core::functions::manifest::deduplication_key(
+        &manifest,
+        &[signer1, signer2],
+        0x01,
+    )
+    .unwrap();
+    let key2 = radix_engine_toolkit_core::functions::manifest::deduplication_key(
+        &manifest,
+        &[signer2, signer1, signer2],
+        0x01,
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(key1, key2);
+}
+
+#[test]
+fn deduplication_key_differs_for_different_signers_or_networks() {
+    // Arrange
+    let manifest = test_data::manifest();
+    let signer1 = PublicKey::from(test_data::private_key1().public_key());
+    let signer2 = PublicKey::from(test_data::private_key2().public_key());
+
+    // Act
+    let key = radix_engine_toolkit_core::functions::manifest::deduplication_key(
+        &manifest,
+        &[signer1],
+        0x01,
+    )
+    .unwrap();
+    let key_with_other_signer = radix_engine_toolkit_core::functions::manifest::deduplication_key(
+        &manifest,
+        &[signer2],
+        0x01,
+    )
+    .unwrap();
+    let key_on_other_network = radix_engine_toolkit_core::functions::manifest::deduplication_key(
+        &manifest,
+        &[signer1],
+        0x02,
+    )
+    .unwrap();
+
+    // Assert
+    assert_ne!(key, key_with_other_signer);
+    assert_ne!(key, key_on_other_network);
+}
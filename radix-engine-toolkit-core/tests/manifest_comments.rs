@@ -0,0 +1,100 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_core::functions::instructions::*;
+use scrypto::prelude::*;
+use transaction::manifest::*;
+
+#[test]
+fn comments_are_extracted_with_the_instruction_they_are_attached_to() {
+    // Arrange
+    let manifest = format!(
+        r#"# Get some XRD
+CALL_METHOD
+    Address("{faucet}")
+    "free" # Not an inline comment
+;
+CALL_METHOD Address("{faucet}") "not # a comment;"; # Inline
+# Trailing
+"#,
+        faucet = faucet()
+    );
+
+    // Act
+    let comments = extract_comments(&manifest);
+
+    // Assert
+    assert_eq!(
+        comments,
+        vec![
+            ManifestComment {
+                instruction_index: 0,
+                text: " Get some XRD".to_owned(),
+                position: ManifestCommentPosition::Before,
+            },
+            ManifestComment {
+                instruction_index: 0,
+                text: " Not an inline comment".to_owned(),
+                position: ManifestCommentPosition::Before,
+            },
+            ManifestComment {
+                instruction_index: 1,
+                text: " Inline".to_owned(),
+                position: ManifestCommentPosition::Inline,
+            },
+            ManifestComment {
+                instruction_index: 2,
+                text: " Trailing".to_owned(),
+                position: ManifestCommentPosition::Before,
+            },
+        ]
+    )
+}
+
+#[test]
+fn comments_survive_compilation_and_decompilation_when_reattached() {
+    // Arrange
+    let manifest = format!(
+        r#"# Get some XRD
+CALL_METHOD Address("{faucet}") "free";
+CALL_METHOD Address("{faucet}") "free"; # Twice
+"#,
+        faucet = faucet()
+    );
+    let comments = extract_comments(&manifest);
+    let network_definition = NetworkDefinition::simulator();
+    let compiled = compile(&manifest, &network_definition, MockBlobProvider::new()).unwrap();
+    let decompiled = decompile(&compiled.instructions, &network_definition).unwrap();
+
+    // Act
+    let reattached = attach_comments(&decompiled, &comments);
+
+    // Assert
+    assert_eq!(extract_comments(&reattached), comments);
+    assert_eq!(
+        compile(&reattached, &network_definition, MockBlobProvider::new())
+            .unwrap()
+            .instructions,
+        compiled.instructions
+    );
+}
+
+fn faucet() -> String {
+    AddressBech32Encoder::for_simulator()
+        .encode(FAUCET.as_node_id().as_bytes())
+        .unwrap()
+}
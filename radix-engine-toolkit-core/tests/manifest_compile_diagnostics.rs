@@ -0,0 +1,124 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use radix_engine_toolkit_core::functions::instructions::*;
+use scrypto::prelude::*;
+use transaction::manifest::MockBlobProvider;
+
+#[test]
+fn misspelled_instruction_name_is_located_and_a_suggestion_is_made() {
+    // Arrange
+    let manifest = r#"CALL_METHOD
+    Address("component_sim1cptxxxxxxxxxfaucetxxxxxxxxx000527798379xxxxxxxxxhkrefh")
+    "free"
+;
+TAKE_ALL_FROM_WORKTOPP
+    Address("resource_sim1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxakj8n3")
+    Bucket("xrd")
+;
+"#;
+
+    // Act
+    let diagnostic = diagnose(manifest);
+
+    // Assert
+    assert_eq!(
+        diagnostic.span,
+        Some(ManifestSpan {
+            start: ManifestPosition { line: 4, column: 0 },
+            end: ManifestPosition {
+                line: 4,
+                column: 22
+            },
+        })
+    );
+    assert_eq!(diagnostic.token.as_deref(), Some("TAKE_ALL_FROM_WORKTOPP"));
+    assert_eq!(
+        diagnostic.expected,
+        vec![ManifestTokenClass::InstructionName]
+    );
+    assert_eq!(
+        diagnostic.suggestion.as_deref(),
+        Some("TAKE_ALL_FROM_WORKTOP")
+    );
+}
+
+#[test]
+fn misspelled_value_kind_is_located_and_a_suggestion_is_made() {
+    // Arrange
+    let manifest = r#"CALL_METHOD
+    Adress("component_sim1cptxxxxxxxxxfaucetxxxxxxxxx000527798379xxxxxxxxxhkrefh")
+    "free"
+;
+"#;
+
+    // Act
+    let diagnostic = diagnose(manifest);
+
+    // Assert
+    assert_eq!(
+        diagnostic.span,
+        Some(ManifestSpan {
+            start: ManifestPosition { line: 1, column: 4 },
+            end: ManifestPosition {
+                line: 1,
+                column: 10
+            },
+        })
+    );
+    assert_eq!(diagnostic.token.as_deref(), Some("Adress"));
+    assert_eq!(diagnostic.expected, vec![ManifestTokenClass::ValueKind]);
+    assert_eq!(diagnostic.suggestion.as_deref(), Some("Address"));
+}
+
+#[test]
+fn missing_semicolon_is_located_at_the_end_of_the_previous_instruction() {
+    // Arrange
+    let manifest = r#"DROP_ALL_PROOFS
+DROP_ALL_PROOFS;
+"#;
+
+    // Act
+    let diagnostic = diagnose(manifest);
+
+    // Assert
+    assert_eq!(
+        diagnostic.span,
+        Some(ManifestSpan {
+            start: ManifestPosition {
+                line: 0,
+                column: 15
+            },
+            end: ManifestPosition {
+                line: 0,
+                column: 15
+            },
+        })
+    );
+    assert_eq!(diagnostic.token.as_deref(), Some("DROP_ALL_PROOFS"));
+    assert_eq!(diagnostic.expected, vec![ManifestTokenClass::Semicolon]);
+    assert_eq!(diagnostic.suggestion.as_deref(), Some(";"));
+}
+
+fn diagnose(manifest: &str) -> ManifestCompileDiagnostic {
+    let error = transaction::manifest::compile(
+        manifest,
+        &NetworkDefinition::simulator(),
+        MockBlobProvider::new(),
+    )
+    .expect_err("Manifest must fail to compile");
+    diagnose_compile_error(manifest, &error)
+}
@@ -0,0 +1,96 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use radix_engine_toolkit_core::functions::manifest::*;
+
+#[test]
+fn includes_are_resolved_recursively() {
+    // Arrange
+    let manifest = r#"INCLUDE "lock_fee.rtm";
+CALL_METHOD Address("a") "free";
+"#;
+    let loader = |path: &str| match path {
+        "lock_fee.rtm" => {
+            Ok("INCLUDE \"faucet.rtm\";\nCALL_METHOD Address(\"a\") \"lock_fee\";".to_owned())
+        }
+        "faucet.rtm" => Ok("# The faucet\n".to_owned()),
+        _ => Err(()),
+    };
+
+    // Act
+    let resolved = resolve_includes(manifest, loader);
+
+    // Assert
+    assert_eq!(
+        resolved,
+        Ok(r#"# The faucet
+CALL_METHOD Address("a") "lock_fee";
+CALL_METHOD Address("a") "free";
+"#
+        .to_owned())
+    );
+}
+
+#[test]
+fn include_directives_in_string_literals_are_not_resolved() {
+    // Arrange
+    let manifest = "CALL_METHOD Address(\"a\") \"x\" \"multi\nINCLUDE \"fragment.rtm\";\n\";\n";
+
+    // Act
+    let resolved = resolve_includes(manifest, |_| Err(()));
+
+    // Assert
+    assert_eq!(resolved, Ok(manifest.to_owned()));
+}
+
+#[test]
+fn include_cycles_are_reported() {
+    // Arrange
+    let manifest = "INCLUDE \"a.rtm\";\n";
+    let loader = |path: &str| match path {
+        "a.rtm" => Ok::<_, ()>("INCLUDE \"b.rtm\";\n".to_owned()),
+        _ => Ok("INCLUDE \"a.rtm\";\n".to_owned()),
+    };
+
+    // Act
+    let resolved = resolve_includes(manifest, loader);
+
+    // Assert
+    assert_eq!(
+        resolved,
+        Err(ManifestIncludeError::IncludeCycle {
+            path: "a.rtm".to_owned()
+        })
+    );
+}
+
+#[test]
+fn malformed_include_directives_are_reported() {
+    // Arrange
+    let manifest = "CALL_METHOD Address(\"a\") \"free\";\nINCLUDE fragment.rtm;\n";
+
+    // Act
+    let resolved = resolve_includes(manifest, |_| Ok::<_, ()>(String::new()));
+
+    // Assert
+    assert_eq!(
+        resolved,
+        Err(ManifestIncludeError::InvalidDirective {
+            line_number: 2,
+            directive: "INCLUDE fragment.rtm;".to_owned()
+        })
+    );
+}
@@ -84,6 +84,33 @@ fn manifest_value_can_be_represented_as_a_string() {
     }
 }
 
+#[test]
+fn core_api_programmatic_json_can_be_encoded_as_a_manifest_value() {
+    // Arrange
+    let representation =
+        radix_engine_toolkit_core::functions::scrypto_sbor::StringRepresentation::CoreApiProgrammaticJson(
+            r#"{
+                "kind": "Tuple",
+                "fields": [
+                    { "kind": "U32", "value": 10 },
+                    { "kind": "String", "value": "hello" }
+                ]
+            }"#
+            .to_owned(),
+        );
+
+    // Act
+    let encoded = radix_engine_toolkit_core::functions::manifest_sbor::encode_string_representation(
+        representation,
+    );
+
+    // Assert
+    assert_eq!(
+        encoded.unwrap(),
+        manifest_encode(&(10u32, "hello".to_owned())).unwrap()
+    );
+}
+
 #[derive(ManifestSbor, ScryptoSbor)]
 struct MyStruct {
     value: bool,
@@ -0,0 +1,141 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_core::functions::instructions::maximum_outflows;
+use scrypto::prelude::*;
+use transaction::prelude::*;
+
+mod test_data;
+
+#[test]
+fn outflow_is_bounded_by_the_amount_sent_to_third_parties() {
+    // Arrange
+    let account1 = test_data::account1();
+    let account2 = test_data::account2();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account1, dec!("10"))
+        .withdraw_from_account(account1, XRD, dec!("100"))
+        .take_from_worktop(XRD, dec!("30"), "bucket")
+        .deposit(account2, "bucket")
+        .deposit_batch(account1)
+        .build();
+
+    // Act
+    let outflows =
+        maximum_outflows(&manifest.instructions, [account1].into_iter().collect()).unwrap();
+
+    // Assert
+    assert_eq!(outflows.len(), 1);
+    assert_eq!(
+        outflows
+            .get(&account1)
+            .and_then(|outflows| outflows.get(&XRD)),
+        Some(&dec!("40"))
+    );
+}
+
+#[test]
+fn entire_worktop_sent_to_third_parties_is_an_outflow_of_all_withdrawn_resources() {
+    // Arrange
+    let account1 = test_data::account1();
+    let account2 = test_data::account2();
+
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account1, XRD, dec!("100"))
+        .deposit_batch(account2)
+        .build();
+
+    // Act
+    let outflows =
+        maximum_outflows(&manifest.instructions, [account1].into_iter().collect()).unwrap();
+
+    // Assert
+    assert_eq!(outflows.len(), 1);
+    assert_eq!(
+        outflows
+            .get(&account1)
+            .and_then(|outflows| outflows.get(&XRD)),
+        Some(&dec!("100"))
+    );
+}
+
+#[test]
+fn resources_deposited_back_into_the_accounts_are_not_an_outflow() {
+    // Arrange
+    let account1 = test_data::account1();
+    let account2 = test_data::account2();
+
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account1, XRD, dec!("100"))
+        .deposit_batch(account2)
+        .build();
+
+    // Act
+    let outflows = maximum_outflows(
+        &manifest.instructions,
+        [account1, account2].into_iter().collect(),
+    )
+    .unwrap();
+
+    // Assert
+    assert!(outflows.is_empty());
+}
+
+#[test]
+fn resources_burned_in_the_accounts_are_an_outflow() {
+    // Arrange
+    let account1 = test_data::account1();
+
+    let manifest = ManifestBuilder::new()
+        .burn_in_account(account1, XRD, dec!("25"))
+        .build();
+
+    // Act
+    let outflows =
+        maximum_outflows(&manifest.instructions, [account1].into_iter().collect()).unwrap();
+
+    // Assert
+    assert_eq!(outflows.len(), 1);
+    assert_eq!(
+        outflows
+            .get(&account1)
+            .and_then(|outflows| outflows.get(&XRD)),
+        Some(&dec!("25"))
+    );
+}
+
+#[test]
+fn recalls_from_vaults_make_the_outflows_unbounded() {
+    // Arrange
+    let account1 = test_data::account1();
+
+    let mut vault_address = account1.as_node_id().0;
+    vault_address[0] = EntityType::InternalFungibleVault as u8;
+
+    let instructions = vec![InstructionV1::CallDirectVaultMethod {
+        address: InternalAddress::new_or_panic(vault_address),
+        method_name: "recall".to_owned(),
+        args: manifest_args!(dec!("100")).into(),
+    }];
+
+    // Act
+    let outflows = maximum_outflows(&instructions, [account1].into_iter().collect());
+
+    // Assert
+    assert!(outflows.is_none());
+}
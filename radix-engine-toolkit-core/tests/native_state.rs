@@ -0,0 +1,116 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_interface::blueprints::account::DefaultDepositRule;
+use radix_engine_toolkit_core::functions::native_state::*;
+use sbor::representations::SerializationMode;
+use scrypto::prelude::*;
+
+#[test]
+fn state_schemas_of_all_native_blueprints_can_be_found() {
+    // Arrange
+    let blueprints = [
+        NativeBlueprint::Account,
+        NativeBlueprint::Validator,
+        NativeBlueprint::AccessController,
+        NativeBlueprint::OneResourcePool,
+        NativeBlueprint::TwoResourcePool,
+        NativeBlueprint::MultiResourcePool,
+        NativeBlueprint::FungibleResourceManager,
+        NativeBlueprint::NonFungibleResourceManager,
+        NativeBlueprint::FungibleVault,
+        NativeBlueprint::NonFungibleVault,
+    ];
+
+    for blueprint in blueprints {
+        // Act
+        let field_count = field_count(blueprint);
+
+        // Assert
+        assert!(matches!(field_count, Ok(count) if count > 0));
+    }
+}
+
+#[test]
+fn decoding_a_field_that_does_not_exist_fails() {
+    // Arrange
+    let bech32_encoder = AddressBech32Encoder::new(&NetworkDefinition::simulator());
+
+    // Act
+    let result = decode_field(
+        NativeBlueprint::Account,
+        u8::MAX,
+        scrypto_encode(&()).unwrap(),
+        SerializationMode::Programmatic,
+        &bech32_encoder,
+    );
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(NativeStateDecodeError::FieldNotFound {
+            blueprint: NativeBlueprint::Account,
+            field_index: u8::MAX
+        })
+    ));
+}
+
+#[test]
+fn fungible_vault_balance_is_decoded_into_its_typed_model() {
+    // Arrange
+    let payload = scrypto_encode(&(dec!("100"),)).unwrap();
+
+    // Act
+    let state = decode_typed_field(NativeBlueprint::FungibleVault, 0, payload);
+
+    // Assert
+    assert_eq!(
+        state.unwrap(),
+        Some(NativeFieldState::FungibleVaultBalance {
+            amount: dec!("100")
+        })
+    );
+}
+
+#[test]
+fn account_state_is_decoded_into_its_typed_model() {
+    // Arrange
+    let payload = scrypto_encode(&(DefaultDepositRule::AllowExisting,)).unwrap();
+
+    // Act
+    let state = decode_typed_field(NativeBlueprint::Account, 0, payload);
+
+    // Assert
+    assert_eq!(
+        state.unwrap(),
+        Some(NativeFieldState::Account {
+            default_deposit_rule: DefaultDepositRule::AllowExisting
+        })
+    );
+}
+
+#[test]
+fn fields_without_a_typed_model_are_not_decoded() {
+    // Arrange
+    let payload = scrypto_encode(&()).unwrap();
+
+    // Act
+    let state = decode_typed_field(NativeBlueprint::Validator, 0, payload);
+
+    // Assert
+    assert!(matches!(state, Ok(None)));
+}
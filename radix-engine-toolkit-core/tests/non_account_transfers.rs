@@ -0,0 +1,100 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_interface::blueprints::account::*;
+use radix_engine_toolkit_core::functions::instructions::non_account_transfers;
+use radix_engine_toolkit_core::instruction_visitor::visitors::non_account_transfers_visitor::*;
+use scrypto::prelude::*;
+use transaction::prelude::*;
+
+mod test_data;
+
+#[test]
+fn entire_worktop_passed_to_a_non_account_component_is_flagged() {
+    // Arrange
+    let instructions = vec![
+        InstructionV1::CallMethod {
+            address: DynamicGlobalAddress::Static(test_data::account1().into()),
+            method_name: ACCOUNT_WITHDRAW_IDENT.to_owned(),
+            args: to_manifest_value_and_unwrap!(&AccountWithdrawInput {
+                resource_address: XRD,
+                amount: dec!("10"),
+            }),
+        },
+        InstructionV1::CallMethod {
+            address: DynamicGlobalAddress::Static(FAUCET.into()),
+            method_name: "deposit_batch".to_owned(),
+            args: to_manifest_value_and_unwrap!(&(ManifestExpression::EntireWorktop,)),
+        },
+    ];
+
+    // Act
+    let transfers = non_account_transfers(&instructions);
+
+    // Assert
+    assert_eq!(
+        transfers,
+        vec![NonAccountTransfer {
+            instruction_index: 1,
+            component_address: DynamicGlobalAddress::Static(FAUCET.into()),
+            method_name: "deposit_batch".to_owned(),
+            entire_worktop: true,
+            buckets: vec![],
+        }]
+    );
+}
+
+#[test]
+fn buckets_passed_to_a_non_account_component_are_flagged() {
+    // Arrange
+    let instructions = vec![
+        InstructionV1::TakeAllFromWorktop {
+            resource_address: XRD,
+        },
+        InstructionV1::CallMethod {
+            address: DynamicGlobalAddress::Static(FAUCET.into()),
+            method_name: "swap".to_owned(),
+            args: to_manifest_value_and_unwrap!(&(ManifestBucket(0),)),
+        },
+    ];
+
+    // Act
+    let transfers = non_account_transfers(&instructions);
+
+    // Assert
+    assert_eq!(transfers.len(), 1);
+    assert_eq!(transfers[0].instruction_index, 1);
+    assert!(!transfers[0].entire_worktop);
+    assert_eq!(transfers[0].buckets, vec![ManifestBucket(0)]);
+}
+
+#[test]
+fn deposits_into_accounts_are_not_flagged() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(test_data::account1(), XRD, dec!("10"))
+        .take_from_worktop(XRD, dec!("5"), "bucket")
+        .try_deposit_or_abort(test_data::account2(), None, "bucket")
+        .try_deposit_entire_worktop_or_abort(test_data::account3(), None)
+        .build();
+
+    // Act
+    let transfers = non_account_transfers(&manifest.instructions);
+
+    // Assert
+    assert!(transfers.is_empty());
+}
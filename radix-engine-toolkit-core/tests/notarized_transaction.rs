@@ -15,6 +15,11 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use radix_engine_toolkit_core::functions::notarized_transaction::{
+    NotaryVerificationFailure, PreflightIssue,
+};
+use scrypto::prelude::*;
+use transaction::model::*;
 use transaction::validation::ValidationConfig;
 mod test_data;
 
@@ -75,3 +80,170 @@ fn notarized_transaction_can_be_statically_validated() {
     // Assert
     assert!(validation_result.is_ok())
 }
+
+#[test]
+fn preflight_check_reports_missing_fee_lock() {
+    // Arrange
+    let transaction = test_data::notarized_transaction();
+    let validation_config = ValidationConfig::default(0x01);
+
+    // Act
+    let issues = radix_engine_toolkit_core::functions::notarized_transaction::preflight_check(
+        &transaction,
+        validation_config,
+        Some(Epoch::of(150)),
+        &[],
+    );
+
+    // Assert
+    assert!(matches!(issues.as_slice(), [PreflightIssue::NoFeeLocked]))
+}
+
+#[test]
+fn preflight_check_reports_expired_epoch_window_and_recently_used_nonce() {
+    // Arrange
+    let transaction = test_data::notarized_transaction();
+    let validation_config = ValidationConfig::default(0x01);
+
+    // Act
+    let issues = radix_engine_toolkit_core::functions::notarized_transaction::preflight_check(
+        &transaction,
+        validation_config,
+        Some(Epoch::of(200)),
+        &[100],
+    );
+
+    // Assert
+    assert!(issues
+        .iter()
+        .any(|issue| matches!(issue, PreflightIssue::EpochWindowExpired { .. })));
+    assert!(issues
+        .iter()
+        .any(|issue| matches!(issue, PreflightIssue::NonceRecentlyUsed(100))));
+}
+
+#[test]
+fn submission_envelope_contains_the_payload_and_hashes_of_the_transaction() {
+    // Arrange
+    let transaction = test_data::notarized_transaction();
+
+    // Act
+    let envelope =
+        radix_engine_toolkit_core::functions::notarized_transaction::submission_envelope(
+            &transaction,
+        )
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        envelope.payload,
+        radix_engine_toolkit_core::functions::notarized_transaction::compile(&transaction).unwrap()
+    );
+    assert_eq!(
+        envelope.payload_id.hash,
+        radix_engine_toolkit_core::functions::notarized_transaction::hash(&transaction)
+            .unwrap()
+            .hash
+    );
+    assert_eq!(
+        envelope.intent_hash.hash,
+        radix_engine_toolkit_core::functions::intent::hash(&transaction.signed_intent.intent)
+            .unwrap()
+            .hash
+    );
+    assert!(envelope
+        .payload_id
+        .id
+        .starts_with("notarizedtransaction_rdx"));
+    assert!(envelope.intent_hash.id.starts_with("txid_rdx"));
+}
+
+#[test]
+fn notary_is_verified_against_the_expected_public_key() {
+    // Arrange
+    let transaction = test_data::notarized_transaction();
+    let expected_notary_public_key = test_data::private_key1().public_key().into();
+
+    // Act
+    let failures = radix_engine_toolkit_core::functions::notarized_transaction::verify_notary(
+        &transaction,
+        &expected_notary_public_key,
+    )
+    .unwrap();
+
+    // Assert
+    assert!(failures.is_empty())
+}
+
+#[test]
+fn notary_verification_reports_an_unexpected_notary_public_key() {
+    // Arrange
+    let transaction = test_data::notarized_transaction();
+    let expected_notary_public_key = test_data::private_key3().public_key().into();
+
+    // Act
+    let failures = radix_engine_toolkit_core::functions::notarized_transaction::verify_notary(
+        &transaction,
+        &expected_notary_public_key,
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(
+        failures,
+        vec![NotaryVerificationFailure::NotaryPublicKeyMismatch {
+            actual: test_data::private_key1().public_key().into()
+        }]
+    )
+}
+
+#[test]
+fn notary_verification_reports_an_invalid_notary_signature() {
+    // Arrange
+    let mut transaction = test_data::notarized_transaction();
+    transaction.notary_signature = NotarySignatureV1(
+        test_data::private_key1()
+            .sign(&hash("Not the signed intent"))
+            .into(),
+    );
+    let expected_notary_public_key = test_data::private_key1().public_key().into();
+
+    // Act
+    let failures = radix_engine_toolkit_core::functions::notarized_transaction::verify_notary(
+        &transaction,
+        &expected_notary_public_key,
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(
+        failures,
+        vec![NotaryVerificationFailure::InvalidNotarySignature]
+    )
+}
+
+#[test]
+fn notary_verification_reports_a_notary_signature_on_another_curve() {
+    // Arrange
+    let mut transaction = test_data::notarized_transaction();
+    let signed_intent_hash =
+        radix_engine_toolkit_core::functions::signed_intent::hash(&transaction.signed_intent)
+            .unwrap()
+            .hash;
+    transaction.notary_signature =
+        NotarySignatureV1(test_data::private_key2().sign(&signed_intent_hash).into());
+    let expected_notary_public_key = test_data::private_key1().public_key().into();
+
+    // Act
+    let failures = radix_engine_toolkit_core::functions::notarized_transaction::verify_notary(
+        &transaction,
+        &expected_notary_public_key,
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(
+        failures,
+        vec![NotaryVerificationFailure::NotarySignatureCurveMismatch]
+    )
+}
@@ -0,0 +1,140 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_core::functions::derive::virtual_account_address_from_public_key;
+use radix_engine_toolkit_core::functions::olympia::*;
+use scrypto::prelude::*;
+use transaction::prelude::Secp256k1PrivateKey;
+
+#[test]
+fn olympia_transfer_can_be_decoded() {
+    // Arrange
+    let public_key = Secp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let payload = transfer_payload(&public_key, 10);
+
+    // Act
+    let transaction = decode_transaction(&payload).unwrap();
+
+    // Assert
+    assert_eq!(transaction.instructions.len(), 6);
+    assert_eq!(
+        transaction.instructions[2],
+        OlympiaInstruction::Up(OlympiaSubstate::Tokens {
+            resource: vec![0x01],
+            owner: public_key,
+            amount: dec!("10")
+        })
+    );
+    assert_eq!(
+        transaction.instructions[3],
+        OlympiaInstruction::Message(b"Hello".to_vec())
+    );
+}
+
+#[test]
+fn olympia_transfer_is_mapped_to_babylon_addresses() {
+    // Arrange
+    let public_key = Secp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let transaction = decode_transaction(&transfer_payload(&public_key, 10)).unwrap();
+
+    // Act
+    let actions = actions(&transaction).unwrap();
+
+    // Assert
+    assert_eq!(
+        actions,
+        vec![OlympiaAction::TokensDeposited {
+            account: virtual_account_address_from_public_key(&public_key),
+            resource_address: XRD,
+            amount: dec!("10")
+        }]
+    );
+}
+
+#[test]
+fn olympia_transaction_with_unknown_opcode_fails_to_decode() {
+    // Arrange
+    let payload = [0x09, 0x00, 0x01, 0xFF];
+
+    // Act
+    let result = decode_transaction(&payload);
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(OlympiaTransactionDecodeError::UnknownOpcode(0xFF))
+    );
+}
+
+#[test]
+fn olympia_tokens_substate_with_truncated_amount_fails_to_decode_as_invalid_substate() {
+    // Arrange
+    let public_key = Secp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let mut substate = vec![0x06, 0x00, 0x01, 0x04];
+    substate.extend(public_key.0);
+    substate.extend([0u8; 16]);
+    let mut payload = vec![0x01];
+    payload.extend((substate.len() as u16).to_be_bytes());
+    payload.extend(substate);
+
+    // Act
+    let result = decode_transaction(&payload);
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(OlympiaTransactionDecodeError::InvalidSubstate { type_id: 0x06 })
+    );
+}
+
+fn transfer_payload(public_key: &Secp256k1PublicKey, amount: u128) -> Vec<u8> {
+    let mut payload = vec![];
+
+    // Header
+    payload.extend([0x09, 0x00, 0x01]);
+
+    // Down
+    payload.push(0x04);
+    payload.extend([0xAA; 32]);
+    payload.extend(0u32.to_be_bytes());
+
+    // Up
+    let substate = {
+        let mut substate = vec![0x06, 0x00, 0x01, 0x04];
+        substate.extend(public_key.0);
+        substate.extend([0u8; 16]);
+        substate.extend((amount * 10u128.pow(18)).to_be_bytes());
+        substate
+    };
+    payload.push(0x01);
+    payload.extend((substate.len() as u16).to_be_bytes());
+    payload.extend(substate);
+
+    // Message
+    payload.push(0x06);
+    payload.extend(5u16.to_be_bytes());
+    payload.extend(b"Hello");
+
+    // Signature
+    payload.push(0x07);
+    payload.extend([0xBB; 65]);
+
+    // End
+    payload.push(0x00);
+
+    payload
+}
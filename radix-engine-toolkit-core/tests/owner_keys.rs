@@ -0,0 +1,76 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use radix_engine_toolkit_core::instruction_visitor::core::traverser::traverse;
+use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::owner_keys_visitor::*;
+use scrypto::api::node_modules::metadata::*;
+use scrypto::prelude::*;
+use transaction::prelude::*;
+
+#[test]
+fn owner_keys_changes_are_collected_per_entity() {
+    // Arrange
+    let public_key = Secp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let account = ComponentAddress::virtual_account_from_public_key(&public_key);
+    let identity = ComponentAddress::virtual_identity_from_public_key(&public_key);
+    let public_key_hashes = vec![PublicKeyHash::new_from_public_key(&public_key.into())];
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, dec!("10"))
+        .set_metadata(
+            identity,
+            OWNER_KEYS_METADATA_KEY,
+            MetadataValue::PublicKeyHashArray(public_key_hashes.clone()),
+        )
+        .lock_metadata(identity, OWNER_KEYS_METADATA_KEY)
+        .set_metadata(account, "name", MetadataValue::String("Account".to_owned()))
+        .build();
+
+    // Act
+    let mut visitor = OwnerKeysVisitor::default();
+    traverse(&manifest.instructions, &mut [&mut visitor]).unwrap();
+    let owner_keys_changes = visitor.output();
+
+    // Assert
+    assert_eq!(
+        owner_keys_changes,
+        Some(
+            [(
+                identity.into(),
+                vec![
+                    OwnerKeysAction::Set(public_key_hashes),
+                    OwnerKeysAction::Lock
+                ]
+            )]
+            .into_iter()
+            .collect()
+        )
+    );
+}
+
+#[test]
+fn manifests_not_touching_owner_keys_have_no_owner_keys_changes() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .call_method(FAUCET, "free", manifest_args!())
+        .build();
+
+    // Act
+    let mut visitor = OwnerKeysVisitor::default();
+    traverse(&manifest.instructions, &mut [&mut visitor]).unwrap();
+
+    // Assert
+    assert_eq!(visitor.output(), None);
+}
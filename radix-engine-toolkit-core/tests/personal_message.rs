@@ -0,0 +1,66 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_core::functions::personal_message::*;
+use scrypto::prelude::*;
+use transaction::prelude::*;
+
+#[test]
+fn signed_personal_message_verifies_to_signer_account() {
+    // Arrange
+    let private_key = PrivateKey::Ed25519(Ed25519PrivateKey::from_u64(1).unwrap());
+    let message = b"Claim airdrop";
+
+    // Act
+    let signature = sign(&private_key, message);
+    let signer_account = verify(message, &signature);
+
+    // Assert
+    assert_eq!(
+        signer_account,
+        Some(ComponentAddress::virtual_account_from_public_key(
+            &private_key.public_key()
+        ))
+    );
+}
+
+#[test]
+fn personal_message_signature_does_not_verify_for_another_message() {
+    // Arrange
+    let private_key = PrivateKey::Secp256k1(Secp256k1PrivateKey::from_u64(1).unwrap());
+    let signature = sign(&private_key, b"Claim airdrop");
+
+    // Act
+    let signer_account = verify(b"Claim another airdrop", &signature);
+
+    // Assert
+    assert_ne!(
+        signer_account,
+        Some(ComponentAddress::virtual_account_from_public_key(
+            &private_key.public_key()
+        ))
+    );
+}
+
+#[test]
+fn personal_message_hash_is_domain_separated() {
+    // Act
+    let personal_message_hash = hash(b"message");
+
+    // Assert
+    assert_ne!(personal_message_hash, scrypto::prelude::hash(b"message"));
+}
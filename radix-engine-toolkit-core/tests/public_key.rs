@@ -0,0 +1,108 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_core::functions::public_key::*;
+use scrypto::prelude::*;
+
+mod test_data;
+
+#[test]
+fn valid_public_keys_are_accepted() {
+    // Arrange
+    let secp256k1_public_key = PublicKey::from(test_data::private_key1().public_key());
+    let ed25519_public_key = PublicKey::from(test_data::private_key2().public_key());
+
+    // Act
+    let secp256k1_result = validate(&secp256k1_public_key);
+    let ed25519_result = validate(&ed25519_public_key);
+
+    // Assert
+    assert_eq!(secp256k1_result, Ok(()));
+    assert_eq!(ed25519_result, Ok(()));
+}
+
+#[test]
+fn secp256k1_public_key_not_on_the_curve_is_rejected() {
+    // Arrange
+    let mut bytes = [0xFF; Secp256k1PublicKey::LENGTH];
+    bytes[0] = 0x02;
+
+    // Act
+    let result = secp256k1_validate(&bytes);
+
+    // Assert
+    assert_eq!(result, Err(PublicKeyError::InvalidCurvePoint));
+}
+
+#[test]
+fn public_keys_of_invalid_length_are_rejected() {
+    // Arrange
+    let bytes = [0x02; 20];
+
+    // Act
+    let secp256k1_result = secp256k1_validate(&bytes);
+    let ed25519_result = ed25519_validate(&bytes);
+
+    // Assert
+    assert_eq!(
+        secp256k1_result,
+        Err(PublicKeyError::InvalidLength {
+            expected: Secp256k1PublicKey::LENGTH,
+            actual: 20
+        })
+    );
+    assert_eq!(
+        ed25519_result,
+        Err(PublicKeyError::InvalidLength {
+            expected: Ed25519PublicKey::LENGTH,
+            actual: 20
+        })
+    );
+}
+
+#[test]
+fn secp256k1_public_key_round_trips_through_decompression() {
+    // Arrange
+    let public_key = test_data::private_key1().public_key();
+
+    // Act
+    let uncompressed = secp256k1_decompress(&public_key).unwrap();
+    let compressed = secp256k1_compress(&uncompressed).unwrap();
+
+    // Assert
+    assert_eq!(uncompressed.len(), SECP256K1_UNCOMPRESSED_PUBLIC_KEY_LENGTH);
+    assert_eq!(uncompressed[0], 0x04);
+    assert_eq!(compressed, public_key);
+}
+
+#[test]
+fn compressed_public_key_is_rejected_by_compression() {
+    // Arrange
+    let public_key = test_data::private_key1().public_key();
+
+    // Act
+    let result = secp256k1_compress(&public_key.0);
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(PublicKeyError::InvalidLength {
+            expected: SECP256K1_UNCOMPRESSED_PUBLIC_KEY_LENGTH,
+            actual: Secp256k1PublicKey::LENGTH
+        })
+    );
+}
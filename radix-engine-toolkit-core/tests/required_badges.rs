@@ -0,0 +1,87 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_core::functions::instructions::required_badges;
+use radix_engine_toolkit_core::instruction_visitor::visitors::required_badges_visitor::*;
+use scrypto::prelude::*;
+use transaction::prelude::*;
+
+#[test]
+fn withdraw_from_virtual_account_requires_its_signature_badge() {
+    // Arrange
+    let public_key = Secp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let account = ComponentAddress::virtual_account_from_public_key(&public_key);
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account, XRD, dec!("10"))
+        .build();
+
+    // Act
+    let requirements = required_badges(&manifest.instructions);
+
+    // Assert
+    assert_eq!(
+        requirements,
+        vec![InstructionBadgeRequirements {
+            instruction_index: 0,
+            proofs_created: vec![],
+            required_badges: vec![RequiredBadge {
+                entity_address: account.into(),
+                method_name: ACCOUNT_WITHDRAW_IDENT.to_owned(),
+                requirement: BadgeRequirement::NonFungible(NonFungibleGlobalId::from_public_key(
+                    &public_key
+                ))
+            }]
+        }]
+    );
+}
+
+#[test]
+fn proofs_created_from_accounts_are_listed() {
+    // Arrange
+    let public_key = Secp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let account = ComponentAddress::virtual_account_from_public_key(&public_key);
+    let manifest = ManifestBuilder::new()
+        .create_proof_from_account_of_amount(account, XRD, dec!("10"))
+        .build();
+
+    // Act
+    let requirements = required_badges(&manifest.instructions);
+
+    // Assert
+    assert_eq!(requirements.len(), 1);
+    assert_eq!(
+        requirements[0].proofs_created,
+        vec![CreatedProof::Amount {
+            resource_address: XRD,
+            amount: dec!("10")
+        }]
+    );
+}
+
+#[test]
+fn calls_to_public_methods_require_no_badges() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .call_method(FAUCET, "free", manifest_args!())
+        .build();
+
+    // Act
+    let requirements = required_badges(&manifest.instructions);
+
+    // Assert
+    assert!(requirements.is_empty());
+}
@@ -0,0 +1,71 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_core::functions::instructions::required_signers;
+use radix_engine_toolkit_core::instruction_visitor::visitors::required_signers_visitor::*;
+use scrypto::prelude::*;
+use transaction::prelude::*;
+
+#[test]
+fn accounts_and_identities_requiring_auth_are_classified_with_their_methods() {
+    // Arrange
+    let public_key = Secp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let account = ComponentAddress::virtual_account_from_public_key(&public_key);
+    let identity = ComponentAddress::virtual_identity_from_public_key(&public_key);
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, dec!("10"))
+        .withdraw_from_account(account, XRD, dec!("10"))
+        .set_metadata(identity, "x", MetadataValue::Bool(true))
+        .build();
+
+    // Act
+    let signers = required_signers(&manifest.instructions);
+
+    // Assert
+    assert_eq!(
+        signers,
+        vec![
+            RequiredSigner {
+                entity_address: account.into(),
+                entity_kind: SignerEntityKind::Account,
+                methods: vec![
+                    ACCOUNT_LOCK_FEE_IDENT.to_owned(),
+                    ACCOUNT_WITHDRAW_IDENT.to_owned()
+                ]
+            },
+            RequiredSigner {
+                entity_address: identity.into(),
+                entity_kind: SignerEntityKind::Identity,
+                methods: vec![METADATA_SET_IDENT.to_owned()]
+            }
+        ]
+    );
+}
+
+#[test]
+fn calls_to_public_methods_require_no_signers() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .call_method(FAUCET, "free", manifest_args!())
+        .build();
+
+    // Act
+    let signers = required_signers(&manifest.instructions);
+
+    // Assert
+    assert!(signers.is_empty());
+}
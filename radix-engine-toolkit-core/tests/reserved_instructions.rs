@@ -0,0 +1,105 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_interface::blueprints::account::*;
+use radix_engine_interface::blueprints::identity::*;
+use radix_engine_toolkit_core::functions::instructions::reserved_instructions;
+use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::reserved_instructions::*;
+use scrypto::prelude::*;
+use transaction::prelude::*;
+
+#[test]
+fn lock_fee_reserved_instruction_reports_index_and_amount() {
+    // Arrange
+    let public_key = Secp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let account = ComponentAddress::virtual_account_from_public_key(&public_key);
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account, XRD, dec!("10"))
+        .lock_fee(account, dec!("5"))
+        .build();
+
+    // Act
+    let usages = reserved_instructions(&manifest.instructions);
+
+    // Assert
+    assert_eq!(
+        usages,
+        vec![ReservedInstructionUsage {
+            instruction_index: 1,
+            address: DynamicGlobalAddress::Static(account.into()),
+            reserved_instruction: ReservedInstruction::AccountLockFee,
+            arguments: ReservedInstructionArguments::AccountLockFee {
+                amount: dec!("5"),
+                contingent: false
+            }
+        }]
+    );
+}
+
+#[test]
+fn account_settings_reserved_instruction_reports_updated_settings() {
+    // Arrange
+    let public_key = Secp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let account = ComponentAddress::virtual_account_from_public_key(&public_key);
+    let manifest = ManifestBuilder::new()
+        .call_method(
+            account,
+            ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT,
+            AccountSetDefaultDepositRuleInput {
+                default: DefaultDepositRule::Reject,
+            },
+        )
+        .build();
+
+    // Act
+    let usages = reserved_instructions(&manifest.instructions);
+
+    // Assert
+    assert_eq!(
+        usages
+            .into_iter()
+            .map(|usage| usage.arguments)
+            .collect::<Vec<_>>(),
+        vec![ReservedInstructionArguments::AccountSetDefaultDepositRule {
+            default_deposit_rule: DefaultDepositRule::Reject
+        }]
+    );
+}
+
+#[test]
+fn identity_securify_reserved_instruction_is_reported_for_identities() {
+    // Arrange
+    let public_key = Secp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let identity = ComponentAddress::virtual_identity_from_public_key(&public_key);
+    let manifest = ManifestBuilder::new()
+        .call_method(identity, IDENTITY_SECURIFY_IDENT, manifest_args!())
+        .build();
+
+    // Act
+    let usages = reserved_instructions(&manifest.instructions);
+
+    // Assert
+    assert_eq!(
+        usages,
+        vec![ReservedInstructionUsage {
+            instruction_index: 0,
+            address: DynamicGlobalAddress::Static(identity.into()),
+            reserved_instruction: ReservedInstruction::IdentitySecurify,
+            arguments: ReservedInstructionArguments::Securify
+        }]
+    );
+}
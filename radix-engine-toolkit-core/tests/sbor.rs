@@ -0,0 +1,103 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_common::prelude::*;
+use radix_engine_toolkit_core::functions::sbor::*;
+
+#[test]
+fn scrypto_and_manifest_payloads_are_told_apart_by_their_prefix() {
+    // Arrange
+    let bech32_encoder = AddressBech32Encoder::for_simulator();
+    let scrypto_payload = scrypto_encode(&(1u8, "hello")).unwrap();
+    let manifest_payload = manifest_encode(&(1u8, "hello")).unwrap();
+
+    // Act
+    let scrypto = decode_unknown(
+        &scrypto_payload,
+        SborDecodeLimits::default(),
+        &bech32_encoder,
+    );
+    let manifest = decode_unknown(
+        &manifest_payload,
+        SborDecodeLimits::default(),
+        &bech32_encoder,
+    );
+
+    // Assert
+    assert_eq!(scrypto.unwrap().kind, SborPayloadKind::Scrypto);
+    assert_eq!(manifest.unwrap().kind, SborPayloadKind::Manifest);
+}
+
+#[test]
+fn payloads_with_an_unknown_prefix_are_rejected() {
+    // Arrange
+    let bech32_encoder = AddressBech32Encoder::for_simulator();
+
+    // Act
+    let result = decode_unknown([0xff, 0x01], SborDecodeLimits::default(), &bech32_encoder);
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(SborDecodeUnknownError::UnknownPayloadPrefix(Some(0xff)))
+    ));
+}
+
+#[test]
+fn payloads_longer_than_the_limit_are_rejected() {
+    // Arrange
+    let bech32_encoder = AddressBech32Encoder::for_simulator();
+    let payload = scrypto_encode(&vec![0u8; 100]).unwrap();
+    let limits = SborDecodeLimits {
+        maximum_length: 10,
+        ..Default::default()
+    };
+
+    // Act
+    let result = decode_unknown(&payload, limits, &bech32_encoder);
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(SborDecodeUnknownError::PayloadTooLong {
+            maximum_length: 10,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn payloads_deeper_than_the_limit_are_rejected() {
+    // Arrange
+    let bech32_encoder = AddressBech32Encoder::for_simulator();
+    let payload = scrypto_encode(&(((1u8,),),)).unwrap();
+    let limits = SborDecodeLimits {
+        maximum_depth: 2,
+        ..Default::default()
+    };
+
+    // Act
+    let result = decode_unknown(&payload, limits, &bech32_encoder);
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(SborDecodeUnknownError::DecodeError(
+            DecodeError::MaxDepthExceeded(..)
+        ))
+    ));
+}
@@ -0,0 +1,125 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine::blueprints::native_schema::*;
+use radix_engine_toolkit_core::functions::schema::*;
+use scrypto::blueprints::account::*;
+use scrypto::prelude::*;
+
+#[test]
+fn package_definition_can_be_decoded() {
+    // Arrange
+    let encoded = manifest_encode(&*ACCOUNT_PACKAGE_DEFINITION).unwrap();
+
+    // Act
+    let package_definition = decode_package_definition(&encoded);
+
+    // Assert
+    assert_eq!(package_definition.unwrap(), *ACCOUNT_PACKAGE_DEFINITION);
+}
+
+#[test]
+fn account_blueprint_information_contains_functions_and_events() {
+    // Act
+    let blueprints = package_information(&ACCOUNT_PACKAGE_DEFINITION).unwrap();
+
+    // Assert
+    let account = blueprints
+        .iter()
+        .find(|blueprint| blueprint.name == ACCOUNT_BLUEPRINT)
+        .unwrap();
+    assert!(account
+        .functions
+        .iter()
+        .any(|function| function.name == ACCOUNT_WITHDRAW_IDENT));
+    assert!(account
+        .events
+        .iter()
+        .any(|event| event.name == "DepositEvent"));
+}
+
+#[test]
+fn function_signature_of_account_withdraw_has_named_arguments() {
+    // Act
+    let signature = function_signature(
+        &ACCOUNT_PACKAGE_DEFINITION,
+        ACCOUNT_BLUEPRINT,
+        ACCOUNT_WITHDRAW_IDENT,
+    )
+    .unwrap()
+    .unwrap();
+
+    // Assert
+    assert!(signature.receiver.is_some());
+    assert_eq!(
+        signature
+            .arguments
+            .into_iter()
+            .map(|argument| argument.name)
+            .collect::<Vec<_>>(),
+        vec![
+            Some("resource_address".to_owned()),
+            Some("amount".to_owned())
+        ]
+    );
+}
+
+#[test]
+fn function_signature_of_unknown_blueprint_is_none() {
+    // Act
+    let signature = function_signature(&ACCOUNT_PACKAGE_DEFINITION, "Foo", "bar").unwrap();
+
+    // Assert
+    assert!(signature.is_none());
+}
+
+#[test]
+fn types_of_account_withdraw_arguments_are_described_by_their_kind() {
+    // Arrange
+    let signature = function_signature(
+        &ACCOUNT_PACKAGE_DEFINITION,
+        ACCOUNT_BLUEPRINT,
+        ACCOUNT_WITHDRAW_IDENT,
+    )
+    .unwrap()
+    .unwrap();
+
+    // Act
+    let blueprints = package_information(&ACCOUNT_PACKAGE_DEFINITION).unwrap();
+
+    // Assert
+    let account = blueprints
+        .iter()
+        .find(|blueprint| blueprint.name == ACCOUNT_BLUEPRINT)
+        .unwrap();
+    let kinds = signature
+        .arguments
+        .iter()
+        .map(|argument| {
+            account
+                .types
+                .iter()
+                .find(|description| description.local_type_id == argument.local_type_id)
+                .map(|description| description.kind.clone())
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        vec![TypeKindDescription::Reference, TypeKindDescription::Decimal]
+    );
+}
@@ -0,0 +1,108 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_core::utils::*;
+use scrypto::prelude::*;
+
+#[test]
+fn valid_values_have_no_violations() {
+    // Arrange
+    let value = ManifestValue::Tuple {
+        fields: vec![
+            ManifestValue::U8 { value: 1 },
+            ManifestValue::String {
+                value: "hello".to_owned(),
+            },
+        ],
+    };
+
+    // Act
+    let result = validate_manifest_value_against_schema::<(u8, String)>(&value);
+
+    // Assert
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn every_invalid_field_of_a_tuple_is_reported_with_its_path() {
+    // Arrange
+    let value = ManifestValue::Tuple {
+        fields: vec![
+            ManifestValue::U16 { value: 1 },
+            ManifestValue::Bool { value: true },
+        ],
+    };
+
+    // Act
+    let violations = validate_manifest_value_against_schema::<(u8, String)>(&value).unwrap_err();
+
+    // Assert
+    assert_eq!(
+        violations
+            .iter()
+            .map(|violation| (
+                violation.path.as_str(),
+                violation.expected_type_kind.as_str(),
+                violation.found_value_kind
+            ))
+            .collect::<Vec<_>>(),
+        vec![
+            ("/0", "U8", ManifestValueKind::U16),
+            ("/1", "String", ManifestValueKind::Bool),
+        ]
+    );
+}
+
+#[test]
+fn invalid_map_values_are_reported_with_their_entry_path() {
+    // Arrange
+    let value = ManifestValue::Map {
+        key_value_kind: ManifestValueKind::String,
+        value_value_kind: ManifestValueKind::String,
+        entries: vec![(
+            ManifestValue::String {
+                value: "key".to_owned(),
+            },
+            ManifestValue::String {
+                value: "value".to_owned(),
+            },
+        )],
+    };
+
+    // Act
+    let violations =
+        validate_manifest_value_against_schema::<BTreeMap<String, u8>>(&value).unwrap_err();
+
+    // Assert
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].path, "/0/value");
+    assert_eq!(violations[0].expected_type_kind, "U8");
+}
+
+#[test]
+fn values_of_the_wrong_kind_are_reported_at_the_root() {
+    // Arrange
+    let value = ManifestValue::Bool { value: false };
+
+    // Act
+    let violations = validate_manifest_value_against_schema::<(u8, String)>(&value).unwrap_err();
+
+    // Assert
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].path, "");
+    assert_eq!(violations[0].found_value_kind, ManifestValueKind::Bool);
+}
@@ -82,6 +82,40 @@ fn scrypto_value_can_be_represented_as_a_string() {
     }
 }
 
+#[test]
+fn core_api_programmatic_json_can_be_encoded() {
+    // Arrange
+    let representation =
+        radix_engine_toolkit_core::functions::scrypto_sbor::StringRepresentation::CoreApiProgrammaticJson(
+            r#"{
+                "kind": "Tuple",
+                "type_name": "MyTuple",
+                "fields": [
+                    { "kind": "U8", "field_name": "number", "value": 5 },
+                    { "kind": "Array", "element_kind": "U8", "hex": "0102" },
+                    {
+                        "kind": "Enum",
+                        "variant_id": 1,
+                        "variant_name": "Some",
+                        "fields": [{ "kind": "String", "value": "x" }]
+                    }
+                ]
+            }"#
+            .to_owned(),
+        );
+
+    // Act
+    let encoded = radix_engine_toolkit_core::functions::scrypto_sbor::encode_string_representation(
+        representation,
+    );
+
+    // Assert
+    assert_eq!(
+        encoded.unwrap(),
+        scrypto_encode(&(5u8, vec![1u8, 2u8], Some("x".to_owned()))).unwrap()
+    );
+}
+
 #[derive(ScryptoSbor)]
 struct MyStruct {
     value: bool,
@@ -15,6 +15,9 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use radix_engine_toolkit_core::functions::signed_intent::NotarySignatoryIssue;
+use transaction::model::*;
+use transaction::prelude::*;
 use transaction::validation::ValidationConfig;
 mod test_data;
 
@@ -73,3 +76,98 @@ fn signed_intent_can_be_statically_validated() {
     // Assert
     assert!(validation_result.is_ok())
 }
+
+#[test]
+fn duplicate_and_reordered_signatures_normalize_to_the_same_signed_intent() {
+    // Arrange
+    let signed_intent = test_data::signed_intent();
+    let mut shuffled_signed_intent = signed_intent.clone();
+    shuffled_signed_intent
+        .intent_signatures
+        .signatures
+        .reverse();
+    shuffled_signed_intent
+        .intent_signatures
+        .signatures
+        .push(signed_intent.intent_signatures.signatures[0].clone());
+
+    // Act
+    let normalized =
+        radix_engine_toolkit_core::functions::signed_intent::normalize_signatures(&signed_intent)
+            .unwrap();
+    let shuffled_normalized =
+        radix_engine_toolkit_core::functions::signed_intent::normalize_signatures(
+            &shuffled_signed_intent,
+        )
+        .unwrap();
+
+    // Assert
+    assert_eq!(normalized, shuffled_normalized);
+    assert_eq!(
+        normalized.intent_signatures.signatures.len(),
+        signed_intent.intent_signatures.signatures.len()
+    );
+}
+
+#[test]
+fn signatures_of_another_intent_fail_normalization() {
+    // Arrange
+    let mut signed_intent = test_data::signed_intent();
+    signed_intent.intent.header.nonce += 1;
+
+    // Act
+    let normalized =
+        radix_engine_toolkit_core::functions::signed_intent::normalize_signatures(&signed_intent);
+
+    // Assert
+    assert!(matches!(
+        normalized,
+        Err(
+            radix_engine_toolkit_core::functions::signed_intent::SignatureNormalizationError::InvalidSignature {
+                index: 0
+            }
+        )
+    ));
+}
+
+#[test]
+fn notary_signatory_issues_are_empty_for_a_correctly_configured_signed_intent() {
+    // Arrange
+    let signed_intent = test_data::signed_intent();
+
+    // Act
+    let issues = radix_engine_toolkit_core::functions::signed_intent::notary_signatory_issues(
+        &signed_intent,
+    );
+
+    // Assert
+    assert_eq!(issues, Ok(vec![]))
+}
+
+#[test]
+fn redundant_notary_signature_is_reported_when_notary_is_signatory() {
+    // Arrange
+    let mut signed_intent = test_data::signed_intent();
+    let intent_hash = radix_engine_toolkit_core::functions::intent::hash(&signed_intent.intent)
+        .unwrap()
+        .hash;
+    signed_intent
+        .intent_signatures
+        .signatures
+        .push(IntentSignatureV1(
+            test_data::private_key1().sign_with_public_key(&intent_hash),
+        ));
+
+    // Act
+    let issues = radix_engine_toolkit_core::functions::signed_intent::notary_signatory_issues(
+        &signed_intent,
+    );
+
+    // Assert
+    assert_eq!(
+        issues,
+        Ok(vec![NotarySignatoryIssue::RedundantNotarySignature {
+            index: 3
+        }])
+    )
+}
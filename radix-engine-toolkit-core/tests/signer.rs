@@ -0,0 +1,197 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_core::functions::*;
+use radix_engine_toolkit_core::signer;
+use scrypto::prelude::*;
+use transaction::model::*;
+use transaction::prelude::*;
+
+mod test_data;
+
+#[test]
+fn signer_intent_hash_matches_the_intent_hash() {
+    // Arrange
+    let intent = test_data::intent();
+    let compiled_intent = intent::compile(&intent).unwrap();
+
+    // Act
+    let hash = signer::intent_hash(&compiled_intent).unwrap();
+
+    // Assert
+    assert_eq!(hash.0, intent::hash(&intent).unwrap().hash);
+}
+
+#[test]
+fn instruction_summaries_describe_the_instructions_of_the_intent() {
+    // Arrange
+    let compiled_intent = intent::compile(&test_data::intent()).unwrap();
+    let account1 = test_data::account1();
+    let encoder = AddressBech32Encoder::new(&NetworkDefinition::mainnet());
+
+    // Act
+    let summaries =
+        signer::instruction_summaries(&compiled_intent, &NetworkDefinition::mainnet()).unwrap();
+
+    // Assert
+    assert_eq!(summaries.len(), 2);
+    assert_eq!(
+        summaries[0].to_string(),
+        format!(
+            "Call Method: address {}, method withdraw, arguments (Address(\"{}\"), Decimal(\"10\"))",
+            encoder.encode(account1.as_node_id().as_bytes()).unwrap(),
+            encoder.encode(XRD.as_node_id().as_bytes()).unwrap()
+        )
+    );
+}
+
+#[test]
+fn assembled_signed_intent_matches_the_signed_intent() {
+    // Arrange
+    let signed_intent = test_data::signed_intent();
+    let signatures = signed_intent
+        .intent_signatures
+        .signatures
+        .iter()
+        .map(|signature| signature.0.clone())
+        .collect();
+
+    // Act
+    let compiled =
+        signer::assemble_signed_intent(signed_intent.intent.clone(), signatures).unwrap();
+
+    // Assert
+    assert_eq!(compiled, signed_intent::compile(&signed_intent).unwrap());
+}
+
+#[test]
+fn display_records_chunk_addresses_to_the_maximum_field_length() {
+    // Arrange
+    let compiled_intent = intent::compile(&test_data::intent()).unwrap();
+    let encoder = AddressBech32Encoder::new(&NetworkDefinition::mainnet());
+    let account1 = encoder
+        .encode(test_data::account1().as_node_id().as_bytes())
+        .unwrap();
+
+    // Act
+    let records =
+        signer::display_records(&compiled_intent, &NetworkDefinition::mainnet(), 20).unwrap();
+
+    // Assert
+    assert_eq!(records[0].title, "1/2 Call Method");
+    assert_eq!(records[0].fields[0].name, "address");
+    assert!(records[0].fields[0]
+        .chunks
+        .iter()
+        .all(|chunk| chunk.chars().count() <= 20));
+    assert_eq!(records[0].fields[0].chunks.concat(), account1);
+    assert_eq!(records[0].fields[1].chunks, vec!["withdraw".to_owned()]);
+}
+
+#[test]
+fn summary_hash_only_depends_on_the_displayed_content() {
+    // Arrange
+    let intent = test_data::intent();
+    let mut other_nonce_intent = intent.clone();
+    other_nonce_intent.header.nonce += 1;
+
+    // Act
+    let summary_hash = signer::summary_hash(
+        &intent::compile(&intent).unwrap(),
+        &NetworkDefinition::mainnet(),
+    )
+    .unwrap();
+    let other_nonce_summary_hash = signer::summary_hash(
+        &intent::compile(&other_nonce_intent).unwrap(),
+        &NetworkDefinition::mainnet(),
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(summary_hash, other_nonce_summary_hash);
+    assert_ne!(
+        summary_hash,
+        intent::hash(&intent).unwrap().hash,
+        "The summary hash must not be the hash of the payload"
+    );
+}
+
+#[test]
+fn summary_hash_differs_between_networks() {
+    // Arrange
+    let compiled_intent = intent::compile(&test_data::intent()).unwrap();
+
+    // Act
+    let mainnet_summary_hash =
+        signer::summary_hash(&compiled_intent, &NetworkDefinition::mainnet()).unwrap();
+    let simulator_summary_hash =
+        signer::summary_hash(&compiled_intent, &NetworkDefinition::simulator()).unwrap();
+
+    // Assert
+    assert_ne!(mainnet_summary_hash, simulator_summary_hash);
+}
+
+#[test]
+fn summary_hash_differs_between_intents_withdrawing_different_amounts() {
+    // Arrange
+    let intent = test_data::intent();
+    let mut other_amount_intent = intent.clone();
+    other_amount_intent.instructions.0[0] = InstructionV1::CallMethod {
+        address: DynamicGlobalAddress::Static(test_data::account1().into()),
+        method_name: "withdraw".to_owned(),
+        args: manifest_args!(XRD, dec!("1000")).into(),
+    };
+
+    // Act
+    let summary_hash = signer::summary_hash(
+        &intent::compile(&intent).unwrap(),
+        &NetworkDefinition::mainnet(),
+    )
+    .unwrap();
+    let other_amount_summary_hash = signer::summary_hash(
+        &intent::compile(&other_amount_intent).unwrap(),
+        &NetworkDefinition::mainnet(),
+    )
+    .unwrap();
+
+    // Assert
+    assert_ne!(summary_hash, other_amount_summary_hash);
+}
+
+#[test]
+fn display_records_include_the_arguments_of_invocations() {
+    // Arrange
+    let compiled_intent = intent::compile(&test_data::intent()).unwrap();
+    let encoder = AddressBech32Encoder::new(&NetworkDefinition::mainnet());
+
+    // Act
+    let records =
+        signer::display_records(&compiled_intent, &NetworkDefinition::mainnet(), 100).unwrap();
+
+    // Assert
+    assert_eq!(records[0].fields.len(), 4);
+    assert_eq!(records[0].fields[2].name, "argument 1");
+    assert_eq!(
+        records[0].fields[2].chunks.concat(),
+        format!(
+            "Address(\"{}\")",
+            encoder.encode(XRD.as_node_id().as_bytes()).unwrap()
+        )
+    );
+    assert_eq!(records[0].fields[3].name, "argument 2");
+    assert_eq!(records[0].fields[3].chunks.concat(), "Decimal(\"10\")");
+}
@@ -0,0 +1,155 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_core::functions::signing_state::*;
+use radix_engine_toolkit_core::functions::*;
+use scrypto::prelude::*;
+use transaction::model::*;
+use transaction::prelude::*;
+mod test_data;
+
+#[test]
+fn signing_state_requires_both_withdrawing_accounts() {
+    // Arrange
+    let intent = intent();
+
+    // Act
+    let state = SigningState::new(intent);
+
+    // Assert
+    assert_eq!(
+        state.remaining_signers(),
+        vec![account3().into(), account4().into()]
+    );
+    assert!(!state.can_notarize());
+}
+
+#[test]
+fn signatures_are_tracked_until_the_intent_can_be_notarized() {
+    // Arrange
+    let intent = intent();
+    let intent_hash = radix_engine_toolkit_core::functions::intent::hash(&intent)
+        .unwrap()
+        .hash;
+    let mut state = SigningState::new(intent);
+
+    // Act
+    let first = state.add_signature(test_data::private_key3().sign_with_public_key(&intent_hash));
+    let remaining_after_first = state.remaining_signers();
+    let second = state.add_signature(test_data::private_key4().sign_with_public_key(&intent_hash));
+
+    // Assert
+    assert_eq!(first.unwrap(), vec![GlobalAddress::from(account3())]);
+    assert_eq!(remaining_after_first, vec![GlobalAddress::from(account4())]);
+    assert_eq!(second.unwrap(), vec![GlobalAddress::from(account4())]);
+    assert!(state.can_notarize());
+    assert_eq!(state.signed_intent().intent_signatures.signatures.len(), 2);
+}
+
+#[test]
+fn signatures_of_keys_that_are_not_required_are_rejected() {
+    // Arrange
+    let intent = intent();
+    let intent_hash = radix_engine_toolkit_core::functions::intent::hash(&intent)
+        .unwrap()
+        .hash;
+    let mut state = SigningState::new(intent);
+
+    // Act
+    let result = state.add_signature(test_data::private_key2().sign_with_public_key(&intent_hash));
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(SigningStateError::SignatureNotRequired(..))
+    ));
+}
+
+#[test]
+fn duplicate_signatures_are_rejected() {
+    // Arrange
+    let intent = intent();
+    let intent_hash = radix_engine_toolkit_core::functions::intent::hash(&intent)
+        .unwrap()
+        .hash;
+    let mut state = SigningState::new(intent);
+    state
+        .add_signature(test_data::private_key3().sign_with_public_key(&intent_hash))
+        .unwrap();
+
+    // Act
+    let result = state.add_signature(test_data::private_key3().sign_with_public_key(&intent_hash));
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(SigningStateError::DuplicateSignature(..))
+    ));
+}
+
+#[test]
+fn signing_state_can_be_resumed_from_its_encoded_form() {
+    // Arrange
+    let intent = intent();
+    let intent_hash = radix_engine_toolkit_core::functions::intent::hash(&intent)
+        .unwrap()
+        .hash;
+    let mut state = SigningState::new(intent);
+    state
+        .add_signature(test_data::private_key3().sign_with_public_key(&intent_hash))
+        .unwrap();
+
+    // Act
+    let resumed = SigningState::from_bytes(&state.to_bytes().unwrap()).unwrap();
+
+    // Assert
+    assert_eq!(resumed, state);
+    assert_eq!(
+        resumed.remaining_signers(),
+        vec![GlobalAddress::from(account4())]
+    );
+}
+
+fn intent() -> IntentV1 {
+    TransactionBuilder::new()
+        .manifest(
+            ManifestBuilder::new()
+                .withdraw_from_account(account3(), XRD, dec!("10"))
+                .withdraw_from_account(account4(), XRD, dec!("10"))
+                .try_deposit_entire_worktop_or_abort(test_data::account1(), None)
+                .build(),
+        )
+        .header(TransactionHeaderV1 {
+            network_id: 0x01,
+            start_epoch_inclusive: Epoch::of(100),
+            end_epoch_exclusive: Epoch::of(200),
+            nonce: 100,
+            notary_public_key: test_data::private_key1().public_key().into(),
+            notary_is_signatory: false,
+            tip_percentage: 0,
+        })
+        .build_signed_intent()
+        .intent
+}
+
+fn account3() -> ComponentAddress {
+    derive::virtual_account_address_from_public_key(&test_data::private_key3().public_key())
+}
+
+fn account4() -> ComponentAddress {
+    derive::virtual_account_address_from_public_key(&test_data::private_key4().public_key())
+}
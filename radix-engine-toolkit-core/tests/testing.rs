@@ -0,0 +1,78 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+#![cfg(feature = "testing")]
+
+use radix_engine_toolkit_core::functions::address::*;
+use radix_engine_toolkit_core::testing::*;
+use scrypto::prelude::*;
+
+#[test]
+fn random_addresses_are_valid_for_their_entity_type_and_network() {
+    // Arrange
+    let entity_types = [
+        EntityType::GlobalPackage,
+        EntityType::GlobalFungibleResourceManager,
+        EntityType::GlobalNonFungibleResourceManager,
+        EntityType::GlobalGenericComponent,
+        EntityType::GlobalVirtualEd25519Account,
+        EntityType::InternalFungibleVault,
+    ];
+
+    for network_id in [0x01, 0x02, 0xF2] {
+        for entity_type in entity_types {
+            // Act
+            let address = random_address(entity_type, network_id);
+
+            // Assert
+            assert!(validate(&address, Some(network_id), &[entity_type]).is_empty());
+        }
+    }
+}
+
+#[test]
+fn random_addresses_are_different_from_one_another() {
+    // Arrange
+    let entity_type = EntityType::GlobalGenericComponent;
+
+    // Act
+    let address1 = random_address(entity_type, 0x01);
+    let address2 = random_address(entity_type, 0x01);
+
+    // Assert
+    assert_ne!(address1, address2);
+}
+
+#[test]
+fn random_non_fungible_local_ids_have_the_requested_type() {
+    // Arrange
+    let id_types = [
+        NonFungibleIdType::Integer,
+        NonFungibleIdType::String,
+        NonFungibleIdType::Bytes,
+        NonFungibleIdType::RUID,
+    ];
+
+    for id_type in id_types {
+        // Act
+        let non_fungible_global_id = random_non_fungible_global_id(id_type);
+
+        // Assert
+        assert_eq!(non_fungible_global_id.local_id().id_type(), id_type);
+        assert!(!non_fungible_global_id.resource_address().is_fungible());
+    }
+}
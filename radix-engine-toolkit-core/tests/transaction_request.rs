@@ -0,0 +1,111 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_core::functions::transaction_request::*;
+use scrypto::prelude::*;
+mod test_data;
+
+#[test]
+fn request_constructed_from_a_manifest_compiles_back_into_it() {
+    // Arrange
+    let manifest = test_data::manifest();
+
+    // Act
+    let request = from_manifest(&manifest, 0x01, None, None).unwrap();
+    let compiled = to_manifest(&request, 0x01).unwrap();
+
+    // Assert
+    assert_eq!(request.version, TRANSACTION_REQUEST_VERSION);
+    assert_eq!(compiled.instructions, manifest.instructions);
+    assert!(validate(&request, 0x01, &TransactionRequestLimits::default()).is_empty());
+}
+
+#[test]
+fn normalization_makes_equivalent_requests_equal() {
+    // Arrange
+    let request = from_manifest(&test_data::manifest(), 0x01, None, None).unwrap();
+    let equivalent_request = TransactionRequest {
+        transaction_manifest: format!(
+            "\r\n  {}  \r\n",
+            request.transaction_manifest.replace('\n', "\r\n")
+        ),
+        blobs: vec![vec![1, 2, 3], vec![1, 2, 3]],
+        message: Some("  ".to_owned()),
+        ..request.clone()
+    };
+
+    // Act
+    let normalized = normalize(equivalent_request);
+
+    // Assert
+    assert_eq!(
+        normalized.transaction_manifest,
+        request.transaction_manifest
+    );
+    assert_eq!(normalized.blobs, vec![vec![1, 2, 3]]);
+    assert_eq!(normalized.message, None);
+}
+
+#[test]
+fn all_request_violations_are_reported_at_once() {
+    // Arrange
+    let request = TransactionRequest {
+        version: TRANSACTION_REQUEST_VERSION + 1,
+        transaction_manifest: "CALL_METHOD".to_owned(),
+        blobs: vec![vec![0; 10]],
+        message: Some("Hello World".to_owned()),
+        lock_fee_hint: Some(LockFeeHint {
+            amount: Decimal::ZERO,
+            account: Some(FAUCET),
+        }),
+    };
+    let limits = TransactionRequestLimits {
+        max_manifest_length: 1024,
+        max_blobs_size: 5,
+        max_message_length: 5,
+    };
+
+    // Act
+    let violations = validate(&request, 0x01, &limits);
+
+    // Assert
+    assert_eq!(violations.len(), 6);
+    assert!(matches!(
+        violations[0],
+        TransactionRequestViolation::UnsupportedVersion { .. }
+    ));
+    assert!(matches!(
+        violations[1],
+        TransactionRequestViolation::BlobsTooLarge { size: 10, max: 5 }
+    ));
+    assert!(matches!(
+        violations[2],
+        TransactionRequestViolation::MessageTooLong { length: 11, max: 5 }
+    ));
+    assert!(matches!(
+        violations[3],
+        TransactionRequestViolation::LockFeeHintNotPositive { .. }
+    ));
+    assert!(matches!(
+        violations[4],
+        TransactionRequestViolation::LockFeeHintAccountNotAnAccount { address: FAUCET }
+    ));
+    assert!(matches!(
+        violations[5],
+        TransactionRequestViolation::InvalidManifest(..)
+    ));
+}
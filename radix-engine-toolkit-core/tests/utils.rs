@@ -234,6 +234,122 @@ fn decoding_transaction_id_succeeds() {
     }
 }
 
+#[test]
+fn addresses_of_registered_networks_can_be_encoded_and_decoded() {
+    // Arrange
+    let network_definition = NetworkDefinition {
+        id: 0x7E,
+        logical_name: "privatenet".to_owned(),
+        hrp_suffix: "tdx_private_".to_owned(),
+    };
+    radix_engine_toolkit_core::utils::register_network_definition(network_definition.clone())
+        .unwrap();
+
+    // Act
+    let encoded = AddressBech32Encoder::new(
+        &radix_engine_toolkit_core::utils::network_definition_from_network_id(0x7E),
+    )
+    .encode(XRD.as_node_id().as_bytes())
+    .unwrap();
+    let network_id = radix_engine_toolkit_core::utils::network_id_from_address_string(&encoded);
+
+    // Assert
+    assert_eq!(encoded.split('1').next(), Some("resource_tdx_private_"));
+    assert_eq!(network_id, Some(0x7E));
+    assert!(radix_engine_toolkit_core::utils::network_definitions().contains(&network_definition));
+}
+
+#[test]
+fn built_in_networks_can_not_be_redefined() {
+    // Arrange
+    let network_definition = NetworkDefinition {
+        id: 0x01,
+        logical_name: "mainnet".to_owned(),
+        hrp_suffix: "main".to_owned(),
+    };
+
+    // Act
+    let result = radix_engine_toolkit_core::utils::register_network_definition(network_definition);
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(radix_engine_toolkit_core::utils::NetworkRegistrationError::NetworkIdIsBuiltIn(0x01))
+    );
+}
+
+#[test]
+fn networks_with_hrp_suffixes_in_use_can_not_be_registered() {
+    // Arrange
+    let network_definition = NetworkDefinition {
+        id: 0x7F,
+        logical_name: "impostornet".to_owned(),
+        hrp_suffix: "rdx".to_owned(),
+    };
+
+    // Act
+    let result = radix_engine_toolkit_core::utils::register_network_definition(network_definition);
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(
+            radix_engine_toolkit_core::utils::NetworkRegistrationError::HrpSuffixInUse {
+                hrp_suffix: "rdx".to_owned(),
+                network_id: 0x01
+            }
+        )
+    );
+}
+
+#[test]
+fn networks_with_hrp_suffixes_ending_another_suffix_can_not_be_registered() {
+    // Arrange
+    let network_definition = NetworkDefinition {
+        id: 0x7D,
+        logical_name: "shadownet".to_owned(),
+        hrp_suffix: "2_".to_owned(),
+    };
+
+    // Act
+    let result = radix_engine_toolkit_core::utils::register_network_definition(network_definition);
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(
+            radix_engine_toolkit_core::utils::NetworkRegistrationError::HrpSuffixInUse {
+                hrp_suffix: "2_".to_owned(),
+                network_id: 0x02
+            }
+        )
+    );
+}
+
+#[test]
+fn networks_with_implicit_hrp_suffix_of_another_network_can_not_be_registered() {
+    // Arrange
+    let network_definition = NetworkDefinition {
+        id: 0x7C,
+        logical_name: "impostornet".to_owned(),
+        hrp_suffix: "tdx_30_".to_owned(),
+    };
+
+    // Act
+    let result = radix_engine_toolkit_core::utils::register_network_definition(network_definition);
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(
+            radix_engine_toolkit_core::utils::NetworkRegistrationError::HrpSuffixInUse {
+                hrp_suffix: "tdx_30_".to_owned(),
+                network_id: 0x30
+            }
+        )
+    );
+}
+
 #[derive(NonFungibleData, ScryptoSbor, ManifestSbor)]
 struct Owl {
     name: String,
@@ -253,3 +369,19 @@ fn add_execution_trace(transaction_receipt: TransactionReceipt) -> TransactionRe
         ..transaction_receipt
     }
 }
+
+#[test]
+fn first_divergence_of_payloads_is_reported() {
+    // Arrange
+    let original = [0x4d, 0x22, 0x01, 0x02];
+
+    // Act
+    let identical = first_divergence(&original, &[0x4d, 0x22, 0x01, 0x02]);
+    let modified = first_divergence(&original, &[0x4d, 0x22, 0x03, 0x02]);
+    let truncated = first_divergence(&original, &[0x4d, 0x22]);
+
+    // Assert
+    assert_eq!(identical, None);
+    assert_eq!(modified, Some(2));
+    assert_eq!(truncated, Some(2));
+}
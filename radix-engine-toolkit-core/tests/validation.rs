@@ -0,0 +1,169 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_core::functions::validation::*;
+use scrypto::prelude::*;
+use transaction::model::*;
+use transaction::validation::*;
+mod test_data;
+
+#[test]
+fn valid_notarized_transaction_has_no_violations() {
+    // Arrange
+    let transaction = test_data::notarized_transaction();
+
+    // Act
+    let violations = validate_notarized_transaction(&transaction, &ValidationConfig::default(0x01));
+
+    // Assert
+    assert!(violations.is_empty(), "{violations:?}");
+}
+
+#[test]
+fn all_header_violations_are_reported_at_once() {
+    // Arrange
+    let mut header = test_data::intent().header;
+    header.network_id = 0x02;
+    header.end_epoch_exclusive = header.start_epoch_inclusive;
+    header.tip_percentage = u16::MAX;
+
+    // Act
+    let violations = validate_header(&header, &ValidationConfig::default(0x01));
+
+    // Assert
+    assert_eq!(violations.len(), 3);
+    assert!(matches!(
+        violations[0],
+        StaticValidationViolation::NetworkIdMismatch {
+            expected: 0x01,
+            actual: 0x02
+        }
+    ));
+    assert!(matches!(
+        violations[1],
+        StaticValidationViolation::EmptyEpochRange { .. }
+    ));
+    assert!(matches!(
+        violations[2],
+        StaticValidationViolation::TipPercentageOutOfRange {
+            tip_percentage: u16::MAX,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn too_large_epoch_range_is_reported() {
+    // Arrange
+    let validation_config = ValidationConfig::default(0x01);
+    let mut header = test_data::intent().header;
+    header.end_epoch_exclusive =
+        Epoch::of(header.start_epoch_inclusive.number() + validation_config.max_epoch_range + 1);
+
+    // Act
+    let violations = validate_header(&header, &validation_config);
+
+    // Assert
+    assert!(matches!(
+        violations.as_slice(),
+        [StaticValidationViolation::EpochRangeTooLarge { .. }]
+    ));
+}
+
+#[test]
+fn all_message_violations_are_reported_at_once() {
+    // Arrange
+    let message_validation_config = MessageValidationConfig::default();
+    let message = MessageV1::Plaintext(PlaintextMessageV1 {
+        mime_type: "a".repeat(message_validation_config.max_mime_type_length + 1),
+        message: MessageContentsV1::String(
+            "a".repeat(message_validation_config.max_plaintext_message_length + 1),
+        ),
+    });
+
+    // Act
+    let violations = validate_message(&message, &message_validation_config);
+
+    // Assert
+    assert!(matches!(
+        violations.as_slice(),
+        [
+            StaticValidationViolation::MimeTypeTooLong { .. },
+            StaticValidationViolation::PlaintextMessageTooLong { .. }
+        ]
+    ));
+}
+
+#[test]
+fn too_many_intent_signatures_are_reported() {
+    // Arrange
+    let mut signed_intent = test_data::signed_intent();
+    signed_intent.intent_signatures.signatures = vec![
+        signed_intent.intent_signatures.signatures[0]
+            .clone();
+        MAX_NUMBER_OF_INTENT_SIGNATURES + 1
+    ];
+
+    // Act
+    let violations = validate_intent_signature_count(&signed_intent);
+
+    // Assert
+    assert!(matches!(
+        violations.as_slice(),
+        [StaticValidationViolation::TooManyIntentSignatures { count, .. }]
+            if *count == MAX_NUMBER_OF_INTENT_SIGNATURES + 1
+    ));
+}
+
+#[test]
+fn invalid_id_allocation_is_reported() {
+    // Arrange
+    let instructions = vec![InstructionV1::DropProof {
+        proof_id: ManifestProof(1),
+    }];
+
+    // Act
+    let violations = validate_id_allocation(&instructions);
+
+    // Assert
+    assert!(matches!(
+        violations.as_slice(),
+        [StaticValidationViolation::InvalidIdAllocation(..)]
+    ));
+}
+
+#[test]
+fn violations_of_different_rules_are_all_reported_for_an_intent() {
+    // Arrange
+    let mut intent = test_data::intent();
+    intent.header.network_id = 0x02;
+    intent.instructions.0.push(InstructionV1::DropProof {
+        proof_id: ManifestProof(1),
+    });
+
+    // Act
+    let violations = validate_intent(&intent, &ValidationConfig::default(0x01));
+
+    // Assert
+    assert!(matches!(
+        violations.as_slice(),
+        [
+            StaticValidationViolation::NetworkIdMismatch { .. },
+            StaticValidationViolation::InvalidIdAllocation(..)
+        ]
+    ));
+}
@@ -0,0 +1,51 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_core::functions::instructions::worktop_guarantees;
+use radix_engine_toolkit_core::instruction_visitor::visitors::worktop_guarantees_visitor::*;
+use scrypto::prelude::*;
+use transaction::prelude::*;
+
+#[test]
+fn worktop_assertions_are_reported_with_their_instruction_index() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .call_method(FAUCET, "free", manifest_args!())
+        .assert_worktop_contains(XRD, dec!("10"))
+        .assert_worktop_contains_any(XRD)
+        .build();
+
+    // Act
+    let guarantees = worktop_guarantees(&manifest.instructions);
+
+    // Assert
+    assert_eq!(
+        guarantees,
+        vec![
+            WorktopGuarantee {
+                instruction_index: 1,
+                resource_address: XRD,
+                guarantee: GuaranteeKind::Amount(dec!("10"))
+            },
+            WorktopGuarantee {
+                instruction_index: 2,
+                resource_address: XRD,
+                guarantee: GuaranteeKind::Any
+            }
+        ]
+    );
+}
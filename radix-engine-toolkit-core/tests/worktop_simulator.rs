@@ -0,0 +1,132 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_core::functions::instructions::simulated_deposits;
+use radix_engine_toolkit_core::instruction_visitor::visitors::worktop_simulator_visitor::*;
+use scrypto::prelude::*;
+use transaction::prelude::*;
+
+mod test_data;
+
+#[test]
+fn deposits_of_withdrawn_resources_are_exact() {
+    // Arrange
+    let account1 = test_data::account1();
+    let account2 = test_data::account2();
+    let account3 = test_data::account3();
+
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account1, XRD, dec!("100"))
+        .take_from_worktop(XRD, dec!("30"), "bucket")
+        .try_deposit_or_abort(account2, None, "bucket")
+        .try_deposit_entire_worktop_or_abort(account3, None)
+        .build();
+
+    // Act
+    let deposits = simulated_deposits(&manifest.instructions);
+
+    // Assert
+    assert_eq!(
+        deposits.get(&account2),
+        Some(&vec![SimulatedDeposit {
+            instruction_index: 2,
+            resource_address: Some(XRD),
+            amount: SimulatedAmount::Exact(dec!("30")),
+        }])
+    );
+    assert_eq!(
+        deposits.get(&account3),
+        Some(&vec![SimulatedDeposit {
+            instruction_index: 3,
+            resource_address: Some(XRD),
+            amount: SimulatedAmount::Exact(dec!("70")),
+        }])
+    );
+}
+
+#[test]
+fn deposits_after_calls_to_components_are_unknown() {
+    // Arrange
+    let account1 = test_data::account1();
+
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account1, XRD, dec!("100"))
+        .call_method(FAUCET, "free", manifest_args!())
+        .take_from_worktop(XRD, dec!("30"), "bucket")
+        .try_deposit_or_abort(account1, None, "bucket")
+        .try_deposit_entire_worktop_or_abort(account1, None)
+        .build();
+
+    // Act
+    let deposits = simulated_deposits(&manifest.instructions);
+
+    // Assert
+    assert_eq!(
+        deposits.get(&account1),
+        Some(&vec![
+            SimulatedDeposit {
+                instruction_index: 3,
+                resource_address: Some(XRD),
+                amount: SimulatedAmount::Exact(dec!("30")),
+            },
+            SimulatedDeposit {
+                instruction_index: 4,
+                resource_address: Some(XRD),
+                amount: SimulatedAmount::Unknown,
+            },
+            SimulatedDeposit {
+                instruction_index: 4,
+                resource_address: None,
+                amount: SimulatedAmount::Unknown,
+            }
+        ])
+    );
+}
+
+#[test]
+fn refundable_deposits_are_unknown() {
+    // Arrange
+    let account1 = test_data::account1();
+    let account2 = test_data::account2();
+
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account1, XRD, dec!("100"))
+        .try_deposit_entire_worktop_or_refund(account2, None)
+        .try_deposit_entire_worktop_or_abort(account1, None)
+        .build();
+
+    // Act
+    let deposits = simulated_deposits(&manifest.instructions);
+
+    // Assert
+    assert_eq!(
+        deposits.get(&account2),
+        Some(&vec![SimulatedDeposit {
+            instruction_index: 1,
+            resource_address: Some(XRD),
+            amount: SimulatedAmount::Unknown,
+        }])
+    );
+    assert_eq!(
+        deposits.get(&account1),
+        Some(&vec![SimulatedDeposit {
+            instruction_index: 2,
+            resource_address: None,
+            amount: SimulatedAmount::Unknown,
+        }])
+    );
+}
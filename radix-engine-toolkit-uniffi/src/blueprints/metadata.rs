@@ -35,7 +35,12 @@ impl ToNative for MetadataInit {
     type Native = NativeMetadataInit;
 
     fn to_native(self) -> Result<Self::Native> {
-        self.into_iter()
+        // The entries are sorted by key as the iteration order of the map is not deterministic and
+        // the order of the entries is part of the encoded manifest.
+        let mut entries = self.into_iter().collect::<Vec<_>>();
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+        entries
+            .into_iter()
             .map(|(key, value)| {
                 let metadata = match value.value.map(|value| value.to_native()) {
                     Some(Ok(metadata)) => Some(metadata),
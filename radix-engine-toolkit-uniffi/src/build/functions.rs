@@ -22,6 +22,12 @@ pub fn build_information() -> BuildInformation {
     core_information().into()
 }
 
+/// The methods which the static analyzers treat as requiring auth, per native blueprint or module.
+#[uniffi::export]
+pub fn methods_that_require_auth() -> MethodsThatRequireAuth {
+    core_methods_that_require_auth().into()
+}
+
 #[derive(Clone, Record)]
 pub struct BuildInformation {
     pub version: String,
@@ -51,3 +57,35 @@ impl From<CoreBuildInformation> for BuildInformation {
         }
     }
 }
+
+#[derive(Clone, Debug, PartialEq, Eq, Record)]
+pub struct MethodsThatRequireAuth {
+    pub account: Vec<String>,
+    pub identity: Vec<String>,
+    pub validator: Vec<String>,
+    pub role_assignment: Vec<String>,
+    pub metadata: Vec<String>,
+    pub royalty: Vec<String>,
+}
+
+impl From<CoreMethodsThatRequireAuth> for MethodsThatRequireAuth {
+    fn from(
+        CoreMethodsThatRequireAuth {
+            account,
+            identity,
+            validator,
+            role_assignment,
+            metadata,
+            royalty,
+        }: CoreMethodsThatRequireAuth,
+    ) -> Self {
+        Self {
+            account,
+            identity,
+            validator,
+            role_assignment,
+            metadata,
+            royalty,
+        }
+    }
+}
@@ -21,18 +21,35 @@ use crate::prelude::*;
 use radix_engine::types::FromPublicKey;
 use radix_engine_common::prelude::to_manifest_value;
 
-#[derive(Debug, Clone, Object, Default)]
+#[derive(Debug, Clone, Object)]
 pub struct ManifestBuilder {
     name_record: NameRecord,
     instructions: Vec<NativeInstruction>,
     blobs: Vec<Vec<u8>>,
 }
 
+/// A snapshot of the state of a [`ManifestBuilder`] that the builder can be rolled back to. The name
+/// record is captured in full so that the names of buckets, proofs, address reservations, and named
+/// addresses created after the checkpoint are freed by the rollback and their ids are reallocated.
+#[derive(Debug, Clone, Object)]
+pub struct ManifestBuilderCheckpoint {
+    /// The hash of the instructions and blobs of the builder at the checkpoint, used to reject the
+    /// rollback of builders whose instructions and blobs do not start with them.
+    prefix_hash: NativeHash,
+    name_record: NameRecord,
+    instructions_length: usize,
+    blobs_length: usize,
+}
+
 #[uniffi::export]
 impl ManifestBuilder {
     #[uniffi::constructor]
     pub fn new() -> Arc<Self> {
-        Arc::new(Default::default())
+        Arc::new(Self {
+            name_record: Default::default(),
+            instructions: Default::default(),
+            blobs: Default::default(),
+        })
     }
 
     //===================
@@ -592,6 +609,46 @@ impl ManifestBuilder {
 
     /* Account */
 
+    pub fn account_lock_fee(
+        self: Arc<Self>,
+        account_address: Arc<Address>,
+        amount: Arc<Decimal>,
+    ) -> Result<Arc<Self>> {
+        builder_arc_map(self, |builder| {
+            let account_address = NativeGlobalAddress::try_from(*account_address)?;
+            let amount = amount.0;
+
+            let instruction = NativeInstruction::CallMethod {
+                address: NativeDynamicGlobalAddress::Static(account_address),
+                method_name: NATIVE_ACCOUNT_LOCK_FEE_IDENT.to_owned(),
+                args: native_to_manifest_value_and_unwrap!(&NativeAccountLockFeeInput { amount }),
+            };
+            builder.instructions.push(instruction);
+            Ok(())
+        })
+    }
+
+    pub fn account_lock_contingent_fee(
+        self: Arc<Self>,
+        account_address: Arc<Address>,
+        amount: Arc<Decimal>,
+    ) -> Result<Arc<Self>> {
+        builder_arc_map(self, |builder| {
+            let account_address = NativeGlobalAddress::try_from(*account_address)?;
+            let amount = amount.0;
+
+            let instruction = NativeInstruction::CallMethod {
+                address: NativeDynamicGlobalAddress::Static(account_address),
+                method_name: NATIVE_ACCOUNT_LOCK_CONTINGENT_FEE_IDENT.to_owned(),
+                args: native_to_manifest_value_and_unwrap!(&NativeAccountLockContingentFeeInput {
+                    amount
+                }),
+            };
+            builder.instructions.push(instruction);
+            Ok(())
+        })
+    }
+
     pub fn withdraw_from_account(
         self: Arc<Self>,
         account_address: Arc<Address>,
@@ -734,6 +791,59 @@ impl ManifestBuilder {
         })
     }
 
+    /// Presents the authorized depositor badge by creating a proof of it from the account holding
+    /// the badge and then deposits the bucket into the account with the badge. The deposit goes
+    /// through the deposit rules of the account when the badge is one of its authorized depositors.
+    pub fn account_try_deposit_or_abort_with_authorized_depositor_badge(
+        self: Arc<Self>,
+        account_address: Arc<Address>,
+        badge_holder_address: Arc<Address>,
+        authorized_depositor_badge: ResourceOrNonFungible,
+        bucket: ManifestBuilderBucket,
+    ) -> Result<Arc<Self>> {
+        builder_arc_map(self, |builder| {
+            let address = NativeGlobalAddress::try_from(*account_address)?;
+            let badge_holder_address = NativeGlobalAddress::try_from(*badge_holder_address)?;
+            let bucket = builder.name_record.get_bucket(&bucket.name)?;
+            let authorized_depositor_badge = authorized_depositor_badge.to_native()?;
+
+            let create_proof_instruction = match &authorized_depositor_badge {
+                NativeResourceOrNonFungible::Resource(resource_address) => {
+                    NativeInstruction::CallMethod {
+                        address: NativeDynamicGlobalAddress::Static(badge_holder_address),
+                        method_name: NATIVE_ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT.to_owned(),
+                        args: native_to_manifest_value_and_unwrap!(
+                            &NativeAccountCreateProofOfAmountInput {
+                                resource_address: *resource_address,
+                                amount: NativeDecimal::ONE,
+                            }
+                        ),
+                    }
+                }
+                NativeResourceOrNonFungible::NonFungible(non_fungible_global_id) => {
+                    NativeInstruction::CallMethod {
+                        address: NativeDynamicGlobalAddress::Static(badge_holder_address),
+                        method_name: NATIVE_ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT.to_owned(),
+                        args: native_to_manifest_value_and_unwrap!(
+                            &NativeAccountCreateProofOfNonFungiblesInput {
+                                resource_address: non_fungible_global_id.resource_address(),
+                                ids: [non_fungible_global_id.local_id().clone()].into(),
+                            }
+                        ),
+                    }
+                }
+            };
+            let deposit_instruction = NativeInstruction::CallMethod {
+                address: NativeDynamicGlobalAddress::Static(address),
+                method_name: NATIVE_ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT.to_owned(),
+                args: manifest_args!(*bucket, Some(authorized_depositor_badge)).into(),
+            };
+            builder.instructions.push(create_proof_instruction);
+            builder.instructions.push(deposit_instruction);
+            Ok(())
+        })
+    }
+
     pub fn account_deposit_batch(
         self: Arc<Self>,
         account_address: Arc<Address>,
@@ -805,6 +915,159 @@ impl ManifestBuilder {
         })
     }
 
+    /// Deposits the bucket into the account using the given deposit mode. The authorized depositor
+    /// badge is only used by the try-deposit modes and is ignored for [`AccountDepositMode::Deposit`].
+    pub fn account_deposit_with_mode(
+        self: Arc<Self>,
+        account_address: Arc<Address>,
+        mode: AccountDepositMode,
+        authorized_depositor_badge: Option<ResourceOrNonFungible>,
+        bucket: ManifestBuilderBucket,
+    ) -> Result<Arc<Self>> {
+        match mode {
+            AccountDepositMode::Deposit => self.account_deposit(account_address, bucket),
+            AccountDepositMode::TryDepositOrAbort => self.account_try_deposit_or_abort(
+                account_address,
+                authorized_depositor_badge,
+                bucket,
+            ),
+            AccountDepositMode::TryDepositOrRefund => self.account_try_deposit_or_refund(
+                account_address,
+                authorized_depositor_badge,
+                bucket,
+            ),
+        }
+    }
+
+    /// Deposits the entire worktop into the account using the given deposit mode. The authorized
+    /// depositor badge is only used by the try-deposit modes and is ignored for
+    /// [`AccountDepositMode::Deposit`].
+    pub fn account_deposit_batch_with_mode(
+        self: Arc<Self>,
+        account_address: Arc<Address>,
+        mode: AccountDepositMode,
+        authorized_depositor_badge: Option<ResourceOrNonFungible>,
+    ) -> Result<Arc<Self>> {
+        match mode {
+            AccountDepositMode::Deposit => self.account_deposit_batch(account_address),
+            AccountDepositMode::TryDepositOrAbort => {
+                self.account_try_deposit_batch_or_abort(account_address, authorized_depositor_badge)
+            }
+            AccountDepositMode::TryDepositOrRefund => self
+                .account_try_deposit_batch_or_refund(account_address, authorized_depositor_badge),
+        }
+    }
+
+    pub fn account_set_default_deposit_rule(
+        self: Arc<Self>,
+        account_address: Arc<Address>,
+        default_deposit_rule: AccountDefaultDepositRule,
+    ) -> Result<Arc<Self>> {
+        builder_arc_map(self, |builder| {
+            let address = NativeGlobalAddress::try_from(*account_address)?;
+            let default = default_deposit_rule.to_native()?;
+
+            let instruction = NativeInstruction::CallMethod {
+                address: NativeDynamicGlobalAddress::Static(address),
+                method_name: NATIVE_ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT.to_owned(),
+                args: native_to_manifest_value_and_unwrap!(
+                    &NativeAccountSetDefaultDepositRuleInput { default }
+                ),
+            };
+            builder.instructions.push(instruction);
+            Ok(())
+        })
+    }
+
+    pub fn account_set_resource_preference(
+        self: Arc<Self>,
+        account_address: Arc<Address>,
+        resource_address: Arc<Address>,
+        resource_preference: ResourcePreference,
+    ) -> Result<Arc<Self>> {
+        builder_arc_map(self, |builder| {
+            let address = NativeGlobalAddress::try_from(*account_address)?;
+            let resource_address = NativeResourceAddress::try_from(*resource_address)?;
+            let resource_preference = resource_preference.to_native()?;
+
+            let instruction = NativeInstruction::CallMethod {
+                address: NativeDynamicGlobalAddress::Static(address),
+                method_name: NATIVE_ACCOUNT_SET_RESOURCE_PREFERENCE_IDENT.to_owned(),
+                args: native_to_manifest_value_and_unwrap!(
+                    &NativeAccountSetResourcePreferenceInput {
+                        resource_address,
+                        resource_preference
+                    }
+                ),
+            };
+            builder.instructions.push(instruction);
+            Ok(())
+        })
+    }
+
+    pub fn account_remove_resource_preference(
+        self: Arc<Self>,
+        account_address: Arc<Address>,
+        resource_address: Arc<Address>,
+    ) -> Result<Arc<Self>> {
+        builder_arc_map(self, |builder| {
+            let address = NativeGlobalAddress::try_from(*account_address)?;
+            let resource_address = NativeResourceAddress::try_from(*resource_address)?;
+
+            let instruction = NativeInstruction::CallMethod {
+                address: NativeDynamicGlobalAddress::Static(address),
+                method_name: NATIVE_ACCOUNT_REMOVE_RESOURCE_PREFERENCE_IDENT.to_owned(),
+                args: native_to_manifest_value_and_unwrap!(
+                    &NativeAccountRemoveResourcePreferenceInput { resource_address }
+                ),
+            };
+            builder.instructions.push(instruction);
+            Ok(())
+        })
+    }
+
+    pub fn account_add_authorized_depositor(
+        self: Arc<Self>,
+        account_address: Arc<Address>,
+        badge: ResourceOrNonFungible,
+    ) -> Result<Arc<Self>> {
+        builder_arc_map(self, |builder| {
+            let address = NativeGlobalAddress::try_from(*account_address)?;
+            let badge = badge.to_native()?;
+
+            let instruction = NativeInstruction::CallMethod {
+                address: NativeDynamicGlobalAddress::Static(address),
+                method_name: NATIVE_ACCOUNT_ADD_AUTHORIZED_DEPOSITOR.to_owned(),
+                args: native_to_manifest_value_and_unwrap!(
+                    &NativeAccountAddAuthorizedDepositorInput { badge }
+                ),
+            };
+            builder.instructions.push(instruction);
+            Ok(())
+        })
+    }
+
+    pub fn account_remove_authorized_depositor(
+        self: Arc<Self>,
+        account_address: Arc<Address>,
+        badge: ResourceOrNonFungible,
+    ) -> Result<Arc<Self>> {
+        builder_arc_map(self, |builder| {
+            let address = NativeGlobalAddress::try_from(*account_address)?;
+            let badge = badge.to_native()?;
+
+            let instruction = NativeInstruction::CallMethod {
+                address: NativeDynamicGlobalAddress::Static(address),
+                method_name: NATIVE_ACCOUNT_REMOVE_AUTHORIZED_DEPOSITOR.to_owned(),
+                args: native_to_manifest_value_and_unwrap!(
+                    &NativeAccountRemoveAuthorizedDepositorInput { badge }
+                ),
+            };
+            builder.instructions.push(instruction);
+            Ok(())
+        })
+    }
+
     /* Package */
 
     pub fn publish_package(
@@ -834,6 +1097,117 @@ impl ManifestBuilder {
         })
     }
 
+    /* Royalty */
+
+    pub fn set_component_royalty(
+        self: Arc<Self>,
+        component_address: Arc<Address>,
+        method: String,
+        amount: RoyaltyAmount,
+    ) -> Result<Arc<Self>> {
+        builder_arc_map(self, |builder| {
+            let address = NativeGlobalAddress::try_from(*component_address)?;
+            let amount = amount.to_native()?;
+
+            let instruction = NativeInstruction::CallRoyaltyMethod {
+                address: NativeDynamicGlobalAddress::Static(address),
+                method_name: NATIVE_COMPONENT_ROYALTY_SET_ROYALTY_IDENT.to_owned(),
+                args: native_to_manifest_value_and_unwrap!(&NativeComponentRoyaltySetInput {
+                    method,
+                    amount
+                }),
+            };
+            builder.instructions.push(instruction);
+            Ok(())
+        })
+    }
+
+    pub fn lock_component_royalty(
+        self: Arc<Self>,
+        component_address: Arc<Address>,
+        method: String,
+    ) -> Result<Arc<Self>> {
+        builder_arc_map(self, |builder| {
+            let address = NativeGlobalAddress::try_from(*component_address)?;
+
+            let instruction = NativeInstruction::CallRoyaltyMethod {
+                address: NativeDynamicGlobalAddress::Static(address),
+                method_name: NATIVE_COMPONENT_ROYALTY_LOCK_ROYALTY_IDENT.to_owned(),
+                args: native_to_manifest_value_and_unwrap!(&NativeComponentRoyaltyLockInput {
+                    method
+                }),
+            };
+            builder.instructions.push(instruction);
+            Ok(())
+        })
+    }
+
+    pub fn claim_component_royalties(
+        self: Arc<Self>,
+        component_address: Arc<Address>,
+    ) -> Result<Arc<Self>> {
+        builder_arc_map(self, |builder| {
+            let address = NativeGlobalAddress::try_from(*component_address)?;
+
+            let instruction = NativeInstruction::CallRoyaltyMethod {
+                address: NativeDynamicGlobalAddress::Static(address),
+                method_name: NATIVE_COMPONENT_ROYALTY_CLAIM_ROYALTIES_IDENT.to_owned(),
+                args: native_to_manifest_value_and_unwrap!(&NativeComponentClaimRoyaltiesInput {}),
+            };
+            builder.instructions.push(instruction);
+            Ok(())
+        })
+    }
+
+    pub fn claim_package_royalties(
+        self: Arc<Self>,
+        package_address: Arc<Address>,
+    ) -> Result<Arc<Self>> {
+        builder_arc_map(self, |builder| {
+            let address = NativeGlobalAddress::try_from(*package_address)?;
+
+            let instruction = NativeInstruction::CallMethod {
+                address: NativeDynamicGlobalAddress::Static(address),
+                method_name: NATIVE_PACKAGE_CLAIM_ROYALTIES_IDENT.to_owned(),
+                args: native_to_manifest_value_and_unwrap!(&NativePackageClaimRoyaltiesInput {}),
+            };
+            builder.instructions.push(instruction);
+            Ok(())
+        })
+    }
+
+    /* Identity */
+
+    pub fn create_identity(self: Arc<Self>) -> Result<Arc<Self>> {
+        builder_arc_map(self, |builder| {
+            let instruction = NativeInstruction::CallFunction {
+                package_address: NativeDynamicPackageAddress::Static(NATIVE_IDENTITY_PACKAGE),
+                blueprint_name: NATIVE_IDENTITY_BLUEPRINT.to_owned(),
+                function_name: NATIVE_IDENTITY_CREATE_IDENT.to_owned(),
+                args: native_to_manifest_value_and_unwrap!(&NativeIdentityCreateInput {}),
+            };
+            builder.instructions.push(instruction);
+            Ok(())
+        })
+    }
+
+    pub fn create_identity_advanced(self: Arc<Self>, owner_role: OwnerRole) -> Result<Arc<Self>> {
+        builder_arc_map(self, |builder| {
+            let owner_role = owner_role.to_native()?;
+
+            let instruction = NativeInstruction::CallFunction {
+                package_address: NativeDynamicPackageAddress::Static(NATIVE_IDENTITY_PACKAGE),
+                blueprint_name: NATIVE_IDENTITY_BLUEPRINT.to_owned(),
+                function_name: NATIVE_IDENTITY_CREATE_ADVANCED_IDENT.to_owned(),
+                args: native_to_manifest_value_and_unwrap!(&NativeIdentityCreateAdvancedInput {
+                    owner_role
+                }),
+            };
+            builder.instructions.push(instruction);
+            Ok(())
+        })
+    }
+
     /* Access Controller */
 
     pub fn access_controller_initiate_recovery(
@@ -940,6 +1314,146 @@ impl ManifestBuilder {
         })
     }
 
+    pub fn access_controller_timed_confirm_recovery(
+        self: Arc<Self>,
+        access_controller_address: Arc<Address>,
+        proposed_primary_role: Arc<AccessRule>,
+        proposed_recovery_role: Arc<AccessRule>,
+        proposed_confirmation_role: Arc<AccessRule>,
+        proposed_timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> Result<Arc<Self>> {
+        builder_arc_map(self, |builder| {
+            let rule_set = NativeRuleSet {
+                primary_role: proposed_primary_role.0.clone(),
+                recovery_role: proposed_recovery_role.0.clone(),
+                confirmation_role: proposed_confirmation_role.0.clone(),
+            };
+
+            let instruction = NativeInstruction::CallMethod {
+                address: NativeDynamicGlobalAddress::Static(
+                    (*access_controller_address).try_into()?,
+                ),
+                method_name: NATIVE_ACCESS_CONTROLLER_TIMED_CONFIRM_RECOVERY_IDENT.to_owned(),
+                args: native_to_manifest_value_and_unwrap!(
+                    &NativeAccessControllerTimedConfirmRecoveryInput {
+                        rule_set,
+                        timed_recovery_delay_in_minutes: proposed_timed_recovery_delay_in_minutes,
+                    }
+                ),
+            };
+            builder.instructions.push(instruction);
+
+            Ok(())
+        })
+    }
+
+    pub fn access_controller_cancel_recovery(
+        self: Arc<Self>,
+        access_controller_address: Arc<Address>,
+        proposer: Proposer,
+    ) -> Result<Arc<Self>> {
+        builder_arc_map(self, |builder| {
+            let (method_name, args) = match proposer {
+                Proposer::Primary => (
+                    NATIVE_ACCESS_CONTROLLER_CANCEL_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT,
+                    native_to_manifest_value_and_unwrap!(
+                        &NativeAccessControllerCancelPrimaryRoleRecoveryProposalInput
+                    ),
+                ),
+                Proposer::Recovery => (
+                    NATIVE_ACCESS_CONTROLLER_CANCEL_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT,
+                    native_to_manifest_value_and_unwrap!(
+                        &NativeAccessControllerCancelRecoveryRoleRecoveryProposalInput
+                    ),
+                ),
+            };
+
+            let instruction = NativeInstruction::CallMethod {
+                address: NativeDynamicGlobalAddress::Static(
+                    (*access_controller_address).try_into()?,
+                ),
+                method_name: method_name.to_owned(),
+                args,
+            };
+            builder.instructions.push(instruction);
+
+            Ok(())
+        })
+    }
+
+    pub fn access_controller_stop_timed_recovery(
+        self: Arc<Self>,
+        access_controller_address: Arc<Address>,
+        proposed_primary_role: Arc<AccessRule>,
+        proposed_recovery_role: Arc<AccessRule>,
+        proposed_confirmation_role: Arc<AccessRule>,
+        proposed_timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> Result<Arc<Self>> {
+        builder_arc_map(self, |builder| {
+            let rule_set = NativeRuleSet {
+                primary_role: proposed_primary_role.0.clone(),
+                recovery_role: proposed_recovery_role.0.clone(),
+                confirmation_role: proposed_confirmation_role.0.clone(),
+            };
+
+            let instruction = NativeInstruction::CallMethod {
+                address: NativeDynamicGlobalAddress::Static(
+                    (*access_controller_address).try_into()?,
+                ),
+                method_name: NATIVE_ACCESS_CONTROLLER_STOP_TIMED_RECOVERY_IDENT.to_owned(),
+                args: native_to_manifest_value_and_unwrap!(
+                    &NativeAccessControllerStopTimedRecoveryInput {
+                        rule_set,
+                        timed_recovery_delay_in_minutes: proposed_timed_recovery_delay_in_minutes,
+                    }
+                ),
+            };
+            builder.instructions.push(instruction);
+
+            Ok(())
+        })
+    }
+
+    pub fn access_controller_lock_primary_role(
+        self: Arc<Self>,
+        access_controller_address: Arc<Address>,
+    ) -> Result<Arc<Self>> {
+        builder_arc_map(self, |builder| {
+            let instruction = NativeInstruction::CallMethod {
+                address: NativeDynamicGlobalAddress::Static(
+                    (*access_controller_address).try_into()?,
+                ),
+                method_name: NATIVE_ACCESS_CONTROLLER_LOCK_PRIMARY_ROLE_IDENT.to_owned(),
+                args: native_to_manifest_value_and_unwrap!(
+                    &NativeAccessControllerLockPrimaryRoleInput
+                ),
+            };
+            builder.instructions.push(instruction);
+
+            Ok(())
+        })
+    }
+
+    pub fn access_controller_unlock_primary_role(
+        self: Arc<Self>,
+        access_controller_address: Arc<Address>,
+    ) -> Result<Arc<Self>> {
+        builder_arc_map(self, |builder| {
+            let instruction = NativeInstruction::CallMethod {
+                address: NativeDynamicGlobalAddress::Static(
+                    (*access_controller_address).try_into()?,
+                ),
+                method_name: NATIVE_ACCESS_CONTROLLER_UNLOCK_PRIMARY_ROLE_IDENT.to_owned(),
+                args: native_to_manifest_value_and_unwrap!(
+                    &NativeAccessControllerUnlockPrimaryRoleInput
+                ),
+            };
+            builder.instructions.push(instruction);
+
+            Ok(())
+        })
+    }
+
     pub fn create_signature_based_access_controller(
         self: Arc<Self>,
         controlled_asset: ManifestBuilderBucket,
@@ -1181,10 +1695,83 @@ impl ManifestBuilder {
         })
     }
 
+    pub fn set_owner_keys(
+        self: Arc<Self>,
+        address: Arc<Address>,
+        public_key_hashes: Vec<PublicKeyHash>,
+    ) -> Result<Arc<Self>> {
+        builder_arc_map(self, |builder| {
+            let address = NativeGlobalAddress::try_from(*address)?;
+            let value = NativeMetadataValue::PublicKeyHashArray(
+                public_key_hashes
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<_>>()?,
+            );
+
+            let instruction = NativeInstruction::CallMetadataMethod {
+                address: NativeDynamicGlobalAddress::Static(address),
+                method_name: NATIVE_METADATA_SET_IDENT.to_string(),
+                args: native_to_manifest_value_and_unwrap!(&NativeMetadataSetInput {
+                    key: CORE_OWNER_KEYS_METADATA_KEY.to_owned(),
+                    value
+                }),
+            };
+            builder.instructions.push(instruction);
+
+            Ok(())
+        })
+    }
+
     //=================
     // Builder Methods
     //=================
 
+    pub fn checkpoint(self: Arc<Self>) -> Arc<ManifestBuilderCheckpoint> {
+        Arc::new(ManifestBuilderCheckpoint {
+            prefix_hash: prefix_hash(&self.instructions, &self.blobs),
+            name_record: self.name_record.clone(),
+            instructions_length: self.instructions.len(),
+            blobs_length: self.blobs.len(),
+        })
+    }
+
+    /// Removes all of the instructions and blobs added after the checkpoint and restores the name
+    /// record to its state at the checkpoint. The instructions and blobs of the builder must start
+    /// with those of the builder at the checkpoint, checkpoints of unrelated builders or of builders
+    /// that branched off from a common ancestor are rejected.
+    pub fn rollback(
+        self: Arc<Self>,
+        checkpoint: Arc<ManifestBuilderCheckpoint>,
+    ) -> Result<Arc<Self>> {
+        builder_arc_map(self, |builder| {
+            if checkpoint.instructions_length > builder.instructions.len()
+                || checkpoint.blobs_length > builder.blobs.len()
+            {
+                return Err(RadixEngineToolkitError::InvalidManifestBuilderCheckpoint {
+                    instructions_length: builder.instructions.len() as u64,
+                    checkpoint_instructions_length: checkpoint.instructions_length as u64,
+                    blobs_length: builder.blobs.len() as u64,
+                    checkpoint_blobs_length: checkpoint.blobs_length as u64,
+                });
+            }
+            if prefix_hash(
+                &builder.instructions[..checkpoint.instructions_length],
+                &builder.blobs[..checkpoint.blobs_length],
+            ) != checkpoint.prefix_hash
+            {
+                return Err(RadixEngineToolkitError::ManifestBuilderCheckpointOfAnotherBuilder);
+            }
+
+            builder
+                .instructions
+                .truncate(checkpoint.instructions_length);
+            builder.blobs.truncate(checkpoint.blobs_length);
+            builder.name_record = checkpoint.name_record.clone();
+            Ok(())
+        })
+    }
+
     pub fn build(self: Arc<Self>, network_id: u8) -> Arc<TransactionManifest> {
         Arc::new(TransactionManifest {
             instructions: Arc::new(Instructions(self.instructions.clone(), network_id)),
@@ -1193,6 +1780,15 @@ impl ManifestBuilder {
     }
 }
 
+fn prefix_hash(instructions: &[NativeInstruction], blobs: &[Vec<u8>]) -> NativeHash {
+    let mut bytes = native_manifest_encode(&instructions.to_vec())
+        .expect("The instructions of the builder are manifest encodable");
+    for blob in blobs {
+        bytes.extend(native_hash(blob).0);
+    }
+    native_hash(bytes)
+}
+
 #[derive(Debug, Clone, Record)]
 pub struct SecurityStructureRole {
     pub super_admin_factors: Vec<PublicKey>,
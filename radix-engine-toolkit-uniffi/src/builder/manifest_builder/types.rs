@@ -43,6 +43,37 @@ pub enum ManifestBuilderAddress {
     Static { value: Arc<Address> },
 }
 
+/// The method used to deposit resources into an account. [`Deposit`] requires the account owner's
+/// authorization, while the try-deposit methods respect the deposit rules of the account and either
+/// abort the transaction or refund the resources when the deposit is not allowed.
+///
+/// [`Deposit`]: AccountDepositMode::Deposit
+#[derive(Clone, Debug, Enum)]
+pub enum AccountDepositMode {
+    Deposit,
+    TryDepositOrAbort,
+    TryDepositOrRefund,
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum RoyaltyAmount {
+    Free,
+    Xrd { value: Arc<Decimal> },
+    Usd { value: Arc<Decimal> },
+}
+
+impl ToNative for RoyaltyAmount {
+    type Native = NativeRoyaltyAmount;
+
+    fn to_native(self) -> Result<Self::Native> {
+        Ok(match self {
+            Self::Free => NativeRoyaltyAmount::Free,
+            Self::Xrd { value } => NativeRoyaltyAmount::Xrd(value.0),
+            Self::Usd { value } => NativeRoyaltyAmount::Usd(value.0),
+        })
+    }
+}
+
 pub trait NameRecordConvertible {
     type Native;
 
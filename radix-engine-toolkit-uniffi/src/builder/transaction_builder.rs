@@ -146,6 +146,21 @@ impl TransactionBuilderIntentSignaturesStep {
 }
 
 impl TransactionBuilderIntentSignaturesStep {
+    /// Checks if the given key is that of the notary and the notary is a signatory. Intent
+    /// signatures by such a key are redundant and would get the transaction rejected, so they are
+    /// skipped when notarizing.
+    fn is_notary_signatory(&self, public_key: PublicKey) -> bool {
+        let header = &self.0;
+        header.notary_is_signatory
+            && matches!(
+                (
+                    NativePublicKey::try_from(public_key),
+                    NativePublicKey::try_from(header.notary_public_key.clone()),
+                ),
+                (Ok(public_key), Ok(notary_public_key)) if public_key == notary_public_key
+            )
+    }
+
     fn notarize(&self, notary: &dyn Signer) -> Result<NotarizedTransaction> {
         /* Processing the intent */
         let header = self.0.clone();
@@ -163,12 +178,14 @@ impl TransactionBuilderIntentSignaturesStep {
             let mut intent_signatures = self
                 .3
                 .iter()
+                .filter(|signer| !self.is_notary_signatory(signer.public_key()))
                 .map(|signer| signer.sign_to_signature_with_public_key(hash.clone()))
                 .map(NativeSignatureWithPublicKey::try_from)
                 .collect::<Result<Vec<NativeSignatureWithPublicKey>>>()?;
             intent_signatures.extend(
                 self.4
                     .iter()
+                    .filter(|signer| !self.is_notary_signatory(signer.public_key()))
                     .map(|signer| signer.sign_to_signature_with_public_key(hash.clone()))
                     .map(NativeSignatureWithPublicKey::try_from)
                     .collect::<Result<Vec<NativeSignatureWithPublicKey>>>()?,
@@ -56,6 +56,11 @@ impl Address {
         Ok(Arc::new(Self(typed_node_id, network_id)))
     }
 
+    #[uniffi::constructor]
+    pub fn from_node_id_bytes(node_id_bytes: Vec<u8>, network_id: u8) -> Result<Arc<Self>> {
+        Self::from_raw(node_id_bytes, network_id)
+    }
+
     #[uniffi::constructor]
     pub fn virtual_account_address_from_public_key(
         public_key: PublicKey,
@@ -99,6 +104,10 @@ impl Address {
         self.0.to_vec()
     }
 
+    pub fn node_id_bytes(&self) -> Vec<u8> {
+        self.0.to_vec()
+    }
+
     pub fn address_string(&self) -> String {
         let network_definition = core_network_definition_from_network_id(self.1);
         let bech32_encoder = NativeAddressBech32Encoder::new(&network_definition);
@@ -216,3 +225,120 @@ impl TryFrom<Address> for NativeGlobalAddress {
         value.0.try_into().map_err(Into::into)
     }
 }
+
+#[uniffi::export]
+pub fn addresses_from_node_id_bytes(
+    node_id_bytes: Vec<Vec<u8>>,
+    network_id: u8,
+) -> Result<Vec<Arc<Address>>> {
+    node_id_bytes
+        .into_iter()
+        .map(|bytes| Address::from_node_id_bytes(bytes, network_id))
+        .collect()
+}
+
+#[uniffi::export]
+pub fn addresses_from_strings(addresses: Vec<String>) -> Result<Vec<Arc<Address>>> {
+    addresses.into_iter().map(Address::new).collect()
+}
+
+#[uniffi::export]
+pub fn addresses_node_id_bytes(addresses: Vec<Arc<Address>>) -> Vec<Vec<u8>> {
+    addresses
+        .iter()
+        .map(|address| address.node_id_bytes())
+        .collect()
+}
+
+#[uniffi::export]
+pub fn addresses_as_strings(addresses: Vec<Arc<Address>>) -> Vec<String> {
+    addresses
+        .iter()
+        .map(|address| address.address_string())
+        .collect()
+}
+
+#[uniffi::export]
+pub fn addresses_entity_types(addresses: Vec<Arc<Address>>) -> Vec<EntityType> {
+    addresses
+        .iter()
+        .map(|address| address.entity_type())
+        .collect()
+}
+
+#[uniffi::export]
+pub fn address_validate(
+    address: String,
+    expected_network_id: Option<u8>,
+    expected_entity_types: Vec<EntityType>,
+) -> Vec<AddressValidationIssue> {
+    let expected_entity_types = expected_entity_types
+        .into_iter()
+        .map(NativeEntityType::from)
+        .collect::<Vec<_>>();
+    core_address_validate(&address, expected_network_id, &expected_entity_types)
+        .into_iter()
+        .map(Into::into)
+        .collect()
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum AddressValidationIssue {
+    InvalidBech32 {
+        error: String,
+    },
+    InvalidChecksum,
+    InvalidVariant,
+    InvalidLength {
+        expected: u64,
+        actual: u64,
+    },
+    InvalidEntityType {
+        entity_type_byte: u8,
+    },
+    UnknownNetwork {
+        hrp: String,
+    },
+    WrongNetwork {
+        expected: u8,
+        actual: u8,
+    },
+    HrpMismatch {
+        expected: String,
+        actual: String,
+    },
+    WrongEntityType {
+        expected: Vec<EntityType>,
+        actual: EntityType,
+    },
+}
+
+impl From<CoreAddressValidationIssue> for AddressValidationIssue {
+    fn from(value: CoreAddressValidationIssue) -> Self {
+        match value {
+            CoreAddressValidationIssue::InvalidBech32(error) => Self::InvalidBech32 { error },
+            CoreAddressValidationIssue::InvalidChecksum => Self::InvalidChecksum,
+            CoreAddressValidationIssue::InvalidVariant => Self::InvalidVariant,
+            CoreAddressValidationIssue::InvalidLength { expected, actual } => Self::InvalidLength {
+                expected: expected as u64,
+                actual: actual as u64,
+            },
+            CoreAddressValidationIssue::InvalidEntityType(entity_type_byte) => {
+                Self::InvalidEntityType { entity_type_byte }
+            }
+            CoreAddressValidationIssue::UnknownNetwork(hrp) => Self::UnknownNetwork { hrp },
+            CoreAddressValidationIssue::WrongNetwork { expected, actual } => {
+                Self::WrongNetwork { expected, actual }
+            }
+            CoreAddressValidationIssue::HrpMismatch { expected, actual } => {
+                Self::HrpMismatch { expected, actual }
+            }
+            CoreAddressValidationIssue::WrongEntityType { expected, actual } => {
+                Self::WrongEntityType {
+                    expected: expected.into_iter().map(Into::into).collect(),
+                    actual: actual.into(),
+                }
+            }
+        }
+    }
+}
@@ -239,3 +239,98 @@ impl From<crate::prelude::NativeRoundingMode> for RoundingMode {
         }
     }
 }
+
+#[derive(Clone, Debug, crate::prelude::Record)]
+pub struct AmountFormatOptions {
+    pub divisibility: u8,
+    pub symbol: Option<String>,
+    pub maximum_decimal_places: Option<u8>,
+    pub rounding_mode: RoundingMode,
+    pub minimum_decimal_places: u8,
+    /// A single character separating groups of three digits of the integral part, if any.
+    pub group_separator: Option<String>,
+}
+
+#[uniffi::export]
+pub fn amount_format(
+    amount: crate::prelude::Arc<Decimal>,
+    options: AmountFormatOptions,
+) -> crate::error::Result<String> {
+    let options = crate::prelude::CoreAmountFormatOptions::try_from(options)?;
+    crate::prelude::core_amount_format(&amount.0, &options).map_err(Into::into)
+}
+
+/// Parses an amount typed in by a user, accepting group separators, a leading `+` and exponents.
+#[uniffi::export]
+pub fn amount_parse(input: String) -> crate::error::Result<crate::prelude::Arc<Decimal>> {
+    crate::prelude::core_amount_parse(&input)
+        .map(|amount| crate::prelude::Arc::new(Decimal(amount)))
+        .map_err(Into::into)
+}
+
+#[uniffi::export]
+pub fn amount_xrd_format_options() -> AmountFormatOptions {
+    crate::prelude::CoreAmountFormatOptions::xrd().into()
+}
+
+impl TryFrom<AmountFormatOptions> for crate::prelude::CoreAmountFormatOptions {
+    type Error = crate::prelude::RadixEngineToolkitError;
+
+    fn try_from(
+        AmountFormatOptions {
+            divisibility,
+            symbol,
+            maximum_decimal_places,
+            rounding_mode,
+            minimum_decimal_places,
+            group_separator,
+        }: AmountFormatOptions,
+    ) -> crate::error::Result<Self> {
+        let group_separator = match group_separator {
+            Some(separator) => {
+                let mut characters = separator.chars();
+                match (characters.next(), characters.next()) {
+                    (Some(character), None) => Some(character),
+                    _ => {
+                        return Err(crate::prelude::RadixEngineToolkitError::AmountFormatError {
+                            error: format!(
+                                "The group separator must be a single character: {separator:?}"
+                            ),
+                        })
+                    }
+                }
+            }
+            None => None,
+        };
+        Ok(Self {
+            divisibility,
+            symbol,
+            maximum_decimal_places,
+            rounding_mode: rounding_mode.into(),
+            minimum_decimal_places,
+            group_separator,
+        })
+    }
+}
+
+impl From<crate::prelude::CoreAmountFormatOptions> for AmountFormatOptions {
+    fn from(
+        crate::prelude::CoreAmountFormatOptions {
+            divisibility,
+            symbol,
+            maximum_decimal_places,
+            rounding_mode,
+            minimum_decimal_places,
+            group_separator,
+        }: crate::prelude::CoreAmountFormatOptions,
+    ) -> Self {
+        Self {
+            divisibility,
+            symbol,
+            maximum_decimal_places,
+            rounding_mode: rounding_mode.into(),
+            minimum_decimal_places,
+            group_separator: group_separator.map(String::from),
+        }
+    }
+}
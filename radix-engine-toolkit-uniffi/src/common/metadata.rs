@@ -330,3 +330,111 @@ pub fn metadata_sbor_encode(value: MetadataValue) -> Result<Vec<u8>> {
     let native = value.to_native()?;
     Ok(native_scrypto_encode(&native).expect("Can't fail"))
 }
+
+#[uniffi::export]
+pub fn dapp_definition_verify_link(
+    entity_address: Arc<Address>,
+    entity_metadata: HashMap<String, MetadataValue>,
+    dapp_definition_address: Arc<Address>,
+    dapp_definition_metadata: HashMap<String, MetadataValue>,
+) -> Result<DappDefinitionLinkVerification> {
+    let network_id = entity_address.network_id();
+    let verification = core_dapp_definition_verify_link(
+        (*entity_address).try_into()?,
+        &metadata_to_native(&entity_metadata)?,
+        (*dapp_definition_address).try_into()?,
+        &metadata_to_native(&dapp_definition_metadata)?,
+    );
+    Ok(DappDefinitionLinkVerification::from_native(
+        verification,
+        network_id,
+    ))
+}
+
+#[uniffi::export]
+pub fn dapp_definition_referenced_dapp_definitions(
+    metadata: HashMap<String, MetadataValue>,
+    network_id: u8,
+) -> Result<Vec<Arc<Address>>> {
+    let metadata = metadata_to_native(&metadata)?;
+    Ok(core_dapp_definition_referenced_dapp_definitions(&metadata)
+        .into_iter()
+        .map(|address| Arc::new(Address::from_typed_node_id(address, network_id)))
+        .collect())
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct DappDefinitionLinkVerification {
+    pub entity_address: Arc<Address>,
+    pub dapp_definition_address: Arc<Address>,
+    pub is_verified: bool,
+    pub entity_references_dapp_definition: bool,
+    pub dapp_definition_claims_entity: bool,
+    pub violations: Vec<DappDefinitionLinkViolation>,
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum DappDefinitionLinkViolation {
+    InvalidMetadataValueType { address: Arc<Address>, key: String },
+    EntityDoesNotReferenceDappDefinition,
+    DappDefinitionIsNotAnAccount,
+    AccountTypeIsNotDappDefinition { account_type: Option<String> },
+    DappDefinitionDoesNotClaimEntity,
+}
+
+impl DappDefinitionLinkVerification {
+    pub fn from_native(native: CoreDappDefinitionLinkVerification, network_id: u8) -> Self {
+        Self {
+            entity_address: Arc::new(Address::from_typed_node_id(
+                native.entity_address,
+                network_id,
+            )),
+            dapp_definition_address: Arc::new(Address::from_typed_node_id(
+                native.dapp_definition_address,
+                network_id,
+            )),
+            is_verified: native.is_verified(),
+            entity_references_dapp_definition: native.entity_references_dapp_definition,
+            dapp_definition_claims_entity: native.dapp_definition_claims_entity,
+            violations: native
+                .violations
+                .into_iter()
+                .map(|violation| DappDefinitionLinkViolation::from_native(violation, network_id))
+                .collect(),
+        }
+    }
+}
+
+impl DappDefinitionLinkViolation {
+    pub fn from_native(native: CoreDappDefinitionLinkViolation, network_id: u8) -> Self {
+        match native {
+            CoreDappDefinitionLinkViolation::InvalidMetadataValueType { address, key } => {
+                Self::InvalidMetadataValueType {
+                    address: Arc::new(Address::from_typed_node_id(address, network_id)),
+                    key,
+                }
+            }
+            CoreDappDefinitionLinkViolation::EntityDoesNotReferenceDappDefinition => {
+                Self::EntityDoesNotReferenceDappDefinition
+            }
+            CoreDappDefinitionLinkViolation::DappDefinitionIsNotAnAccount => {
+                Self::DappDefinitionIsNotAnAccount
+            }
+            CoreDappDefinitionLinkViolation::AccountTypeIsNotDappDefinition { account_type } => {
+                Self::AccountTypeIsNotDappDefinition { account_type }
+            }
+            CoreDappDefinitionLinkViolation::DappDefinitionDoesNotClaimEntity => {
+                Self::DappDefinitionDoesNotClaimEntity
+            }
+        }
+    }
+}
+
+fn metadata_to_native(
+    metadata: &HashMap<String, MetadataValue>,
+) -> Result<HashMap<String, NativeMetadataValue>> {
+    metadata
+        .iter()
+        .map(|(key, value)| Ok((key.clone(), value.to_native()?)))
+        .collect()
+}
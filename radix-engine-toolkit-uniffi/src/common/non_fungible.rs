@@ -70,6 +70,21 @@ impl NonFungibleGlobalId {
         derive_virtual_signature_non_fungible_global_id_from_public_key(public_key, network_id)
     }
 
+    #[uniffi::constructor]
+    pub fn virtual_signature_badge_from_public_key_hash(
+        public_key_hash: PublicKeyHash,
+        network_id: u8,
+    ) -> Result<Arc<Self>> {
+        derive_virtual_signature_non_fungible_global_id_from_public_key_hash(
+            public_key_hash,
+            network_id,
+        )
+    }
+
+    pub fn virtual_signature_badge_public_key_hash(&self) -> Result<PublicKeyHash> {
+        derive_public_key_hash_from_virtual_signature_non_fungible_global_id(Arc::new(self.clone()))
+    }
+
     pub fn resource_address(&self) -> Arc<Address> {
         let address = self.0.resource_address();
         Arc::new(Address::from_typed_node_id(address, self.1))
@@ -16,39 +16,75 @@
 // under the License.
 
 use crate::prelude::*;
+use zeroize::Zeroizing;
 
+/// A private key held by the toolkit. The native key is constructed once from the bytes of the key
+/// and is used for all signatures so that no further copies of the key are made, the bytes that it
+/// was constructed from are zeroized right after.
 #[derive(Object)]
-pub struct PrivateKey(pub(crate) NativePrivateKey);
+pub struct PrivateKey {
+    native: NativePrivateKey,
+    curve: Curve,
+}
 
 #[uniffi::export]
 impl PrivateKey {
     #[uniffi::constructor]
     pub fn new(bytes: Vec<u8>, curve: Curve) -> Result<Arc<Self>> {
-        match curve {
-            Curve::Ed25519 => Self::new_ed25519(bytes),
-            Curve::Secp256k1 => Self::new_secp256k1(bytes),
-        }
+        let bytes = Zeroizing::new(bytes);
+        let native = Self::native_from_bytes(&bytes, curve)?;
+        Ok(Arc::new(Self { native, curve }))
     }
 
     #[uniffi::constructor]
     pub fn new_secp256k1(bytes: Vec<u8>) -> Result<Arc<Self>> {
-        NativeSecp256k1PrivateKey::from_bytes(&bytes)
-            .map_err(|_| RadixEngineToolkitError::InvalidLength {
-                expected: NativeEd25519PublicKey::LENGTH as u64,
-                actual: bytes.len() as u64,
-                data: bytes,
-            })
-            .map(|value| Arc::new(Self(NativePrivateKey::Secp256k1(value))))
+        Self::new(bytes, Curve::Secp256k1)
     }
+
     #[uniffi::constructor]
     pub fn new_ed25519(bytes: Vec<u8>) -> Result<Arc<Self>> {
-        NativeEd25519PrivateKey::from_bytes(&bytes)
-            .map_err(|_| RadixEngineToolkitError::InvalidLength {
-                expected: NativeEd25519PublicKey::LENGTH as u64,
-                actual: bytes.len() as u64,
-                data: bytes,
-            })
-            .map(|value| Arc::new(Self(NativePrivateKey::Ed25519(value))))
+        Self::new(bytes, Curve::Ed25519)
+    }
+
+    #[uniffi::constructor]
+    pub fn from_hex_string(private_key: String, curve: Curve) -> Result<Arc<Self>> {
+        let private_key = Zeroizing::new(private_key);
+        let bytes = hex::decode(private_key.as_str())
+            .map_err(|_| RadixEngineToolkitError::InvalidPrivateKey)?;
+        Self::new(bytes, curve)
+    }
+
+    /// Generates a new private key from the entropy provided by the operating system.
+    #[uniffi::constructor]
+    pub fn generate(curve: Curve) -> Result<Arc<Self>> {
+        loop {
+            let mut bytes = Zeroizing::new(vec![0u8; 32]);
+            getrandom::getrandom(&mut bytes).map_err(|error| {
+                RadixEngineToolkitError::EntropySourceError {
+                    error: error.to_string(),
+                }
+            })?;
+
+            // Not all 32 byte values are valid Secp256k1 private keys, the ones that are not are
+            // rejected and new entropy is obtained.
+            if let Ok(native) = Self::native_from_bytes(&bytes, curve) {
+                return Ok(Arc::new(Self { native, curve }));
+            }
+        }
+    }
+
+    #[uniffi::constructor]
+    pub fn generate_secp256k1() -> Result<Arc<Self>> {
+        Self::generate(Curve::Secp256k1)
+    }
+
+    #[uniffi::constructor]
+    pub fn generate_ed25519() -> Result<Arc<Self>> {
+        Self::generate(Curve::Ed25519)
+    }
+
+    fn curve(&self) -> Curve {
+        self.curve
     }
 
     fn sign(&self, hash: Arc<Hash>) -> Vec<u8> {
@@ -66,6 +102,24 @@ impl PrivateKey {
     fn public_key(&self) -> PublicKey {
         Signer::public_key(self)
     }
+
+    /// Signs an arbitrary message with the personal message domain separation applied.
+    fn sign_personal_message(&self, message: Vec<u8>) -> SignatureWithPublicKey {
+        core_personal_message_sign(&self.native, &message).into()
+    }
+}
+
+impl PrivateKey {
+    fn native_from_bytes(bytes: &[u8], curve: Curve) -> Result<NativePrivateKey> {
+        match curve {
+            Curve::Secp256k1 => NativeSecp256k1PrivateKey::from_bytes(bytes)
+                .map(NativePrivateKey::Secp256k1)
+                .map_err(|_| RadixEngineToolkitError::InvalidPrivateKey),
+            Curve::Ed25519 => NativeEd25519PrivateKey::from_bytes(bytes)
+                .map(NativePrivateKey::Ed25519)
+                .map_err(|_| RadixEngineToolkitError::InvalidPrivateKey),
+        }
+    }
 }
 
 impl Signer for PrivateKey {
@@ -76,15 +130,15 @@ impl Signer for PrivateKey {
     }
 
     fn sign_to_signature(&self, hash: Arc<Hash>) -> Signature {
-        self.0.sign_without_public_key(&hash.0).into()
+        self.native.sign_without_public_key(&hash.0).into()
     }
 
     fn sign_to_signature_with_public_key(&self, hash: Arc<Hash>) -> SignatureWithPublicKey {
-        self.0.sign_with_public_key(&hash.0).into()
+        self.native.sign_with_public_key(&hash.0).into()
     }
 
     fn public_key(&self) -> PublicKey {
-        self.0.public_key().into()
+        self.native.public_key().into()
     }
 }
 
@@ -17,6 +17,25 @@
 
 use crate::prelude::*;
 
+#[uniffi::export]
+pub fn public_key_validate(public_key: PublicKey) -> Result<()> {
+    core_public_key_validate(&public_key.try_into()?).map_err(Into::into)
+}
+
+#[uniffi::export]
+pub fn public_key_secp256k1_compress(uncompressed: Vec<u8>) -> Result<Secp256k1PublicKey> {
+    core_public_key_secp256k1_compress(&uncompressed)
+        .map(Into::into)
+        .map_err(Into::into)
+}
+
+#[uniffi::export]
+pub fn public_key_secp256k1_decompress(public_key: Secp256k1PublicKey) -> Result<Vec<u8>> {
+    core_public_key_secp256k1_decompress(&public_key.try_into()?)
+        .map(|uncompressed| uncompressed.to_vec())
+        .map_err(Into::into)
+}
+
 #[derive(Clone, Enum, Debug)]
 pub enum PublicKey {
     Secp256k1 { value: Vec<u8> },
@@ -51,6 +51,41 @@ pub fn derive_virtual_signature_non_fungible_global_id_from_public_key(
     )))
 }
 
+#[uniffi::export]
+pub fn derive_virtual_signature_non_fungible_global_id_from_public_key_hash(
+    public_key_hash: PublicKeyHash,
+    network_id: u8,
+) -> Result<Arc<NonFungibleGlobalId>> {
+    let public_key_hash = NativePublicKeyHash::try_from(public_key_hash)?;
+    let non_fungible_global_id =
+        core_virtual_signature_non_fungible_global_id_from_public_key_hash(&public_key_hash);
+    Ok(Arc::new(NonFungibleGlobalId(
+        non_fungible_global_id,
+        network_id,
+    )))
+}
+
+#[uniffi::export]
+pub fn derive_public_key_hash_from_virtual_signature_non_fungible_global_id(
+    non_fungible_global_id: Arc<NonFungibleGlobalId>,
+) -> Result<PublicKeyHash> {
+    core_public_key_hash_from_virtual_signature_non_fungible_global_id(&non_fungible_global_id.0)
+        .map(Into::into)
+        .map_err(Into::into)
+}
+
+#[uniffi::export]
+pub fn derive_virtual_global_address_from_public_key_hash(
+    public_key_hash: PublicKeyHash,
+    entity_type: EntityType,
+    network_id: u8,
+) -> Result<Arc<Address>> {
+    let public_key_hash = NativePublicKeyHash::try_from(public_key_hash)?;
+    let address =
+        core_virtual_global_address_from_public_key_hash(&public_key_hash, entity_type.into())?;
+    Ok(Arc::new(Address::from_typed_node_id(address, network_id)))
+}
+
 #[uniffi::export]
 pub fn derive_virtual_account_address_from_olympia_account_address(
     olympia_account_address: Arc<OlympiaAddress>,
@@ -102,3 +137,15 @@ pub fn derive_olympia_account_address_from_public_key(
     let address = core_olympia_account_address_from_public_key(&public_key, olympia_network.into());
     Ok(Arc::new(OlympiaAddress(address)))
 }
+
+#[uniffi::export]
+pub fn derive_nonce_from_entropy(entropy: Vec<u8>) -> u32 {
+    core_intent_nonce_from_entropy(&entropy)
+}
+
+#[uniffi::export]
+pub fn derive_nonce_from_intent(intent: Arc<Intent>, key: Vec<u8>, attempt: u32) -> Result<u32> {
+    NativeIntent::try_from(intent.as_ref().clone()).and_then(|intent| {
+        core_intent_nonce_from_intent(&intent, &key, attempt).map_err(Into::into)
+    })
+}
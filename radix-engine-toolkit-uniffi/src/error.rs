@@ -52,6 +52,12 @@ pub enum RadixEngineToolkitError {
     #[error("Public key is not valid for a given operation")]
     InvalidPublicKey,
 
+    #[error("The bytes are not a valid private key for the curve")]
+    InvalidPrivateKey,
+
+    #[error("Failed to obtain entropy from the operating system")]
+    EntropySourceError { error: String },
+
     #[error("Manifest compilation errored out")]
     CompileError { error: String },
 
@@ -76,6 +82,21 @@ pub enum RadixEngineToolkitError {
     #[error("An error occurred during doing a Manifest SBOR encode/decode")]
     ManifestSborError { error: String },
 
+    #[error("An error occurred when decoding an SBOR payload of an unknown kind")]
+    SborDecodeUnknownError { error: String },
+
+    #[error("An error occurred when formatting an amount")]
+    AmountFormatError { error: String },
+
+    #[error("An error occurred when parsing an amount typed in by a user")]
+    AmountParseError { error: String },
+
+    #[error("The public key is not a valid encoding of a point on its curve")]
+    PublicKeyError { error: String },
+
+    #[error("An error occurred when adding a signature to or updating the signing state")]
+    SigningStateError { error: String },
+
     #[error("An error occurred during doing a Scrypto SBOR encode/decode")]
     ScryptoSborError { error: String },
 
@@ -88,12 +109,26 @@ pub enum RadixEngineToolkitError {
     #[error("An error ocurred when building the manifest due to the naming of objects")]
     ManifestBuilderNameRecordError { error: NameRecordError },
 
+    #[error("The checkpoint is ahead of the manifest builder being rolled back to it")]
+    InvalidManifestBuilderCheckpoint {
+        instructions_length: u64,
+        checkpoint_instructions_length: u64,
+        blobs_length: u64,
+        checkpoint_blobs_length: u64,
+    },
+
+    #[error("The checkpoint was taken from a manifest builder with other instructions or blobs")]
+    ManifestBuilderCheckpointOfAnotherBuilder,
+
     #[error("An error ocurred when trying to modify the manifest")]
     ManifestModificationError { error: String },
 
     #[error("The node id has no valid entity type")]
     InvalidEntityTypeIdError { error: String },
 
+    #[error("Failed to register the network definition")]
+    NetworkRegistrationError { error: String },
+
     #[error(
         "An error encountered when doing decimal arithmetic pertaining to overflow and underflow"
     )]
@@ -158,9 +193,15 @@ impl_dbg_str_from! { CoreInstructionValidationError, TransactionValidationFailed
 impl_dbg_str_from! { CoreExecutionExecutionModuleError, ExecutionModuleError }
 impl_dbg_str_from! { CoreManifestSborError, ManifestSborError }
 impl_dbg_str_from! { CoreScryptoSborError, ScryptoSborError }
+impl_dbg_str_from! { CoreSborDecodeUnknownError, SborDecodeUnknownError }
+impl_dbg_str_from! { CoreSigningStateError, SigningStateError }
+impl_dbg_str_from! { CoreAmountFormatError, AmountFormatError }
+impl_dbg_str_from! { CoreAmountParseError, AmountParseError }
+impl_dbg_str_from! { CorePublicKeyError, PublicKeyError }
 impl_dbg_str_from! { NativeTypedNativeEventError, TypedNativeEventError }
 impl_dbg_str_from! { CoreManifestModificationError, ManifestModificationError }
 impl_dbg_str_from! { CoreInvalidEntityTypeIdError, InvalidEntityTypeIdError }
+impl_dbg_str_from! { CoreNetworkRegistrationError, NetworkRegistrationError }
 
 impl From<NameRecordError> for RadixEngineToolkitError {
     fn from(value: NameRecordError) -> Self {
@@ -32,13 +32,22 @@ mod core {
     /* Modules */
     pub use radix_engine_toolkit_core::functions::information::{
         information as core_information, 
+        methods_that_require_auth as core_methods_that_require_auth,
+        MethodsThatRequireAuth as CoreMethodsThatRequireAuth,
         BuildInformation as CoreBuildInformation,
         DependencyInformation as CoreDependencyInformation,
     };
+    pub use radix_engine_toolkit_core::functions::address::{
+        validate as core_address_validate,
+        AddressValidationIssue as CoreAddressValidationIssue,
+    };
     pub use radix_engine_toolkit_core::functions::derive::{
         virtual_account_address_from_public_key as core_virtual_account_address_from_public_key,
         virtual_identity_address_from_public_key as core_virtual_identity_address_from_public_key,
         virtual_signature_non_fungible_global_id_from_public_key as core_virtual_signature_non_fungible_global_id_from_public_key,
+        virtual_signature_non_fungible_global_id_from_public_key_hash as core_virtual_signature_non_fungible_global_id_from_public_key_hash,
+        public_key_hash_from_virtual_signature_non_fungible_global_id as core_public_key_hash_from_virtual_signature_non_fungible_global_id,
+        virtual_global_address_from_public_key_hash as core_virtual_global_address_from_public_key_hash,
         virtual_account_address_from_olympia_account_address as core_virtual_account_address_from_olympia_account_address,
         resource_address_from_olympia_resource_address as core_resource_address_from_olympia_resource_address,
         public_key_from_olympia_account_address as core_public_key_from_olympia_account_address,
@@ -57,27 +66,90 @@ mod core {
         accounts_requiring_auth as core_instructions_accounts_requiring_auth,
         accounts_withdrawn_from as core_instructions_accounts_withdrawn_from,
         accounts_deposited_into as core_instructions_accounts_deposited_into,
+        non_account_transfers as core_instructions_non_account_transfers,
+        authorized_depositor_badges as core_instructions_authorized_depositor_badges,
+        access_controller_interactions as core_instructions_access_controller_interactions,
+        instruction_line_ranges as core_instructions_instruction_line_ranges,
+        diagnose_compile_error as core_instructions_diagnose_compile_error,
         InstructionValidationError as CoreInstructionValidationError,
+        InstructionLineRange as CoreInstructionLineRange,
+        ManifestCompileDiagnostic as CoreManifestCompileDiagnostic,
+        ManifestCompileErrorKind as CoreManifestCompileErrorKind,
+        ManifestSpan as CoreManifestSpan,
+        ManifestPosition as CoreManifestPosition,
+        ManifestTokenClass as CoreManifestTokenClass,
+    };
+    pub use radix_engine_toolkit_core::functions::hashing::{
+        blake2b_256 as core_hashing_blake2b_256,
+        sha256 as core_hashing_sha256,
+        sha256_twice as core_hashing_sha256_twice,
+        keccak256 as core_hashing_keccak256,
+    };
+    pub use radix_engine_toolkit_core::functions::personal_message::{
+        hash as core_personal_message_hash,
+        sign as core_personal_message_sign,
+        verify as core_personal_message_verify,
     };
     pub use radix_engine_toolkit_core::functions::utils::{
         decode_transaction_id as core_decode_transaction_id
     };
     pub use radix_engine_toolkit_core::functions::manifest::{
         hash as core_manifest_hash,
+        deduplication_key as core_manifest_deduplication_key,
         compile as core_manifest_compile,
         decompile as core_manifest_decompile,
         statically_validate as core_manifest_statically_validate,
         modify as core_manifest_modify,
+        add_guarantees as core_manifest_add_guarantees,
+        set_lock_fee as core_manifest_set_lock_fee,
+        sponsor_fee as core_manifest_sponsor_fee,
+        fee_sponsorship as core_manifest_fee_sponsorship,
+        SponsoredManifest as CoreManifestSponsoredManifest,
+        FeeSponsorship as CoreManifestFeeSponsorship,
+        modify_instructions as core_manifest_modify_instructions,
         parse_transfer_information as core_manifest_parse_transfer_information,
         TransactionManifestModifications as CoreManifestTransactionManifestModifications,
         Assertion as CoreManifestAssertion,
+        Guarantee as CoreManifestGuarantee,
+        InstructionModification as CoreManifestInstructionModification,
         ManifestModificationError as CoreManifestModificationError
     };
+    pub use radix_engine_toolkit_core::functions::validation::{
+        validate_intent as core_validation_validate_intent,
+        validate_signed_intent as core_validation_validate_signed_intent,
+        validate_notarized_transaction as core_validation_validate_notarized_transaction,
+        StaticValidationViolation as CoreStaticValidationViolation,
+    };
+    pub use radix_engine_toolkit_core::functions::dapp_definition::{
+        verify_link as core_dapp_definition_verify_link,
+        referenced_dapp_definitions as core_dapp_definition_referenced_dapp_definitions,
+        DappDefinitionLinkVerification as CoreDappDefinitionLinkVerification,
+        DappDefinitionLinkViolation as CoreDappDefinitionLinkViolation,
+    };
+    pub use radix_engine_toolkit_core::functions::transaction_request::{
+        normalize as core_transaction_request_normalize,
+        validate as core_transaction_request_validate,
+        to_manifest as core_transaction_request_to_manifest,
+        from_manifest as core_transaction_request_from_manifest,
+        TransactionRequest as CoreTransactionRequest,
+        LockFeeHint as CoreLockFeeHint,
+        TransactionRequestLimits as CoreTransactionRequestLimits,
+        TransactionRequestViolation as CoreTransactionRequestViolation,
+    };
+    pub use radix_engine_toolkit_core::functions::logging::{
+        ToLogString as CoreToLogString,
+    };
     pub use radix_engine_toolkit_core::functions::intent::{
         hash as core_intent_hash,
         compile as core_intent_compile,
         decompile as core_intent_decompile,
         statically_validate as core_intent_statically_validate,
+        nonce_from_entropy as core_intent_nonce_from_entropy,
+        nonce_from_intent as core_intent_nonce_from_intent,
+        compare as core_intent_compare,
+        IntentComparison as CoreIntentComparison,
+        HeaderField as CoreHeaderField,
+        InstructionDifference as CoreInstructionDifference,
     };
     pub use radix_engine_toolkit_core::functions::signed_intent::{
         hash as core_signed_intent_hash,
@@ -85,14 +157,38 @@ mod core {
         decompile as core_signed_intent_decompile,
         statically_validate as core_signed_intent_statically_validate,
     };
+    pub use radix_engine_toolkit_core::functions::amount::{
+        format as core_amount_format,
+        parse as core_amount_parse,
+        AmountFormatOptions as CoreAmountFormatOptions,
+        AmountFormatError as CoreAmountFormatError,
+        AmountParseError as CoreAmountParseError,
+    };
+    pub use radix_engine_toolkit_core::functions::public_key::{
+        validate as core_public_key_validate,
+        secp256k1_compress as core_public_key_secp256k1_compress,
+        secp256k1_decompress as core_public_key_secp256k1_decompress,
+        PublicKeyError as CorePublicKeyError,
+    };
+    pub use radix_engine_toolkit_core::functions::signing_state::{
+        SigningState as CoreSigningState,
+        SignerStatus as CoreSignerStatus,
+        SigningStateError as CoreSigningStateError,
+    };
     pub use radix_engine_toolkit_core::functions::notarized_transaction::{
         hash as core_notarized_transaction_hash,
         compile as core_notarized_transaction_compile,
         decompile as core_notarized_transaction_decompile,
         statically_validate as core_notarized_transaction_statically_validate,
+        submission_envelope as core_notarized_transaction_submission_envelope,
+        verify_notary as core_notarized_transaction_verify_notary,
+        SubmissionEnvelope as CoreSubmissionEnvelope,
+        NotaryVerificationFailure as CoreNotaryVerificationFailure,
     };
     pub use radix_engine_toolkit_core::functions::execution::{
         analyze as core_execution_analyze,
+        analyze_with_dex_registry as core_execution_analyze_with_dex_registry,
+        minimum_xrd_required_per_account as core_execution_minimum_xrd_required_per_account,
         ExecutionAnalysis as CoreExecutionExecutionAnalysis,
         FeeSummary as CoreExecutionFeeSummary,
         FeeLocks as CoreExecutionFeeLocks,
@@ -103,14 +199,25 @@ mod core {
         TransactionType as CoreExecutionTransactionType,
         ExecutionModuleError as CoreExecutionExecutionModuleError,
         ExecutionAnalysisTransactionReceipt as CoreExecutionAnalysisTransactionReceipt,
+        ResourceMovementDiscrepancy as CoreExecutionResourceMovementDiscrepancy,
+        ResourceMovementDirection as CoreExecutionResourceMovementDirection,
+        ResourceMovementGraph as CoreExecutionResourceMovementGraph,
+        ResourceMovementGraphNode as CoreExecutionResourceMovementGraphNode,
+        ResourceMovementGraphEdge as CoreExecutionResourceMovementGraphEdge,
+        ResourceMovementGuarantee as CoreExecutionResourceMovementGuarantee,
         StakeTransactionType as CoreStakeTransactionType,
         UnstakeTransactionType as CoreUnstakeTransactionType,
-        ClaimStakeTransactionType as CoreClaimStakeTransactionType
+        ClaimStakeTransactionType as CoreClaimStakeTransactionType,
+        OwnerKeysUpdateTransactionType as CoreOwnerKeysUpdateTransactionType,
+        EntityControlChangesTransactionType as CoreEntityControlChangesTransactionType,
+        RoyaltyClaimTransactionType as CoreRoyaltyClaimTransactionType,
+        SwapTransactionType as CoreSwapTransactionType
     };
     pub use radix_engine_toolkit_core::functions::manifest_sbor::{
         ManifestSborError as CoreManifestSborError,
         ManifestSborStringRepresentation as CoreManifestSborStringRepresentation,
         decode_to_string_representation as core_manifest_decode_to_string_representation,
+        encode_string_representation as core_manifest_encode_string_representation,
     };
     pub use radix_engine_toolkit_core::functions::scrypto_sbor::{
         ScryptoSborError as CoreScryptoSborError,
@@ -118,13 +225,48 @@ mod core {
         decode_to_string_representation as core_scrypto_decode_to_string_representation,
         encode_string_representation as core_scrypto_encode_string_representation,
     };
+    pub use radix_engine_toolkit_core::functions::sbor::{
+        SborDecodeLimits as CoreSborDecodeLimits,
+        SborDecodeUnknownError as CoreSborDecodeUnknownError,
+        SborPayloadKind as CoreSborPayloadKind,
+        UnknownSborPayload as CoreUnknownSborPayload,
+        decode_unknown as core_sbor_decode_unknown,
+    };
     pub use radix_engine_toolkit_core::functions::events::{
         sbor_decode_to_native_event as core_events_sbor_decode_to_native_event
     };
     
     /* Visitors */
+    pub use radix_engine_toolkit_core::instruction_visitor::visitors::required_badges_visitor::{
+        InstructionBadgeRequirements as CoreInstructionBadgeRequirements,
+        CreatedProof as CoreCreatedProof,
+        RequiredBadge as CoreRequiredBadge,
+        BadgeRequirement as CoreBadgeRequirement,
+    };
+    pub use radix_engine_toolkit_core::instruction_visitor::visitors::authorized_depositor_badges_visitor::{
+        AuthorizedDepositorBadgeUsage as CoreAuthorizedDepositorBadgeUsage,
+    };
+    pub use radix_engine_toolkit_core::instruction_visitor::visitors::worktop_guarantees_visitor::{
+        WorktopGuarantee as CoreWorktopGuarantee,
+        GuaranteeKind as CoreGuaranteeKind,
+    };
+    pub use radix_engine_toolkit_core::instruction_visitor::visitors::access_controller_visitor::{
+        AccessControllerInteraction as CoreAccessControllerInteraction,
+        AccessControllerOperation as CoreAccessControllerOperation,
+    };
+    pub use radix_engine_toolkit_core::instruction_visitor::visitors::fee_locks_visitor::{
+        FeePayers as CoreFeePayers,
+    };
+    pub use radix_engine_toolkit_core::instruction_visitor::visitors::non_account_transfers_visitor::{
+        NonAccountTransfer as CoreNonAccountTransfer,
+    };
+    pub use radix_engine_toolkit_core::instruction_visitor::visitors::required_signers_visitor::{
+        RequiredSigner as CoreRequiredSigner,
+    };
     pub use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::reserved_instructions::{
         ReservedInstruction as CoreReservedInstruction,
+        ReservedInstructionUsage as CoreReservedInstructionUsage,
+        ReservedInstructionArguments as CoreReservedInstructionArguments,
     };
     pub use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::transfer_visitor::{
         Resources as CoreResources,
@@ -135,7 +277,23 @@ mod core {
     };
     pub use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::general_transaction_visitor::{
         Source as CoreSource,
-        ResourceTracker as CoreResourceTracker
+        ResourceTracker as CoreResourceTracker,
+        PresentedProof as CorePresentedProof
+    };
+    pub use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::owner_keys_visitor::{
+        OwnerKeysAction as CoreOwnerKeysAction,
+        OWNER_KEYS_METADATA_KEY as CORE_OWNER_KEYS_METADATA_KEY,
+    };
+    pub use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::royalty_claims_visitor::{
+        RoyaltyClaim as CoreRoyaltyClaim,
+    };
+    pub use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::swap_visitor::{
+        DexRegistry as CoreDexRegistry,
+        DexVenue as CoreDexVenue,
+        Swap as CoreSwap,
+    };
+    pub use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::entity_control_changes_visitor::{
+        EntityControlChange as CoreEntityControlChange,
     };
     pub use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::stake_visitor::{
         StakeInformation as CoreStakeInformation
@@ -151,15 +309,29 @@ mod core {
     pub use radix_engine_toolkit_core::utils::{
         manifest_from_intent as core_manifest_from_intent,
         network_definition_from_network_id as core_network_definition_from_network_id,
+        register_network_definition as core_register_network_definition,
+        deregister_network_definition as core_deregister_network_definition,
+        network_definitions as core_network_definitions,
+        hrp_table as core_hrp_table,
+        NetworkRegistrationError as CoreNetworkRegistrationError,
         network_id_from_hrp as core_network_id_from_hrp,
         network_id_from_address_string as core_network_id_from_address_string,
         to_manifest_type as core_to_manifest_type,
         validate_manifest_value_against_schema as core_validate_manifest_value_against_schema,
+        manifest_value_schema_violations as core_manifest_value_schema_violations,
+        SchemaViolation as CoreSchemaViolation,
         is_account as core_is_account,
         is_identity as core_is_identity,
         metadata_of_newly_created_entities as core_metadata_of_newly_created_entities,
         data_of_newly_minted_non_fungibles as core_data_of_newly_minted_non_fungibles,
     };
+
+    /* Testing */
+    #[cfg(feature = "testing")]
+    pub use radix_engine_toolkit_core::testing::{
+        random_node_id as core_testing_random_node_id,
+        random_non_fungible_local_id as core_testing_random_non_fungible_local_id,
+    };
 }
 
 #[rustfmt::skip]
@@ -210,6 +382,7 @@ mod native {
         PackageAddress as NativePackageAddress,
         
         NonFungibleLocalId as NativeNonFungibleLocalId,
+        NonFungibleIdType as NativeNonFungibleIdType,
         NonFungibleGlobalId as NativeNonFungibleGlobalId,
 
         ParseDecimalError as NativeParseDecimalError,
@@ -406,6 +579,7 @@ mod native {
         Schema as NativeSchema,
         VersionedSchema as NativeVersionedSchema,
         ScryptoCustomSchema as NativeScryptoCustomSchema,
+        NetworkDefinition as NativeNetworkDefinition,
         SCRYPTO_SBOR_V1_PAYLOAD_PREFIX as NATIVE_SCRYPTO_SBOR_V1_PAYLOAD_PREFIX,
         MANIFEST_SBOR_V1_PAYLOAD_PREFIX as NATIVE_MANIFEST_SBOR_V1_PAYLOAD_PREFIX,
     };
@@ -527,6 +701,17 @@ mod native {
         KeyValueStoreInitEntry as NativeKeyValueStoreInitEntry,
         BlueprintId as NativeBlueprintId
     };
+    pub use radix_engine_interface::api::node_modules::royalty::{
+        ComponentRoyaltySetInput as NativeComponentRoyaltySetInput,
+        ComponentRoyaltyLockInput as NativeComponentRoyaltyLockInput,
+        ComponentClaimRoyaltiesInput as NativeComponentClaimRoyaltiesInput,
+        COMPONENT_ROYALTY_SET_ROYALTY_IDENT as NATIVE_COMPONENT_ROYALTY_SET_ROYALTY_IDENT,
+        COMPONENT_ROYALTY_LOCK_ROYALTY_IDENT as NATIVE_COMPONENT_ROYALTY_LOCK_ROYALTY_IDENT,
+        COMPONENT_ROYALTY_CLAIM_ROYALTIES_IDENT as NATIVE_COMPONENT_ROYALTY_CLAIM_ROYALTIES_IDENT,
+    };
+    pub use scrypto::prelude::{
+        RoyaltyAmount as NativeRoyaltyAmount,
+    };
     pub use radix_engine_interface::api::node_modules::auth::{
         RoleAssignmentCreateInput as NativeRoleAssignmentCreateInput,
         RoleAssignmentSetInput as NativeRoleAssignmentSetInput,
@@ -633,6 +818,13 @@ mod native {
         ACCESS_CONTROLLER_STOP_TIMED_RECOVERY_IDENT as NATIVE_ACCESS_CONTROLLER_STOP_TIMED_RECOVERY_IDENT,
         ACCESS_CONTROLLER_MINT_RECOVERY_BADGES_IDENT as NATIVE_ACCESS_CONTROLLER_MINT_RECOVERY_BADGES_IDENT,
     };
+    pub use radix_engine_interface::blueprints::identity::{
+        IdentityCreateInput as NativeIdentityCreateInput,
+        IdentityCreateAdvancedInput as NativeIdentityCreateAdvancedInput,
+        IDENTITY_BLUEPRINT as NATIVE_IDENTITY_BLUEPRINT,
+        IDENTITY_CREATE_IDENT as NATIVE_IDENTITY_CREATE_IDENT,
+        IDENTITY_CREATE_ADVANCED_IDENT as NATIVE_IDENTITY_CREATE_ADVANCED_IDENT,
+    };
     pub use radix_engine_interface::blueprints::account::{
         ResourcePreference as NativeResourcePreference,
         DefaultDepositRule as NativeDefaultDepositRule,
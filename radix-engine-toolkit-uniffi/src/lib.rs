@@ -28,6 +28,8 @@ pub mod error;
 pub mod events;
 pub mod manifest;
 pub mod sbor;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod traits;
 pub mod transaction;
 pub mod utils;
@@ -97,6 +99,8 @@ pub mod prelude {
     pub use crate::transaction::message::*;
     pub use crate::transaction::notarized_transaction::*;
     pub use crate::transaction::signed_intent::*;
+    pub use crate::transaction::signing_state::*;
+    pub use crate::transaction::transaction_request::*;
     pub use crate::transaction::validation::*;
 
     /* SBOR */
@@ -111,6 +115,10 @@ pub mod prelude {
     /* Derive */
     pub use crate::derive::functions::*;
 
+    /* Testing */
+    #[cfg(feature = "testing")]
+    pub use crate::testing::functions::*;
+
     /* Internal Prelude */
     pub(crate) use crate::internal_prelude::*;
 
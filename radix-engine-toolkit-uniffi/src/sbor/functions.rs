@@ -46,6 +46,40 @@ pub fn sbor_decode_to_string_representation(
     }
 }
 
+#[uniffi::export]
+pub fn sbor_decode_unknown(
+    bytes: Vec<u8>,
+    network_id: u8,
+    maximum_depth: Option<u64>,
+    maximum_length: Option<u64>,
+) -> Result<UnknownSborPayload> {
+    let network_definition = core_network_definition_from_network_id(network_id);
+    let bech32_encoder = NativeAddressBech32Encoder::new(&network_definition);
+    let default_limits = CoreSborDecodeLimits::default();
+    let limits = CoreSborDecodeLimits {
+        maximum_depth: maximum_depth.map_or(default_limits.maximum_depth, |value| value as usize),
+        maximum_length: maximum_length
+            .map_or(default_limits.maximum_length, |value| value as usize),
+    };
+    let payload = core_sbor_decode_unknown(bytes, limits, &bech32_encoder)?;
+    Ok(payload.into())
+}
+
+#[uniffi::export]
+pub fn manifest_value_validate_against_schema(
+    value: ManifestValue,
+    schema: Schema,
+) -> Result<Vec<SchemaViolation>> {
+    let value = value.to_native()?;
+    let (local_type_id, schema): (NativeLocalTypeId, NativeSchema<NativeScryptoCustomSchema>) =
+        schema.try_into()?;
+    let violations = core_manifest_value_schema_violations(&value, &schema, local_type_id)
+        .into_iter()
+        .map(Into::into)
+        .collect();
+    Ok(violations)
+}
+
 #[uniffi::export]
 pub fn scrypto_sbor_decode_to_string_representation(
     bytes: Vec<u8>,
@@ -75,6 +109,13 @@ pub fn scrypto_sbor_encode_string_representation(
     core_scrypto_encode_string_representation(representation.into()).map_err(Into::into)
 }
 
+#[uniffi::export]
+pub fn manifest_sbor_encode_string_representation(
+    representation: ScryptoSborString,
+) -> Result<Vec<u8>> {
+    core_manifest_encode_string_representation(representation.into()).map_err(Into::into)
+}
+
 #[uniffi::export]
 pub fn manifest_sbor_decode_to_string_representation(
     bytes: Vec<u8>,
@@ -103,6 +144,28 @@ pub enum ManifestSborStringRepresentation {
     JSON { value: SerializationMode },
 }
 
+#[derive(Clone, Debug, Record)]
+pub struct SchemaViolation {
+    pub path: String,
+    pub expected_type_kind: String,
+    pub expected_type_name: Option<String>,
+    pub found_value_kind: ManifestValueKind,
+    pub error: String,
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum SborPayloadKind {
+    Scrypto,
+    Manifest,
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct UnknownSborPayload {
+    pub kind: SborPayloadKind,
+    pub programmatic_json: String,
+    pub natural_json: String,
+}
+
 #[derive(Clone, Debug, Enum)]
 pub enum SerializationMode {
     Programmatic,
@@ -124,6 +187,7 @@ pub struct Schema {
 #[derive(Clone, Debug, Enum)]
 pub enum ScryptoSborString {
     ProgrammaticJson { value: String },
+    CoreApiProgrammaticJson { value: String },
 }
 
 impl From<ManifestSborStringRepresentation> for CoreManifestSborStringRepresentation {
@@ -174,6 +238,48 @@ impl From<ScryptoSborString> for CoreScryptoStringRepresentation {
             ScryptoSborString::ProgrammaticJson { value } => {
                 CoreScryptoStringRepresentation::ProgrammaticJson(value)
             }
+            ScryptoSborString::CoreApiProgrammaticJson { value } => {
+                CoreScryptoStringRepresentation::CoreApiProgrammaticJson(value)
+            }
+        }
+    }
+}
+
+impl From<CoreUnknownSborPayload> for UnknownSborPayload {
+    fn from(
+        CoreUnknownSborPayload {
+            kind,
+            programmatic_json,
+            natural_json,
+        }: CoreUnknownSborPayload,
+    ) -> Self {
+        Self {
+            kind: match kind {
+                CoreSborPayloadKind::Scrypto => SborPayloadKind::Scrypto,
+                CoreSborPayloadKind::Manifest => SborPayloadKind::Manifest,
+            },
+            programmatic_json,
+            natural_json,
+        }
+    }
+}
+
+impl From<CoreSchemaViolation> for SchemaViolation {
+    fn from(
+        CoreSchemaViolation {
+            path,
+            expected_type_kind,
+            expected_type_name,
+            found_value_kind,
+            error,
+        }: CoreSchemaViolation,
+    ) -> Self {
+        Self {
+            path,
+            expected_type_kind,
+            expected_type_name,
+            found_value_kind: found_value_kind.into(),
+            error,
         }
     }
 }
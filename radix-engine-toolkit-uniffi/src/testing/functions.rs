@@ -0,0 +1,62 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+#[derive(Clone, Copy, Debug, Enum, Hash, PartialEq, Eq)]
+pub enum NonFungibleLocalIdType {
+    Integer,
+    Str,
+    Bytes,
+    Ruid,
+}
+
+impl From<NonFungibleLocalIdType> for NativeNonFungibleIdType {
+    fn from(value: NonFungibleLocalIdType) -> Self {
+        match value {
+            NonFungibleLocalIdType::Integer => Self::Integer,
+            NonFungibleLocalIdType::Str => Self::String,
+            NonFungibleLocalIdType::Bytes => Self::Bytes,
+            NonFungibleLocalIdType::Ruid => Self::RUID,
+        }
+    }
+}
+
+/// A syntactically valid random address of the given entity type on the given network.
+#[uniffi::export]
+pub fn testing_random_address(entity_type: EntityType, network_id: u8) -> Arc<Address> {
+    let node_id = core_testing_random_node_id(entity_type.into());
+    Address::from_raw(node_id.0.to_vec(), network_id)
+        .expect("The random node id has a valid entity type")
+}
+
+#[uniffi::export]
+pub fn testing_random_non_fungible_local_id(id_type: NonFungibleLocalIdType) -> NonFungibleLocalId {
+    core_testing_random_non_fungible_local_id(id_type.into()).into()
+}
+
+/// A random non-fungible of a random non-fungible resource on the given network.
+#[uniffi::export]
+pub fn testing_random_non_fungible_global_id(
+    id_type: NonFungibleLocalIdType,
+    network_id: u8,
+) -> Result<Arc<NonFungibleGlobalId>> {
+    NonFungibleGlobalId::from_parts(
+        testing_random_address(EntityType::GlobalNonFungibleResourceManager, network_id),
+        testing_random_non_fungible_local_id(id_type),
+    )
+}
@@ -45,6 +45,20 @@ impl Instructions {
         native_decompile(&self.0, &network_definition).map_err(Into::into)
     }
 
+    /// The manifest string of the instructions along with the range of lines that each of the
+    /// instructions spans in it.
+    pub fn as_str_with_line_ranges(&self) -> Result<InstructionsString> {
+        let string = self.as_str()?;
+        let line_ranges = core_instructions_instruction_line_ranges(&string)
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        Ok(InstructionsString {
+            string,
+            line_ranges,
+        })
+    }
+
     pub fn instructions_list(&self) -> Vec<Instruction> {
         self.0
             .iter()
@@ -56,3 +70,132 @@ impl Instructions {
         self.1
     }
 }
+
+/// Compiles the manifest string and returns the diagnostic of the error encountered when doing so,
+/// or [`None`] if it compiles successfully.
+#[uniffi::export]
+pub fn instructions_compile_diagnostics(
+    string: String,
+    network_id: u8,
+) -> Option<ManifestCompileDiagnostic> {
+    let network_definition = core_network_definition_from_network_id(network_id);
+    let blob_provider = NativeMockBlobProvider::new();
+    native_compile(&string, &network_definition, blob_provider)
+        .err()
+        .map(|error| core_instructions_diagnose_compile_error(&string, &error).into())
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct InstructionsString {
+    pub string: String,
+    pub line_ranges: Vec<InstructionLineRange>,
+}
+
+/// The zero-based and inclusive range of lines of a manifest string that an instruction spans.
+#[derive(Clone, Debug, Record)]
+pub struct InstructionLineRange {
+    pub instruction_index: u64,
+    pub first_line: u64,
+    pub last_line: u64,
+}
+
+impl From<CoreInstructionLineRange> for InstructionLineRange {
+    fn from(value: CoreInstructionLineRange) -> Self {
+        Self {
+            instruction_index: value.instruction_index as u64,
+            first_line: value.first_line as u64,
+            last_line: value.last_line as u64,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct ManifestCompileDiagnostic {
+    pub kind: ManifestCompileErrorKind,
+    pub message: String,
+    pub span: Option<ManifestSpan>,
+    pub token: Option<String>,
+    pub expected: Vec<ManifestTokenClass>,
+    pub suggestion: Option<String>,
+}
+
+impl From<CoreManifestCompileDiagnostic> for ManifestCompileDiagnostic {
+    fn from(value: CoreManifestCompileDiagnostic) -> Self {
+        Self {
+            kind: value.kind.into(),
+            message: value.message,
+            span: value.span.map(Into::into),
+            token: value.token,
+            expected: value.expected.into_iter().map(Into::into).collect(),
+            suggestion: value.suggestion,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Enum)]
+pub enum ManifestCompileErrorKind {
+    LexerError,
+    ParserError,
+    GeneratorError,
+}
+
+impl From<CoreManifestCompileErrorKind> for ManifestCompileErrorKind {
+    fn from(value: CoreManifestCompileErrorKind) -> Self {
+        match value {
+            CoreManifestCompileErrorKind::LexerError => Self::LexerError,
+            CoreManifestCompileErrorKind::ParserError => Self::ParserError,
+            CoreManifestCompileErrorKind::GeneratorError => Self::GeneratorError,
+        }
+    }
+}
+
+/// A range of a manifest string that's inclusive of its start and exclusive of its end.
+#[derive(Clone, Debug, Record)]
+pub struct ManifestSpan {
+    pub start: ManifestPosition,
+    pub end: ManifestPosition,
+}
+
+impl From<CoreManifestSpan> for ManifestSpan {
+    fn from(value: CoreManifestSpan) -> Self {
+        Self {
+            start: value.start.into(),
+            end: value.end.into(),
+        }
+    }
+}
+
+/// A zero-based line and column, in characters, of a manifest string.
+#[derive(Clone, Debug, Record)]
+pub struct ManifestPosition {
+    pub line: u64,
+    pub column: u64,
+}
+
+impl From<CoreManifestPosition> for ManifestPosition {
+    fn from(value: CoreManifestPosition) -> Self {
+        Self {
+            line: value.line as u64,
+            column: value.column as u64,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Enum)]
+pub enum ManifestTokenClass {
+    InstructionName,
+    ValueKind,
+    Semicolon,
+    ClosingQuote,
+}
+
+impl From<CoreManifestTokenClass> for ManifestTokenClass {
+    fn from(value: CoreManifestTokenClass) -> Self {
+        match value {
+            CoreManifestTokenClass::InstructionName => Self::InstructionName,
+            CoreManifestTokenClass::ValueKind => Self::ValueKind,
+            CoreManifestTokenClass::Semicolon => Self::Semicolon,
+            CoreManifestTokenClass::ClosingQuote => Self::ClosingQuote,
+        }
+    }
+}
@@ -76,6 +76,13 @@ impl Intent {
             .and_then(|intent| core_intent_compile(&intent).map_err(Into::into))
     }
 
+    /// A representation of the intent that is safe to log, see the logging module of the core
+    /// toolkit.
+    pub fn to_log_string(&self) -> Result<String> {
+        NativeIntent::try_from(self.clone())
+            .map(|intent| intent.to_log_string(self.header.network_id))
+    }
+
     pub fn statically_validate(&self, validation_config: Arc<ValidationConfig>) -> Result<()> {
         core_intent_statically_validate(
             &self.clone().try_into()?,
@@ -83,6 +90,116 @@ impl Intent {
         )
         .map_err(Into::into)
     }
+
+    /// All of the static validation rules that the intent violates, unlike
+    /// [`Self::statically_validate`] which stops at the first one.
+    pub fn static_validation_violations(
+        &self,
+        validation_config: Arc<ValidationConfig>,
+    ) -> Result<Vec<StaticValidationViolation>> {
+        let violations = core_validation_validate_intent(
+            &NativeIntent::try_from(self.clone())?,
+            &validation_config.as_ref().clone().into(),
+        );
+        Ok(violations
+            .into_iter()
+            .map(StaticValidationViolation::from)
+            .collect())
+    }
+
+    pub fn compare(&self, other: Arc<Intent>) -> Result<IntentComparison> {
+        let intent_a = NativeIntent::try_from(self.clone())?;
+        let intent_b = NativeIntent::try_from(other.as_ref().clone())?;
+        Ok(core_intent_compare(&intent_a, &intent_b).into())
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct IntentComparison {
+    pub is_identical: bool,
+    pub changed_header_fields: Vec<HeaderField>,
+    pub message_changed: bool,
+    pub instruction_differences: Vec<InstructionDifference>,
+    pub added_blobs: Vec<Arc<Hash>>,
+    pub removed_blobs: Vec<Arc<Hash>>,
+}
+
+#[derive(Clone, Copy, Debug, Enum)]
+pub enum HeaderField {
+    NetworkId,
+    StartEpochInclusive,
+    EndEpochExclusive,
+    Nonce,
+    NotaryPublicKey,
+    NotaryIsSignatory,
+    TipPercentage,
+}
+
+#[derive(Clone, Copy, Debug, Enum)]
+pub enum InstructionDifference {
+    Unchanged { index_a: u64, index_b: u64 },
+    Removed { index_a: u64 },
+    Added { index_b: u64 },
+}
+
+impl From<CoreIntentComparison> for IntentComparison {
+    fn from(value: CoreIntentComparison) -> Self {
+        Self {
+            is_identical: value.is_identical(),
+            changed_header_fields: value
+                .changed_header_fields
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            message_changed: value.message_changed,
+            instruction_differences: value
+                .instruction_differences
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            added_blobs: value
+                .added_blobs
+                .into_iter()
+                .map(|hash| Arc::new(hash.into()))
+                .collect(),
+            removed_blobs: value
+                .removed_blobs
+                .into_iter()
+                .map(|hash| Arc::new(hash.into()))
+                .collect(),
+        }
+    }
+}
+
+impl From<CoreHeaderField> for HeaderField {
+    fn from(value: CoreHeaderField) -> Self {
+        match value {
+            CoreHeaderField::NetworkId => Self::NetworkId,
+            CoreHeaderField::StartEpochInclusive => Self::StartEpochInclusive,
+            CoreHeaderField::EndEpochExclusive => Self::EndEpochExclusive,
+            CoreHeaderField::Nonce => Self::Nonce,
+            CoreHeaderField::NotaryPublicKey => Self::NotaryPublicKey,
+            CoreHeaderField::NotaryIsSignatory => Self::NotaryIsSignatory,
+            CoreHeaderField::TipPercentage => Self::TipPercentage,
+        }
+    }
+}
+
+impl From<CoreInstructionDifference> for InstructionDifference {
+    fn from(value: CoreInstructionDifference) -> Self {
+        match value {
+            CoreInstructionDifference::Unchanged { index_a, index_b } => Self::Unchanged {
+                index_a: index_a as u64,
+                index_b: index_b as u64,
+            },
+            CoreInstructionDifference::Removed { index_a } => Self::Removed {
+                index_a: index_a as u64,
+            },
+            CoreInstructionDifference::Added { index_b } => Self::Added {
+                index_b: index_b as u64,
+            },
+        }
+    }
 }
 
 impl From<NativeIntent> for Intent {
@@ -99,6 +99,27 @@ impl TransactionManifest {
             .collect()
     }
 
+    pub fn non_account_transfers(&self) -> Vec<NonAccountTransfer> {
+        core_instructions_non_account_transfers(&self.instructions.0)
+            .into_iter()
+            .map(|transfer| NonAccountTransfer::from_native(transfer, self.instructions.1))
+            .collect()
+    }
+
+    pub fn authorized_depositor_badges(&self) -> Vec<AuthorizedDepositorBadgeUsage> {
+        core_instructions_authorized_depositor_badges(&self.instructions.0)
+            .iter()
+            .map(|usage| AuthorizedDepositorBadgeUsage::from_native(usage, self.instructions.1))
+            .collect()
+    }
+
+    pub fn access_controller_interactions(&self) -> Vec<AccessControllerInteraction> {
+        core_instructions_access_controller_interactions(&self.instructions.0)
+            .iter()
+            .map(|value| AccessControllerInteraction::from_native(value, self.instructions.1))
+            .collect()
+    }
+
     pub fn analyze_execution(&self, transaction_receipt: Vec<u8>) -> Result<ExecutionAnalysis> {
         let receipt =
             native_scrypto_decode::<NativeVersionedTransactionReceipt>(&transaction_receipt)?;
@@ -112,6 +133,54 @@ impl TransactionManifest {
         ))
     }
 
+    pub fn analyze_execution_with_dex_registry(
+        &self,
+        transaction_receipt: Vec<u8>,
+        dex_registry: Vec<DexVenue>,
+    ) -> Result<ExecutionAnalysis> {
+        let dex_registry = dex_registry
+            .into_iter()
+            .map(|venue| venue.to_native())
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .fold(CoreDexRegistry::new(), CoreDexRegistry::register);
+        let receipt =
+            native_scrypto_decode::<NativeVersionedTransactionReceipt>(&transaction_receipt)?;
+        let analysis = core_execution_analyze_with_dex_registry(
+            &self.instructions.0,
+            &CoreExecutionAnalysisTransactionReceipt::new(&receipt)?,
+            &dex_registry,
+            &mut [],
+        )?;
+        Ok(ExecutionAnalysis::from_native(
+            &analysis,
+            self.instructions.1,
+        ))
+    }
+
+    /// The minimum XRD balance that each account must hold for the transaction to go through, see
+    /// the execution module of the core toolkit.
+    pub fn minimum_xrd_required_per_account(
+        &self,
+        transaction_receipt: Vec<u8>,
+    ) -> Result<HashMap<String, Arc<Decimal>>> {
+        let receipt =
+            native_scrypto_decode::<NativeVersionedTransactionReceipt>(&transaction_receipt)?;
+        let minimum_xrd_required = core_execution_minimum_xrd_required_per_account(
+            &self.instructions.0,
+            &CoreExecutionAnalysisTransactionReceipt::new(&receipt)?,
+        );
+        Ok(minimum_xrd_required
+            .into_iter()
+            .map(|(account, amount)| {
+                (
+                    Address::from_typed_node_id(account, self.instructions.1).as_str(),
+                    Arc::new(Decimal(amount)),
+                )
+            })
+            .collect())
+    }
+
     pub fn modify(&self, modifications: TransactionManifestModifications) -> Result<Arc<Self>> {
         let modifications = modifications.to_native()?;
         let native_manifest = core_manifest_modify(&self.to_native(), modifications)?;
@@ -119,6 +188,80 @@ impl TransactionManifest {
         Ok(Arc::new(manifest))
     }
 
+    pub fn set_lock_fee(&self, account: Arc<Address>, amount: Arc<Decimal>) -> Result<Arc<Self>> {
+        let native_manifest =
+            core_manifest_set_lock_fee(&self.to_native(), (*account).try_into()?, amount.0)?;
+        let manifest = Self::from_native(&native_manifest, self.instructions.network_id());
+        Ok(Arc::new(manifest))
+    }
+
+    pub fn sponsor_fee(
+        &self,
+        sponsor: Arc<Address>,
+        amount: Arc<Decimal>,
+    ) -> Result<SponsoredManifest> {
+        let network_id = self.instructions.network_id();
+        let CoreManifestSponsoredManifest {
+            manifest,
+            sponsor_signers,
+            user_signers,
+        } = core_manifest_sponsor_fee(&self.to_native(), (*sponsor).try_into()?, amount.0)?;
+        let signers = |signers: Vec<CoreRequiredSigner>| {
+            signers
+                .into_iter()
+                .map(|signer| {
+                    Arc::new(Address::from_typed_node_id(
+                        signer.entity_address,
+                        network_id,
+                    ))
+                })
+                .collect()
+        };
+
+        Ok(SponsoredManifest {
+            manifest: Arc::new(Self::from_native(&manifest, network_id)),
+            sponsor_signers: signers(sponsor_signers),
+            user_signers: signers(user_signers),
+        })
+    }
+
+    pub fn deduplication_key(&self, signers: Vec<PublicKey>) -> Result<Arc<Hash>> {
+        let signers = signers
+            .into_iter()
+            .map(NativePublicKey::try_from)
+            .collect::<Result<Vec<_>>>()?;
+        let key = core_manifest_deduplication_key(
+            &self.to_native(),
+            &signers,
+            self.instructions.network_id(),
+        )?;
+        Ok(Arc::new(Hash(key)))
+    }
+
+    pub fn fee_sponsorship(&self) -> FeeSponsorship {
+        FeeSponsorship::from_native(
+            core_manifest_fee_sponsorship(&self.instructions.0),
+            self.instructions.network_id(),
+        )
+    }
+
+    pub fn add_guarantees(&self, guarantees: Vec<Guarantee>) -> Result<Arc<Self>> {
+        let native_manifest =
+            core_manifest_add_guarantees(&self.to_native(), guarantees.to_native()?)?;
+        let manifest = Self::from_native(&native_manifest, self.instructions.network_id());
+        Ok(Arc::new(manifest))
+    }
+
+    pub fn modify_instructions(
+        &self,
+        modifications: Vec<InstructionModification>,
+    ) -> Result<Arc<Self>> {
+        let native_manifest =
+            core_manifest_modify_instructions(&self.to_native(), modifications.to_native()?)?;
+        let manifest = Self::from_native(&native_manifest, self.instructions.network_id());
+        Ok(Arc::new(manifest))
+    }
+
     /* Transaction Types attempted parsing */
 
     pub fn parse_transfer_information(
@@ -195,6 +338,14 @@ pub struct FeeSummary {
     pub finalization_cost: Arc<Decimal>,
     pub storage_expansion_cost: Arc<Decimal>,
     pub royalty_cost: Arc<Decimal>,
+    pub fee_payers: FeePayers,
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum FeePayers {
+    None,
+    Single { account: Arc<Address> },
+    Multiple { accounts: Vec<Arc<Address>> },
 }
 
 #[derive(Clone, Debug, Record)]
@@ -229,10 +380,25 @@ pub enum TransactionType {
     ClaimStakeTransaction {
         claims: Vec<ClaimStakeInformation>,
     },
+    OwnerKeysUpdate {
+        owner_keys_changes: HashMap<String, Vec<OwnerKeysAction>>,
+    },
+    EntityControlChanges {
+        entity_control_changes: HashMap<String, Vec<EntityControlChange>>,
+    },
+    RoyaltyClaim {
+        claims: Vec<RoyaltyClaim>,
+        destinations: Vec<Arc<Address>>,
+    },
+    Swap {
+        swaps: Vec<Swap>,
+    },
     GeneralTransaction {
         account_proofs: Vec<Arc<Address>>,
         account_withdraws: HashMap<String, Vec<ResourceTracker>>,
         account_deposits: HashMap<String, Vec<ResourceTracker>>,
+        account_created_proofs: HashMap<String, Vec<ResourceSpecifier>>,
+        presented_proofs: Vec<PresentedProof>,
         addresses_in_manifest: HashMap<EntityType, Vec<Arc<Address>>>,
         metadata_of_newly_created_entities: HashMap<String, HashMap<String, Option<MetadataValue>>>,
         data_of_newly_minted_non_fungibles: HashMap<String, HashMap<NonFungibleLocalId, Vec<u8>>>,
@@ -240,6 +406,58 @@ pub enum TransactionType {
     },
 }
 
+#[derive(Clone, Debug, Enum)]
+pub enum OwnerKeysAction {
+    Set {
+        public_key_hashes: Vec<PublicKeyHash>,
+    },
+    Remove,
+    Lock,
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum EntityControlChange {
+    MetadataLocked {
+        key: String,
+    },
+    RoleSet {
+        module: ObjectModuleId,
+        role_key: String,
+        rule: Arc<AccessRule>,
+    },
+    OwnerRoleSet {
+        rule: Arc<AccessRule>,
+    },
+    OwnerRoleLocked,
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct RoyaltyClaim {
+    pub instruction_index: u64,
+    pub claimed_from: Arc<Address>,
+    pub claimed_xrd: Arc<Decimal>,
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct DexVenue {
+    pub name: String,
+    pub component_addresses: Vec<Arc<Address>>,
+    pub swap_methods: Vec<String>,
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct Swap {
+    pub instruction_index: u64,
+    pub venue: String,
+    pub component_address: Arc<Address>,
+    pub method_name: String,
+    pub input_resource: Arc<Address>,
+    pub input_amount: Arc<Decimal>,
+    pub output_resource: Arc<Address>,
+    pub output_amount: Arc<Decimal>,
+    pub minimum_output_amount: Option<Arc<Decimal>>,
+}
+
 #[derive(Clone, Debug, Record)]
 pub struct StakeInformation {
     pub from_account: Arc<Address>,
@@ -285,6 +503,30 @@ pub enum ResourceSpecifier {
     },
 }
 
+#[derive(Clone, Debug, Record)]
+pub struct PresentedProof {
+    pub instruction_index: u64,
+    pub component_address: Arc<Address>,
+    pub method_name: String,
+    pub account: Arc<Address>,
+    pub proof: ResourceSpecifier,
+}
+
+impl PresentedProof {
+    pub fn from_native(native: &CorePresentedProof, network_id: u8) -> Self {
+        Self {
+            instruction_index: native.instruction_index as u64,
+            component_address: Arc::new(Address::from_typed_node_id(
+                native.component_address,
+                network_id,
+            )),
+            method_name: native.method_name.clone(),
+            account: Arc::new(Address::from_typed_node_id(native.account, network_id)),
+            proof: ResourceSpecifier::from_native(&native.proof, network_id),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Enum)]
 pub enum Resources {
     Amount { amount: Arc<Decimal> },
@@ -297,6 +539,12 @@ pub struct ExecutionAnalysis {
     pub fee_summary: FeeSummary,
     pub transaction_types: Vec<TransactionType>,
     pub reserved_instructions: Vec<ReservedInstruction>,
+    pub reserved_instruction_usages: Vec<ReservedInstructionUsage>,
+    pub required_badges: Vec<InstructionBadgeRequirements>,
+    pub guarantees: Vec<WorktopGuarantee>,
+    pub authorized_depositor_badges: Vec<AuthorizedDepositorBadgeUsage>,
+    pub resource_movement_discrepancies: Vec<ResourceMovementDiscrepancy>,
+    pub resource_movement_graph: ResourceMovementGraph,
 }
 
 #[derive(Clone, Debug, Record)]
@@ -313,6 +561,13 @@ impl ExecutionAnalysis {
             fee_summary,
             transaction_types,
             reserved_instructions,
+            reserved_instruction_usages,
+            required_badges,
+            guarantees,
+            authorized_depositor_badges,
+            resource_movement_discrepancies,
+            resource_movement_graph,
+            ..
         }: &CoreExecutionExecutionAnalysis,
         network_id: u8,
     ) -> Self {
@@ -322,11 +577,418 @@ impl ExecutionAnalysis {
                 .map(|transaction_type| TransactionType::from_native(transaction_type, network_id))
                 .collect(),
             fee_locks: FeeLocks::from_native(fee_locks),
-            fee_summary: FeeSummary::from_native(fee_summary),
+            fee_summary: FeeSummary::from_native(fee_summary, network_id),
             reserved_instructions: reserved_instructions
                 .iter()
                 .map(|value| (*value).into())
                 .collect(),
+            reserved_instruction_usages: reserved_instruction_usages
+                .iter()
+                .map(|value| ReservedInstructionUsage::from_native(value, network_id))
+                .collect(),
+            required_badges: required_badges
+                .iter()
+                .map(|value| InstructionBadgeRequirements::from_native(value, network_id))
+                .collect(),
+            guarantees: guarantees
+                .iter()
+                .map(|value| WorktopGuarantee::from_native(value, network_id))
+                .collect(),
+            authorized_depositor_badges: authorized_depositor_badges
+                .iter()
+                .map(|value| AuthorizedDepositorBadgeUsage::from_native(value, network_id))
+                .collect(),
+            resource_movement_discrepancies: resource_movement_discrepancies
+                .iter()
+                .map(|value| ResourceMovementDiscrepancy::from_native(value, network_id))
+                .collect(),
+            resource_movement_graph: ResourceMovementGraph::from_native(
+                resource_movement_graph,
+                network_id,
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct ResourceMovementDiscrepancy {
+    pub account: Arc<Address>,
+    pub resource_address: Arc<Address>,
+    pub direction: ResourceMovementDirection,
+    pub manifest_amount: Arc<Decimal>,
+    pub receipt_amount: Arc<Decimal>,
+}
+
+#[derive(Clone, Copy, Debug, Enum)]
+pub enum ResourceMovementDirection {
+    Withdraw,
+    Deposit,
+}
+
+impl ResourceMovementDiscrepancy {
+    pub fn from_native(native: &CoreExecutionResourceMovementDiscrepancy, network_id: u8) -> Self {
+        Self {
+            account: Arc::new(Address::from_typed_node_id(native.account, network_id)),
+            resource_address: Arc::new(Address::from_typed_node_id(
+                native.resource_address,
+                network_id,
+            )),
+            direction: match native.direction {
+                CoreExecutionResourceMovementDirection::Withdraw => {
+                    ResourceMovementDirection::Withdraw
+                }
+                CoreExecutionResourceMovementDirection::Deposit => {
+                    ResourceMovementDirection::Deposit
+                }
+            },
+            manifest_amount: Arc::new(Decimal(native.manifest_amount)),
+            receipt_amount: Arc::new(Decimal(native.receipt_amount)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct ResourceMovementGraph {
+    pub nodes: Vec<ResourceMovementGraphNode>,
+    pub edges: Vec<ResourceMovementGraphEdge>,
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum ResourceMovementGraphNode {
+    Worktop,
+    Entity {
+        address: Arc<Address>,
+        entity_type: EntityType,
+    },
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct ResourceMovementGraphEdge {
+    pub instruction_index: u64,
+    pub from: u64,
+    pub to: u64,
+    pub resource_address: Arc<Address>,
+    pub amount: Arc<Decimal>,
+    pub guarantee: ResourceMovementGuarantee,
+}
+
+#[derive(Clone, Copy, Debug, Enum)]
+pub enum ResourceMovementGuarantee {
+    Guaranteed,
+    Predicted,
+}
+
+impl ResourceMovementGraph {
+    pub fn from_native(native: &CoreExecutionResourceMovementGraph, network_id: u8) -> Self {
+        Self {
+            nodes: native
+                .nodes
+                .iter()
+                .map(|node| match node {
+                    CoreExecutionResourceMovementGraphNode::Worktop => {
+                        ResourceMovementGraphNode::Worktop
+                    }
+                    CoreExecutionResourceMovementGraphNode::Entity(node_id) => {
+                        ResourceMovementGraphNode::Entity {
+                            address: Arc::new(Address::from_typed_node_id(*node_id, network_id)),
+                            entity_type: node_id.entity_type().into(),
+                        }
+                    }
+                })
+                .collect(),
+            edges: native
+                .edges
+                .iter()
+                .map(|edge| ResourceMovementGraphEdge::from_native(edge, network_id))
+                .collect(),
+        }
+    }
+}
+
+impl ResourceMovementGraphEdge {
+    pub fn from_native(native: &CoreExecutionResourceMovementGraphEdge, network_id: u8) -> Self {
+        Self {
+            instruction_index: native.instruction_index as u64,
+            from: native.from as u64,
+            to: native.to as u64,
+            resource_address: Arc::new(Address::from_typed_node_id(
+                native.resource_address,
+                network_id,
+            )),
+            amount: Arc::new(Decimal(native.amount)),
+            guarantee: match native.guarantee {
+                CoreExecutionResourceMovementGuarantee::Guaranteed => {
+                    ResourceMovementGuarantee::Guaranteed
+                }
+                CoreExecutionResourceMovementGuarantee::Predicted => {
+                    ResourceMovementGuarantee::Predicted
+                }
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct WorktopGuarantee {
+    pub instruction_index: u64,
+    pub resource_address: Arc<Address>,
+    pub guarantee: GuaranteeKind,
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum GuaranteeKind {
+    Amount { amount: Arc<Decimal> },
+    Any,
+    NonFungibles { ids: Vec<NonFungibleLocalId> },
+}
+
+impl WorktopGuarantee {
+    pub fn from_native(native: &CoreWorktopGuarantee, network_id: u8) -> Self {
+        Self {
+            instruction_index: native.instruction_index as u64,
+            resource_address: Arc::new(Address::from_typed_node_id(
+                native.resource_address,
+                network_id,
+            )),
+            guarantee: match &native.guarantee {
+                CoreGuaranteeKind::Amount(amount) => GuaranteeKind::Amount {
+                    amount: Arc::new(Decimal(*amount)),
+                },
+                CoreGuaranteeKind::Any => GuaranteeKind::Any,
+                CoreGuaranteeKind::NonFungibles(ids) => GuaranteeKind::NonFungibles {
+                    ids: ids.iter().cloned().map(Into::into).collect(),
+                },
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct AccessControllerInteraction {
+    pub instruction_index: u64,
+    pub access_controller: Arc<Address>,
+    pub operation: AccessControllerOperation,
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum AccessControllerOperation {
+    CreateProof,
+    InitiateRecovery {
+        proposer: Proposer,
+        proposal: RecoveryProposal,
+    },
+    QuickConfirmRecovery {
+        proposer: Proposer,
+        proposal: RecoveryProposal,
+    },
+    TimedConfirmRecovery {
+        proposal: RecoveryProposal,
+    },
+    CancelRecovery {
+        proposer: Proposer,
+    },
+    StopTimedRecovery {
+        proposal: RecoveryProposal,
+    },
+    InitiateBadgeWithdrawAttempt {
+        proposer: Proposer,
+    },
+    QuickConfirmBadgeWithdrawAttempt {
+        proposer: Proposer,
+    },
+    CancelBadgeWithdrawAttempt {
+        proposer: Proposer,
+    },
+    LockPrimaryRole,
+    UnlockPrimaryRole,
+    MintRecoveryBadges {
+        non_fungible_local_ids: Vec<NonFungibleLocalId>,
+    },
+}
+
+impl AccessControllerInteraction {
+    pub fn from_native(native: &CoreAccessControllerInteraction, network_id: u8) -> Self {
+        Self {
+            instruction_index: native.instruction_index as u64,
+            access_controller: Arc::new(Address::from_typed_node_id(
+                native.access_controller,
+                network_id,
+            )),
+            operation: AccessControllerOperation::from_native(&native.operation),
+        }
+    }
+}
+
+impl AccessControllerOperation {
+    pub fn from_native(native: &CoreAccessControllerOperation) -> Self {
+        let proposer = |proposer: &NativeProposer| <Proposer as FromNative>::from_native(*proposer);
+        let proposal = |proposal: &NativeRecoveryProposal| {
+            <RecoveryProposal as FromNative>::from_native(proposal.clone())
+        };
+
+        match native {
+            CoreAccessControllerOperation::CreateProof => Self::CreateProof,
+            CoreAccessControllerOperation::InitiateRecovery {
+                proposer: native_proposer,
+                proposal: native_proposal,
+            } => Self::InitiateRecovery {
+                proposer: proposer(native_proposer),
+                proposal: proposal(native_proposal),
+            },
+            CoreAccessControllerOperation::QuickConfirmRecovery {
+                proposer: native_proposer,
+                proposal: native_proposal,
+            } => Self::QuickConfirmRecovery {
+                proposer: proposer(native_proposer),
+                proposal: proposal(native_proposal),
+            },
+            CoreAccessControllerOperation::TimedConfirmRecovery {
+                proposal: native_proposal,
+            } => Self::TimedConfirmRecovery {
+                proposal: proposal(native_proposal),
+            },
+            CoreAccessControllerOperation::CancelRecovery {
+                proposer: native_proposer,
+            } => Self::CancelRecovery {
+                proposer: proposer(native_proposer),
+            },
+            CoreAccessControllerOperation::StopTimedRecovery {
+                proposal: native_proposal,
+            } => Self::StopTimedRecovery {
+                proposal: proposal(native_proposal),
+            },
+            CoreAccessControllerOperation::InitiateBadgeWithdrawAttempt {
+                proposer: native_proposer,
+            } => Self::InitiateBadgeWithdrawAttempt {
+                proposer: proposer(native_proposer),
+            },
+            CoreAccessControllerOperation::QuickConfirmBadgeWithdrawAttempt {
+                proposer: native_proposer,
+            } => Self::QuickConfirmBadgeWithdrawAttempt {
+                proposer: proposer(native_proposer),
+            },
+            CoreAccessControllerOperation::CancelBadgeWithdrawAttempt {
+                proposer: native_proposer,
+            } => Self::CancelBadgeWithdrawAttempt {
+                proposer: proposer(native_proposer),
+            },
+            CoreAccessControllerOperation::LockPrimaryRole => Self::LockPrimaryRole,
+            CoreAccessControllerOperation::UnlockPrimaryRole => Self::UnlockPrimaryRole,
+            CoreAccessControllerOperation::MintRecoveryBadges {
+                non_fungible_local_ids,
+            } => Self::MintRecoveryBadges {
+                non_fungible_local_ids: non_fungible_local_ids
+                    .iter()
+                    .cloned()
+                    .map(Into::into)
+                    .collect(),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct InstructionBadgeRequirements {
+    pub instruction_index: u64,
+    pub proofs_created: Vec<CreatedProof>,
+    pub required_badges: Vec<RequiredBadge>,
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum CreatedProof {
+    Amount {
+        resource_address: Arc<Address>,
+        amount: Arc<Decimal>,
+    },
+    NonFungibles {
+        resource_address: Arc<Address>,
+        ids: Vec<NonFungibleLocalId>,
+    },
+    All {
+        resource_address: Arc<Address>,
+    },
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct RequiredBadge {
+    pub entity_address: Arc<Address>,
+    pub method_name: String,
+    pub requirement: BadgeRequirement,
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum BadgeRequirement {
+    NonFungible { id: Arc<NonFungibleGlobalId> },
+    AnyOfRoles { roles: Vec<String> },
+}
+
+impl InstructionBadgeRequirements {
+    pub fn from_native(native: &CoreInstructionBadgeRequirements, network_id: u8) -> Self {
+        Self {
+            instruction_index: native.instruction_index as u64,
+            proofs_created: native
+                .proofs_created
+                .iter()
+                .map(|value| CreatedProof::from_native(value, network_id))
+                .collect(),
+            required_badges: native
+                .required_badges
+                .iter()
+                .map(|value| RequiredBadge::from_native(value, network_id))
+                .collect(),
+        }
+    }
+}
+
+impl CreatedProof {
+    pub fn from_native(native: &CoreCreatedProof, network_id: u8) -> Self {
+        match native {
+            CoreCreatedProof::Amount {
+                resource_address,
+                amount,
+            } => Self::Amount {
+                resource_address: Arc::new(Address::from_typed_node_id(
+                    *resource_address,
+                    network_id,
+                )),
+                amount: Arc::new(Decimal(*amount)),
+            },
+            CoreCreatedProof::NonFungibles {
+                resource_address,
+                ids,
+            } => Self::NonFungibles {
+                resource_address: Arc::new(Address::from_typed_node_id(
+                    *resource_address,
+                    network_id,
+                )),
+                ids: ids.iter().cloned().map(Into::into).collect(),
+            },
+            CoreCreatedProof::All { resource_address } => Self::All {
+                resource_address: Arc::new(Address::from_typed_node_id(
+                    *resource_address,
+                    network_id,
+                )),
+            },
+        }
+    }
+}
+
+impl RequiredBadge {
+    pub fn from_native(native: &CoreRequiredBadge, network_id: u8) -> Self {
+        Self {
+            entity_address: Arc::new(Address::from_typed_node_id(
+                native.entity_address,
+                network_id,
+            )),
+            method_name: native.method_name.clone(),
+            requirement: match &native.requirement {
+                CoreBadgeRequirement::NonFungible(id) => BadgeRequirement::NonFungible {
+                    id: Arc::new(NonFungibleGlobalId(id.clone(), network_id)),
+                },
+                CoreBadgeRequirement::AnyOfRoles(roles) => BadgeRequirement::AnyOfRoles {
+                    roles: roles.clone(),
+                },
+            },
         }
     }
 }
@@ -463,6 +1125,8 @@ impl TransactionType {
                     account_proofs,
                     account_withdraws,
                     account_deposits,
+                    account_created_proofs,
+                    presented_proofs,
                     addresses_in_manifest: (addresses_in_manifest, _),
                     metadata_of_newly_created_entities,
                     data_of_newly_minted_non_fungibles,
@@ -498,6 +1162,22 @@ impl TransactionType {
                             )
                         })
                         .collect(),
+                    account_created_proofs: account_created_proofs
+                        .iter()
+                        .map(|(key, value)| {
+                            (
+                                Address::from_typed_node_id(*key, network_id).as_str(),
+                                value
+                                    .iter()
+                                    .map(|value| ResourceSpecifier::from_native(value, network_id))
+                                    .collect(),
+                            )
+                        })
+                        .collect(),
+                    presented_proofs: presented_proofs
+                        .iter()
+                        .map(|value| PresentedProof::from_native(value, network_id))
+                        .collect(),
                     addresses_in_manifest: {
                         let mut map = HashMap::<EntityType, Vec<Arc<Address>>>::new();
                         for address in addresses_in_manifest {
@@ -574,6 +1254,150 @@ impl TransactionType {
                         .collect(),
                 }
             }
+            CoreExecutionTransactionType::OwnerKeysUpdate(value) => {
+                let CoreOwnerKeysUpdateTransactionType { owner_keys_changes } = value.as_ref();
+                Self::OwnerKeysUpdate {
+                    owner_keys_changes: owner_keys_changes
+                        .iter()
+                        .map(|(key, value)| {
+                            (
+                                Arc::new(Address::from_typed_node_id(*key, network_id)).as_str(),
+                                value.iter().map(OwnerKeysAction::from_native).collect(),
+                            )
+                        })
+                        .collect(),
+                }
+            }
+            CoreExecutionTransactionType::EntityControlChanges(value) => {
+                let CoreEntityControlChangesTransactionType {
+                    entity_control_changes,
+                } = value.as_ref();
+                Self::EntityControlChanges {
+                    entity_control_changes: entity_control_changes
+                        .iter()
+                        .map(|(key, value)| {
+                            (
+                                Arc::new(Address::from_typed_node_id(*key, network_id)).as_str(),
+                                value.iter().map(EntityControlChange::from_native).collect(),
+                            )
+                        })
+                        .collect(),
+                }
+            }
+            CoreExecutionTransactionType::RoyaltyClaim(value) => {
+                let CoreRoyaltyClaimTransactionType {
+                    claims,
+                    destinations,
+                } = value.as_ref();
+                Self::RoyaltyClaim {
+                    claims: claims
+                        .iter()
+                        .map(
+                            |CoreRoyaltyClaim {
+                                 instruction_index,
+                                 claimed_from,
+                                 claimed_xrd,
+                             }| RoyaltyClaim {
+                                instruction_index: *instruction_index as u64,
+                                claimed_from: Arc::new(Address::from_typed_node_id(
+                                    *claimed_from,
+                                    network_id,
+                                )),
+                                claimed_xrd: Arc::new(Decimal(*claimed_xrd)),
+                            },
+                        )
+                        .collect(),
+                    destinations: destinations
+                        .iter()
+                        .map(|address| Arc::new(Address::from_typed_node_id(*address, network_id)))
+                        .collect(),
+                }
+            }
+            CoreExecutionTransactionType::Swap(value) => {
+                let CoreSwapTransactionType { swaps } = value.as_ref();
+                Self::Swap {
+                    swaps: swaps
+                        .iter()
+                        .map(|swap| Swap::from_native(swap, network_id))
+                        .collect(),
+                }
+            }
+        }
+    }
+}
+
+impl DexVenue {
+    pub fn to_native(&self) -> Result<CoreDexVenue> {
+        Ok(CoreDexVenue {
+            name: self.name.clone(),
+            component_addresses: self
+                .component_addresses
+                .iter()
+                .map(|address| (**address).try_into())
+                .collect::<Result<_>>()?,
+            swap_methods: self.swap_methods.clone(),
+        })
+    }
+}
+
+impl Swap {
+    pub fn from_native(native: &CoreSwap, network_id: u8) -> Self {
+        Self {
+            instruction_index: native.instruction_index as u64,
+            venue: native.venue.clone(),
+            component_address: Arc::new(Address::from_typed_node_id(
+                native.component_address,
+                network_id,
+            )),
+            method_name: native.method_name.clone(),
+            input_resource: Arc::new(Address::from_typed_node_id(
+                native.input_resource,
+                network_id,
+            )),
+            input_amount: Arc::new(Decimal(native.input_amount)),
+            output_resource: Arc::new(Address::from_typed_node_id(
+                native.output_resource,
+                network_id,
+            )),
+            output_amount: Arc::new(Decimal(native.output_amount)),
+            minimum_output_amount: native
+                .minimum_output_amount
+                .map(|amount| Arc::new(Decimal(amount))),
+        }
+    }
+}
+
+impl OwnerKeysAction {
+    pub fn from_native(native: &CoreOwnerKeysAction) -> Self {
+        match native {
+            CoreOwnerKeysAction::Set(public_key_hashes) => Self::Set {
+                public_key_hashes: public_key_hashes.iter().cloned().map(Into::into).collect(),
+            },
+            CoreOwnerKeysAction::Remove => Self::Remove,
+            CoreOwnerKeysAction::Lock => Self::Lock,
+        }
+    }
+}
+
+impl EntityControlChange {
+    pub fn from_native(native: &CoreEntityControlChange) -> Self {
+        match native {
+            CoreEntityControlChange::MetadataLocked { key } => {
+                Self::MetadataLocked { key: key.clone() }
+            }
+            CoreEntityControlChange::RoleSet {
+                module,
+                role_key,
+                rule,
+            } => Self::RoleSet {
+                module: (*module).into(),
+                role_key: role_key.key.clone(),
+                rule: Arc::new(AccessRule(rule.clone())),
+            },
+            CoreEntityControlChange::OwnerRoleSet { rule } => Self::OwnerRoleSet {
+                rule: Arc::new(AccessRule(rule.clone())),
+            },
+            CoreEntityControlChange::OwnerRoleLocked => Self::OwnerRoleLocked,
         }
     }
 }
@@ -599,13 +1423,33 @@ impl FeeSummary {
             royalty_cost,
             finalization_cost,
             storage_expansion_cost,
+            fee_payers,
         }: &CoreExecutionFeeSummary,
+        network_id: u8,
     ) -> Self {
         Self {
             execution_cost: Arc::new(Decimal(*execution_cost)),
             royalty_cost: Arc::new(Decimal(*royalty_cost)),
             finalization_cost: Arc::new(Decimal(*finalization_cost)),
             storage_expansion_cost: Arc::new(Decimal(*storage_expansion_cost)),
+            fee_payers: FeePayers::from_native(fee_payers, network_id),
+        }
+    }
+}
+
+impl FeePayers {
+    pub fn from_native(native: &CoreFeePayers, network_id: u8) -> Self {
+        match native {
+            CoreFeePayers::None => Self::None,
+            CoreFeePayers::Single(account) => Self::Single {
+                account: Arc::new(Address::from_typed_node_id(*account, network_id)),
+            },
+            CoreFeePayers::Multiple(accounts) => Self::Multiple {
+                accounts: accounts
+                    .iter()
+                    .map(|account| Arc::new(Address::from_typed_node_id(*account, network_id)))
+                    .collect(),
+            },
         }
     }
 }
@@ -737,6 +1581,29 @@ impl FromNative for AccountDefaultDepositRule {
     }
 }
 
+impl ToNative for ResourcePreference {
+    type Native = NativeResourcePreference;
+
+    fn to_native(self) -> Result<Self::Native> {
+        Ok(match self {
+            Self::Allowed => NativeResourcePreference::Allowed,
+            Self::Disallowed => NativeResourcePreference::Disallowed,
+        })
+    }
+}
+
+impl ToNative for AccountDefaultDepositRule {
+    type Native = NativeDefaultDepositRule;
+
+    fn to_native(self) -> Result<Self::Native> {
+        Ok(match self {
+            Self::Accept => NativeDefaultDepositRule::Accept,
+            Self::Reject => NativeDefaultDepositRule::Reject,
+            Self::AllowExisting => NativeDefaultDepositRule::AllowExisting,
+        })
+    }
+}
+
 impl FromNativeWithNetworkContext for AuthorizedDepositorsChanges {
     type Native = CoreAuthorizedDepositorsChanges;
 
@@ -789,6 +1656,149 @@ impl From<CoreReservedInstruction> for ReservedInstruction {
     }
 }
 
+#[derive(Clone, Debug, Record)]
+pub struct ReservedInstructionUsage {
+    pub instruction_index: u64,
+    pub address: ManifestAddress,
+    pub reserved_instruction: ReservedInstruction,
+    pub arguments: ReservedInstructionArguments,
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum ReservedInstructionArguments {
+    AccountLockFee {
+        amount: Arc<Decimal>,
+        contingent: bool,
+    },
+    AccountLockFeeAndWithdraw {
+        amount_to_lock: Arc<Decimal>,
+        resource_address: Arc<Address>,
+        amount: Arc<Decimal>,
+    },
+    AccountLockFeeAndWithdrawNonFungibles {
+        amount_to_lock: Arc<Decimal>,
+        resource_address: Arc<Address>,
+        ids: Vec<NonFungibleLocalId>,
+    },
+    Securify,
+    AccountAddAuthorizedDepositor {
+        badge: ResourceOrNonFungible,
+    },
+    AccountRemoveAuthorizedDepositor {
+        badge: ResourceOrNonFungible,
+    },
+    AccountSetDefaultDepositRule {
+        default_deposit_rule: AccountDefaultDepositRule,
+    },
+    AccountSetResourcePreference {
+        resource_address: Arc<Address>,
+        resource_preference: ResourcePreference,
+    },
+    AccountRemoveResourcePreference {
+        resource_address: Arc<Address>,
+    },
+    AccessControllerMethod {
+        method_name: String,
+    },
+    Undecodable {
+        method_name: String,
+    },
+}
+
+impl ReservedInstructionUsage {
+    pub fn from_native(native: &CoreReservedInstructionUsage, network_id: u8) -> Self {
+        Self {
+            instruction_index: native.instruction_index as u64,
+            address: ManifestAddress::from_dynamic_global_address(&native.address, network_id),
+            reserved_instruction: native.reserved_instruction.into(),
+            arguments: ReservedInstructionArguments::from_native(&native.arguments, network_id),
+        }
+    }
+}
+
+impl ReservedInstructionArguments {
+    pub fn from_native(native: &CoreReservedInstructionArguments, network_id: u8) -> Self {
+        match native {
+            CoreReservedInstructionArguments::AccountLockFee { amount, contingent } => {
+                Self::AccountLockFee {
+                    amount: Arc::new(Decimal(*amount)),
+                    contingent: *contingent,
+                }
+            }
+            CoreReservedInstructionArguments::AccountLockFeeAndWithdraw {
+                amount_to_lock,
+                resource_address,
+                amount,
+            } => Self::AccountLockFeeAndWithdraw {
+                amount_to_lock: Arc::new(Decimal(*amount_to_lock)),
+                resource_address: Arc::new(Address::from_typed_node_id(
+                    *resource_address,
+                    network_id,
+                )),
+                amount: Arc::new(Decimal(*amount)),
+            },
+            CoreReservedInstructionArguments::AccountLockFeeAndWithdrawNonFungibles {
+                amount_to_lock,
+                resource_address,
+                ids,
+            } => Self::AccountLockFeeAndWithdrawNonFungibles {
+                amount_to_lock: Arc::new(Decimal(*amount_to_lock)),
+                resource_address: Arc::new(Address::from_typed_node_id(
+                    *resource_address,
+                    network_id,
+                )),
+                ids: ids.iter().cloned().map(Into::into).collect(),
+            },
+            CoreReservedInstructionArguments::Securify => Self::Securify,
+            CoreReservedInstructionArguments::AccountAddAuthorizedDepositor { badge } => {
+                Self::AccountAddAuthorizedDepositor {
+                    badge: FromNativeWithNetworkContext::from_native(badge.clone(), network_id),
+                }
+            }
+            CoreReservedInstructionArguments::AccountRemoveAuthorizedDepositor { badge } => {
+                Self::AccountRemoveAuthorizedDepositor {
+                    badge: FromNativeWithNetworkContext::from_native(badge.clone(), network_id),
+                }
+            }
+            CoreReservedInstructionArguments::AccountSetDefaultDepositRule {
+                default_deposit_rule,
+            } => Self::AccountSetDefaultDepositRule {
+                default_deposit_rule: <AccountDefaultDepositRule as FromNative>::from_native(
+                    *default_deposit_rule,
+                ),
+            },
+            CoreReservedInstructionArguments::AccountSetResourcePreference {
+                resource_address,
+                resource_preference,
+            } => Self::AccountSetResourcePreference {
+                resource_address: Arc::new(Address::from_typed_node_id(
+                    *resource_address,
+                    network_id,
+                )),
+                resource_preference: <ResourcePreference as FromNative>::from_native(
+                    *resource_preference,
+                ),
+            },
+            CoreReservedInstructionArguments::AccountRemoveResourcePreference {
+                resource_address,
+            } => Self::AccountRemoveResourcePreference {
+                resource_address: Arc::new(Address::from_typed_node_id(
+                    *resource_address,
+                    network_id,
+                )),
+            },
+            CoreReservedInstructionArguments::AccessControllerMethod { method_name } => {
+                Self::AccessControllerMethod {
+                    method_name: method_name.clone(),
+                }
+            }
+            CoreReservedInstructionArguments::Undecodable { method_name } => Self::Undecodable {
+                method_name: method_name.clone(),
+            },
+        }
+    }
+}
+
 macro_rules! define_source_enum {
     ($type: ty) => {
         paste::paste! {
@@ -836,6 +1846,28 @@ pub struct IndexedAssertion {
     pub assertion: Assertion,
 }
 
+#[derive(Clone, Debug, Record)]
+pub struct Guarantee {
+    pub instruction_index: u64,
+    pub resource_address: Arc<Address>,
+    pub amount: Arc<Decimal>,
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum InstructionModification {
+    Insert {
+        index: u64,
+        instruction: Instruction,
+    },
+    Remove {
+        index: u64,
+    },
+    Replace {
+        index: u64,
+        instruction: Instruction,
+    },
+}
+
 #[derive(Clone, Debug, Record)]
 pub struct LockFeeModification {
     pub account_address: Arc<Address>,
@@ -874,6 +1906,39 @@ impl ToNative for TransactionManifestModifications {
     }
 }
 
+impl ToNative for Guarantee {
+    type Native = CoreManifestGuarantee;
+
+    fn to_native(self) -> Result<Self::Native> {
+        Ok(Self::Native {
+            instruction_index: self.instruction_index as usize,
+            resource_address: (*self.resource_address).try_into()?,
+            amount: self.amount.0,
+        })
+    }
+}
+
+impl ToNative for InstructionModification {
+    type Native = CoreManifestInstructionModification;
+
+    fn to_native(self) -> Result<Self::Native> {
+        let value = match self {
+            Self::Insert { index, instruction } => Self::Native::Insert {
+                index: index as usize,
+                instruction: instruction.to_native()?,
+            },
+            Self::Remove { index } => Self::Native::Remove {
+                index: index as usize,
+            },
+            Self::Replace { index, instruction } => Self::Native::Replace {
+                index: index as usize,
+                instruction: instruction.to_native()?,
+            },
+        };
+        Ok(value)
+    }
+}
+
 impl ToNative for Assertion {
     type Native = CoreManifestAssertion;
 
@@ -1007,3 +2072,82 @@ impl ClaimStakeInformation {
         }
     }
 }
+
+#[derive(Clone, Debug, Record)]
+pub struct SponsoredManifest {
+    pub manifest: Arc<TransactionManifest>,
+    pub sponsor_signers: Vec<Arc<Address>>,
+    pub user_signers: Vec<Arc<Address>>,
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct FeeSponsorship {
+    pub fee_payers: Vec<Arc<Address>>,
+    pub asset_movers: Vec<Arc<Address>>,
+    pub sponsors: Vec<Arc<Address>>,
+    pub is_sponsored: bool,
+}
+
+impl FeeSponsorship {
+    pub fn from_native(native: CoreManifestFeeSponsorship, network_id: u8) -> Self {
+        let addresses = |accounts: &[NativeComponentAddress]| {
+            accounts
+                .iter()
+                .map(|account| Arc::new(Address::from_typed_node_id(*account, network_id)))
+                .collect()
+        };
+        Self {
+            fee_payers: addresses(&native.fee_payers),
+            asset_movers: addresses(&native.asset_movers),
+            sponsors: addresses(&native.sponsors),
+            is_sponsored: native.is_sponsored(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct NonAccountTransfer {
+    pub instruction_index: u64,
+    pub component_address: ManifestAddress,
+    pub method_name: String,
+    pub entire_worktop: bool,
+    pub buckets: Vec<ManifestBucket>,
+}
+
+impl NonAccountTransfer {
+    pub fn from_native(native: CoreNonAccountTransfer, network_id: u8) -> Self {
+        Self {
+            instruction_index: native.instruction_index as u64,
+            component_address: ManifestAddress::from_dynamic_global_address(
+                &native.component_address,
+                network_id,
+            ),
+            method_name: native.method_name,
+            entire_worktop: native.entire_worktop,
+            buckets: native.buckets.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// A deposit into an account which presents an authorized depositor badge.
+#[derive(Clone, Debug, Record)]
+pub struct AuthorizedDepositorBadgeUsage {
+    pub instruction_index: u64,
+    pub account: ManifestAddress,
+    pub method_name: String,
+    pub badge: ResourceOrNonFungible,
+}
+
+impl AuthorizedDepositorBadgeUsage {
+    pub fn from_native(native: &CoreAuthorizedDepositorBadgeUsage, network_id: u8) -> Self {
+        Self {
+            instruction_index: native.instruction_index as u64,
+            account: ManifestAddress::from_dynamic_global_address(&native.account, network_id),
+            method_name: native.method_name.clone(),
+            badge: <ResourceOrNonFungible as FromNativeWithNetworkContext>::from_native(
+                native.badge.clone(),
+                network_id,
+            ),
+        }
+    }
+}
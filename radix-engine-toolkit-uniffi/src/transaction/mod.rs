@@ -24,4 +24,6 @@ pub mod manifest;
 pub mod message;
 pub mod notarized_transaction;
 pub mod signed_intent;
+pub mod signing_state;
+pub mod transaction_request;
 pub mod validation;
@@ -80,6 +80,58 @@ impl NotarizedTransaction {
         })
     }
 
+    /// A representation of the notarized transaction that is safe to log, see the logging module
+    /// of the core toolkit.
+    pub fn to_log_string(&self) -> Result<String> {
+        NativeNotarizedTransaction::try_from(self.clone()).map(|notarized_transaction| {
+            notarized_transaction.to_log_string(self.signed_intent.intent.header.network_id)
+        })
+    }
+
+    /// Returns the hex-encoded payload to submit to the network along with the payload id and the
+    /// intent hash to track the transaction by.
+    pub fn submission_envelope(&self) -> Result<SubmissionEnvelope> {
+        let network_id = self.signed_intent.intent.header.network_id;
+        NativeNotarizedTransaction::try_from(self.clone()).and_then(|notarized_transaction| {
+            core_notarized_transaction_submission_envelope(&notarized_transaction)
+                .map_err(Into::into)
+                .map(
+                    |CoreSubmissionEnvelope {
+                         payload,
+                         payload_id,
+                         intent_hash,
+                     }| SubmissionEnvelope {
+                        payload_hex: hex::encode(payload),
+                        payload_id: Arc::new(TransactionHash::new(
+                            &NativeNotarizedTransactionHash(payload_id.hash),
+                            network_id,
+                        )),
+                        intent_hash: Arc::new(TransactionHash::new(
+                            &NativeIntentHash(intent_hash.hash),
+                            network_id,
+                        )),
+                    },
+                )
+        })
+    }
+
+    /// Checks that the transaction was notarized by the expected public key, returning the reasons
+    /// for which it was not. An empty list is returned if it was.
+    pub fn verify_notary(
+        &self,
+        expected_notary_public_key: PublicKey,
+    ) -> Result<Vec<NotaryVerificationFailure>> {
+        let expected_notary_public_key = NativePublicKey::try_from(expected_notary_public_key)?;
+        NativeNotarizedTransaction::try_from(self.clone()).and_then(|notarized_transaction| {
+            core_notarized_transaction_verify_notary(
+                &notarized_transaction,
+                &expected_notary_public_key,
+            )
+            .map_err(Into::into)
+            .map(|failures| failures.into_iter().map(Into::into).collect())
+        })
+    }
+
     pub fn statically_validate(&self, validation_config: Arc<ValidationConfig>) -> Result<()> {
         core_notarized_transaction_statically_validate(
             &self.clone().try_into()?,
@@ -87,6 +139,22 @@ impl NotarizedTransaction {
         )
         .map_err(Into::into)
     }
+
+    /// All of the static validation rules that the notarized transaction violates, unlike
+    /// [`Self::statically_validate`] which stops at the first one.
+    pub fn static_validation_violations(
+        &self,
+        validation_config: Arc<ValidationConfig>,
+    ) -> Result<Vec<StaticValidationViolation>> {
+        let violations = core_validation_validate_notarized_transaction(
+            &NativeNotarizedTransaction::try_from(self.clone())?,
+            &validation_config.as_ref().clone().into(),
+        );
+        Ok(violations
+            .into_iter()
+            .map(StaticValidationViolation::from)
+            .collect())
+    }
 }
 
 impl From<NativeNotarizedTransaction> for NotarizedTransaction {
@@ -119,3 +187,33 @@ impl TryFrom<NotarizedTransaction> for NativeNotarizedTransaction {
         })
     }
 }
+
+#[derive(Clone, Debug, Record)]
+pub struct SubmissionEnvelope {
+    pub payload_hex: String,
+    pub payload_id: Arc<TransactionHash>,
+    pub intent_hash: Arc<TransactionHash>,
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum NotaryVerificationFailure {
+    NotaryPublicKeyMismatch { actual: PublicKey },
+    NotarySignatureCurveMismatch,
+    InvalidNotarySignature,
+}
+
+impl From<CoreNotaryVerificationFailure> for NotaryVerificationFailure {
+    fn from(value: CoreNotaryVerificationFailure) -> Self {
+        match value {
+            CoreNotaryVerificationFailure::NotaryPublicKeyMismatch { actual } => {
+                Self::NotaryPublicKeyMismatch {
+                    actual: actual.into(),
+                }
+            }
+            CoreNotaryVerificationFailure::NotarySignatureCurveMismatch => {
+                Self::NotarySignatureCurveMismatch
+            }
+            CoreNotaryVerificationFailure::InvalidNotarySignature => Self::InvalidNotarySignature,
+        }
+    }
+}
@@ -76,6 +76,13 @@ impl SignedIntent {
         })
     }
 
+    /// A representation of the signed intent that is safe to log, see the logging module of the
+    /// core toolkit.
+    pub fn to_log_string(&self) -> Result<String> {
+        NativeSignedIntent::try_from(self.clone())
+            .map(|signed_intent| signed_intent.to_log_string(self.intent.header.network_id))
+    }
+
     pub fn statically_validate(&self, validation_config: Arc<ValidationConfig>) -> Result<()> {
         core_signed_intent_statically_validate(
             &self.clone().try_into()?,
@@ -83,6 +90,22 @@ impl SignedIntent {
         )
         .map_err(Into::into)
     }
+
+    /// All of the static validation rules that the signed intent violates, unlike
+    /// [`Self::statically_validate`] which stops at the first one.
+    pub fn static_validation_violations(
+        &self,
+        validation_config: Arc<ValidationConfig>,
+    ) -> Result<Vec<StaticValidationViolation>> {
+        let violations = core_validation_validate_signed_intent(
+            &NativeSignedIntent::try_from(self.clone())?,
+            &validation_config.as_ref().clone().into(),
+        );
+        Ok(violations
+            .into_iter()
+            .map(StaticValidationViolation::from)
+            .collect())
+    }
 }
 
 impl From<NativeSignedIntent> for SignedIntent {
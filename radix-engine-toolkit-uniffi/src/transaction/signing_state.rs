@@ -0,0 +1,139 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::RwLock;
+
+use crate::prelude::*;
+
+/// Tracks the signatures collected for an intent, which of its required signers are satisfied, and
+/// whether it can be notarized. The state can be encoded to resume signing in another session.
+#[derive(Debug, Object)]
+pub struct SigningState(RwLock<CoreSigningState>, u8);
+
+#[uniffi::export]
+impl SigningState {
+    #[uniffi::constructor]
+    pub fn new(intent: Arc<Intent>) -> Result<Arc<Self>> {
+        let network_id = intent.header.network_id;
+        let intent = NativeIntent::try_from(intent.as_ref().clone())?;
+        Ok(Arc::new(Self(
+            RwLock::new(CoreSigningState::new(intent)),
+            network_id,
+        )))
+    }
+
+    #[uniffi::constructor]
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Arc<Self>> {
+        let state = CoreSigningState::from_bytes(&bytes)?;
+        let network_id = state.intent().header.network_id;
+        Ok(Arc::new(Self(RwLock::new(state), network_id)))
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        self.read().to_bytes().map_err(Into::into)
+    }
+
+    pub fn set_signer_keys(
+        &self,
+        entity_address: Arc<Address>,
+        keys: Vec<PublicKey>,
+        threshold: u8,
+    ) -> Result<()> {
+        let entity_address = NativeGlobalAddress::try_from(entity_address.as_ref().clone())?;
+        let keys = keys
+            .into_iter()
+            .map(NativePublicKey::try_from)
+            .collect::<Result<Vec<_>>>()?;
+        self.write()
+            .set_signer_keys(entity_address, keys, threshold)
+            .map_err(Into::into)
+    }
+
+    pub fn add_signature(&self, signature: SignatureWithPublicKey) -> Result<Vec<Arc<Address>>> {
+        let signature = NativeSignatureWithPublicKey::try_from(signature)?;
+        self.write()
+            .add_signature(signature)
+            .map(|addresses| {
+                addresses
+                    .into_iter()
+                    .map(|address| Arc::new(Address::from_typed_node_id(address, self.1)))
+                    .collect()
+            })
+            .map_err(Into::into)
+    }
+
+    pub fn signer_statuses(&self) -> Vec<SignerStatus> {
+        self.read()
+            .signer_statuses()
+            .into_iter()
+            .map(|status| SignerStatus::from_native(status, self.1))
+            .collect()
+    }
+
+    pub fn remaining_signers(&self) -> Vec<Arc<Address>> {
+        self.read()
+            .remaining_signers()
+            .into_iter()
+            .map(|address| Arc::new(Address::from_typed_node_id(address, self.1)))
+            .collect()
+    }
+
+    pub fn can_notarize(&self) -> bool {
+        self.read().can_notarize()
+    }
+
+    pub fn signed_intent(&self) -> Arc<SignedIntent> {
+        Arc::new(self.read().signed_intent().clone().into())
+    }
+}
+
+impl SigningState {
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, CoreSigningState> {
+        self.0.read().expect("Signing state lock is poisoned")
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, CoreSigningState> {
+        self.0.write().expect("Signing state lock is poisoned")
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct SignerStatus {
+    pub entity_address: Arc<Address>,
+    pub threshold: u8,
+    pub signed_by: Vec<PublicKey>,
+    pub is_satisfied: bool,
+}
+
+impl SignerStatus {
+    pub fn from_native(
+        CoreSignerStatus {
+            entity_address,
+            threshold,
+            signed_by,
+            is_satisfied,
+        }: CoreSignerStatus,
+        network_id: u8,
+    ) -> Self {
+        Self {
+            entity_address: Arc::new(Address::from_typed_node_id(entity_address, network_id)),
+            threshold,
+            signed_by: signed_by.into_iter().map(Into::into).collect(),
+            is_satisfied,
+        }
+    }
+}
@@ -0,0 +1,254 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+#[derive(Clone, Debug, Record)]
+pub struct TransactionRequest {
+    pub version: u64,
+    pub transaction_manifest: String,
+    pub blobs: Vec<Vec<u8>>,
+    pub message: Option<String>,
+    pub lock_fee_hint: Option<LockFeeHint>,
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct LockFeeHint {
+    pub amount: Arc<Decimal>,
+    pub account: Option<Arc<Address>>,
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct TransactionRequestLimits {
+    pub max_manifest_length: u64,
+    pub max_blobs_size: u64,
+    pub max_message_length: u64,
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum TransactionRequestViolation {
+    UnsupportedVersion {
+        version: u64,
+        supported: u64,
+    },
+    ManifestTooLong {
+        length: u64,
+        max: u64,
+    },
+    BlobsTooLarge {
+        size: u64,
+        max: u64,
+    },
+    MessageTooLong {
+        length: u64,
+        max: u64,
+    },
+    LockFeeHintNotPositive {
+        amount: Arc<Decimal>,
+    },
+    LockFeeHintAccountNotAnAccount {
+        address: Arc<Address>,
+    },
+    InvalidManifest {
+        diagnostic: ManifestCompileDiagnostic,
+    },
+}
+
+#[uniffi::export]
+pub fn transaction_request_limits_default() -> TransactionRequestLimits {
+    CoreTransactionRequestLimits::default().into()
+}
+
+#[uniffi::export]
+pub fn transaction_request_normalize(
+    request: TransactionRequest,
+    network_id: u8,
+) -> Result<TransactionRequest> {
+    let native = request.to_native()?;
+    Ok(TransactionRequest::from_native(
+        core_transaction_request_normalize(native),
+        network_id,
+    ))
+}
+
+/// Validates the request against the limits, or the limits of the transaction validator if none
+/// are provided, and returns all of the violations found.
+#[uniffi::export]
+pub fn transaction_request_validate(
+    request: TransactionRequest,
+    network_id: u8,
+    limits: Option<TransactionRequestLimits>,
+) -> Result<Vec<TransactionRequestViolation>> {
+    let native = request.to_native()?;
+    let limits = limits.map(Into::into).unwrap_or_default();
+    Ok(
+        core_transaction_request_validate(&native, network_id, &limits)
+            .into_iter()
+            .map(|violation| TransactionRequestViolation::from_native(violation, network_id))
+            .collect(),
+    )
+}
+
+#[uniffi::export]
+pub fn transaction_request_to_manifest(
+    request: TransactionRequest,
+    network_id: u8,
+) -> Result<Arc<TransactionManifest>> {
+    let native = request.to_native()?;
+    let manifest =
+        core_transaction_request_to_manifest(&native, network_id).map_err(|diagnostic| {
+            RadixEngineToolkitError::CompileError {
+                error: diagnostic.message,
+            }
+        })?;
+    Ok(Arc::new(TransactionManifest::from_native(
+        &manifest, network_id,
+    )))
+}
+
+#[uniffi::export]
+pub fn transaction_request_from_manifest(
+    manifest: Arc<TransactionManifest>,
+    message: Option<String>,
+    lock_fee_hint: Option<LockFeeHint>,
+) -> Result<TransactionRequest> {
+    let network_id = manifest.instructions.1;
+    let native_manifest = manifest.to_native();
+    let lock_fee_hint = lock_fee_hint
+        .map(|lock_fee_hint| lock_fee_hint.to_native())
+        .transpose()?;
+    let request = core_transaction_request_from_manifest(
+        &native_manifest,
+        network_id,
+        message,
+        lock_fee_hint,
+    )?;
+    Ok(TransactionRequest::from_native(request, network_id))
+}
+
+impl TransactionRequest {
+    pub fn from_native(native: CoreTransactionRequest, network_id: u8) -> Self {
+        Self {
+            version: native.version,
+            transaction_manifest: native.transaction_manifest,
+            blobs: native.blobs,
+            message: native.message,
+            lock_fee_hint: native
+                .lock_fee_hint
+                .map(|lock_fee_hint| LockFeeHint::from_native(lock_fee_hint, network_id)),
+        }
+    }
+
+    pub fn to_native(&self) -> Result<CoreTransactionRequest> {
+        Ok(CoreTransactionRequest {
+            version: self.version,
+            transaction_manifest: self.transaction_manifest.clone(),
+            blobs: self.blobs.clone(),
+            message: self.message.clone(),
+            lock_fee_hint: self
+                .lock_fee_hint
+                .as_ref()
+                .map(LockFeeHint::to_native)
+                .transpose()?,
+        })
+    }
+}
+
+impl LockFeeHint {
+    pub fn from_native(native: CoreLockFeeHint, network_id: u8) -> Self {
+        Self {
+            amount: Arc::new(Decimal(native.amount)),
+            account: native
+                .account
+                .map(|account| Arc::new(Address::from_typed_node_id(account, network_id))),
+        }
+    }
+
+    pub fn to_native(&self) -> Result<CoreLockFeeHint> {
+        Ok(CoreLockFeeHint {
+            amount: self.amount.0,
+            account: self
+                .account
+                .as_ref()
+                .map(|account| (**account).try_into())
+                .transpose()?,
+        })
+    }
+}
+
+impl TransactionRequestViolation {
+    pub fn from_native(native: CoreTransactionRequestViolation, network_id: u8) -> Self {
+        match native {
+            CoreTransactionRequestViolation::UnsupportedVersion { version, supported } => {
+                Self::UnsupportedVersion { version, supported }
+            }
+            CoreTransactionRequestViolation::ManifestTooLong { length, max } => {
+                Self::ManifestTooLong {
+                    length: length as u64,
+                    max: max as u64,
+                }
+            }
+            CoreTransactionRequestViolation::BlobsTooLarge { size, max } => Self::BlobsTooLarge {
+                size: size as u64,
+                max: max as u64,
+            },
+            CoreTransactionRequestViolation::MessageTooLong { length, max } => {
+                Self::MessageTooLong {
+                    length: length as u64,
+                    max: max as u64,
+                }
+            }
+            CoreTransactionRequestViolation::LockFeeHintNotPositive { amount } => {
+                Self::LockFeeHintNotPositive {
+                    amount: Arc::new(Decimal(amount)),
+                }
+            }
+            CoreTransactionRequestViolation::LockFeeHintAccountNotAnAccount { address } => {
+                Self::LockFeeHintAccountNotAnAccount {
+                    address: Arc::new(Address::from_typed_node_id(address, network_id)),
+                }
+            }
+            CoreTransactionRequestViolation::InvalidManifest(diagnostic) => Self::InvalidManifest {
+                diagnostic: diagnostic.into(),
+            },
+        }
+    }
+}
+
+//============
+// From Impls
+//============
+
+impl From<CoreTransactionRequestLimits> for TransactionRequestLimits {
+    fn from(value: CoreTransactionRequestLimits) -> Self {
+        Self {
+            max_manifest_length: value.max_manifest_length as u64,
+            max_blobs_size: value.max_blobs_size as u64,
+            max_message_length: value.max_message_length as u64,
+        }
+    }
+}
+
+impl From<TransactionRequestLimits> for CoreTransactionRequestLimits {
+    fn from(value: TransactionRequestLimits) -> Self {
+        Self {
+            max_manifest_length: value.max_manifest_length as usize,
+            max_blobs_size: value.max_blobs_size as usize,
+            max_message_length: value.max_message_length as usize,
+        }
+    }
+}
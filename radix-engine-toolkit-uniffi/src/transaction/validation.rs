@@ -127,6 +127,65 @@ impl MessageValidationConfig {
     }
 }
 
+#[derive(Clone, Debug, Enum)]
+pub enum StaticValidationViolation {
+    NetworkIdMismatch {
+        expected: u8,
+        actual: u8,
+    },
+    EmptyEpochRange {
+        start_epoch_inclusive: u64,
+        end_epoch_exclusive: u64,
+    },
+    EpochRangeTooLarge {
+        range: u64,
+        max: u64,
+    },
+    TipPercentageOutOfRange {
+        tip_percentage: u16,
+        min: u16,
+        max: u16,
+    },
+    TooManyIntentSignatures {
+        count: u64,
+        max: u64,
+    },
+    MimeTypeTooLong {
+        length: u64,
+        max: u64,
+    },
+    PlaintextMessageTooLong {
+        length: u64,
+        max: u64,
+    },
+    EncryptedMessageTooLong {
+        length: u64,
+        max: u64,
+    },
+    NoDecryptors,
+    NoDecryptorsForCurve {
+        curve_type: CurveType,
+    },
+    DecryptorsCurveMismatch {
+        expected: CurveType,
+        actual: CurveType,
+    },
+    TooManyDecryptors {
+        count: u64,
+        max: u64,
+    },
+    ManifestTooDeep {
+        max_depth: u64,
+    },
+    InvalidIdAllocation {
+        error: String,
+    },
+    NotarizedPayloadTooLarge {
+        size: u64,
+        max: u64,
+    },
+}
+
 //============
 // From Impls
 //============
@@ -178,3 +237,88 @@ impl From<NativeMessageValidationConfig> for MessageValidationConfig {
         }
     }
 }
+
+impl From<CoreStaticValidationViolation> for StaticValidationViolation {
+    fn from(value: CoreStaticValidationViolation) -> Self {
+        match value {
+            CoreStaticValidationViolation::NetworkIdMismatch { expected, actual } => {
+                Self::NetworkIdMismatch { expected, actual }
+            }
+            CoreStaticValidationViolation::EmptyEpochRange {
+                start_epoch_inclusive,
+                end_epoch_exclusive,
+            } => Self::EmptyEpochRange {
+                start_epoch_inclusive: start_epoch_inclusive.number(),
+                end_epoch_exclusive: end_epoch_exclusive.number(),
+            },
+            CoreStaticValidationViolation::EpochRangeTooLarge { range, max } => {
+                Self::EpochRangeTooLarge { range, max }
+            }
+            CoreStaticValidationViolation::TipPercentageOutOfRange {
+                tip_percentage,
+                min,
+                max,
+            } => Self::TipPercentageOutOfRange {
+                tip_percentage,
+                min,
+                max,
+            },
+            CoreStaticValidationViolation::TooManyIntentSignatures { count, max } => {
+                Self::TooManyIntentSignatures {
+                    count: count as u64,
+                    max: max as u64,
+                }
+            }
+            CoreStaticValidationViolation::MimeTypeTooLong { length, max } => {
+                Self::MimeTypeTooLong {
+                    length: length as u64,
+                    max: max as u64,
+                }
+            }
+            CoreStaticValidationViolation::PlaintextMessageTooLong { length, max } => {
+                Self::PlaintextMessageTooLong {
+                    length: length as u64,
+                    max: max as u64,
+                }
+            }
+            CoreStaticValidationViolation::EncryptedMessageTooLong { length, max } => {
+                Self::EncryptedMessageTooLong {
+                    length: length as u64,
+                    max: max as u64,
+                }
+            }
+            CoreStaticValidationViolation::NoDecryptors => Self::NoDecryptors,
+            CoreStaticValidationViolation::NoDecryptorsForCurve(curve_type) => {
+                Self::NoDecryptorsForCurve {
+                    curve_type: curve_type.into(),
+                }
+            }
+            CoreStaticValidationViolation::DecryptorsCurveMismatch { expected, actual } => {
+                Self::DecryptorsCurveMismatch {
+                    expected: expected.into(),
+                    actual: actual.into(),
+                }
+            }
+            CoreStaticValidationViolation::TooManyDecryptors { count, max } => {
+                Self::TooManyDecryptors {
+                    count: count as u64,
+                    max: max as u64,
+                }
+            }
+            CoreStaticValidationViolation::ManifestTooDeep { max_depth } => Self::ManifestTooDeep {
+                max_depth: max_depth as u64,
+            },
+            CoreStaticValidationViolation::InvalidIdAllocation(error) => {
+                Self::InvalidIdAllocation {
+                    error: format!("{error:?}"),
+                }
+            }
+            CoreStaticValidationViolation::NotarizedPayloadTooLarge { size, max } => {
+                Self::NotarizedPayloadTooLarge {
+                    size: size as u64,
+                    max: max as u64,
+                }
+            }
+        }
+    }
+}
@@ -22,11 +22,121 @@ pub fn known_addresses(network_id: u8) -> KnownAddresses {
     KnownAddresses::new_from_network(network_id)
 }
 
+#[uniffi::export]
+pub fn known_addresses_for_networks(network_ids: Vec<u8>) -> HashMap<u8, KnownAddresses> {
+    network_ids
+        .into_iter()
+        .map(|network_id| (network_id, KnownAddresses::new_from_network(network_id)))
+        .collect()
+}
+
 #[uniffi::export]
 pub fn hash(data: Vec<u8>) -> Arc<Hash> {
     Hash::from_unhashed_bytes(data)
 }
 
+#[uniffi::export]
+pub fn blake2b_256_hash(data: Vec<u8>) -> Vec<u8> {
+    core_hashing_blake2b_256(&data).to_vec()
+}
+
+#[uniffi::export]
+pub fn sha256_hash(data: Vec<u8>) -> Vec<u8> {
+    core_hashing_sha256(&data).to_vec()
+}
+
+#[uniffi::export]
+pub fn sha256_twice_hash(data: Vec<u8>) -> Vec<u8> {
+    core_hashing_sha256_twice(&data).to_vec()
+}
+
+#[uniffi::export]
+pub fn keccak256_hash(data: Vec<u8>) -> Vec<u8> {
+    core_hashing_keccak256(&data).to_vec()
+}
+
+#[uniffi::export]
+pub fn personal_message_hash(message: Vec<u8>) -> Arc<Hash> {
+    Arc::new(Hash(core_personal_message_hash(&message)))
+}
+
+#[uniffi::export]
+pub fn personal_message_verify(
+    message: Vec<u8>,
+    signature: SignatureWithPublicKey,
+    network_id: u8,
+) -> Result<Option<Arc<Address>>> {
+    let signature = NativeSignatureWithPublicKey::try_from(signature)?;
+    Ok(core_personal_message_verify(&message, &signature)
+        .map(|address| Arc::new(Address::from_typed_node_id(address, network_id))))
+}
+
+#[uniffi::export]
+pub fn register_network_definition(
+    network_definition: NetworkDefinition,
+) -> Result<Option<NetworkDefinition>> {
+    core_register_network_definition(network_definition.into())
+        .map(|previous| previous.map(Into::into))
+        .map_err(Into::into)
+}
+
+#[uniffi::export]
+pub fn deregister_network_definition(network_id: u8) -> Option<NetworkDefinition> {
+    core_deregister_network_definition(network_id).map(Into::into)
+}
+
+#[uniffi::export]
+pub fn network_definitions() -> Vec<NetworkDefinition> {
+    core_network_definitions()
+        .into_iter()
+        .map(Into::into)
+        .collect()
+}
+
+#[uniffi::export]
+pub fn network_hrps(network_id: u8) -> Vec<EntityHrp> {
+    core_hrp_table(&core_network_definition_from_network_id(network_id))
+        .into_iter()
+        .map(|(entity_type, hrp)| EntityHrp {
+            entity_type: entity_type.into(),
+            hrp,
+        })
+        .collect()
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct EntityHrp {
+    pub entity_type: EntityType,
+    pub hrp: String,
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct NetworkDefinition {
+    pub id: u8,
+    pub logical_name: String,
+    pub hrp_suffix: String,
+}
+
+impl From<NativeNetworkDefinition> for NetworkDefinition {
+    fn from(value: NativeNetworkDefinition) -> Self {
+        Self {
+            id: value.id,
+            logical_name: value.logical_name,
+            hrp_suffix: value.hrp_suffix,
+        }
+    }
+}
+
+impl From<NetworkDefinition> for NativeNetworkDefinition {
+    fn from(value: NetworkDefinition) -> Self {
+        Self {
+            id: value.id,
+            logical_name: value.logical_name,
+            hrp_suffix: value.hrp_suffix,
+        }
+    }
+}
+
 macro_rules! define_known_addresses {
     (
         $(
@@ -103,11 +213,13 @@ define_known_addresses! {
         role_assignment_module_package,
         genesis_helper_package,
         faucet_package,
+        transaction_tracker_package,
     ],
     component_addresses => [
         consensus_manager,
         genesis_helper,
         faucet,
+        transaction_tracker,
     ]
 }
 
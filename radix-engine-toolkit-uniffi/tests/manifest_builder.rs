@@ -0,0 +1,220 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_uniffi::prelude::*;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[test]
+fn manifest_builder_can_be_rolled_back_to_its_own_checkpoint() {
+    // Arrange
+    let builder = ManifestBuilder::new().drop_all_proofs().unwrap();
+    let checkpoint = builder.clone().checkpoint();
+    let builder = builder.drop_all_proofs().unwrap();
+
+    // Act
+    let rolled_back = builder.rollback(checkpoint);
+
+    // Assert
+    let manifest = rolled_back.unwrap().build(0x01);
+    assert_eq!(manifest.instructions().instructions_list().len(), 1);
+}
+
+#[test]
+fn manifest_builder_rejects_checkpoint_of_another_builder() {
+    // Arrange
+    let builder = ManifestBuilder::new().drop_all_proofs().unwrap();
+    let checkpoint = ManifestBuilder::new()
+        .drop_auth_zone_proofs()
+        .unwrap()
+        .checkpoint();
+
+    // Act
+    let rolled_back = builder.rollback(checkpoint);
+
+    // Assert
+    assert!(matches!(
+        rolled_back,
+        Err(RadixEngineToolkitError::ManifestBuilderCheckpointOfAnotherBuilder)
+    ));
+}
+
+#[test]
+fn manifest_builder_rejects_checkpoint_of_a_sibling_branch() {
+    // Arrange
+    let builder = ManifestBuilder::new().drop_all_proofs().unwrap();
+    let branch1 = builder.clone().drop_auth_zone_proofs().unwrap();
+    let checkpoint = branch1.clone().checkpoint();
+    let branch2 = builder
+        .drop_auth_zone_signature_proofs()
+        .unwrap()
+        .drop_all_proofs()
+        .unwrap();
+
+    // Act
+    let rolled_back = branch2.rollback(checkpoint);
+
+    // Assert
+    assert!(matches!(
+        rolled_back,
+        Err(RadixEngineToolkitError::ManifestBuilderCheckpointOfAnotherBuilder)
+    ));
+}
+
+#[test]
+fn manifest_builder_encodes_metadata_init_in_the_order_of_its_keys() {
+    // Arrange
+    let metadata = MetadataModuleConfig {
+        init: ["symbol", "name", "description"]
+            .into_iter()
+            .map(|key| {
+                (
+                    key.to_owned(),
+                    MetadataInitEntry {
+                        value: Some(MetadataValue::StringValue {
+                            value: format!("{key} value"),
+                        }),
+                        lock: false,
+                    },
+                )
+            })
+            .collect(),
+        roles: HashMap::new(),
+    };
+    let resource_roles = FungibleResourceRoles {
+        mint_roles: None,
+        burn_roles: None,
+        freeze_roles: None,
+        recall_roles: None,
+        withdraw_roles: None,
+        deposit_roles: None,
+    };
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .create_fungible_resource_manager(
+            OwnerRole::None,
+            true,
+            18,
+            None,
+            resource_roles,
+            metadata,
+            None,
+        )
+        .unwrap()
+        .build(0xf2);
+
+    // Assert
+    let manifest_string = manifest.instructions().as_str().unwrap();
+    let position = |key: &str| manifest_string.find(&format!("\"{key} value\"")).unwrap();
+    assert!(position("description") < position("name"));
+    assert!(position("name") < position("symbol"));
+}
+
+#[test]
+fn account_deposit_with_mode_deposit_calls_the_deposit_method() {
+    // Arrange
+    let mode = AccountDepositMode::Deposit;
+
+    // Act
+    let method_name = deposit_with_mode_method_name(mode);
+
+    // Assert
+    assert_eq!(method_name, "deposit");
+}
+
+#[test]
+fn account_deposit_with_mode_try_deposit_or_abort_calls_the_try_deposit_or_abort_method() {
+    // Arrange
+    let mode = AccountDepositMode::TryDepositOrAbort;
+
+    // Act
+    let method_name = deposit_with_mode_method_name(mode);
+
+    // Assert
+    assert_eq!(method_name, "try_deposit_or_abort");
+}
+
+#[test]
+fn account_deposit_with_mode_try_deposit_or_refund_calls_the_try_deposit_or_refund_method() {
+    // Arrange
+    let mode = AccountDepositMode::TryDepositOrRefund;
+
+    // Act
+    let method_name = deposit_with_mode_method_name(mode);
+
+    // Assert
+    assert_eq!(method_name, "try_deposit_or_refund");
+}
+
+#[test]
+fn account_deposit_batch_with_mode_calls_the_batch_method_of_each_mode() {
+    // Arrange
+    let modes = [
+        (AccountDepositMode::Deposit, "deposit_batch"),
+        (
+            AccountDepositMode::TryDepositOrAbort,
+            "try_deposit_batch_or_abort",
+        ),
+        (
+            AccountDepositMode::TryDepositOrRefund,
+            "try_deposit_batch_or_refund",
+        ),
+    ];
+
+    for (mode, expected_method_name) in modes {
+        // Act
+        let manifest = ManifestBuilder::new()
+            .account_deposit_batch_with_mode(account_address(), mode, None)
+            .unwrap()
+            .build(0xf2);
+
+        // Assert
+        assert_eq!(
+            last_method_name(manifest.instructions().instructions_list()),
+            expected_method_name
+        );
+    }
+}
+
+fn deposit_with_mode_method_name(mode: AccountDepositMode) -> String {
+    let bucket = ManifestBuilderBucket {
+        name: "bucket".to_owned(),
+    };
+    let manifest = ManifestBuilder::new()
+        .take_all_from_worktop(known_addresses(0xf2).resource_addresses.xrd, bucket.clone())
+        .unwrap()
+        .account_deposit_with_mode(account_address(), mode, None, bucket)
+        .unwrap()
+        .build(0xf2);
+    last_method_name(manifest.instructions().instructions_list())
+}
+
+fn last_method_name(instructions: Vec<Instruction>) -> String {
+    match instructions.last() {
+        Some(Instruction::CallMethod { method_name, .. }) => method_name.clone(),
+        instruction => panic!("Expected a call method instruction, found: {instruction:?}"),
+    }
+}
+
+fn account_address() -> Arc<Address> {
+    Address::virtual_account_address_from_public_key(
+        PublicKey::Ed25519 { value: vec![0; 32] },
+        0xf2,
+    )
+    .unwrap()
+}
@@ -0,0 +1,461 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Long-lived contexts that can be created by clients of the toolkit to avoid the repeated setup
+//! of network definitions, Bech32 encoders and decoders, and decoded schemas on every invocation.
+//!
+//! A context is created through the `context_create` function and is referred to by its id. Each
+//! of the exported functions has a `_with_options` variant which takes a context id, or zero for no
+//! context; the invocation is then run with the context set as the current context of the thread,
+//! and the helpers in this module make use of the caches of the current context. When no context
+//! is set the helpers behave exactly as they would without a context.
+//!
+//! Independent of the contexts, a process-wide bounded cache memoizes the Bech32 encoding of node
+//! ids so that analysis of transactions with many repeated addresses does not re-encode them.
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+
+use radix_engine_common::prelude::*;
+use radix_engine_interface::blueprints::package::PackageDefinition;
+use radix_engine_toolkit_core::functions::schema::{decode_package_definition, SchemaDecodeError};
+use radix_engine_toolkit_core::utils::network_definition_from_network_id;
+use scrypto::prelude::{hash, Hash};
+
+static CONTEXTS: Mutex<BTreeMap<u64, Arc<ToolkitContext>>> = Mutex::new(BTreeMap::new());
+static NEXT_CONTEXT_ID: AtomicU64 = AtomicU64::new(1);
+static ADDRESS_CACHE: Mutex<AddressCache> = Mutex::new(AddressCache::new());
+
+thread_local! {
+    static CURRENT_CONTEXT: RefCell<Option<Arc<ToolkitContext>>> = RefCell::new(None);
+}
+
+#[derive(Default)]
+pub struct ToolkitContext {
+    network_definitions: RwLock<HashMap<u8, Arc<NetworkDefinition>>>,
+    bech32_encoders: RwLock<HashMap<u8, Arc<AddressBech32Encoder>>>,
+    bech32_decoders: RwLock<HashMap<u8, Arc<AddressBech32Decoder>>>,
+    package_definitions: RwLock<HashMap<Hash, Arc<PackageDefinition>>>,
+}
+
+impl ToolkitContext {
+    pub fn network_definition(&self, network_id: u8) -> Arc<NetworkDefinition> {
+        get_or_insert(&self.network_definitions, network_id, || {
+            network_definition_from_network_id(network_id)
+        })
+    }
+
+    pub fn bech32_encoder(&self, network_id: u8) -> Arc<AddressBech32Encoder> {
+        get_or_insert(&self.bech32_encoders, network_id, || {
+            AddressBech32Encoder::new(&self.network_definition(network_id))
+        })
+    }
+
+    pub fn bech32_decoder(&self, network_id: u8) -> Arc<AddressBech32Decoder> {
+        get_or_insert(&self.bech32_decoders, network_id, || {
+            AddressBech32Decoder::new(&self.network_definition(network_id))
+        })
+    }
+
+    pub fn package_definition(
+        &self,
+        payload: &[u8],
+    ) -> Result<Arc<PackageDefinition>, SchemaDecodeError> {
+        let key = hash(payload);
+        if let Some(package_definition) = self
+            .package_definitions
+            .read()
+            .expect("Lock poisoned")
+            .get(&key)
+        {
+            return Ok(package_definition.clone());
+        }
+
+        let package_definition = Arc::new(decode_package_definition(payload)?);
+        self.package_definitions
+            .write()
+            .expect("Lock poisoned")
+            .insert(key, package_definition.clone());
+        Ok(package_definition)
+    }
+}
+
+impl ToolkitContext {
+    pub fn cache_statistics(&self) -> CacheStatistics {
+        CacheStatistics {
+            contexts: 1,
+            network_definitions: self
+                .network_definitions
+                .read()
+                .expect("Lock poisoned")
+                .len(),
+            bech32_encoders: self.bech32_encoders.read().expect("Lock poisoned").len(),
+            bech32_decoders: self.bech32_decoders.read().expect("Lock poisoned").len(),
+            package_definitions: self
+                .package_definitions
+                .read()
+                .expect("Lock poisoned")
+                .len(),
+            encoded_addresses: 0,
+        }
+    }
+
+    /// Clears all of the caches of the context returning the number of evicted entries.
+    pub fn clear_caches(&self) -> usize {
+        let mut evicted = 0;
+        evicted += clear(&self.network_definitions);
+        evicted += clear(&self.bech32_encoders);
+        evicted += clear(&self.bech32_decoders);
+        evicted += clear(&self.package_definitions);
+        evicted
+    }
+
+    /// Evicts the cached network definition, encoder, and decoder of a network. Used when the
+    /// definition of the network changes.
+    pub fn evict_network(&self, network_id: u8) {
+        evict(&self.network_definitions, network_id);
+        evict(&self.bech32_encoders, network_id);
+        evict(&self.bech32_decoders, network_id);
+    }
+}
+
+/// The number of entries in the caches of the contexts.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStatistics {
+    pub contexts: usize,
+    pub network_definitions: usize,
+    pub bech32_encoders: usize,
+    pub bech32_decoders: usize,
+    pub package_definitions: usize,
+    pub encoded_addresses: usize,
+}
+
+impl std::ops::Add for CacheStatistics {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            contexts: self.contexts + rhs.contexts,
+            network_definitions: self.network_definitions + rhs.network_definitions,
+            bech32_encoders: self.bech32_encoders + rhs.bech32_encoders,
+            bech32_decoders: self.bech32_decoders + rhs.bech32_decoders,
+            package_definitions: self.package_definitions + rhs.package_definitions,
+            encoded_addresses: self.encoded_addresses + rhs.encoded_addresses,
+        }
+    }
+}
+
+fn clear<K, V>(cache: &RwLock<HashMap<K, V>>) -> usize {
+    let mut cache = cache.write().expect("Lock poisoned");
+    let length = cache.len();
+    cache.clear();
+    length
+}
+
+fn evict<T>(cache: &RwLock<HashMap<u8, Arc<T>>>, network_id: u8) {
+    cache.write().expect("Lock poisoned").remove(&network_id);
+}
+
+fn get_or_insert<T>(
+    cache: &RwLock<HashMap<u8, Arc<T>>>,
+    network_id: u8,
+    create: impl FnOnce() -> T,
+) -> Arc<T> {
+    if let Some(item) = cache.read().expect("Lock poisoned").get(&network_id) {
+        return item.clone();
+    }
+    let item = Arc::new(create());
+    cache
+        .write()
+        .expect("Lock poisoned")
+        .insert(network_id, item.clone());
+    item
+}
+
+/// Creates a new context and returns its id.
+pub fn create_context() -> u64 {
+    let context_id = NEXT_CONTEXT_ID.fetch_add(1, Ordering::SeqCst);
+    CONTEXTS
+        .lock()
+        .expect("Lock poisoned")
+        .insert(context_id, Default::default());
+    context_id
+}
+
+/// Destroys the context with the given id. Returns `false` if no such context exists.
+pub fn destroy_context(context_id: u64) -> bool {
+    CONTEXTS
+        .lock()
+        .expect("Lock poisoned")
+        .remove(&context_id)
+        .is_some()
+}
+
+/// Returns the aggregated statistics of the caches of all of the contexts and of the address
+/// cache.
+pub fn cache_statistics() -> CacheStatistics {
+    let statistics = CONTEXTS
+        .lock()
+        .expect("Lock poisoned")
+        .values()
+        .map(|context| context.cache_statistics())
+        .fold(CacheStatistics::default(), |acc, item| acc + item);
+    CacheStatistics {
+        encoded_addresses: ADDRESS_CACHE.lock().expect("Lock poisoned").len(),
+        ..statistics
+    }
+}
+
+/// Clears the caches of all of the contexts without destroying them, along with the address
+/// cache. Returns the number of evicted entries.
+pub fn free_caches() -> usize {
+    let evicted = CONTEXTS
+        .lock()
+        .expect("Lock poisoned")
+        .values()
+        .map(|context| context.clear_caches())
+        .sum::<usize>();
+    evicted + ADDRESS_CACHE.lock().expect("Lock poisoned").clear()
+}
+
+/// Evicts the cached entries of a network from all of the contexts and from the address cache.
+pub fn evict_network_caches(network_id: u8) {
+    CONTEXTS
+        .lock()
+        .expect("Lock poisoned")
+        .values()
+        .for_each(|context| context.evict_network(network_id));
+    ADDRESS_CACHE
+        .lock()
+        .expect("Lock poisoned")
+        .evict_network(network_id);
+}
+
+pub fn get_context(context_id: u64) -> Option<Arc<ToolkitContext>> {
+    CONTEXTS
+        .lock()
+        .expect("Lock poisoned")
+        .get(&context_id)
+        .cloned()
+}
+
+/// Runs the callback with the given context set as the current context of the thread. The previous
+/// context is restored once the callback returns, or unwinds.
+pub fn with_context<R>(context: Arc<ToolkitContext>, callback: impl FnOnce() -> R) -> R {
+    let _guard = CurrentContextGuard {
+        previous: CURRENT_CONTEXT.with(|current| current.replace(Some(context))),
+    };
+    callback()
+}
+
+/// Restores the previous context of the thread when dropped.
+struct CurrentContextGuard {
+    previous: Option<Arc<ToolkitContext>>,
+}
+
+impl Drop for CurrentContextGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT_CONTEXT.with(|current| current.replace(previous));
+    }
+}
+
+fn current_context() -> Option<Arc<ToolkitContext>> {
+    CURRENT_CONTEXT.with(|current| current.borrow().clone())
+}
+
+pub fn network_definition(network_id: u8) -> Arc<NetworkDefinition> {
+    match current_context() {
+        Some(context) => context.network_definition(network_id),
+        None => Arc::new(network_definition_from_network_id(network_id)),
+    }
+}
+
+pub fn bech32_encoder(network_id: u8) -> Arc<AddressBech32Encoder> {
+    match current_context() {
+        Some(context) => context.bech32_encoder(network_id),
+        None => Arc::new(AddressBech32Encoder::new(
+            &network_definition_from_network_id(network_id),
+        )),
+    }
+}
+
+pub fn bech32_decoder(network_id: u8) -> Arc<AddressBech32Decoder> {
+    match current_context() {
+        Some(context) => context.bech32_decoder(network_id),
+        None => Arc::new(AddressBech32Decoder::new(
+            &network_definition_from_network_id(network_id),
+        )),
+    }
+}
+
+pub fn package_definition(payload: &[u8]) -> Result<Arc<PackageDefinition>, SchemaDecodeError> {
+    match current_context() {
+        Some(context) => context.package_definition(payload),
+        None => decode_package_definition(payload).map(Arc::new),
+    }
+}
+
+/// Encodes the node id as a Bech32m address of the given network, making use of the address cache
+/// when it is enabled. The cache is locked once per call, with the address encoded under the lock
+/// on a miss.
+pub fn encode_address(
+    node_id: &NodeId,
+    network_id: u8,
+) -> Result<String, AddressBech32EncodeError> {
+    ADDRESS_CACHE
+        .lock()
+        .expect("Lock poisoned")
+        .get_or_encode(node_id, network_id, || {
+            bech32_encoder(network_id).encode(&node_id.0)
+        })
+}
+
+/// Enables or disables the address cache and sets the maximum number of addresses it holds. The
+/// least recently used addresses are evicted when the cache shrinks or overflows, and the cache
+/// is emptied when it is disabled. Returns the number of evicted entries.
+pub fn configure_address_cache(enabled: bool, capacity: usize) -> usize {
+    ADDRESS_CACHE
+        .lock()
+        .expect("Lock poisoned")
+        .configure(enabled, capacity)
+}
+
+/// A least-recently-used cache of the Bech32m encoding of node ids keyed by the node id and the
+/// network id. Recency is tracked through a monotonic tick so that both lookups and evictions are
+/// logarithmic in the size of the cache.
+pub struct AddressCache {
+    enabled: bool,
+    capacity: usize,
+    tick: u64,
+    entries: BTreeMap<(NodeId, u8), (String, u64)>,
+    recency: BTreeMap<u64, (NodeId, u8)>,
+}
+
+impl Default for AddressCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AddressCache {
+    pub const DEFAULT_CAPACITY: usize = 4096;
+
+    pub const fn new() -> Self {
+        Self {
+            enabled: true,
+            capacity: Self::DEFAULT_CAPACITY,
+            tick: 0,
+            entries: BTreeMap::new(),
+            recency: BTreeMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the cached address of the node id on the network, or encodes it through the
+    /// callback and caches it when it is not cached.
+    pub fn get_or_encode<E>(
+        &mut self,
+        node_id: &NodeId,
+        network_id: u8,
+        encode: impl FnOnce() -> Result<String, E>,
+    ) -> Result<String, E> {
+        if let Some(address) = self.get(node_id, network_id) {
+            return Ok(address);
+        }
+
+        let address = encode()?;
+        self.insert(*node_id, network_id, address.clone());
+        Ok(address)
+    }
+
+    /// Enables or disables the cache and sets its capacity, returning the number of evicted
+    /// entries.
+    pub fn configure(&mut self, enabled: bool, capacity: usize) -> usize {
+        self.enabled = enabled;
+        self.capacity = capacity;
+        if enabled {
+            self.shrink_to_capacity()
+        } else {
+            self.clear()
+        }
+    }
+
+    pub fn clear(&mut self) -> usize {
+        let length = self.entries.len();
+        self.entries.clear();
+        self.recency.clear();
+        length
+    }
+
+    pub fn evict_network(&mut self, network_id: u8) {
+        self.entries.retain(|(_, id), _| *id != network_id);
+        self.recency.retain(|_, (_, id)| *id != network_id);
+    }
+
+    fn get(&mut self, node_id: &NodeId, network_id: u8) -> Option<String> {
+        if !self.enabled {
+            return None;
+        }
+
+        let tick = self.next_tick();
+        let (address, last_used) = self.entries.get_mut(&(*node_id, network_id))?;
+        self.recency.remove(last_used);
+        self.recency.insert(tick, (*node_id, network_id));
+        *last_used = tick;
+        Some(address.clone())
+    }
+
+    fn insert(&mut self, node_id: NodeId, network_id: u8, address: String) {
+        if !self.enabled || self.capacity == 0 {
+            return;
+        }
+
+        let tick = self.next_tick();
+        if let Some((_, last_used)) = self.entries.insert((node_id, network_id), (address, tick)) {
+            self.recency.remove(&last_used);
+        }
+        self.recency.insert(tick, (node_id, network_id));
+        self.shrink_to_capacity();
+    }
+
+    fn shrink_to_capacity(&mut self) -> usize {
+        let mut evicted = 0;
+        while self.entries.len() > self.capacity {
+            let Some((_, key)) = self.recency.pop_first() else {
+                break;
+            };
+            self.entries.remove(&key);
+            evicted += 1;
+        }
+        evicted
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+}
@@ -15,12 +15,26 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use radix_engine_toolkit_core::functions::amount::AmountFormatError;
+use radix_engine_toolkit_core::functions::amount::AmountParseError;
+use radix_engine_toolkit_core::functions::events::EventFilterError;
+use radix_engine_toolkit_core::functions::manifest::CallArgumentsEncodeError;
+use radix_engine_toolkit_core::functions::manifest::ManifestIncludeError;
+use radix_engine_toolkit_core::functions::manifest::ManifestModificationError;
 use radix_engine_toolkit_core::functions::manifest_sbor::ManifestSborError;
+use radix_engine_toolkit_core::functions::native_state::NativeStateDecodeError;
+use radix_engine_toolkit_core::functions::olympia::OlympiaTransactionDecodeError;
+use radix_engine_toolkit_core::functions::public_key::PublicKeyError;
+use radix_engine_toolkit_core::functions::sbor::SborDecodeUnknownError;
+use radix_engine_toolkit_core::functions::schema::SchemaDecodeError;
 use radix_engine_toolkit_core::functions::scrypto_sbor::ScryptoSborError;
+use radix_engine_toolkit_core::functions::signed_intent::SignatureNormalizationError;
+use radix_engine_toolkit_core::utils::NetworkRegistrationError;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    models::legacy::LegacyConversionError,
     models::transaction::instructions::SerializableInstructionsError, utils::debug_string,
 };
 
@@ -38,7 +52,10 @@ pub enum InvocationInterpretationError {
     DeserializationError(String),
     Utf8Error(String),
     FailedToAllocateJniString(String),
+    FailedToAllocateJniByteArray(String),
     FailedToReadJniString(String),
+    ContextNotFound(String),
+    UnknownSerializationMode(String),
 }
 
 #[derive(Serialize, Deserialize, JsonSchema, Debug)]
@@ -53,6 +70,26 @@ pub enum InvocationHandlingError {
     ScryptoSborError(String),
     InvalidAddress(String),
     ExecutionModuleError(String),
+    EventFilterError(String),
+    SchemaDecodeError(String),
+    CallArgumentsEncodeError(String),
+    NetworkRegistrationError(String),
+    OlympiaTransactionDecodeError(String),
+    NativeStateDecodeError(String),
+    SignatureNormalizationError(String),
+    ManifestModificationError(String),
+    EpochRangeError(String),
+    FunctionExamplesError(String),
+    ManifestConversionError(String),
+    ManifestIncludeError(String),
+    LegacyConversionError(String),
+    SborDecodeUnknownError(String),
+    AmountFormatError(String),
+    AmountParseError(String),
+    PublicKeyError(String),
+    AttestationError(String),
+    InvalidAttestationSignature(String),
+    GatewayModelConversionError(String),
 }
 
 impl From<InvocationHandlingError> for Error {
@@ -84,3 +121,87 @@ impl From<ScryptoSborError> for InvocationHandlingError {
         Self::ScryptoSborError(debug_string(value))
     }
 }
+
+impl From<EventFilterError> for InvocationHandlingError {
+    fn from(value: EventFilterError) -> Self {
+        Self::EventFilterError(debug_string(value))
+    }
+}
+
+impl From<SchemaDecodeError> for InvocationHandlingError {
+    fn from(value: SchemaDecodeError) -> Self {
+        Self::SchemaDecodeError(debug_string(value))
+    }
+}
+
+impl From<CallArgumentsEncodeError> for InvocationHandlingError {
+    fn from(value: CallArgumentsEncodeError) -> Self {
+        Self::CallArgumentsEncodeError(debug_string(value))
+    }
+}
+
+impl From<NetworkRegistrationError> for InvocationHandlingError {
+    fn from(value: NetworkRegistrationError) -> Self {
+        Self::NetworkRegistrationError(debug_string(value))
+    }
+}
+
+impl From<OlympiaTransactionDecodeError> for InvocationHandlingError {
+    fn from(value: OlympiaTransactionDecodeError) -> Self {
+        Self::OlympiaTransactionDecodeError(debug_string(value))
+    }
+}
+
+impl From<NativeStateDecodeError> for InvocationHandlingError {
+    fn from(value: NativeStateDecodeError) -> Self {
+        Self::NativeStateDecodeError(debug_string(value))
+    }
+}
+
+impl From<SignatureNormalizationError> for InvocationHandlingError {
+    fn from(value: SignatureNormalizationError) -> Self {
+        Self::SignatureNormalizationError(debug_string(value))
+    }
+}
+
+impl<E: std::fmt::Debug> From<ManifestIncludeError<E>> for InvocationHandlingError {
+    fn from(value: ManifestIncludeError<E>) -> Self {
+        Self::ManifestIncludeError(debug_string(value))
+    }
+}
+
+impl From<SborDecodeUnknownError> for InvocationHandlingError {
+    fn from(value: SborDecodeUnknownError) -> Self {
+        Self::SborDecodeUnknownError(debug_string(value))
+    }
+}
+
+impl From<AmountFormatError> for InvocationHandlingError {
+    fn from(value: AmountFormatError) -> Self {
+        Self::AmountFormatError(debug_string(value))
+    }
+}
+
+impl From<AmountParseError> for InvocationHandlingError {
+    fn from(value: AmountParseError) -> Self {
+        Self::AmountParseError(debug_string(value))
+    }
+}
+
+impl From<PublicKeyError> for InvocationHandlingError {
+    fn from(value: PublicKeyError) -> Self {
+        Self::PublicKeyError(debug_string(value))
+    }
+}
+
+impl From<LegacyConversionError> for InvocationHandlingError {
+    fn from(value: LegacyConversionError) -> Self {
+        Self::LegacyConversionError(debug_string(value))
+    }
+}
+
+impl From<ManifestModificationError> for InvocationHandlingError {
+    fn from(value: ManifestModificationError) -> Self {
+        Self::ManifestModificationError(debug_string(value))
+    }
+}
@@ -16,6 +16,7 @@
 // under the License.
 
 use crate::prelude::*;
+use radix_engine_toolkit_core::functions::address::AddressValidationIssue;
 use scrypto::prelude::*;
 
 use schemars::*;
@@ -79,3 +80,162 @@ impl<'f> Function<'f> for AddressDecode {
 
 export_function!(AddressDecode as address_decode);
 export_jni_function!(AddressDecode as addressDecode);
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct AddressValidateInput {
+    pub address: String,
+    pub expected_network_id: Option<SerializableU8>,
+    pub expected_entity_types: Vec<SerializableEntityType>,
+}
+
+#[typeshare::typeshare]
+pub type AddressValidateOutput = Vec<SerializableAddressValidationIssue>;
+
+pub struct AddressValidate;
+impl<'f> Function<'f> for AddressValidate {
+    type Input = AddressValidateInput;
+    type Output = AddressValidateOutput;
+
+    fn handle(input: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let expected_entity_types = input
+            .expected_entity_types
+            .into_iter()
+            .map(EntityType::from)
+            .collect::<Vec<_>>();
+
+        let issues = radix_engine_toolkit_core::functions::address::validate(
+            &input.address,
+            input.expected_network_id.map(|network_id| *network_id),
+            &expected_entity_types,
+        );
+
+        Ok(issues.into_iter().map(Into::into).collect())
+    }
+}
+
+export_function!(AddressValidate as address_validate);
+export_jni_function!(AddressValidate as addressValidate);
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct AddressAbbreviateInput {
+    pub address: String,
+    pub leading_characters: SerializableU32,
+    pub trailing_characters: SerializableU32,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct AddressAbbreviateOutput {
+    pub abbreviated: String,
+    pub checksum_marker: String,
+}
+
+pub struct AddressAbbreviate;
+impl<'f> Function<'f> for AddressAbbreviate {
+    type Input = AddressAbbreviateInput;
+    type Output = AddressAbbreviateOutput;
+
+    fn handle(input: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let abbreviated = radix_engine_toolkit_core::functions::address::abbreviate(
+            &input.address,
+            *input.leading_characters as usize,
+            *input.trailing_characters as usize,
+        );
+
+        Ok(Self::Output {
+            abbreviated: abbreviated.abbreviated,
+            checksum_marker: abbreviated.checksum_marker,
+        })
+    }
+}
+
+export_function!(AddressAbbreviate as address_abbreviate);
+export_jni_function!(AddressAbbreviate as addressAbbreviate);
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct AddressIsLookAlikeInput {
+    pub address1: String,
+    pub address2: String,
+    pub leading_characters: SerializableU32,
+    pub trailing_characters: SerializableU32,
+}
+
+#[typeshare::typeshare]
+pub type AddressIsLookAlikeOutput = bool;
+
+pub struct AddressIsLookAlike;
+impl<'f> Function<'f> for AddressIsLookAlike {
+    type Input = AddressIsLookAlikeInput;
+    type Output = AddressIsLookAlikeOutput;
+
+    fn handle(input: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        Ok(
+            radix_engine_toolkit_core::functions::address::is_look_alike(
+                &input.address1,
+                &input.address2,
+                *input.leading_characters as usize,
+                *input.trailing_characters as usize,
+            ),
+        )
+    }
+}
+
+export_function!(AddressIsLookAlike as address_is_look_alike);
+export_jni_function!(AddressIsLookAlike as addressIsLookAlike);
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableAddressValidationIssue {
+    InvalidBech32(String),
+    InvalidChecksum,
+    InvalidVariant,
+    InvalidLength {
+        expected: SerializableU64,
+        actual: SerializableU64,
+    },
+    InvalidEntityType(SerializableU8),
+    UnknownNetwork(String),
+    WrongNetwork {
+        expected: SerializableU8,
+        actual: SerializableU8,
+    },
+    HrpMismatch {
+        expected: String,
+        actual: String,
+    },
+    WrongEntityType {
+        expected: Vec<SerializableEntityType>,
+        actual: SerializableEntityType,
+    },
+}
+
+impl From<AddressValidationIssue> for SerializableAddressValidationIssue {
+    fn from(value: AddressValidationIssue) -> Self {
+        match value {
+            AddressValidationIssue::InvalidBech32(error) => Self::InvalidBech32(error),
+            AddressValidationIssue::InvalidChecksum => Self::InvalidChecksum,
+            AddressValidationIssue::InvalidVariant => Self::InvalidVariant,
+            AddressValidationIssue::InvalidLength { expected, actual } => Self::InvalidLength {
+                expected: (expected as u64).into(),
+                actual: (actual as u64).into(),
+            },
+            AddressValidationIssue::InvalidEntityType(byte) => Self::InvalidEntityType(byte.into()),
+            AddressValidationIssue::UnknownNetwork(hrp) => Self::UnknownNetwork(hrp),
+            AddressValidationIssue::WrongNetwork { expected, actual } => Self::WrongNetwork {
+                expected: expected.into(),
+                actual: actual.into(),
+            },
+            AddressValidationIssue::HrpMismatch { expected, actual } => {
+                Self::HrpMismatch { expected, actual }
+            }
+            AddressValidationIssue::WrongEntityType { expected, actual } => Self::WrongEntityType {
+                expected: expected.into_iter().map(Into::into).collect(),
+                actual: actual.into(),
+            },
+        }
+    }
+}
@@ -0,0 +1,246 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+use radix_engine_toolkit_core::functions::amount::*;
+use schemars::JsonSchema;
+use scrypto::prelude::*;
+use serde::{Deserialize, Serialize};
+
+//===============
+// Amount Format
+//===============
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct AmountFormatInput {
+    pub amount: SerializableDecimal,
+    pub options: SerializableAmountFormatOptions,
+}
+
+/// The formatted amount, e.g. `1,000.5 XRD`. The decimal separator is always `.`.
+#[typeshare::typeshare]
+pub type AmountFormatOutput = String;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableAmountFormatOptions {
+    /// The divisibility of the resource, amounts never have more decimal places than this.
+    pub divisibility: SerializableU8,
+    /// The symbol of the resource which is appended to the amount after a space.
+    pub symbol: Option<String>,
+    /// The maximum number of decimal places to show. Amounts with more decimal places than this
+    /// and the divisibility are rounded with the rounding mode.
+    pub maximum_decimal_places: Option<SerializableU8>,
+    pub rounding_mode: SerializableRoundingMode,
+    /// The minimum number of decimal places to show, the fractional part is padded with zeros up
+    /// to this number of places.
+    pub minimum_decimal_places: SerializableU8,
+    /// The separator put between groups of three digits of the integral part, if any.
+    pub group_separator: Option<String>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SerializableRoundingMode {
+    ToPositiveInfinity,
+    ToNegativeInfinity,
+    ToZero,
+    AwayFromZero,
+    ToNearestMidpointTowardZero,
+    ToNearestMidpointAwayFromZero,
+    ToNearestMidpointToEven,
+}
+
+pub struct AmountFormat;
+impl<'f> Function<'f> for AmountFormat {
+    type Input = AmountFormatInput;
+    type Output = AmountFormatOutput;
+
+    fn handle(
+        AmountFormatInput { amount, options }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let options = AmountFormatOptions::try_from(options)?;
+        radix_engine_toolkit_core::functions::amount::format(&*amount, &options)
+            .map_err(InvocationHandlingError::from)
+    }
+}
+
+export_function!(AmountFormat as amount_format);
+export_jni_function!(AmountFormat as amountFormat);
+
+//============================
+// Amount XRD Format Options
+//============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct AmountXrdFormatOptionsInput {
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct AmountXrdFormatOptionsOutput {
+    /// The address of XRD on the network.
+    pub resource_address: SerializableNodeId,
+    /// The default options for formatting XRD amounts.
+    pub options: SerializableAmountFormatOptions,
+}
+
+pub struct AmountXrdFormatOptions;
+impl<'f> Function<'f> for AmountXrdFormatOptions {
+    type Input = AmountXrdFormatOptionsInput;
+    type Output = AmountXrdFormatOptionsOutput;
+
+    fn handle(
+        AmountXrdFormatOptionsInput { network_id }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        Ok(AmountXrdFormatOptionsOutput {
+            resource_address: SerializableNodeId::new(XRD.into_node_id(), *network_id),
+            options: AmountFormatOptions::xrd().into(),
+        })
+    }
+}
+
+export_function!(AmountXrdFormatOptions as amount_xrd_format_options);
+export_jni_function!(AmountXrdFormatOptions as amountXrdFormatOptions);
+
+//==============
+// Amount Parse
+//==============
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct AmountParseInput {
+    /// The amount as typed in by the user, e.g. `+1,000.5` or `1.5e3`. The decimal separator is
+    /// always `.`.
+    pub input: String,
+}
+
+/// The amount in its canonical representation.
+#[typeshare::typeshare]
+pub type AmountParseOutput = SerializableDecimal;
+
+pub struct AmountParse;
+impl<'f> Function<'f> for AmountParse {
+    type Input = AmountParseInput;
+    type Output = AmountParseOutput;
+
+    fn handle(
+        AmountParseInput { input }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        radix_engine_toolkit_core::functions::amount::parse(&input)
+            .map(SerializableDecimal::from)
+            .map_err(InvocationHandlingError::from)
+    }
+}
+
+export_function!(AmountParse as amount_parse);
+export_jni_function!(AmountParse as amountParse);
+
+impl TryFrom<SerializableAmountFormatOptions> for AmountFormatOptions {
+    type Error = InvocationHandlingError;
+
+    fn try_from(
+        SerializableAmountFormatOptions {
+            divisibility,
+            symbol,
+            maximum_decimal_places,
+            rounding_mode,
+            minimum_decimal_places,
+            group_separator,
+        }: SerializableAmountFormatOptions,
+    ) -> Result<Self, Self::Error> {
+        let group_separator = match group_separator {
+            Some(separator) => {
+                let mut characters = separator.chars();
+                match (characters.next(), characters.next()) {
+                    (Some(character), None) => Some(character),
+                    _ => {
+                        return Err(InvocationHandlingError::AmountFormatError(format!(
+                            "The group separator must be a single character: {separator:?}"
+                        )))
+                    }
+                }
+            }
+            None => None,
+        };
+        Ok(Self {
+            divisibility: *divisibility,
+            symbol,
+            maximum_decimal_places: maximum_decimal_places.map(|places| *places),
+            rounding_mode: rounding_mode.into(),
+            minimum_decimal_places: *minimum_decimal_places,
+            group_separator,
+        })
+    }
+}
+
+impl From<AmountFormatOptions> for SerializableAmountFormatOptions {
+    fn from(
+        AmountFormatOptions {
+            divisibility,
+            symbol,
+            maximum_decimal_places,
+            rounding_mode,
+            minimum_decimal_places,
+            group_separator,
+        }: AmountFormatOptions,
+    ) -> Self {
+        Self {
+            divisibility: divisibility.into(),
+            symbol,
+            maximum_decimal_places: maximum_decimal_places.map(Into::into),
+            rounding_mode: rounding_mode.into(),
+            minimum_decimal_places: minimum_decimal_places.into(),
+            group_separator: group_separator.map(String::from),
+        }
+    }
+}
+
+impl From<SerializableRoundingMode> for RoundingMode {
+    fn from(value: SerializableRoundingMode) -> Self {
+        match value {
+            SerializableRoundingMode::ToPositiveInfinity => Self::ToPositiveInfinity,
+            SerializableRoundingMode::ToNegativeInfinity => Self::ToNegativeInfinity,
+            SerializableRoundingMode::ToZero => Self::ToZero,
+            SerializableRoundingMode::AwayFromZero => Self::AwayFromZero,
+            SerializableRoundingMode::ToNearestMidpointTowardZero => {
+                Self::ToNearestMidpointTowardZero
+            }
+            SerializableRoundingMode::ToNearestMidpointAwayFromZero => {
+                Self::ToNearestMidpointAwayFromZero
+            }
+            SerializableRoundingMode::ToNearestMidpointToEven => Self::ToNearestMidpointToEven,
+        }
+    }
+}
+
+impl From<RoundingMode> for SerializableRoundingMode {
+    fn from(value: RoundingMode) -> Self {
+        match value {
+            RoundingMode::ToPositiveInfinity => Self::ToPositiveInfinity,
+            RoundingMode::ToNegativeInfinity => Self::ToNegativeInfinity,
+            RoundingMode::ToZero => Self::ToZero,
+            RoundingMode::AwayFromZero => Self::AwayFromZero,
+            RoundingMode::ToNearestMidpointTowardZero => Self::ToNearestMidpointTowardZero,
+            RoundingMode::ToNearestMidpointAwayFromZero => Self::ToNearestMidpointAwayFromZero,
+            RoundingMode::ToNearestMidpointToEven => Self::ToNearestMidpointToEven,
+        }
+    }
+}
@@ -0,0 +1,66 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+//================
+// Context Create
+//================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct ContextCreateInput {}
+#[typeshare::typeshare]
+pub type ContextCreateOutput = SerializableU64;
+
+pub struct ContextCreate;
+impl<'f> Function<'f> for ContextCreate {
+    type Input = ContextCreateInput;
+    type Output = ContextCreateOutput;
+
+    fn handle(_: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        Ok(crate::context::create_context().into())
+    }
+}
+
+export_function!(ContextCreate as context_create);
+export_jni_function!(ContextCreate as contextCreate);
+
+//=================
+// Context Destroy
+//=================
+
+#[typeshare::typeshare]
+pub type ContextDestroyInput = SerializableU64;
+#[typeshare::typeshare]
+pub type ContextDestroyOutput = bool;
+
+pub struct ContextDestroy;
+impl<'f> Function<'f> for ContextDestroy {
+    type Input = ContextDestroyInput;
+    type Output = ContextDestroyOutput;
+
+    fn handle(input: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        Ok(crate::context::destroy_context(*input))
+    }
+}
+
+export_function!(ContextDestroy as context_destroy);
+export_jni_function!(ContextDestroy as contextDestroy);
@@ -0,0 +1,214 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_common::prelude::*;
+use radix_engine_toolkit_core::functions::dapp_definition::*;
+use schemars::JsonSchema;
+use scrypto::api::node_modules::metadata::MetadataValue;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+//=============================
+// Dapp Definition Verify Link
+//=============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct DappDefinitionVerifyLinkInput {
+    pub entity_address: SerializableNodeId,
+    pub entity_metadata: HashMap<String, SerializableMetadataValue>,
+    pub dapp_definition_address: SerializableNodeId,
+    pub dapp_definition_metadata: HashMap<String, SerializableMetadataValue>,
+}
+
+#[typeshare::typeshare]
+pub type DappDefinitionVerifyLinkOutput = SerializableDappDefinitionLinkVerification;
+
+pub struct DappDefinitionVerifyLink;
+impl<'f> Function<'f> for DappDefinitionVerifyLink {
+    type Input = DappDefinitionVerifyLinkInput;
+    type Output = DappDefinitionVerifyLinkOutput;
+
+    fn handle(
+        DappDefinitionVerifyLinkInput {
+            entity_address,
+            entity_metadata,
+            dapp_definition_address,
+            dapp_definition_metadata,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let network_id = entity_address.0.network_id;
+
+        let verification = radix_engine_toolkit_core::functions::dapp_definition::verify_link(
+            to_global_address(&entity_address)?,
+            &metadata_to_native(&entity_metadata)?,
+            to_global_address(&dapp_definition_address)?,
+            &metadata_to_native(&dapp_definition_metadata)?,
+        );
+
+        Ok(SerializableDappDefinitionLinkVerification::new(
+            verification,
+            network_id,
+        ))
+    }
+}
+
+export_function!(DappDefinitionVerifyLink as dapp_definition_verify_link);
+export_jni_function!(DappDefinitionVerifyLink as dappDefinitionVerifyLink);
+
+//=============================================
+// Dapp Definition Referenced Dapp Definitions
+//=============================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct DappDefinitionReferencedDappDefinitionsInput {
+    pub metadata: HashMap<String, SerializableMetadataValue>,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+pub type DappDefinitionReferencedDappDefinitionsOutput = Vec<SerializableNodeId>;
+
+pub struct DappDefinitionReferencedDappDefinitions;
+impl<'f> Function<'f> for DappDefinitionReferencedDappDefinitions {
+    type Input = DappDefinitionReferencedDappDefinitionsInput;
+    type Output = DappDefinitionReferencedDappDefinitionsOutput;
+
+    fn handle(
+        DappDefinitionReferencedDappDefinitionsInput {
+            metadata,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let metadata = metadata_to_native(&metadata)?;
+
+        Ok(
+            radix_engine_toolkit_core::functions::dapp_definition::referenced_dapp_definitions(
+                &metadata,
+            )
+            .into_iter()
+            .map(|address| SerializableNodeId::new(address.into_node_id(), *network_id))
+            .collect(),
+        )
+    }
+}
+
+export_function!(
+    DappDefinitionReferencedDappDefinitions as dapp_definition_referenced_dapp_definitions
+);
+export_jni_function!(
+    DappDefinitionReferencedDappDefinitions as dappDefinitionReferencedDappDefinitions
+);
+
+//========
+// Models
+//========
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableDappDefinitionLinkVerification {
+    pub entity_address: SerializableNodeId,
+    pub dapp_definition_address: SerializableNodeId,
+    pub is_verified: bool,
+    pub entity_references_dapp_definition: bool,
+    pub dapp_definition_claims_entity: bool,
+    pub violations: Vec<SerializableDappDefinitionLinkViolation>,
+}
+
+impl SerializableDappDefinitionLinkVerification {
+    pub fn new(verification: DappDefinitionLinkVerification, network_id: u8) -> Self {
+        Self {
+            entity_address: SerializableNodeId::new(
+                verification.entity_address.into_node_id(),
+                network_id,
+            ),
+            dapp_definition_address: SerializableNodeId::new(
+                verification.dapp_definition_address.into_node_id(),
+                network_id,
+            ),
+            is_verified: verification.is_verified(),
+            entity_references_dapp_definition: verification.entity_references_dapp_definition,
+            dapp_definition_claims_entity: verification.dapp_definition_claims_entity,
+            violations: verification
+                .violations
+                .into_iter()
+                .map(|violation| {
+                    SerializableDappDefinitionLinkViolation::new(violation, network_id)
+                })
+                .collect(),
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableDappDefinitionLinkViolation {
+    InvalidMetadataValueType {
+        address: SerializableNodeId,
+        key: String,
+    },
+    EntityDoesNotReferenceDappDefinition,
+    DappDefinitionIsNotAnAccount,
+    AccountTypeIsNotDappDefinition {
+        account_type: Option<String>,
+    },
+    DappDefinitionDoesNotClaimEntity,
+}
+
+impl SerializableDappDefinitionLinkViolation {
+    pub fn new(violation: DappDefinitionLinkViolation, network_id: u8) -> Self {
+        match violation {
+            DappDefinitionLinkViolation::InvalidMetadataValueType { address, key } => {
+                Self::InvalidMetadataValueType {
+                    address: SerializableNodeId::new(address.into_node_id(), network_id),
+                    key,
+                }
+            }
+            DappDefinitionLinkViolation::EntityDoesNotReferenceDappDefinition => {
+                Self::EntityDoesNotReferenceDappDefinition
+            }
+            DappDefinitionLinkViolation::DappDefinitionIsNotAnAccount => {
+                Self::DappDefinitionIsNotAnAccount
+            }
+            DappDefinitionLinkViolation::AccountTypeIsNotDappDefinition { account_type } => {
+                Self::AccountTypeIsNotDappDefinition { account_type }
+            }
+            DappDefinitionLinkViolation::DappDefinitionDoesNotClaimEntity => {
+                Self::DappDefinitionDoesNotClaimEntity
+            }
+        }
+    }
+}
+
+fn to_global_address(
+    address: &SerializableNodeId,
+) -> Result<GlobalAddress, InvocationHandlingError> {
+    GlobalAddress::try_from(address.clone())
+        .map_err(|_| InvocationHandlingError::InvalidAddress(address.0.to_string()))
+}
+
+fn metadata_to_native(
+    metadata: &HashMap<String, SerializableMetadataValue>,
+) -> Result<HashMap<String, MetadataValue>, InvocationHandlingError> {
+    metadata
+        .iter()
+        .map(|(key, value)| Ok((key.clone(), value.to_native()?)))
+        .collect()
+}
@@ -16,8 +16,9 @@
 // under the License.
 
 use crate::prelude::*;
-use radix_engine_common::prelude::PublicKey;
+use radix_engine_common::prelude::{PublicKey, PublicKeyHash};
 use schemars::JsonSchema;
+use scrypto::prelude::Epoch;
 use serde::{Deserialize, Serialize};
 use transaction::model::TransactionHashBech32Encoder;
 
@@ -154,6 +155,136 @@ export_jni_function!(
         as deriveVirtualSignatureNonFungibleGlobalIdFromPublicKey
 );
 
+//======================================================================
+// Derive Virtual Signature Non-Fungible Global Id from Public Key Hash
+//======================================================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct DeriveVirtualSignatureNonFungibleGlobalIdFromPublicKeyHashInput {
+    pub public_key_hash: SerializablePublicKeyHash,
+    pub network_id: SerializableU8,
+}
+pub type DeriveVirtualSignatureNonFungibleGlobalIdFromPublicKeyHashOutput =
+    SerializableNonFungibleGlobalId;
+
+pub struct DeriveVirtualSignatureNonFungibleGlobalIdFromPublicKeyHash;
+impl<'a> Function<'a> for DeriveVirtualSignatureNonFungibleGlobalIdFromPublicKeyHash {
+    type Input = DeriveVirtualSignatureNonFungibleGlobalIdFromPublicKeyHashInput;
+    type Output = DeriveVirtualSignatureNonFungibleGlobalIdFromPublicKeyHashOutput;
+
+    fn handle(input: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let DeriveVirtualSignatureNonFungibleGlobalIdFromPublicKeyHashInput {
+            public_key_hash,
+            network_id,
+        } = input;
+
+        let non_fungible_global_id =
+            radix_engine_toolkit_core::functions::derive::virtual_signature_non_fungible_global_id_from_public_key_hash(
+                &PublicKeyHash::from(public_key_hash),
+            );
+
+        Ok(SerializableNonFungibleGlobalId::new(
+            non_fungible_global_id,
+            *network_id,
+        ))
+    }
+}
+
+export_function!(
+    DeriveVirtualSignatureNonFungibleGlobalIdFromPublicKeyHash
+        as derive_virtual_signature_non_fungible_global_id_from_public_key_hash
+);
+export_jni_function!(
+    DeriveVirtualSignatureNonFungibleGlobalIdFromPublicKeyHash
+        as deriveVirtualSignatureNonFungibleGlobalIdFromPublicKeyHash
+);
+
+//====================================================
+// Derive Virtual Global Address from Public Key Hash
+//====================================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct DeriveVirtualGlobalAddressFromPublicKeyHashInput {
+    pub public_key_hash: SerializablePublicKeyHash,
+    /// One of the virtual account or identity entity types of the curve of the public key hash.
+    pub entity_type: SerializableEntityType,
+    pub network_id: SerializableU8,
+}
+pub type DeriveVirtualGlobalAddressFromPublicKeyHashOutput = SerializableNodeId;
+
+pub struct DeriveVirtualGlobalAddressFromPublicKeyHash;
+impl<'a> Function<'a> for DeriveVirtualGlobalAddressFromPublicKeyHash {
+    type Input = DeriveVirtualGlobalAddressFromPublicKeyHashInput;
+    type Output = DeriveVirtualGlobalAddressFromPublicKeyHashOutput;
+
+    fn handle(input: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let DeriveVirtualGlobalAddressFromPublicKeyHashInput {
+            public_key_hash,
+            entity_type,
+            network_id,
+        } = input;
+
+        let address =
+            radix_engine_toolkit_core::functions::derive::virtual_global_address_from_public_key_hash(
+                &PublicKeyHash::from(public_key_hash),
+                entity_type.into(),
+            )
+            .map_err(|error| InvocationHandlingError::DerivationError(debug_string(error)))?;
+
+        Ok(SerializableNodeId::new(address.into_node_id(), *network_id))
+    }
+}
+
+export_function!(
+    DeriveVirtualGlobalAddressFromPublicKeyHash
+        as derive_virtual_global_address_from_public_key_hash
+);
+export_jni_function!(
+    DeriveVirtualGlobalAddressFromPublicKeyHash as deriveVirtualGlobalAddressFromPublicKeyHash
+);
+
+//======================================================================
+// Derive Public Key Hash from Virtual Signature Non-Fungible Global Id
+//======================================================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct DerivePublicKeyHashFromVirtualSignatureNonFungibleGlobalIdInput {
+    pub non_fungible_global_id: SerializableNonFungibleGlobalId,
+}
+pub type DerivePublicKeyHashFromVirtualSignatureNonFungibleGlobalIdOutput =
+    SerializablePublicKeyHash;
+
+pub struct DerivePublicKeyHashFromVirtualSignatureNonFungibleGlobalId;
+impl<'a> Function<'a> for DerivePublicKeyHashFromVirtualSignatureNonFungibleGlobalId {
+    type Input = DerivePublicKeyHashFromVirtualSignatureNonFungibleGlobalIdInput;
+    type Output = DerivePublicKeyHashFromVirtualSignatureNonFungibleGlobalIdOutput;
+
+    fn handle(input: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let DerivePublicKeyHashFromVirtualSignatureNonFungibleGlobalIdInput {
+            non_fungible_global_id,
+        } = input;
+
+        let public_key_hash =
+            radix_engine_toolkit_core::functions::derive::public_key_hash_from_virtual_signature_non_fungible_global_id(
+                &non_fungible_global_id.0.non_fungible_global_id,
+            ).map_err(|error| InvocationHandlingError::DerivationError(debug_string(error)))?;
+
+        Ok(public_key_hash.into())
+    }
+}
+
+export_function!(
+    DerivePublicKeyHashFromVirtualSignatureNonFungibleGlobalId
+        as derive_public_key_hash_from_virtual_signature_non_fungible_global_id
+);
+export_jni_function!(
+    DerivePublicKeyHashFromVirtualSignatureNonFungibleGlobalId
+        as derivePublicKeyHashFromVirtualSignatureNonFungibleGlobalId
+);
+
 //=============================================================
 // Derive Virtual Account Address from Olympia Account Address
 //=============================================================
@@ -394,3 +525,127 @@ export_jni_function!(
     DeriveBech32mTransactionIdentifierFromIntentHash
         as deriveBech32mTransactionIdentifierFromIntentHash
 );
+
+//==============
+// Derive Nonce
+//==============
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+#[serde(tag = "kind", content = "value")]
+pub enum DeriveNonceInput {
+    Entropy {
+        entropy: SerializableBytes,
+    },
+    Intent {
+        intent: SerializableIntent,
+        key: SerializableBytes,
+        attempt: SerializableU32,
+    },
+}
+#[typeshare::typeshare]
+pub type DeriveNonceOutput = SerializableU32;
+
+pub struct DeriveNonce;
+impl<'a> Function<'a> for DeriveNonce {
+    type Input = DeriveNonceInput;
+    type Output = DeriveNonceOutput;
+
+    fn handle(input: Self::Input) -> Result<Self::Output, InvocationHandlingError> {
+        let nonce = match input {
+            DeriveNonceInput::Entropy { entropy } => {
+                radix_engine_toolkit_core::functions::intent::nonce_from_entropy(&entropy)
+            }
+            DeriveNonceInput::Intent {
+                intent,
+                key,
+                attempt,
+            } => {
+                let intent = intent.to_native(*intent.header.network_id)?;
+                radix_engine_toolkit_core::functions::intent::nonce_from_intent(
+                    &intent, &key, *attempt,
+                )
+                .map_err(|error| {
+                    InvocationHandlingError::EncodeError(debug_string(error), debug_string(intent))
+                })?
+            }
+        };
+
+        Ok(nonce.into())
+    }
+}
+
+export_function!(DeriveNonce as derive_nonce);
+export_jni_function!(DeriveNonce as deriveNonce);
+
+//================================
+// Derive Transaction Tracker Key
+//================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct DeriveTransactionTrackerKeyInput {
+    pub intent_hash: SerializableHash,
+    pub end_epoch_exclusive: SerializableU64,
+    pub partitioning: SerializableTransactionTrackerPartitioning,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableTransactionTrackerPartitioning {
+    pub start_epoch: SerializableU64,
+    pub start_partition: SerializableU8,
+    pub partition_range_start_inclusive: SerializableU8,
+    pub partition_range_end_inclusive: SerializableU8,
+    pub epochs_per_partition: SerializableU64,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableTransactionTrackerKey {
+    pub partition_number: SerializableU8,
+    pub db_node_key: SerializableBytes,
+    pub db_partition_number: SerializableU8,
+    pub db_sort_key: SerializableBytes,
+}
+#[typeshare::typeshare]
+pub type DeriveTransactionTrackerKeyOutput = Option<SerializableTransactionTrackerKey>;
+
+pub struct DeriveTransactionTrackerKey;
+impl<'a> Function<'a> for DeriveTransactionTrackerKey {
+    type Input = DeriveTransactionTrackerKeyInput;
+    type Output = DeriveTransactionTrackerKeyOutput;
+
+    fn handle(
+        DeriveTransactionTrackerKeyInput {
+            intent_hash,
+            end_epoch_exclusive,
+            partitioning,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let partitioning =
+            radix_engine_toolkit_core::functions::intent::TransactionTrackerPartitioning {
+                start_epoch: Epoch::of(*partitioning.start_epoch),
+                start_partition: *partitioning.start_partition,
+                partition_range_start_inclusive: *partitioning.partition_range_start_inclusive,
+                partition_range_end_inclusive: *partitioning.partition_range_end_inclusive,
+                epochs_per_partition: *partitioning.epochs_per_partition,
+            };
+
+        let key = radix_engine_toolkit_core::functions::intent::transaction_tracker_key(
+            &intent_hash,
+            Epoch::of(*end_epoch_exclusive),
+            &partitioning,
+        );
+
+        Ok(key.map(|key| SerializableTransactionTrackerKey {
+            partition_number: key.partition_number.0.into(),
+            db_node_key: key.db_partition_key.node_key.into(),
+            db_partition_number: key.db_partition_key.partition_num.into(),
+            db_sort_key: key.db_sort_key.0.into(),
+        }))
+    }
+}
+
+export_function!(DeriveTransactionTrackerKey as derive_transaction_tracker_key);
+export_jni_function!(DeriveTransactionTrackerKey as deriveTransactionTrackerKey);
@@ -0,0 +1,149 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+use radix_engine_toolkit_core::functions::events::*;
+use schemars::JsonSchema;
+use scrypto::prelude::*;
+use serde::{Deserialize, Serialize};
+
+//=======================
+// Events Compile Filter
+//=======================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct EventsCompileFilterInput {
+    pub filter: SerializableEventFilter,
+    pub network_id: SerializableU8,
+}
+#[typeshare::typeshare]
+pub type EventsCompileFilterOutput = SerializableCompiledEventFilter;
+
+pub struct EventsCompileFilter;
+impl<'f> Function<'f> for EventsCompileFilter {
+    type Input = EventsCompileFilterInput;
+    type Output = EventsCompileFilterOutput;
+
+    fn handle(
+        EventsCompileFilterInput { filter, network_id }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let filter = filter.to_native()?;
+        let compiled = compile_event_filter(&filter)?;
+        Ok(SerializableCompiledEventFilter::new(compiled, *network_id))
+    }
+}
+
+export_function!(EventsCompileFilter as events_compile_filter);
+export_jni_function!(EventsCompileFilter as eventsCompileFilter);
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableEventFilter {
+    Emitter(SerializableNodeId),
+    EventName(String),
+    AccountResourceMovement {
+        account: SerializableNodeId,
+        resource_address: Option<SerializableNodeId>,
+    },
+    All(Vec<SerializableEventFilter>),
+    Any(Vec<SerializableEventFilter>),
+}
+
+impl SerializableEventFilter {
+    pub fn to_native(&self) -> Result<EventFilter, InvocationHandlingError> {
+        let filter = match self {
+            Self::Emitter(address) => EventFilter::Emitter(
+                GlobalAddress::try_from(address.clone())
+                    .map_err(|_| InvocationHandlingError::InvalidAddress(address.0.to_string()))?,
+            ),
+            Self::EventName(name) => EventFilter::EventName(name.clone()),
+            Self::AccountResourceMovement {
+                account,
+                resource_address,
+            } => EventFilter::AccountResourceMovement {
+                account: ComponentAddress::try_from(account.clone())
+                    .map_err(|_| InvocationHandlingError::InvalidAddress(account.0.to_string()))?,
+                resource_address: resource_address
+                    .as_ref()
+                    .map(|address| {
+                        ResourceAddress::try_from(address.clone()).map_err(|_| {
+                            InvocationHandlingError::InvalidAddress(address.0.to_string())
+                        })
+                    })
+                    .transpose()?,
+            },
+            Self::All(filters) => EventFilter::All(
+                filters
+                    .iter()
+                    .map(Self::to_native)
+                    .collect::<Result<_, _>>()?,
+            ),
+            Self::Any(filters) => EventFilter::Any(
+                filters
+                    .iter()
+                    .map(Self::to_native)
+                    .collect::<Result<_, _>>()?,
+            ),
+        };
+        Ok(filter)
+    }
+}
+
+/// The compiled form of a [`SerializableEventFilter`] in disjunctive normal form: an event is
+/// matched if it matches any of the clauses.
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableCompiledEventFilter {
+    pub clauses: Vec<SerializableEventFilterClause>,
+}
+
+/// A conjunction of predicates on an event. Predicates that are null match all events.
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableEventFilterClause {
+    pub emitter: Option<SerializableNodeId>,
+    pub event_name: Option<String>,
+    pub resource_address: Option<SerializableNodeId>,
+}
+
+impl SerializableCompiledEventFilter {
+    pub fn new(CompiledEventFilter { clauses }: CompiledEventFilter, network_id: u8) -> Self {
+        Self {
+            clauses: clauses
+                .into_iter()
+                .map(
+                    |EventFilterClause {
+                         emitter,
+                         event_name,
+                         resource_address,
+                     }| SerializableEventFilterClause {
+                        emitter: emitter.map(|address| {
+                            SerializableNodeId::new(address.into_node_id(), network_id)
+                        }),
+                        event_name,
+                        resource_address: resource_address.map(|address| {
+                            SerializableNodeId::new(address.into_node_id(), network_id)
+                        }),
+                    },
+                )
+                .collect(),
+        }
+    }
+}
@@ -15,22 +15,38 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Deref;
+
 use crate::prelude::*;
 
 use radix_engine::system::system_modules::execution_trace::*;
 use radix_engine::transaction::*;
 use radix_engine::types::ResourceOrNonFungible;
 use radix_engine_common::prelude::*;
+use radix_engine_toolkit_core::functions::attestation::Attestation;
 use radix_engine_toolkit_core::functions::execution::*;
+use radix_engine_toolkit_core::functions::lint::*;
+use radix_engine_toolkit_core::instruction_visitor::visitors::fee_locks_visitor::FeePayers;
 use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::account_deposit_settings_visitor::AuthorizedDepositorsChanges;
 use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::account_deposit_settings_visitor::ResourcePreferenceAction;
-use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::reserved_instructions::ReservedInstruction;
+use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::owner_keys_visitor::OwnerKeysAction;
+use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::entity_control_changes_visitor::EntityControlChange;
+use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::royalty_claims_visitor::RoyaltyClaim;
+use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::swap_visitor::*;
+use radix_engine_toolkit_core::instruction_visitor::visitors::required_badges_visitor::*;
+use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::reserved_instructions::*;
+use radix_engine_toolkit_core::instruction_visitor::visitors::worktop_guarantees_visitor::*;
+use radix_engine_toolkit_core::instruction_visitor::visitors::authorized_depositor_badges_visitor::*;
 use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::transfer_visitor::*;
 use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::general_transaction_visitor::*;
 use schemars::*;
 use scrypto::api::node_modules::metadata::*;
 use scrypto::blueprints::account::{ResourcePreference, DefaultDepositRule};
+use scrypto::prelude::ObjectModuleId;
 use serde::*;
+use transaction::prelude::{DynamicGlobalAddress, Ed25519PrivateKey};
+use zeroize::Zeroizing;
 
 //===================
 // Execution Analyze
@@ -42,6 +58,39 @@ pub struct ExecutionAnalyzeInput {
     pub instructions: SerializableInstructions,
     pub network_id: SerializableU8,
     pub preview_receipt: SerializableBytes,
+    /// The exchanges whose swaps are classified as [`SerializableTransactionType::Swap`]. No swaps
+    /// are classified when this is omitted.
+    #[serde(default)]
+    pub dex_registry: Vec<SerializableDexVenue>,
+    /// The Ed25519 private key to attest to the output with. The output has no attestation when
+    /// this is omitted. The toolkit zeroizes its copy of the key once the output is signed.
+    #[serde(default)]
+    pub attestation_private_key: Option<SerializableBytes>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableDexVenue {
+    pub name: String,
+    pub component_addresses: Vec<SerializableNodeId>,
+    pub swap_methods: Vec<String>,
+}
+
+impl SerializableDexVenue {
+    pub fn to_native(&self) -> Result<DexVenue, InvocationHandlingError> {
+        Ok(DexVenue {
+            name: self.name.clone(),
+            component_addresses: self
+                .component_addresses
+                .iter()
+                .map(|address| {
+                    ComponentAddress::try_from(address.clone())
+                        .map_err(|_| InvocationHandlingError::InvalidAddress(address.0.to_string()))
+                })
+                .collect::<Result<_, _>>()?,
+            swap_methods: self.swap_methods.clone(),
+        })
+    }
 }
 
 #[typeshare::typeshare]
@@ -51,6 +100,15 @@ pub struct ExecutionAnalyzeOutput {
     pub fee_summary: SerializableFeeSummary,
     pub transaction_types: Vec<SerializableTransactionType>,
     pub reserved_instructions: Vec<SerializableReservedInstruction>,
+    pub reserved_instruction_usages: Vec<SerializableReservedInstructionUsage>,
+    pub required_badges: Vec<SerializableInstructionBadgeRequirements>,
+    pub guarantees: Vec<SerializableWorktopGuarantee>,
+    pub authorized_depositor_badges: Vec<SerializableAuthorizedDepositorBadgeUsage>,
+    pub resource_movement_discrepancies: Vec<SerializableResourceMovementDiscrepancy>,
+    pub resource_movement_graph: SerializableResourceMovementGraph,
+    /// The attestation over the canonical encoding of this output, with the attestation itself
+    /// being [`None`] in the encoded output.
+    pub attestation: Option<SerializableAttestation>,
 }
 
 pub struct ExecutionAnalyze;
@@ -63,9 +121,17 @@ impl<'f> Function<'f> for ExecutionAnalyze {
             instructions,
             network_id,
             preview_receipt,
+            dex_registry,
+            attestation_private_key,
         }: Self::Input,
     ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
         let instructions = instructions.to_instructions(*network_id)?;
+        let dex_registry = dex_registry
+            .iter()
+            .map(SerializableDexVenue::to_native)
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .fold(DexRegistry::new(), DexRegistry::register);
         let receipt =
             scrypto_decode::<VersionedTransactionReceipt>(&preview_receipt).map_err(|error| {
                 InvocationHandlingError::DecodeError(
@@ -76,7 +142,12 @@ impl<'f> Function<'f> for ExecutionAnalyze {
 
         let execution_analysis = ExecutionAnalysisTransactionReceipt::new(&receipt)
             .and_then(|receipt| {
-                radix_engine_toolkit_core::functions::execution::analyze(&instructions, &receipt)
+                radix_engine_toolkit_core::functions::execution::analyze_with_dex_registry(
+                    &instructions,
+                    &receipt,
+                    &dex_registry,
+                    &mut [],
+                )
             })
             .map_err(|error| InvocationHandlingError::ExecutionModuleError(debug_string(error)))?;
 
@@ -85,25 +156,389 @@ impl<'f> Function<'f> for ExecutionAnalyze {
             .into_iter()
             .map(|value| SerializableTransactionType::new(value, *network_id))
             .collect();
-        let fee_summary = execution_analysis.fee_summary.into();
+        let fee_summary = SerializableFeeSummary::new(execution_analysis.fee_summary, *network_id);
         let fee_locks = execution_analysis.fee_locks.into();
+        // The reserved instructions are found as a set, they're sorted so that the output (and thus
+        // the payload of its attestation) does not depend on the iteration order of the set.
+        let mut reserved_instructions = execution_analysis
+            .reserved_instructions
+            .into_iter()
+            .map(SerializableReservedInstruction::from)
+            .collect::<Vec<_>>();
+        reserved_instructions.sort();
 
-        Ok(Self::Output {
+        let output = Self::Output {
             fee_locks,
             fee_summary,
             transaction_types,
-            reserved_instructions: execution_analysis
-                .reserved_instructions
+            reserved_instructions,
+            reserved_instruction_usages: execution_analysis
+                .reserved_instruction_usages
                 .into_iter()
-                .map(From::from)
+                .map(|value| SerializableReservedInstructionUsage::new(value, *network_id))
                 .collect(),
-        })
+            required_badges: execution_analysis
+                .required_badges
+                .into_iter()
+                .map(|value| SerializableInstructionBadgeRequirements::new(value, *network_id))
+                .collect(),
+            guarantees: execution_analysis
+                .guarantees
+                .into_iter()
+                .map(|value| SerializableWorktopGuarantee::new(value, *network_id))
+                .collect(),
+            authorized_depositor_badges: execution_analysis
+                .authorized_depositor_badges
+                .into_iter()
+                .map(|value| SerializableAuthorizedDepositorBadgeUsage::new(value, *network_id))
+                .collect(),
+            resource_movement_discrepancies: execution_analysis
+                .resource_movement_discrepancies
+                .into_iter()
+                .map(|value| SerializableResourceMovementDiscrepancy::new(value, *network_id))
+                .collect(),
+            resource_movement_graph: SerializableResourceMovementGraph::new(
+                execution_analysis.resource_movement_graph,
+                *network_id,
+            ),
+            attestation: None,
+        };
+
+        match attestation_private_key {
+            Some(mut private_key_bytes) => {
+                let private_key_bytes = Zeroizing::new(std::mem::take(&mut *private_key_bytes));
+                let private_key =
+                    Ed25519PrivateKey::from_bytes(&private_key_bytes).map_err(|_| {
+                        InvocationHandlingError::AttestationError(
+                            "The bytes are not a valid Ed25519 private key".to_owned(),
+                        )
+                    })?;
+                let payload = canonical_json(&output).map_err(|error| {
+                    InvocationHandlingError::AttestationError(debug_string(error))
+                })?;
+                let attestation =
+                    radix_engine_toolkit_core::functions::attestation::sign(payload, &private_key);
+                Ok(Self::Output {
+                    attestation: Some(attestation.into()),
+                    ..output
+                })
+            }
+            None => Ok(output),
+        }
     }
 }
 
 export_function!(ExecutionAnalyze as execution_analyze);
 export_jni_function!(ExecutionAnalyze as executionAnalyze);
 
+//==============================
+// Execution Verify Attestation
+//==============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionVerifyAttestationInput {
+    pub attestation: SerializableAttestation,
+    /// The public key that the attestation is expected to be signed by.
+    pub trusted_public_key: SerializableEd25519PublicKey,
+}
+
+/// The output of the analysis decoded from the payload of the attestation. Attestations that are
+/// not signed by the trusted public key fail with an `InvalidAttestationSignature` error.
+#[typeshare::typeshare]
+pub type ExecutionVerifyAttestationOutput = ExecutionAnalyzeOutput;
+
+pub struct ExecutionVerifyAttestation;
+impl<'f> Function<'f> for ExecutionVerifyAttestation {
+    type Input = ExecutionVerifyAttestationInput;
+    type Output = ExecutionVerifyAttestationOutput;
+
+    fn handle(
+        ExecutionVerifyAttestationInput {
+            attestation,
+            trusted_public_key,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let attestation = Attestation::from(attestation);
+        let trusted_public_key = Ed25519PublicKey::from(trusted_public_key);
+
+        if !radix_engine_toolkit_core::functions::attestation::verify(
+            &attestation,
+            &trusted_public_key,
+        ) {
+            return Err(InvocationHandlingError::InvalidAttestationSignature(
+                "The attestation is not signed by the trusted public key".to_owned(),
+            ));
+        }
+
+        serde_json::from_slice(&attestation.payload)
+            .map_err(|error| InvocationHandlingError::AttestationError(debug_string(error)))
+    }
+}
+
+export_function!(ExecutionVerifyAttestation as execution_verify_attestation);
+export_jni_function!(ExecutionVerifyAttestation as executionVerifyAttestation);
+
+//==============================================
+// Execution Extract Account Resource Movements
+//==============================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionExtractAccountResourceMovementsInput {
+    pub network_id: SerializableU8,
+    pub preview_receipt: SerializableBytes,
+}
+
+/// The movements are keyed by account and then by resource address, both in ascending order.
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionExtractAccountResourceMovementsOutput {
+    #[typeshare(
+        serialized_as = "HashMap<SerializableNodeId, HashMap<SerializableNodeId, SerializableDecimal>>"
+    )]
+    pub withdraws: BTreeMap<SerializableNodeId, BTreeMap<SerializableNodeId, SerializableDecimal>>,
+    #[typeshare(
+        serialized_as = "HashMap<SerializableNodeId, HashMap<SerializableNodeId, SerializableDecimal>>"
+    )]
+    pub deposits: BTreeMap<SerializableNodeId, BTreeMap<SerializableNodeId, SerializableDecimal>>,
+}
+
+pub struct ExecutionExtractAccountResourceMovements;
+impl<'f> Function<'f> for ExecutionExtractAccountResourceMovements {
+    type Input = ExecutionExtractAccountResourceMovementsInput;
+    type Output = ExecutionExtractAccountResourceMovementsOutput;
+
+    fn handle(
+        ExecutionExtractAccountResourceMovementsInput {
+            network_id,
+            preview_receipt,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let receipt =
+            scrypto_decode::<VersionedTransactionReceipt>(&preview_receipt).map_err(|error| {
+                InvocationHandlingError::DecodeError(
+                    debug_string(error),
+                    debug_string(preview_receipt),
+                )
+            })?;
+
+        let AccountResourceMovements {
+            withdraws,
+            deposits,
+        } = ExecutionAnalysisTransactionReceipt::new(&receipt)
+            .map(|receipt| {
+                radix_engine_toolkit_core::functions::execution::account_resource_movements_from_events(
+                    &receipt,
+                )
+            })
+            .map_err(|error| InvocationHandlingError::ExecutionModuleError(debug_string(error)))?;
+
+        let to_serializable =
+            |movements: HashMap<ComponentAddress, HashMap<ResourceAddress, Decimal>>| {
+                movements
+                    .into_iter()
+                    .map(|(account, amounts)| {
+                        (
+                            SerializableNodeId::new(account.into_node_id(), *network_id),
+                            amounts
+                                .into_iter()
+                                .map(|(resource_address, amount)| {
+                                    (
+                                        SerializableNodeId::new(
+                                            resource_address.into_node_id(),
+                                            *network_id,
+                                        ),
+                                        amount.into(),
+                                    )
+                                })
+                                .collect(),
+                        )
+                    })
+                    .collect()
+            };
+
+        Ok(Self::Output {
+            withdraws: to_serializable(withdraws),
+            deposits: to_serializable(deposits),
+        })
+    }
+}
+
+export_function!(
+    ExecutionExtractAccountResourceMovements as execution_extract_account_resource_movements
+);
+export_jni_function!(
+    ExecutionExtractAccountResourceMovements as executionExtractAccountResourceMovements
+);
+
+//=============================================
+// Execution Minimum XRD Required Per Account
+//=============================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionMinimumXrdRequiredPerAccountInput {
+    pub instructions: SerializableInstructions,
+    pub network_id: SerializableU8,
+    pub preview_receipt: SerializableBytes,
+}
+
+/// The minimum XRD balance that each account must hold for the transaction to go through. Accounts
+/// that do not need any XRD are omitted.
+#[typeshare::typeshare]
+pub type ExecutionMinimumXrdRequiredPerAccountOutput =
+    IndexMap<SerializableNodeId, SerializableDecimal>;
+
+pub struct ExecutionMinimumXrdRequiredPerAccount;
+impl<'f> Function<'f> for ExecutionMinimumXrdRequiredPerAccount {
+    type Input = ExecutionMinimumXrdRequiredPerAccountInput;
+    type Output = ExecutionMinimumXrdRequiredPerAccountOutput;
+
+    fn handle(
+        ExecutionMinimumXrdRequiredPerAccountInput {
+            instructions,
+            network_id,
+            preview_receipt,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let instructions = instructions.to_instructions(*network_id)?;
+        let receipt =
+            scrypto_decode::<VersionedTransactionReceipt>(&preview_receipt).map_err(|error| {
+                InvocationHandlingError::DecodeError(
+                    debug_string(error),
+                    debug_string(preview_receipt),
+                )
+            })?;
+
+        let minimum_xrd_required = ExecutionAnalysisTransactionReceipt::new(&receipt)
+            .map(|receipt| {
+                radix_engine_toolkit_core::functions::execution::minimum_xrd_required_per_account(
+                    &instructions,
+                    &receipt,
+                )
+            })
+            .map_err(|error| InvocationHandlingError::ExecutionModuleError(debug_string(error)))?;
+
+        Ok(minimum_xrd_required
+            .into_iter()
+            .map(|(account, amount)| {
+                (
+                    SerializableNodeId::new(account.into_node_id(), *network_id),
+                    amount.into(),
+                )
+            })
+            .collect())
+    }
+}
+
+export_function!(
+    ExecutionMinimumXrdRequiredPerAccount as execution_minimum_xrd_required_per_account
+);
+export_jni_function!(
+    ExecutionMinimumXrdRequiredPerAccount as executionMinimumXrdRequiredPerAccount
+);
+
+//================
+// Execution Lint
+//================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionLintInput {
+    pub network_id: SerializableU8,
+    pub preview_receipt: SerializableBytes,
+    pub owned_accounts: Vec<SerializableNodeId>,
+    pub known_counterparties: Vec<SerializableNodeId>,
+    pub dust_threshold: SerializableDecimal,
+    pub look_alike_leading_characters: SerializableU32,
+    pub look_alike_trailing_characters: SerializableU32,
+}
+
+#[typeshare::typeshare]
+pub type ExecutionLintOutput = Vec<SerializableLintFinding>;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableLintFinding {
+    AddressPoisoning {
+        sender: SerializableNodeId,
+        resembled_counterparty: SerializableNodeId,
+        recipients: Vec<SerializableNodeId>,
+    },
+}
+
+pub struct ExecutionLint;
+impl<'f> Function<'f> for ExecutionLint {
+    type Input = ExecutionLintInput;
+    type Output = ExecutionLintOutput;
+
+    fn handle(
+        ExecutionLintInput {
+            network_id,
+            preview_receipt,
+            owned_accounts,
+            known_counterparties,
+            dust_threshold,
+            look_alike_leading_characters,
+            look_alike_trailing_characters,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let to_component_addresses = |addresses: Vec<SerializableNodeId>| {
+            addresses
+                .into_iter()
+                .map(|address| {
+                    ComponentAddress::try_from(address)
+                        .map_err(|_| InvocationHandlingError::InvalidAddress(address.0.to_string()))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        };
+        let configuration = LintConfiguration {
+            network_id: *network_id,
+            owned_accounts: to_component_addresses(owned_accounts)?,
+            known_counterparties: to_component_addresses(known_counterparties)?,
+            dust_threshold: *dust_threshold,
+            look_alike_leading_characters: *look_alike_leading_characters as usize,
+            look_alike_trailing_characters: *look_alike_trailing_characters as usize,
+        };
+
+        let receipt =
+            scrypto_decode::<VersionedTransactionReceipt>(&preview_receipt).map_err(|error| {
+                InvocationHandlingError::DecodeError(
+                    debug_string(error),
+                    debug_string(preview_receipt),
+                )
+            })?;
+        let findings = ExecutionAnalysisTransactionReceipt::new(&receipt)
+            .map(|receipt| {
+                radix_engine_toolkit_core::functions::lint::lint(&receipt, &configuration)
+            })
+            .map_err(|error| InvocationHandlingError::ExecutionModuleError(debug_string(error)))?;
+
+        let to_serializable = |address: ComponentAddress| {
+            SerializableNodeId::new(address.into_node_id(), *network_id)
+        };
+        Ok(findings
+            .into_iter()
+            .map(|finding| match finding {
+                LintFinding::AddressPoisoning(AddressPoisoningFinding {
+                    sender,
+                    resembled_counterparty,
+                    recipients,
+                }) => SerializableLintFinding::AddressPoisoning {
+                    sender: to_serializable(sender),
+                    resembled_counterparty: to_serializable(resembled_counterparty),
+                    recipients: recipients.into_iter().map(to_serializable).collect(),
+                },
+            })
+            .collect())
+    }
+}
+
+export_function!(ExecutionLint as execution_lint);
+export_jni_function!(ExecutionLint as executionLint);
+
 #[typeshare::typeshare]
 #[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
 #[serde(tag = "kind", content = "value")]
@@ -114,6 +549,10 @@ pub enum SerializableTransactionType {
     Stake(Box<SerializableStakeTransactionType>),
     Unstake(Box<SerializableUnstakeTransactionType>),
     ClaimStake(Box<SerializableClaimStakeTransactionType>),
+    OwnerKeysUpdate(Box<SerializableOwnerKeysUpdateTransactionType>),
+    EntityControlChanges(Box<SerializableEntityControlChangesTransactionType>),
+    RoyaltyClaim(Box<SerializableRoyaltyClaimTransactionType>),
+    Swap(Box<SerializableSwapTransactionType>),
     GeneralTransaction(Box<SerializableGeneralTransactionType>),
 }
 
@@ -198,6 +637,26 @@ impl SerializableTransactionType {
                                 )
                             })
                             .collect(),
+                        account_created_proofs: general_transaction
+                            .account_created_proofs
+                            .into_iter()
+                            .map(|(key, value)| {
+                                (
+                                    SerializableNodeId::new(key.into_node_id(), network_id),
+                                    value
+                                        .into_iter()
+                                        .map(|value| {
+                                            SerializableResourceSpecifier::new(value, network_id)
+                                        })
+                                        .collect(),
+                                )
+                            })
+                            .collect(),
+                        presented_proofs: general_transaction
+                            .presented_proofs
+                            .into_iter()
+                            .map(|value| SerializablePresentedProof::new(value, network_id))
+                            .collect(),
                         addresses_in_manifest: InstructionsExtractAddressesOutput {
                             addresses: transform_addresses_set_to_map(
                                 general_transaction.addresses_in_manifest.0,
@@ -391,6 +850,83 @@ impl SerializableTransactionType {
                     },
                 ))
             }
+            TransactionType::OwnerKeysUpdate(owner_keys_update_transaction) => {
+                SerializableTransactionType::OwnerKeysUpdate(Box::new(
+                    SerializableOwnerKeysUpdateTransactionType {
+                        owner_keys_changes: owner_keys_update_transaction
+                            .owner_keys_changes
+                            .into_iter()
+                            .map(|(address, actions)| {
+                                (
+                                    SerializableNodeId::new(address.into_node_id(), network_id),
+                                    actions.into_iter().map(Into::into).collect(),
+                                )
+                            })
+                            .collect(),
+                    },
+                ))
+            }
+            TransactionType::EntityControlChanges(entity_control_changes_transaction) => {
+                SerializableTransactionType::EntityControlChanges(Box::new(
+                    SerializableEntityControlChangesTransactionType {
+                        entity_control_changes: entity_control_changes_transaction
+                            .entity_control_changes
+                            .into_iter()
+                            .map(|(address, changes)| {
+                                (
+                                    SerializableNodeId::new(address.into_node_id(), network_id),
+                                    changes
+                                        .into_iter()
+                                        .map(|change| {
+                                            SerializableEntityControlChange::new(change, network_id)
+                                        })
+                                        .collect(),
+                                )
+                            })
+                            .collect(),
+                    },
+                ))
+            }
+            TransactionType::RoyaltyClaim(royalty_claim_transaction) => {
+                SerializableTransactionType::RoyaltyClaim(Box::new(
+                    SerializableRoyaltyClaimTransactionType {
+                        claims: royalty_claim_transaction
+                            .claims
+                            .into_iter()
+                            .map(
+                                |RoyaltyClaim {
+                                     instruction_index,
+                                     claimed_from,
+                                     claimed_xrd,
+                                 }| SerializableRoyaltyClaim {
+                                    instruction_index: (instruction_index as u64).into(),
+                                    claimed_from: SerializableNodeId::new(
+                                        claimed_from.into_node_id(),
+                                        network_id,
+                                    ),
+                                    claimed_xrd: claimed_xrd.into(),
+                                },
+                            )
+                            .collect(),
+                        destinations: royalty_claim_transaction
+                            .destinations
+                            .into_iter()
+                            .map(|address| {
+                                SerializableNodeId::new(address.into_node_id(), network_id)
+                            })
+                            .collect(),
+                    },
+                ))
+            }
+            TransactionType::Swap(swap_transaction) => {
+                SerializableTransactionType::Swap(Box::new(SerializableSwapTransactionType {
+                    swaps: swap_transaction
+                        .swaps
+                        .into_iter()
+                        .map(|swap| SerializableSwap::new(swap, network_id))
+                        .collect(),
+                }))
+            }
         }
     }
 }
@@ -402,15 +938,43 @@ pub struct SerializableFeeSummary {
     pub finalization_cost: SerializableDecimal,
     pub storage_expansion_cost: SerializableDecimal,
     pub royalty_cost: SerializableDecimal,
+    pub fee_payers: SerializableFeePayers,
 }
 
-impl From<FeeSummary> for SerializableFeeSummary {
-    fn from(value: FeeSummary) -> Self {
+impl SerializableFeeSummary {
+    pub fn new(value: FeeSummary, network_id: u8) -> Self {
         Self {
             execution_cost: value.execution_cost.into(),
             finalization_cost: value.finalization_cost.into(),
             storage_expansion_cost: value.storage_expansion_cost.into(),
             royalty_cost: value.royalty_cost.into(),
+            fee_payers: SerializableFeePayers::new(value.fee_payers, network_id),
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableFeePayers {
+    None,
+    Single(SerializableNodeId),
+    Multiple(Vec<SerializableNodeId>),
+}
+
+impl SerializableFeePayers {
+    pub fn new(value: FeePayers, network_id: u8) -> Self {
+        match value {
+            FeePayers::None => Self::None,
+            FeePayers::Single(account) => {
+                Self::Single(SerializableNodeId::new(account.into_node_id(), network_id))
+            }
+            FeePayers::Multiple(accounts) => Self::Multiple(
+                accounts
+                    .into_iter()
+                    .map(|account| SerializableNodeId::new(account.into_node_id(), network_id))
+                    .collect(),
+            ),
         }
     }
 }
@@ -442,7 +1006,7 @@ pub enum SerializableResourceSpecifier {
     Ids {
         resource_address: SerializableNodeId,
         #[typeshare(serialized_as = "Vec<SerializableNonFungibleLocalId>")]
-        ids: HashSet<SerializableNonFungibleLocalId>,
+        ids: BTreeSet<SerializableNonFungibleLocalId>,
     },
 }
 
@@ -467,6 +1031,31 @@ impl SerializableResourceSpecifier {
     }
 }
 
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SerializablePresentedProof {
+    pub instruction_index: SerializableU64,
+    pub component_address: SerializableNodeId,
+    pub method_name: String,
+    pub account: SerializableNodeId,
+    pub proof: SerializableResourceSpecifier,
+}
+
+impl SerializablePresentedProof {
+    pub fn new(presented_proof: PresentedProof, network_id: u8) -> Self {
+        Self {
+            instruction_index: (presented_proof.instruction_index as u64).into(),
+            component_address: SerializableNodeId::new(
+                presented_proof.component_address.into_node_id(),
+                network_id,
+            ),
+            method_name: presented_proof.method_name,
+            account: SerializableNodeId::new(presented_proof.account.into_node_id(), network_id),
+            proof: SerializableResourceSpecifier::new(presented_proof.proof, network_id),
+        }
+    }
+}
+
 #[typeshare::typeshare]
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "kind", content = "value")]
@@ -474,7 +1063,7 @@ pub enum SerializableResources {
     Amount(SerializableDecimal),
     Ids(
         #[typeshare(serialized_as = "Vec<SerializableNonFungibleLocalId>")]
-        HashSet<SerializableNonFungibleLocalId>,
+        BTreeSet<SerializableNonFungibleLocalId>,
     ),
 }
 
@@ -499,19 +1088,30 @@ pub struct SerializableSimpleTransferTransactionType {
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct SerializableTransferTransactionType {
     pub from: SerializableNodeId,
-    pub transfers: HashMap<SerializableNodeId, HashMap<SerializableNodeId, SerializableResources>>,
+    #[typeshare(
+        serialized_as = "HashMap<SerializableNodeId, HashMap<SerializableNodeId, SerializableResources>>"
+    )]
+    pub transfers:
+        BTreeMap<SerializableNodeId, BTreeMap<SerializableNodeId, SerializableResources>>,
 }
 
 #[typeshare::typeshare]
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct SerializableAccountDepositSettingsTransactionType {
-    pub resource_preference_changes: HashMap<
+    #[typeshare(
+        serialized_as = "HashMap<SerializableNodeId, HashMap<SerializableNodeId, SerializableResourcePreferenceAction>>"
+    )]
+    pub resource_preference_changes: BTreeMap<
         SerializableNodeId,
-        HashMap<SerializableNodeId, SerializableResourcePreferenceAction>,
+        BTreeMap<SerializableNodeId, SerializableResourcePreferenceAction>,
     >,
-    pub default_deposit_rule_changes: HashMap<SerializableNodeId, SerializableDefaultDepositRule>,
+    #[typeshare(serialized_as = "HashMap<SerializableNodeId, SerializableDefaultDepositRule>")]
+    pub default_deposit_rule_changes: BTreeMap<SerializableNodeId, SerializableDefaultDepositRule>,
+    #[typeshare(
+        serialized_as = "HashMap<SerializableNodeId, SerializableAuthorizedDepositorsChanges>"
+    )]
     pub authorized_depositors_changes:
-        HashMap<SerializableNodeId, SerializableAuthorizedDepositorsChanges>,
+        BTreeMap<SerializableNodeId, SerializableAuthorizedDepositorsChanges>,
 }
 
 #[typeshare::typeshare]
@@ -534,34 +1134,191 @@ pub struct SerializableClaimStakeTransactionType {
 
 #[typeshare::typeshare]
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
-pub struct SerializableStakeInformation {
-    pub from_account: SerializableNodeId,
-    pub validator_address: SerializableNodeId,
-    pub stake_unit_resource: SerializableNodeId,
-    pub stake_unit_amount: SerializableDecimal,
-    pub staked_xrd: SerializableDecimal,
+pub struct SerializableOwnerKeysUpdateTransactionType {
+    #[typeshare(serialized_as = "HashMap<SerializableNodeId, Vec<SerializableOwnerKeysAction>>")]
+    pub owner_keys_changes: BTreeMap<SerializableNodeId, Vec<SerializableOwnerKeysAction>>,
 }
 
 #[typeshare::typeshare]
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
-pub struct SerializableUnstakeInformation {
-    pub from_account: SerializableNodeId,
-    pub stake_unit_address: SerializableNodeId,
-    pub stake_unit_amount: SerializableDecimal,
-    pub validator_address: SerializableNodeId,
-    pub claim_nft_resource: SerializableNodeId,
-    pub claim_nft_local_id: SerializableNonFungibleLocalId,
-    pub claim_nft_data: SerializableUnstakeData,
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableOwnerKeysAction {
+    Set(Vec<SerializablePublicKeyHash>),
+    Remove,
+    Lock,
 }
 
-#[typeshare::typeshare]
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
-pub struct SerializableClaimStakeInformation {
-    pub from_account: SerializableNodeId,
-    pub validator_address: SerializableNodeId,
+impl From<OwnerKeysAction> for SerializableOwnerKeysAction {
+    fn from(value: OwnerKeysAction) -> Self {
+        match value {
+            OwnerKeysAction::Set(public_key_hashes) => {
+                Self::Set(public_key_hashes.into_iter().map(Into::into).collect())
+            }
+            OwnerKeysAction::Remove => Self::Remove,
+            OwnerKeysAction::Lock => Self::Lock,
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SerializableEntityControlChangesTransactionType {
+    #[typeshare(
+        serialized_as = "HashMap<SerializableNodeId, Vec<SerializableEntityControlChange>>"
+    )]
+    pub entity_control_changes: BTreeMap<SerializableNodeId, Vec<SerializableEntityControlChange>>,
+}
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableEntityControlChange {
+    MetadataLocked {
+        key: String,
+    },
+    RoleSet {
+        module: SerializableObjectModuleId,
+        role_key: String,
+        rule: SerializableManifestValue,
+    },
+    OwnerRoleSet {
+        rule: SerializableManifestValue,
+    },
+    OwnerRoleLocked,
+}
+
+impl SerializableEntityControlChange {
+    pub fn new(change: EntityControlChange, network_id: u8) -> Self {
+        let rule = |rule: AccessRule| {
+            SerializableManifestValue::from_typed(&rule, network_id)
+                .expect("Access rules are always representable as manifest values")
+        };
+        match change {
+            EntityControlChange::MetadataLocked { key } => Self::MetadataLocked { key },
+            EntityControlChange::RoleSet {
+                module,
+                role_key,
+                rule: access_rule,
+            } => Self::RoleSet {
+                module: module.into(),
+                role_key: role_key.key,
+                rule: rule(access_rule),
+            },
+            EntityControlChange::OwnerRoleSet { rule: access_rule } => Self::OwnerRoleSet {
+                rule: rule(access_rule),
+            },
+            EntityControlChange::OwnerRoleLocked => Self::OwnerRoleLocked,
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SerializableRoyaltyClaimTransactionType {
+    pub claims: Vec<SerializableRoyaltyClaim>,
+    pub destinations: Vec<SerializableNodeId>,
+}
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SerializableRoyaltyClaim {
+    pub instruction_index: SerializableU64,
+    pub claimed_from: SerializableNodeId,
+    pub claimed_xrd: SerializableDecimal,
+}
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SerializableSwapTransactionType {
+    pub swaps: Vec<SerializableSwap>,
+}
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SerializableSwap {
+    pub instruction_index: SerializableU64,
+    pub venue: String,
+    pub component_address: SerializableNodeId,
+    pub method_name: String,
+    pub input_resource: SerializableNodeId,
+    pub input_amount: SerializableDecimal,
+    pub output_resource: SerializableNodeId,
+    pub output_amount: SerializableDecimal,
+    pub minimum_output_amount: Option<SerializableDecimal>,
+}
+
+impl SerializableSwap {
+    pub fn new(swap: Swap, network_id: u8) -> Self {
+        Self {
+            instruction_index: (swap.instruction_index as u64).into(),
+            venue: swap.venue,
+            component_address: SerializableNodeId::new(
+                swap.component_address.into_node_id(),
+                network_id,
+            ),
+            method_name: swap.method_name,
+            input_resource: SerializableNodeId::new(swap.input_resource.into_node_id(), network_id),
+            input_amount: swap.input_amount.into(),
+            output_resource: SerializableNodeId::new(
+                swap.output_resource.into_node_id(),
+                network_id,
+            ),
+            output_amount: swap.output_amount.into(),
+            minimum_output_amount: swap.minimum_output_amount.map(Into::into),
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum SerializableObjectModuleId {
+    Main,
+    Metadata,
+    Royalty,
+    RoleAssignment,
+}
+
+impl From<ObjectModuleId> for SerializableObjectModuleId {
+    fn from(value: ObjectModuleId) -> Self {
+        match value {
+            ObjectModuleId::Main => Self::Main,
+            ObjectModuleId::Metadata => Self::Metadata,
+            ObjectModuleId::Royalty => Self::Royalty,
+            ObjectModuleId::RoleAssignment => Self::RoleAssignment,
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SerializableStakeInformation {
+    pub from_account: SerializableNodeId,
+    pub validator_address: SerializableNodeId,
+    pub stake_unit_resource: SerializableNodeId,
+    pub stake_unit_amount: SerializableDecimal,
+    pub staked_xrd: SerializableDecimal,
+}
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SerializableUnstakeInformation {
+    pub from_account: SerializableNodeId,
+    pub stake_unit_address: SerializableNodeId,
+    pub stake_unit_amount: SerializableDecimal,
+    pub validator_address: SerializableNodeId,
+    pub claim_nft_resource: SerializableNodeId,
+    pub claim_nft_local_id: SerializableNonFungibleLocalId,
+    pub claim_nft_data: SerializableUnstakeData,
+}
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SerializableClaimStakeInformation {
+    pub from_account: SerializableNodeId,
+    pub validator_address: SerializableNodeId,
     pub claim_nft_resource: SerializableNodeId,
     #[typeshare(serialized_as = "Vec<SerializableNodeId>")]
-    pub claim_nft_local_ids: HashSet<SerializableNonFungibleLocalId>,
+    pub claim_nft_local_ids: BTreeSet<SerializableNonFungibleLocalId>,
     pub claimed_xrd: SerializableDecimal,
 }
 
@@ -573,18 +1330,31 @@ pub struct SerializableUnstakeData {
     pub claim_amount: SerializableDecimal,
 }
 
+/// All of the maps and sets of the general transaction type are ordered by their keys, which makes
+/// its serialization deterministic.
 #[typeshare::typeshare]
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct SerializableGeneralTransactionType {
     #[typeshare(serialized_as = "Vec<SerializableNodeId>")]
-    pub account_proofs: HashSet<SerializableNodeId>,
-    pub account_withdraws: HashMap<SerializableNodeId, Vec<SerializableResourceTracker>>,
-    pub account_deposits: HashMap<SerializableNodeId, Vec<SerializableResourceTracker>>,
+    pub account_proofs: BTreeSet<SerializableNodeId>,
+    #[typeshare(serialized_as = "HashMap<SerializableNodeId, Vec<SerializableResourceTracker>>")]
+    pub account_withdraws: BTreeMap<SerializableNodeId, Vec<SerializableResourceTracker>>,
+    #[typeshare(serialized_as = "HashMap<SerializableNodeId, Vec<SerializableResourceTracker>>")]
+    pub account_deposits: BTreeMap<SerializableNodeId, Vec<SerializableResourceTracker>>,
+    #[typeshare(serialized_as = "HashMap<SerializableNodeId, Vec<SerializableResourceSpecifier>>")]
+    pub account_created_proofs: BTreeMap<SerializableNodeId, Vec<SerializableResourceSpecifier>>,
+    pub presented_proofs: Vec<SerializablePresentedProof>,
     pub addresses_in_manifest: InstructionsExtractAddressesOutput,
+    #[typeshare(
+        serialized_as = "HashMap<SerializableNodeId, HashMap<String, Option<SerializableMetadataValue>>>"
+    )]
     pub metadata_of_newly_created_entities:
-        HashMap<SerializableNodeId, HashMap<String, Option<SerializableMetadataValue>>>,
+        BTreeMap<SerializableNodeId, BTreeMap<String, Option<SerializableMetadataValue>>>,
+    #[typeshare(
+        serialized_as = "HashMap<SerializableNodeId, HashMap<SerializableNonFungibleLocalId, SerializableBytes>>"
+    )]
     pub data_of_newly_minted_non_fungibles:
-        HashMap<SerializableNodeId, HashMap<SerializableNonFungibleLocalId, SerializableBytes>>,
+        BTreeMap<SerializableNodeId, BTreeMap<SerializableNonFungibleLocalId, SerializableBytes>>,
 }
 
 #[typeshare::typeshare]
@@ -839,6 +1609,74 @@ impl SerializableMetadataValue {
             }
         }
     }
+
+    pub fn to_native(&self) -> Result<MetadataValue, InvocationHandlingError> {
+        let global_address = |node_id: &SerializableNodeId| {
+            GlobalAddress::try_from(node_id.clone())
+                .map_err(|_| InvocationHandlingError::InvalidAddress(node_id.0.to_string()))
+        };
+        let non_fungible_global_id =
+            |id: &SerializableNonFungibleGlobalId| id.0.non_fungible_global_id.clone();
+
+        let value = match self {
+            Self::String(value) => MetadataValue::String(value.clone()),
+            Self::Bool(value) => MetadataValue::Bool(*value),
+            Self::U8(value) => MetadataValue::U8(**value),
+            Self::U32(value) => MetadataValue::U32(**value),
+            Self::U64(value) => MetadataValue::U64(**value),
+            Self::I32(value) => MetadataValue::I32(**value),
+            Self::I64(value) => MetadataValue::I64(**value),
+            Self::Decimal(value) => MetadataValue::Decimal(**value),
+            Self::GlobalAddress(value) => MetadataValue::GlobalAddress(global_address(value)?),
+            Self::PublicKey(value) => MetadataValue::PublicKey(value.clone().into()),
+            Self::NonFungibleGlobalId(value) => {
+                MetadataValue::NonFungibleGlobalId(non_fungible_global_id(value))
+            }
+            Self::NonFungibleLocalId(value) => {
+                MetadataValue::NonFungibleLocalId(value.deref().clone())
+            }
+            Self::Instant(value) => MetadataValue::Instant(Instant::new(**value)),
+            Self::Url(value) => MetadataValue::Url(UncheckedUrl(value.clone())),
+            Self::Origin(value) => MetadataValue::Origin(UncheckedOrigin(value.clone())),
+            Self::PublicKeyHash(value) => MetadataValue::PublicKeyHash(value.clone().into()),
+
+            Self::StringArray(value) => MetadataValue::StringArray(value.clone()),
+            Self::BoolArray(value) => MetadataValue::BoolArray(value.clone()),
+            Self::U8Array(value) => MetadataValue::U8Array(value.iter().map(|v| **v).collect()),
+            Self::U32Array(value) => MetadataValue::U32Array(value.iter().map(|v| **v).collect()),
+            Self::U64Array(value) => MetadataValue::U64Array(value.iter().map(|v| **v).collect()),
+            Self::I32Array(value) => MetadataValue::I32Array(value.iter().map(|v| **v).collect()),
+            Self::I64Array(value) => MetadataValue::I64Array(value.iter().map(|v| **v).collect()),
+            Self::DecimalArray(value) => {
+                MetadataValue::DecimalArray(value.iter().map(|v| **v).collect())
+            }
+            Self::GlobalAddressArray(value) => MetadataValue::GlobalAddressArray(
+                value.iter().map(global_address).collect::<Result<_, _>>()?,
+            ),
+            Self::PublicKeyArray(value) => {
+                MetadataValue::PublicKeyArray(value.iter().cloned().map(Into::into).collect())
+            }
+            Self::NonFungibleGlobalIdArray(value) => MetadataValue::NonFungibleGlobalIdArray(
+                value.iter().map(non_fungible_global_id).collect(),
+            ),
+            Self::NonFungibleLocalIdArray(value) => MetadataValue::NonFungibleLocalIdArray(
+                value.iter().map(|id| id.deref().clone()).collect(),
+            ),
+            Self::InstantArray(value) => MetadataValue::InstantArray(
+                value.iter().map(|value| Instant::new(**value)).collect(),
+            ),
+            Self::UrlArray(value) => {
+                MetadataValue::UrlArray(value.iter().cloned().map(UncheckedUrl).collect())
+            }
+            Self::OriginArray(value) => {
+                MetadataValue::OriginArray(value.iter().cloned().map(UncheckedOrigin).collect())
+            }
+            Self::PublicKeyHashArray(value) => {
+                MetadataValue::PublicKeyHashArray(value.iter().cloned().map(Into::into).collect())
+            }
+        };
+        Ok(value)
+    }
 }
 
 #[typeshare::typeshare]
@@ -916,7 +1754,7 @@ impl SerializableResourceTracker {
 }
 
 #[typeshare::typeshare]
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, JsonSchema)]
 pub enum SerializableReservedInstruction {
     AccountLockFee,
     AccountSecurify,
@@ -949,9 +1787,505 @@ impl From<ReservedInstruction> for SerializableReservedInstruction {
     }
 }
 
+#[typeshare::typeshare]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SerializableReservedInstructionUsage {
+    pub instruction_index: SerializableU64,
+    pub address: SerializableManifestAddress,
+    pub reserved_instruction: SerializableReservedInstruction,
+    pub arguments: SerializableReservedInstructionArguments,
+}
+
+impl SerializableReservedInstructionUsage {
+    pub fn new(value: ReservedInstructionUsage, network_id: u8) -> Self {
+        Self {
+            instruction_index: (value.instruction_index as u64).into(),
+            address: match value.address {
+                DynamicGlobalAddress::Named(named) => {
+                    SerializableManifestAddress::Named(SerializableU32::from(named))
+                }
+                DynamicGlobalAddress::Static(global_address) => {
+                    SerializableManifestAddress::Static(SerializableNodeId::new(
+                        global_address.into_node_id(),
+                        network_id,
+                    ))
+                }
+            },
+            reserved_instruction: value.reserved_instruction.into(),
+            arguments: SerializableReservedInstructionArguments::new(value.arguments, network_id),
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableReservedInstructionArguments {
+    AccountLockFee {
+        amount: SerializableDecimal,
+        contingent: bool,
+    },
+    AccountLockFeeAndWithdraw {
+        amount_to_lock: SerializableDecimal,
+        resource_address: SerializableNodeId,
+        amount: SerializableDecimal,
+    },
+    AccountLockFeeAndWithdrawNonFungibles {
+        amount_to_lock: SerializableDecimal,
+        resource_address: SerializableNodeId,
+        ids: Vec<SerializableNonFungibleLocalId>,
+    },
+    Securify,
+    AccountAddAuthorizedDepositor {
+        badge: SerializableResourceOrNonFungible,
+    },
+    AccountRemoveAuthorizedDepositor {
+        badge: SerializableResourceOrNonFungible,
+    },
+    AccountSetDefaultDepositRule {
+        default_deposit_rule: SerializableDefaultDepositRule,
+    },
+    AccountSetResourcePreference {
+        resource_address: SerializableNodeId,
+        resource_preference: SerializableResourcePreference,
+    },
+    AccountRemoveResourcePreference {
+        resource_address: SerializableNodeId,
+    },
+    AccessControllerMethod {
+        method_name: String,
+    },
+    Undecodable {
+        method_name: String,
+    },
+}
+
+impl SerializableReservedInstructionArguments {
+    pub fn new(value: ReservedInstructionArguments, network_id: u8) -> Self {
+        match value {
+            ReservedInstructionArguments::AccountLockFee { amount, contingent } => {
+                Self::AccountLockFee {
+                    amount: amount.into(),
+                    contingent,
+                }
+            }
+            ReservedInstructionArguments::AccountLockFeeAndWithdraw {
+                amount_to_lock,
+                resource_address,
+                amount,
+            } => Self::AccountLockFeeAndWithdraw {
+                amount_to_lock: amount_to_lock.into(),
+                resource_address: SerializableNodeId::new(
+                    resource_address.into_node_id(),
+                    network_id,
+                ),
+                amount: amount.into(),
+            },
+            ReservedInstructionArguments::AccountLockFeeAndWithdrawNonFungibles {
+                amount_to_lock,
+                resource_address,
+                ids,
+            } => Self::AccountLockFeeAndWithdrawNonFungibles {
+                amount_to_lock: amount_to_lock.into(),
+                resource_address: SerializableNodeId::new(
+                    resource_address.into_node_id(),
+                    network_id,
+                ),
+                ids: ids.into_iter().map(Into::into).collect(),
+            },
+            ReservedInstructionArguments::Securify => Self::Securify,
+            ReservedInstructionArguments::AccountAddAuthorizedDepositor { badge } => {
+                Self::AccountAddAuthorizedDepositor {
+                    badge: SerializableResourceOrNonFungible::new(badge, network_id),
+                }
+            }
+            ReservedInstructionArguments::AccountRemoveAuthorizedDepositor { badge } => {
+                Self::AccountRemoveAuthorizedDepositor {
+                    badge: SerializableResourceOrNonFungible::new(badge, network_id),
+                }
+            }
+            ReservedInstructionArguments::AccountSetDefaultDepositRule {
+                default_deposit_rule,
+            } => Self::AccountSetDefaultDepositRule {
+                default_deposit_rule: default_deposit_rule.into(),
+            },
+            ReservedInstructionArguments::AccountSetResourcePreference {
+                resource_address,
+                resource_preference,
+            } => Self::AccountSetResourcePreference {
+                resource_address: SerializableNodeId::new(
+                    resource_address.into_node_id(),
+                    network_id,
+                ),
+                resource_preference: resource_preference.into(),
+            },
+            ReservedInstructionArguments::AccountRemoveResourcePreference { resource_address } => {
+                Self::AccountRemoveResourcePreference {
+                    resource_address: SerializableNodeId::new(
+                        resource_address.into_node_id(),
+                        network_id,
+                    ),
+                }
+            }
+            ReservedInstructionArguments::AccessControllerMethod { method_name } => {
+                Self::AccessControllerMethod { method_name }
+            }
+            ReservedInstructionArguments::Undecodable { method_name } => {
+                Self::Undecodable { method_name }
+            }
+        }
+    }
+}
+
 macro_rules! array_into {
     ($array: expr) => {
         $array.into_iter().map(Into::into).collect()
     };
 }
 use array_into;
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SerializableInstructionBadgeRequirements {
+    pub instruction_index: SerializableU64,
+    pub proofs_created: Vec<SerializableCreatedProof>,
+    pub required_badges: Vec<SerializableRequiredBadge>,
+}
+
+impl SerializableInstructionBadgeRequirements {
+    pub fn new(value: InstructionBadgeRequirements, network_id: u8) -> Self {
+        Self {
+            instruction_index: (value.instruction_index as u64).into(),
+            proofs_created: value
+                .proofs_created
+                .into_iter()
+                .map(|value| SerializableCreatedProof::new(value, network_id))
+                .collect(),
+            required_badges: value
+                .required_badges
+                .into_iter()
+                .map(|value| SerializableRequiredBadge::new(value, network_id))
+                .collect(),
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SerializableWorktopGuarantee {
+    pub instruction_index: SerializableU64,
+    pub resource_address: SerializableNodeId,
+    pub guarantee: SerializableGuaranteeKind,
+}
+
+impl SerializableWorktopGuarantee {
+    pub fn new(value: WorktopGuarantee, network_id: u8) -> Self {
+        Self {
+            instruction_index: (value.instruction_index as u64).into(),
+            resource_address: SerializableNodeId::new(
+                value.resource_address.into_node_id(),
+                network_id,
+            ),
+            guarantee: value.guarantee.into(),
+        }
+    }
+}
+
+/// A deposit into an account which presents an authorized depositor badge.
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SerializableAuthorizedDepositorBadgeUsage {
+    pub instruction_index: SerializableU64,
+    pub account: SerializableManifestAddress,
+    pub method_name: String,
+    pub badge: SerializableResourceOrNonFungible,
+}
+
+impl SerializableAuthorizedDepositorBadgeUsage {
+    pub fn new(value: AuthorizedDepositorBadgeUsage, network_id: u8) -> Self {
+        Self {
+            instruction_index: (value.instruction_index as u64).into(),
+            account: match value.account {
+                DynamicGlobalAddress::Named(named) => {
+                    SerializableManifestAddress::Named(SerializableU32::from(named))
+                }
+                DynamicGlobalAddress::Static(global_address) => {
+                    SerializableManifestAddress::Static(SerializableNodeId::new(
+                        global_address.into_node_id(),
+                        network_id,
+                    ))
+                }
+            },
+            method_name: value.method_name,
+            badge: SerializableResourceOrNonFungible::new(value.badge, network_id),
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableGuaranteeKind {
+    Amount(SerializableDecimal),
+    Any,
+    NonFungibles(Vec<SerializableNonFungibleLocalId>),
+}
+
+impl From<GuaranteeKind> for SerializableGuaranteeKind {
+    fn from(value: GuaranteeKind) -> Self {
+        match value {
+            GuaranteeKind::Amount(amount) => Self::Amount(amount.into()),
+            GuaranteeKind::Any => Self::Any,
+            GuaranteeKind::NonFungibles(ids) => {
+                Self::NonFungibles(ids.into_iter().map(Into::into).collect())
+            }
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableCreatedProof {
+    Amount {
+        resource_address: SerializableNodeId,
+        amount: SerializableDecimal,
+    },
+    NonFungibles {
+        resource_address: SerializableNodeId,
+        ids: Vec<SerializableNonFungibleLocalId>,
+    },
+    All {
+        resource_address: SerializableNodeId,
+    },
+}
+
+impl SerializableCreatedProof {
+    pub fn new(value: CreatedProof, network_id: u8) -> Self {
+        match value {
+            CreatedProof::Amount {
+                resource_address,
+                amount,
+            } => Self::Amount {
+                resource_address: SerializableNodeId::new(
+                    resource_address.into_node_id(),
+                    network_id,
+                ),
+                amount: amount.into(),
+            },
+            CreatedProof::NonFungibles {
+                resource_address,
+                ids,
+            } => Self::NonFungibles {
+                resource_address: SerializableNodeId::new(
+                    resource_address.into_node_id(),
+                    network_id,
+                ),
+                ids: ids.into_iter().map(Into::into).collect(),
+            },
+            CreatedProof::All { resource_address } => Self::All {
+                resource_address: SerializableNodeId::new(
+                    resource_address.into_node_id(),
+                    network_id,
+                ),
+            },
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SerializableRequiredBadge {
+    pub entity_address: SerializableNodeId,
+    pub method_name: String,
+    pub requirement: SerializableBadgeRequirement,
+}
+
+impl SerializableRequiredBadge {
+    pub fn new(value: RequiredBadge, network_id: u8) -> Self {
+        Self {
+            entity_address: SerializableNodeId::new(
+                value.entity_address.into_node_id(),
+                network_id,
+            ),
+            method_name: value.method_name,
+            requirement: match value.requirement {
+                BadgeRequirement::NonFungible(id) => SerializableBadgeRequirement::NonFungible(
+                    SerializableNonFungibleGlobalId::new(id, network_id),
+                ),
+                BadgeRequirement::AnyOfRoles(roles) => {
+                    SerializableBadgeRequirement::AnyOfRoles(roles)
+                }
+            },
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableBadgeRequirement {
+    NonFungible(SerializableNonFungibleGlobalId),
+    AnyOfRoles(Vec<String>),
+}
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SerializableResourceMovementDiscrepancy {
+    pub account: SerializableNodeId,
+    pub resource_address: SerializableNodeId,
+    pub direction: SerializableResourceMovementDirection,
+    pub manifest_amount: SerializableDecimal,
+    pub receipt_amount: SerializableDecimal,
+}
+
+impl SerializableResourceMovementDiscrepancy {
+    pub fn new(value: ResourceMovementDiscrepancy, network_id: u8) -> Self {
+        Self {
+            account: SerializableNodeId::new(value.account.into_node_id(), network_id),
+            resource_address: SerializableNodeId::new(
+                value.resource_address.into_node_id(),
+                network_id,
+            ),
+            direction: value.direction.into(),
+            manifest_amount: value.manifest_amount.into(),
+            receipt_amount: value.receipt_amount.into(),
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum SerializableResourceMovementDirection {
+    Withdraw,
+    Deposit,
+}
+
+impl From<ResourceMovementDirection> for SerializableResourceMovementDirection {
+    fn from(value: ResourceMovementDirection) -> Self {
+        match value {
+            ResourceMovementDirection::Withdraw => Self::Withdraw,
+            ResourceMovementDirection::Deposit => Self::Deposit,
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SerializableResourceMovementGraph {
+    pub nodes: Vec<SerializableResourceMovementGraphNode>,
+    pub edges: Vec<SerializableResourceMovementGraphEdge>,
+}
+
+impl SerializableResourceMovementGraph {
+    pub fn new(value: ResourceMovementGraph, network_id: u8) -> Self {
+        Self {
+            nodes: value
+                .nodes
+                .into_iter()
+                .map(|node| SerializableResourceMovementGraphNode::new(node, network_id))
+                .collect(),
+            edges: value
+                .edges
+                .into_iter()
+                .map(|edge| SerializableResourceMovementGraphEdge::new(edge, network_id))
+                .collect(),
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableResourceMovementGraphNode {
+    Worktop,
+    Entity {
+        address: SerializableNodeId,
+        entity_type: SerializableEntityType,
+    },
+}
+
+impl SerializableResourceMovementGraphNode {
+    pub fn new(value: ResourceMovementGraphNode, network_id: u8) -> Self {
+        match value {
+            ResourceMovementGraphNode::Worktop => Self::Worktop,
+            ResourceMovementGraphNode::Entity(node_id) => Self::Entity {
+                address: SerializableNodeId::new(*node_id.as_node_id(), network_id),
+                entity_type: node_id.entity_type().into(),
+            },
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SerializableResourceMovementGraphEdge {
+    pub instruction_index: SerializableU64,
+    pub from: SerializableU64,
+    pub to: SerializableU64,
+    pub resource_address: SerializableNodeId,
+    pub amount: SerializableDecimal,
+    pub guarantee: SerializableResourceMovementGuarantee,
+}
+
+impl SerializableResourceMovementGraphEdge {
+    pub fn new(value: ResourceMovementGraphEdge, network_id: u8) -> Self {
+        Self {
+            instruction_index: (value.instruction_index as u64).into(),
+            from: (value.from as u64).into(),
+            to: (value.to as u64).into(),
+            resource_address: SerializableNodeId::new(
+                value.resource_address.into_node_id(),
+                network_id,
+            ),
+            amount: value.amount.into(),
+            guarantee: value.guarantee.into(),
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum SerializableResourceMovementGuarantee {
+    Guaranteed,
+    Predicted,
+}
+
+impl From<ResourceMovementGuarantee> for SerializableResourceMovementGuarantee {
+    fn from(value: ResourceMovementGuarantee) -> Self {
+        match value {
+            ResourceMovementGuarantee::Guaranteed => Self::Guaranteed,
+            ResourceMovementGuarantee::Predicted => Self::Predicted,
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableAttestation {
+    /// The canonical encoding of the attested output, see [`canonical_json`].
+    pub payload: SerializableBytes,
+    pub public_key: SerializableEd25519PublicKey,
+    #[typeshare(serialized_as = "String")]
+    pub signature: AsHex<[u8; 64]>,
+}
+
+impl From<Attestation> for SerializableAttestation {
+    fn from(value: Attestation) -> Self {
+        Self {
+            payload: value.payload.into(),
+            public_key: value.public_key.into(),
+            signature: value.signature.0.into(),
+        }
+    }
+}
+
+impl From<SerializableAttestation> for Attestation {
+    fn from(value: SerializableAttestation) -> Self {
+        Self {
+            payload: (*value.payload).clone(),
+            public_key: value.public_key.into(),
+            signature: Ed25519Signature(*value.signature),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Functions over the Gateway API's JSON models, which are only compiled in with the
+//! `gateway-models` feature.
+
+use crate::prelude::*;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+//=========================================
+// Gateway Summarize Committed Transaction
+//=========================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct GatewaySummarizeCommittedTransactionInput {
+    /// The `CommittedTransactionInfo` of the transaction as returned by the Gateway. It must have
+    /// been requested with the `raw_hex` opt-in and, for the fee summary to be included, with the
+    /// `receipt_fee_summary` opt-in.
+    pub committed_transaction: GatewayCommittedTransactionInfo,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct GatewaySummarizeCommittedTransactionOutput {
+    pub transaction_status: GatewayTransactionStatus,
+    pub fee_summary: Option<SerializableFeeSummary>,
+    pub addresses_by_role: InstructionsExtractAddressesByRoleOutput,
+    pub reserved_instruction_usages: Vec<SerializableReservedInstructionUsage>,
+    pub required_badges: Vec<SerializableInstructionBadgeRequirements>,
+}
+
+pub struct GatewaySummarizeCommittedTransaction;
+impl<'f> Function<'f> for GatewaySummarizeCommittedTransaction {
+    type Input = GatewaySummarizeCommittedTransactionInput;
+    type Output = GatewaySummarizeCommittedTransactionOutput;
+
+    fn handle(
+        GatewaySummarizeCommittedTransactionInput {
+            committed_transaction,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let analysis_input =
+            GatewayAnalysisInput::try_from(committed_transaction).map_err(|error| {
+                InvocationHandlingError::GatewayModelConversionError(debug_string(error))
+            })?;
+        // The addresses in the summary are encoded for the network that the transaction was
+        // committed on.
+        let network_id = analysis_input
+            .notarized_transaction
+            .signed_intent
+            .intent
+            .header
+            .network_id;
+        let summary = analysis_input.summarize();
+
+        Ok(Self::Output {
+            transaction_status: summary.transaction_status,
+            fee_summary: summary
+                .fee_summary
+                .map(|fee_summary| SerializableFeeSummary::new(fee_summary, network_id)),
+            addresses_by_role: InstructionsExtractAddressesByRoleOutput::new(
+                summary.addresses_by_role,
+                network_id,
+            ),
+            reserved_instruction_usages: summary
+                .reserved_instruction_usages
+                .into_iter()
+                .map(|value| SerializableReservedInstructionUsage::new(value, network_id))
+                .collect(),
+            required_badges: summary
+                .required_badges
+                .into_iter()
+                .map(|value| SerializableInstructionBadgeRequirements::new(value, network_id))
+                .collect(),
+        })
+    }
+}
+
+export_function!(GatewaySummarizeCommittedTransaction as gateway_summarize_committed_transaction);
+export_jni_function!(GatewaySummarizeCommittedTransaction as gatewaySummarizeCommittedTransaction);
@@ -17,7 +17,10 @@
 
 use super::traits::Function;
 use crate::memory::Pointer;
-use crate::utils::{serialize_and_write_to_memory, serialize_to_jstring};
+use crate::utils::{
+    serialize_and_write_to_memory, serialize_to_jbyte_array, serialize_to_jstring,
+    serialize_with_mode, write_length_prefixed_to_memory, SerializationMode,
+};
 
 pub fn handle_invocation<'f, F>(input: Pointer) -> Pointer
 where
@@ -39,6 +42,74 @@ where
     }
 }
 
+/// Handles an invocation made through the `_with_options` variant of a function. The options are
+/// passed as scalars so that they can be passed by every FFI: the `context_id` is the id of the
+/// context to run the invocation in, or zero to run it without a context, and the
+/// `serialization_mode` is the [`SerializationMode`] that the output, or error, is serialized in.
+///
+/// The input is always JSON and the output is always length-prefixed, even for the JSON mode and
+/// when the serialization mode is unknown, in which case the error is serialized as JSON.
+pub fn handle_invocation_with_options<'f, F>(
+    context_id: u64,
+    serialization_mode: u8,
+    input: Pointer,
+) -> Pointer
+where
+    F: Function<'f>,
+{
+    let bytes = invoke_with_options::<F>(context_id, serialization_mode, || {
+        crate::utils::read_and_deserialize_from_memory::<F::Input>(input)
+    });
+    write_length_prefixed_to_memory(&bytes)
+}
+
+fn invoke_with_options<'f, F>(
+    context_id: u64,
+    serialization_mode: u8,
+    read_input: impl FnOnce() -> Result<F::Input, crate::error::InvocationInterpretationError>,
+) -> Vec<u8>
+where
+    F: Function<'f>,
+{
+    let serialization_mode = match SerializationMode::try_from(serialization_mode) {
+        Ok(serialization_mode) => serialization_mode,
+        Err(error) => {
+            let error = crate::error::Error::from(error);
+            return serialize_with_mode(&error, SerializationMode::Json).expect("can't fail");
+        }
+    };
+
+    let invoke = || {
+        read_input()
+            .map_err(crate::error::Error::from)
+            .and_then(|input| F::handle(input).map_err(crate::error::Error::from))
+            .and_then(|output| {
+                serialize_with_mode::<F::Output>(&output, serialization_mode)
+                    .map_err(crate::error::Error::from)
+            })
+            .or_else(|error| {
+                serialize_with_mode::<crate::error::Error>(&error, serialization_mode)
+                    .map_err(crate::error::Error::from)
+            })
+            .expect("can't fail")
+    };
+
+    match context_id {
+        0 => invoke(),
+        context_id => match crate::context::get_context(context_id) {
+            Some(context) => crate::context::with_context(context, invoke),
+            None => {
+                let error = crate::error::Error::from(
+                    crate::error::InvocationInterpretationError::ContextNotFound(
+                        context_id.to_string(),
+                    ),
+                );
+                serialize_with_mode(&error, serialization_mode).expect("can't fail")
+            }
+        },
+    }
+}
+
 pub fn handle_jni_invocation<'f, F>(
     mut env: jni::JNIEnv,
     _: jni::objects::JClass,
@@ -62,3 +133,21 @@ where
         Err(ptr) => ptr,
     }
 }
+
+/// The JNI equivalent of [`handle_invocation_with_options`]. The output is returned as a byte
+/// array since it may be in a binary serialization mode.
+pub fn handle_jni_invocation_with_options<'f, F>(
+    mut env: jni::JNIEnv,
+    _: jni::objects::JClass,
+    context_id: u64,
+    serialization_mode: u8,
+    input: jni::objects::JString,
+) -> jni::sys::jbyteArray
+where
+    F: Function<'f>,
+{
+    let bytes = invoke_with_options::<F>(context_id, serialization_mode, || {
+        crate::utils::deserialize_from_jstring::<F::Input>(&mut env, &input)
+    });
+    serialize_to_jbyte_array(&env, &bytes).expect("can't fail")
+}
@@ -0,0 +1,72 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+//==============
+// Hashing Hash
+//==============
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct HashingHashInput {
+    pub data: SerializableBytes,
+    pub algorithm: SerializableHashAlgorithm,
+}
+#[typeshare::typeshare]
+pub type HashingHashOutput = SerializableBytes;
+
+pub struct HashingHash;
+impl<'f> Function<'f> for HashingHash {
+    type Input = HashingHashInput;
+    type Output = HashingHashOutput;
+
+    fn handle(
+        HashingHashInput { data, algorithm }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let hash = match algorithm {
+            SerializableHashAlgorithm::Blake2b256 => {
+                radix_engine_toolkit_core::functions::hashing::blake2b_256(&data).0
+            }
+            SerializableHashAlgorithm::Sha256 => {
+                radix_engine_toolkit_core::functions::hashing::sha256(&data)
+            }
+            SerializableHashAlgorithm::Sha256Twice => {
+                radix_engine_toolkit_core::functions::hashing::sha256_twice(&data)
+            }
+            SerializableHashAlgorithm::Keccak256 => {
+                radix_engine_toolkit_core::functions::hashing::keccak256(&data)
+            }
+        };
+
+        Ok(hash.to_vec().into())
+    }
+}
+
+export_function!(HashingHash as hashing_hash);
+export_jni_function!(HashingHash as hashingHash);
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SerializableHashAlgorithm {
+    Blake2b256,
+    Sha256,
+    Sha256Twice,
+    Keccak256,
+}
@@ -0,0 +1,123 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+use schemars::JsonSchema;
+use scrypto::prelude::Epoch;
+use serde::{Deserialize, Serialize};
+
+//===========================================
+// Header Epoch Range From Validity Duration
+//===========================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct HeaderEpochRangeFromValidityDurationInput {
+    pub current_epoch: SerializableU64,
+    pub epoch_duration_in_seconds: SerializableU64,
+    pub validity_duration_in_seconds: SerializableU64,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct HeaderEpochRangeFromValidityDurationOutput {
+    pub start_epoch_inclusive: SerializableU64,
+    pub end_epoch_exclusive: SerializableU64,
+}
+
+pub struct HeaderEpochRangeFromValidityDuration;
+impl<'f> Function<'f> for HeaderEpochRangeFromValidityDuration {
+    type Input = HeaderEpochRangeFromValidityDurationInput;
+    type Output = HeaderEpochRangeFromValidityDurationOutput;
+
+    fn handle(
+        HeaderEpochRangeFromValidityDurationInput {
+            current_epoch,
+            epoch_duration_in_seconds,
+            validity_duration_in_seconds,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let (start_epoch_inclusive, end_epoch_exclusive) =
+            radix_engine_toolkit_core::functions::header::epoch_range_from_validity_duration(
+                Epoch::of(*current_epoch),
+                *epoch_duration_in_seconds,
+                *validity_duration_in_seconds,
+            )
+            .ok_or(InvocationHandlingError::EpochRangeError(
+                "The epoch duration is zero or the end epoch overflows".to_owned(),
+            ))?;
+
+        Ok(HeaderEpochRangeFromValidityDurationOutput {
+            start_epoch_inclusive: start_epoch_inclusive.number().into(),
+            end_epoch_exclusive: end_epoch_exclusive.number().into(),
+        })
+    }
+}
+
+export_function!(HeaderEpochRangeFromValidityDuration as header_epoch_range_from_validity_duration);
+export_jni_function!(HeaderEpochRangeFromValidityDuration as headerEpochRangeFromValidityDuration);
+
+//===========================================
+// Header Validity Duration From Epoch Range
+//===========================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct HeaderValidityDurationFromEpochRangeInput {
+    pub current_epoch: SerializableU64,
+    pub end_epoch_exclusive: SerializableU64,
+    pub epoch_duration_in_seconds: SerializableU64,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct HeaderValidityDurationFromEpochRangeOutput {
+    pub minimum_validity_duration_in_seconds: SerializableU64,
+    pub maximum_validity_duration_in_seconds: SerializableU64,
+}
+
+pub struct HeaderValidityDurationFromEpochRange;
+impl<'f> Function<'f> for HeaderValidityDurationFromEpochRange {
+    type Input = HeaderValidityDurationFromEpochRangeInput;
+    type Output = HeaderValidityDurationFromEpochRangeOutput;
+
+    fn handle(
+        HeaderValidityDurationFromEpochRangeInput {
+            current_epoch,
+            end_epoch_exclusive,
+            epoch_duration_in_seconds,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let (minimum, maximum) =
+            radix_engine_toolkit_core::functions::header::validity_duration_from_epoch_range(
+                Epoch::of(*current_epoch),
+                Epoch::of(*end_epoch_exclusive),
+                *epoch_duration_in_seconds,
+            )
+            .ok_or(InvocationHandlingError::EpochRangeError(
+                "The end epoch is not after the current epoch or the duration overflows".to_owned(),
+            ))?;
+
+        Ok(HeaderValidityDurationFromEpochRangeOutput {
+            minimum_validity_duration_in_seconds: minimum.into(),
+            maximum_validity_duration_in_seconds: maximum.into(),
+        })
+    }
+}
+
+export_function!(HeaderValidityDurationFromEpochRange as header_validity_duration_from_epoch_range);
+export_jni_function!(HeaderValidityDurationFromEpochRange as headerValidityDurationFromEpochRange);
@@ -17,6 +17,7 @@
 
 use super::macros::{export_function, export_jni_function};
 use super::traits::Function;
+use crate::prelude::*;
 use radix_engine_toolkit_core::functions::information::DependencyInformation;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -71,3 +72,505 @@ impl<'a> Function<'a> for BuildInformation {
 
 export_function!(BuildInformation as build_information);
 export_jni_function!(BuildInformation as buildInformation);
+
+//============================
+// Methods That Require Auth
+//============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct MethodsThatRequireAuthInput {}
+
+/// The methods which the static analyzers treat as requiring auth, per native blueprint or module.
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct MethodsThatRequireAuthOutput {
+    pub account: Vec<String>,
+    pub identity: Vec<String>,
+    pub validator: Vec<String>,
+    pub role_assignment: Vec<String>,
+    pub metadata: Vec<String>,
+    pub royalty: Vec<String>,
+}
+
+pub struct MethodsThatRequireAuth;
+impl<'a> Function<'a> for MethodsThatRequireAuth {
+    type Input = MethodsThatRequireAuthInput;
+    type Output = MethodsThatRequireAuthOutput;
+
+    fn handle(_: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let radix_engine_toolkit_core::functions::information::MethodsThatRequireAuth {
+            account,
+            identity,
+            validator,
+            role_assignment,
+            metadata,
+            royalty,
+        } = radix_engine_toolkit_core::functions::information::methods_that_require_auth();
+        Ok(MethodsThatRequireAuthOutput {
+            account,
+            identity,
+            validator,
+            role_assignment,
+            metadata,
+            royalty,
+        })
+    }
+}
+
+export_function!(MethodsThatRequireAuth as methods_that_require_auth);
+export_jni_function!(MethodsThatRequireAuth as methodsThatRequireAuth);
+
+//==============
+// Memory Stats
+//==============
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct MemoryStatsInput {}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct MemoryStatsOutput {
+    pub allocated_bytes: SerializableU64,
+    pub live_allocations: SerializableU64,
+    pub total_allocations: SerializableU64,
+    pub contexts: SerializableU64,
+    pub cached_network_definitions: SerializableU64,
+    pub cached_bech32_encoders: SerializableU64,
+    pub cached_bech32_decoders: SerializableU64,
+    pub cached_package_definitions: SerializableU64,
+    pub cached_encoded_addresses: SerializableU64,
+}
+
+pub struct MemoryStats;
+impl<'a> Function<'a> for MemoryStats {
+    type Input = MemoryStatsInput;
+    type Output = MemoryStatsOutput;
+
+    fn handle(_: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let allocation_statistics = crate::memory::allocation_statistics();
+        let cache_statistics = crate::context::cache_statistics();
+        Ok(MemoryStatsOutput {
+            allocated_bytes: (allocation_statistics.allocated_bytes as u64).into(),
+            live_allocations: (allocation_statistics.live_allocations as u64).into(),
+            total_allocations: (allocation_statistics.total_allocations as u64).into(),
+            contexts: (cache_statistics.contexts as u64).into(),
+            cached_network_definitions: (cache_statistics.network_definitions as u64).into(),
+            cached_bech32_encoders: (cache_statistics.bech32_encoders as u64).into(),
+            cached_bech32_decoders: (cache_statistics.bech32_decoders as u64).into(),
+            cached_package_definitions: (cache_statistics.package_definitions as u64).into(),
+            cached_encoded_addresses: (cache_statistics.encoded_addresses as u64).into(),
+        })
+    }
+}
+
+export_function!(MemoryStats as memory_stats);
+export_jni_function!(MemoryStats as memoryStats);
+
+//============
+// Free Cache
+//============
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct FreeCacheInput {}
+
+/// The number of cache entries that were evicted.
+#[typeshare::typeshare]
+pub type FreeCacheOutput = SerializableU64;
+
+pub struct FreeCache;
+impl<'a> Function<'a> for FreeCache {
+    type Input = FreeCacheInput;
+    type Output = FreeCacheOutput;
+
+    fn handle(_: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        Ok((crate::context::free_caches() as u64).into())
+    }
+}
+
+export_function!(FreeCache as free_cache);
+export_jni_function!(FreeCache as freeCache);
+
+//=========================
+// Address Cache Configure
+//=========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct AddressCacheConfigureInput {
+    /// Controls whether the Bech32m encoding of addresses is memoized.
+    pub enabled: bool,
+    /// The maximum number of encoded addresses held by the cache.
+    pub capacity: SerializableU32,
+}
+
+/// The number of cache entries that were evicted.
+#[typeshare::typeshare]
+pub type AddressCacheConfigureOutput = SerializableU64;
+
+pub struct AddressCacheConfigure;
+impl<'a> Function<'a> for AddressCacheConfigure {
+    type Input = AddressCacheConfigureInput;
+    type Output = AddressCacheConfigureOutput;
+
+    fn handle(
+        AddressCacheConfigureInput { enabled, capacity }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let evicted = crate::context::configure_address_cache(enabled, *capacity as usize);
+        Ok((evicted as u64).into())
+    }
+}
+
+export_function!(AddressCacheConfigure as address_cache_configure);
+export_jni_function!(AddressCacheConfigure as addressCacheConfigure);
+
+//==================
+// Function Catalog
+//==================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct FunctionCatalogInput {}
+
+#[typeshare::typeshare]
+pub type FunctionCatalogOutput = Vec<FunctionCatalogEntry>;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct FunctionCatalogEntry {
+    /// The name of the function as exported by the library.
+    pub name: String,
+    /// The JSON Schema of the function's input serialized as a JSON string.
+    pub input_schema: String,
+    /// The JSON Schema of the function's output serialized as a JSON string.
+    pub output_schema: String,
+}
+
+pub struct FunctionCatalog;
+impl<'a> Function<'a> for FunctionCatalog {
+    type Input = FunctionCatalogInput;
+    type Output = FunctionCatalogOutput;
+
+    fn handle(_: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        #[allow(unused_mut)]
+        let mut catalog = exported_functions!(function_catalog);
+        #[cfg(feature = "gateway-models")]
+        catalog.extend(function_catalog![
+            GatewaySummarizeCommittedTransaction as gateway_summarize_committed_transaction
+        ]);
+        Ok(catalog)
+    }
+}
+
+export_function!(FunctionCatalog as function_catalog);
+export_jni_function!(FunctionCatalog as functionCatalog);
+
+//===================
+// Function Examples
+//===================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct FunctionExamplesInput {
+    /// The name of the function to get the examples of. The examples of all functions are returned
+    /// if this is not specified.
+    pub function_name: Option<String>,
+}
+
+#[typeshare::typeshare]
+pub type FunctionExamplesOutput = Vec<FunctionExamplesEntry>;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct FunctionExamplesEntry {
+    /// The name of the function as exported by the library.
+    pub name: String,
+    pub examples: Vec<FunctionExample>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct FunctionExample {
+    /// The input of the function serialized as a JSON string.
+    pub input: String,
+    /// The output that the function produces for the input serialized as a JSON string.
+    pub output: String,
+}
+
+/// The example inputs of the functions keyed by module and then by the name of the function as
+/// exported by the library. The outputs of the examples are obtained by invoking the functions.
+const FUNCTION_EXAMPLE_INPUTS: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/assets/function_examples.json"
+));
+
+/// Returns the example inputs of the functions keyed by module and then by the name of the function
+/// as exported by the library.
+pub fn function_example_inputs() -> Result<
+    indexmap::IndexMap<String, indexmap::IndexMap<String, Vec<serde_json::Value>>>,
+    InvocationHandlingError,
+> {
+    serde_json::from_str(FUNCTION_EXAMPLE_INPUTS)
+        .map_err(|error| InvocationHandlingError::FunctionExamplesError(debug_string(error)))
+}
+
+pub struct FunctionExamples;
+impl<'a> Function<'a> for FunctionExamples {
+    type Input = FunctionExamplesInput;
+    type Output = FunctionExamplesOutput;
+
+    fn handle(
+        FunctionExamplesInput { function_name }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let invoke = exported_functions!(function_invoker);
+
+        let entries = function_example_inputs()?
+            .into_values()
+            .flatten()
+            .filter(|(name, _)| {
+                function_name
+                    .as_ref()
+                    .map_or(true, |function_name| function_name == name)
+            })
+            .map(|(name, inputs)| {
+                let examples = inputs
+                    .into_iter()
+                    .map(|input| {
+                        Ok(FunctionExample {
+                            output: invoke(&name, input.clone())?,
+                            input: input.to_string(),
+                        })
+                    })
+                    .collect::<Result<Vec<_>, InvocationHandlingError>>()?;
+                Ok(FunctionExamplesEntry { name, examples })
+            })
+            .collect::<Result<Vec<_>, InvocationHandlingError>>()?;
+
+        match function_name {
+            Some(function_name) if entries.is_empty() => {
+                Err(InvocationHandlingError::FunctionExamplesError(format!(
+                    "No examples exist for function: {function_name}"
+                )))
+            }
+            _ => Ok(entries),
+        }
+    }
+}
+
+/// Invokes the function with the input given as JSON, returning its output serialized as a JSON
+/// string.
+fn invoke_with_json<'f, F>(input: serde_json::Value) -> Result<String, InvocationHandlingError>
+where
+    F: Function<'f>,
+{
+    let input = serde_json::from_value::<F::Input>(input)
+        .map_err(|error| InvocationHandlingError::FunctionExamplesError(debug_string(error)))?;
+    let output = F::handle(input)?;
+    serde_json::to_string(&output)
+        .map_err(|error| InvocationHandlingError::FunctionExamplesError(debug_string(error)))
+}
+
+export_function!(FunctionExamples as function_examples);
+export_jni_function!(FunctionExamples as functionExamples);
+
+macro_rules! function_catalog {
+    (
+        $( $function: ty as $name: ident ),* $(,)?
+    ) => {
+        vec![
+            $(
+                FunctionCatalogEntry {
+                    name: stringify!($name).to_owned(),
+                    input_schema: serde_json::to_string(&schemars::schema_for!(
+                        <$function as Function>::Input
+                    ))
+                    .expect("Impossible Case!"),
+                    output_schema: serde_json::to_string(&schemars::schema_for!(
+                        <$function as Function>::Output
+                    ))
+                    .expect("Impossible Case!"),
+                },
+            )*
+        ]
+    };
+}
+use function_catalog;
+
+/// Invokes the given macro with all of the functions exported by the library and the names that
+/// they're exported as.
+macro_rules! exported_functions {
+    ($macro: ident) => {
+        $macro![
+            BuildInformation as build_information,
+            MethodsThatRequireAuth as methods_that_require_auth,
+            FunctionCatalog as function_catalog,
+            FunctionExamples as function_examples,
+            MemoryStats as memory_stats,
+            FreeCache as free_cache,
+            AddressCacheConfigure as address_cache_configure,
+            ContextCreate as context_create,
+            ContextDestroy as context_destroy,
+            DeriveVirtualAccountAddressFromPublicKey
+                as derive_virtual_account_address_from_public_key,
+            DeriveVirtualIdentityAddressFromPublicKey
+                as derive_virtual_identity_address_from_public_key,
+            DeriveVirtualSignatureNonFungibleGlobalIdFromPublicKey
+                as derive_virtual_signature_non_fungible_global_id_from_public_key,
+            DeriveVirtualSignatureNonFungibleGlobalIdFromPublicKeyHash
+                as derive_virtual_signature_non_fungible_global_id_from_public_key_hash,
+            DeriveVirtualGlobalAddressFromPublicKeyHash
+                as derive_virtual_global_address_from_public_key_hash,
+            DerivePublicKeyHashFromVirtualSignatureNonFungibleGlobalId
+                as derive_public_key_hash_from_virtual_signature_non_fungible_global_id,
+            DeriveVirtualAccountAddressFromOlympiaAccountAddress
+                as derive_virtual_account_address_from_olympia_account_address,
+            DeriveResourceAddressFromOlympiaResourceAddress
+                as derive_resource_address_from_olympia_resource_address,
+            DerivePublicKeyFromOlympiaAccountAddress
+                as derive_public_key_from_olympia_account_address,
+            DeriveOlympiaAccountAddressFromPublicKey
+                as derive_olympia_account_address_from_public_key,
+            DeriveNodeAddressFromPublicKey as derive_node_address_from_public_key,
+            DeriveNonce as derive_nonce,
+            DeriveTransactionTrackerKey as derive_transaction_tracker_key,
+            DeriveBech32mTransactionIdentifierFromIntentHash
+                as derive_bech32m_transaction_identifier_from_intent_hash,
+            InstructionsHash as instructions_hash,
+            InstructionsConvert as instructions_convert,
+            InstructionsCompile as instructions_compile,
+            InstructionsDecompile as instructions_decompile,
+            InstructionsStaticallyValidate as instructions_statically_validate,
+            InstructionsExtractAddresses as instructions_extract_addresses,
+            InstructionsExtractAddressesByRole as instructions_extract_addresses_by_role,
+            InstructionsExtractRequiredSigners as instructions_extract_required_signers,
+            InstructionsExtractMaximumOutflows as instructions_extract_maximum_outflows,
+            InstructionsSimulateDeposits as instructions_simulate_deposits,
+            InstructionsAuthorizedDepositorBadges as instructions_authorized_depositor_badges,
+            InstructionsNonAccountTransfers as instructions_non_account_transfers,
+            InstructionsExtractComments as instructions_extract_comments,
+            InstructionsAttachComments as instructions_attach_comments,
+            InstructionsLineRanges as instructions_line_ranges,
+            InstructionsCompileDiagnostics as instructions_compile_diagnostics,
+            ExecutionAnalyze as execution_analyze,
+            ExecutionVerifyAttestation as execution_verify_attestation,
+            ExecutionExtractAccountResourceMovements
+                as execution_extract_account_resource_movements,
+            ExecutionMinimumXrdRequiredPerAccount as execution_minimum_xrd_required_per_account,
+            ExecutionLint as execution_lint,
+            EventsCompileFilter as events_compile_filter,
+            ManifestHash as manifest_hash,
+            ManifestDeduplicationKey as manifest_deduplication_key,
+            ManifestCompile as manifest_compile,
+            ManifestDecompile as manifest_decompile,
+            ManifestConvert as manifest_convert,
+            ManifestStaticallyValidate as manifest_statically_validate,
+            ManifestEncodeCallArguments as manifest_encode_call_arguments,
+            ManifestEncodeMetadataInit as manifest_encode_metadata_init,
+            ManifestFaucetFreeXrd as manifest_faucet_free_xrd,
+            ManifestModifyAddGuarantees as manifest_modify_add_guarantees,
+            ManifestModifySetLockFee as manifest_modify_set_lock_fee,
+            ManifestModifySponsorFee as manifest_modify_sponsor_fee,
+            ManifestExtractFeeSponsorship as manifest_extract_fee_sponsorship,
+            ManifestModifyInstructions as manifest_modify_instructions,
+            ManifestEnforcePolicy as manifest_enforce_policy,
+            ManifestValidateFeeLocks as manifest_validate_fee_locks,
+            ManifestValidateIdAllocation as manifest_validate_id_allocation,
+            ManifestResolveIncludes as manifest_resolve_includes,
+            LegacyValueUpgrade as legacy_value_upgrade,
+            LegacyValueDowngrade as legacy_value_downgrade,
+            LegacyInstructionsUpgrade as legacy_instructions_upgrade,
+            LegacyInstructionsDowngrade as legacy_instructions_downgrade,
+            IntentHash as intent_hash,
+            IntentCompile as intent_compile,
+            IntentDecompile as intent_decompile,
+            IntentStaticallyValidate as intent_statically_validate,
+            IntentRequiredSigningKeys as intent_required_signing_keys,
+            IntentDisplayRecords as intent_display_records,
+            IntentSummaryHash as intent_summary_hash,
+            IntentCompare as intent_compare,
+            SignedIntentHash as signed_intent_hash,
+            SignedIntentCompile as signed_intent_compile,
+            SignedIntentDecompile as signed_intent_decompile,
+            SignedIntentStaticallyValidate as signed_intent_statically_validate,
+            SignedIntentNormalizeSignatures as signed_intent_normalize_signatures,
+            SignedIntentNotarySignatoryIssues as signed_intent_notary_signatory_issues,
+            NotarizedTransactionHash as notarized_transaction_hash,
+            NotarizedTransactionCompile as notarized_transaction_compile,
+            NotarizedTransactionDecompile as notarized_transaction_decompile,
+            NotarizedTransactionStaticallyValidate as notarized_transaction_statically_validate,
+            NotarizedTransactionSubmissionEnvelope as notarized_transaction_submission_envelope,
+            TransactionPreflightCheck as transaction_preflight_check,
+            NotarizedTransactionVerifyNotary as notarized_transaction_verify_notary,
+            ValidationHeader as validation_header,
+            ValidationIntentSignatureCount as validation_intent_signature_count,
+            ValidationMessage as validation_message,
+            ValidationManifestDepth as validation_manifest_depth,
+            ValidationIdAllocation as validation_id_allocation,
+            ValidationNotarizedPayloadSize as validation_notarized_payload_size,
+            ValidationIntent as validation_intent,
+            ValidationSignedIntent as validation_signed_intent,
+            ValidationNotarizedTransaction as validation_notarized_transaction,
+            TransactionRequestNormalize as transaction_request_normalize,
+            TransactionRequestValidate as transaction_request_validate,
+            TransactionRequestToManifest as transaction_request_to_manifest,
+            TransactionRequestFromManifest as transaction_request_from_manifest,
+            DappDefinitionVerifyLink as dapp_definition_verify_link,
+            DappDefinitionReferencedDappDefinitions as dapp_definition_referenced_dapp_definitions,
+            OlympiaTransactionDecode as olympia_transaction_decode,
+            UtilsKnownAddress as utils_known_addresses,
+            UtilsKnownAddressesBatch as utils_known_addresses_batch,
+            UtilsRegisterNetwork as utils_register_network,
+            UtilsDeregisterNetwork as utils_deregister_network,
+            UtilsNetworks as utils_networks,
+            PayloadRoundTripVerify as payload_round_trip_verify,
+            ScryptoSborDecodeToString as scrypto_sbor_decode_to_string,
+            ScryptoSborEncodeStringRepresentation as scrypto_sbor_encode_string_representation,
+            ManifestSborDecodeToString as manifest_sbor_decode_to_string,
+            ManifestSborEncodeStringRepresentation as manifest_sbor_encode_string_representation,
+            SborDecodeUnknown as sbor_decode_unknown,
+            NativeStateDecode as native_state_decode,
+            HashingHash as hashing_hash,
+            HeaderEpochRangeFromValidityDuration as header_epoch_range_from_validity_duration,
+            HeaderValidityDurationFromEpochRange as header_validity_duration_from_epoch_range,
+            PersonalMessageHash as personal_message_hash,
+            PersonalMessageVerify as personal_message_verify,
+            PublicKeyValidate as public_key_validate,
+            PublicKeySecp256k1Compress as public_key_secp256k1_compress,
+            PublicKeySecp256k1Decompress as public_key_secp256k1_decompress,
+            SchemaDecode as schema_decode,
+            SchemaFunctionSignature as schema_function_signature,
+            AddressEntityType as address_entity_type,
+            AddressDecode as address_decode,
+            AddressValidate as address_validate,
+            AddressAbbreviate as address_abbreviate,
+            AddressIsLookAlike as address_is_look_alike,
+            AmountFormat as amount_format,
+            AmountXrdFormatOptions as amount_xrd_format_options,
+            AmountParse as amount_parse,
+            ValueAlias as value_alias,
+            ValueDealias as value_dealias,
+            ValueValidateAgainstSchema as value_validate_against_schema,
+        ]
+    };
+}
+use exported_functions;
+
+macro_rules! function_invoker {
+    (
+        $( $function: ty as $name: ident ),* $(,)?
+    ) => {
+        |name: &str, input: serde_json::Value| -> Result<String, InvocationHandlingError> {
+            match name {
+                $(
+                    stringify!($name) => invoke_with_json::<$function>(input),
+                )*
+                _ => Err(InvocationHandlingError::FunctionExamplesError(format!(
+                    "No function is exported with the name: {name}"
+                ))),
+            }
+        }
+    };
+}
+use function_invoker;
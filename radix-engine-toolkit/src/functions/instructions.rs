@@ -15,13 +15,23 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::functions::execution::SerializableAuthorizedDepositorBadgeUsage;
 use crate::prelude::*;
 
 use radix_engine_common::types::EntityType;
+use radix_engine_toolkit_core::functions::instructions::{
+    InstructionLineRange, ManifestComment, ManifestCommentPosition, ManifestCompileDiagnostic,
+    ManifestCompileErrorKind, ManifestPosition, ManifestSpan, ManifestTokenClass,
+};
+use radix_engine_toolkit_core::instruction_visitor::visitors::addresses_by_role_visitor::AddressesByRole;
+use radix_engine_toolkit_core::instruction_visitor::visitors::required_signers_visitor::*;
+use radix_engine_toolkit_core::instruction_visitor::visitors::worktop_simulator_visitor::SimulatedAmount;
 use radix_engine_toolkit_core::models::node_id::TypedNodeId;
-use sbor::prelude::{HashMap, HashSet};
+use sbor::prelude::{BTreeMap, BTreeSet, HashSet};
 use schemars::JsonSchema;
+use scrypto::prelude::ComponentAddress;
 use serde::{Deserialize, Serialize};
+use transaction::prelude::DynamicGlobalAddress;
 
 //===================
 // Instructions Hash
@@ -107,9 +117,12 @@ export_jni_function!(InstructionsConvert as instructionsConvert);
 pub struct InstructionsCompileInput {
     pub instructions: SerializableInstructions,
     pub network_id: SerializableU8,
+    /// The encoding that the compiled payload is returned in, hex if not specified.
+    #[serde(default)]
+    pub bytes_encoding: SerializableBytesEncoding,
 }
 #[typeshare::typeshare]
-pub type InstructionsCompileOutput = SerializableBytes;
+pub type InstructionsCompileOutput = String;
 
 pub struct InstructionsCompile;
 impl<'a> Function<'a> for InstructionsCompile {
@@ -120,6 +133,7 @@ impl<'a> Function<'a> for InstructionsCompile {
         InstructionsCompileInput {
             instructions,
             network_id,
+            bytes_encoding,
         }: Self::Input,
     ) -> Result<Self::Output, InvocationHandlingError> {
         let instructions = instructions.to_instructions(*network_id)?;
@@ -129,7 +143,7 @@ impl<'a> Function<'a> for InstructionsCompile {
             InvocationHandlingError::EncodeError(debug_string(error), debug_string(instructions))
         })?;
 
-        Ok(compiled.into())
+        Ok(bytes_encoding.encode(&compiled))
     }
 }
 
@@ -143,7 +157,10 @@ export_jni_function!(InstructionsCompile as instructionsCompile);
 #[typeshare::typeshare]
 #[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
 pub struct InstructionsDecompileInput {
-    pub compiled: SerializableBytes,
+    pub compiled: String,
+    /// The encoding of the compiled payload, hex if not specified.
+    #[serde(default)]
+    pub bytes_encoding: SerializableBytesEncoding,
     pub network_id: SerializableU8,
     pub instructions_kind: SerializableInstructionsKind,
 }
@@ -158,16 +175,16 @@ impl<'a> Function<'a> for InstructionsDecompile {
     fn handle(
         InstructionsDecompileInput {
             compiled,
+            bytes_encoding,
             network_id,
             instructions_kind,
         }: Self::Input,
     ) -> Result<Self::Output, InvocationHandlingError> {
-        let instructions = radix_engine_toolkit_core::functions::instructions::decompile(
-            &**compiled,
-        )
-        .map_err(|error| {
-            InvocationHandlingError::EncodeError(debug_string(error), debug_string(compiled))
-        })?;
+        let compiled = bytes_encoding.decode(&compiled)?;
+        let instructions = radix_engine_toolkit_core::functions::instructions::decompile(&compiled)
+            .map_err(|error| {
+                InvocationHandlingError::EncodeError(debug_string(error), debug_string(compiled))
+            })?;
 
         let instructions =
             SerializableInstructions::new(&instructions, instructions_kind, *network_id)?;
@@ -236,9 +253,11 @@ pub struct InstructionsExtractAddressesInput {
 #[typeshare::typeshare]
 #[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
 pub struct InstructionsExtractAddressesOutput {
-    pub addresses: HashMap<SerializableEntityType, Vec<SerializableNodeId>>,
+    /// The addresses grouped by entity type, each group being sorted.
+    #[typeshare(serialized_as = "HashMap<SerializableEntityType, Vec<SerializableNodeId>>")]
+    pub addresses: BTreeMap<SerializableEntityType, Vec<SerializableNodeId>>,
     #[typeshare(serialized_as = "Vec<SerializableU32>")]
-    pub named_addresses: HashSet<SerializableU32>,
+    pub named_addresses: BTreeSet<SerializableU32>,
 }
 
 pub struct InstructionsExtractAddresses;
@@ -269,6 +288,585 @@ impl<'a> Function<'a> for InstructionsExtractAddresses {
 export_function!(InstructionsExtractAddresses as instructions_extract_addresses);
 export_jni_function!(InstructionsExtractAddresses as instructionsExtractAddresses);
 
+//========================================
+// Instructions Extract Addresses By Role
+//========================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct InstructionsExtractAddressesByRoleInput {
+    pub instructions: SerializableInstructions,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct InstructionsExtractAddressesByRoleOutput {
+    pub called_components: Vec<SerializableNodeId>,
+    pub called_packages: Vec<SerializableNodeId>,
+    pub accounts_withdrawn_from: Vec<SerializableNodeId>,
+    pub accounts_deposited_into: Vec<SerializableNodeId>,
+    pub resources_moved: Vec<SerializableNodeId>,
+    pub badges_presented: Vec<SerializableNodeId>,
+}
+
+impl InstructionsExtractAddressesByRoleOutput {
+    pub fn new(value: AddressesByRole, network_id: u8) -> Self {
+        Self {
+            called_components: value
+                .called_components
+                .into_iter()
+                .map(|address| SerializableNodeId::new(address.into_node_id(), network_id))
+                .collect(),
+            called_packages: value
+                .called_packages
+                .into_iter()
+                .map(|address| SerializableNodeId::new(address.into_node_id(), network_id))
+                .collect(),
+            accounts_withdrawn_from: value
+                .accounts_withdrawn_from
+                .into_iter()
+                .map(|address| SerializableNodeId::new(address.into_node_id(), network_id))
+                .collect(),
+            accounts_deposited_into: value
+                .accounts_deposited_into
+                .into_iter()
+                .map(|address| SerializableNodeId::new(address.into_node_id(), network_id))
+                .collect(),
+            resources_moved: value
+                .resources_moved
+                .into_iter()
+                .map(|address| SerializableNodeId::new(address.into_node_id(), network_id))
+                .collect(),
+            badges_presented: value
+                .badges_presented
+                .into_iter()
+                .map(|address| SerializableNodeId::new(address.into_node_id(), network_id))
+                .collect(),
+        }
+    }
+}
+
+pub struct InstructionsExtractAddressesByRole;
+impl<'a> Function<'a> for InstructionsExtractAddressesByRole {
+    type Input = InstructionsExtractAddressesByRoleInput;
+    type Output = InstructionsExtractAddressesByRoleOutput;
+
+    fn handle(
+        InstructionsExtractAddressesByRoleInput {
+            instructions,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let instructions = instructions.to_instructions(*network_id)?;
+
+        let addresses_by_role =
+            radix_engine_toolkit_core::functions::instructions::extract_addresses_by_role(
+                &instructions,
+            );
+
+        Ok(Self::Output::new(addresses_by_role, *network_id))
+    }
+}
+
+export_function!(InstructionsExtractAddressesByRole as instructions_extract_addresses_by_role);
+export_jni_function!(InstructionsExtractAddressesByRole as instructionsExtractAddressesByRole);
+
+//=======================================
+// Instructions Extract Required Signers
+//=======================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct InstructionsExtractRequiredSignersInput {
+    pub instructions: SerializableInstructions,
+    pub network_id: SerializableU8,
+}
+
+pub type InstructionsExtractRequiredSignersOutput = Vec<SerializableRequiredSigner>;
+
+pub struct InstructionsExtractRequiredSigners;
+impl<'a> Function<'a> for InstructionsExtractRequiredSigners {
+    type Input = InstructionsExtractRequiredSignersInput;
+    type Output = InstructionsExtractRequiredSignersOutput;
+
+    fn handle(
+        InstructionsExtractRequiredSignersInput {
+            instructions,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let instructions = instructions.to_instructions(*network_id)?;
+
+        let required_signers =
+            radix_engine_toolkit_core::functions::instructions::required_signers(&instructions);
+
+        Ok(required_signers
+            .into_iter()
+            .map(|required_signer| SerializableRequiredSigner::new(required_signer, *network_id))
+            .collect())
+    }
+}
+
+export_function!(InstructionsExtractRequiredSigners as instructions_extract_required_signers);
+export_jni_function!(InstructionsExtractRequiredSigners as instructionsExtractRequiredSigners);
+
+//=======================================
+// Instructions Extract Maximum Outflows
+//=======================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct InstructionsExtractMaximumOutflowsInput {
+    pub instructions: SerializableInstructions,
+    pub accounts: Vec<SerializableNodeId>,
+    pub network_id: SerializableU8,
+}
+
+/// The maximum outflows keyed by account and then by resource address, both in ascending order.
+/// This is `null` when the outflows are unbounded, such as when the instructions recall from vaults.
+#[typeshare::typeshare]
+pub type InstructionsExtractMaximumOutflowsOutput =
+    Option<BTreeMap<SerializableNodeId, BTreeMap<SerializableNodeId, SerializableDecimal>>>;
+
+pub struct InstructionsExtractMaximumOutflows;
+impl<'a> Function<'a> for InstructionsExtractMaximumOutflows {
+    type Input = InstructionsExtractMaximumOutflowsInput;
+    type Output = InstructionsExtractMaximumOutflowsOutput;
+
+    fn handle(
+        InstructionsExtractMaximumOutflowsInput {
+            instructions,
+            accounts,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let instructions = instructions.to_instructions(*network_id)?;
+        let accounts = accounts
+            .into_iter()
+            .map(|account| {
+                ComponentAddress::try_from(account.clone())
+                    .map_err(|_| InvocationHandlingError::InvalidAddress(account.0.to_string()))
+            })
+            .collect::<Result<HashSet<_>, _>>()?;
+
+        let maximum_outflows = radix_engine_toolkit_core::functions::instructions::maximum_outflows(
+            &instructions,
+            accounts,
+        );
+
+        Ok(maximum_outflows.map(|maximum_outflows| {
+            maximum_outflows
+                .into_iter()
+                .map(|(account, outflows)| {
+                    (
+                        SerializableNodeId::new(account.into_node_id(), *network_id),
+                        outflows
+                            .into_iter()
+                            .map(|(resource_address, amount)| {
+                                (
+                                    SerializableNodeId::new(
+                                        resource_address.into_node_id(),
+                                        *network_id,
+                                    ),
+                                    amount.into(),
+                                )
+                            })
+                            .collect(),
+                    )
+                })
+                .collect()
+        }))
+    }
+}
+
+export_function!(InstructionsExtractMaximumOutflows as instructions_extract_maximum_outflows);
+export_jni_function!(InstructionsExtractMaximumOutflows as instructionsExtractMaximumOutflows);
+
+//===============================
+// Instructions Simulate Deposits
+//===============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct InstructionsSimulateDepositsInput {
+    pub instructions: SerializableInstructions,
+    pub network_id: SerializableU8,
+}
+
+/// The simulated deposits of each account with the accounts in ascending order and the deposits
+/// in the order of the instructions.
+#[typeshare::typeshare]
+pub type InstructionsSimulateDepositsOutput =
+    BTreeMap<SerializableNodeId, Vec<SerializableSimulatedDeposit>>;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableSimulatedDeposit {
+    pub instruction_index: SerializableU64,
+    /// The resource deposited, [`None`] for the resources of unknown addresses and amounts which
+    /// could have been on the worktop when it was deposited in its entirety.
+    pub resource_address: Option<SerializableNodeId>,
+    pub amount: SerializableSimulatedAmount,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableSimulatedAmount {
+    /// The amount is statically derivable from the instructions.
+    Exact(SerializableDecimal),
+    /// The amount depends on the execution of the instructions.
+    Unknown,
+}
+
+pub struct InstructionsSimulateDeposits;
+impl<'a> Function<'a> for InstructionsSimulateDeposits {
+    type Input = InstructionsSimulateDepositsInput;
+    type Output = InstructionsSimulateDepositsOutput;
+
+    fn handle(
+        InstructionsSimulateDepositsInput {
+            instructions,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let instructions = instructions.to_instructions(*network_id)?;
+
+        let simulated_deposits =
+            radix_engine_toolkit_core::functions::instructions::simulated_deposits(&instructions);
+
+        Ok(simulated_deposits
+            .into_iter()
+            .map(|(account, deposits)| {
+                (
+                    SerializableNodeId::new(account.into_node_id(), *network_id),
+                    deposits
+                        .into_iter()
+                        .map(|deposit| SerializableSimulatedDeposit {
+                            instruction_index: (deposit.instruction_index as u64).into(),
+                            resource_address: deposit.resource_address.map(|resource_address| {
+                                SerializableNodeId::new(
+                                    resource_address.into_node_id(),
+                                    *network_id,
+                                )
+                            }),
+                            amount: match deposit.amount {
+                                SimulatedAmount::Exact(amount) => {
+                                    SerializableSimulatedAmount::Exact(amount.into())
+                                }
+                                SimulatedAmount::Unknown => SerializableSimulatedAmount::Unknown,
+                            },
+                        })
+                        .collect(),
+                )
+            })
+            .collect())
+    }
+}
+
+export_function!(InstructionsSimulateDeposits as instructions_simulate_deposits);
+export_jni_function!(InstructionsSimulateDeposits as instructionsSimulateDeposits);
+
+//==========================================
+// Instructions Authorized Depositor Badges
+//==========================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct InstructionsAuthorizedDepositorBadgesInput {
+    pub instructions: SerializableInstructions,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+pub type InstructionsAuthorizedDepositorBadgesOutput =
+    Vec<SerializableAuthorizedDepositorBadgeUsage>;
+
+pub struct InstructionsAuthorizedDepositorBadges;
+impl<'a> Function<'a> for InstructionsAuthorizedDepositorBadges {
+    type Input = InstructionsAuthorizedDepositorBadgesInput;
+    type Output = InstructionsAuthorizedDepositorBadgesOutput;
+
+    fn handle(
+        InstructionsAuthorizedDepositorBadgesInput {
+            instructions,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let instructions = instructions.to_instructions(*network_id)?;
+
+        let usages =
+            radix_engine_toolkit_core::functions::instructions::authorized_depositor_badges(
+                &instructions,
+            );
+
+        Ok(usages
+            .into_iter()
+            .map(|usage| SerializableAuthorizedDepositorBadgeUsage::new(usage, *network_id))
+            .collect())
+    }
+}
+
+export_function!(InstructionsAuthorizedDepositorBadges as instructions_authorized_depositor_badges);
+export_jni_function!(
+    InstructionsAuthorizedDepositorBadges as instructionsAuthorizedDepositorBadges
+);
+
+//======================================
+// Instructions Non Account Transfers
+//======================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct InstructionsNonAccountTransfersInput {
+    pub instructions: SerializableInstructions,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+pub type InstructionsNonAccountTransfersOutput = Vec<SerializableNonAccountTransfer>;
+
+/// An instruction passing the entire worktop or buckets to a component that is not an account.
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableNonAccountTransfer {
+    pub instruction_index: SerializableU64,
+    pub component_address: SerializableManifestAddress,
+    pub method_name: String,
+    pub entire_worktop: bool,
+    pub buckets: Vec<SerializableBucketId>,
+}
+
+pub struct InstructionsNonAccountTransfers;
+impl<'a> Function<'a> for InstructionsNonAccountTransfers {
+    type Input = InstructionsNonAccountTransfersInput;
+    type Output = InstructionsNonAccountTransfersOutput;
+
+    fn handle(
+        InstructionsNonAccountTransfersInput {
+            instructions,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let instructions = instructions.to_instructions(*network_id)?;
+
+        let transfers = radix_engine_toolkit_core::functions::instructions::non_account_transfers(
+            &instructions,
+        );
+
+        Ok(transfers
+            .into_iter()
+            .map(|transfer| SerializableNonAccountTransfer {
+                instruction_index: (transfer.instruction_index as u64).into(),
+                component_address: match transfer.component_address {
+                    DynamicGlobalAddress::Static(address) => SerializableManifestAddress::Static(
+                        SerializableNodeId::new(address.into_node_id(), *network_id),
+                    ),
+                    DynamicGlobalAddress::Named(named_address) => {
+                        SerializableManifestAddress::Named(named_address.into())
+                    }
+                },
+                method_name: transfer.method_name,
+                entire_worktop: transfer.entire_worktop,
+                buckets: transfer.buckets.into_iter().map(Into::into).collect(),
+            })
+            .collect())
+    }
+}
+
+export_function!(InstructionsNonAccountTransfers as instructions_non_account_transfers);
+export_jni_function!(InstructionsNonAccountTransfers as instructionsNonAccountTransfers);
+
+//===============================
+// Instructions Extract Comments
+//===============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct InstructionsExtractCommentsInput {
+    pub instructions: String,
+}
+
+#[typeshare::typeshare]
+pub type InstructionsExtractCommentsOutput = Vec<SerializableManifestComment>;
+
+pub struct InstructionsExtractComments;
+impl<'a> Function<'a> for InstructionsExtractComments {
+    type Input = InstructionsExtractCommentsInput;
+    type Output = InstructionsExtractCommentsOutput;
+
+    fn handle(
+        InstructionsExtractCommentsInput { instructions }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let comments =
+            radix_engine_toolkit_core::functions::instructions::extract_comments(&instructions);
+        Ok(comments.into_iter().map(Into::into).collect())
+    }
+}
+
+export_function!(InstructionsExtractComments as instructions_extract_comments);
+export_jni_function!(InstructionsExtractComments as instructionsExtractComments);
+
+//==============================
+// Instructions Attach Comments
+//==============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct InstructionsAttachCommentsInput {
+    pub instructions: String,
+    pub comments: Vec<SerializableManifestComment>,
+}
+
+#[typeshare::typeshare]
+pub type InstructionsAttachCommentsOutput = String;
+
+pub struct InstructionsAttachComments;
+impl<'a> Function<'a> for InstructionsAttachComments {
+    type Input = InstructionsAttachCommentsInput;
+    type Output = InstructionsAttachCommentsOutput;
+
+    fn handle(
+        InstructionsAttachCommentsInput {
+            instructions,
+            comments,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let comments = comments.into_iter().map(Into::into).collect::<Vec<_>>();
+        Ok(
+            radix_engine_toolkit_core::functions::instructions::attach_comments(
+                &instructions,
+                &comments,
+            ),
+        )
+    }
+}
+
+export_function!(InstructionsAttachComments as instructions_attach_comments);
+export_jni_function!(InstructionsAttachComments as instructionsAttachComments);
+
+//===========================
+// Instructions Line Ranges
+//===========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct InstructionsLineRangesInput {
+    /// The manifest string, typically obtained by decompiling with the `String` instructions kind.
+    pub instructions: String,
+}
+
+#[typeshare::typeshare]
+pub type InstructionsLineRangesOutput = Vec<SerializableInstructionLineRange>;
+
+pub struct InstructionsLineRanges;
+impl<'a> Function<'a> for InstructionsLineRanges {
+    type Input = InstructionsLineRangesInput;
+    type Output = InstructionsLineRangesOutput;
+
+    fn handle(
+        InstructionsLineRangesInput { instructions }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let line_ranges =
+            radix_engine_toolkit_core::functions::instructions::instruction_line_ranges(
+                &instructions,
+            );
+        Ok(line_ranges.into_iter().map(Into::into).collect())
+    }
+}
+
+export_function!(InstructionsLineRanges as instructions_line_ranges);
+export_jni_function!(InstructionsLineRanges as instructionsLineRanges);
+
+//====================================
+// Instructions Compile Diagnostics
+//====================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct InstructionsCompileDiagnosticsInput {
+    /// The manifest string to compile.
+    pub instructions: String,
+    pub network_id: SerializableU8,
+}
+
+/// The diagnostic of the error encountered when compiling the manifest string or [`None`] if it
+/// compiles successfully.
+#[typeshare::typeshare]
+pub type InstructionsCompileDiagnosticsOutput = Option<SerializableManifestCompileDiagnostic>;
+
+pub struct InstructionsCompileDiagnostics;
+impl<'a> Function<'a> for InstructionsCompileDiagnostics {
+    type Input = InstructionsCompileDiagnosticsInput;
+    type Output = InstructionsCompileDiagnosticsOutput;
+
+    fn handle(
+        InstructionsCompileDiagnosticsInput {
+            instructions,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let network_definition = crate::context::network_definition(*network_id);
+        let diagnostic = transaction::manifest::compile(
+            &instructions,
+            &network_definition,
+            transaction::manifest::MockBlobProvider::new(),
+        )
+        .err()
+        .map(|error| {
+            radix_engine_toolkit_core::functions::instructions::diagnose_compile_error(
+                &instructions,
+                &error,
+            )
+            .into()
+        });
+        Ok(diagnostic)
+    }
+}
+
+export_function!(InstructionsCompileDiagnostics as instructions_compile_diagnostics);
+export_jni_function!(InstructionsCompileDiagnostics as instructionsCompileDiagnostics);
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableRequiredSigner {
+    pub entity_address: SerializableNodeId,
+    pub entity_kind: SerializableSignerEntityKind,
+    pub methods: Vec<String>,
+}
+
+impl SerializableRequiredSigner {
+    pub fn new(value: RequiredSigner, network_id: u8) -> Self {
+        Self {
+            entity_address: SerializableNodeId::new(
+                value.entity_address.into_node_id(),
+                network_id,
+            ),
+            entity_kind: value.entity_kind.into(),
+            methods: value.methods,
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SerializableSignerEntityKind {
+    Account,
+    Identity,
+    AccessController,
+}
+
+impl From<SignerEntityKind> for SerializableSignerEntityKind {
+    fn from(value: SignerEntityKind) -> Self {
+        match value {
+            SignerEntityKind::Account => Self::Account,
+            SignerEntityKind::Identity => Self::Identity,
+            SignerEntityKind::AccessController => Self::AccessController,
+        }
+    }
+}
+
 #[typeshare::typeshare]
 #[derive(
     Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, JsonSchema,
@@ -396,8 +994,8 @@ impl From<SerializableEntityType> for EntityType {
 pub(crate) fn transform_addresses_set_to_map(
     addresses: HashSet<TypedNodeId>,
     network_id: u8,
-) -> HashMap<SerializableEntityType, Vec<SerializableNodeId>> {
-    let mut addresses_map = HashMap::<SerializableEntityType, Vec<SerializableNodeId>>::new();
+) -> BTreeMap<SerializableEntityType, Vec<SerializableNodeId>> {
+    let mut addresses_map = BTreeMap::<SerializableEntityType, Vec<SerializableNodeId>>::new();
     for node_id in addresses.into_iter() {
         addresses_map
             .entry(node_id.entity_type().into())
@@ -405,7 +1003,161 @@ pub(crate) fn transform_addresses_set_to_map(
             .push(SerializableNodeId::new(*node_id.as_node_id(), network_id))
     }
     for entity_type in SerializableEntityType::all() {
-        addresses_map.entry(entity_type).or_default();
+        addresses_map.entry(entity_type).or_default().sort();
     }
     addresses_map
 }
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableManifestComment {
+    pub instruction_index: SerializableU64,
+    pub text: String,
+    pub position: SerializableManifestCommentPosition,
+}
+
+/// The zero-based and inclusive range of lines of a manifest string that an instruction spans.
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableInstructionLineRange {
+    pub instruction_index: SerializableU64,
+    pub first_line: SerializableU64,
+    pub last_line: SerializableU64,
+}
+
+impl From<InstructionLineRange> for SerializableInstructionLineRange {
+    fn from(value: InstructionLineRange) -> Self {
+        Self {
+            instruction_index: (value.instruction_index as u64).into(),
+            first_line: (value.first_line as u64).into(),
+            last_line: (value.last_line as u64).into(),
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableManifestCompileDiagnostic {
+    pub kind: SerializableManifestCompileErrorKind,
+    pub message: String,
+    pub span: Option<SerializableManifestSpan>,
+    pub token: Option<String>,
+    pub expected: Vec<SerializableManifestTokenClass>,
+    pub suggestion: Option<String>,
+}
+
+impl From<ManifestCompileDiagnostic> for SerializableManifestCompileDiagnostic {
+    fn from(value: ManifestCompileDiagnostic) -> Self {
+        Self {
+            kind: value.kind.into(),
+            message: value.message,
+            span: value.span.map(Into::into),
+            token: value.token,
+            expected: value.expected.into_iter().map(Into::into).collect(),
+            suggestion: value.suggestion,
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SerializableManifestCompileErrorKind {
+    LexerError,
+    ParserError,
+    GeneratorError,
+}
+
+impl From<ManifestCompileErrorKind> for SerializableManifestCompileErrorKind {
+    fn from(value: ManifestCompileErrorKind) -> Self {
+        match value {
+            ManifestCompileErrorKind::LexerError => Self::LexerError,
+            ManifestCompileErrorKind::ParserError => Self::ParserError,
+            ManifestCompileErrorKind::GeneratorError => Self::GeneratorError,
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableManifestSpan {
+    pub start: SerializableManifestPosition,
+    pub end: SerializableManifestPosition,
+}
+
+impl From<ManifestSpan> for SerializableManifestSpan {
+    fn from(value: ManifestSpan) -> Self {
+        Self {
+            start: value.start.into(),
+            end: value.end.into(),
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableManifestPosition {
+    pub line: SerializableU64,
+    pub column: SerializableU64,
+}
+
+impl From<ManifestPosition> for SerializableManifestPosition {
+    fn from(value: ManifestPosition) -> Self {
+        Self {
+            line: (value.line as u64).into(),
+            column: (value.column as u64).into(),
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SerializableManifestTokenClass {
+    InstructionName,
+    ValueKind,
+    Semicolon,
+    ClosingQuote,
+}
+
+impl From<ManifestTokenClass> for SerializableManifestTokenClass {
+    fn from(value: ManifestTokenClass) -> Self {
+        match value {
+            ManifestTokenClass::InstructionName => Self::InstructionName,
+            ManifestTokenClass::ValueKind => Self::ValueKind,
+            ManifestTokenClass::Semicolon => Self::Semicolon,
+            ManifestTokenClass::ClosingQuote => Self::ClosingQuote,
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SerializableManifestCommentPosition {
+    Before,
+    Inline,
+}
+
+impl From<ManifestComment> for SerializableManifestComment {
+    fn from(value: ManifestComment) -> Self {
+        Self {
+            instruction_index: (value.instruction_index as u64).into(),
+            text: value.text,
+            position: match value.position {
+                ManifestCommentPosition::Before => SerializableManifestCommentPosition::Before,
+                ManifestCommentPosition::Inline => SerializableManifestCommentPosition::Inline,
+            },
+        }
+    }
+}
+
+impl From<SerializableManifestComment> for ManifestComment {
+    fn from(value: SerializableManifestComment) -> Self {
+        Self {
+            instruction_index: *value.instruction_index as usize,
+            text: value.text,
+            position: match value.position {
+                SerializableManifestCommentPosition::Before => ManifestCommentPosition::Before,
+                SerializableManifestCommentPosition::Inline => ManifestCommentPosition::Inline,
+            },
+        }
+    }
+}
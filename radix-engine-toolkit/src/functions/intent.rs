@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use radix_engine_toolkit_core::functions::intent::{HeaderField, InstructionDifference};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -52,22 +53,33 @@ export_jni_function!(IntentHash as intentHash);
 //================
 
 #[typeshare::typeshare]
-pub type IntentCompileInput = SerializableIntent;
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct IntentCompileInput {
+    pub intent: SerializableIntent,
+    /// The encoding that the compiled payload is returned in, hex if not specified.
+    #[serde(default)]
+    pub bytes_encoding: SerializableBytesEncoding,
+}
 #[typeshare::typeshare]
-pub type IntentCompileOutput = SerializableBytes;
+pub type IntentCompileOutput = String;
 
 pub struct IntentCompile;
 impl<'f> Function<'f> for IntentCompile {
     type Input = IntentCompileInput;
     type Output = IntentCompileOutput;
 
-    fn handle(intent: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+    fn handle(
+        IntentCompileInput {
+            intent,
+            bytes_encoding,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
         let intent = intent.to_native(*intent.header.network_id)?;
         let compile =
             radix_engine_toolkit_core::functions::intent::compile(&intent).map_err(|error| {
                 InvocationHandlingError::EncodeError(debug_string(error), debug_string(intent))
             })?;
-        Ok(compile.into())
+        Ok(bytes_encoding.encode(&compile))
     }
 }
 
@@ -81,7 +93,10 @@ export_jni_function!(IntentCompile as intentCompile);
 #[typeshare::typeshare]
 #[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
 pub struct IntentDecompileInput {
-    pub compiled: SerializableBytes,
+    pub compiled: String,
+    /// The encoding of the compiled payload, hex if not specified.
+    #[serde(default)]
+    pub bytes_encoding: SerializableBytesEncoding,
     pub instructions_kind: SerializableInstructionsKind,
 }
 #[typeshare::typeshare]
@@ -95,10 +110,12 @@ impl<'a> Function<'a> for IntentDecompile {
     fn handle(
         IntentDecompileInput {
             compiled,
+            bytes_encoding,
             instructions_kind,
         }: Self::Input,
     ) -> Result<Self::Output, InvocationHandlingError> {
-        let intent = radix_engine_toolkit_core::functions::intent::decompile(&**compiled).map_err(
+        let compiled = bytes_encoding.decode(&compiled)?;
+        let intent = radix_engine_toolkit_core::functions::intent::decompile(&compiled).map_err(
             |error| {
                 InvocationHandlingError::EncodeError(debug_string(error), debug_string(compiled))
             },
@@ -159,3 +176,317 @@ impl<'a> Function<'a> for IntentStaticallyValidate {
 
 export_function!(IntentStaticallyValidate as intent_statically_validate);
 export_jni_function!(IntentStaticallyValidate as intentStaticallyValidate);
+
+//==============================
+// Intent Required Signing Keys
+//==============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct IntentRequiredSigningKeysInput {
+    pub intent: SerializableIntent,
+    pub candidate_public_keys: Vec<SerializablePublicKey>,
+}
+
+#[typeshare::typeshare]
+pub type IntentRequiredSigningKeysOutput = Vec<SerializablePublicKey>;
+
+pub struct IntentRequiredSigningKeys;
+impl<'a> Function<'a> for IntentRequiredSigningKeys {
+    type Input = IntentRequiredSigningKeysInput;
+    type Output = IntentRequiredSigningKeysOutput;
+
+    fn handle(
+        IntentRequiredSigningKeysInput {
+            intent,
+            candidate_public_keys,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let intent = intent.to_native(*intent.header.network_id)?;
+        let candidate_public_keys = candidate_public_keys
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<_>>();
+
+        let required_signing_keys =
+            radix_engine_toolkit_core::functions::intent::required_signing_keys(
+                &intent,
+                &candidate_public_keys,
+            );
+        Ok(required_signing_keys.into_iter().map(Into::into).collect())
+    }
+}
+
+export_function!(IntentRequiredSigningKeys as intent_required_signing_keys);
+export_jni_function!(IntentRequiredSigningKeys as intentRequiredSigningKeys);
+
+//========================
+// Intent Display Records
+//========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct IntentDisplayRecordsInput {
+    pub compiled: SerializableBytes,
+    pub network_id: SerializableU8,
+    /// The maximum number of characters in a chunk of a field value.
+    pub max_field_length: SerializableU32,
+}
+
+#[typeshare::typeshare]
+pub type IntentDisplayRecordsOutput = Vec<DisplayRecord>;
+
+/// A screen of a hardware wallet describing a single instruction of the intent.
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct DisplayRecord {
+    pub title: String,
+    pub fields: Vec<DisplayField>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct DisplayField {
+    pub name: String,
+    /// The value of the field split into chunks of at most the maximum field length.
+    pub chunks: Vec<String>,
+}
+
+pub struct IntentDisplayRecords;
+impl<'a> Function<'a> for IntentDisplayRecords {
+    type Input = IntentDisplayRecordsInput;
+    type Output = IntentDisplayRecordsOutput;
+
+    fn handle(
+        IntentDisplayRecordsInput {
+            compiled,
+            network_id,
+            max_field_length,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let network_definition =
+            radix_engine_toolkit_core::utils::network_definition_from_network_id(*network_id);
+        let display_records = radix_engine_toolkit_core::signer::display_records(
+            &**compiled,
+            &network_definition,
+            *max_field_length as usize,
+        )
+        .map_err(|error| {
+            InvocationHandlingError::DecodeError(debug_string(error), debug_string(&compiled))
+        })?;
+
+        Ok(display_records
+            .into_iter()
+            .map(|record| DisplayRecord {
+                title: record.title,
+                fields: record
+                    .fields
+                    .into_iter()
+                    .map(|field| DisplayField {
+                        name: field.name,
+                        chunks: field.chunks,
+                    })
+                    .collect(),
+            })
+            .collect())
+    }
+}
+
+export_function!(IntentDisplayRecords as intent_display_records);
+export_jni_function!(IntentDisplayRecords as intentDisplayRecords);
+
+//====================
+// Intent Summary Hash
+//====================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct IntentSummaryHashInput {
+    pub compiled: SerializableBytes,
+    pub network_id: SerializableU8,
+}
+
+/// The hash of the normalized display records of the intent which hardware wallets show when the
+/// transaction is too big to fully render.
+#[typeshare::typeshare]
+pub type IntentSummaryHashOutput = SerializableHash;
+
+pub struct IntentSummaryHash;
+impl<'a> Function<'a> for IntentSummaryHash {
+    type Input = IntentSummaryHashInput;
+    type Output = IntentSummaryHashOutput;
+
+    fn handle(
+        IntentSummaryHashInput {
+            compiled,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let network_definition =
+            radix_engine_toolkit_core::utils::network_definition_from_network_id(*network_id);
+        let summary_hash =
+            radix_engine_toolkit_core::signer::summary_hash(&**compiled, &network_definition)
+                .map_err(|error| {
+                    InvocationHandlingError::DecodeError(
+                        debug_string(error),
+                        debug_string(&compiled),
+                    )
+                })?;
+
+        Ok(summary_hash.into())
+    }
+}
+
+export_function!(IntentSummaryHash as intent_summary_hash);
+export_jni_function!(IntentSummaryHash as intentSummaryHash);
+
+//================
+// Intent Compare
+//================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct IntentCompareInput {
+    pub compiled_a: SerializableBytes,
+    pub compiled_b: SerializableBytes,
+    pub instructions_kind: SerializableInstructionsKind,
+}
+
+/// The differences between two intents along with both decompiled intents, so that UIs can render
+/// the old and new values of everything that changed.
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct IntentCompareOutput {
+    pub intent_a: SerializableIntent,
+    pub intent_b: SerializableIntent,
+    pub is_identical: bool,
+    pub changed_header_fields: Vec<SerializableHeaderField>,
+    pub message_changed: bool,
+    /// The instructions of both intents in order, aligned on their longest common subsequence.
+    pub instruction_differences: Vec<SerializableInstructionDifference>,
+    pub added_blobs: Vec<SerializableHash>,
+    pub removed_blobs: Vec<SerializableHash>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SerializableHeaderField {
+    NetworkId,
+    StartEpochInclusive,
+    EndEpochExclusive,
+    Nonce,
+    NotaryPublicKey,
+    NotaryIsSignatory,
+    TipPercentage,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableInstructionDifference {
+    Unchanged {
+        index_a: SerializableU64,
+        index_b: SerializableU64,
+    },
+    Removed {
+        index_a: SerializableU64,
+    },
+    Added {
+        index_b: SerializableU64,
+    },
+}
+
+pub struct IntentCompare;
+impl<'a> Function<'a> for IntentCompare {
+    type Input = IntentCompareInput;
+    type Output = IntentCompareOutput;
+
+    fn handle(
+        IntentCompareInput {
+            compiled_a,
+            compiled_b,
+            instructions_kind,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let decompile = |compiled: &SerializableBytes| {
+            radix_engine_toolkit_core::functions::intent::decompile(&***compiled).map_err(|error| {
+                InvocationHandlingError::DecodeError(debug_string(error), debug_string(compiled))
+            })
+        };
+        let native_intent_a = decompile(&compiled_a)?;
+        let native_intent_b = decompile(&compiled_b)?;
+
+        let comparison = radix_engine_toolkit_core::functions::intent::compare(
+            &native_intent_a,
+            &native_intent_b,
+        );
+
+        let intent_a = SerializableIntent::from_native(
+            &native_intent_a,
+            native_intent_a.header.network_id,
+            instructions_kind,
+        )?;
+        let intent_b = SerializableIntent::from_native(
+            &native_intent_b,
+            native_intent_b.header.network_id,
+            instructions_kind,
+        )?;
+
+        Ok(IntentCompareOutput {
+            intent_a,
+            intent_b,
+            is_identical: comparison.is_identical(),
+            changed_header_fields: comparison
+                .changed_header_fields
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            message_changed: comparison.message_changed,
+            instruction_differences: comparison
+                .instruction_differences
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            added_blobs: comparison.added_blobs.into_iter().map(Into::into).collect(),
+            removed_blobs: comparison
+                .removed_blobs
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        })
+    }
+}
+
+impl From<HeaderField> for SerializableHeaderField {
+    fn from(value: HeaderField) -> Self {
+        match value {
+            HeaderField::NetworkId => Self::NetworkId,
+            HeaderField::StartEpochInclusive => Self::StartEpochInclusive,
+            HeaderField::EndEpochExclusive => Self::EndEpochExclusive,
+            HeaderField::Nonce => Self::Nonce,
+            HeaderField::NotaryPublicKey => Self::NotaryPublicKey,
+            HeaderField::NotaryIsSignatory => Self::NotaryIsSignatory,
+            HeaderField::TipPercentage => Self::TipPercentage,
+        }
+    }
+}
+
+impl From<InstructionDifference> for SerializableInstructionDifference {
+    fn from(value: InstructionDifference) -> Self {
+        match value {
+            InstructionDifference::Unchanged { index_a, index_b } => Self::Unchanged {
+                index_a: (index_a as u64).into(),
+                index_b: (index_b as u64).into(),
+            },
+            InstructionDifference::Removed { index_a } => Self::Removed {
+                index_a: (index_a as u64).into(),
+            },
+            InstructionDifference::Added { index_b } => Self::Added {
+                index_b: (index_b as u64).into(),
+            },
+        }
+    }
+}
+
+export_function!(IntentCompare as intent_compare);
+export_jni_function!(IntentCompare as intentCompare);
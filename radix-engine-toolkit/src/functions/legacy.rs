@@ -0,0 +1,131 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+//======================
+// Legacy Value Upgrade
+//======================
+
+pub type LegacyValueUpgradeInput = LegacyValue;
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct LegacyValueUpgradeOutput {
+    pub value: SerializableManifestValue,
+    pub losses: Vec<LegacyConversionLoss>,
+}
+
+pub struct LegacyValueUpgrade;
+impl<'f> Function<'f> for LegacyValueUpgrade {
+    type Input = LegacyValueUpgradeInput;
+    type Output = LegacyValueUpgradeOutput;
+
+    fn handle(input: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let (value, losses) = legacy_value_to_value(&input)?;
+        Ok(Self::Output { value, losses })
+    }
+}
+
+export_function!(LegacyValueUpgrade as legacy_value_upgrade);
+export_jni_function!(LegacyValueUpgrade as legacyValueUpgrade);
+
+//========================
+// Legacy Value Downgrade
+//========================
+
+pub type LegacyValueDowngradeInput = SerializableManifestValue;
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct LegacyValueDowngradeOutput {
+    pub value: LegacyValue,
+    pub losses: Vec<LegacyConversionLoss>,
+}
+
+pub struct LegacyValueDowngrade;
+impl<'f> Function<'f> for LegacyValueDowngrade {
+    type Input = LegacyValueDowngradeInput;
+    type Output = LegacyValueDowngradeOutput;
+
+    fn handle(input: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let (value, losses) = value_to_legacy_value(&input)?;
+        Ok(Self::Output { value, losses })
+    }
+}
+
+export_function!(LegacyValueDowngrade as legacy_value_downgrade);
+export_jni_function!(LegacyValueDowngrade as legacyValueDowngrade);
+
+//=============================
+// Legacy Instructions Upgrade
+//=============================
+
+pub type LegacyInstructionsUpgradeInput = Vec<LegacyInstruction>;
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct LegacyInstructionsUpgradeOutput {
+    pub instructions: Vec<SerializableInstruction>,
+    pub losses: Vec<LegacyConversionLoss>,
+}
+
+pub struct LegacyInstructionsUpgrade;
+impl<'f> Function<'f> for LegacyInstructionsUpgrade {
+    type Input = LegacyInstructionsUpgradeInput;
+    type Output = LegacyInstructionsUpgradeOutput;
+
+    fn handle(input: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let (instructions, losses) = legacy_instructions_to_instructions(&input)?;
+        Ok(Self::Output {
+            instructions,
+            losses,
+        })
+    }
+}
+
+export_function!(LegacyInstructionsUpgrade as legacy_instructions_upgrade);
+export_jni_function!(LegacyInstructionsUpgrade as legacyInstructionsUpgrade);
+
+//===============================
+// Legacy Instructions Downgrade
+//===============================
+
+pub type LegacyInstructionsDowngradeInput = Vec<SerializableInstruction>;
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct LegacyInstructionsDowngradeOutput {
+    pub instructions: Vec<LegacyInstruction>,
+    pub losses: Vec<LegacyConversionLoss>,
+}
+
+pub struct LegacyInstructionsDowngrade;
+impl<'f> Function<'f> for LegacyInstructionsDowngrade {
+    type Input = LegacyInstructionsDowngradeInput;
+    type Output = LegacyInstructionsDowngradeOutput;
+
+    fn handle(input: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let (instructions, losses) = instructions_to_legacy_instructions(&input)?;
+        Ok(Self::Output {
+            instructions,
+            losses,
+        })
+    }
+}
+
+export_function!(LegacyInstructionsDowngrade as legacy_instructions_downgrade);
+export_jni_function!(LegacyInstructionsDowngrade as legacyInstructionsDowngrade);
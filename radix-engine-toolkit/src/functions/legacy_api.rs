@@ -0,0 +1,157 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Adapters exposing the function names of the old request/response API over the current function
+//! layer. They are kept to allow clients to upgrade the toolkit without rewriting all of their call
+//! sites at once and are only compiled in with the `legacy-api` feature.
+//!
+//! Each adapter forwards to the function that replaced it and accepts and returns the input and
+//! output of that function. Payloads that use the old value and instruction models can be brought
+//! over to the current ones through the [`crate::functions::legacy`] functions.
+
+use crate::prelude::*;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+macro_rules! legacy_adapter {
+    ($legacy: ident => $function: ident as $name: ident, $jni_name: ident) => {
+        #[doc = concat!("Legacy name of [`", stringify!($function), "`].")]
+        pub struct $legacy;
+        impl<'f> Function<'f> for $legacy {
+            type Input = <$function as Function<'f>>::Input;
+            type Output = <$function as Function<'f>>::Output;
+
+            fn handle(
+                input: Self::Input,
+            ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+                $function::handle(input)
+            }
+        }
+
+        export_function!($legacy as $name);
+        export_jni_function!($legacy as $jni_name);
+    };
+}
+
+//===================
+// Renamed Functions
+//===================
+
+legacy_adapter!(LegacyInformation => BuildInformation as information, information);
+legacy_adapter!(
+    LegacyConvertManifest => ManifestConvert as convert_manifest, convertManifest
+);
+legacy_adapter!(
+    LegacyAnalyzeManifest => InstructionsExtractAddresses as analyze_manifest, analyzeManifest
+);
+legacy_adapter!(
+    LegacyCompileTransactionIntent => IntentCompile
+        as compile_transaction_intent, compileTransactionIntent
+);
+legacy_adapter!(
+    LegacyDecompileTransactionIntent => IntentDecompile
+        as decompile_transaction_intent, decompileTransactionIntent
+);
+legacy_adapter!(
+    LegacyCompileSignedTransactionIntent => SignedIntentCompile
+        as compile_signed_transaction_intent, compileSignedTransactionIntent
+);
+legacy_adapter!(
+    LegacyDecompileSignedTransactionIntent => SignedIntentDecompile
+        as decompile_signed_transaction_intent, decompileSignedTransactionIntent
+);
+legacy_adapter!(
+    LegacyCompileNotarizedTransaction => NotarizedTransactionCompile
+        as compile_notarized_transaction, compileNotarizedTransaction
+);
+legacy_adapter!(
+    LegacyDecompileNotarizedTransaction => NotarizedTransactionDecompile
+        as decompile_notarized_transaction, decompileNotarizedTransaction
+);
+legacy_adapter!(
+    LegacyStaticallyValidateTransaction => NotarizedTransactionStaticallyValidate
+        as statically_validate_transaction, staticallyValidateTransaction
+);
+legacy_adapter!(
+    LegacyDeriveVirtualAccountAddress => DeriveVirtualAccountAddressFromPublicKey
+        as derive_virtual_account_address, deriveVirtualAccountAddress
+);
+legacy_adapter!(
+    LegacyDeriveVirtualIdentityAddress => DeriveVirtualIdentityAddressFromPublicKey
+        as derive_virtual_identity_address, deriveVirtualIdentityAddress
+);
+legacy_adapter!(
+    LegacyKnownEntityAddresses => UtilsKnownAddress as known_entity_addresses, knownEntityAddresses
+);
+
+//======================================
+// Decompile Unknown Transaction Intent
+//======================================
+
+pub type LegacyDecompileUnknownTransactionIntentInput = IntentDecompileInput;
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum LegacyDecompileUnknownTransactionIntentOutput {
+    NotarizedTransaction(NotarizedTransactionDecompileOutput),
+    SignedIntent(SignedIntentDecompileOutput),
+    Intent(IntentDecompileOutput),
+}
+
+/// Decompiles a payload that may be a notarized transaction, a signed intent, or an intent. The
+/// payload is decompiled as each of them in that order, and the error of decompiling it as an
+/// intent is returned if none of them succeed.
+pub struct LegacyDecompileUnknownTransactionIntent;
+impl<'f> Function<'f> for LegacyDecompileUnknownTransactionIntent {
+    type Input = LegacyDecompileUnknownTransactionIntentInput;
+    type Output = LegacyDecompileUnknownTransactionIntentOutput;
+
+    fn handle(
+        IntentDecompileInput {
+            compiled,
+            bytes_encoding,
+            instructions_kind,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        if let Ok(notarized_transaction) =
+            NotarizedTransactionDecompile::handle(NotarizedTransactionDecompileInput {
+                compiled: compiled.clone(),
+                bytes_encoding,
+                instructions_kind: instructions_kind.clone(),
+            })
+        {
+            return Ok(Self::Output::NotarizedTransaction(notarized_transaction));
+        }
+        if let Ok(signed_intent) = SignedIntentDecompile::handle(SignedIntentDecompileInput {
+            compiled: compiled.clone(),
+            bytes_encoding,
+            instructions_kind: instructions_kind.clone(),
+        }) {
+            return Ok(Self::Output::SignedIntent(signed_intent));
+        }
+        IntentDecompile::handle(IntentDecompileInput {
+            compiled,
+            bytes_encoding,
+            instructions_kind,
+        })
+        .map(Self::Output::Intent)
+    }
+}
+
+export_function!(LegacyDecompileUnknownTransactionIntent as decompile_unknown_transaction_intent);
+export_jni_function!(LegacyDecompileUnknownTransactionIntent as decompileUnknownTransactionIntent);
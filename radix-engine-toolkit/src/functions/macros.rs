@@ -21,6 +21,21 @@ macro_rules! export_function {
         pub extern "C" fn $name(ptr: $crate::memory::Pointer) -> $crate::memory::Pointer {
             crate::functions::handler::handle_invocation::<$function>(ptr)
         }
+
+        paste::paste! {
+            #[no_mangle]
+            pub extern "C" fn [< $name _with_options >](
+                context_id: u64,
+                serialization_mode: u8,
+                ptr: $crate::memory::Pointer,
+            ) -> $crate::memory::Pointer {
+                crate::functions::handler::handle_invocation_with_options::<$function>(
+                    context_id,
+                    serialization_mode,
+                    ptr,
+                )
+            }
+        }
     };
 }
 
@@ -35,6 +50,23 @@ macro_rules! export_jni_function {
             ) -> jni::sys::jstring {
                 crate::functions::handler::handle_jni_invocation::<$function>(env, class, input)
             }
+
+            #[no_mangle]
+            pub extern "system" fn [< Java_com_radixdlt_toolkit_RadixEngineToolkitFFI_ $name WithOptions >](
+                env: jni::JNIEnv,
+                class: jni::objects::JClass,
+                context_id: jni::sys::jlong,
+                serialization_mode: jni::sys::jbyte,
+                input: jni::objects::JString,
+            ) -> jni::sys::jbyteArray {
+                crate::functions::handler::handle_jni_invocation_with_options::<$function>(
+                    env,
+                    class,
+                    context_id as u64,
+                    serialization_mode as u8,
+                    input,
+                )
+            }
         }
     };
 }
@@ -15,7 +15,12 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use radix_engine_toolkit_core::functions::manifest::{
+    Guarantee, InstructionModification, ManifestPolicy, PolicyViolation,
+};
+use radix_engine_toolkit_core::instruction_visitor::visitors::id_allocation_visitor::*;
 use schemars::JsonSchema;
+use scrypto::prelude::{ComponentAddress, Hash, HashSet, ResourceAddress};
 use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
@@ -56,6 +61,49 @@ impl<'f> Function<'f> for ManifestHash {
 export_function!(ManifestHash as manifest_hash);
 export_jni_function!(ManifestHash as manifestHash);
 
+//=============================
+// Manifest Deduplication Key
+//=============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestDeduplicationKeyInput {
+    pub manifest: SerializableTransactionManifest,
+    pub signers: Vec<SerializablePublicKey>,
+    pub network_id: SerializableU8,
+}
+#[typeshare::typeshare]
+pub type ManifestDeduplicationKeyOutput = SerializableHash;
+
+pub struct ManifestDeduplicationKey;
+impl<'f> Function<'f> for ManifestDeduplicationKey {
+    type Input = ManifestDeduplicationKeyInput;
+    type Output = ManifestDeduplicationKeyOutput;
+
+    fn handle(
+        ManifestDeduplicationKeyInput {
+            manifest,
+            signers,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let manifest = manifest.to_native(*network_id)?;
+        let signers = signers.into_iter().map(Into::into).collect::<Vec<_>>();
+        let key = radix_engine_toolkit_core::functions::manifest::deduplication_key(
+            &manifest,
+            &signers,
+            *network_id,
+        )
+        .map_err(|error| {
+            InvocationHandlingError::EncodeError(debug_string(error), debug_string(manifest))
+        })?;
+        Ok(key.into())
+    }
+}
+
+export_function!(ManifestDeduplicationKey as manifest_deduplication_key);
+export_jni_function!(ManifestDeduplicationKey as manifestDeduplicationKey);
+
 //==================
 // Manifest Compile
 //==================
@@ -65,9 +113,12 @@ export_jni_function!(ManifestHash as manifestHash);
 pub struct ManifestCompileInput {
     pub manifest: SerializableTransactionManifest,
     pub network_id: SerializableU8,
+    /// The encoding that the compiled payload is returned in, hex if not specified.
+    #[serde(default)]
+    pub bytes_encoding: SerializableBytesEncoding,
 }
 #[typeshare::typeshare]
-pub type ManifestCompileOutput = SerializableBytes;
+pub type ManifestCompileOutput = String;
 
 pub struct ManifestCompile;
 impl<'f> Function<'f> for ManifestCompile {
@@ -78,6 +129,7 @@ impl<'f> Function<'f> for ManifestCompile {
         ManifestCompileInput {
             manifest,
             network_id,
+            bytes_encoding,
         }: Self::Input,
     ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
         let manifest = manifest.to_native(*network_id)?;
@@ -86,7 +138,7 @@ impl<'f> Function<'f> for ManifestCompile {
                 InvocationHandlingError::EncodeError(debug_string(error), debug_string(manifest))
             },
         )?;
-        Ok(compile.into())
+        Ok(bytes_encoding.encode(&compile))
     }
 }
 
@@ -100,7 +152,10 @@ export_jni_function!(ManifestCompile as manifestCompile);
 #[typeshare::typeshare]
 #[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
 pub struct ManifestDecompileInput {
-    pub compiled: SerializableBytes,
+    pub compiled: String,
+    /// The encoding of the compiled payload, hex if not specified.
+    #[serde(default)]
+    pub bytes_encoding: SerializableBytesEncoding,
     pub network_id: SerializableU8,
     pub instructions_kind: SerializableInstructionsKind,
 }
@@ -115,11 +170,13 @@ impl<'a> Function<'a> for ManifestDecompile {
     fn handle(
         ManifestDecompileInput {
             compiled,
+            bytes_encoding,
             network_id,
             instructions_kind,
         }: Self::Input,
     ) -> Result<Self::Output, InvocationHandlingError> {
-        let manifest = radix_engine_toolkit_core::functions::manifest::decompile(&**compiled)
+        let compiled = bytes_encoding.decode(&compiled)?;
+        let manifest = radix_engine_toolkit_core::functions::manifest::decompile(&compiled)
             .map_err(|error| {
                 InvocationHandlingError::EncodeError(debug_string(error), debug_string(compiled))
             })?;
@@ -178,3 +235,955 @@ impl<'a> Function<'a> for ManifestStaticallyValidate {
 
 export_function!(ManifestStaticallyValidate as manifest_statically_validate);
 export_jni_function!(ManifestStaticallyValidate as manifestStaticallyValidate);
+
+//==================
+// Manifest Convert
+//==================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestConvertInput {
+    pub manifest: SerializableTransactionManifest,
+    pub network_id: SerializableU8,
+    pub instructions_kind: SerializableInstructionsKind,
+}
+#[typeshare::typeshare]
+pub type ManifestConvertOutput = SerializableTransactionManifest;
+
+/// Converts the instructions of the manifest to the given kind. Unlike [`InstructionsConvert`], the
+/// blobs of the manifest are carried over as-is and the converted manifest is checked to hash to
+/// the same value as the original one, so an intent built with the converted manifest has the same
+/// hash as one built with the original manifest.
+pub struct ManifestConvert;
+impl<'f> Function<'f> for ManifestConvert {
+    type Input = ManifestConvertInput;
+    type Output = ManifestConvertOutput;
+
+    fn handle(
+        ManifestConvertInput {
+            manifest,
+            network_id,
+            instructions_kind,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let mut instructions = manifest.instructions.clone();
+        instructions.convert_serializable_instructions_kind(instructions_kind, *network_id)?;
+        let converted_manifest = SerializableTransactionManifest {
+            instructions,
+            blobs: manifest.blobs.clone(),
+        };
+
+        let hash =
+            |manifest: &SerializableTransactionManifest| -> Result<Hash, InvocationHandlingError> {
+                let manifest = manifest.to_native(*network_id)?;
+                radix_engine_toolkit_core::functions::manifest::hash(&manifest).map_err(|error| {
+                    InvocationHandlingError::EncodeError(
+                        debug_string(error),
+                        debug_string(manifest),
+                    )
+                })
+            };
+        let original_hash = hash(&manifest)?;
+        let converted_hash = hash(&converted_manifest)?;
+        if original_hash != converted_hash {
+            return Err(InvocationHandlingError::ManifestConversionError(format!(
+                "The hash of the converted manifest ({converted_hash}) does not match that of the \
+                original manifest ({original_hash})"
+            )));
+        }
+
+        Ok(converted_manifest)
+    }
+}
+
+export_function!(ManifestConvert as manifest_convert);
+export_jni_function!(ManifestConvert as manifestConvert);
+
+//================================
+// Manifest Encode Call Arguments
+//================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestEncodeCallArgumentsInput {
+    pub package_definition: SerializableBytes,
+    pub blueprint_name: String,
+    pub function_name: String,
+    pub arguments: Vec<SerializableManifestValue>,
+}
+#[typeshare::typeshare]
+pub type ManifestEncodeCallArgumentsOutput = SerializableBytes;
+
+pub struct ManifestEncodeCallArguments;
+impl<'f> Function<'f> for ManifestEncodeCallArguments {
+    type Input = ManifestEncodeCallArgumentsInput;
+    type Output = ManifestEncodeCallArgumentsOutput;
+
+    fn handle(
+        ManifestEncodeCallArgumentsInput {
+            package_definition,
+            blueprint_name,
+            function_name,
+            arguments,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let package_definition = crate::context::package_definition(&package_definition)?;
+        let arguments = arguments
+            .iter()
+            .map(|argument| {
+                argument.to_manifest_value().map_err(|error| {
+                    InvocationHandlingError::EncodeError(
+                        debug_string(error),
+                        debug_string(argument),
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let encoded = radix_engine_toolkit_core::functions::manifest::encode_call_arguments(
+            &package_definition,
+            &blueprint_name,
+            &function_name,
+            arguments,
+        )?;
+        Ok(encoded.into())
+    }
+}
+
+export_function!(ManifestEncodeCallArguments as manifest_encode_call_arguments);
+export_jni_function!(ManifestEncodeCallArguments as manifestEncodeCallArguments);
+
+//===============================
+// Manifest Encode Metadata Init
+//===============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestEncodeMetadataInitInput {
+    pub metadata_init: SerializableMetadataInit,
+    pub network_id: SerializableU8,
+}
+#[typeshare::typeshare]
+pub type ManifestEncodeMetadataInitOutput = SerializableManifestValue;
+
+pub struct ManifestEncodeMetadataInit;
+impl<'f> Function<'f> for ManifestEncodeMetadataInit {
+    type Input = ManifestEncodeMetadataInitInput;
+    type Output = ManifestEncodeMetadataInitOutput;
+
+    fn handle(
+        ManifestEncodeMetadataInitInput {
+            metadata_init,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let native_metadata_init = metadata_init_to_native(&metadata_init)?;
+        let value = SerializableManifestValue::from_typed(&native_metadata_init, *network_id)
+            .map_err(|error| {
+                InvocationHandlingError::EncodeError(
+                    debug_string(error),
+                    debug_string(metadata_init),
+                )
+            })?;
+        Ok(value)
+    }
+}
+
+export_function!(ManifestEncodeMetadataInit as manifest_encode_metadata_init);
+export_jni_function!(ManifestEncodeMetadataInit as manifestEncodeMetadataInit);
+
+//==========================
+// Manifest Faucet Free XRD
+//==========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestFaucetFreeXrdInput {
+    pub network_id: SerializableU8,
+    pub to_account: SerializableNodeId,
+    pub instructions_kind: SerializableInstructionsKind,
+}
+#[typeshare::typeshare]
+pub type ManifestFaucetFreeXrdOutput = SerializableTransactionManifest;
+
+pub struct ManifestFaucetFreeXrd;
+impl<'a> Function<'a> for ManifestFaucetFreeXrd {
+    type Input = ManifestFaucetFreeXrdInput;
+    type Output = ManifestFaucetFreeXrdOutput;
+
+    fn handle(
+        ManifestFaucetFreeXrdInput {
+            network_id,
+            to_account,
+            instructions_kind,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let to_account = ComponentAddress::try_from(to_account.clone())
+            .map_err(|_| InvocationHandlingError::InvalidAddress(to_account.0.to_string()))?;
+
+        let manifest = radix_engine_toolkit_core::functions::manifest::faucet_free_xrd(to_account);
+        let manifest = SerializableTransactionManifest::from_native(
+            &manifest,
+            *network_id,
+            instructions_kind,
+        )?;
+
+        Ok(manifest)
+    }
+}
+
+export_function!(ManifestFaucetFreeXrd as manifest_faucet_free_xrd);
+export_jni_function!(ManifestFaucetFreeXrd as manifestFaucetFreeXrd);
+
+//================================
+// Manifest Modify Add Guarantees
+//================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestModifyAddGuaranteesInput {
+    pub manifest: SerializableTransactionManifest,
+    pub guarantees: Vec<SerializableGuarantee>,
+    pub network_id: SerializableU8,
+    pub instructions_kind: SerializableInstructionsKind,
+}
+#[typeshare::typeshare]
+pub type ManifestModifyAddGuaranteesOutput = SerializableTransactionManifest;
+
+pub struct ManifestModifyAddGuarantees;
+impl<'a> Function<'a> for ManifestModifyAddGuarantees {
+    type Input = ManifestModifyAddGuaranteesInput;
+    type Output = ManifestModifyAddGuaranteesOutput;
+
+    fn handle(
+        ManifestModifyAddGuaranteesInput {
+            manifest,
+            guarantees,
+            network_id,
+            instructions_kind,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let manifest = manifest.to_native(*network_id)?;
+        let guarantees = guarantees
+            .into_iter()
+            .map(|guarantee| {
+                let resource_address = ResourceAddress::try_from(
+                    guarantee.resource_address.clone(),
+                )
+                .map_err(|_| {
+                    InvocationHandlingError::InvalidAddress(
+                        guarantee.resource_address.0.to_string(),
+                    )
+                })?;
+                Ok(Guarantee {
+                    instruction_index: *guarantee.instruction_index as usize,
+                    resource_address,
+                    amount: *guarantee.amount,
+                })
+            })
+            .collect::<Result<Vec<_>, InvocationHandlingError>>()?;
+
+        let manifest =
+            radix_engine_toolkit_core::functions::manifest::add_guarantees(&manifest, guarantees)?;
+        let manifest = SerializableTransactionManifest::from_native(
+            &manifest,
+            *network_id,
+            instructions_kind,
+        )?;
+
+        Ok(manifest)
+    }
+}
+
+export_function!(ManifestModifyAddGuarantees as manifest_modify_add_guarantees);
+export_jni_function!(ManifestModifyAddGuarantees as manifestModifyAddGuarantees);
+
+//==============================
+// Manifest Modify Set Lock Fee
+//==============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestModifySetLockFeeInput {
+    pub manifest: SerializableTransactionManifest,
+    pub account: SerializableNodeId,
+    pub amount: SerializableDecimal,
+    pub network_id: SerializableU8,
+    pub instructions_kind: SerializableInstructionsKind,
+}
+#[typeshare::typeshare]
+pub type ManifestModifySetLockFeeOutput = SerializableTransactionManifest;
+
+pub struct ManifestModifySetLockFee;
+impl<'a> Function<'a> for ManifestModifySetLockFee {
+    type Input = ManifestModifySetLockFeeInput;
+    type Output = ManifestModifySetLockFeeOutput;
+
+    fn handle(
+        ManifestModifySetLockFeeInput {
+            manifest,
+            account,
+            amount,
+            network_id,
+            instructions_kind,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let manifest = manifest.to_native(*network_id)?;
+        let account = ComponentAddress::try_from(account.clone())
+            .map_err(|_| InvocationHandlingError::InvalidAddress(account.0.to_string()))?;
+
+        let manifest = radix_engine_toolkit_core::functions::manifest::set_lock_fee(
+            &manifest, account, *amount,
+        )?;
+        let manifest = SerializableTransactionManifest::from_native(
+            &manifest,
+            *network_id,
+            instructions_kind,
+        )?;
+
+        Ok(manifest)
+    }
+}
+
+export_function!(ManifestModifySetLockFee as manifest_modify_set_lock_fee);
+export_jni_function!(ManifestModifySetLockFee as manifestModifySetLockFee);
+
+//============================
+// Manifest Modify Sponsor Fee
+//============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestModifySponsorFeeInput {
+    pub manifest: SerializableTransactionManifest,
+    pub sponsor: SerializableNodeId,
+    pub amount: SerializableDecimal,
+    pub network_id: SerializableU8,
+    pub instructions_kind: SerializableInstructionsKind,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestModifySponsorFeeOutput {
+    pub manifest: SerializableTransactionManifest,
+    /// The signers required from the sponsor, at least for locking the fee.
+    pub sponsor_signers: Vec<SerializableRequiredSigner>,
+    /// The signers required from the user.
+    pub user_signers: Vec<SerializableRequiredSigner>,
+}
+
+pub struct ManifestModifySponsorFee;
+impl<'a> Function<'a> for ManifestModifySponsorFee {
+    type Input = ManifestModifySponsorFeeInput;
+    type Output = ManifestModifySponsorFeeOutput;
+
+    fn handle(
+        ManifestModifySponsorFeeInput {
+            manifest,
+            sponsor,
+            amount,
+            network_id,
+            instructions_kind,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let manifest = manifest.to_native(*network_id)?;
+        let sponsor = ComponentAddress::try_from(sponsor.clone())
+            .map_err(|_| InvocationHandlingError::InvalidAddress(sponsor.0.to_string()))?;
+
+        let sponsored_manifest = radix_engine_toolkit_core::functions::manifest::sponsor_fee(
+            &manifest, sponsor, *amount,
+        )?;
+        let required_signers = |signers: Vec<_>| {
+            signers
+                .into_iter()
+                .map(|signer| SerializableRequiredSigner::new(signer, *network_id))
+                .collect()
+        };
+
+        Ok(ManifestModifySponsorFeeOutput {
+            manifest: SerializableTransactionManifest::from_native(
+                &sponsored_manifest.manifest,
+                *network_id,
+                instructions_kind,
+            )?,
+            sponsor_signers: required_signers(sponsored_manifest.sponsor_signers),
+            user_signers: required_signers(sponsored_manifest.user_signers),
+        })
+    }
+}
+
+export_function!(ManifestModifySponsorFee as manifest_modify_sponsor_fee);
+export_jni_function!(ManifestModifySponsorFee as manifestModifySponsorFee);
+
+//=================================
+// Manifest Extract Fee Sponsorship
+//=================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestExtractFeeSponsorshipInput {
+    pub manifest: SerializableTransactionManifest,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestExtractFeeSponsorshipOutput {
+    /// The accounts that fees are locked against, in the order of their first lock.
+    pub fee_payers: Vec<SerializableNodeId>,
+    /// The accounts that resources are withdrawn from or deposited into.
+    pub asset_movers: Vec<SerializableNodeId>,
+    /// The fee payers whose assets don't move, i.e. that only pay the fees for others.
+    pub sponsors: Vec<SerializableNodeId>,
+    pub is_sponsored: bool,
+}
+
+pub struct ManifestExtractFeeSponsorship;
+impl<'a> Function<'a> for ManifestExtractFeeSponsorship {
+    type Input = ManifestExtractFeeSponsorshipInput;
+    type Output = ManifestExtractFeeSponsorshipOutput;
+
+    fn handle(
+        ManifestExtractFeeSponsorshipInput {
+            manifest,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let manifest = manifest.to_native(*network_id)?;
+
+        let fee_sponsorship =
+            radix_engine_toolkit_core::functions::manifest::fee_sponsorship(&manifest.instructions);
+        let addresses = |accounts: &[ComponentAddress]| {
+            accounts
+                .iter()
+                .map(|account| SerializableNodeId::new(account.into_node_id(), *network_id))
+                .collect()
+        };
+
+        Ok(ManifestExtractFeeSponsorshipOutput {
+            fee_payers: addresses(&fee_sponsorship.fee_payers),
+            asset_movers: addresses(&fee_sponsorship.asset_movers),
+            sponsors: addresses(&fee_sponsorship.sponsors),
+            is_sponsored: fee_sponsorship.is_sponsored(),
+        })
+    }
+}
+
+export_function!(ManifestExtractFeeSponsorship as manifest_extract_fee_sponsorship);
+export_jni_function!(ManifestExtractFeeSponsorship as manifestExtractFeeSponsorship);
+
+//==============================
+// Manifest Modify Instructions
+//==============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestModifyInstructionsInput {
+    pub manifest: SerializableTransactionManifest,
+    pub modifications: Vec<SerializableInstructionModification>,
+    pub network_id: SerializableU8,
+    pub instructions_kind: SerializableInstructionsKind,
+}
+#[typeshare::typeshare]
+pub type ManifestModifyInstructionsOutput = SerializableTransactionManifest;
+
+pub struct ManifestModifyInstructions;
+impl<'a> Function<'a> for ManifestModifyInstructions {
+    type Input = ManifestModifyInstructionsInput;
+    type Output = ManifestModifyInstructionsOutput;
+
+    fn handle(
+        ManifestModifyInstructionsInput {
+            manifest,
+            modifications,
+            network_id,
+            instructions_kind,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let manifest = manifest.to_native(*network_id)?;
+        let modifications = modifications
+            .into_iter()
+            .enumerate()
+            .map(|(modification_index, modification)| {
+                modification.to_native().map_err(|error| {
+                    SerializableInstructionsError::LocatedInstructionConversionError(
+                        LocatedInstructionConversionError {
+                            instruction_index: modification_index,
+                            error,
+                        },
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let manifest = radix_engine_toolkit_core::functions::manifest::modify_instructions(
+            &manifest,
+            modifications,
+        )?;
+        let manifest = SerializableTransactionManifest::from_native(
+            &manifest,
+            *network_id,
+            instructions_kind,
+        )?;
+
+        Ok(manifest)
+    }
+}
+
+export_function!(ManifestModifyInstructions as manifest_modify_instructions);
+export_jni_function!(ManifestModifyInstructions as manifestModifyInstructions);
+
+//=========================
+// Manifest Enforce Policy
+//=========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestEnforcePolicyInput {
+    pub manifest: SerializableTransactionManifest,
+    pub policy: SerializableManifestPolicy,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestEnforcePolicyOutput {
+    pub passed: bool,
+    pub violations: Vec<SerializablePolicyViolation>,
+}
+
+pub struct ManifestEnforcePolicy;
+impl<'a> Function<'a> for ManifestEnforcePolicy {
+    type Input = ManifestEnforcePolicyInput;
+    type Output = ManifestEnforcePolicyOutput;
+
+    fn handle(
+        ManifestEnforcePolicyInput {
+            manifest,
+            policy,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let manifest = manifest.to_native(*network_id)?;
+        let policy = policy.to_native()?;
+
+        let violations =
+            radix_engine_toolkit_core::functions::manifest::enforce_policy(&manifest, &policy)
+                .into_iter()
+                .map(|violation| SerializablePolicyViolation::new(violation, *network_id))
+                .collect::<Vec<_>>();
+
+        Ok(ManifestEnforcePolicyOutput {
+            passed: violations.is_empty(),
+            violations,
+        })
+    }
+}
+
+export_function!(ManifestEnforcePolicy as manifest_enforce_policy);
+export_jni_function!(ManifestEnforcePolicy as manifestEnforcePolicy);
+
+//=============================
+// Manifest Validate Fee Locks
+//=============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestValidateFeeLocksInput {
+    pub manifest: SerializableTransactionManifest,
+    pub network_id: SerializableU8,
+    pub target_fee: SerializableDecimal,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestValidateFeeLocksOutput {
+    pub meets_target: bool,
+    pub fee_payers: SerializableFeePayers,
+    pub total_lock: SerializableDecimal,
+    pub total_contingent_lock: SerializableDecimal,
+    pub shortfall: SerializableDecimal,
+}
+
+pub struct ManifestValidateFeeLocks;
+impl<'a> Function<'a> for ManifestValidateFeeLocks {
+    type Input = ManifestValidateFeeLocksInput;
+    type Output = ManifestValidateFeeLocksOutput;
+
+    fn handle(
+        ManifestValidateFeeLocksInput {
+            manifest,
+            network_id,
+            target_fee,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let manifest = manifest.to_native(*network_id)?;
+
+        let validation = radix_engine_toolkit_core::functions::manifest::validate_fee_locks(
+            &manifest.instructions,
+            *target_fee,
+        );
+
+        Ok(ManifestValidateFeeLocksOutput {
+            meets_target: validation.meets_target(),
+            fee_payers: SerializableFeePayers::new(validation.fee_payers, *network_id),
+            total_lock: validation.total_lock.into(),
+            total_contingent_lock: validation.total_contingent_lock.into(),
+            shortfall: validation.shortfall.into(),
+        })
+    }
+}
+
+export_function!(ManifestValidateFeeLocks as manifest_validate_fee_locks);
+export_jni_function!(ManifestValidateFeeLocks as manifestValidateFeeLocks);
+
+//=================================
+// Manifest Validate Id Allocation
+//=================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestValidateIdAllocationInput {
+    pub manifest: SerializableTransactionManifest,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+pub type ManifestValidateIdAllocationOutput = Vec<SerializableIdAllocationDiagnostic>;
+
+pub struct ManifestValidateIdAllocation;
+impl<'a> Function<'a> for ManifestValidateIdAllocation {
+    type Input = ManifestValidateIdAllocationInput;
+    type Output = ManifestValidateIdAllocationOutput;
+
+    fn handle(
+        ManifestValidateIdAllocationInput {
+            manifest,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let manifest = manifest.to_native(*network_id)?;
+
+        let diagnostics = radix_engine_toolkit_core::functions::manifest::validate_id_allocation(
+            &manifest.instructions,
+        );
+
+        Ok(diagnostics.into_iter().map(Into::into).collect())
+    }
+}
+
+export_function!(ManifestValidateIdAllocation as manifest_validate_id_allocation);
+export_jni_function!(ManifestValidateIdAllocation as manifestValidateIdAllocation);
+
+//===========================
+// Manifest Resolve Includes
+//===========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestResolveIncludesInput {
+    pub manifest: String,
+    /// The contents of the fragments that may be included, keyed by the path used to include them.
+    pub fragments: HashMap<String, String>,
+}
+
+#[typeshare::typeshare]
+pub type ManifestResolveIncludesOutput = String;
+
+pub struct ManifestResolveIncludes;
+impl<'a> Function<'a> for ManifestResolveIncludes {
+    type Input = ManifestResolveIncludesInput;
+    type Output = ManifestResolveIncludesOutput;
+
+    fn handle(
+        ManifestResolveIncludesInput {
+            manifest,
+            fragments,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let manifest =
+            radix_engine_toolkit_core::functions::manifest::resolve_includes(&manifest, |path| {
+                fragments
+                    .get(path)
+                    .cloned()
+                    .ok_or("No fragment was provided for the path")
+            })?;
+        Ok(manifest)
+    }
+}
+
+export_function!(ManifestResolveIncludes as manifest_resolve_includes);
+export_jni_function!(ManifestResolveIncludes as manifestResolveIncludes);
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableGuarantee {
+    pub instruction_index: SerializableU64,
+    pub resource_address: SerializableNodeId,
+    pub amount: SerializableDecimal,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableInstructionModification {
+    Insert {
+        index: SerializableU64,
+        instruction: SerializableInstruction,
+    },
+    Remove {
+        index: SerializableU64,
+    },
+    Replace {
+        index: SerializableU64,
+        instruction: SerializableInstruction,
+    },
+}
+
+impl SerializableInstructionModification {
+    pub fn to_native(&self) -> Result<InstructionModification, InstructionConversionError> {
+        let modification = match self {
+            Self::Insert { index, instruction } => InstructionModification::Insert {
+                index: **index as usize,
+                instruction: instruction.to_instruction()?,
+            },
+            Self::Remove { index } => InstructionModification::Remove {
+                index: **index as usize,
+            },
+            Self::Replace { index, instruction } => InstructionModification::Replace {
+                index: **index as usize,
+                instruction: instruction.to_instruction()?,
+            },
+        };
+        Ok(modification)
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableManifestPolicy {
+    pub allowed_packages: Option<Vec<SerializableNodeId>>,
+    pub allowed_components: Option<Vec<SerializableNodeId>>,
+    pub allowed_resources: Option<Vec<SerializableNodeId>>,
+    pub max_xrd_spend: Option<SerializableDecimal>,
+}
+
+impl SerializableManifestPolicy {
+    pub fn to_native(&self) -> Result<ManifestPolicy, InvocationHandlingError> {
+        fn to_native_addresses<T>(
+            addresses: &Option<Vec<SerializableNodeId>>,
+        ) -> Result<Option<HashSet<T>>, InvocationHandlingError>
+        where
+            T: TryFrom<SerializableNodeId> + Eq + std::hash::Hash,
+        {
+            addresses
+                .as_ref()
+                .map(|addresses| {
+                    addresses
+                        .iter()
+                        .map(|address| {
+                            T::try_from(address.clone()).map_err(|_| {
+                                InvocationHandlingError::InvalidAddress(address.0.to_string())
+                            })
+                        })
+                        .collect::<Result<HashSet<_>, _>>()
+                })
+                .transpose()
+        }
+
+        Ok(ManifestPolicy {
+            allowed_packages: to_native_addresses(&self.allowed_packages)?,
+            allowed_components: to_native_addresses(&self.allowed_components)?,
+            allowed_resources: to_native_addresses(&self.allowed_resources)?,
+            max_xrd_spend: self.max_xrd_spend.as_ref().map(|value| **value),
+        })
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializablePolicyViolation {
+    PackageNotAllowed {
+        instruction_index: SerializableU64,
+        package_address: SerializableNodeId,
+    },
+    ComponentNotAllowed {
+        instruction_index: SerializableU64,
+        component_address: SerializableNodeId,
+    },
+    ResourceNotAllowed {
+        instruction_index: SerializableU64,
+        resource_address: SerializableNodeId,
+    },
+    NamedAddressNotAllowed {
+        instruction_index: SerializableU64,
+    },
+    ResourceMethodNotAllowed {
+        instruction_index: SerializableU64,
+        resource_address: SerializableNodeId,
+        method_name: String,
+    },
+    DirectVaultMethodNotAllowed {
+        instruction_index: SerializableU64,
+        vault_address: SerializableNodeId,
+        method_name: String,
+    },
+    InvalidArguments {
+        instruction_index: SerializableU64,
+        method_name: String,
+    },
+    MaxXrdSpendExceeded {
+        max_xrd_spend: SerializableDecimal,
+        xrd_spend: SerializableDecimal,
+    },
+}
+
+impl SerializablePolicyViolation {
+    pub fn new(violation: PolicyViolation, network_id: u8) -> Self {
+        match violation {
+            PolicyViolation::PackageNotAllowed {
+                instruction_index,
+                package_address,
+            } => Self::PackageNotAllowed {
+                instruction_index: (instruction_index as u64).into(),
+                package_address: SerializableNodeId::new(
+                    package_address.into_node_id(),
+                    network_id,
+                ),
+            },
+            PolicyViolation::ComponentNotAllowed {
+                instruction_index,
+                component_address,
+            } => Self::ComponentNotAllowed {
+                instruction_index: (instruction_index as u64).into(),
+                component_address: SerializableNodeId::new(
+                    component_address.into_node_id(),
+                    network_id,
+                ),
+            },
+            PolicyViolation::ResourceNotAllowed {
+                instruction_index,
+                resource_address,
+            } => Self::ResourceNotAllowed {
+                instruction_index: (instruction_index as u64).into(),
+                resource_address: SerializableNodeId::new(
+                    resource_address.into_node_id(),
+                    network_id,
+                ),
+            },
+            PolicyViolation::NamedAddressNotAllowed { instruction_index } => {
+                Self::NamedAddressNotAllowed {
+                    instruction_index: (instruction_index as u64).into(),
+                }
+            }
+            PolicyViolation::ResourceMethodNotAllowed {
+                instruction_index,
+                resource_address,
+                method_name,
+            } => Self::ResourceMethodNotAllowed {
+                instruction_index: (instruction_index as u64).into(),
+                resource_address: SerializableNodeId::new(
+                    resource_address.into_node_id(),
+                    network_id,
+                ),
+                method_name,
+            },
+            PolicyViolation::DirectVaultMethodNotAllowed {
+                instruction_index,
+                vault_address,
+                method_name,
+            } => Self::DirectVaultMethodNotAllowed {
+                instruction_index: (instruction_index as u64).into(),
+                vault_address: SerializableNodeId::new(vault_address.into_node_id(), network_id),
+                method_name,
+            },
+            PolicyViolation::InvalidArguments {
+                instruction_index,
+                method_name,
+            } => Self::InvalidArguments {
+                instruction_index: (instruction_index as u64).into(),
+                method_name,
+            },
+            PolicyViolation::MaxXrdSpendExceeded {
+                max_xrd_spend,
+                xrd_spend,
+            } => Self::MaxXrdSpendExceeded {
+                max_xrd_spend: max_xrd_spend.into(),
+                xrd_spend: xrd_spend.into(),
+            },
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableIdAllocationDiagnostic {
+    pub instruction_index: SerializableU64,
+    pub kind: SerializableIdAllocationDiagnosticKind,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableIdAllocationDiagnosticKind {
+    BucketNotYetCreated(SerializableBucketId),
+    BucketAlreadyConsumed {
+        bucket: SerializableBucketId,
+        consumed_at: SerializableU64,
+    },
+    BucketLockedByProofs {
+        bucket: SerializableBucketId,
+        proofs: Vec<SerializableProofId>,
+    },
+    BucketNeverConsumed(SerializableBucketId),
+    ProofNotYetCreated(SerializableProofId),
+    ProofAlreadyDropped {
+        proof: SerializableProofId,
+        dropped_at: SerializableU64,
+    },
+    ProofNeverDropped(SerializableProofId),
+}
+
+impl From<IdAllocationDiagnostic> for SerializableIdAllocationDiagnostic {
+    fn from(value: IdAllocationDiagnostic) -> Self {
+        let to_u64 = |value: usize| SerializableU64::from(value as u64);
+        let kind = match value.kind {
+            IdAllocationDiagnosticKind::BucketNotYetCreated(bucket) => {
+                SerializableIdAllocationDiagnosticKind::BucketNotYetCreated(bucket.into())
+            }
+            IdAllocationDiagnosticKind::BucketAlreadyConsumed {
+                bucket,
+                consumed_at,
+            } => SerializableIdAllocationDiagnosticKind::BucketAlreadyConsumed {
+                bucket: bucket.into(),
+                consumed_at: to_u64(consumed_at),
+            },
+            IdAllocationDiagnosticKind::BucketLockedByProofs { bucket, proofs } => {
+                SerializableIdAllocationDiagnosticKind::BucketLockedByProofs {
+                    bucket: bucket.into(),
+                    proofs: proofs.into_iter().map(Into::into).collect(),
+                }
+            }
+            IdAllocationDiagnosticKind::BucketNeverConsumed(bucket) => {
+                SerializableIdAllocationDiagnosticKind::BucketNeverConsumed(bucket.into())
+            }
+            IdAllocationDiagnosticKind::ProofNotYetCreated(proof) => {
+                SerializableIdAllocationDiagnosticKind::ProofNotYetCreated(proof.into())
+            }
+            IdAllocationDiagnosticKind::ProofAlreadyDropped { proof, dropped_at } => {
+                SerializableIdAllocationDiagnosticKind::ProofAlreadyDropped {
+                    proof: proof.into(),
+                    dropped_at: to_u64(dropped_at),
+                }
+            }
+            IdAllocationDiagnosticKind::ProofNeverDropped(proof) => {
+                SerializableIdAllocationDiagnosticKind::ProofNeverDropped(proof.into())
+            }
+        };
+        Self {
+            instruction_index: to_u64(value.instruction_index),
+            kind,
+        }
+    }
+}
@@ -21,7 +21,6 @@ use crate::prelude::*;
 
 use radix_engine_common::prelude::*;
 use radix_engine_toolkit_core::functions::manifest_sbor::*;
-use radix_engine_toolkit_core::utils::*;
 use sbor::{LocalTypeId, Schema};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -79,8 +78,7 @@ impl<'f> Function<'f> for ManifestSborDecodeToString {
         } else {
             None
         };
-        let network_definition = network_definition_from_network_id(network_id);
-        let bech32_encoder = AddressBech32Encoder::new(&network_definition);
+        let bech32_encoder = crate::context::bech32_encoder(network_id);
 
         let string =
             radix_engine_toolkit_core::functions::manifest_sbor::decode_to_string_representation(
@@ -97,6 +95,39 @@ impl<'f> Function<'f> for ManifestSborDecodeToString {
 export_function!(ManifestSborDecodeToString as manifest_sbor_decode_to_string);
 export_jni_function!(ManifestSborDecodeToString as manifestSborDecodeToString);
 
+//============================================
+// Manifest Sbor Encode String Representation
+//============================================
+
+/// A Scrypto value in one of its string representations which is encoded as a manifest value, with
+/// references becoming static addresses.
+#[typeshare::typeshare]
+pub type ManifestSborEncodeStringRepresentationInput = SerializableScryptoSborStringRepresentation;
+
+#[typeshare::typeshare]
+pub type ManifestSborEncodeStringRepresentationOutput = SerializableBytes;
+
+pub struct ManifestSborEncodeStringRepresentation;
+impl<'f> Function<'f> for ManifestSborEncodeStringRepresentation {
+    type Input = ManifestSborEncodeStringRepresentationInput;
+    type Output = ManifestSborEncodeStringRepresentationOutput;
+
+    fn handle(input: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let bytes =
+            radix_engine_toolkit_core::functions::manifest_sbor::encode_string_representation(
+                input.into(),
+            )?;
+        Ok(bytes.into())
+    }
+}
+
+export_function!(
+    ManifestSborEncodeStringRepresentation as manifest_sbor_encode_string_representation
+);
+export_jni_function!(
+    ManifestSborEncodeStringRepresentation as manifestSborEncodeStringRepresentation
+);
+
 #[typeshare::typeshare]
 #[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
 #[serde(tag = "kind", content = "value")]
@@ -16,17 +16,37 @@
 // under the License.
 
 pub mod address;
+pub mod amount;
+pub mod context;
+pub mod dapp_definition;
 pub mod derive;
+pub mod events;
 pub mod execution;
+#[cfg(feature = "gateway-models")]
+pub mod gateway;
 pub mod handler;
+pub mod hashing;
+pub mod header;
 pub mod information;
 pub mod instructions;
 pub mod intent;
+pub mod legacy;
+#[cfg(feature = "legacy-api")]
+pub mod legacy_api;
 pub mod macros;
 pub mod manifest;
 pub mod manifest_sbor;
+pub mod native_state;
 pub mod notarized_transaction;
+pub mod olympia;
+pub mod personal_message;
+pub mod public_key;
+pub mod sbor;
+pub mod schema;
 pub mod scrypto_sbor;
 pub mod signed_intent;
 pub mod traits;
+pub mod transaction_request;
 pub mod utils;
+pub mod validation;
+pub mod value;
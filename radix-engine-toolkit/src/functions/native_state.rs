@@ -0,0 +1,231 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+use radix_engine_toolkit_core::functions::native_state::{NativeBlueprint, NativeFieldState};
+use schemars::JsonSchema;
+use scrypto::prelude::*;
+use serde::{Deserialize, Serialize};
+
+//=====================
+// Native State Decode
+//=====================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct NativeStateDecodeInput {
+    pub blueprint: SerializableNativeBlueprint,
+    pub field_index: SerializableU8,
+    pub encoded_payload: SerializableBytes,
+    pub representation: SerializableSerializationMode,
+    pub network_id: SerializableU8,
+}
+#[typeshare::typeshare]
+pub type NativeStateDecodeOutput = SerializableNativeFieldState;
+
+pub struct NativeStateDecode;
+impl<'f> Function<'f> for NativeStateDecode {
+    type Input = NativeStateDecodeInput;
+    type Output = NativeStateDecodeOutput;
+
+    fn handle(
+        NativeStateDecodeInput {
+            blueprint,
+            field_index,
+            encoded_payload,
+            representation,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let bech32_encoder = crate::context::bech32_encoder(*network_id);
+
+        // The payload is validated against the schema of the field before it's decoded into its
+        // typed model, the string representation is only returned for fields without one.
+        let string_representation =
+            radix_engine_toolkit_core::functions::native_state::decode_field(
+                blueprint.into(),
+                *field_index,
+                &*encoded_payload,
+                representation.into(),
+                &bech32_encoder,
+            )?;
+        let state = radix_engine_toolkit_core::functions::native_state::decode_typed_field(
+            blueprint.into(),
+            *field_index,
+            &*encoded_payload,
+        )?;
+
+        Ok(match state {
+            Some(state) => SerializableNativeFieldState::new(state, *network_id),
+            None => SerializableNativeFieldState::Other {
+                string_representation,
+            },
+        })
+    }
+}
+
+export_function!(NativeStateDecode as native_state_decode);
+export_jni_function!(NativeStateDecode as nativeStateDecode);
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SerializableNativeBlueprint {
+    Account,
+    Validator,
+    AccessController,
+    OneResourcePool,
+    TwoResourcePool,
+    MultiResourcePool,
+    FungibleResourceManager,
+    NonFungibleResourceManager,
+    FungibleVault,
+    NonFungibleVault,
+}
+
+impl From<SerializableNativeBlueprint> for NativeBlueprint {
+    fn from(value: SerializableNativeBlueprint) -> Self {
+        match value {
+            SerializableNativeBlueprint::Account => Self::Account,
+            SerializableNativeBlueprint::Validator => Self::Validator,
+            SerializableNativeBlueprint::AccessController => Self::AccessController,
+            SerializableNativeBlueprint::OneResourcePool => Self::OneResourcePool,
+            SerializableNativeBlueprint::TwoResourcePool => Self::TwoResourcePool,
+            SerializableNativeBlueprint::MultiResourcePool => Self::MultiResourcePool,
+            SerializableNativeBlueprint::FungibleResourceManager => Self::FungibleResourceManager,
+            SerializableNativeBlueprint::NonFungibleResourceManager => {
+                Self::NonFungibleResourceManager
+            }
+            SerializableNativeBlueprint::FungibleVault => Self::FungibleVault,
+            SerializableNativeBlueprint::NonFungibleVault => Self::NonFungibleVault,
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableNativeFieldState {
+    FungibleVaultBalance {
+        amount: SerializableDecimal,
+    },
+    NonFungibleVaultBalance {
+        amount: SerializableDecimal,
+    },
+    FungibleResourceManagerDivisibility {
+        divisibility: SerializableU8,
+    },
+    FungibleResourceManagerTotalSupply {
+        total_supply: SerializableDecimal,
+    },
+    Account {
+        default_deposit_rule: SerializableDefaultDepositRule,
+    },
+    OneResourcePool {
+        vault: SerializableNodeId,
+        pool_unit_resource_address: SerializableNodeId,
+    },
+    TwoResourcePool {
+        vaults: Vec<SerializablePoolVault>,
+        pool_unit_resource_address: SerializableNodeId,
+    },
+    MultiResourcePool {
+        vaults: Vec<SerializablePoolVault>,
+        pool_unit_resource_address: SerializableNodeId,
+    },
+    /// A field that there is no typed model for, in the requested string representation.
+    Other {
+        string_representation: String,
+    },
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializablePoolVault {
+    pub resource_address: SerializableNodeId,
+    pub vault: SerializableNodeId,
+}
+
+impl SerializableNativeFieldState {
+    pub fn new(state: NativeFieldState, network_id: u8) -> Self {
+        let pool_vaults = |vaults: Vec<(ResourceAddress, NodeId)>| {
+            vaults
+                .into_iter()
+                .map(|(resource_address, vault)| SerializablePoolVault {
+                    resource_address: SerializableNodeId::new(
+                        resource_address.into_node_id(),
+                        network_id,
+                    ),
+                    vault: SerializableNodeId::new(vault, network_id),
+                })
+                .collect::<Vec<_>>()
+        };
+        match state {
+            NativeFieldState::FungibleVaultBalance { amount } => Self::FungibleVaultBalance {
+                amount: amount.into(),
+            },
+            NativeFieldState::NonFungibleVaultBalance { amount } => Self::NonFungibleVaultBalance {
+                amount: amount.into(),
+            },
+            NativeFieldState::FungibleResourceManagerDivisibility { divisibility } => {
+                Self::FungibleResourceManagerDivisibility {
+                    divisibility: divisibility.into(),
+                }
+            }
+            NativeFieldState::FungibleResourceManagerTotalSupply { total_supply } => {
+                Self::FungibleResourceManagerTotalSupply {
+                    total_supply: total_supply.into(),
+                }
+            }
+            NativeFieldState::Account {
+                default_deposit_rule,
+            } => Self::Account {
+                default_deposit_rule: default_deposit_rule.into(),
+            },
+            NativeFieldState::OneResourcePool {
+                vault,
+                pool_unit_resource_address,
+            } => Self::OneResourcePool {
+                vault: SerializableNodeId::new(vault, network_id),
+                pool_unit_resource_address: SerializableNodeId::new(
+                    pool_unit_resource_address.into_node_id(),
+                    network_id,
+                ),
+            },
+            NativeFieldState::TwoResourcePool {
+                vaults,
+                pool_unit_resource_address,
+            } => Self::TwoResourcePool {
+                vaults: pool_vaults(vaults),
+                pool_unit_resource_address: SerializableNodeId::new(
+                    pool_unit_resource_address.into_node_id(),
+                    network_id,
+                ),
+            },
+            NativeFieldState::MultiResourcePool {
+                vaults,
+                pool_unit_resource_address,
+            } => Self::MultiResourcePool {
+                vaults: pool_vaults(vaults),
+                pool_unit_resource_address: SerializableNodeId::new(
+                    pool_unit_resource_address.into_node_id(),
+                    network_id,
+                ),
+            },
+        }
+    }
+}
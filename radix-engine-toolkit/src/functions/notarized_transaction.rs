@@ -15,7 +15,11 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use radix_engine_toolkit_core::functions::notarized_transaction::{
+    NotaryVerificationFailure, PreflightIssue, SubmissionEnvelope,
+};
 use schemars::JsonSchema;
+use scrypto::prelude::Epoch;
 use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
@@ -60,9 +64,15 @@ export_jni_function!(NotarizedTransactionHash as notarizedTransactionHash);
 //===============================
 
 #[typeshare::typeshare]
-pub type NotarizedTransactionCompileInput = SerializableNotarizedTransaction;
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct NotarizedTransactionCompileInput {
+    pub notarized_transaction: SerializableNotarizedTransaction,
+    /// The encoding that the compiled payload is returned in, hex if not specified.
+    #[serde(default)]
+    pub bytes_encoding: SerializableBytesEncoding,
+}
 #[typeshare::typeshare]
-pub type NotarizedTransactionCompileOutput = SerializableBytes;
+pub type NotarizedTransactionCompileOutput = String;
 
 pub struct NotarizedTransactionCompile;
 impl<'f> Function<'f> for NotarizedTransactionCompile {
@@ -70,7 +80,10 @@ impl<'f> Function<'f> for NotarizedTransactionCompile {
     type Output = NotarizedTransactionCompileOutput;
 
     fn handle(
-        notarized_transaction: Self::Input,
+        NotarizedTransactionCompileInput {
+            notarized_transaction,
+            bytes_encoding,
+        }: Self::Input,
     ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
         let notarized_transaction = notarized_transaction
             .to_native(*notarized_transaction.signed_intent.intent.header.network_id)?;
@@ -83,7 +96,7 @@ impl<'f> Function<'f> for NotarizedTransactionCompile {
                 debug_string(notarized_transaction),
             )
         })?;
-        Ok(compile.into())
+        Ok(bytes_encoding.encode(&compile))
     }
 }
 
@@ -97,7 +110,10 @@ export_jni_function!(NotarizedTransactionCompile as notarizedTransactionCompile)
 #[typeshare::typeshare]
 #[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
 pub struct NotarizedTransactionDecompileInput {
-    pub compiled: SerializableBytes,
+    pub compiled: String,
+    /// The encoding of the compiled payload, hex if not specified.
+    #[serde(default)]
+    pub bytes_encoding: SerializableBytesEncoding,
     pub instructions_kind: SerializableInstructionsKind,
 }
 #[typeshare::typeshare]
@@ -111,11 +127,13 @@ impl<'a> Function<'a> for NotarizedTransactionDecompile {
     fn handle(
         NotarizedTransactionDecompileInput {
             compiled,
+            bytes_encoding,
             instructions_kind,
         }: Self::Input,
     ) -> Result<Self::Output, InvocationHandlingError> {
+        let compiled = bytes_encoding.decode(&compiled)?;
         let notarized_transaction =
-            radix_engine_toolkit_core::functions::notarized_transaction::decompile(&**compiled)
+            radix_engine_toolkit_core::functions::notarized_transaction::decompile(&compiled)
                 .map_err(|error| {
                     InvocationHandlingError::EncodeError(
                         debug_string(error),
@@ -186,3 +204,257 @@ export_function!(
 export_jni_function!(
     NotarizedTransactionStaticallyValidate as notarizedTransactionStaticallyValidate
 );
+
+//==========================================
+// Notarized Transaction Submission Envelope
+//==========================================
+
+#[typeshare::typeshare]
+pub type NotarizedTransactionSubmissionEnvelopeInput = SerializableNotarizedTransaction;
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct NotarizedTransactionSubmissionEnvelopeOutput {
+    pub payload: SerializableBytes,
+    pub payload_id: SerializableTransactionHash,
+    pub intent_hash: SerializableTransactionHash,
+}
+
+pub struct NotarizedTransactionSubmissionEnvelope;
+impl<'f> Function<'f> for NotarizedTransactionSubmissionEnvelope {
+    type Input = NotarizedTransactionSubmissionEnvelopeInput;
+    type Output = NotarizedTransactionSubmissionEnvelopeOutput;
+
+    fn handle(
+        notarized_transaction: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let notarized_transaction = notarized_transaction
+            .to_native(*notarized_transaction.signed_intent.intent.header.network_id)?;
+        let SubmissionEnvelope {
+            payload,
+            payload_id,
+            intent_hash,
+        } = radix_engine_toolkit_core::functions::notarized_transaction::submission_envelope(
+            &notarized_transaction,
+        )
+        .map_err(|error| {
+            InvocationHandlingError::EncodeError(
+                debug_string(error),
+                debug_string(notarized_transaction),
+            )
+        })?;
+
+        Ok(Self::Output {
+            payload: payload.into(),
+            payload_id: payload_id.into(),
+            intent_hash: intent_hash.into(),
+        })
+    }
+}
+
+export_function!(
+    NotarizedTransactionSubmissionEnvelope as notarized_transaction_submission_envelope
+);
+export_jni_function!(
+    NotarizedTransactionSubmissionEnvelope as notarizedTransactionSubmissionEnvelope
+);
+
+//=============================
+// Transaction Preflight Check
+//=============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct TransactionPreflightCheckInput {
+    pub notarized_transaction: SerializableNotarizedTransaction,
+    pub validation_config: SerializableValidationConfig,
+    pub current_epoch: Option<SerializableU64>,
+    pub recently_used_nonces: Vec<SerializableU32>,
+}
+
+#[typeshare::typeshare]
+pub type TransactionPreflightCheckOutput = Vec<SerializablePreflightIssue>;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializablePreflightIssue {
+    EncodeError(String),
+    PayloadTooLarge {
+        payload_size: SerializableU64,
+        max_payload_size: SerializableU64,
+    },
+    StaticValidationFailed(String),
+    EpochWindowNotStarted {
+        current_epoch: SerializableU64,
+        start_epoch_inclusive: SerializableU64,
+    },
+    EpochWindowExpired {
+        current_epoch: SerializableU64,
+        end_epoch_exclusive: SerializableU64,
+    },
+    NoFeeLocked,
+    NonceRecentlyUsed(SerializableU32),
+    NotarySignatoryMisconfigured(SerializableNotarySignatoryIssue),
+}
+
+impl From<PreflightIssue> for SerializablePreflightIssue {
+    fn from(value: PreflightIssue) -> Self {
+        match value {
+            PreflightIssue::EncodeError(error) => Self::EncodeError(debug_string(error)),
+            PreflightIssue::PayloadTooLarge {
+                payload_size,
+                max_payload_size,
+            } => Self::PayloadTooLarge {
+                payload_size: (payload_size as u64).into(),
+                max_payload_size: (max_payload_size as u64).into(),
+            },
+            PreflightIssue::StaticValidationFailed(error) => {
+                Self::StaticValidationFailed(debug_string(error))
+            }
+            PreflightIssue::EpochWindowNotStarted {
+                current_epoch,
+                start_epoch_inclusive,
+            } => Self::EpochWindowNotStarted {
+                current_epoch: current_epoch.number().into(),
+                start_epoch_inclusive: start_epoch_inclusive.number().into(),
+            },
+            PreflightIssue::EpochWindowExpired {
+                current_epoch,
+                end_epoch_exclusive,
+            } => Self::EpochWindowExpired {
+                current_epoch: current_epoch.number().into(),
+                end_epoch_exclusive: end_epoch_exclusive.number().into(),
+            },
+            PreflightIssue::NoFeeLocked => Self::NoFeeLocked,
+            PreflightIssue::NonceRecentlyUsed(nonce) => Self::NonceRecentlyUsed(nonce.into()),
+            PreflightIssue::NotarySignatoryMisconfigured(issue) => {
+                Self::NotarySignatoryMisconfigured(issue.into())
+            }
+        }
+    }
+}
+
+pub struct TransactionPreflightCheck;
+impl<'a> Function<'a> for TransactionPreflightCheck {
+    type Input = TransactionPreflightCheckInput;
+    type Output = TransactionPreflightCheckOutput;
+
+    fn handle(
+        TransactionPreflightCheckInput {
+            notarized_transaction,
+            validation_config,
+            current_epoch,
+            recently_used_nonces,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let notarized_transaction = notarized_transaction
+            .to_native(*notarized_transaction.signed_intent.intent.header.network_id)?;
+        let recently_used_nonces = recently_used_nonces
+            .into_iter()
+            .map(|nonce| *nonce)
+            .collect::<Vec<_>>();
+
+        let issues = radix_engine_toolkit_core::functions::notarized_transaction::preflight_check(
+            &notarized_transaction,
+            validation_config.into(),
+            current_epoch.map(|epoch| Epoch::of(*epoch)),
+            &recently_used_nonces,
+        );
+        Ok(issues.into_iter().map(Into::into).collect())
+    }
+}
+
+export_function!(TransactionPreflightCheck as transaction_preflight_check);
+export_jni_function!(TransactionPreflightCheck as transactionPreflightCheck);
+
+//=====================================
+// Notarized Transaction Verify Notary
+//=====================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct NotarizedTransactionVerifyNotaryInput {
+    pub compiled: SerializableBytes,
+    pub expected_notary_public_key: SerializablePublicKey,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct NotarizedTransactionVerifyNotaryOutput {
+    /// The notary public key in the header of the transaction.
+    pub notary_public_key: SerializablePublicKey,
+    /// The reasons for which the transaction was not notarized by the expected public key, empty
+    /// if it was.
+    pub failures: Vec<SerializableNotaryVerificationFailure>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableNotaryVerificationFailure {
+    NotaryPublicKeyMismatch { actual: SerializablePublicKey },
+    NotarySignatureCurveMismatch,
+    InvalidNotarySignature,
+}
+
+impl From<NotaryVerificationFailure> for SerializableNotaryVerificationFailure {
+    fn from(value: NotaryVerificationFailure) -> Self {
+        match value {
+            NotaryVerificationFailure::NotaryPublicKeyMismatch { actual } => {
+                Self::NotaryPublicKeyMismatch {
+                    actual: actual.into(),
+                }
+            }
+            NotaryVerificationFailure::NotarySignatureCurveMismatch => {
+                Self::NotarySignatureCurveMismatch
+            }
+            NotaryVerificationFailure::InvalidNotarySignature => Self::InvalidNotarySignature,
+        }
+    }
+}
+
+pub struct NotarizedTransactionVerifyNotary;
+impl<'a> Function<'a> for NotarizedTransactionVerifyNotary {
+    type Input = NotarizedTransactionVerifyNotaryInput;
+    type Output = NotarizedTransactionVerifyNotaryOutput;
+
+    fn handle(
+        NotarizedTransactionVerifyNotaryInput {
+            compiled,
+            expected_notary_public_key,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let notarized_transaction =
+            radix_engine_toolkit_core::functions::notarized_transaction::decompile(&**compiled)
+                .map_err(|error| {
+                    InvocationHandlingError::DecodeError(
+                        debug_string(error),
+                        debug_string(compiled),
+                    )
+                })?;
+
+        let failures = radix_engine_toolkit_core::functions::notarized_transaction::verify_notary(
+            &notarized_transaction,
+            &expected_notary_public_key.into(),
+        )
+        .map_err(|error| {
+            InvocationHandlingError::EncodeError(
+                debug_string(error),
+                debug_string(&notarized_transaction),
+            )
+        })?;
+
+        Ok(Self::Output {
+            notary_public_key: notarized_transaction
+                .signed_intent
+                .intent
+                .header
+                .notary_public_key
+                .into(),
+            failures: failures.into_iter().map(Into::into).collect(),
+        })
+    }
+}
+
+export_function!(NotarizedTransactionVerifyNotary as notarized_transaction_verify_notary);
+export_jni_function!(NotarizedTransactionVerifyNotary as notarizedTransactionVerifyNotary);
@@ -0,0 +1,136 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+use radix_engine_toolkit_core::functions::olympia::{OlympiaAction, OlympiaInstruction};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+//============================
+// Olympia Transaction Decode
+//============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct OlympiaTransactionDecodeInput {
+    pub payload: SerializableBytes,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct OlympiaTransactionDecodeOutput {
+    pub actions: Vec<SerializableOlympiaAction>,
+    pub messages: Vec<SerializableBytes>,
+}
+
+pub struct OlympiaTransactionDecode;
+impl<'f> Function<'f> for OlympiaTransactionDecode {
+    type Input = OlympiaTransactionDecodeInput;
+    type Output = OlympiaTransactionDecodeOutput;
+
+    fn handle(
+        OlympiaTransactionDecodeInput {
+            payload,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let transaction =
+            radix_engine_toolkit_core::functions::olympia::decode_transaction(&payload)?;
+        let actions = radix_engine_toolkit_core::functions::olympia::actions(&transaction)
+            .map_err(|error| InvocationHandlingError::DerivationError(debug_string(error)))?;
+
+        let messages = transaction
+            .instructions
+            .into_iter()
+            .filter_map(|instruction| match instruction {
+                OlympiaInstruction::Message(message) => Some(message.into()),
+                _ => None,
+            })
+            .collect();
+
+        Ok(Self::Output {
+            actions: actions
+                .into_iter()
+                .map(|action| SerializableOlympiaAction::from_native(action, *network_id))
+                .collect(),
+            messages,
+        })
+    }
+}
+
+export_function!(OlympiaTransactionDecode as olympia_transaction_decode);
+export_jni_function!(OlympiaTransactionDecode as olympiaTransactionDecode);
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableOlympiaAction {
+    TokensDeposited {
+        account: SerializableNodeId,
+        resource_address: SerializableNodeId,
+        amount: SerializableDecimal,
+    },
+    StakePrepared {
+        account: SerializableNodeId,
+        validator: SerializableSecp256k1PublicKey,
+        amount: SerializableDecimal,
+    },
+    UnstakePrepared {
+        account: SerializableNodeId,
+        validator: SerializableSecp256k1PublicKey,
+        amount: SerializableDecimal,
+    },
+}
+
+impl SerializableOlympiaAction {
+    pub fn from_native(native: OlympiaAction, network_id: u8) -> Self {
+        match native {
+            OlympiaAction::TokensDeposited {
+                account,
+                resource_address,
+                amount,
+            } => Self::TokensDeposited {
+                account: SerializableNodeId::from_global_address(account, network_id),
+                resource_address: SerializableNodeId::from_global_address(
+                    resource_address,
+                    network_id,
+                ),
+                amount: amount.into(),
+            },
+            OlympiaAction::StakePrepared {
+                account,
+                validator,
+                amount,
+            } => Self::StakePrepared {
+                account: SerializableNodeId::from_global_address(account, network_id),
+                validator: validator.into(),
+                amount: amount.into(),
+            },
+            OlympiaAction::UnstakePrepared {
+                account,
+                validator,
+                amount,
+            } => Self::UnstakePrepared {
+                account: SerializableNodeId::from_global_address(account, network_id),
+                validator: validator.into(),
+                amount: amount.into(),
+            },
+        }
+    }
+}
@@ -0,0 +1,85 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+//=======================
+// Personal Message Hash
+//=======================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct PersonalMessageHashInput {
+    pub message: SerializableBytes,
+}
+#[typeshare::typeshare]
+pub type PersonalMessageHashOutput = SerializableHash;
+
+pub struct PersonalMessageHash;
+impl<'f> Function<'f> for PersonalMessageHash {
+    type Input = PersonalMessageHashInput;
+    type Output = PersonalMessageHashOutput;
+
+    fn handle(
+        PersonalMessageHashInput { message }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        Ok(radix_engine_toolkit_core::functions::personal_message::hash(&message).into())
+    }
+}
+
+export_function!(PersonalMessageHash as personal_message_hash);
+export_jni_function!(PersonalMessageHash as personalMessageHash);
+
+//=========================
+// Personal Message Verify
+//=========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct PersonalMessageVerifyInput {
+    pub message: SerializableBytes,
+    pub signature: SerializableSignatureWithPublicKey,
+    pub network_id: SerializableU8,
+}
+#[typeshare::typeshare]
+pub type PersonalMessageVerifyOutput = Option<SerializableNodeId>;
+
+pub struct PersonalMessageVerify;
+impl<'f> Function<'f> for PersonalMessageVerify {
+    type Input = PersonalMessageVerifyInput;
+    type Output = PersonalMessageVerifyOutput;
+
+    fn handle(
+        PersonalMessageVerifyInput {
+            message,
+            signature,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let signer_account = radix_engine_toolkit_core::functions::personal_message::verify(
+            &message,
+            &signature.into(),
+        );
+        Ok(signer_account
+            .map(|address| SerializableNodeId::new(address.into_node_id(), *network_id)))
+    }
+}
+
+export_function!(PersonalMessageVerify as personal_message_verify);
+export_jni_function!(PersonalMessageVerify as personalMessageVerify);
@@ -0,0 +1,132 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+//=====================
+// Public Key Validate
+//=====================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct PublicKeyValidateInput {
+    pub curve: SerializableCurveType,
+    /// The encoded public key, compressed in the case of Secp256k1.
+    pub public_key: SerializableBytes,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum PublicKeyValidateOutput {
+    Valid(SerializablePublicKey),
+    Invalid(String),
+}
+
+pub struct PublicKeyValidate;
+impl<'f> Function<'f> for PublicKeyValidate {
+    type Input = PublicKeyValidateInput;
+    type Output = PublicKeyValidateOutput;
+
+    fn handle(
+        PublicKeyValidateInput { curve, public_key }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let public_key = match curve {
+            SerializableCurveType::Secp256k1 => {
+                radix_engine_toolkit_core::functions::public_key::secp256k1_validate(&public_key)
+                    .map(PublicKey::Secp256k1)
+            }
+            SerializableCurveType::Ed25519 => {
+                radix_engine_toolkit_core::functions::public_key::ed25519_validate(&public_key)
+                    .map(PublicKey::Ed25519)
+            }
+        };
+
+        match public_key {
+            Ok(public_key) => Ok(Self::Output::Valid(public_key.into())),
+            Err(error) => Ok(Self::Output::Invalid(debug_string(error))),
+        }
+    }
+}
+
+export_function!(PublicKeyValidate as public_key_validate);
+export_jni_function!(PublicKeyValidate as publicKeyValidate);
+
+//================================
+// Public Key Secp256k1 Compress
+//================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct PublicKeySecp256k1CompressInput {
+    /// The 65-byte uncompressed public key.
+    pub public_key: SerializableBytes,
+}
+
+#[typeshare::typeshare]
+pub type PublicKeySecp256k1CompressOutput = SerializableSecp256k1PublicKey;
+
+pub struct PublicKeySecp256k1Compress;
+impl<'f> Function<'f> for PublicKeySecp256k1Compress {
+    type Input = PublicKeySecp256k1CompressInput;
+    type Output = PublicKeySecp256k1CompressOutput;
+
+    fn handle(
+        PublicKeySecp256k1CompressInput { public_key }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let public_key =
+            radix_engine_toolkit_core::functions::public_key::secp256k1_compress(&public_key)?;
+        Ok(public_key.into())
+    }
+}
+
+export_function!(PublicKeySecp256k1Compress as public_key_secp256k1_compress);
+export_jni_function!(PublicKeySecp256k1Compress as publicKeySecp256k1Compress);
+
+//==================================
+// Public Key Secp256k1 Decompress
+//==================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct PublicKeySecp256k1DecompressInput {
+    pub public_key: SerializableSecp256k1PublicKey,
+}
+
+/// The 65-byte uncompressed public key.
+#[typeshare::typeshare]
+pub type PublicKeySecp256k1DecompressOutput = SerializableBytes;
+
+pub struct PublicKeySecp256k1Decompress;
+impl<'f> Function<'f> for PublicKeySecp256k1Decompress {
+    type Input = PublicKeySecp256k1DecompressInput;
+    type Output = PublicKeySecp256k1DecompressOutput;
+
+    fn handle(
+        PublicKeySecp256k1DecompressInput { public_key }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let public_key = radix_engine_toolkit_core::functions::public_key::secp256k1_decompress(
+            &public_key.into(),
+        )?;
+        Ok(public_key.to_vec().into())
+    }
+}
+
+export_function!(PublicKeySecp256k1Decompress as public_key_secp256k1_decompress);
+export_jni_function!(PublicKeySecp256k1Decompress as publicKeySecp256k1Decompress);
@@ -0,0 +1,98 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+use radix_engine_toolkit_core::functions::sbor::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+//=====================
+// Sbor Decode Unknown
+//=====================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SborDecodeUnknownInput {
+    pub encoded_payload: SerializableBytes,
+    pub network_id: SerializableU8,
+    pub maximum_depth: Option<SerializableU64>,
+    pub maximum_length: Option<SerializableU64>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SborDecodeUnknownOutput {
+    pub kind: SerializableSborPayloadKind,
+    pub programmatic_json: String,
+    pub natural_json: String,
+}
+
+pub struct SborDecodeUnknown;
+impl<'f> Function<'f> for SborDecodeUnknown {
+    type Input = SborDecodeUnknownInput;
+    type Output = SborDecodeUnknownOutput;
+
+    fn handle(
+        SborDecodeUnknownInput {
+            encoded_payload,
+            network_id,
+            maximum_depth,
+            maximum_length,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let default_limits = SborDecodeLimits::default();
+        let limits = SborDecodeLimits {
+            maximum_depth: maximum_depth
+                .map_or(default_limits.maximum_depth, |value| *value as usize),
+            maximum_length: maximum_length
+                .map_or(default_limits.maximum_length, |value| *value as usize),
+        };
+        let bech32_encoder = crate::context::bech32_encoder(*network_id);
+
+        let UnknownSborPayload {
+            kind,
+            programmatic_json,
+            natural_json,
+        } = decode_unknown(&**encoded_payload, limits, &bech32_encoder)?;
+
+        Ok(Self::Output {
+            kind: kind.into(),
+            programmatic_json,
+            natural_json,
+        })
+    }
+}
+
+export_function!(SborDecodeUnknown as sbor_decode_unknown);
+export_jni_function!(SborDecodeUnknown as sborDecodeUnknown);
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SerializableSborPayloadKind {
+    Scrypto,
+    Manifest,
+}
+
+impl From<SborPayloadKind> for SerializableSborPayloadKind {
+    fn from(value: SborPayloadKind) -> Self {
+        match value {
+            SborPayloadKind::Scrypto => Self::Scrypto,
+            SborPayloadKind::Manifest => Self::Manifest,
+        }
+    }
+}
@@ -0,0 +1,305 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+use radix_engine_toolkit_core::functions::schema::*;
+use schemars::JsonSchema;
+use scrypto::prelude::*;
+use serde::{Deserialize, Serialize};
+
+//===============
+// Schema Decode
+//===============
+
+#[typeshare::typeshare]
+pub type SchemaDecodeInput = SerializableBytes;
+#[typeshare::typeshare]
+pub type SchemaDecodeOutput = Vec<SerializableBlueprintInformation>;
+
+pub struct SchemaDecode;
+impl<'f> Function<'f> for SchemaDecode {
+    type Input = SchemaDecodeInput;
+    type Output = SchemaDecodeOutput;
+
+    fn handle(input: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let package_definition = crate::context::package_definition(&input)?;
+        let blueprints = package_information(&package_definition)?;
+        Ok(blueprints.into_iter().map(Into::into).collect())
+    }
+}
+
+export_function!(SchemaDecode as schema_decode);
+export_jni_function!(SchemaDecode as schemaDecode);
+
+//===========================
+// Schema Function Signature
+//===========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SchemaFunctionSignatureInput {
+    pub package_definition: SerializableBytes,
+    pub blueprint_name: String,
+    pub function_name: String,
+}
+#[typeshare::typeshare]
+pub type SchemaFunctionSignatureOutput = Option<SerializableFunctionInformation>;
+
+pub struct SchemaFunctionSignature;
+impl<'f> Function<'f> for SchemaFunctionSignature {
+    type Input = SchemaFunctionSignatureInput;
+    type Output = SchemaFunctionSignatureOutput;
+
+    fn handle(
+        SchemaFunctionSignatureInput {
+            package_definition,
+            blueprint_name,
+            function_name,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let package_definition = crate::context::package_definition(&package_definition)?;
+        let signature = function_signature(&package_definition, &blueprint_name, &function_name)?;
+        Ok(signature.map(Into::into))
+    }
+}
+
+export_function!(SchemaFunctionSignature as schema_function_signature);
+export_jni_function!(SchemaFunctionSignature as schemaFunctionSignature);
+
+//========
+// Models
+//========
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableBlueprintInformation {
+    pub name: String,
+    pub is_transient: bool,
+    pub feature_set: Vec<String>,
+    pub functions: Vec<SerializableFunctionInformation>,
+    pub events: Vec<SerializableEventInformation>,
+    pub types: Vec<SerializableTypeDescription>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableFunctionInformation {
+    pub name: String,
+    pub export_name: String,
+    pub receiver: Option<SerializableReceiver>,
+    pub input: SerializableTypeRef,
+    pub output: SerializableTypeRef,
+    pub arguments: Vec<SerializableFieldDescription>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableReceiver {
+    pub is_mutable: bool,
+    pub is_direct_access: bool,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableEventInformation {
+    pub name: String,
+    pub event_type: SerializableTypeRef,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableTypeRef {
+    Static(SerializableLocalTypeId),
+    Generic(SerializableU8),
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableTypeDescription {
+    pub local_type_id: SerializableLocalTypeId,
+    pub type_name: Option<String>,
+    pub kind: SerializableTypeKindDescription,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableTypeKindDescription {
+    Any,
+    Bool,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    String,
+    Array {
+        element_type: SerializableLocalTypeId,
+    },
+    Tuple {
+        fields: Vec<SerializableFieldDescription>,
+    },
+    Enum {
+        variants: Vec<SerializableVariantDescription>,
+    },
+    Map {
+        key_type: SerializableLocalTypeId,
+        value_type: SerializableLocalTypeId,
+    },
+    Reference,
+    Own,
+    Decimal,
+    PreciseDecimal,
+    NonFungibleLocalId,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableFieldDescription {
+    pub name: Option<String>,
+    pub local_type_id: SerializableLocalTypeId,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableVariantDescription {
+    pub discriminator: SerializableU8,
+    pub name: Option<String>,
+    pub field_types: Vec<SerializableLocalTypeId>,
+}
+
+impl From<BlueprintInformation> for SerializableBlueprintInformation {
+    fn from(value: BlueprintInformation) -> Self {
+        Self {
+            name: value.name,
+            is_transient: value.is_transient,
+            feature_set: value.feature_set.into_iter().collect(),
+            functions: value.functions.into_iter().map(Into::into).collect(),
+            events: value.events.into_iter().map(Into::into).collect(),
+            types: value.types.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<FunctionInformation> for SerializableFunctionInformation {
+    fn from(value: FunctionInformation) -> Self {
+        Self {
+            name: value.name,
+            export_name: value.export_name,
+            receiver: value.receiver.map(|receiver_info| SerializableReceiver {
+                is_mutable: receiver_info.receiver == Receiver::SelfRefMut,
+                is_direct_access: receiver_info.ref_types.contains(RefTypes::DIRECT_ACCESS),
+            }),
+            input: value.input.into(),
+            output: value.output.into(),
+            arguments: value
+                .arguments
+                .into_iter()
+                .map(|argument| SerializableFieldDescription {
+                    name: argument.name,
+                    local_type_id: argument.local_type_id.into(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<EventInformation> for SerializableEventInformation {
+    fn from(value: EventInformation) -> Self {
+        Self {
+            name: value.name,
+            event_type: value.event_type.into(),
+        }
+    }
+}
+
+impl From<TypeRef<LocalTypeId>> for SerializableTypeRef {
+    fn from(value: TypeRef<LocalTypeId>) -> Self {
+        match value {
+            TypeRef::Static(local_type_id) => Self::Static(local_type_id.into()),
+            TypeRef::Generic(index) => Self::Generic(index.into()),
+        }
+    }
+}
+
+impl From<TypeDescription> for SerializableTypeDescription {
+    fn from(value: TypeDescription) -> Self {
+        let kind = match value.kind {
+            TypeKindDescription::Any => SerializableTypeKindDescription::Any,
+            TypeKindDescription::Bool => SerializableTypeKindDescription::Bool,
+            TypeKindDescription::I8 => SerializableTypeKindDescription::I8,
+            TypeKindDescription::I16 => SerializableTypeKindDescription::I16,
+            TypeKindDescription::I32 => SerializableTypeKindDescription::I32,
+            TypeKindDescription::I64 => SerializableTypeKindDescription::I64,
+            TypeKindDescription::I128 => SerializableTypeKindDescription::I128,
+            TypeKindDescription::U8 => SerializableTypeKindDescription::U8,
+            TypeKindDescription::U16 => SerializableTypeKindDescription::U16,
+            TypeKindDescription::U32 => SerializableTypeKindDescription::U32,
+            TypeKindDescription::U64 => SerializableTypeKindDescription::U64,
+            TypeKindDescription::U128 => SerializableTypeKindDescription::U128,
+            TypeKindDescription::String => SerializableTypeKindDescription::String,
+            TypeKindDescription::Reference => SerializableTypeKindDescription::Reference,
+            TypeKindDescription::Own => SerializableTypeKindDescription::Own,
+            TypeKindDescription::Decimal => SerializableTypeKindDescription::Decimal,
+            TypeKindDescription::PreciseDecimal => SerializableTypeKindDescription::PreciseDecimal,
+            TypeKindDescription::NonFungibleLocalId => {
+                SerializableTypeKindDescription::NonFungibleLocalId
+            }
+            TypeKindDescription::Array { element_type } => SerializableTypeKindDescription::Array {
+                element_type: element_type.into(),
+            },
+            TypeKindDescription::Tuple { fields } => SerializableTypeKindDescription::Tuple {
+                fields: fields
+                    .into_iter()
+                    .map(|(name, local_type_id)| SerializableFieldDescription {
+                        name,
+                        local_type_id: local_type_id.into(),
+                    })
+                    .collect(),
+            },
+            TypeKindDescription::Enum { variants } => SerializableTypeKindDescription::Enum {
+                variants: variants
+                    .into_iter()
+                    .map(|variant| SerializableVariantDescription {
+                        discriminator: variant.discriminator.into(),
+                        name: variant.name,
+                        field_types: variant.field_types.into_iter().map(Into::into).collect(),
+                    })
+                    .collect(),
+            },
+            TypeKindDescription::Map {
+                key_type,
+                value_type,
+            } => SerializableTypeKindDescription::Map {
+                key_type: key_type.into(),
+                value_type: value_type.into(),
+            },
+        };
+        Self {
+            local_type_id: value.local_type_id.into(),
+            type_name: value.type_name,
+            kind,
+        }
+    }
+}
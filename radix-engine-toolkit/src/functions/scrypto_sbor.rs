@@ -20,7 +20,6 @@ use std::ops::Deref;
 use crate::prelude::*;
 
 use radix_engine_common::prelude::*;
-use radix_engine_toolkit_core::utils::*;
 use sbor::{LocalTypeId, Schema};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -71,8 +70,7 @@ impl<'f> Function<'f> for ScryptoSborDecodeToString {
         } else {
             None
         };
-        let network_definition = network_definition_from_network_id(network_id);
-        let bech32_encoder = AddressBech32Encoder::new(&network_definition);
+        let bech32_encoder = crate::context::bech32_encoder(network_id);
 
         let string =
             radix_engine_toolkit_core::functions::scrypto_sbor::decode_to_string_representation(
@@ -94,6 +92,23 @@ export_jni_function!(ScryptoSborDecodeToString as scryptoSborDecodeToString);
 #[serde(tag = "kind", content = "value")]
 pub enum SerializableScryptoSborStringRepresentation {
     ProgrammaticJson(String),
+    /// The programmatic JSON format emitted by the Core and Gateway APIs.
+    CoreApiProgrammaticJson(String),
+}
+
+impl From<SerializableScryptoSborStringRepresentation>
+    for radix_engine_toolkit_core::functions::scrypto_sbor::StringRepresentation
+{
+    fn from(value: SerializableScryptoSborStringRepresentation) -> Self {
+        match value {
+            SerializableScryptoSborStringRepresentation::ProgrammaticJson(value) => {
+                Self::ProgrammaticJson(value)
+            }
+            SerializableScryptoSborStringRepresentation::CoreApiProgrammaticJson(value) => {
+                Self::CoreApiProgrammaticJson(value)
+            }
+        }
+    }
 }
 
 #[typeshare::typeshare]
@@ -108,14 +123,9 @@ impl<'f> Function<'f> for ScryptoSborEncodeStringRepresentation {
     type Output = ScryptoSborEncodeStringRepresentationOutput;
 
     fn handle(input: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
-        let input = match input {
-            SerializableScryptoSborStringRepresentation::ProgrammaticJson(value) => {
-                radix_engine_toolkit_core::functions::scrypto_sbor::StringRepresentation::ProgrammaticJson(value)
-            }
-        };
         let bytes =
             radix_engine_toolkit_core::functions::scrypto_sbor::encode_string_representation(
-                input,
+                input.into(),
             )?;
         Ok(bytes.into())
     }
@@ -18,6 +18,8 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use radix_engine_toolkit_core::functions::signed_intent::NotarySignatoryIssue;
+
 use crate::prelude::*;
 
 //====================
@@ -57,9 +59,15 @@ export_jni_function!(SignedIntentHash as signedIntentHash);
 //=======================
 
 #[typeshare::typeshare]
-pub type SignedIntentCompileInput = SerializableSignedIntent;
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SignedIntentCompileInput {
+    pub signed_intent: SerializableSignedIntent,
+    /// The encoding that the compiled payload is returned in, hex if not specified.
+    #[serde(default)]
+    pub bytes_encoding: SerializableBytesEncoding,
+}
 #[typeshare::typeshare]
-pub type SignedIntentCompileOutput = SerializableBytes;
+pub type SignedIntentCompileOutput = String;
 
 pub struct SignedIntentCompile;
 impl<'f> Function<'f> for SignedIntentCompile {
@@ -67,7 +75,10 @@ impl<'f> Function<'f> for SignedIntentCompile {
     type Output = SignedIntentCompileOutput;
 
     fn handle(
-        signed_intent: Self::Input,
+        SignedIntentCompileInput {
+            signed_intent,
+            bytes_encoding,
+        }: Self::Input,
     ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
         let signed_intent = signed_intent.to_native(*signed_intent.intent.header.network_id)?;
         let compile = radix_engine_toolkit_core::functions::signed_intent::compile(&signed_intent)
@@ -77,7 +88,7 @@ impl<'f> Function<'f> for SignedIntentCompile {
                     debug_string(signed_intent),
                 )
             })?;
-        Ok(compile.into())
+        Ok(bytes_encoding.encode(&compile))
     }
 }
 
@@ -91,7 +102,10 @@ export_jni_function!(SignedIntentCompile as signedIntentCompile);
 #[typeshare::typeshare]
 #[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
 pub struct SignedIntentDecompileInput {
-    pub compiled: SerializableBytes,
+    pub compiled: String,
+    /// The encoding of the compiled payload, hex if not specified.
+    #[serde(default)]
+    pub bytes_encoding: SerializableBytesEncoding,
     pub instructions_kind: SerializableInstructionsKind,
 }
 #[typeshare::typeshare]
@@ -105,11 +119,13 @@ impl<'a> Function<'a> for SignedIntentDecompile {
     fn handle(
         SignedIntentDecompileInput {
             compiled,
+            bytes_encoding,
             instructions_kind,
         }: Self::Input,
     ) -> Result<Self::Output, InvocationHandlingError> {
+        let compiled = bytes_encoding.decode(&compiled)?;
         let signed_intent = radix_engine_toolkit_core::functions::signed_intent::decompile(
-            &**compiled,
+            &compiled,
         )
         .map_err(|error| {
             InvocationHandlingError::EncodeError(debug_string(error), debug_string(compiled))
@@ -173,3 +189,91 @@ impl<'a> Function<'a> for SignedIntentStaticallyValidate {
 
 export_function!(SignedIntentStaticallyValidate as signed_intent_statically_validate);
 export_jni_function!(SignedIntentStaticallyValidate as signedIntentStaticallyValidate);
+
+//====================================
+// Signed Intent Normalize Signatures
+//====================================
+
+#[typeshare::typeshare]
+pub type SignedIntentNormalizeSignaturesInput = SerializableSignedIntent;
+#[typeshare::typeshare]
+pub type SignedIntentNormalizeSignaturesOutput = SerializableBytes;
+
+pub struct SignedIntentNormalizeSignatures;
+impl<'f> Function<'f> for SignedIntentNormalizeSignatures {
+    type Input = SignedIntentNormalizeSignaturesInput;
+    type Output = SignedIntentNormalizeSignaturesOutput;
+
+    fn handle(
+        signed_intent: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let signed_intent = signed_intent.to_native(*signed_intent.intent.header.network_id)?;
+        let signed_intent =
+            radix_engine_toolkit_core::functions::signed_intent::normalize_signatures(
+                &signed_intent,
+            )?;
+        let compile = radix_engine_toolkit_core::functions::signed_intent::compile(&signed_intent)
+            .map_err(|error| {
+                InvocationHandlingError::EncodeError(
+                    debug_string(error),
+                    debug_string(signed_intent),
+                )
+            })?;
+        Ok(compile.into())
+    }
+}
+
+export_function!(SignedIntentNormalizeSignatures as signed_intent_normalize_signatures);
+export_jni_function!(SignedIntentNormalizeSignatures as signedIntentNormalizeSignatures);
+
+//=======================================
+// Signed Intent Notary Signatory Issues
+//=======================================
+
+#[typeshare::typeshare]
+pub type SignedIntentNotarySignatoryIssuesInput = SerializableSignedIntent;
+#[typeshare::typeshare]
+pub type SignedIntentNotarySignatoryIssuesOutput = Vec<SerializableNotarySignatoryIssue>;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableNotarySignatoryIssue {
+    RedundantNotarySignature { index: SerializableU64 },
+    NotaryNotRequiredSigner,
+}
+
+impl From<NotarySignatoryIssue> for SerializableNotarySignatoryIssue {
+    fn from(value: NotarySignatoryIssue) -> Self {
+        match value {
+            NotarySignatoryIssue::RedundantNotarySignature { index } => {
+                Self::RedundantNotarySignature {
+                    index: (index as u64).into(),
+                }
+            }
+            NotarySignatoryIssue::NotaryNotRequiredSigner => Self::NotaryNotRequiredSigner,
+        }
+    }
+}
+
+pub struct SignedIntentNotarySignatoryIssues;
+impl<'f> Function<'f> for SignedIntentNotarySignatoryIssues {
+    type Input = SignedIntentNotarySignatoryIssuesInput;
+    type Output = SignedIntentNotarySignatoryIssuesOutput;
+
+    fn handle(
+        signed_intent: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let signed_intent = signed_intent.to_native(*signed_intent.intent.header.network_id)?;
+        let issues = radix_engine_toolkit_core::functions::signed_intent::notary_signatory_issues(
+            &signed_intent,
+        )
+        .map_err(|error| {
+            InvocationHandlingError::EncodeError(debug_string(error), debug_string(signed_intent))
+        })?;
+        Ok(issues.into_iter().map(Into::into).collect())
+    }
+}
+
+export_function!(SignedIntentNotarySignatoryIssues as signed_intent_notary_signatory_issues);
+export_jni_function!(SignedIntentNotarySignatoryIssues as signedIntentNotarySignatoryIssues);
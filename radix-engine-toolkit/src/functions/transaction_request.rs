@@ -0,0 +1,381 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_common::prelude::*;
+use radix_engine_toolkit_core::functions::transaction_request::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+//===============================
+// Transaction Request Normalize
+//===============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct TransactionRequestNormalizeInput {
+    pub request: SerializableTransactionRequest,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+pub type TransactionRequestNormalizeOutput = SerializableTransactionRequest;
+
+pub struct TransactionRequestNormalize;
+impl<'f> Function<'f> for TransactionRequestNormalize {
+    type Input = TransactionRequestNormalizeInput;
+    type Output = TransactionRequestNormalizeOutput;
+
+    fn handle(
+        TransactionRequestNormalizeInput {
+            request,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let request = request.to_native()?;
+        let request = radix_engine_toolkit_core::functions::transaction_request::normalize(request);
+
+        Ok(SerializableTransactionRequest::new(request, *network_id))
+    }
+}
+
+export_function!(TransactionRequestNormalize as transaction_request_normalize);
+export_jni_function!(TransactionRequestNormalize as transactionRequestNormalize);
+
+//==============================
+// Transaction Request Validate
+//==============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct TransactionRequestValidateInput {
+    pub request: SerializableTransactionRequest,
+    pub network_id: SerializableU8,
+    /// The limits to validate the request against, the limits of the transaction validator are
+    /// used when not provided.
+    #[serde(default)]
+    pub limits: Option<SerializableTransactionRequestLimits>,
+}
+
+#[typeshare::typeshare]
+pub type TransactionRequestValidateOutput = Vec<SerializableTransactionRequestViolation>;
+
+pub struct TransactionRequestValidate;
+impl<'f> Function<'f> for TransactionRequestValidate {
+    type Input = TransactionRequestValidateInput;
+    type Output = TransactionRequestValidateOutput;
+
+    fn handle(
+        TransactionRequestValidateInput {
+            request,
+            network_id,
+            limits,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let request = request.to_native()?;
+        let limits = limits.map(Into::into).unwrap_or_default();
+
+        let violations = radix_engine_toolkit_core::functions::transaction_request::validate(
+            &request,
+            *network_id,
+            &limits,
+        );
+
+        Ok(violations
+            .into_iter()
+            .map(|violation| SerializableTransactionRequestViolation::new(violation, *network_id))
+            .collect())
+    }
+}
+
+export_function!(TransactionRequestValidate as transaction_request_validate);
+export_jni_function!(TransactionRequestValidate as transactionRequestValidate);
+
+//=================================
+// Transaction Request To Manifest
+//=================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct TransactionRequestToManifestInput {
+    pub request: SerializableTransactionRequest,
+    pub network_id: SerializableU8,
+    pub instructions_kind: SerializableInstructionsKind,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum TransactionRequestToManifestOutput {
+    Manifest(SerializableTransactionManifest),
+    InvalidManifest(SerializableManifestCompileDiagnostic),
+}
+
+pub struct TransactionRequestToManifest;
+impl<'f> Function<'f> for TransactionRequestToManifest {
+    type Input = TransactionRequestToManifestInput;
+    type Output = TransactionRequestToManifestOutput;
+
+    fn handle(
+        TransactionRequestToManifestInput {
+            request,
+            network_id,
+            instructions_kind,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let request = request.to_native()?;
+
+        match radix_engine_toolkit_core::functions::transaction_request::to_manifest(
+            &request,
+            *network_id,
+        ) {
+            Ok(manifest) => SerializableTransactionManifest::from_native(
+                &manifest,
+                *network_id,
+                instructions_kind,
+            )
+            .map(TransactionRequestToManifestOutput::Manifest)
+            .map_err(Into::into),
+            Err(diagnostic) => Ok(TransactionRequestToManifestOutput::InvalidManifest(
+                diagnostic.into(),
+            )),
+        }
+    }
+}
+
+export_function!(TransactionRequestToManifest as transaction_request_to_manifest);
+export_jni_function!(TransactionRequestToManifest as transactionRequestToManifest);
+
+//===================================
+// Transaction Request From Manifest
+//===================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct TransactionRequestFromManifestInput {
+    pub manifest: SerializableTransactionManifest,
+    pub network_id: SerializableU8,
+    #[serde(default)]
+    pub message: Option<String>,
+    #[serde(default)]
+    pub lock_fee_hint: Option<SerializableLockFeeHint>,
+}
+
+#[typeshare::typeshare]
+pub type TransactionRequestFromManifestOutput = SerializableTransactionRequest;
+
+pub struct TransactionRequestFromManifest;
+impl<'f> Function<'f> for TransactionRequestFromManifest {
+    type Input = TransactionRequestFromManifestInput;
+    type Output = TransactionRequestFromManifestOutput;
+
+    fn handle(
+        TransactionRequestFromManifestInput {
+            manifest,
+            network_id,
+            message,
+            lock_fee_hint,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let manifest = manifest.to_native(*network_id)?;
+        let lock_fee_hint = lock_fee_hint
+            .map(|lock_fee_hint| lock_fee_hint.to_native())
+            .transpose()?;
+
+        let request = radix_engine_toolkit_core::functions::transaction_request::from_manifest(
+            &manifest,
+            *network_id,
+            message,
+            lock_fee_hint,
+        )
+        .map_err(|error| {
+            InvocationHandlingError::SerializableInstructionsError(debug_string(error))
+        })?;
+
+        Ok(SerializableTransactionRequest::new(request, *network_id))
+    }
+}
+
+export_function!(TransactionRequestFromManifest as transaction_request_from_manifest);
+export_jni_function!(TransactionRequestFromManifest as transactionRequestFromManifest);
+
+//========
+// Models
+//========
+
+/// The transaction request that dApps send to wallets. The manifest is in its string
+/// representation and the blobs are hex encoded.
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableTransactionRequest {
+    pub version: SerializableU64,
+    pub transaction_manifest: String,
+    #[serde(default)]
+    pub blobs: Vec<SerializableBytes>,
+    #[serde(default)]
+    pub message: Option<String>,
+    #[serde(default)]
+    pub lock_fee_hint: Option<SerializableLockFeeHint>,
+}
+
+impl SerializableTransactionRequest {
+    pub fn new(request: TransactionRequest, network_id: u8) -> Self {
+        Self {
+            version: request.version.into(),
+            transaction_manifest: request.transaction_manifest,
+            blobs: request.blobs.into_iter().map(Into::into).collect(),
+            message: request.message,
+            lock_fee_hint: request
+                .lock_fee_hint
+                .map(|lock_fee_hint| SerializableLockFeeHint::new(lock_fee_hint, network_id)),
+        }
+    }
+
+    pub fn to_native(&self) -> Result<TransactionRequest, InvocationHandlingError> {
+        Ok(TransactionRequest {
+            version: *self.version,
+            transaction_manifest: self.transaction_manifest.clone(),
+            blobs: self.blobs.iter().map(|blob| (**blob).clone()).collect(),
+            message: self.message.clone(),
+            lock_fee_hint: self
+                .lock_fee_hint
+                .as_ref()
+                .map(SerializableLockFeeHint::to_native)
+                .transpose()?,
+        })
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableLockFeeHint {
+    pub amount: SerializableDecimal,
+    #[serde(default)]
+    pub account: Option<SerializableNodeId>,
+}
+
+impl SerializableLockFeeHint {
+    pub fn new(lock_fee_hint: LockFeeHint, network_id: u8) -> Self {
+        Self {
+            amount: lock_fee_hint.amount.into(),
+            account: lock_fee_hint
+                .account
+                .map(|account| SerializableNodeId::new(account.into_node_id(), network_id)),
+        }
+    }
+
+    pub fn to_native(&self) -> Result<LockFeeHint, InvocationHandlingError> {
+        Ok(LockFeeHint {
+            amount: *self.amount,
+            account: self
+                .account
+                .as_ref()
+                .map(|address| {
+                    ComponentAddress::try_from(address.clone())
+                        .map_err(|_| InvocationHandlingError::InvalidAddress(address.0.to_string()))
+                })
+                .transpose()?,
+        })
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableTransactionRequestLimits {
+    pub max_manifest_length: SerializableU64,
+    pub max_blobs_size: SerializableU64,
+    pub max_message_length: SerializableU64,
+}
+
+impl From<SerializableTransactionRequestLimits> for TransactionRequestLimits {
+    fn from(value: SerializableTransactionRequestLimits) -> Self {
+        Self {
+            max_manifest_length: *value.max_manifest_length as usize,
+            max_blobs_size: *value.max_blobs_size as usize,
+            max_message_length: *value.max_message_length as usize,
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableTransactionRequestViolation {
+    UnsupportedVersion {
+        version: SerializableU64,
+        supported: SerializableU64,
+    },
+    ManifestTooLong {
+        length: SerializableU64,
+        max: SerializableU64,
+    },
+    BlobsTooLarge {
+        size: SerializableU64,
+        max: SerializableU64,
+    },
+    MessageTooLong {
+        length: SerializableU64,
+        max: SerializableU64,
+    },
+    LockFeeHintNotPositive {
+        amount: SerializableDecimal,
+    },
+    LockFeeHintAccountNotAnAccount {
+        address: SerializableNodeId,
+    },
+    InvalidManifest(SerializableManifestCompileDiagnostic),
+}
+
+impl SerializableTransactionRequestViolation {
+    pub fn new(violation: TransactionRequestViolation, network_id: u8) -> Self {
+        let to_u64 = |value: usize| SerializableU64::from(value as u64);
+        match violation {
+            TransactionRequestViolation::UnsupportedVersion { version, supported } => {
+                Self::UnsupportedVersion {
+                    version: version.into(),
+                    supported: supported.into(),
+                }
+            }
+            TransactionRequestViolation::ManifestTooLong { length, max } => Self::ManifestTooLong {
+                length: to_u64(length),
+                max: to_u64(max),
+            },
+            TransactionRequestViolation::BlobsTooLarge { size, max } => Self::BlobsTooLarge {
+                size: to_u64(size),
+                max: to_u64(max),
+            },
+            TransactionRequestViolation::MessageTooLong { length, max } => Self::MessageTooLong {
+                length: to_u64(length),
+                max: to_u64(max),
+            },
+            TransactionRequestViolation::LockFeeHintNotPositive { amount } => {
+                Self::LockFeeHintNotPositive {
+                    amount: amount.into(),
+                }
+            }
+            TransactionRequestViolation::LockFeeHintAccountNotAnAccount { address } => {
+                Self::LockFeeHintAccountNotAnAccount {
+                    address: SerializableNodeId::new(address.into_node_id(), network_id),
+                }
+            }
+            TransactionRequestViolation::InvalidManifest(diagnostic) => {
+                Self::InvalidManifest(diagnostic.into())
+            }
+        }
+    }
+}
@@ -19,6 +19,7 @@ use crate::prelude::*;
 use schemars::JsonSchema;
 use scrypto::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 #[typeshare::typeshare]
 pub type UtilsKnownAddressesInput = SerializableU8;
@@ -37,58 +38,62 @@ impl<'f> Function<'f> for UtilsKnownAddress {
     type Output = UtilsKnownAddressesOutput;
 
     fn handle(input: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
-        let network_id = *input;
+        Ok(known_addresses(*input))
+    }
+}
 
-        let resource_addresses = construct_addresses! {
-            ResourceAddresses,
-            network_id,
-            [
-                xrd,
-                secp256k1_signature_virtual_badge,
-                ed25519_signature_virtual_badge,
-                package_of_direct_caller_virtual_badge,
-                global_caller_virtual_badge,
-                system_transaction_badge,
-                package_owner_badge,
-                validator_owner_badge,
-                account_owner_badge,
-                identity_owner_badge,
-            ]
-        };
-        let package_addresses = construct_addresses! {
-            PackageAddresses,
-            network_id,
-            [
-                package_package,
-                resource_package,
-                account_package,
-                identity_package,
-                consensus_manager_package,
-                access_controller_package,
-                pool_package,
-                transaction_processor_package,
-                metadata_module_package,
-                royalty_module_package,
-                role_assignment_module_package,
-                genesis_helper_package,
-                faucet_package,
-            ]
-        };
-        let component_addresses = construct_addresses! {
-            ComponentAddresses,
-            network_id,
-            [
-                consensus_manager,
-                genesis_helper,
-                faucet,
-            ]
-        };
+fn known_addresses(network_id: u8) -> UtilsKnownAddressesOutput {
+    let resource_addresses = construct_addresses! {
+        ResourceAddresses,
+        network_id,
+        [
+            xrd,
+            secp256k1_signature_virtual_badge,
+            ed25519_signature_virtual_badge,
+            package_of_direct_caller_virtual_badge,
+            global_caller_virtual_badge,
+            system_transaction_badge,
+            package_owner_badge,
+            validator_owner_badge,
+            account_owner_badge,
+            identity_owner_badge,
+        ]
+    };
+    let package_addresses = construct_addresses! {
+        PackageAddresses,
+        network_id,
+        [
+            package_package,
+            resource_package,
+            account_package,
+            identity_package,
+            consensus_manager_package,
+            access_controller_package,
+            pool_package,
+            transaction_processor_package,
+            metadata_module_package,
+            royalty_module_package,
+            role_assignment_module_package,
+            genesis_helper_package,
+            faucet_package,
+            transaction_tracker_package,
+        ]
+    };
+    let component_addresses = construct_addresses! {
+        ComponentAddresses,
+        network_id,
+        [
+            consensus_manager,
+            genesis_helper,
+            faucet,
+            transaction_tracker,
+        ]
+    };
 
-        Ok(Self::Output {
-            component_addresses,
-            package_addresses,
-            resource_addresses,
-        })
+    UtilsKnownAddressesOutput {
+        component_addresses,
+        package_addresses,
+        resource_addresses,
     }
 }
 
@@ -123,6 +128,7 @@ pub struct PackageAddresses {
     pub role_assignment_module_package: SerializableNodeId,
     pub genesis_helper_package: SerializableNodeId,
     pub faucet_package: SerializableNodeId,
+    pub transaction_tracker_package: SerializableNodeId,
 }
 
 #[typeshare::typeshare]
@@ -131,6 +137,7 @@ pub struct ComponentAddresses {
     pub consensus_manager: SerializableNodeId,
     pub genesis_helper: SerializableNodeId,
     pub faucet: SerializableNodeId,
+    pub transaction_tracker: SerializableNodeId,
 }
 
 macro_rules! construct_addresses {
@@ -148,3 +155,269 @@ use construct_addresses;
 
 export_function!(UtilsKnownAddress as utils_known_addresses);
 export_jni_function!(UtilsKnownAddress as utilsKnownAddresses);
+
+//=======================
+// Known Addresses Batch
+//=======================
+
+#[typeshare::typeshare]
+pub type UtilsKnownAddressesBatchInput = Vec<SerializableU8>;
+
+#[typeshare::typeshare]
+pub type UtilsKnownAddressesBatchOutput = BTreeMap<SerializableU8, UtilsKnownAddressesOutput>;
+
+pub struct UtilsKnownAddressesBatch;
+impl<'f> Function<'f> for UtilsKnownAddressesBatch {
+    type Input = UtilsKnownAddressesBatchInput;
+    type Output = UtilsKnownAddressesBatchOutput;
+
+    fn handle(input: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        Ok(input
+            .into_iter()
+            .map(|network_id| (network_id, known_addresses(*network_id)))
+            .collect())
+    }
+}
+
+export_function!(UtilsKnownAddressesBatch as utils_known_addresses_batch);
+export_jni_function!(UtilsKnownAddressesBatch as utilsKnownAddressesBatch);
+
+//==================
+// Register Network
+//==================
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SerializableNetworkDefinition {
+    pub network_id: SerializableU8,
+    pub logical_name: String,
+    pub hrp_suffix: String,
+}
+
+impl From<NetworkDefinition> for SerializableNetworkDefinition {
+    fn from(value: NetworkDefinition) -> Self {
+        Self {
+            network_id: value.id.into(),
+            logical_name: value.logical_name,
+            hrp_suffix: value.hrp_suffix,
+        }
+    }
+}
+
+impl From<SerializableNetworkDefinition> for NetworkDefinition {
+    fn from(value: SerializableNetworkDefinition) -> Self {
+        Self {
+            id: *value.network_id,
+            logical_name: value.logical_name,
+            hrp_suffix: value.hrp_suffix,
+        }
+    }
+}
+
+#[typeshare::typeshare]
+pub type UtilsRegisterNetworkInput = SerializableNetworkDefinition;
+#[typeshare::typeshare]
+pub type UtilsRegisterNetworkOutput = Option<SerializableNetworkDefinition>;
+
+pub struct UtilsRegisterNetwork;
+impl<'f> Function<'f> for UtilsRegisterNetwork {
+    type Input = UtilsRegisterNetworkInput;
+    type Output = UtilsRegisterNetworkOutput;
+
+    fn handle(input: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let network_id = *input.network_id;
+        let previous = radix_engine_toolkit_core::utils::register_network_definition(input.into())?;
+        crate::context::evict_network_caches(network_id);
+        Ok(previous.map(Into::into))
+    }
+}
+
+export_function!(UtilsRegisterNetwork as utils_register_network);
+export_jni_function!(UtilsRegisterNetwork as utilsRegisterNetwork);
+
+//====================
+// Deregister Network
+//====================
+
+#[typeshare::typeshare]
+pub type UtilsDeregisterNetworkInput = SerializableU8;
+#[typeshare::typeshare]
+pub type UtilsDeregisterNetworkOutput = Option<SerializableNetworkDefinition>;
+
+pub struct UtilsDeregisterNetwork;
+impl<'f> Function<'f> for UtilsDeregisterNetwork {
+    type Input = UtilsDeregisterNetworkInput;
+    type Output = UtilsDeregisterNetworkOutput;
+
+    fn handle(input: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let network_id = *input;
+        let previous = radix_engine_toolkit_core::utils::deregister_network_definition(network_id);
+        crate::context::evict_network_caches(network_id);
+        Ok(previous.map(Into::into))
+    }
+}
+
+export_function!(UtilsDeregisterNetwork as utils_deregister_network);
+export_jni_function!(UtilsDeregisterNetwork as utilsDeregisterNetwork);
+
+//===================
+// Network HRP Table
+//===================
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct UtilsNetworksInput {}
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SerializableNetworkInformation {
+    pub network_definition: SerializableNetworkDefinition,
+    #[typeshare(serialized_as = "HashMap<SerializableEntityType, String>")]
+    pub hrps: BTreeMap<SerializableEntityType, String>,
+}
+
+#[typeshare::typeshare]
+pub type UtilsNetworksOutput = Vec<SerializableNetworkInformation>;
+
+pub struct UtilsNetworks;
+impl<'f> Function<'f> for UtilsNetworks {
+    type Input = UtilsNetworksInput;
+    type Output = UtilsNetworksOutput;
+
+    fn handle(_: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let networks = radix_engine_toolkit_core::utils::network_definitions()
+            .into_iter()
+            .map(|network_definition| {
+                let hrps = radix_engine_toolkit_core::utils::hrp_table(&network_definition)
+                    .into_iter()
+                    .map(|(entity_type, hrp)| (entity_type.into(), hrp))
+                    .collect();
+                SerializableNetworkInformation {
+                    network_definition: network_definition.into(),
+                    hrps,
+                }
+            })
+            .collect();
+
+        Ok(networks)
+    }
+}
+
+export_function!(UtilsNetworks as utils_networks);
+export_jni_function!(UtilsNetworks as utilsNetworks);
+
+//===========================
+// Payload Round Trip Verify
+//===========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub enum SerializablePayloadKind {
+    Intent,
+    SignedIntent,
+    NotarizedTransaction,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct PayloadRoundTripVerifyInput {
+    pub compiled: SerializableBytes,
+    pub payload_kind: SerializablePayloadKind,
+    pub instructions_kind: SerializableInstructionsKind,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum PayloadRoundTripVerifyOutput {
+    Identical,
+    Divergent {
+        offset: SerializableU64,
+        original_length: SerializableU64,
+        recompiled_length: SerializableU64,
+    },
+}
+
+pub struct PayloadRoundTripVerify;
+impl<'f> Function<'f> for PayloadRoundTripVerify {
+    type Input = PayloadRoundTripVerifyInput;
+    type Output = PayloadRoundTripVerifyOutput;
+
+    fn handle(
+        PayloadRoundTripVerifyInput {
+            compiled,
+            payload_kind,
+            instructions_kind,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let decode_error = |error: DecodeError| {
+            InvocationHandlingError::DecodeError(debug_string(error), debug_string(&compiled))
+        };
+        let encode_error = |error: EncodeError| {
+            InvocationHandlingError::EncodeError(debug_string(error), debug_string(&compiled))
+        };
+
+        // The payload is taken through the serializable model and back so that any lossiness in
+        // the model shows up as a difference in the recompiled bytes.
+        let recompiled = match payload_kind {
+            SerializablePayloadKind::Intent => {
+                let intent = radix_engine_toolkit_core::functions::intent::decompile(&**compiled)
+                    .map_err(decode_error)?;
+                let network_id = intent.header.network_id;
+                let intent =
+                    SerializableIntent::from_native(&intent, network_id, instructions_kind)?
+                        .to_native(network_id)?;
+                radix_engine_toolkit_core::functions::intent::compile(&intent)
+                    .map_err(encode_error)?
+            }
+            SerializablePayloadKind::SignedIntent => {
+                let signed_intent =
+                    radix_engine_toolkit_core::functions::signed_intent::decompile(&**compiled)
+                        .map_err(decode_error)?;
+                let network_id = signed_intent.intent.header.network_id;
+                let signed_intent = SerializableSignedIntent::from_native(
+                    &signed_intent,
+                    network_id,
+                    instructions_kind,
+                )?
+                .to_native(network_id)?;
+                radix_engine_toolkit_core::functions::signed_intent::compile(&signed_intent)
+                    .map_err(encode_error)?
+            }
+            SerializablePayloadKind::NotarizedTransaction => {
+                let notarized_transaction =
+                    radix_engine_toolkit_core::functions::notarized_transaction::decompile(
+                        &**compiled,
+                    )
+                    .map_err(decode_error)?;
+                let network_id = notarized_transaction.signed_intent.intent.header.network_id;
+                let notarized_transaction = SerializableNotarizedTransaction::from_native(
+                    &notarized_transaction,
+                    network_id,
+                    instructions_kind,
+                )?
+                .to_native(network_id)?;
+                radix_engine_toolkit_core::functions::notarized_transaction::compile(
+                    &notarized_transaction,
+                )
+                .map_err(encode_error)?
+            }
+        };
+
+        let output = match radix_engine_toolkit_core::functions::utils::first_divergence(
+            &compiled,
+            &recompiled,
+        ) {
+            None => Self::Output::Identical,
+            Some(offset) => Self::Output::Divergent {
+                offset: (offset as u64).into(),
+                original_length: (compiled.len() as u64).into(),
+                recompiled_length: (recompiled.len() as u64).into(),
+            },
+        };
+        Ok(output)
+    }
+}
+
+export_function!(PayloadRoundTripVerify as payload_round_trip_verify);
+export_jni_function!(PayloadRoundTripVerify as payloadRoundTripVerify);
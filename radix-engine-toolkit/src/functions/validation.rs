@@ -0,0 +1,519 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_core::functions::validation::StaticValidationViolation;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+//===================
+// Validation Header
+//===================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ValidationHeaderInput {
+    pub header: SerializableTransactionHeader,
+    pub validation_config: SerializableValidationConfig,
+}
+
+#[typeshare::typeshare]
+pub type ValidationHeaderOutput = Vec<SerializableStaticValidationViolation>;
+
+pub struct ValidationHeader;
+impl<'f> Function<'f> for ValidationHeader {
+    type Input = ValidationHeaderInput;
+    type Output = ValidationHeaderOutput;
+
+    fn handle(
+        ValidationHeaderInput {
+            header,
+            validation_config,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let header = header.into();
+        let validation_config = validation_config.into();
+
+        Ok(to_serializable(
+            radix_engine_toolkit_core::functions::validation::validate_header(
+                &header,
+                &validation_config,
+            ),
+        ))
+    }
+}
+
+export_function!(ValidationHeader as validation_header);
+export_jni_function!(ValidationHeader as validationHeader);
+
+//===================================
+// Validation Intent Signature Count
+//===================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ValidationIntentSignatureCountInput {
+    pub signed_intent: SerializableSignedIntent,
+}
+
+#[typeshare::typeshare]
+pub type ValidationIntentSignatureCountOutput = Vec<SerializableStaticValidationViolation>;
+
+pub struct ValidationIntentSignatureCount;
+impl<'f> Function<'f> for ValidationIntentSignatureCount {
+    type Input = ValidationIntentSignatureCountInput;
+    type Output = ValidationIntentSignatureCountOutput;
+
+    fn handle(
+        ValidationIntentSignatureCountInput { signed_intent }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let signed_intent = signed_intent.to_native(*signed_intent.intent.header.network_id)?;
+
+        Ok(to_serializable(
+            radix_engine_toolkit_core::functions::validation::validate_intent_signature_count(
+                &signed_intent,
+            ),
+        ))
+    }
+}
+
+export_function!(ValidationIntentSignatureCount as validation_intent_signature_count);
+export_jni_function!(ValidationIntentSignatureCount as validationIntentSignatureCount);
+
+//====================
+// Validation Message
+//====================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ValidationMessageInput {
+    pub message: SerializableMessage,
+    pub message_validation_config: SerializableMessageValidationConfig,
+}
+
+#[typeshare::typeshare]
+pub type ValidationMessageOutput = Vec<SerializableStaticValidationViolation>;
+
+pub struct ValidationMessage;
+impl<'f> Function<'f> for ValidationMessage {
+    type Input = ValidationMessageInput;
+    type Output = ValidationMessageOutput;
+
+    fn handle(
+        ValidationMessageInput {
+            message,
+            message_validation_config,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let message = message.into();
+        let message_validation_config = message_validation_config.into();
+
+        Ok(to_serializable(
+            radix_engine_toolkit_core::functions::validation::validate_message(
+                &message,
+                &message_validation_config,
+            ),
+        ))
+    }
+}
+
+export_function!(ValidationMessage as validation_message);
+export_jni_function!(ValidationMessage as validationMessage);
+
+//===========================
+// Validation Manifest Depth
+//===========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ValidationManifestDepthInput {
+    pub intent: SerializableIntent,
+}
+
+#[typeshare::typeshare]
+pub type ValidationManifestDepthOutput = Vec<SerializableStaticValidationViolation>;
+
+pub struct ValidationManifestDepth;
+impl<'f> Function<'f> for ValidationManifestDepth {
+    type Input = ValidationManifestDepthInput;
+    type Output = ValidationManifestDepthOutput;
+
+    fn handle(
+        ValidationManifestDepthInput { intent }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let intent = intent.to_native(*intent.header.network_id)?;
+
+        Ok(to_serializable(
+            radix_engine_toolkit_core::functions::validation::validate_manifest_depth(&intent),
+        ))
+    }
+}
+
+export_function!(ValidationManifestDepth as validation_manifest_depth);
+export_jni_function!(ValidationManifestDepth as validationManifestDepth);
+
+//==========================
+// Validation Id Allocation
+//==========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ValidationIdAllocationInput {
+    pub instructions: SerializableInstructions,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+pub type ValidationIdAllocationOutput = Vec<SerializableStaticValidationViolation>;
+
+pub struct ValidationIdAllocation;
+impl<'f> Function<'f> for ValidationIdAllocation {
+    type Input = ValidationIdAllocationInput;
+    type Output = ValidationIdAllocationOutput;
+
+    fn handle(
+        ValidationIdAllocationInput {
+            instructions,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let instructions = instructions.to_instructions(*network_id)?;
+
+        Ok(to_serializable(
+            radix_engine_toolkit_core::functions::validation::validate_id_allocation(&instructions),
+        ))
+    }
+}
+
+export_function!(ValidationIdAllocation as validation_id_allocation);
+export_jni_function!(ValidationIdAllocation as validationIdAllocation);
+
+//===================================
+// Validation Notarized Payload Size
+//===================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ValidationNotarizedPayloadSizeInput {
+    pub notarized_transaction: SerializableNotarizedTransaction,
+    pub validation_config: SerializableValidationConfig,
+}
+
+#[typeshare::typeshare]
+pub type ValidationNotarizedPayloadSizeOutput = Vec<SerializableStaticValidationViolation>;
+
+pub struct ValidationNotarizedPayloadSize;
+impl<'f> Function<'f> for ValidationNotarizedPayloadSize {
+    type Input = ValidationNotarizedPayloadSizeInput;
+    type Output = ValidationNotarizedPayloadSizeOutput;
+
+    fn handle(
+        ValidationNotarizedPayloadSizeInput {
+            notarized_transaction,
+            validation_config,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let notarized_transaction = notarized_transaction
+            .to_native(*notarized_transaction.signed_intent.intent.header.network_id)?;
+        let validation_config = validation_config.into();
+
+        Ok(to_serializable(
+            radix_engine_toolkit_core::functions::validation::validate_notarized_payload_size(
+                &notarized_transaction,
+                &validation_config,
+            ),
+        ))
+    }
+}
+
+export_function!(ValidationNotarizedPayloadSize as validation_notarized_payload_size);
+export_jni_function!(ValidationNotarizedPayloadSize as validationNotarizedPayloadSize);
+
+//===================
+// Validation Intent
+//===================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ValidationIntentInput {
+    pub intent: SerializableIntent,
+    pub validation_config: SerializableValidationConfig,
+}
+
+#[typeshare::typeshare]
+pub type ValidationIntentOutput = Vec<SerializableStaticValidationViolation>;
+
+pub struct ValidationIntent;
+impl<'f> Function<'f> for ValidationIntent {
+    type Input = ValidationIntentInput;
+    type Output = ValidationIntentOutput;
+
+    fn handle(
+        ValidationIntentInput {
+            intent,
+            validation_config,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let intent = intent.to_native(*intent.header.network_id)?;
+        let validation_config = validation_config.into();
+
+        Ok(to_serializable(
+            radix_engine_toolkit_core::functions::validation::validate_intent(
+                &intent,
+                &validation_config,
+            ),
+        ))
+    }
+}
+
+export_function!(ValidationIntent as validation_intent);
+export_jni_function!(ValidationIntent as validationIntent);
+
+//==========================
+// Validation Signed Intent
+//==========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ValidationSignedIntentInput {
+    pub signed_intent: SerializableSignedIntent,
+    pub validation_config: SerializableValidationConfig,
+}
+
+#[typeshare::typeshare]
+pub type ValidationSignedIntentOutput = Vec<SerializableStaticValidationViolation>;
+
+pub struct ValidationSignedIntent;
+impl<'f> Function<'f> for ValidationSignedIntent {
+    type Input = ValidationSignedIntentInput;
+    type Output = ValidationSignedIntentOutput;
+
+    fn handle(
+        ValidationSignedIntentInput {
+            signed_intent,
+            validation_config,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let signed_intent = signed_intent.to_native(*signed_intent.intent.header.network_id)?;
+        let validation_config = validation_config.into();
+
+        Ok(to_serializable(
+            radix_engine_toolkit_core::functions::validation::validate_signed_intent(
+                &signed_intent,
+                &validation_config,
+            ),
+        ))
+    }
+}
+
+export_function!(ValidationSignedIntent as validation_signed_intent);
+export_jni_function!(ValidationSignedIntent as validationSignedIntent);
+
+//==================================
+// Validation Notarized Transaction
+//==================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ValidationNotarizedTransactionInput {
+    pub notarized_transaction: SerializableNotarizedTransaction,
+    pub validation_config: SerializableValidationConfig,
+}
+
+#[typeshare::typeshare]
+pub type ValidationNotarizedTransactionOutput = Vec<SerializableStaticValidationViolation>;
+
+pub struct ValidationNotarizedTransaction;
+impl<'f> Function<'f> for ValidationNotarizedTransaction {
+    type Input = ValidationNotarizedTransactionInput;
+    type Output = ValidationNotarizedTransactionOutput;
+
+    fn handle(
+        ValidationNotarizedTransactionInput {
+            notarized_transaction,
+            validation_config,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let notarized_transaction = notarized_transaction
+            .to_native(*notarized_transaction.signed_intent.intent.header.network_id)?;
+        let validation_config = validation_config.into();
+
+        Ok(to_serializable(
+            radix_engine_toolkit_core::functions::validation::validate_notarized_transaction(
+                &notarized_transaction,
+                &validation_config,
+            ),
+        ))
+    }
+}
+
+export_function!(ValidationNotarizedTransaction as validation_notarized_transaction);
+export_jni_function!(ValidationNotarizedTransaction as validationNotarizedTransaction);
+
+//============
+// Violations
+//============
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableStaticValidationViolation {
+    NetworkIdMismatch {
+        expected: SerializableU8,
+        actual: SerializableU8,
+    },
+    EmptyEpochRange {
+        start_epoch_inclusive: SerializableU64,
+        end_epoch_exclusive: SerializableU64,
+    },
+    EpochRangeTooLarge {
+        range: SerializableU64,
+        max: SerializableU64,
+    },
+    TipPercentageOutOfRange {
+        tip_percentage: SerializableU16,
+        min: SerializableU16,
+        max: SerializableU16,
+    },
+    TooManyIntentSignatures {
+        count: SerializableU64,
+        max: SerializableU64,
+    },
+    MimeTypeTooLong {
+        length: SerializableU64,
+        max: SerializableU64,
+    },
+    PlaintextMessageTooLong {
+        length: SerializableU64,
+        max: SerializableU64,
+    },
+    EncryptedMessageTooLong {
+        length: SerializableU64,
+        max: SerializableU64,
+    },
+    NoDecryptors,
+    NoDecryptorsForCurve(SerializableCurveType),
+    DecryptorsCurveMismatch {
+        expected: SerializableCurveType,
+        actual: SerializableCurveType,
+    },
+    TooManyDecryptors {
+        count: SerializableU64,
+        max: SerializableU64,
+    },
+    ManifestTooDeep {
+        max_depth: SerializableU64,
+    },
+    InvalidIdAllocation(String),
+    NotarizedPayloadTooLarge {
+        size: SerializableU64,
+        max: SerializableU64,
+    },
+}
+
+impl From<StaticValidationViolation> for SerializableStaticValidationViolation {
+    fn from(value: StaticValidationViolation) -> Self {
+        let to_u64 = |value: usize| SerializableU64::from(value as u64);
+        match value {
+            StaticValidationViolation::NetworkIdMismatch { expected, actual } => {
+                Self::NetworkIdMismatch {
+                    expected: expected.into(),
+                    actual: actual.into(),
+                }
+            }
+            StaticValidationViolation::EmptyEpochRange {
+                start_epoch_inclusive,
+                end_epoch_exclusive,
+            } => Self::EmptyEpochRange {
+                start_epoch_inclusive: start_epoch_inclusive.number().into(),
+                end_epoch_exclusive: end_epoch_exclusive.number().into(),
+            },
+            StaticValidationViolation::EpochRangeTooLarge { range, max } => {
+                Self::EpochRangeTooLarge {
+                    range: range.into(),
+                    max: max.into(),
+                }
+            }
+            StaticValidationViolation::TipPercentageOutOfRange {
+                tip_percentage,
+                min,
+                max,
+            } => Self::TipPercentageOutOfRange {
+                tip_percentage: tip_percentage.into(),
+                min: min.into(),
+                max: max.into(),
+            },
+            StaticValidationViolation::TooManyIntentSignatures { count, max } => {
+                Self::TooManyIntentSignatures {
+                    count: to_u64(count),
+                    max: to_u64(max),
+                }
+            }
+            StaticValidationViolation::MimeTypeTooLong { length, max } => Self::MimeTypeTooLong {
+                length: to_u64(length),
+                max: to_u64(max),
+            },
+            StaticValidationViolation::PlaintextMessageTooLong { length, max } => {
+                Self::PlaintextMessageTooLong {
+                    length: to_u64(length),
+                    max: to_u64(max),
+                }
+            }
+            StaticValidationViolation::EncryptedMessageTooLong { length, max } => {
+                Self::EncryptedMessageTooLong {
+                    length: to_u64(length),
+                    max: to_u64(max),
+                }
+            }
+            StaticValidationViolation::NoDecryptors => Self::NoDecryptors,
+            StaticValidationViolation::NoDecryptorsForCurve(curve_type) => {
+                Self::NoDecryptorsForCurve(curve_type.into())
+            }
+            StaticValidationViolation::DecryptorsCurveMismatch { expected, actual } => {
+                Self::DecryptorsCurveMismatch {
+                    expected: expected.into(),
+                    actual: actual.into(),
+                }
+            }
+            StaticValidationViolation::TooManyDecryptors { count, max } => {
+                Self::TooManyDecryptors {
+                    count: to_u64(count),
+                    max: to_u64(max),
+                }
+            }
+            StaticValidationViolation::ManifestTooDeep { max_depth } => Self::ManifestTooDeep {
+                max_depth: to_u64(max_depth),
+            },
+            StaticValidationViolation::InvalidIdAllocation(error) => {
+                Self::InvalidIdAllocation(debug_string(error))
+            }
+            StaticValidationViolation::NotarizedPayloadTooLarge { size, max } => {
+                Self::NotarizedPayloadTooLarge {
+                    size: to_u64(size),
+                    max: to_u64(max),
+                }
+            }
+        }
+    }
+}
+
+fn to_serializable(
+    violations: Vec<StaticValidationViolation>,
+) -> Vec<SerializableStaticValidationViolation> {
+    violations.into_iter().map(Into::into).collect()
+}
@@ -0,0 +1,145 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+use radix_engine_common::prelude::*;
+use radix_engine_toolkit_core::utils::{manifest_value_schema_violations, SchemaViolation};
+use sbor::{LocalTypeId, Schema};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+//=============
+// Value Alias
+//=============
+
+#[typeshare::typeshare]
+pub type ValueAliasInput = SerializableManifestValue;
+#[typeshare::typeshare]
+pub type ValueAliasOutput = SerializableManifestValue;
+
+pub struct ValueAlias;
+impl<'f> Function<'f> for ValueAlias {
+    type Input = ValueAliasInput;
+    type Output = ValueAliasOutput;
+
+    fn handle(input: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        Ok(input.alias())
+    }
+}
+
+export_function!(ValueAlias as value_alias);
+export_jni_function!(ValueAlias as valueAlias);
+
+//===============
+// Value Dealias
+//===============
+
+#[typeshare::typeshare]
+pub type ValueDealiasInput = SerializableManifestValue;
+#[typeshare::typeshare]
+pub type ValueDealiasOutput = SerializableManifestValue;
+
+pub struct ValueDealias;
+impl<'f> Function<'f> for ValueDealias {
+    type Input = ValueDealiasInput;
+    type Output = ValueDealiasOutput;
+
+    fn handle(input: Self::Input) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        Ok(input.dealias())
+    }
+}
+
+export_function!(ValueDealias as value_dealias);
+export_jni_function!(ValueDealias as valueDealias);
+
+//===============================
+// Value Validate Against Schema
+//===============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ValueValidateAgainstSchemaInput {
+    pub value: SerializableManifestValue,
+    pub schema: PayloadSchema,
+}
+#[typeshare::typeshare]
+pub type ValueValidateAgainstSchemaOutput = Vec<SerializableSchemaViolation>;
+
+pub struct ValueValidateAgainstSchema;
+impl<'f> Function<'f> for ValueValidateAgainstSchema {
+    type Input = ValueValidateAgainstSchemaInput;
+    type Output = ValueValidateAgainstSchemaOutput;
+
+    fn handle(
+        ValueValidateAgainstSchemaInput {
+            value,
+            schema:
+                PayloadSchema {
+                    local_type_id,
+                    schema,
+                },
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let value = value.to_manifest_value().map_err(|error| {
+            InvocationHandlingError::EncodeError(debug_string(error), debug_string(&value))
+        })?;
+        let local_type_id = LocalTypeId::from(local_type_id);
+        let schema = scrypto_decode::<Schema<ScryptoCustomSchema>>(&schema).map_err(|error| {
+            InvocationHandlingError::DecodeError(debug_string(error), debug_string(schema))
+        })?;
+
+        let violations = manifest_value_schema_violations(&value, &schema, local_type_id)
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        Ok(violations)
+    }
+}
+
+export_function!(ValueValidateAgainstSchema as value_validate_against_schema);
+export_jni_function!(ValueValidateAgainstSchema as valueValidateAgainstSchema);
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableSchemaViolation {
+    pub path: String,
+    pub expected_type_kind: String,
+    pub expected_type_name: Option<String>,
+    pub found_value_kind: SerializableManifestValueKind,
+    pub error: String,
+}
+
+impl From<SchemaViolation> for SerializableSchemaViolation {
+    fn from(
+        SchemaViolation {
+            path,
+            expected_type_kind,
+            expected_type_name,
+            found_value_kind,
+            error,
+        }: SchemaViolation,
+    ) -> Self {
+        Self {
+            path,
+            expected_type_kind,
+            expected_type_name,
+            found_value_kind: found_value_kind.into(),
+            error,
+        }
+    }
+}
@@ -20,6 +20,7 @@ compile_error!("Either feature `moka` or `lru` must be enabled for this crate.")
 #[cfg(all(feature = "moka", feature = "lru"))]
 compile_error!("Feature `moka` and `lru` can't be enabled at the same time.");
 
+pub mod context;
 pub mod error;
 pub mod functions;
 pub mod memory;
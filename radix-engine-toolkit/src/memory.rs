@@ -18,13 +18,23 @@
 #![allow(clippy::missing_safety_doc)]
 
 use std::alloc::{alloc, dealloc, Layout};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 pub type Pointer = *mut std::ffi::c_char;
 
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static TOTAL_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
 #[no_mangle]
 pub unsafe extern "C" fn toolkit_alloc(capacity: usize) -> Pointer {
     let align = std::mem::align_of::<usize>();
     let layout = Layout::from_size_align_unchecked(capacity, align);
+
+    ALLOCATED_BYTES.fetch_add(capacity, Ordering::Relaxed);
+    LIVE_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    TOTAL_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+
     alloc(layout) as Pointer
 }
 
@@ -32,14 +42,47 @@ pub unsafe extern "C" fn toolkit_alloc(capacity: usize) -> Pointer {
 pub unsafe extern "C" fn toolkit_free(pointer: Pointer, capacity: usize) {
     let align = std::mem::align_of::<usize>();
     let layout = Layout::from_size_align_unchecked(capacity, align);
+
+    ALLOCATED_BYTES.fetch_sub(capacity, Ordering::Relaxed);
+    LIVE_ALLOCATIONS.fetch_sub(1, Ordering::Relaxed);
+
     dealloc(pointer as *mut _, layout);
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn toolkit_free_c_string(pointer: Pointer) {
-    // Loading the C-String from memory to get the byte-count of the string.
+    // Loading the C-String from memory to get the byte-count of the string. The null terminator is
+    // included in the byte-count as it was included when the string was allocated.
     let length = std::ffi::CStr::from_ptr(pointer as *const std::ffi::c_char)
-        .to_bytes()
+        .to_bytes_with_nul()
         .len();
     toolkit_free(pointer, length);
 }
+
+#[no_mangle]
+pub unsafe extern "C" fn toolkit_free_length_prefixed(pointer: Pointer) {
+    // The first four bytes hold the little-endian length of the payload that follows them.
+    let mut length = [0u8; 4];
+    (pointer as *const u8).copy_to(length.as_mut_ptr(), length.len());
+    toolkit_free(pointer, u32::from_le_bytes(length) as usize + length.len());
+}
+
+/// Statistics of the memory allocated through [`toolkit_alloc`] and freed through
+/// [`toolkit_free`]. Memory allocated internally by the toolkit is not included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AllocationStatistics {
+    /// The number of bytes currently allocated.
+    pub allocated_bytes: usize,
+    /// The number of allocations that have not yet been freed.
+    pub live_allocations: usize,
+    /// The total number of allocations made since the library was loaded.
+    pub total_allocations: usize,
+}
+
+pub fn allocation_statistics() -> AllocationStatistics {
+    AllocationStatistics {
+        allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed),
+        live_allocations: LIVE_ALLOCATIONS.load(Ordering::Relaxed),
+        total_allocations: TOTAL_ALLOCATIONS.load(Ordering::Relaxed),
+    }
+}
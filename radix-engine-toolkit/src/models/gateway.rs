@@ -0,0 +1,175 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Models of the Gateway API's committed transaction JSON and converters from them into the inputs
+//! used by the toolkit's analysis. Only the fields needed for the analysis are modeled, all other
+//! fields in the Gateway JSON are ignored.
+
+use crate::prelude::*;
+
+use radix_engine_toolkit_core::functions::execution::FeeSummary;
+use radix_engine_toolkit_core::functions::instructions::*;
+use radix_engine_toolkit_core::functions::manifest::fee_locks;
+use radix_engine_toolkit_core::instruction_visitor::visitors::fee_locks_visitor::FeePayers;
+use radix_engine_toolkit_core::instruction_visitor::visitors::addresses_by_role_visitor::*;
+use radix_engine_toolkit_core::instruction_visitor::visitors::required_badges_visitor::*;
+use radix_engine_toolkit_core::instruction_visitor::visitors::transaction_type::reserved_instructions::*;
+use sbor::prelude::DecodeError;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use transaction::prelude::*;
+
+/// The `CommittedTransactionInfo` model of the Gateway API. The `raw_hex` and `receipt` fields are
+/// only present when the `raw_hex` and `receipt_fee_summary` opt-ins are set on the request.
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct GatewayCommittedTransactionInfo {
+    #[typeshare(serialized_as = "number")]
+    pub state_version: u64,
+    #[typeshare(serialized_as = "number")]
+    pub epoch: u64,
+    #[typeshare(serialized_as = "number")]
+    pub round: u64,
+    pub transaction_status: GatewayTransactionStatus,
+    #[serde(default)]
+    pub payload_hash: Option<String>,
+    #[serde(default)]
+    pub intent_hash: Option<String>,
+    #[serde(default)]
+    pub fee_paid: Option<SerializableDecimal>,
+    #[serde(default)]
+    pub raw_hex: Option<SerializableBytes>,
+    #[serde(default)]
+    pub receipt: Option<GatewayTransactionReceipt>,
+}
+
+/// The `TransactionReceipt` model of the Gateway API.
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct GatewayTransactionReceipt {
+    #[serde(default)]
+    pub status: Option<GatewayTransactionStatus>,
+    #[serde(default)]
+    pub fee_summary: Option<GatewayFeeSummary>,
+    #[serde(default)]
+    pub error_message: Option<String>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct GatewayFeeSummary {
+    pub xrd_total_execution_cost: SerializableDecimal,
+    pub xrd_total_finalization_cost: SerializableDecimal,
+    pub xrd_total_royalty_cost: SerializableDecimal,
+    pub xrd_total_storage_cost: SerializableDecimal,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GatewayTransactionStatus {
+    Unknown,
+    CommittedSuccess,
+    CommittedFailure,
+    Pending,
+    Rejected,
+}
+
+impl From<GatewayFeeSummary> for FeeSummary {
+    fn from(value: GatewayFeeSummary) -> Self {
+        Self {
+            execution_cost: *value.xrd_total_execution_cost,
+            finalization_cost: *value.xrd_total_finalization_cost,
+            storage_expansion_cost: *value.xrd_total_storage_cost,
+            royalty_cost: *value.xrd_total_royalty_cost,
+            fee_payers: Default::default(),
+        }
+    }
+}
+
+/// The analysis inputs that could be obtained from the Gateway JSON of a committed transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GatewayAnalysisInput {
+    pub notarized_transaction: NotarizedTransactionV1,
+    pub transaction_status: GatewayTransactionStatus,
+    pub fee_summary: Option<FeeSummary>,
+}
+
+impl GatewayAnalysisInput {
+    pub fn instructions(&self) -> &[InstructionV1] {
+        &self
+            .notarized_transaction
+            .signed_intent
+            .intent
+            .instructions
+            .0
+    }
+
+    /// Produces a summary of the transaction from the data available in the Gateway JSON. Unlike
+    /// the execution analysis, this does not require a preview receipt and thus does not include
+    /// the parts of the analysis which rely on the execution trace.
+    pub fn summarize(&self) -> GatewayTransactionSummary {
+        let instructions = self.instructions();
+        GatewayTransactionSummary {
+            transaction_status: self.transaction_status,
+            fee_summary: self.fee_summary.clone().map(|fee_summary| FeeSummary {
+                fee_payers: FeePayers::from_fee_locks(&fee_locks(instructions)),
+                ..fee_summary
+            }),
+            addresses_by_role: extract_addresses_by_role(instructions),
+            reserved_instruction_usages: reserved_instructions(instructions),
+            required_badges: required_badges(instructions),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GatewayTransactionSummary {
+    pub transaction_status: GatewayTransactionStatus,
+    pub fee_summary: Option<FeeSummary>,
+    pub addresses_by_role: AddressesByRole,
+    pub reserved_instruction_usages: Vec<ReservedInstructionUsage>,
+    pub required_badges: Vec<InstructionBadgeRequirements>,
+}
+
+impl TryFrom<GatewayCommittedTransactionInfo> for GatewayAnalysisInput {
+    type Error = GatewayModelConversionError;
+
+    fn try_from(value: GatewayCommittedTransactionInfo) -> Result<Self, Self::Error> {
+        let raw_hex = value
+            .raw_hex
+            .ok_or(GatewayModelConversionError::RawPayloadNotIncluded)?;
+        let notarized_transaction =
+            radix_engine_toolkit_core::functions::notarized_transaction::decompile(&*raw_hex)
+                .map_err(GatewayModelConversionError::PayloadDecodeError)?;
+
+        Ok(Self {
+            notarized_transaction,
+            transaction_status: value.transaction_status,
+            fee_summary: value
+                .receipt
+                .and_then(|receipt| receipt.fee_summary)
+                .map(Into::into),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GatewayModelConversionError {
+    /// The `raw_hex` opt-in was not set when the transaction was requested from the Gateway.
+    RawPayloadNotIncluded,
+    PayloadDecodeError(DecodeError),
+}
@@ -0,0 +1,1158 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Models of the `Value` and `Instruction` JSON shapes used by the request/response API of the
+//! pre-Babylon toolkit and conversions between them and the current models.
+//!
+//! The legacy shapes are internally tagged (`{"type": "U8", "value": "1"}`) which typeshare can't
+//! express, so they're only described through their JSON schema. Conversions in either direction
+//! report the information that could not be carried over as a list of [`LegacyConversionLoss`]
+//! instead of failing, and only fail when a value or instruction has no equivalent at all.
+
+use std::collections::HashMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use transaction::prelude::*;
+
+use crate::prelude::*;
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type")]
+pub enum LegacyValue {
+    Bool {
+        value: bool,
+    },
+    I8 {
+        value: SerializableI8,
+    },
+    I16 {
+        value: SerializableI16,
+    },
+    I32 {
+        value: SerializableI32,
+    },
+    I64 {
+        value: SerializableI64,
+    },
+    I128 {
+        value: SerializableI128,
+    },
+    U8 {
+        value: SerializableU8,
+    },
+    U16 {
+        value: SerializableU16,
+    },
+    U32 {
+        value: SerializableU32,
+    },
+    U64 {
+        value: SerializableU64,
+    },
+    U128 {
+        value: SerializableU128,
+    },
+    String {
+        value: String,
+    },
+    Enum {
+        variant: LegacyEnumDiscriminator,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        fields: Option<Vec<LegacyValue>>,
+    },
+    Some {
+        value: Box<LegacyValue>,
+    },
+    None,
+    Ok {
+        value: Box<LegacyValue>,
+    },
+    Err {
+        value: Box<LegacyValue>,
+    },
+    Array {
+        element_kind: SerializableManifestValueKind,
+        elements: Vec<LegacyValue>,
+    },
+    Map {
+        key_value_kind: SerializableManifestValueKind,
+        value_value_kind: SerializableManifestValueKind,
+        entries: Vec<LegacyMapEntry>,
+    },
+    Tuple {
+        elements: Vec<LegacyValue>,
+    },
+    Address {
+        address: SerializableNodeId,
+    },
+    Bucket {
+        identifier: LegacyTransientIdentifier,
+    },
+    Proof {
+        identifier: LegacyTransientIdentifier,
+    },
+    Expression {
+        value: LegacyExpression,
+    },
+    Blob {
+        hash: SerializableHash,
+    },
+    Decimal {
+        value: SerializableDecimal,
+    },
+    PreciseDecimal {
+        value: SerializablePreciseDecimal,
+    },
+    NonFungibleLocalId {
+        value: SerializableNonFungibleLocalId,
+    },
+    NonFungibleGlobalId {
+        resource_address: SerializableNodeId,
+        non_fungible_local_id: SerializableNonFungibleLocalId,
+    },
+    Bytes {
+        value: SerializableBytes,
+    },
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct LegacyMapEntry {
+    pub key: LegacyValue,
+    pub value: LegacyValue,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type")]
+pub enum LegacyEnumDiscriminator {
+    U8 { discriminator: SerializableU8 },
+    String { discriminator: String },
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(tag = "type", content = "value")]
+pub enum LegacyTransientIdentifier {
+    String(String),
+    U32(SerializableU32),
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LegacyExpression {
+    EntireWorktop,
+    EntireAuthZone,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "instruction", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LegacyInstruction {
+    CallFunction {
+        package_address: LegacyValue,
+        blueprint_name: LegacyValue,
+        function_name: LegacyValue,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        arguments: Option<Vec<LegacyValue>>,
+    },
+    CallMethod {
+        component_address: LegacyValue,
+        method_name: LegacyValue,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        arguments: Option<Vec<LegacyValue>>,
+    },
+    TakeFromWorktop {
+        resource_address: LegacyValue,
+        into_bucket: LegacyValue,
+    },
+    TakeFromWorktopByAmount {
+        resource_address: LegacyValue,
+        amount: LegacyValue,
+        into_bucket: LegacyValue,
+    },
+    TakeFromWorktopByIds {
+        resource_address: LegacyValue,
+        ids: Vec<LegacyValue>,
+        into_bucket: LegacyValue,
+    },
+    ReturnToWorktop {
+        bucket: LegacyValue,
+    },
+    AssertWorktopContains {
+        resource_address: LegacyValue,
+    },
+    AssertWorktopContainsByAmount {
+        resource_address: LegacyValue,
+        amount: LegacyValue,
+    },
+    AssertWorktopContainsByIds {
+        resource_address: LegacyValue,
+        ids: Vec<LegacyValue>,
+    },
+    PopFromAuthZone {
+        into_proof: LegacyValue,
+    },
+    PushToAuthZone {
+        proof: LegacyValue,
+    },
+    ClearAuthZone,
+    ClearSignatureProofs,
+    CreateProofFromAuthZone {
+        resource_address: LegacyValue,
+        into_proof: LegacyValue,
+    },
+    CreateProofFromAuthZoneByAmount {
+        resource_address: LegacyValue,
+        amount: LegacyValue,
+        into_proof: LegacyValue,
+    },
+    CreateProofFromAuthZoneByIds {
+        resource_address: LegacyValue,
+        ids: Vec<LegacyValue>,
+        into_proof: LegacyValue,
+    },
+    CreateProofFromBucket {
+        bucket: LegacyValue,
+        into_proof: LegacyValue,
+    },
+    CloneProof {
+        proof: LegacyValue,
+        into_proof: LegacyValue,
+    },
+    DropProof {
+        proof: LegacyValue,
+    },
+    DropAllProofs,
+    BurnResource {
+        bucket: LegacyValue,
+    },
+}
+
+/// Information that was present in the source of a conversion but that has no representation in
+/// its target. The instruction index is [`None`] when a standalone value is converted.
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum LegacyConversionLoss {
+    /// The name of a bucket was replaced by its numeric id.
+    BucketNameDropped {
+        instruction_index: Option<SerializableU64>,
+        name: String,
+    },
+    /// The name of a proof was replaced by its numeric id.
+    ProofNameDropped {
+        instruction_index: Option<SerializableU64>,
+        name: String,
+    },
+    /// The name of an enum variant was replaced by its discriminator.
+    EnumVariantNameDropped {
+        instruction_index: Option<SerializableU64>,
+        name: String,
+    },
+    /// A call to a method on one of the object's modules was converted into a call to a method
+    /// on the main module since the legacy instructions have no way of targeting modules.
+    ObjectModuleDropped {
+        instruction_index: Option<SerializableU64>,
+        module: String,
+    },
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "error")]
+pub enum LegacyConversionError {
+    UnknownEnumVariantName(String),
+    UnknownBucket(LegacyTransientIdentifier),
+    UnknownProof(LegacyTransientIdentifier),
+    UnexpectedValue {
+        expected: String,
+        found: Box<LegacyValue>,
+    },
+    ArgumentsAreNotATuple,
+    NamedAddressesAreNotSupported,
+    AddressReservationsAreNotSupported,
+    InvalidNonFungibleGlobalId,
+    UnsupportedInstruction(String),
+}
+
+/// Converts a legacy value into a [`SerializableManifestValue`]. Buckets and proofs may only be
+/// referenced through their numeric identifiers since there are no instructions to resolve their
+/// names against.
+pub fn legacy_value_to_value(
+    value: &LegacyValue,
+) -> Result<(SerializableManifestValue, Vec<LegacyConversionLoss>), LegacyConversionError> {
+    let mut context = LegacyToValueContext::default();
+    let value = context.value(value)?;
+    Ok((value, context.losses))
+}
+
+/// Converts a [`SerializableManifestValue`] into a legacy value.
+pub fn value_to_legacy_value(
+    value: &SerializableManifestValue,
+) -> Result<(LegacyValue, Vec<LegacyConversionLoss>), LegacyConversionError> {
+    let mut context = ValueToLegacyContext::default();
+    let value = context.value(value)?;
+    Ok((value, context.losses))
+}
+
+/// Converts legacy instructions into [`SerializableInstruction`]s. Buckets and proofs created by
+/// the instructions are assigned ids in the order in which they're created.
+pub fn legacy_instructions_to_instructions(
+    instructions: &[LegacyInstruction],
+) -> Result<(Vec<SerializableInstruction>, Vec<LegacyConversionLoss>), LegacyConversionError> {
+    let mut context = LegacyToValueContext {
+        resolve_by_creation_order: true,
+        ..Default::default()
+    };
+    let instructions = instructions
+        .iter()
+        .enumerate()
+        .map(|(instruction_index, instruction)| {
+            context.instruction_index = Some(instruction_index);
+            context.instruction(instruction)
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((instructions, context.losses))
+}
+
+/// Converts [`SerializableInstruction`]s into legacy instructions. Buckets and proofs are referred
+/// to through their numeric ids which round-trip through [`legacy_instructions_to_instructions`].
+pub fn instructions_to_legacy_instructions(
+    instructions: &[SerializableInstruction],
+) -> Result<(Vec<LegacyInstruction>, Vec<LegacyConversionLoss>), LegacyConversionError> {
+    let mut context = ValueToLegacyContext::default();
+    let instructions = instructions
+        .iter()
+        .enumerate()
+        .map(|(instruction_index, instruction)| {
+            context.instruction_index = Some(instruction_index);
+            context.instruction(instruction)
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((instructions, context.losses))
+}
+
+#[derive(Default)]
+struct LegacyToValueContext {
+    instruction_index: Option<usize>,
+    resolve_by_creation_order: bool,
+    buckets: HashMap<LegacyTransientIdentifier, u32>,
+    proofs: HashMap<LegacyTransientIdentifier, u32>,
+    losses: Vec<LegacyConversionLoss>,
+}
+
+impl LegacyToValueContext {
+    fn index(&self) -> Option<SerializableU64> {
+        self.instruction_index.map(|index| (index as u64).into())
+    }
+
+    fn new_bucket(&mut self, value: &LegacyValue) -> Result<(), LegacyConversionError> {
+        let identifier = transient_identifier(value, "Bucket")?;
+        if let LegacyTransientIdentifier::String(name) = &identifier {
+            self.losses.push(LegacyConversionLoss::BucketNameDropped {
+                instruction_index: self.index(),
+                name: name.clone(),
+            })
+        }
+        let id = self.buckets.len() as u32;
+        self.buckets.insert(identifier, id);
+        Ok(())
+    }
+
+    fn new_proof(&mut self, value: &LegacyValue) -> Result<(), LegacyConversionError> {
+        let identifier = transient_identifier(value, "Proof")?;
+        if let LegacyTransientIdentifier::String(name) = &identifier {
+            self.losses.push(LegacyConversionLoss::ProofNameDropped {
+                instruction_index: self.index(),
+                name: name.clone(),
+            })
+        }
+        let id = self.proofs.len() as u32;
+        self.proofs.insert(identifier, id);
+        Ok(())
+    }
+
+    fn bucket(
+        &self,
+        identifier: &LegacyTransientIdentifier,
+    ) -> Result<SerializableU32, LegacyConversionError> {
+        match (self.buckets.get(identifier), identifier) {
+            (Some(id), _) => Ok((*id).into()),
+            (None, LegacyTransientIdentifier::U32(id)) if !self.resolve_by_creation_order => {
+                Ok(*id)
+            }
+            (None, _) => Err(LegacyConversionError::UnknownBucket(identifier.clone())),
+        }
+    }
+
+    fn proof(
+        &self,
+        identifier: &LegacyTransientIdentifier,
+    ) -> Result<SerializableU32, LegacyConversionError> {
+        match (self.proofs.get(identifier), identifier) {
+            (Some(id), _) => Ok((*id).into()),
+            (None, LegacyTransientIdentifier::U32(id)) if !self.resolve_by_creation_order => {
+                Ok(*id)
+            }
+            (None, _) => Err(LegacyConversionError::UnknownProof(identifier.clone())),
+        }
+    }
+
+    fn bucket_of(&self, value: &LegacyValue) -> Result<SerializableU32, LegacyConversionError> {
+        self.bucket(&transient_identifier(value, "Bucket")?)
+    }
+
+    fn proof_of(&self, value: &LegacyValue) -> Result<SerializableU32, LegacyConversionError> {
+        self.proof(&transient_identifier(value, "Proof")?)
+    }
+
+    fn instruction(
+        &mut self,
+        instruction: &LegacyInstruction,
+    ) -> Result<SerializableInstruction, LegacyConversionError> {
+        let instruction = match instruction {
+            LegacyInstruction::CallFunction {
+                package_address,
+                blueprint_name,
+                function_name,
+                arguments,
+            } => SerializableInstruction::CallFunction {
+                package_address: SerializableManifestAddress::Static(address(package_address)?),
+                blueprint_name: string(blueprint_name)?,
+                function_name: string(function_name)?,
+                args: self.arguments(arguments)?,
+            },
+            LegacyInstruction::CallMethod {
+                component_address,
+                method_name,
+                arguments,
+            } => SerializableInstruction::CallMethod {
+                address: SerializableManifestAddress::Static(address(component_address)?),
+                method_name: string(method_name)?,
+                args: self.arguments(arguments)?,
+            },
+            LegacyInstruction::TakeFromWorktop {
+                resource_address,
+                into_bucket,
+            } => {
+                self.new_bucket(into_bucket)?;
+                SerializableInstruction::TakeAllFromWorktop {
+                    resource_address: address(resource_address)?,
+                }
+            }
+            LegacyInstruction::TakeFromWorktopByAmount {
+                resource_address,
+                amount,
+                into_bucket,
+            } => {
+                self.new_bucket(into_bucket)?;
+                SerializableInstruction::TakeFromWorktop {
+                    resource_address: address(resource_address)?,
+                    amount: decimal(amount)?,
+                }
+            }
+            LegacyInstruction::TakeFromWorktopByIds {
+                resource_address,
+                ids,
+                into_bucket,
+            } => {
+                self.new_bucket(into_bucket)?;
+                SerializableInstruction::TakeNonFungiblesFromWorktop {
+                    resource_address: address(resource_address)?,
+                    ids: local_ids(ids)?,
+                }
+            }
+            LegacyInstruction::ReturnToWorktop { bucket } => {
+                SerializableInstruction::ReturnToWorktop {
+                    bucket_id: self.bucket_of(bucket)?,
+                }
+            }
+            LegacyInstruction::AssertWorktopContains { resource_address } => {
+                SerializableInstruction::AssertWorktopContainsAny {
+                    resource_address: address(resource_address)?,
+                }
+            }
+            LegacyInstruction::AssertWorktopContainsByAmount {
+                resource_address,
+                amount,
+            } => SerializableInstruction::AssertWorktopContains {
+                resource_address: address(resource_address)?,
+                amount: decimal(amount)?,
+            },
+            LegacyInstruction::AssertWorktopContainsByIds {
+                resource_address,
+                ids,
+            } => SerializableInstruction::AssertWorktopContainsNonFungibles {
+                resource_address: address(resource_address)?,
+                ids: local_ids(ids)?,
+            },
+            LegacyInstruction::PopFromAuthZone { into_proof } => {
+                self.new_proof(into_proof)?;
+                SerializableInstruction::PopFromAuthZone
+            }
+            LegacyInstruction::PushToAuthZone { proof } => {
+                SerializableInstruction::PushToAuthZone {
+                    proof_id: self.proof_of(proof)?,
+                }
+            }
+            LegacyInstruction::ClearAuthZone => SerializableInstruction::DropAuthZoneProofs,
+            LegacyInstruction::ClearSignatureProofs => {
+                SerializableInstruction::DropAuthZoneSignatureProofs
+            }
+            LegacyInstruction::CreateProofFromAuthZone {
+                resource_address,
+                into_proof,
+            } => {
+                self.new_proof(into_proof)?;
+                SerializableInstruction::CreateProofFromAuthZoneOfAll {
+                    resource_address: address(resource_address)?,
+                }
+            }
+            LegacyInstruction::CreateProofFromAuthZoneByAmount {
+                resource_address,
+                amount,
+                into_proof,
+            } => {
+                self.new_proof(into_proof)?;
+                SerializableInstruction::CreateProofFromAuthZoneOfAmount {
+                    resource_address: address(resource_address)?,
+                    amount: decimal(amount)?,
+                }
+            }
+            LegacyInstruction::CreateProofFromAuthZoneByIds {
+                resource_address,
+                ids,
+                into_proof,
+            } => {
+                self.new_proof(into_proof)?;
+                SerializableInstruction::CreateProofFromAuthZoneOfNonFungibles {
+                    resource_address: address(resource_address)?,
+                    ids: local_ids(ids)?,
+                }
+            }
+            LegacyInstruction::CreateProofFromBucket { bucket, into_proof } => {
+                let bucket_id = self.bucket_of(bucket)?;
+                self.new_proof(into_proof)?;
+                SerializableInstruction::CreateProofFromBucketOfAll { bucket_id }
+            }
+            LegacyInstruction::CloneProof { proof, into_proof } => {
+                let proof_id = self.proof_of(proof)?;
+                self.new_proof(into_proof)?;
+                SerializableInstruction::CloneProof { proof_id }
+            }
+            LegacyInstruction::DropProof { proof } => SerializableInstruction::DropProof {
+                proof_id: self.proof_of(proof)?,
+            },
+            LegacyInstruction::DropAllProofs => SerializableInstruction::DropAllProofs,
+            LegacyInstruction::BurnResource { bucket } => SerializableInstruction::BurnResource {
+                bucket_id: self.bucket_of(bucket)?,
+            },
+        };
+        Ok(instruction)
+    }
+
+    fn arguments(
+        &mut self,
+        arguments: &Option<Vec<LegacyValue>>,
+    ) -> Result<SerializableManifestValue, LegacyConversionError> {
+        Ok(SerializableManifestValue::Tuple {
+            fields: arguments
+                .iter()
+                .flatten()
+                .map(|value| self.value(value))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn values(
+        &mut self,
+        values: &[LegacyValue],
+    ) -> Result<Vec<SerializableManifestValue>, LegacyConversionError> {
+        values.iter().map(|value| self.value(value)).collect()
+    }
+
+    fn value(
+        &mut self,
+        value: &LegacyValue,
+    ) -> Result<SerializableManifestValue, LegacyConversionError> {
+        let value = match value {
+            LegacyValue::Bool { value } => SerializableManifestValue::Bool { value: *value },
+            LegacyValue::I8 { value } => SerializableManifestValue::I8 { value: *value },
+            LegacyValue::I16 { value } => SerializableManifestValue::I16 { value: *value },
+            LegacyValue::I32 { value } => SerializableManifestValue::I32 { value: *value },
+            LegacyValue::I64 { value } => SerializableManifestValue::I64 { value: *value },
+            LegacyValue::I128 { value } => SerializableManifestValue::I128 { value: *value },
+            LegacyValue::U8 { value } => SerializableManifestValue::U8 { value: *value },
+            LegacyValue::U16 { value } => SerializableManifestValue::U16 { value: *value },
+            LegacyValue::U32 { value } => SerializableManifestValue::U32 { value: *value },
+            LegacyValue::U64 { value } => SerializableManifestValue::U64 { value: *value },
+            LegacyValue::U128 { value } => SerializableManifestValue::U128 { value: *value },
+            LegacyValue::String { value } => SerializableManifestValue::String {
+                value: value.clone(),
+            },
+            LegacyValue::Enum { variant, fields } => SerializableManifestValue::Enum {
+                discriminator: self.discriminator(variant)?,
+                fields: self.values(fields.as_deref().unwrap_or_default())?,
+            },
+            LegacyValue::Some { value } => {
+                self.enum_value(OPTION_VARIANT_SOME, Some(value.as_ref()))?
+            }
+            LegacyValue::None => self.enum_value(OPTION_VARIANT_NONE, None)?,
+            LegacyValue::Ok { value } => {
+                self.enum_value(RESULT_VARIANT_OK, Some(value.as_ref()))?
+            }
+            LegacyValue::Err { value } => {
+                self.enum_value(RESULT_VARIANT_ERR, Some(value.as_ref()))?
+            }
+            LegacyValue::Array {
+                element_kind,
+                elements,
+            } => SerializableManifestValue::Array {
+                element_value_kind: *element_kind,
+                elements: self.values(elements)?,
+            },
+            LegacyValue::Map {
+                key_value_kind,
+                value_value_kind,
+                entries,
+            } => SerializableManifestValue::Map {
+                key_value_kind: *key_value_kind,
+                value_value_kind: *value_value_kind,
+                entries: entries
+                    .iter()
+                    .map(|LegacyMapEntry { key, value }| {
+                        Ok(SerializableMapEntry {
+                            key: self.value(key)?,
+                            value: self.value(value)?,
+                        })
+                    })
+                    .collect::<Result<_, _>>()?,
+            },
+            LegacyValue::Tuple { elements } => SerializableManifestValue::Tuple {
+                fields: self.values(elements)?,
+            },
+            LegacyValue::Address { address } => SerializableManifestValue::Address {
+                value: SerializableManifestAddress::Static(*address),
+            },
+            LegacyValue::Bucket { identifier } => SerializableManifestValue::Bucket {
+                value: self.bucket(identifier)?,
+            },
+            LegacyValue::Proof { identifier } => SerializableManifestValue::Proof {
+                value: self.proof(identifier)?,
+            },
+            LegacyValue::Expression { value } => SerializableManifestValue::Expression {
+                value: match value {
+                    LegacyExpression::EntireWorktop => SerializableExpression::EntireWorktop,
+                    LegacyExpression::EntireAuthZone => SerializableExpression::EntireAuthZone,
+                },
+            },
+            LegacyValue::Blob { hash } => SerializableManifestValue::Blob { value: *hash },
+            LegacyValue::Decimal { value } => SerializableManifestValue::Decimal { value: *value },
+            LegacyValue::PreciseDecimal { value } => {
+                SerializableManifestValue::PreciseDecimal { value: *value }
+            }
+            LegacyValue::NonFungibleLocalId { value } => {
+                SerializableManifestValue::NonFungibleLocalId {
+                    value: value.clone(),
+                }
+            }
+            LegacyValue::NonFungibleGlobalId {
+                resource_address,
+                non_fungible_local_id,
+            } => {
+                let network_id = resource_address.0.network_id;
+                let resource_address =
+                    ResourceAddress::try_from(resource_address.0.node_id.as_bytes())
+                        .map_err(|_| LegacyConversionError::InvalidNonFungibleGlobalId)?;
+                SerializableManifestValue::NonFungibleGlobalId {
+                    value: SerializableNonFungibleGlobalId::new(
+                        NonFungibleGlobalId::new(
+                            resource_address,
+                            (**non_fungible_local_id).clone(),
+                        ),
+                        network_id,
+                    ),
+                }
+            }
+            LegacyValue::Bytes { value } => SerializableManifestValue::Bytes {
+                value: value.clone(),
+            },
+        };
+        Ok(value)
+    }
+
+    fn enum_value(
+        &mut self,
+        discriminator: u8,
+        field: Option<&LegacyValue>,
+    ) -> Result<SerializableManifestValue, LegacyConversionError> {
+        Ok(SerializableManifestValue::Enum {
+            discriminator: discriminator.into(),
+            fields: field
+                .map(|field| self.value(field))
+                .into_iter()
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn discriminator(
+        &mut self,
+        variant: &LegacyEnumDiscriminator,
+    ) -> Result<SerializableU8, LegacyConversionError> {
+        match variant {
+            LegacyEnumDiscriminator::U8 { discriminator } => Ok(*discriminator),
+            LegacyEnumDiscriminator::String { discriminator } => {
+                let value = match discriminator.as_str() {
+                    "Option::None" => OPTION_VARIANT_NONE,
+                    "Option::Some" => OPTION_VARIANT_SOME,
+                    "Result::Ok" => RESULT_VARIANT_OK,
+                    "Result::Err" => RESULT_VARIANT_ERR,
+                    _ => {
+                        return Err(LegacyConversionError::UnknownEnumVariantName(
+                            discriminator.clone(),
+                        ))
+                    }
+                };
+                self.losses
+                    .push(LegacyConversionLoss::EnumVariantNameDropped {
+                        instruction_index: self.index(),
+                        name: discriminator.clone(),
+                    });
+                Ok(value.into())
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct ValueToLegacyContext {
+    instruction_index: Option<usize>,
+    bucket_count: u32,
+    proof_count: u32,
+    losses: Vec<LegacyConversionLoss>,
+}
+
+impl ValueToLegacyContext {
+    fn call_method(
+        &mut self,
+        address: &SerializableManifestAddress,
+        method_name: &str,
+        args: &SerializableManifestValue,
+        module: Option<&str>,
+    ) -> Result<LegacyInstruction, LegacyConversionError> {
+        if let Some(module) = module {
+            self.losses.push(LegacyConversionLoss::ObjectModuleDropped {
+                instruction_index: self.instruction_index.map(|index| (index as u64).into()),
+                module: module.to_owned(),
+            })
+        }
+        Ok(LegacyInstruction::CallMethod {
+            component_address: LegacyValue::Address {
+                address: static_address(address)?,
+            },
+            method_name: LegacyValue::String {
+                value: method_name.to_owned(),
+            },
+            arguments: self.arguments(args)?,
+        })
+    }
+
+    fn instruction(
+        &mut self,
+        instruction: &SerializableInstruction,
+    ) -> Result<LegacyInstruction, LegacyConversionError> {
+        let instruction = match instruction {
+            SerializableInstruction::TakeAllFromWorktop { resource_address } => {
+                LegacyInstruction::TakeFromWorktop {
+                    resource_address: legacy_address(resource_address),
+                    into_bucket: self.next_bucket(),
+                }
+            }
+            SerializableInstruction::TakeFromWorktop {
+                resource_address,
+                amount,
+            } => LegacyInstruction::TakeFromWorktopByAmount {
+                resource_address: legacy_address(resource_address),
+                amount: LegacyValue::Decimal {
+                    value: amount.clone(),
+                },
+                into_bucket: self.next_bucket(),
+            },
+            SerializableInstruction::TakeNonFungiblesFromWorktop {
+                resource_address,
+                ids,
+            } => LegacyInstruction::TakeFromWorktopByIds {
+                resource_address: legacy_address(resource_address),
+                ids: legacy_local_ids(ids),
+                into_bucket: self.next_bucket(),
+            },
+            SerializableInstruction::ReturnToWorktop { bucket_id } => {
+                LegacyInstruction::ReturnToWorktop {
+                    bucket: legacy_bucket(*bucket_id),
+                }
+            }
+            SerializableInstruction::AssertWorktopContainsAny { resource_address } => {
+                LegacyInstruction::AssertWorktopContains {
+                    resource_address: legacy_address(resource_address),
+                }
+            }
+            SerializableInstruction::AssertWorktopContains {
+                resource_address,
+                amount,
+            } => LegacyInstruction::AssertWorktopContainsByAmount {
+                resource_address: legacy_address(resource_address),
+                amount: LegacyValue::Decimal {
+                    value: amount.clone(),
+                },
+            },
+            SerializableInstruction::AssertWorktopContainsNonFungibles {
+                resource_address,
+                ids,
+            } => LegacyInstruction::AssertWorktopContainsByIds {
+                resource_address: legacy_address(resource_address),
+                ids: legacy_local_ids(ids),
+            },
+            SerializableInstruction::PopFromAuthZone => LegacyInstruction::PopFromAuthZone {
+                into_proof: self.next_proof(),
+            },
+            SerializableInstruction::PushToAuthZone { proof_id } => {
+                LegacyInstruction::PushToAuthZone {
+                    proof: legacy_proof(*proof_id),
+                }
+            }
+            SerializableInstruction::CreateProofFromAuthZoneOfAmount {
+                resource_address,
+                amount,
+            } => LegacyInstruction::CreateProofFromAuthZoneByAmount {
+                resource_address: legacy_address(resource_address),
+                amount: LegacyValue::Decimal {
+                    value: amount.clone(),
+                },
+                into_proof: self.next_proof(),
+            },
+            SerializableInstruction::CreateProofFromAuthZoneOfNonFungibles {
+                resource_address,
+                ids,
+            } => LegacyInstruction::CreateProofFromAuthZoneByIds {
+                resource_address: legacy_address(resource_address),
+                ids: legacy_local_ids(ids),
+                into_proof: self.next_proof(),
+            },
+            SerializableInstruction::CreateProofFromAuthZoneOfAll { resource_address } => {
+                LegacyInstruction::CreateProofFromAuthZone {
+                    resource_address: legacy_address(resource_address),
+                    into_proof: self.next_proof(),
+                }
+            }
+            SerializableInstruction::DropAllProofs => LegacyInstruction::DropAllProofs,
+            SerializableInstruction::DropAuthZoneProofs => LegacyInstruction::ClearAuthZone,
+            SerializableInstruction::DropAuthZoneSignatureProofs => {
+                LegacyInstruction::ClearSignatureProofs
+            }
+            SerializableInstruction::CreateProofFromBucketOfAll { bucket_id } => {
+                LegacyInstruction::CreateProofFromBucket {
+                    bucket: legacy_bucket(*bucket_id),
+                    into_proof: self.next_proof(),
+                }
+            }
+            SerializableInstruction::BurnResource { bucket_id } => {
+                LegacyInstruction::BurnResource {
+                    bucket: legacy_bucket(*bucket_id),
+                }
+            }
+            SerializableInstruction::CloneProof { proof_id } => LegacyInstruction::CloneProof {
+                proof: legacy_proof(*proof_id),
+                into_proof: self.next_proof(),
+            },
+            SerializableInstruction::DropProof { proof_id } => LegacyInstruction::DropProof {
+                proof: legacy_proof(*proof_id),
+            },
+            SerializableInstruction::CallFunction {
+                package_address,
+                blueprint_name,
+                function_name,
+                args,
+            } => LegacyInstruction::CallFunction {
+                package_address: LegacyValue::Address {
+                    address: static_address(package_address)?,
+                },
+                blueprint_name: LegacyValue::String {
+                    value: blueprint_name.clone(),
+                },
+                function_name: LegacyValue::String {
+                    value: function_name.clone(),
+                },
+                arguments: self.arguments(args)?,
+            },
+            SerializableInstruction::CallMethod {
+                address,
+                method_name,
+                args,
+            } => self.call_method(address, method_name, args, None)?,
+            SerializableInstruction::CallRoyaltyMethod {
+                address,
+                method_name,
+                args,
+            } => self.call_method(address, method_name, args, Some("Royalty"))?,
+            SerializableInstruction::CallMetadataMethod {
+                address,
+                method_name,
+                args,
+            } => self.call_method(address, method_name, args, Some("Metadata"))?,
+            SerializableInstruction::CallRoleAssignmentMethod {
+                address,
+                method_name,
+                args,
+            } => self.call_method(address, method_name, args, Some("RoleAssignment"))?,
+            SerializableInstruction::CreateProofFromBucketOfAmount { .. }
+            | SerializableInstruction::CreateProofFromBucketOfNonFungibles { .. }
+            | SerializableInstruction::DropNamedProofs
+            | SerializableInstruction::DropAuthZoneRegularProofs
+            | SerializableInstruction::CallDirectVaultMethod { .. }
+            | SerializableInstruction::AllocateGlobalAddress { .. } => {
+                return Err(LegacyConversionError::UnsupportedInstruction(
+                    instruction_name(instruction),
+                ))
+            }
+        };
+        Ok(instruction)
+    }
+
+    fn next_bucket(&mut self) -> LegacyValue {
+        let bucket = legacy_bucket(self.bucket_count.into());
+        self.bucket_count += 1;
+        bucket
+    }
+
+    fn next_proof(&mut self) -> LegacyValue {
+        let proof = legacy_proof(self.proof_count.into());
+        self.proof_count += 1;
+        proof
+    }
+
+    fn arguments(
+        &mut self,
+        args: &SerializableManifestValue,
+    ) -> Result<Option<Vec<LegacyValue>>, LegacyConversionError> {
+        match args {
+            SerializableManifestValue::Tuple { fields } => Ok(Some(
+                fields
+                    .iter()
+                    .map(|value| self.value(value))
+                    .collect::<Result<_, _>>()?,
+            )),
+            _ => Err(LegacyConversionError::ArgumentsAreNotATuple),
+        }
+    }
+
+    fn values(
+        &mut self,
+        values: &[SerializableManifestValue],
+    ) -> Result<Vec<LegacyValue>, LegacyConversionError> {
+        values.iter().map(|value| self.value(value)).collect()
+    }
+
+    fn value(
+        &mut self,
+        value: &SerializableManifestValue,
+    ) -> Result<LegacyValue, LegacyConversionError> {
+        let value = match value {
+            SerializableManifestValue::Bool { value } => LegacyValue::Bool { value: *value },
+            SerializableManifestValue::I8 { value } => LegacyValue::I8 { value: *value },
+            SerializableManifestValue::I16 { value } => LegacyValue::I16 { value: *value },
+            SerializableManifestValue::I32 { value } => LegacyValue::I32 { value: *value },
+            SerializableManifestValue::I64 { value } => LegacyValue::I64 { value: *value },
+            SerializableManifestValue::I128 { value } => LegacyValue::I128 { value: *value },
+            SerializableManifestValue::U8 { value } => LegacyValue::U8 { value: *value },
+            SerializableManifestValue::U16 { value } => LegacyValue::U16 { value: *value },
+            SerializableManifestValue::U32 { value } => LegacyValue::U32 { value: *value },
+            SerializableManifestValue::U64 { value } => LegacyValue::U64 { value: *value },
+            SerializableManifestValue::U128 { value } => LegacyValue::U128 { value: *value },
+            SerializableManifestValue::String { value } => LegacyValue::String {
+                value: value.clone(),
+            },
+            SerializableManifestValue::Enum {
+                discriminator,
+                fields,
+            } => LegacyValue::Enum {
+                variant: LegacyEnumDiscriminator::U8 {
+                    discriminator: *discriminator,
+                },
+                fields: Some(self.values(fields)?),
+            },
+            SerializableManifestValue::Array {
+                element_value_kind,
+                elements,
+            } => LegacyValue::Array {
+                element_kind: *element_value_kind,
+                elements: self.values(elements)?,
+            },
+            SerializableManifestValue::Tuple { fields } => LegacyValue::Tuple {
+                elements: self.values(fields)?,
+            },
+            SerializableManifestValue::Map {
+                key_value_kind,
+                value_value_kind,
+                entries,
+            } => LegacyValue::Map {
+                key_value_kind: *key_value_kind,
+                value_value_kind: *value_value_kind,
+                entries: entries
+                    .iter()
+                    .map(|SerializableMapEntry { key, value }| {
+                        Ok(LegacyMapEntry {
+                            key: self.value(key)?,
+                            value: self.value(value)?,
+                        })
+                    })
+                    .collect::<Result<_, _>>()?,
+            },
+            SerializableManifestValue::Address { value } => LegacyValue::Address {
+                address: static_address(value)?,
+            },
+            SerializableManifestValue::Bucket { value } => legacy_bucket(*value),
+            SerializableManifestValue::Proof { value } => legacy_proof(*value),
+            SerializableManifestValue::Expression { value } => LegacyValue::Expression {
+                value: match value {
+                    SerializableExpression::EntireWorktop => LegacyExpression::EntireWorktop,
+                    SerializableExpression::EntireAuthZone => LegacyExpression::EntireAuthZone,
+                },
+            },
+            SerializableManifestValue::Blob { value } => LegacyValue::Blob { hash: *value },
+            SerializableManifestValue::Decimal { value } => LegacyValue::Decimal { value: *value },
+            SerializableManifestValue::PreciseDecimal { value } => {
+                LegacyValue::PreciseDecimal { value: *value }
+            }
+            SerializableManifestValue::NonFungibleLocalId { value } => {
+                LegacyValue::NonFungibleLocalId {
+                    value: value.clone(),
+                }
+            }
+            SerializableManifestValue::AddressReservation { .. } => {
+                return Err(LegacyConversionError::AddressReservationsAreNotSupported)
+            }
+            SerializableManifestValue::Bytes { value } => LegacyValue::Bytes {
+                value: value.clone(),
+            },
+            SerializableManifestValue::NonFungibleGlobalId { value } => {
+                let SerializableNonFungibleGlobalIdInternal {
+                    non_fungible_global_id,
+                    network_id,
+                } = &value.0;
+                LegacyValue::NonFungibleGlobalId {
+                    resource_address: SerializableNodeId::new(
+                        non_fungible_global_id.resource_address().into_node_id(),
+                        *network_id,
+                    ),
+                    non_fungible_local_id: non_fungible_global_id.local_id().clone().into(),
+                }
+            }
+        };
+        Ok(value)
+    }
+}
+
+fn transient_identifier(
+    value: &LegacyValue,
+    expected: &str,
+) -> Result<LegacyTransientIdentifier, LegacyConversionError> {
+    match (value, expected) {
+        (LegacyValue::Bucket { identifier }, "Bucket")
+        | (LegacyValue::Proof { identifier }, "Proof") => Ok(identifier.clone()),
+        _ => Err(unexpected(expected, value)),
+    }
+}
+
+fn address(value: &LegacyValue) -> Result<SerializableNodeId, LegacyConversionError> {
+    match value {
+        LegacyValue::Address { address } => Ok(*address),
+        _ => Err(unexpected("Address", value)),
+    }
+}
+
+fn string(value: &LegacyValue) -> Result<String, LegacyConversionError> {
+    match value {
+        LegacyValue::String { value } => Ok(value.clone()),
+        _ => Err(unexpected("String", value)),
+    }
+}
+
+fn decimal(value: &LegacyValue) -> Result<SerializableDecimal, LegacyConversionError> {
+    match value {
+        LegacyValue::Decimal { value } => Ok(*value),
+        _ => Err(unexpected("Decimal", value)),
+    }
+}
+
+fn local_ids(
+    values: &[LegacyValue],
+) -> Result<Vec<SerializableNonFungibleLocalId>, LegacyConversionError> {
+    values
+        .iter()
+        .map(|value| match value {
+            LegacyValue::NonFungibleLocalId { value } => Ok(value.clone()),
+            _ => Err(unexpected("NonFungibleLocalId", value)),
+        })
+        .collect()
+}
+
+fn unexpected(expected: &str, found: &LegacyValue) -> LegacyConversionError {
+    LegacyConversionError::UnexpectedValue {
+        expected: expected.to_owned(),
+        found: Box::new(found.clone()),
+    }
+}
+
+fn static_address(
+    address: &SerializableManifestAddress,
+) -> Result<SerializableNodeId, LegacyConversionError> {
+    match address {
+        SerializableManifestAddress::Static(address) => Ok(*address),
+        SerializableManifestAddress::Named(..) => {
+            Err(LegacyConversionError::NamedAddressesAreNotSupported)
+        }
+    }
+}
+
+fn legacy_address(address: &SerializableNodeId) -> LegacyValue {
+    LegacyValue::Address { address: *address }
+}
+
+fn legacy_local_ids(ids: &[SerializableNonFungibleLocalId]) -> Vec<LegacyValue> {
+    ids.iter()
+        .map(|id| LegacyValue::NonFungibleLocalId { value: id.clone() })
+        .collect()
+}
+
+fn legacy_bucket(id: SerializableU32) -> LegacyValue {
+    LegacyValue::Bucket {
+        identifier: LegacyTransientIdentifier::U32(id),
+    }
+}
+
+fn legacy_proof(id: SerializableU32) -> LegacyValue {
+    LegacyValue::Proof {
+        identifier: LegacyTransientIdentifier::U32(id),
+    }
+}
+
+fn instruction_name(instruction: &SerializableInstruction) -> String {
+    serde_json::to_value(instruction)
+        .ok()
+        .and_then(|value| {
+            value
+                .get("kind")
+                .and_then(|kind| kind.as_str().map(ToOwned::to_owned))
+        })
+        .unwrap_or_default()
+}
@@ -17,6 +17,9 @@
 
 pub mod common;
 pub mod cryptographic;
+#[cfg(feature = "gateway-models")]
+pub mod gateway;
+pub mod legacy;
 pub mod manifest;
 pub mod olympia;
 pub mod sbor;
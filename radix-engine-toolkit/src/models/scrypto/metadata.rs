@@ -0,0 +1,52 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+use radix_engine_common::prelude::*;
+use radix_engine_interface::types::KeyValueStoreInitEntry;
+use schemars::*;
+use scrypto::api::node_modules::metadata::*;
+use serde::*;
+use std::collections::BTreeMap;
+
+/// The initial metadata of an entity keyed by the metadata key. This is the typed equivalent of the
+/// `MetadataInit` passed in the `metadata` module config of resource and component creation calls.
+/// The entries are ordered by key so that the same metadata is always encoded into the same bytes,
+/// this is the same order in which the UniFFI manifest builder encodes its metadata init.
+#[typeshare::typeshare]
+pub type SerializableMetadataInit = BTreeMap<String, SerializableMetadataInitEntry>;
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SerializableMetadataInitEntry {
+    pub value: Option<SerializableMetadataValue>,
+    pub lock: bool,
+}
+
+pub fn metadata_init_to_native(
+    metadata_init: &SerializableMetadataInit,
+) -> Result<MetadataInit, InvocationHandlingError> {
+    metadata_init
+        .iter()
+        .map(|(key, SerializableMetadataInitEntry { value, lock })| {
+            let value = value.as_ref().map(|value| value.to_native()).transpose()?;
+            Ok((key.clone(), KeyValueStoreInitEntry { value, lock: *lock }))
+        })
+        .collect::<Result<IndexMap<_, _>, _>>()
+        .map(|data| MetadataInit { data })
+}
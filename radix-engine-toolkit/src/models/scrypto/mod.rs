@@ -15,5 +15,6 @@
 // specific language governing permissions and limitations
 // under the License.
 
+pub mod metadata;
 pub mod node_id;
 pub mod non_fungible_global_id;
@@ -15,9 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use radix_engine_common::prelude::{
-    AddressBech32DecodeError, AddressBech32Decoder, AddressBech32Encoder,
-};
+use radix_engine_common::prelude::AddressBech32DecodeError;
 use schemars::JsonSchema;
 use scrypto::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -62,11 +60,7 @@ pub struct SerializableNodeIdInternal {
 
 impl Display for SerializableNodeIdInternal {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let network_definition =
-            radix_engine_toolkit_core::utils::network_definition_from_network_id(self.network_id);
-        let bech32_encoder = AddressBech32Encoder::new(&network_definition);
-        let string = bech32_encoder
-            .encode(&self.node_id.0)
+        let string = crate::context::encode_address(&self.node_id, self.network_id)
             .map_err(|_| fmt::Error)?;
         write!(f, "{}", string)
     }
@@ -84,9 +78,7 @@ impl FromStr for SerializableNodeIdInternal {
                 Ok,
             )?;
 
-        let network_definition =
-            radix_engine_toolkit_core::utils::network_definition_from_network_id(network_id);
-        let bech32_decoder = AddressBech32Decoder::new(&network_definition);
+        let bech32_decoder = crate::context::bech32_decoder(network_id);
         let (_, data) = bech32_decoder.validate_and_decode(s)?;
 
         data.try_into()
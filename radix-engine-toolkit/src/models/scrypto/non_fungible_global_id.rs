@@ -17,8 +17,6 @@
 
 use crate::prelude::*;
 
-use radix_engine_common::prelude::{AddressBech32Decoder, AddressBech32Encoder};
-use radix_engine_toolkit_core::utils::network_definition_from_network_id;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
@@ -27,7 +25,7 @@ use std::str::FromStr;
 use transaction::prelude::NonFungibleGlobalId;
 
 #[serde_as]
-#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq, Hash)]
 #[serde(transparent)]
 #[schemars(transparent)]
 #[typeshare::typeshare(serialized_as = "String")]
@@ -47,7 +45,7 @@ impl SerializableNonFungibleGlobalId {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct SerializableNonFungibleGlobalIdInternal {
     pub non_fungible_global_id: NonFungibleGlobalId,
     pub network_id: u8,
@@ -55,8 +53,7 @@ pub struct SerializableNonFungibleGlobalIdInternal {
 
 impl Display for SerializableNonFungibleGlobalIdInternal {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let network_definition = network_definition_from_network_id(self.network_id);
-        let bech32_encoder = AddressBech32Encoder::new(&network_definition);
+        let bech32_encoder = crate::context::bech32_encoder(self.network_id);
         write!(
             f,
             "{}",
@@ -81,8 +78,7 @@ impl FromStr for SerializableNonFungibleGlobalIdInternal {
             resource_address_string,
         )
         .ok_or(SerializableNonFungibleGlobalIdError::InvalidResourceAddress)?;
-        let network_definition = network_definition_from_network_id(network_id);
-        let bech32_decoder = AddressBech32Decoder::new(&network_definition);
+        let bech32_decoder = crate::context::bech32_decoder(network_id);
 
         let non_fungible_global_id =
             NonFungibleGlobalId::try_from_canonical_string(&bech32_decoder, s).map_err(
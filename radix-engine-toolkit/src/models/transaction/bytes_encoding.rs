@@ -0,0 +1,56 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use base64::Engine;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::InvocationHandlingError;
+use crate::utils::debug_string;
+
+/// The encoding of the compiled payloads returned by the compile functions and given to the
+/// decompile functions. Hex is the default. Base64 is offered for host environments where decoding
+/// Base64 is considerably faster than decoding hex.
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SerializableBytesEncoding {
+    #[default]
+    Hex,
+    Base64,
+}
+
+impl SerializableBytesEncoding {
+    pub fn encode(&self, bytes: &[u8]) -> String {
+        match self {
+            Self::Hex => hex::encode(bytes),
+            Self::Base64 => base64::engine::general_purpose::STANDARD.encode(bytes),
+        }
+    }
+
+    pub fn decode(&self, string: &str) -> Result<Vec<u8>, InvocationHandlingError> {
+        match self {
+            Self::Hex => hex::decode(string).map_err(|error| {
+                InvocationHandlingError::DecodeError(debug_string(error), string.to_owned())
+            }),
+            Self::Base64 => base64::engine::general_purpose::STANDARD
+                .decode(string)
+                .map_err(|error| {
+                    InvocationHandlingError::DecodeError(debug_string(error), string.to_owned())
+                }),
+        }
+    }
+}
@@ -17,7 +17,6 @@
 
 use crate::prelude::*;
 
-use radix_engine_toolkit_core::utils::*;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use transaction::manifest::*;
@@ -46,7 +45,7 @@ impl SerializableInstructions {
     ) -> Result<Self, SerializableInstructionsError> {
         match kind {
             SerializableInstructionsKind::String => {
-                let network_definition = network_definition_from_network_id(network_id);
+                let network_definition = crate::context::network_definition(network_id);
                 let string = transaction::manifest::decompile(instructions, &network_definition)?;
                 Ok(Self::String(string))
             }
@@ -64,7 +63,7 @@ impl SerializableInstructions {
         match self {
             Self::String(string) => transaction::manifest::compile(
                 string,
-                &network_definition_from_network_id(network_id),
+                &crate::context::network_definition(network_id),
                 MockBlobProvider::new(),
             )
             .map_err(SerializableInstructionsError::from)
@@ -87,7 +86,7 @@ impl SerializableInstructions {
                 let instructions = to_native_instructions(parsed)?;
                 let string = decompile(
                     &instructions,
-                    &network_definition_from_network_id(network_id),
+                    &crate::context::network_definition(network_id),
                 )?;
                 *self = Self::String(string);
                 Ok(())
@@ -95,7 +94,7 @@ impl SerializableInstructions {
             (Self::String(string), SerializableInstructionsKind::Parsed) => {
                 let instructions = transaction::manifest::compile(
                     string,
-                    &network_definition_from_network_id(network_id),
+                    &crate::context::network_definition(network_id),
                     MockBlobProvider::new(),
                 )
                 .map(|manifest| manifest.instructions)?;
@@ -109,6 +109,13 @@ pub enum SerializableManifestValue {
     AddressReservation {
         value: SerializableU32,
     },
+    /* Aliases */
+    Bytes {
+        value: SerializableBytes,
+    },
+    NonFungibleGlobalId {
+        value: SerializableNonFungibleGlobalId,
+    },
 }
 
 #[typeshare::typeshare]
@@ -471,10 +478,161 @@ impl SerializableManifestValue {
                     value: ManifestCustomValue::Address(ManifestAddress::Named(**value)),
                 },
             },
+            Self::Bytes { .. } | Self::NonFungibleGlobalId { .. } => {
+                self.dealias().to_manifest_value()?
+            }
         };
         Ok(value)
     }
 
+    /// Replaces all of the values that have an alias with their aliased form. As an example, an
+    /// array of [`u8`] is aliased to [`SerializableManifestValue::Bytes`] and a tuple of a static
+    /// non-fungible resource address and a non-fungible local id is aliased to
+    /// [`SerializableManifestValue::NonFungibleGlobalId`].
+    pub fn alias(&self) -> Self {
+        match self {
+            Self::Array {
+                element_value_kind: SerializableManifestValueKind::U8,
+                elements,
+            } => {
+                let bytes = elements
+                    .iter()
+                    .map(|element| match element {
+                        Self::U8 { value } => Some(**value),
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<u8>>>();
+                match bytes {
+                    Some(bytes) => Self::Bytes {
+                        value: bytes.into(),
+                    },
+                    None => Self::Array {
+                        element_value_kind: SerializableManifestValueKind::U8,
+                        elements: elements.iter().map(Self::alias).collect(),
+                    },
+                }
+            }
+            Self::Tuple { fields } => match fields.as_slice() {
+                [Self::Address {
+                    value: SerializableManifestAddress::Static(node_id),
+                }, Self::NonFungibleLocalId { value: local_id }]
+                    if node_id.0.node_id.is_global_non_fungible_resource_manager() =>
+                {
+                    Self::NonFungibleGlobalId {
+                        value: SerializableNonFungibleGlobalId::new(
+                            NonFungibleGlobalId::new(
+                                ResourceAddress::new_or_panic(node_id.0.node_id.0),
+                                (**local_id).clone(),
+                            ),
+                            node_id.0.network_id,
+                        ),
+                    }
+                }
+                _ => Self::Tuple {
+                    fields: fields.iter().map(Self::alias).collect(),
+                },
+            },
+            Self::Enum {
+                discriminator,
+                fields,
+            } => Self::Enum {
+                discriminator: *discriminator,
+                fields: fields.iter().map(Self::alias).collect(),
+            },
+            Self::Array {
+                element_value_kind,
+                elements,
+            } => Self::Array {
+                element_value_kind: *element_value_kind,
+                elements: elements.iter().map(Self::alias).collect(),
+            },
+            Self::Map {
+                key_value_kind,
+                value_value_kind,
+                entries,
+            } => Self::Map {
+                key_value_kind: *key_value_kind,
+                value_value_kind: *value_value_kind,
+                entries: entries
+                    .iter()
+                    .map(|SerializableMapEntry { key, value }| SerializableMapEntry {
+                        key: key.alias(),
+                        value: value.alias(),
+                    })
+                    .collect(),
+            },
+            value => value.clone(),
+        }
+    }
+
+    /// Replaces all of the aliased values with their original non-aliased form. This is the
+    /// inverse of [`SerializableManifestValue::alias`].
+    pub fn dealias(&self) -> Self {
+        match self {
+            Self::Bytes { value } => Self::Array {
+                element_value_kind: SerializableManifestValueKind::U8,
+                elements: value
+                    .iter()
+                    .map(|byte| Self::U8 {
+                        value: into!(*byte),
+                    })
+                    .collect(),
+            },
+            Self::NonFungibleGlobalId { value } => {
+                let SerializableNonFungibleGlobalIdInternal {
+                    non_fungible_global_id,
+                    network_id,
+                } = &value.0;
+                Self::Tuple {
+                    fields: vec![
+                        Self::Address {
+                            value: SerializableManifestAddress::Static(SerializableNodeId::new(
+                                non_fungible_global_id.resource_address().into_node_id(),
+                                *network_id,
+                            )),
+                        },
+                        Self::NonFungibleLocalId {
+                            value: into!(non_fungible_global_id.local_id().clone()),
+                        },
+                    ],
+                }
+            }
+            Self::Enum {
+                discriminator,
+                fields,
+            } => Self::Enum {
+                discriminator: *discriminator,
+                fields: fields.iter().map(Self::dealias).collect(),
+            },
+            Self::Array {
+                element_value_kind,
+                elements,
+            } => Self::Array {
+                element_value_kind: *element_value_kind,
+                elements: elements.iter().map(Self::dealias).collect(),
+            },
+            Self::Tuple { fields } => Self::Tuple {
+                fields: fields.iter().map(Self::dealias).collect(),
+            },
+            Self::Map {
+                key_value_kind,
+                value_value_kind,
+                entries,
+            } => Self::Map {
+                key_value_kind: *key_value_kind,
+                value_value_kind: *value_value_kind,
+                entries: entries
+                    .iter()
+                    .map(|SerializableMapEntry { key, value }| SerializableMapEntry {
+                        key: key.dealias(),
+                        value: value.dealias(),
+                    })
+                    .collect(),
+            },
+            value => value.clone(),
+        }
+    }
+
     pub fn to_typed<T: ManifestDecode>(&self) -> Result<T, ValueConversionError> {
         let value = self.to_manifest_value()?;
         manifest_decode(&manifest_encode(&value).unwrap())
@@ -22,34 +22,59 @@ pub use crate::memory::*;
 pub use crate::utils::*;
 
 pub use crate::functions::address::*;
+pub use crate::functions::amount::*;
+pub use crate::functions::context::*;
+pub use crate::functions::dapp_definition::*;
 pub use crate::functions::derive::*;
+pub use crate::functions::events::*;
 pub use crate::functions::execution::*;
+#[cfg(feature = "gateway-models")]
+pub use crate::functions::gateway::*;
 pub use crate::functions::handler::*;
+pub use crate::functions::hashing::*;
+pub use crate::functions::header::*;
 pub use crate::functions::information::*;
 pub use crate::functions::instructions::*;
 pub use crate::functions::intent::*;
+pub use crate::functions::legacy::*;
+#[cfg(feature = "legacy-api")]
+pub use crate::functions::legacy_api::*;
 pub use crate::functions::macros::*;
 pub use crate::functions::manifest::*;
 pub use crate::functions::manifest_sbor::*;
+pub use crate::functions::native_state::*;
 pub use crate::functions::notarized_transaction::*;
+pub use crate::functions::olympia::*;
+pub use crate::functions::personal_message::*;
+pub use crate::functions::public_key::*;
+pub use crate::functions::sbor::*;
+pub use crate::functions::schema::*;
 pub use crate::functions::scrypto_sbor::*;
 pub use crate::functions::signed_intent::*;
 pub use crate::functions::traits::*;
+pub use crate::functions::transaction_request::*;
 pub use crate::functions::utils::*;
+pub use crate::functions::validation::*;
+pub use crate::functions::value::*;
 
 pub use crate::models::common::*;
 pub use crate::models::cryptographic::public_key::*;
 pub use crate::models::cryptographic::public_key_hash::*;
 pub use crate::models::cryptographic::signature::*;
 pub use crate::models::cryptographic::signature_with_public_key::*;
+#[cfg(feature = "gateway-models")]
+pub use crate::models::gateway::*;
+pub use crate::models::legacy::*;
 pub use crate::models::manifest::runtime::*;
 pub use crate::models::olympia::network::*;
 pub use crate::models::sbor::local_type_id::*;
 pub use crate::models::sbor::schema::*;
 pub use crate::models::sbor::serialization_mode::*;
+pub use crate::models::scrypto::metadata::*;
 pub use crate::models::scrypto::node_id::*;
 pub use crate::models::scrypto::non_fungible_global_id::*;
 pub use crate::models::traits::*;
+pub use crate::models::transaction::bytes_encoding::*;
 pub use crate::models::transaction::hash::*;
 pub use crate::models::transaction::header::*;
 pub use crate::models::transaction::instruction::*;
@@ -59,6 +59,89 @@ pub fn read_and_deserialize_from_memory<'s, D: Deserialize<'s>>(
         })
 }
 
+/// The format that function outputs are serialized in. JSON is the default. CBOR is a compact
+/// binary encoding of the same serde model, meant for clients where serializing large outputs as
+/// JSON dominates the cost of an invocation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SerializationMode {
+    Json = 0,
+    Cbor = 1,
+}
+
+impl TryFrom<u8> for SerializationMode {
+    type Error = InvocationInterpretationError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Json),
+            1 => Ok(Self::Cbor),
+            _ => Err(InvocationInterpretationError::UnknownSerializationMode(
+                value.to_string(),
+            )),
+        }
+    }
+}
+
+pub fn serialize_with_mode<S: Serialize>(
+    object: &S,
+    serialization_mode: SerializationMode,
+) -> Result<Vec<u8>, InvocationInterpretationError> {
+    match serialization_mode {
+        SerializationMode::Json => serde_json::to_vec(object).map_err(|error| {
+            InvocationInterpretationError::SerializationError(debug_string(error))
+        }),
+        SerializationMode::Cbor => {
+            let mut bytes = vec![];
+            ciborium::ser::into_writer(object, &mut bytes).map_err(|error| {
+                InvocationInterpretationError::SerializationError(debug_string(error))
+            })?;
+            Ok(bytes)
+        }
+    }
+}
+
+/// Serializes the object into a canonical JSON encoding: compact and with the keys of all objects in
+/// ascending order. Equal objects therefore always have equal encodings, which makes the encoding
+/// suitable for being signed.
+pub fn canonical_json<S: Serialize>(object: &S) -> Result<Vec<u8>, serde_json::Error> {
+    fn sort_keys(value: serde_json::Value) -> serde_json::Value {
+        match value {
+            serde_json::Value::Object(map) => {
+                let mut entries = map.into_iter().collect::<Vec<_>>();
+                entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+                serde_json::Value::Object(
+                    entries
+                        .into_iter()
+                        .map(|(key, value)| (key, sort_keys(value)))
+                        .collect(),
+                )
+            }
+            serde_json::Value::Array(values) => {
+                serde_json::Value::Array(values.into_iter().map(sort_keys).collect())
+            }
+            value => value,
+        }
+    }
+
+    let value = serde_json::to_value(object)?;
+    serde_json::to_vec(&sort_keys(value))
+}
+
+/// Writes the bytes to memory prefixed by their length as a little-endian [`u32`]. Binary
+/// payloads may contain null bytes and can therefore not be returned as C-strings. The memory is
+/// freed through [`crate::memory::toolkit_free_length_prefixed`].
+pub fn write_length_prefixed_to_memory(bytes: &[u8]) -> Pointer {
+    let length = (bytes.len() as u32).to_le_bytes();
+    let byte_count = length.len() + bytes.len();
+
+    unsafe {
+        let pointer = crate::memory::toolkit_alloc(byte_count);
+        pointer.copy_from([&length, bytes].concat().as_ptr() as Pointer, byte_count);
+        pointer
+    }
+}
+
 pub fn serialize_to_jstring<S: Serialize>(
     env: &jni::JNIEnv,
     object: &S,
@@ -73,6 +156,17 @@ pub fn serialize_to_jstring<S: Serialize>(
         .map(|string| string.into_raw())
 }
 
+pub fn serialize_to_jbyte_array(
+    env: &jni::JNIEnv,
+    bytes: &[u8],
+) -> Result<jni::sys::jbyteArray, InvocationInterpretationError> {
+    env.byte_array_from_slice(bytes)
+        .map_err(|error| {
+            InvocationInterpretationError::FailedToAllocateJniByteArray(debug_string(error))
+        })
+        .map(|array| array.into_raw())
+}
+
 pub fn deserialize_from_jstring<D: DeserializeOwned>(
     env: &mut jni::JNIEnv,
     jstring: &jni::objects::JString,
@@ -0,0 +1,84 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::prelude::*;
+use radix_engine_toolkit::utils::canonical_json;
+use radix_engine_toolkit_core::functions::attestation::sign;
+use transaction::prelude::Ed25519PrivateKey;
+
+#[test]
+fn attestation_of_another_key_fails_with_invalid_signature_error() {
+    // Arrange
+    let private_key = Ed25519PrivateKey::from_u64(1).unwrap();
+    let other_private_key = Ed25519PrivateKey::from_u64(2).unwrap();
+    let input = ExecutionVerifyAttestationInput {
+        attestation: sign(b"{}".to_vec(), &private_key).into(),
+        trusted_public_key: other_private_key.public_key().into(),
+    };
+
+    // Act
+    let output = ExecutionVerifyAttestation::handle(input);
+
+    // Assert
+    assert!(matches!(
+        output,
+        Err(InvocationHandlingError::InvalidAttestationSignature(..))
+    ));
+}
+
+#[test]
+fn attestation_with_valid_signature_over_undecodable_payload_fails_with_attestation_error() {
+    // Arrange
+    let private_key = Ed25519PrivateKey::from_u64(1).unwrap();
+    let input = ExecutionVerifyAttestationInput {
+        attestation: sign(b"{}".to_vec(), &private_key).into(),
+        trusted_public_key: private_key.public_key().into(),
+    };
+
+    // Act
+    let output = ExecutionVerifyAttestation::handle(input);
+
+    // Assert
+    assert!(matches!(
+        output,
+        Err(InvocationHandlingError::AttestationError(..))
+    ));
+}
+
+#[test]
+fn canonical_json_encoding_does_not_depend_on_the_order_of_keys() {
+    // Arrange
+    let value1 = serde_json::json!({
+        "b": { "d": "1", "c": ["2", { "f": "3", "e": "4" }] },
+        "a": "5"
+    });
+    let value2 = serde_json::json!({
+        "a": "5",
+        "b": { "c": ["2", { "e": "4", "f": "3" }], "d": "1" }
+    });
+
+    // Act
+    let encoding1 = canonical_json(&value1).unwrap();
+    let encoding2 = canonical_json(&value2).unwrap();
+
+    // Assert
+    assert_eq!(encoding1, encoding2);
+    assert_eq!(
+        encoding1,
+        br#"{"a":"5","b":{"c":["2",{"e":"4","f":"3"}],"d":"1"}}"#.to_vec()
+    );
+}
@@ -0,0 +1,204 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use radix_engine_toolkit::context::*;
+use scrypto::prelude::*;
+
+#[test]
+fn context_can_be_created_and_destroyed() {
+    // Arrange
+    let context_id = create_context();
+
+    // Act
+    let destroyed = destroy_context(context_id);
+    let destroyed_again = destroy_context(context_id);
+
+    // Assert
+    assert!(destroyed);
+    assert!(!destroyed_again);
+}
+
+#[test]
+fn bech32_encoders_are_cached_in_context() {
+    // Arrange
+    let context = get_context(create_context()).unwrap();
+
+    // Act
+    let (encoder1, encoder2) =
+        with_context(context, || (bech32_encoder(0xf2), bech32_encoder(0xf2)));
+
+    // Assert
+    assert!(Arc::ptr_eq(&encoder1, &encoder2));
+}
+
+#[test]
+fn bech32_encoders_are_not_cached_without_context() {
+    // Act
+    let encoder1 = bech32_encoder(0xf2);
+    let encoder2 = bech32_encoder(0xf2);
+
+    // Assert
+    assert!(!Arc::ptr_eq(&encoder1, &encoder2));
+}
+
+#[test]
+fn previous_context_is_restored_when_the_callback_panics() {
+    // Arrange
+    let context = get_context(create_context()).unwrap();
+
+    // Act
+    let result =
+        std::panic::catch_unwind(|| with_context(context, || panic!("Invocation panicked")));
+
+    // Assert
+    assert!(result.is_err());
+    assert!(!Arc::ptr_eq(&bech32_encoder(0xf2), &bech32_encoder(0xf2)));
+}
+
+#[test]
+fn context_caches_can_be_freed() {
+    // Arrange
+    let context = get_context(create_context()).unwrap();
+    with_context(context.clone(), || {
+        bech32_encoder(0xf2);
+        bech32_decoder(0xf2);
+    });
+
+    // Act
+    let evicted = context.clear_caches();
+
+    // Assert
+    assert_eq!(evicted, 3);
+    assert_eq!(
+        context.cache_statistics(),
+        CacheStatistics {
+            contexts: 1,
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn cached_address_encoding_matches_uncached_encoding() {
+    // Arrange
+    let node_id = XRD.into_node_id();
+    let expected = bech32_encoder(0xf2).encode(&node_id.0).unwrap();
+
+    // Act
+    let address1 = encode_address(&node_id, 0xf2).unwrap();
+    let address2 = encode_address(&node_id, 0xf2).unwrap();
+
+    // Assert
+    assert_eq!(address1, expected);
+    assert_eq!(address2, expected);
+}
+
+#[test]
+fn cached_addresses_are_specific_to_the_network() {
+    // Arrange
+    let node_id = XRD.into_node_id();
+
+    // Act
+    let mainnet_address = encode_address(&node_id, 0x01).unwrap();
+    let simulator_address = encode_address(&node_id, 0xf2).unwrap();
+
+    // Assert
+    assert!(mainnet_address.starts_with("resource_rdx"));
+    assert!(simulator_address.starts_with("resource_sim"));
+}
+
+#[test]
+fn least_recently_used_address_is_evicted_when_the_cache_overflows() {
+    // Arrange
+    let mut cache = AddressCache::new();
+    cache.configure(true, 2);
+    let [a, b, c] =
+        [XRD, ACCOUNT_OWNER_BADGE, IDENTITY_OWNER_BADGE].map(|address| address.into_node_id());
+    encode_with_cache(&mut cache, &a);
+    encode_with_cache(&mut cache, &b);
+    encode_with_cache(&mut cache, &a);
+
+    // Act
+    encode_with_cache(&mut cache, &c);
+
+    // Assert
+    assert_eq!(cache.len(), 2);
+    assert!(!encode_with_cache(&mut cache, &a));
+    assert!(!encode_with_cache(&mut cache, &c));
+    assert!(encode_with_cache(&mut cache, &b));
+}
+
+#[test]
+fn address_cache_does_not_exceed_its_capacity() {
+    // Arrange
+    let mut cache = AddressCache::new();
+    cache.configure(true, 3);
+    let node_ids = [
+        XRD,
+        ACCOUNT_OWNER_BADGE,
+        IDENTITY_OWNER_BADGE,
+        PACKAGE_OWNER_BADGE,
+        VALIDATOR_OWNER_BADGE,
+    ]
+    .map(|address| address.into_node_id());
+
+    // Act
+    for node_id in node_ids.iter() {
+        encode_with_cache(&mut cache, node_id);
+    }
+    let evicted = cache.configure(true, 1);
+
+    // Assert
+    assert_eq!(evicted, 2);
+    assert_eq!(cache.len(), 1);
+    assert!(!encode_with_cache(&mut cache, &node_ids[4]));
+}
+
+#[test]
+fn disabled_address_cache_is_emptied_and_caches_nothing() {
+    // Arrange
+    let mut cache = AddressCache::new();
+    let node_id = XRD.into_node_id();
+    encode_with_cache(&mut cache, &node_id);
+
+    // Act
+    let evicted = cache.configure(false, AddressCache::DEFAULT_CAPACITY);
+
+    // Assert
+    assert_eq!(evicted, 1);
+    assert!(encode_with_cache(&mut cache, &node_id));
+    assert!(encode_with_cache(&mut cache, &node_id));
+    assert!(cache.is_empty());
+
+    cache.configure(true, AddressCache::DEFAULT_CAPACITY);
+    assert!(encode_with_cache(&mut cache, &node_id));
+    assert!(!encode_with_cache(&mut cache, &node_id));
+}
+
+/// Encodes the node id through the cache, returning whether the address had to be encoded.
+fn encode_with_cache(cache: &mut AddressCache, node_id: &NodeId) -> bool {
+    let mut encoded = false;
+    cache
+        .get_or_encode(node_id, 0xf2, || {
+            encoded = true;
+            bech32_encoder(0xf2).encode(&node_id.0)
+        })
+        .unwrap();
+    encoded
+}
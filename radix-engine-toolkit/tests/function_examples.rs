@@ -0,0 +1,93 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::prelude::*;
+
+#[test]
+fn examples_of_all_functions_can_be_produced() {
+    // Arrange
+    let input = FunctionExamplesInput {
+        function_name: None,
+    };
+
+    // Act
+    let examples = FunctionExamples::handle(input);
+
+    // Assert
+    let examples = examples.unwrap();
+    let example_count = function_example_inputs()
+        .unwrap()
+        .into_values()
+        .flat_map(|functions| functions.into_values())
+        .map(|inputs| inputs.len())
+        .sum::<usize>();
+    assert_eq!(
+        examples
+            .iter()
+            .map(|entry| entry.examples.len())
+            .sum::<usize>(),
+        example_count
+    );
+}
+
+#[test]
+fn examples_are_only_given_for_exported_functions() {
+    // Arrange
+    let exported_functions = FunctionCatalog::handle(FunctionCatalogInput {})
+        .unwrap()
+        .into_iter()
+        .map(|entry| entry.name)
+        .collect::<Vec<_>>();
+
+    // Act
+    let function_names = function_example_inputs()
+        .unwrap()
+        .into_values()
+        .flat_map(|functions| functions.into_keys())
+        .collect::<Vec<_>>();
+
+    // Assert
+    for function_name in function_names {
+        assert!(
+            exported_functions.contains(&function_name),
+            "{function_name}"
+        );
+    }
+}
+
+#[test]
+fn examples_can_be_filtered_by_function_name() {
+    // Arrange
+    let input = FunctionExamplesInput {
+        function_name: Some("amount_parse".to_owned()),
+    };
+
+    // Act
+    let examples = FunctionExamples::handle(input).unwrap();
+
+    // Assert
+    assert_eq!(examples.len(), 1);
+    assert_eq!(examples[0].name, "amount_parse");
+    assert_eq!(
+        examples[0]
+            .examples
+            .iter()
+            .map(|example| example.output.as_str())
+            .collect::<Vec<_>>(),
+        vec!["\"1000.5\"", "\"1500\""]
+    );
+}
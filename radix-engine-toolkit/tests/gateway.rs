@@ -0,0 +1,192 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+#![cfg(feature = "gateway-models")]
+
+use radix_engine_toolkit::prelude::*;
+use radix_engine_toolkit_core::functions::execution::FeeSummary;
+use radix_engine_toolkit_core::instruction_visitor::visitors::fee_locks_visitor::FeePayers;
+use scrypto::prelude::*;
+use transaction::prelude::*;
+
+#[test]
+fn gateway_committed_transaction_info_can_be_deserialized() {
+    // Arrange
+    let json = r#"{
+        "state_version": 10,
+        "epoch": 2,
+        "round": 3,
+        "transaction_status": "CommittedSuccess",
+        "intent_hash": "txid_tdx_2_1abc",
+        "fee_paid": "0.5",
+        "affected_global_entities": [],
+        "receipt": {
+            "status": "CommittedSuccess",
+            "fee_summary": {
+                "execution_cost_units_consumed": 1000,
+                "xrd_total_execution_cost": "0.1",
+                "xrd_total_finalization_cost": "0.2",
+                "xrd_total_royalty_cost": "0",
+                "xrd_total_storage_cost": "0.2",
+                "xrd_total_tipping_cost": "0"
+            }
+        }
+    }"#;
+
+    // Act
+    let info = serde_json::from_str::<GatewayCommittedTransactionInfo>(json).unwrap();
+
+    // Assert
+    assert_eq!(
+        info.transaction_status,
+        GatewayTransactionStatus::CommittedSuccess
+    );
+    assert_eq!(
+        info.receipt
+            .and_then(|receipt| receipt.fee_summary)
+            .map(FeeSummary::from),
+        Some(FeeSummary {
+            execution_cost: dec!("0.1"),
+            finalization_cost: dec!("0.2"),
+            storage_expansion_cost: dec!("0.2"),
+            royalty_cost: dec!("0"),
+            fee_payers: FeePayers::None,
+        })
+    );
+}
+
+#[test]
+fn conversion_fails_when_raw_payload_is_not_included() {
+    // Arrange
+    let json = r#"{
+        "state_version": 10,
+        "epoch": 2,
+        "round": 3,
+        "transaction_status": "CommittedSuccess"
+    }"#;
+    let info = serde_json::from_str::<GatewayCommittedTransactionInfo>(json).unwrap();
+
+    // Act
+    let analysis_input = GatewayAnalysisInput::try_from(info);
+
+    // Assert
+    assert_eq!(
+        analysis_input,
+        Err(GatewayModelConversionError::RawPayloadNotIncluded)
+    );
+}
+
+#[test]
+fn committed_transaction_can_be_summarized_through_the_function() {
+    // Arrange
+    let private_key = Secp256k1PrivateKey::from_u64(1).unwrap();
+    let account =
+        radix_engine_toolkit_core::functions::derive::virtual_account_address_from_public_key(
+            &private_key.public_key(),
+        );
+    let notarized_transaction = TransactionBuilder::new()
+        .manifest(
+            ManifestBuilder::new()
+                .withdraw_from_account(account, XRD, dec!("10"))
+                .try_deposit_entire_worktop_or_abort(account, None)
+                .build(),
+        )
+        .header(TransactionHeaderV1 {
+            network_id: 0x01,
+            start_epoch_inclusive: Epoch::of(100),
+            end_epoch_exclusive: Epoch::of(200),
+            nonce: 100,
+            notary_public_key: private_key.public_key().into(),
+            notary_is_signatory: true,
+            tip_percentage: 0,
+        })
+        .notarize(&private_key)
+        .build();
+    let raw_hex = radix_engine_toolkit_core::functions::notarized_transaction::compile(
+        &notarized_transaction,
+    )
+    .unwrap();
+    let input = GatewaySummarizeCommittedTransactionInput {
+        committed_transaction: GatewayCommittedTransactionInfo {
+            state_version: 10,
+            epoch: 150,
+            round: 3,
+            transaction_status: GatewayTransactionStatus::CommittedSuccess,
+            payload_hash: None,
+            intent_hash: None,
+            fee_paid: None,
+            raw_hex: Some(raw_hex.into()),
+            receipt: None,
+        },
+    };
+
+    // Act
+    let output = GatewaySummarizeCommittedTransaction::handle(input).unwrap();
+
+    // Assert
+    let account = SerializableNodeId::new(account.into_node_id(), 0x01);
+    assert_eq!(
+        output.transaction_status,
+        GatewayTransactionStatus::CommittedSuccess
+    );
+    assert_eq!(output.fee_summary, None);
+    assert_eq!(
+        output.addresses_by_role.accounts_withdrawn_from,
+        vec![account.clone()]
+    );
+    assert_eq!(
+        output.addresses_by_role.accounts_deposited_into,
+        vec![account]
+    );
+}
+
+#[test]
+fn function_fails_when_raw_payload_is_not_included() {
+    // Arrange
+    let json = r#"{
+        "committed_transaction": {
+            "state_version": 10,
+            "epoch": 2,
+            "round": 3,
+            "transaction_status": "CommittedSuccess"
+        }
+    }"#;
+    let input = serde_json::from_str::<GatewaySummarizeCommittedTransactionInput>(json).unwrap();
+
+    // Act
+    let output = GatewaySummarizeCommittedTransaction::handle(input);
+
+    // Assert
+    assert!(matches!(
+        output,
+        Err(InvocationHandlingError::GatewayModelConversionError(..))
+    ));
+}
+
+#[test]
+fn summarize_function_is_listed_in_the_function_catalog() {
+    // Arrange
+    let input = FunctionCatalogInput {};
+
+    // Act
+    let catalog = FunctionCatalog::handle(input).unwrap();
+
+    // Assert
+    assert!(catalog
+        .iter()
+        .any(|entry| entry.name == "gateway_summarize_committed_transaction"));
+}
@@ -19,7 +19,12 @@
 #![allow(clippy::expect_fun_call)]
 
 use radix_engine_common::prelude::*;
+use radix_engine_toolkit::functions::instructions::{
+    InstructionsExtractAddresses, InstructionsExtractAddressesInput,
+};
+use radix_engine_toolkit::functions::traits::Function;
 use radix_engine_toolkit::models::transaction::instruction::*;
+use radix_engine_toolkit::models::transaction::instructions::SerializableInstructions;
 use transaction::manifest::*;
 use walkdir::WalkDir;
 
@@ -110,3 +115,28 @@ fn common_manifests_can_be_converted_to_serialized_and_back_and_are_equal() {
         assert_eq!(instructions, manifest.instructions, "{path:?}")
     }
 }
+
+#[test]
+fn extracted_addresses_are_serialized_in_a_deterministic_order() {
+    // Arrange
+    let input = InstructionsExtractAddressesInput {
+        instructions: SerializableInstructions::String(
+            include_str!("manifests/account/multi_account_resource_transfer.rtm").to_owned(),
+        ),
+        network_id: 0xf2.into(),
+    };
+
+    // Act
+    let output = InstructionsExtractAddresses::handle(input.clone()).unwrap();
+    let other_output = InstructionsExtractAddresses::handle(input).unwrap();
+
+    // Assert
+    assert!(output
+        .addresses
+        .values()
+        .all(|addresses| addresses.windows(2).all(|window| window[0] <= window[1])));
+    assert_eq!(
+        serde_json::to_string(&output).unwrap(),
+        serde_json::to_string(&other_output).unwrap()
+    );
+}
@@ -0,0 +1,191 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_common::prelude::*;
+use radix_engine_toolkit::prelude::*;
+
+#[test]
+fn named_buckets_are_assigned_ids_in_creation_order() {
+    // Arrange
+    let xrd = SerializableNodeId::new(XRD.into_node_id(), 0xf2);
+    let instructions = vec![
+        LegacyInstruction::TakeFromWorktop {
+            resource_address: LegacyValue::Address { address: xrd },
+            into_bucket: named_bucket("first"),
+        },
+        LegacyInstruction::TakeFromWorktopByAmount {
+            resource_address: LegacyValue::Address { address: xrd },
+            amount: LegacyValue::Decimal {
+                value: dec!("10").into(),
+            },
+            into_bucket: named_bucket("second"),
+        },
+        LegacyInstruction::ReturnToWorktop {
+            bucket: named_bucket("second"),
+        },
+        LegacyInstruction::BurnResource {
+            bucket: named_bucket("first"),
+        },
+    ];
+
+    // Act
+    let (instructions, losses) = legacy_instructions_to_instructions(&instructions).unwrap();
+
+    // Assert
+    assert_eq!(
+        instructions,
+        vec![
+            SerializableInstruction::TakeAllFromWorktop {
+                resource_address: xrd
+            },
+            SerializableInstruction::TakeFromWorktop {
+                resource_address: xrd,
+                amount: dec!("10").into()
+            },
+            SerializableInstruction::ReturnToWorktop {
+                bucket_id: 1.into()
+            },
+            SerializableInstruction::BurnResource {
+                bucket_id: 0.into()
+            },
+        ]
+    );
+    assert_eq!(
+        losses,
+        vec![
+            LegacyConversionLoss::BucketNameDropped {
+                instruction_index: Some(0.into()),
+                name: "first".to_owned()
+            },
+            LegacyConversionLoss::BucketNameDropped {
+                instruction_index: Some(1.into()),
+                name: "second".to_owned()
+            },
+        ]
+    );
+}
+
+#[test]
+fn instructions_round_trip_through_the_legacy_model() {
+    // Arrange
+    let xrd = SerializableNodeId::new(XRD.into_node_id(), 0xf2);
+    let faucet = SerializableNodeId::new(FAUCET.into_node_id(), 0xf2);
+    let instructions = vec![
+        SerializableInstruction::CallMethod {
+            address: SerializableManifestAddress::Static(faucet),
+            method_name: "free".to_owned(),
+            args: SerializableManifestValue::Tuple { fields: vec![] },
+        },
+        SerializableInstruction::TakeAllFromWorktop {
+            resource_address: xrd,
+        },
+        SerializableInstruction::CreateProofFromBucketOfAll {
+            bucket_id: 0.into(),
+        },
+        SerializableInstruction::DropProof { proof_id: 0.into() },
+        SerializableInstruction::CallMethod {
+            address: SerializableManifestAddress::Static(faucet),
+            method_name: "deposit".to_owned(),
+            args: SerializableManifestValue::Tuple {
+                fields: vec![SerializableManifestValue::Bucket { value: 0.into() }],
+            },
+        },
+    ];
+
+    // Act
+    let (legacy_instructions, downgrade_losses) =
+        instructions_to_legacy_instructions(&instructions).unwrap();
+    let (upgraded_instructions, upgrade_losses) =
+        legacy_instructions_to_instructions(&legacy_instructions).unwrap();
+
+    // Assert
+    assert_eq!(upgraded_instructions, instructions);
+    assert!(downgrade_losses.is_empty());
+    assert!(upgrade_losses.is_empty());
+}
+
+#[test]
+fn module_method_calls_are_downgraded_with_a_loss() {
+    // Arrange
+    let faucet = SerializableNodeId::new(FAUCET.into_node_id(), 0xf2);
+    let instructions = vec![SerializableInstruction::CallMetadataMethod {
+        address: SerializableManifestAddress::Static(faucet),
+        method_name: "remove".to_owned(),
+        args: SerializableManifestValue::Tuple {
+            fields: vec![SerializableManifestValue::String {
+                value: "name".to_owned(),
+            }],
+        },
+    }];
+
+    // Act
+    let (_, losses) = instructions_to_legacy_instructions(&instructions).unwrap();
+
+    // Assert
+    assert_eq!(
+        losses,
+        vec![LegacyConversionLoss::ObjectModuleDropped {
+            instruction_index: Some(0.into()),
+            module: "Metadata".to_owned()
+        }]
+    );
+}
+
+#[test]
+fn named_enum_variants_are_resolved_to_their_discriminators() {
+    // Arrange
+    let value = LegacyValue::Enum {
+        variant: LegacyEnumDiscriminator::String {
+            discriminator: "Option::Some".to_owned(),
+        },
+        fields: Some(vec![LegacyValue::U8 { value: 1.into() }]),
+    };
+
+    // Act
+    let (value, losses) = legacy_value_to_value(&value).unwrap();
+
+    // Assert
+    assert_eq!(
+        value,
+        SerializableManifestValue::Enum {
+            discriminator: 1.into(),
+            fields: vec![SerializableManifestValue::U8 { value: 1.into() }]
+        }
+    );
+    assert_eq!(losses.len(), 1);
+}
+
+#[test]
+fn legacy_instructions_deserialize_from_their_json_representation() {
+    // Arrange
+    let json = r#"{
+        "instruction": "DROP_PROOF",
+        "proof": { "type": "Proof", "identifier": { "type": "U32", "value": "0" } }
+    }"#;
+
+    // Act
+    let instruction = serde_json::from_str::<LegacyInstruction>(json);
+
+    // Assert
+    assert!(instruction.is_ok());
+}
+
+fn named_bucket(name: &str) -> LegacyValue {
+    LegacyValue::Bucket {
+        identifier: LegacyTransientIdentifier::String(name.to_owned()),
+    }
+}
@@ -0,0 +1,55 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+#![cfg(feature = "legacy-api")]
+
+use radix_engine_toolkit::prelude::*;
+
+#[test]
+fn convert_manifest_forwards_to_manifest_convert() {
+    // Arrange
+    let input = ManifestConvertInput {
+        manifest: SerializableTransactionManifest {
+            instructions: SerializableInstructions::String("CLEAR_AUTH_ZONE;\n".to_owned()),
+            blobs: vec![],
+        },
+        network_id: 0xf2.into(),
+        instructions_kind: SerializableInstructionsKind::Parsed,
+    };
+
+    // Act
+    let legacy_output = LegacyConvertManifest::handle(input.clone()).unwrap();
+    let output = ManifestConvert::handle(input).unwrap();
+
+    // Assert
+    assert_eq!(legacy_output, output);
+}
+
+#[test]
+fn decompile_unknown_transaction_intent_fails_for_invalid_payloads() {
+    // Arrange
+    let input = LegacyDecompileUnknownTransactionIntentInput {
+        compiled: vec![0x00, 0x01, 0x02].into(),
+        instructions_kind: SerializableInstructionsKind::String,
+    };
+
+    // Act
+    let output = LegacyDecompileUnknownTransactionIntent::handle(input);
+
+    // Assert
+    assert!(output.is_err());
+}
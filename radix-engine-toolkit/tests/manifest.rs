@@ -0,0 +1,75 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::prelude::*;
+
+#[test]
+fn manifest_can_be_converted_without_losing_its_blobs() {
+    // Arrange
+    let manifest = SerializableTransactionManifest {
+        instructions: SerializableInstructions::String("CLEAR_AUTH_ZONE;\n".to_owned()),
+        blobs: vec![vec![0x01, 0x02].into(), vec![0x03].into()],
+    };
+
+    // Act
+    let converted = ManifestConvert::handle(ManifestConvertInput {
+        manifest: manifest.clone(),
+        network_id: 0xf2.into(),
+        instructions_kind: SerializableInstructionsKind::Parsed,
+    })
+    .unwrap();
+
+    // Assert
+    assert!(matches!(
+        converted.instructions,
+        SerializableInstructions::Parsed(..)
+    ));
+    assert_eq!(converted.blobs, manifest.blobs);
+}
+
+#[test]
+fn manifest_converted_back_and_forth_hashes_the_same() {
+    // Arrange
+    let manifest = SerializableTransactionManifest {
+        instructions: SerializableInstructions::String("CLEAR_AUTH_ZONE;\n".to_owned()),
+        blobs: vec![vec![0x01, 0x02].into()],
+    };
+    let parsed = ManifestConvert::handle(ManifestConvertInput {
+        manifest: manifest.clone(),
+        network_id: 0xf2.into(),
+        instructions_kind: SerializableInstructionsKind::Parsed,
+    })
+    .unwrap();
+
+    // Act
+    let converted = ManifestConvert::handle(ManifestConvertInput {
+        manifest: parsed,
+        network_id: 0xf2.into(),
+        instructions_kind: SerializableInstructionsKind::String,
+    })
+    .unwrap();
+
+    // Assert
+    let hash = |manifest: SerializableTransactionManifest| {
+        ManifestHash::handle(ManifestHashInput {
+            manifest,
+            network_id: 0xf2.into(),
+        })
+        .unwrap()
+    };
+    assert_eq!(hash(converted), hash(manifest));
+}
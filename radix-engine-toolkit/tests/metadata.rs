@@ -0,0 +1,93 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_common::prelude::*;
+use radix_engine_interface::types::KeyValueStoreInitEntry;
+use radix_engine_toolkit::prelude::*;
+use scrypto::api::node_modules::metadata::*;
+
+#[test]
+fn metadata_values_can_be_converted_to_serializable_and_back() {
+    // Arrange
+    let values = vec![
+        MetadataValue::String("Hello World".to_owned()),
+        MetadataValue::Decimal(dec!("10.5")),
+        MetadataValue::GlobalAddress(XRD.into()),
+        MetadataValue::Url(UncheckedUrl("https://www.radixdlt.com".to_owned())),
+        MetadataValue::NonFungibleLocalIdArray(vec![NonFungibleLocalId::integer(1)]),
+    ];
+
+    for value in values {
+        // Act
+        let converted = SerializableMetadataValue::new(value.clone(), 0xf2)
+            .to_native()
+            .unwrap();
+
+        // Assert
+        assert_eq!(converted, value);
+    }
+}
+
+#[test]
+fn metadata_init_can_be_converted_to_native() {
+    // Arrange
+    let metadata_init = SerializableMetadataInit::from([(
+        "name".to_owned(),
+        SerializableMetadataInitEntry {
+            value: Some(SerializableMetadataValue::String("Token".to_owned())),
+            lock: true,
+        },
+    )]);
+
+    // Act
+    let native = metadata_init_to_native(&metadata_init).unwrap();
+
+    // Assert
+    assert_eq!(
+        native.data.get("name"),
+        Some(&KeyValueStoreInitEntry {
+            value: Some(MetadataValue::String("Token".to_owned())),
+            lock: true,
+        })
+    );
+}
+
+#[test]
+fn metadata_init_is_converted_to_native_in_the_order_of_its_keys() {
+    // Arrange
+    let metadata_init = ["symbol", "name", "description"]
+        .into_iter()
+        .map(|key| {
+            (
+                key.to_owned(),
+                SerializableMetadataInitEntry {
+                    value: Some(SerializableMetadataValue::String(key.to_owned())),
+                    lock: false,
+                },
+            )
+        })
+        .collect::<SerializableMetadataInit>();
+
+    // Act
+    let native = metadata_init_to_native(&metadata_init).unwrap();
+
+    // Assert
+    assert_eq!(
+        native.data.keys().collect::<Vec<_>>(),
+        vec!["description", "name", "symbol"]
+    );
+}
@@ -0,0 +1,189 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::ffi::CString;
+
+use radix_engine_toolkit::functions::handler::*;
+use radix_engine_toolkit::memory::*;
+use radix_engine_toolkit::prelude::*;
+use radix_engine_toolkit::utils::*;
+
+#[test]
+fn cbor_serialized_output_can_be_deserialized() {
+    // Arrange
+    let manifest = SerializableTransactionManifest {
+        instructions: SerializableInstructions::String("CLEAR_AUTH_ZONE;\n".to_owned()),
+        blobs: vec![vec![0x00, 0x01].into()],
+    };
+
+    // Act
+    let bytes = serialize_with_mode(&manifest, SerializationMode::Cbor).unwrap();
+
+    // Assert
+    let deserialized =
+        ciborium::de::from_reader::<SerializableTransactionManifest, _>(bytes.as_slice()).unwrap();
+    assert_eq!(deserialized, manifest);
+}
+
+#[test]
+fn cbor_serialized_output_is_smaller_than_json() {
+    // Arrange
+    let manifest = SerializableTransactionManifest {
+        instructions: SerializableInstructions::String("CLEAR_AUTH_ZONE;\n".to_owned()),
+        blobs: vec![vec![0xff; 1024].into()],
+    };
+
+    // Act
+    let json = serialize_with_mode(&manifest, SerializationMode::Json).unwrap();
+    let cbor = serialize_with_mode(&manifest, SerializationMode::Cbor).unwrap();
+
+    // Assert
+    assert!(cbor.len() < json.len());
+}
+
+#[test]
+fn invocation_output_is_length_prefixed() {
+    // Arrange
+    let input = CString::new("{}").unwrap().into_raw();
+
+    // Act
+    let output =
+        handle_invocation_with_options::<BuildInformation>(0, SerializationMode::Cbor as u8, input);
+
+    // Assert
+    let payload = unsafe { read_length_prefixed(output) };
+    assert!(ciborium::de::from_reader::<BuildInformationOutput, _>(payload.as_slice()).is_ok());
+    unsafe {
+        toolkit_free_length_prefixed(output);
+        drop(CString::from_raw(input));
+    }
+}
+
+#[test]
+fn unknown_serialization_mode_is_reported_as_a_json_error() {
+    // Arrange
+    let input = CString::new("{}").unwrap().into_raw();
+
+    // Act
+    let output = handle_invocation_with_options::<BuildInformation>(0, 0xff, input);
+
+    // Assert
+    let payload = unsafe { read_length_prefixed(output) };
+    let error = serde_json::from_slice::<serde_json::Value>(&payload).unwrap();
+    assert_eq!(error["error"]["kind"], "UnknownSerializationMode");
+    unsafe {
+        toolkit_free_length_prefixed(output);
+        drop(CString::from_raw(input));
+    }
+}
+
+#[test]
+fn unknown_context_is_reported_in_the_serialization_mode() {
+    // Arrange
+    let input = CString::new("{}").unwrap().into_raw();
+
+    // Act
+    let output = handle_invocation_with_options::<BuildInformation>(
+        u64::MAX,
+        SerializationMode::Cbor as u8,
+        input,
+    );
+
+    // Assert
+    let payload = unsafe { read_length_prefixed(output) };
+    let error = ciborium::de::from_reader::<serde_json::Value, _>(payload.as_slice()).unwrap();
+    assert_eq!(error["error"]["kind"], "ContextNotFound");
+    unsafe {
+        toolkit_free_length_prefixed(output);
+        drop(CString::from_raw(input));
+    }
+}
+
+unsafe fn read_length_prefixed(pointer: Pointer) -> Vec<u8> {
+    let pointer = pointer as *const u8;
+    let mut length = [0u8; 4];
+    pointer.copy_to(length.as_mut_ptr(), length.len());
+    let length = u32::from_le_bytes(length) as usize;
+    std::slice::from_raw_parts(pointer.add(4), length).to_vec()
+}
+
+#[test]
+fn bytes_are_encoded_in_the_requested_bytes_encoding() {
+    // Arrange
+    let bytes = vec![0x00, 0x01, 0xfe, 0xff];
+
+    // Act
+    let hex = SerializableBytesEncoding::Hex.encode(&bytes);
+    let base64 = SerializableBytesEncoding::Base64.encode(&bytes);
+
+    // Assert
+    assert_eq!(hex, "0001feff");
+    assert_eq!(base64, "AAH+/w==");
+    assert_eq!(
+        SerializableBytesEncoding::Base64.decode(&base64).unwrap(),
+        bytes
+    );
+}
+
+#[test]
+fn compiled_instructions_are_returned_and_accepted_in_the_requested_bytes_encoding() {
+    // Arrange
+    let instructions = SerializableInstructions::String("CLEAR_AUTH_ZONE;\n".to_owned());
+    let compile = |bytes_encoding| {
+        InstructionsCompile::handle(InstructionsCompileInput {
+            instructions: instructions.clone(),
+            network_id: 0xf2.into(),
+            bytes_encoding,
+        })
+        .unwrap()
+    };
+    let decompile = |compiled, bytes_encoding| {
+        InstructionsDecompile::handle(InstructionsDecompileInput {
+            compiled,
+            bytes_encoding,
+            network_id: 0xf2.into(),
+            instructions_kind: SerializableInstructionsKind::String,
+        })
+        .unwrap()
+    };
+
+    // Act
+    let hex = compile(SerializableBytesEncoding::Hex);
+    let base64 = compile(SerializableBytesEncoding::Base64);
+
+    // Assert
+    assert_eq!(
+        SerializableBytesEncoding::Base64.decode(&base64).unwrap(),
+        SerializableBytesEncoding::Hex.decode(&hex).unwrap()
+    );
+    assert_eq!(
+        decompile(base64, SerializableBytesEncoding::Base64),
+        decompile(hex, SerializableBytesEncoding::Hex)
+    );
+}
+
+#[test]
+fn bytes_encoding_of_decompile_input_defaults_to_hex() {
+    // Arrange
+    let input = r#"{"compiled":"00","network_id":"242","instructions_kind":"String"}"#;
+
+    // Act
+    let input = serde_json::from_str::<InstructionsDecompileInput>(input).unwrap();
+
+    // Assert
+    assert_eq!(input.bytes_encoding, SerializableBytesEncoding::Hex);
+}
@@ -0,0 +1,69 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_common::prelude::*;
+use radix_engine_toolkit::prelude::*;
+use transaction::prelude::*;
+
+#[test]
+fn array_of_u8_is_aliased_to_bytes() {
+    // Arrange
+    let value = SerializableManifestValue::from_typed(&vec![1u8, 2u8, 3u8], 0xf2).unwrap();
+
+    // Act
+    let aliased = value.alias();
+
+    // Assert
+    assert_eq!(
+        aliased,
+        SerializableManifestValue::Bytes {
+            value: vec![1u8, 2u8, 3u8].into()
+        }
+    );
+}
+
+#[test]
+fn non_fungible_global_id_tuple_is_aliased_and_can_be_dealiased() {
+    // Arrange
+    let id = NonFungibleGlobalId::new(VALIDATOR_OWNER_BADGE, NonFungibleLocalId::integer(1));
+    let value = SerializableManifestValue::from_typed(&id, 0xf2).unwrap();
+
+    // Act
+    let aliased = value.alias();
+    let dealiased = aliased.dealias();
+
+    // Assert
+    assert_eq!(
+        aliased,
+        SerializableManifestValue::NonFungibleGlobalId {
+            value: SerializableNonFungibleGlobalId::new(id, 0xf2)
+        }
+    );
+    assert_eq!(dealiased, value);
+}
+
+#[test]
+fn aliased_values_can_be_converted_to_manifest_values() {
+    // Arrange
+    let value = SerializableManifestValue::from_typed(&vec![1u8, 2u8, 3u8], 0xf2).unwrap();
+
+    // Act
+    let manifest_value = value.alias().to_manifest_value().unwrap();
+
+    // Assert
+    assert_eq!(manifest_value, value.to_manifest_value().unwrap());
+}
@@ -1,4 +1,4 @@
 (cd radix-engine-toolkit-core; cargo nextest run)
-(cd radix-engine-toolkit; cargo nextest run)
+(cd radix-engine-toolkit; cargo nextest run --features gateway-models)
 (cd radix-engine-toolkit-uniffi; cargo nextest run)
 (cd sbor-json; cargo nextest run)
\ No newline at end of file